@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::sync::Arc;
+use std::{path::PathBuf, str::FromStr, sync::Arc};
 
 use nautilus_blockchain::{config::BlockchainAdapterConfig, data::BlockchainDataClient, exchanges};
 use nautilus_common::logging::{
@@ -22,9 +22,11 @@ use nautilus_common::logging::{
 };
 use nautilus_core::{UUID4, env::get_env_var};
 use nautilus_model::{
+    data::BarType,
     defi::chain::{Blockchain, Chain, chains},
     identifiers::TraderId,
 };
+use nautilus_persistence::backend::catalog::ParquetDataCatalog;
 use tokio::sync::Notify;
 
 #[tokio::main]
@@ -102,12 +104,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     Some(pool_creation_block),
                     Some(pool_creation_block + 1),
                 ).await.unwrap();
-                data_client.sync_pool_swaps(
-                    dex_id.as_str(),
-                    weth_usdc_pool.to_string(),
-                    from_block,
-                    None,
-                ).await.unwrap();
+                // Export swaps as trades and one-minute bars when a catalog path is set
+                if let Ok(catalog_path) = std::env::var("CATALOG_PATH") {
+                    let catalog = ParquetDataCatalog::new(PathBuf::from(catalog_path), None);
+                    let pool = data_client.get_pool(weth_usdc_pool).unwrap();
+                    let bar_type = BarType::from_str(&format!(
+                        "{}-1-MINUTE-LAST-INTERNAL",
+                        pool.instrument_id()
+                    ))
+                    .unwrap();
+                    data_client.export_pool_swaps(
+                        dex_id.as_str(),
+                        weth_usdc_pool.to_string(),
+                        from_block,
+                        None,
+                        &catalog,
+                        &[bar_type],
+                    ).await.unwrap();
+                } else {
+                    data_client.sync_pool_swaps(
+                        dex_id.as_str(),
+                        weth_usdc_pool.to_string(),
+                        from_block,
+                        None,
+                    ).await.unwrap();
+                }
 
 
             } => break,
@@ -18,6 +18,7 @@ use nautilus_model::defi::{
     block::Block,
     chain::{Chain, SharedChain},
     dex::Dex,
+    liquidity::{PoolFeeCollect, PoolLiquidityUpdate},
     swap::Swap,
     token::Token,
 };
@@ -224,6 +225,63 @@ impl BlockchainCacheDatabase {
         .map_err(|e| anyhow::anyhow!("Failed to insert into swap table: {e}"))
     }
 
+    /// Records a pool liquidity update (mint or burn) in the database.
+    pub async fn add_pool_liquidity_update(
+        &self,
+        chain_id: u32,
+        update: &PoolLiquidityUpdate,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r"
+            INSERT INTO pool_liquidity (
+                chain_id, pool_address, block, event_type, owner, position_liquidity,
+                amount0, amount1, tick_lower, tick_upper
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        ",
+        )
+        .bind(chain_id as i32)
+        .bind(update.pool.address.to_string())
+        .bind(update.block as i64)
+        .bind(update.kind.to_string())
+        .bind(update.owner.to_string())
+        .bind(update.position_liquidity.to_string())
+        .bind(update.amount0.to_string())
+        .bind(update.amount1.to_string())
+        .bind(update.tick_lower)
+        .bind(update.tick_upper)
+        .execute(&self.pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to insert into pool_liquidity table: {e}"))
+    }
+
+    /// Records a pool fee collect in the database.
+    pub async fn add_pool_fee_collect(
+        &self,
+        chain_id: u32,
+        collect: &PoolFeeCollect,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r"
+            INSERT INTO pool_collect (
+                chain_id, pool_address, block, owner, amount0, amount1, tick_lower, tick_upper
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ",
+        )
+        .bind(chain_id as i32)
+        .bind(collect.pool.address.to_string())
+        .bind(collect.block as i64)
+        .bind(collect.owner.to_string())
+        .bind(collect.amount0.to_string())
+        .bind(collect.amount1.to_string())
+        .bind(collect.tick_lower)
+        .bind(collect.tick_upper)
+        .execute(&self.pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to insert into pool_collect table: {e}"))
+    }
+
     /// Retrieves all token records for the given chain and converts them into `Token` domain objects.
     pub async fn load_tokens(&self, chain: SharedChain) -> anyhow::Result<Vec<Token>> {
         sqlx::query_as::<_, TokenRow>("SELECT * FROM token WHERE chain_id = $1")
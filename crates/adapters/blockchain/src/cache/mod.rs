@@ -24,6 +24,8 @@ use nautilus_model::defi::{
     amm::{Pool, SharedPool},
     block::Block,
     chain::SharedChain,
+    liquidity::{PoolFeeCollect, PoolLiquidityUpdate},
+    position::PoolPosition,
     swap::Swap,
     token::Token,
 };
@@ -47,6 +49,8 @@ pub struct BlockchainCache {
     tokens: HashMap<Address, Token>,
     /// Map of pool addresses to their corresponding `Pool` objects.
     pools: HashMap<Address, SharedPool>,
+    /// Map of (pool, owner, tick lower, tick upper) to liquidity provider positions.
+    positions: HashMap<(Address, Address, i32, i32), PoolPosition>,
     /// Optional database connection for persistent storage.
     database: Option<BlockchainCacheDatabase>,
 }
@@ -60,6 +64,7 @@ impl BlockchainCache {
             dexes: HashMap::new(),
             tokens: HashMap::new(),
             pools: HashMap::new(),
+            positions: HashMap::new(),
             block_timestamps: BTreeMap::new(),
             database: None,
         }
@@ -187,6 +192,100 @@ impl BlockchainCache {
         Ok(())
     }
 
+    /// Adds a pool liquidity update, applying it to the corresponding LP position and
+    /// persisting it to the database if available.
+    ///
+    /// An update which cannot be applied to its position (e.g. burning more liquidity than
+    /// the position holds) is logged and skipped, leaving the position unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting the update to the database fails.
+    pub async fn add_pool_liquidity_update(
+        &mut self,
+        update: PoolLiquidityUpdate,
+    ) -> anyhow::Result<()> {
+        if let Some(database) = &self.database {
+            database
+                .add_pool_liquidity_update(self.chain.chain_id, &update)
+                .await?;
+        }
+
+        let key = (
+            update.pool.address,
+            update.owner,
+            update.tick_lower,
+            update.tick_upper,
+        );
+        let position = self.positions.entry(key).or_insert_with(|| {
+            PoolPosition::new(
+                update.pool.clone(),
+                update.owner,
+                update.tick_lower,
+                update.tick_upper,
+            )
+        });
+        if let Err(e) = position.apply_update(&update) {
+            log::error!("Skipping {update}: {e}");
+        }
+
+        Ok(())
+    }
+
+    /// Adds a pool fee collect, applying it to the corresponding LP position and persisting
+    /// it to the database if available.
+    ///
+    /// A collect which cannot be applied to its position is logged and skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting the collect to the database fails.
+    pub async fn add_pool_fee_collect(&mut self, collect: PoolFeeCollect) -> anyhow::Result<()> {
+        if let Some(database) = &self.database {
+            database
+                .add_pool_fee_collect(self.chain.chain_id, &collect)
+                .await?;
+        }
+
+        let key = (
+            collect.pool.address,
+            collect.owner,
+            collect.tick_lower,
+            collect.tick_upper,
+        );
+        match self.positions.get_mut(&key) {
+            Some(position) => {
+                if let Err(e) = position.apply_collect(&collect) {
+                    log::error!("Skipping {collect}: {e}");
+                }
+            }
+            None => log::warn!("Received collect for unknown position: {collect}"),
+        }
+
+        Ok(())
+    }
+
+    /// Returns the LP position for the given pool, owner and tick range, if any.
+    #[must_use]
+    pub fn get_position(
+        &self,
+        pool: &Address,
+        owner: &Address,
+        tick_lower: i32,
+        tick_upper: i32,
+    ) -> Option<&PoolPosition> {
+        self.positions.get(&(*pool, *owner, tick_lower, tick_upper))
+    }
+
+    /// Returns all LP positions held by the given owner.
+    #[must_use]
+    pub fn positions_for_owner(&self, owner: &Address) -> Vec<&PoolPosition> {
+        self.positions
+            .values()
+            .filter(|position| position.owner == *owner)
+            .collect()
+    }
+
     /// Returns a reference to the `DexExtended` associated with the given name.
     #[must_use]
     pub fn get_dex(&self, name: &str) -> Option<&DexExtended> {
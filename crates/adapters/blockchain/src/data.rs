@@ -15,7 +15,9 @@
 
 use std::{cmp::max, sync::Arc};
 
-use futures_util::StreamExt;
+use alloy::primitives::{U256, keccak256};
+use futures_util::{Stream, StreamExt};
+use hypersync_client::simple_types::Log;
 use nautilus_common::messages::data::{
     RequestBars, RequestBookSnapshot, RequestInstrument, RequestInstruments, RequestQuotes,
     RequestTrades, SubscribeBars, SubscribeBookDeltas, SubscribeBookDepth10,
@@ -30,22 +32,27 @@ use nautilus_common::messages::data::{
 use nautilus_data::client::DataClient;
 use nautilus_infrastructure::sql::pg::PostgresConnectOptions;
 use nautilus_model::{
+    data::BarType,
     defi::{
-        amm::Pool,
+        amm::{Pool, SharedPool},
         chain::{Blockchain, SharedChain},
-        dex::Dex,
+        dex::{AmmType, Dex},
+        liquidity::{PoolFeeCollect, PoolLiquidityUpdate, PoolLiquidityUpdateType},
         swap::Swap,
         token::Token,
     },
     identifiers::{ClientId, Venue},
+    types::{Quantity, fixed::FIXED_PRECISION},
 };
+use nautilus_persistence::backend::catalog::ParquetDataCatalog;
 
 use crate::{
     cache::BlockchainCache,
     config::BlockchainAdapterConfig,
     contracts::erc20::Erc20Contract,
-    events::pool_created::PoolCreated,
-    exchanges::extended::DexExtended,
+    events::{burn::BurnEvent, collect::CollectEvent, mint::MintEvent, pool_created::PoolCreated},
+    exchanges::{extended::DexExtended, parsing::uniswap_v2::TRANSFER_EVENT},
+    export::export_swaps_to_catalog,
     hypersync::client::HyperSyncClient,
     rpc::{
         BlockchainRpcClient, BlockchainRpcClientAny,
@@ -190,13 +197,15 @@ impl BlockchainDataClient {
     }
 
     /// Fetches and caches all swap events for a specific liquidity pool within the given block range.
+    ///
+    /// Returns the synced swaps.
     pub async fn sync_pool_swaps(
         &mut self,
         dex_id: &str,
         pool_address: String,
         from_block: Option<u64>,
         to_block: Option<u64>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<Vec<Swap>> {
         let dex_extended = self.get_dex(dex_id)?.clone();
         let pool_address = validate_address(&pool_address)?;
         let pool = match self.cache.get_pool(&pool_address) {
@@ -229,6 +238,8 @@ impl BlockchainDataClient {
             )
             .await;
 
+        let mut swaps = Vec::new();
+
         tokio::pin!(swaps_stream);
         while let Some(log) = swaps_stream.next().await {
             match parse_swap_event_fn(log) {
@@ -259,12 +270,78 @@ impl BlockchainDataClient {
                         size,
                         price,
                     );
-                    self.cache.add_swap(swap).await?;
+                    self.cache.add_swap(swap.clone()).await?;
+                    swaps.push(swap);
                 }
                 Err(e) => log::error!("Error processing swap event: {e}"),
             }
         }
         log::info!("Finished syncing pool swaps");
+        Ok(swaps)
+    }
+
+    /// Syncs all swap events for a specific liquidity pool within the given block range, and
+    /// writes them to the catalog as trade ticks along with bars for each of the `bar_types`.
+    ///
+    /// Returns the number of trades written.
+    pub async fn export_pool_swaps(
+        &mut self,
+        dex_id: &str,
+        pool_address: String,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+        catalog: &ParquetDataCatalog,
+        bar_types: &[BarType],
+    ) -> anyhow::Result<usize> {
+        let swaps = self
+            .sync_pool_swaps(dex_id, pool_address, from_block, to_block)
+            .await?;
+        let count = export_swaps_to_catalog(catalog, &swaps, bar_types)?;
+        log::info!("Exported {count} trades to catalog");
+        Ok(count)
+    }
+
+    /// Fetches and caches all liquidity mint, burn and fee collect events for a specific
+    /// liquidity pool within the given block range, updating the tracked LP positions.
+    ///
+    /// The events are requested as a single stream, so they are applied to the positions in
+    /// on-chain order. Events which cannot be parsed or applied are logged and skipped.
+    pub async fn sync_pool_liquidity_events(
+        &mut self,
+        dex_id: &str,
+        pool_address: String,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+    ) -> anyhow::Result<()> {
+        let dex_extended = self.get_dex(dex_id)?.clone();
+        let pool_address = validate_address(&pool_address)?;
+        let pool = match self.cache.get_pool(&pool_address) {
+            Some(pool) => pool.clone(),
+            None => anyhow::bail!("Pool {pool_address} is not registered"),
+        };
+
+        if dex_extended.parse_mint_event_fn.is_none() || dex_extended.parse_burn_event_fn.is_none()
+        {
+            anyhow::bail!("Liquidity event parsing functions are not set for dex {dex_id}");
+        }
+
+        let from_block =
+            from_block.map_or(pool.creation_block, |block| max(block, pool.creation_block));
+        let signatures = liquidity_event_signatures(&dex_extended);
+
+        let stream = self
+            .hypersync_client
+            .request_contract_multi_events_stream(
+                from_block,
+                to_block,
+                &pool.address.to_string(),
+                &signatures,
+            )
+            .await;
+
+        apply_liquidity_logs(&mut self.cache, &self.chain, &dex_extended, &pool, stream).await;
+
+        log::info!("Finished syncing pool liquidity events");
         Ok(())
     }
 
@@ -414,6 +491,19 @@ impl BlockchainDataClient {
         }
     }
 
+    /// Returns the registered pool for the given address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address is invalid or the pool is not registered.
+    pub fn get_pool(&self, pool_address: &str) -> anyhow::Result<SharedPool> {
+        let pool_address = validate_address(pool_address)?;
+        match self.cache.get_pool(&pool_address) {
+            Some(pool) => Ok(pool.clone()),
+            None => anyhow::bail!("Pool {pool_address} is not registered"),
+        }
+    }
+
     fn get_dex(&self, dex_id: &str) -> anyhow::Result<&DexExtended> {
         if let Some(dex) = self.cache.get_dex(dex_id) {
             Ok(dex)
@@ -423,6 +513,194 @@ impl BlockchainDataClient {
     }
 }
 
+/// A parsed liquidity pool event which updates an LP position.
+enum LiquidityEvent {
+    Mint(MintEvent),
+    Burn(BurnEvent),
+    Collect(CollectEvent),
+}
+
+/// Returns the distinct event signatures required to track the LP positions of `dex` pools.
+fn liquidity_event_signatures(dex: &DexExtended) -> Vec<&str> {
+    let mut signatures: Vec<&str> = Vec::new();
+    for signature in [
+        dex.mint_created_event.as_ref(),
+        dex.burn_created_event.as_ref(),
+    ] {
+        // Some protocols (e.g. Uniswap V4) emit a single event for both directions
+        if !signature.is_empty() && !signatures.contains(&signature) {
+            signatures.push(signature);
+        }
+    }
+
+    if !dex.collect_created_event.is_empty() && dex.parse_collect_event_fn.is_some() {
+        signatures.push(dex.collect_created_event.as_ref());
+    }
+
+    // Constant product pools are their own LP token, whose transfers identify the holder
+    if matches!(dex.amm_type, AmmType::CPAMM) {
+        signatures.push(TRANSFER_EVENT);
+    }
+
+    signatures
+}
+
+/// Returns the hex encoded topic0 hash of `event_signature`, or an empty string if not set.
+fn event_signature_hash(event_signature: &str) -> String {
+    if event_signature.is_empty() {
+        return String::new();
+    }
+    hex::encode(keccak256(event_signature.as_bytes()))
+}
+
+/// Applies the liquidity event `logs` of `pool`, which must be in on-chain order, to the LP
+/// positions in the `cache`, logging and skipping events which cannot be parsed or applied.
+async fn apply_liquidity_logs(
+    cache: &mut BlockchainCache,
+    chain: &SharedChain,
+    dex_extended: &DexExtended,
+    pool: &SharedPool,
+    logs: impl Stream<Item = Log>,
+) {
+    let mint_signature_hash = event_signature_hash(&dex_extended.mint_created_event);
+    let burn_signature_hash = event_signature_hash(&dex_extended.burn_created_event);
+    let collect_signature_hash = event_signature_hash(&dex_extended.collect_created_event);
+    let shared_signature = mint_signature_hash == burn_signature_hash;
+
+    // The logs the pool emitted earlier in the current transaction
+    let mut transaction_logs: Vec<Log> = Vec::new();
+
+    tokio::pin!(logs);
+    while let Some(log) = logs.next().await {
+        if transaction_logs
+            .last()
+            .is_some_and(|last| last.transaction_hash != log.transaction_hash)
+        {
+            transaction_logs.clear();
+        }
+
+        let topic0 = log
+            .topics
+            .first()
+            .and_then(|topic| topic.as_ref())
+            .map(hex::encode)
+            .unwrap_or_default();
+
+        let parsed = if topic0 == mint_signature_hash {
+            match dex_extended.parse_mint_event(log.clone(), &transaction_logs) {
+                Ok(event) => Ok(LiquidityEvent::Mint(event)),
+                Err(e) if !shared_signature => Err(e),
+                Err(_) => dex_extended
+                    .parse_burn_event(log.clone(), &transaction_logs)
+                    .map(LiquidityEvent::Burn),
+            }
+        } else if topic0 == burn_signature_hash {
+            dex_extended
+                .parse_burn_event(log.clone(), &transaction_logs)
+                .map(LiquidityEvent::Burn)
+        } else if topic0 == collect_signature_hash {
+            dex_extended
+                .parse_collect_event(log.clone())
+                .map(LiquidityEvent::Collect)
+        } else {
+            // LP token transfers only provide context for the mint and burn events
+            transaction_logs.push(log);
+            continue;
+        };
+        transaction_logs.push(log);
+
+        let event = match parsed {
+            Ok(event) => event,
+            Err(e) => {
+                log::error!("Error processing liquidity event: {e}");
+                continue;
+            }
+        };
+
+        let block = match &event {
+            LiquidityEvent::Mint(event) => event.block_number,
+            LiquidityEvent::Burn(event) => event.block_number,
+            LiquidityEvent::Collect(event) => event.block_number,
+        };
+        let Some(timestamp) = cache.get_block_timestamp(block).copied() else {
+            log::error!(
+                "Missing block timestamp for block {block} while processing liquidity event in the cache"
+            );
+            continue;
+        };
+
+        let result = match event {
+            LiquidityEvent::Mint(event) => {
+                let update = PoolLiquidityUpdate::new(
+                    chain.clone(),
+                    dex_extended.dex.clone(),
+                    pool.clone(),
+                    PoolLiquidityUpdateType::Mint,
+                    block,
+                    event.owner,
+                    event.liquidity,
+                    amount_to_quantity(event.amount0, pool.token0.decimals),
+                    amount_to_quantity(event.amount1, pool.token1.decimals),
+                    event.tick_lower,
+                    event.tick_upper,
+                    timestamp,
+                );
+                cache.add_pool_liquidity_update(update).await
+            }
+            LiquidityEvent::Burn(event) => {
+                let update = PoolLiquidityUpdate::new(
+                    chain.clone(),
+                    dex_extended.dex.clone(),
+                    pool.clone(),
+                    PoolLiquidityUpdateType::Burn,
+                    block,
+                    event.owner,
+                    event.liquidity,
+                    amount_to_quantity(event.amount0, pool.token0.decimals),
+                    amount_to_quantity(event.amount1, pool.token1.decimals),
+                    event.tick_lower,
+                    event.tick_upper,
+                    timestamp,
+                );
+                cache.add_pool_liquidity_update(update).await
+            }
+            LiquidityEvent::Collect(event) => {
+                let collect = PoolFeeCollect::new(
+                    chain.clone(),
+                    dex_extended.dex.clone(),
+                    pool.clone(),
+                    block,
+                    event.owner,
+                    amount_to_quantity(U256::from(event.amount0), pool.token0.decimals),
+                    amount_to_quantity(U256::from(event.amount1), pool.token1.decimals),
+                    event.tick_lower,
+                    event.tick_upper,
+                    timestamp,
+                );
+                cache.add_pool_fee_collect(collect).await
+            }
+        };
+
+        if let Err(e) = result {
+            log::error!("Error caching liquidity event: {e}");
+        }
+    }
+}
+
+/// Converts a raw token amount into a [`Quantity`] scaled by the token decimals.
+fn amount_to_quantity(amount: U256, decimals: u8) -> Quantity {
+    let amount_f64: f64 = amount
+        .to_string()
+        .parse()
+        .expect("Failed to parse U256 to f64");
+    let value = amount_f64 / 10f64.powi(i32::from(decimals));
+    Quantity::from(format!(
+        "{:.precision$}",
+        value,
+        precision = FIXED_PRECISION as usize
+    ))
+}
+
 #[async_trait::async_trait]
 impl DataClient for BlockchainDataClient {
     fn client_id(&self) -> ClientId {
@@ -651,3 +929,214 @@ impl DataClient for BlockchainDataClient {
         Ok(())
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::Address;
+    use nautilus_core::UnixNanos;
+    use nautilus_model::defi::{
+        block::Block,
+        chain::chains,
+        math::{MAX_TICK, MIN_TICK},
+    };
+    use rstest::rstest;
+    use serde_json::json;
+    use ustr::Ustr;
+
+    use super::*;
+    use crate::exchanges::{
+        ethereum::UNISWAP_V2,
+        parsing::uniswap_v2::{BURN_EVENT, MINT_EVENT},
+    };
+
+    const E18: u128 = 1_000_000_000_000_000_000;
+
+    fn pool_address() -> Address {
+        "0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc"
+            .parse()
+            .unwrap()
+    }
+
+    fn router() -> Address {
+        "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D"
+            .parse()
+            .unwrap()
+    }
+
+    fn holder() -> Address {
+        Address::repeat_byte(0x22)
+    }
+
+    fn pool(chain: &SharedChain) -> SharedPool {
+        let token0 = Token::new(
+            chain.clone(),
+            Address::repeat_byte(0x01),
+            "USD Coin".to_string(),
+            "USDC".to_string(),
+            6,
+        );
+        let token1 = Token::new(
+            chain.clone(),
+            Address::repeat_byte(0x02),
+            "Wrapped Ether".to_string(),
+            "WETH".to_string(),
+            18,
+        );
+        Arc::new(Pool::new(
+            chain.clone(),
+            (*UNISWAP_V2.dex).clone(),
+            pool_address(),
+            1,
+            token0,
+            token1,
+            3000,
+            0,
+        ))
+    }
+
+    async fn cache(chain: &SharedChain) -> BlockchainCache {
+        let mut cache = BlockchainCache::new(chain.clone());
+        for number in 1..=3 {
+            let block = Block::new(
+                format!("0x{number:064x}"),
+                format!("0x{:064x}", number - 1),
+                number,
+                Ustr::from("0x0000000000000000000000000000000000000000"),
+                30_000_000,
+                100_000,
+                UnixNanos::from(number * 12_000_000_000),
+            );
+            cache.add_block(block).await.unwrap();
+        }
+        cache
+    }
+
+    fn topic(address: Address) -> String {
+        format!("0x{:0>64}", hex::encode(address.as_slice()))
+    }
+
+    fn log(block: u64, transaction: u8, topics: Vec<String>, data: String) -> Log {
+        let log_json = json!({
+            "removed": null,
+            "log_index": null,
+            "transaction_index": null,
+            "transaction_hash": format!("0x{}", hex::encode([transaction; 32])),
+            "block_hash": null,
+            "block_number": format!("0x{block:x}"),
+            "address": format!("0x{}", hex::encode(pool_address().as_slice())),
+            "data": data,
+            "topics": topics,
+        });
+        serde_json::from_value(log_json).expect("Failed to deserialize log")
+    }
+
+    fn transfer_log(block: u64, transaction: u8, from: Address, to: Address, value: u128) -> Log {
+        let topics = vec![
+            format!("0x{}", event_signature_hash(TRANSFER_EVENT)),
+            topic(from),
+            topic(to),
+        ];
+        log(block, transaction, topics, format!("0x{value:064x}"))
+    }
+
+    fn mint_log(block: u64, transaction: u8, amount0: u128, amount1: u128) -> Log {
+        let topics = vec![
+            format!("0x{}", event_signature_hash(MINT_EVENT)),
+            topic(router()),
+        ];
+        log(
+            block,
+            transaction,
+            topics,
+            format!("0x{amount0:064x}{amount1:064x}"),
+        )
+    }
+
+    fn burn_log(block: u64, transaction: u8, amount0: u128, amount1: u128) -> Log {
+        let topics = vec![
+            format!("0x{}", event_signature_hash(BURN_EVENT)),
+            topic(router()),
+            topic(holder()),
+        ];
+        log(
+            block,
+            transaction,
+            topics,
+            format!("0x{amount0:064x}{amount1:064x}"),
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_apply_liquidity_logs_mint_then_burn_nets_position() {
+        let chain: SharedChain = Arc::new(chains::ETHEREUM.clone());
+        let pool = pool(&chain);
+        let mut cache = cache(&chain).await;
+        let liquidity = 6_000_000_000_000;
+
+        // The pool reserves moved between the mint and the burn, so the withdrawn amounts
+        // differ from the deposit while the burned LP tokens equal those minted
+        let logs = vec![
+            transfer_log(1, 0xaa, Address::ZERO, Address::ZERO, 1_000),
+            transfer_log(1, 0xaa, Address::ZERO, holder(), liquidity),
+            mint_log(1, 0xaa, 4_000_000, 9 * E18),
+            transfer_log(3, 0xbb, holder(), pool_address(), liquidity),
+            transfer_log(3, 0xbb, pool_address(), Address::ZERO, liquidity),
+            burn_log(3, 0xbb, 5_000_000, 8 * E18),
+        ];
+
+        apply_liquidity_logs(
+            &mut cache,
+            &chain,
+            &UNISWAP_V2,
+            &pool,
+            futures_util::stream::iter(logs),
+        )
+        .await;
+
+        let position = cache
+            .get_position(&pool_address(), &holder(), MIN_TICK, MAX_TICK)
+            .unwrap();
+        assert_eq!(position.liquidity, 0);
+        assert_eq!(position.deposited0, 4.0);
+        assert_eq!(position.deposited1, 9.0);
+        assert_eq!(position.withdrawn0, 5.0);
+        assert_eq!(position.withdrawn1, 8.0);
+        assert_eq!(cache.positions_for_owner(&router()).len(), 0);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_apply_liquidity_logs_skips_burn_without_position() {
+        let chain: SharedChain = Arc::new(chains::ETHEREUM.clone());
+        let pool = pool(&chain);
+        let mut cache = cache(&chain).await;
+        let liquidity = 6_000_000_000_000;
+
+        let logs = vec![
+            transfer_log(1, 0xaa, holder(), pool_address(), liquidity),
+            transfer_log(1, 0xaa, pool_address(), Address::ZERO, liquidity),
+            burn_log(1, 0xaa, 4_000_000, 9 * E18),
+            transfer_log(2, 0xbb, Address::ZERO, holder(), liquidity),
+            mint_log(2, 0xbb, 4_000_000, 9 * E18),
+        ];
+
+        apply_liquidity_logs(
+            &mut cache,
+            &chain,
+            &UNISWAP_V2,
+            &pool,
+            futures_util::stream::iter(logs),
+        )
+        .await;
+
+        let position = cache
+            .get_position(&pool_address(), &holder(), MIN_TICK, MAX_TICK)
+            .unwrap();
+        assert_eq!(position.liquidity, liquidity);
+        assert_eq!(position.withdrawn0, 0.0);
+    }
+}
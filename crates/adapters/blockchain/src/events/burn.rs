@@ -0,0 +1,62 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use alloy::primitives::{Address, U256};
+
+/// Represents a liquidity burn event emitted by a liquidity pool smart contract.
+///
+/// For concentrated liquidity pools the burned amounts are credited to the position and only
+/// transferred out on a subsequent collect, whereas constant product pools transfer them directly.
+#[derive(Debug, Clone)]
+pub struct BurnEvent {
+    /// The block number in which this burn transaction was included.
+    pub block_number: u64,
+    /// The address that owns the burned liquidity position.
+    pub owner: Address,
+    /// The lower tick of the position range.
+    pub tick_lower: i32,
+    /// The upper tick of the position range.
+    pub tick_upper: i32,
+    /// The amount of liquidity burned from the position.
+    pub liquidity: u128,
+    /// The amount of token0 withdrawn from the pool.
+    pub amount0: U256,
+    /// The amount of token1 withdrawn from the pool.
+    pub amount1: U256,
+}
+
+impl BurnEvent {
+    /// Creates a new [`BurnEvent`] instance with the specified parameters.
+    #[must_use]
+    pub const fn new(
+        block_number: u64,
+        owner: Address,
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity: u128,
+        amount0: U256,
+        amount1: U256,
+    ) -> Self {
+        Self {
+            block_number,
+            owner,
+            tick_lower,
+            tick_upper,
+            liquidity,
+            amount0,
+            amount1,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use alloy::primitives::Address;
+
+/// Represents a fee collect event emitted by a concentrated liquidity pool smart contract.
+///
+/// The collected amounts include accrued fees as well as any principal credited by prior burns.
+#[derive(Debug, Clone)]
+pub struct CollectEvent {
+    /// The block number in which this collect transaction was included.
+    pub block_number: u64,
+    /// The address that owns the liquidity position.
+    pub owner: Address,
+    /// The address that received the collected tokens.
+    pub recipient: Address,
+    /// The lower tick of the position range.
+    pub tick_lower: i32,
+    /// The upper tick of the position range.
+    pub tick_upper: i32,
+    /// The amount of token0 collected.
+    pub amount0: u128,
+    /// The amount of token1 collected.
+    pub amount1: u128,
+}
+
+impl CollectEvent {
+    /// Creates a new [`CollectEvent`] instance with the specified parameters.
+    #[must_use]
+    pub const fn new(
+        block_number: u64,
+        owner: Address,
+        recipient: Address,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount0: u128,
+        amount1: u128,
+    ) -> Self {
+        Self {
+            block_number,
+            owner,
+            recipient,
+            tick_lower,
+            tick_upper,
+            amount0,
+            amount1,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use alloy::primitives::{Address, U256};
+
+/// Represents a liquidity mint event emitted by a liquidity pool smart contract.
+///
+/// For constant product pools (Uniswap V2 style) the position spans the full tick range, and
+/// the liquidity is the amount of LP tokens minted to the owner.
+#[derive(Debug, Clone)]
+pub struct MintEvent {
+    /// The block number in which this mint transaction was included.
+    pub block_number: u64,
+    /// The address that initiated the mint transaction.
+    pub sender: Address,
+    /// The address that owns the minted liquidity position.
+    pub owner: Address,
+    /// The lower tick of the position range.
+    pub tick_lower: i32,
+    /// The upper tick of the position range.
+    pub tick_upper: i32,
+    /// The amount of liquidity minted to the position.
+    pub liquidity: u128,
+    /// The amount of token0 deposited into the pool.
+    pub amount0: U256,
+    /// The amount of token1 deposited into the pool.
+    pub amount1: U256,
+}
+
+impl MintEvent {
+    /// Creates a new [`MintEvent`] instance with the specified parameters.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        block_number: u64,
+        sender: Address,
+        owner: Address,
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity: u128,
+        amount0: U256,
+        amount1: U256,
+    ) -> Self {
+        Self {
+            block_number,
+            sender,
+            owner,
+            tick_lower,
+            tick_upper,
+            liquidity,
+            amount0,
+            amount1,
+        }
+    }
+}
@@ -13,5 +13,8 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod burn;
+pub mod collect;
+pub mod mint;
 pub mod pool_created;
 pub mod swap;
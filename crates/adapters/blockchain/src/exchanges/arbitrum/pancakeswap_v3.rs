@@ -20,11 +20,17 @@ use nautilus_model::defi::{
     dex::{AmmType, Dex},
 };
 
-use crate::exchanges::extended::DexExtended;
+use crate::exchanges::{
+    extended::DexExtended,
+    parsing::uniswap_v3::{
+        BURN_EVENT, COLLECT_EVENT, MINT_EVENT, parse_burn_event, parse_collect_event,
+        parse_mint_event,
+    },
+};
 
 /// PancakeSwap V3 DEX on Arbitrum.
 pub static PANCAKESWAP_V3: LazyLock<DexExtended> = LazyLock::new(|| {
-    let dex = Dex::new(
+    let mut dex = Dex::new(
         chains::ARBITRUM.clone(),
         "PancakeSwap V3",
         "0x0BFbCF9fa4f9C56B0F40a671Ad40E0805A091865",
@@ -32,5 +38,10 @@ pub static PANCAKESWAP_V3: LazyLock<DexExtended> = LazyLock::new(|| {
         "",
         "",
     );
-    DexExtended::new(dex)
+    dex.set_liquidity_events(MINT_EVENT, BURN_EVENT, COLLECT_EVENT);
+    let mut dex = DexExtended::new(dex);
+    dex.set_mint_event_parsing(parse_mint_event);
+    dex.set_burn_event_parsing(parse_burn_event);
+    dex.set_collect_event_parsing(parse_collect_event);
+    dex
 });
@@ -20,11 +20,14 @@ use nautilus_model::defi::{
     dex::{AmmType, Dex},
 };
 
-use crate::exchanges::extended::DexExtended;
+use crate::exchanges::{
+    extended::DexExtended,
+    parsing::uniswap_v2::{BURN_EVENT, MINT_EVENT, parse_burn_event, parse_mint_event},
+};
 
 /// SushiSwap V2 DEX on Arbitrum.
 pub static SUSHISWAP_V2: LazyLock<DexExtended> = LazyLock::new(|| {
-    let dex = Dex::new(
+    let mut dex = Dex::new(
         chains::ARBITRUM.clone(),
         "SushiSwap V2",
         "0xc35DADB65012eC5796536bD9864eD8773aBc74C4",
@@ -32,5 +35,9 @@ pub static SUSHISWAP_V2: LazyLock<DexExtended> = LazyLock::new(|| {
         "",
         "",
     );
-    DexExtended::new(dex)
+    dex.set_liquidity_events(MINT_EVENT, BURN_EVENT, "");
+    let mut dex = DexExtended::new(dex);
+    dex.set_mint_event_parsing(parse_mint_event);
+    dex.set_burn_event_parsing(parse_burn_event);
+    dex
 });
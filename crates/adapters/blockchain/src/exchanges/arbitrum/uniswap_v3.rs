@@ -20,11 +20,17 @@ use nautilus_model::defi::{
     dex::{AmmType, Dex},
 };
 
-use crate::exchanges::extended::DexExtended;
+use crate::exchanges::{
+    extended::DexExtended,
+    parsing::uniswap_v3::{
+        BURN_EVENT, COLLECT_EVENT, MINT_EVENT, parse_burn_event, parse_collect_event,
+        parse_mint_event,
+    },
+};
 
 /// Uniswap V3 DEX on Arbitrum.
 pub static UNISWAP_V3: LazyLock<DexExtended> = LazyLock::new(|| {
-    let dex = Dex::new(
+    let mut dex = Dex::new(
         chains::ARBITRUM.clone(),
         "Uniswap V3",
         "0x1F98431c8aD98523631AE4a59f267346ea31F984",
@@ -32,5 +38,10 @@ pub static UNISWAP_V3: LazyLock<DexExtended> = LazyLock::new(|| {
         "",
         "",
     );
-    DexExtended::new(dex)
+    dex.set_liquidity_events(MINT_EVENT, BURN_EVENT, COLLECT_EVENT);
+    let mut dex = DexExtended::new(dex);
+    dex.set_mint_event_parsing(parse_mint_event);
+    dex.set_burn_event_parsing(parse_burn_event);
+    dex.set_collect_event_parsing(parse_collect_event);
+    dex
 });
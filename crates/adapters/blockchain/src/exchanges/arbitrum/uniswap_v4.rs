@@ -20,11 +20,14 @@ use nautilus_model::defi::{
     dex::{AmmType, Dex},
 };
 
-use crate::exchanges::extended::DexExtended;
+use crate::exchanges::{
+    extended::DexExtended,
+    parsing::uniswap_v4::{MODIFY_LIQUIDITY_EVENT, parse_burn_event, parse_mint_event},
+};
 
 /// Uniswap V4 DEX on Arbitrum.
 pub static UNISWAP_V4: LazyLock<DexExtended> = LazyLock::new(|| {
-    let dex = Dex::new(
+    let mut dex = Dex::new(
         chains::ARBITRUM.clone(),
         "Uniswap V4",
         "0x4E3288c9ca110bCC82bf38F09A7b425c095d92Bf",
@@ -32,5 +35,9 @@ pub static UNISWAP_V4: LazyLock<DexExtended> = LazyLock::new(|| {
         "",
         "",
     );
-    DexExtended::new(dex)
+    dex.set_liquidity_events(MODIFY_LIQUIDITY_EVENT, MODIFY_LIQUIDITY_EVENT, "");
+    let mut dex = DexExtended::new(dex);
+    dex.set_mint_event_parsing(parse_mint_event);
+    dex.set_burn_event_parsing(parse_burn_event);
+    dex
 });
@@ -20,11 +20,14 @@ use nautilus_model::defi::{
     dex::{AmmType, Dex},
 };
 
-use crate::exchanges::extended::DexExtended;
+use crate::exchanges::{
+    extended::DexExtended,
+    parsing::uniswap_v2::{BURN_EVENT, MINT_EVENT, parse_burn_event, parse_mint_event},
+};
 
 /// BaseSwap V2 DEX on Base.
 pub static BASESWAP_V2: LazyLock<DexExtended> = LazyLock::new(|| {
-    let dex = Dex::new(
+    let mut dex = Dex::new(
         chains::BASE.clone(),
         "BaseSwap V2",
         "0xFDa619b6d20975be80A10332cD39b9a4b0FAa8BB",
@@ -32,5 +35,9 @@ pub static BASESWAP_V2: LazyLock<DexExtended> = LazyLock::new(|| {
         "",
         "",
     );
-    DexExtended::new(dex)
+    dex.set_liquidity_events(MINT_EVENT, BURN_EVENT, "");
+    let mut dex = DexExtended::new(dex);
+    dex.set_mint_event_parsing(parse_mint_event);
+    dex.set_burn_event_parsing(parse_burn_event);
+    dex
 });
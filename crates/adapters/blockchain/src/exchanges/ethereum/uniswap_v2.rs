@@ -20,11 +20,14 @@ use nautilus_model::defi::{
     dex::{AmmType, Dex},
 };
 
-use crate::exchanges::extended::DexExtended;
+use crate::exchanges::{
+    extended::DexExtended,
+    parsing::uniswap_v2::{BURN_EVENT, MINT_EVENT, parse_burn_event, parse_mint_event},
+};
 
 /// Uniswap V2 DEX on Ethereum.
 pub static UNISWAP_V2: LazyLock<DexExtended> = LazyLock::new(|| {
-    let dex = Dex::new(
+    let mut dex = Dex::new(
         chains::ETHEREUM.clone(),
         "Uniswap V2",
         "0x5C69bEe701ef814a2B6a3EDD4B1652CB9cc5aA6f",
@@ -32,5 +35,9 @@ pub static UNISWAP_V2: LazyLock<DexExtended> = LazyLock::new(|| {
         "PoolCreated(address,address,address,uint256)",
         "",
     );
-    DexExtended::new(dex)
+    dex.set_liquidity_events(MINT_EVENT, BURN_EVENT, "");
+    let mut dex = DexExtended::new(dex);
+    dex.set_mint_event_parsing(parse_mint_event);
+    dex.set_burn_event_parsing(parse_burn_event);
+    dex
 });
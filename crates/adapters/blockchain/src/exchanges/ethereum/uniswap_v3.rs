@@ -33,7 +33,13 @@ use nautilus_model::{
 
 use crate::{
     events::{pool_created::PoolCreated, swap::SwapEvent},
-    exchanges::extended::DexExtended,
+    exchanges::{
+        extended::DexExtended,
+        parsing::uniswap_v3::{
+            BURN_EVENT, COLLECT_EVENT, MINT_EVENT, parse_burn_event, parse_collect_event,
+            parse_mint_event,
+        },
+    },
     hypersync::helpers::validate_event_signature_hash,
 };
 
@@ -44,17 +50,22 @@ const SWAP_EVENT_SIGNATURE_HASH: &str =
 
 /// Uniswap V3 DEX on Ethereum.
 pub static UNISWAP_V3: LazyLock<DexExtended> = LazyLock::new(|| {
-    let mut dex = DexExtended::new(Dex::new(
+    let mut dex = Dex::new(
         chains::ETHEREUM.clone(),
         "Uniswap V3",
         "0x1F98431c8aD98523631AE4a59f267346ea31F984",
         AmmType::CLAMM,
         "PoolCreated(address,address,uint24,int24,address)",
         "Swap(address,address,int256,int256,uint160,uint128,int24)",
-    ));
+    );
+    dex.set_liquidity_events(MINT_EVENT, BURN_EVENT, COLLECT_EVENT);
+    let mut dex = DexExtended::new(dex);
     dex.set_pool_created_event_parsing(parse_pool_created_event);
     dex.set_swap_event_parsing(parse_swap_event);
     dex.set_convert_trade_data(convert_to_trade_data);
+    dex.set_mint_event_parsing(parse_mint_event);
+    dex.set_burn_event_parsing(parse_burn_event);
+    dex.set_collect_event_parsing(parse_collect_event);
     dex
 });
 
@@ -25,7 +25,10 @@ use nautilus_model::{
     types::{Price, Quantity},
 };
 
-use crate::events::{pool_created::PoolCreated, swap::SwapEvent};
+use crate::events::{
+    burn::BurnEvent, collect::CollectEvent, mint::MintEvent, pool_created::PoolCreated,
+    swap::SwapEvent,
+};
 
 /// Extended DEX wrapper that adds provider-specific event parsing capabilities to the domain `Dex` model.
 #[derive(Debug, Clone)]
@@ -39,6 +42,12 @@ pub struct DexExtended {
     /// Function to convert to trade data
     pub convert_to_trade_data_fn:
         Option<fn(&Token, &Token, &SwapEvent) -> anyhow::Result<(OrderSide, Quantity, Price)>>,
+    /// Function to parse liquidity mint events, given the earlier logs of the transaction
+    pub parse_mint_event_fn: Option<fn(Log, &[Log]) -> anyhow::Result<MintEvent>>,
+    /// Function to parse liquidity burn events, given the earlier logs of the transaction
+    pub parse_burn_event_fn: Option<fn(Log, &[Log]) -> anyhow::Result<BurnEvent>>,
+    /// Function to parse fee collect events
+    pub parse_collect_event_fn: Option<fn(Log) -> anyhow::Result<CollectEvent>>,
 }
 
 impl DexExtended {
@@ -50,6 +59,9 @@ impl DexExtended {
             parse_pool_created_event_fn: None,
             parse_swap_event_fn: None,
             convert_to_trade_data_fn: None,
+            parse_mint_event_fn: None,
+            parse_burn_event_fn: None,
+            parse_collect_event_fn: None,
         }
     }
 
@@ -81,6 +93,30 @@ impl DexExtended {
         self.convert_to_trade_data_fn = Some(convert_trade_data);
     }
 
+    /// Sets the function used to parse liquidity mint events for this Dex.
+    pub fn set_mint_event_parsing(
+        &mut self,
+        parse_mint_event: fn(Log, &[Log]) -> anyhow::Result<MintEvent>,
+    ) {
+        self.parse_mint_event_fn = Some(parse_mint_event);
+    }
+
+    /// Sets the function used to parse liquidity burn events for this Dex.
+    pub fn set_burn_event_parsing(
+        &mut self,
+        parse_burn_event: fn(Log, &[Log]) -> anyhow::Result<BurnEvent>,
+    ) {
+        self.parse_burn_event_fn = Some(parse_burn_event);
+    }
+
+    /// Sets the function used to parse fee collect events for this Dex.
+    pub fn set_collect_event_parsing(
+        &mut self,
+        parse_collect_event: fn(Log) -> anyhow::Result<CollectEvent>,
+    ) {
+        self.parse_collect_event_fn = Some(parse_collect_event);
+    }
+
     /// Parses a pool creation event log using this DEX's specific parsing function.
     pub fn parse_pool_created_event(&self, log: Log) -> anyhow::Result<PoolCreated> {
         if let Some(parse_pool_created_event_fn) = &self.parse_pool_created_event_fn {
@@ -105,6 +141,64 @@ impl DexExtended {
         }
     }
 
+    /// Parses a liquidity mint event log using this DEX's specific parsing function, where
+    /// `transaction_logs` are the logs the pool emitted earlier in the same transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no parsing function is set or the log cannot be parsed.
+    pub fn parse_mint_event(
+        &self,
+        log: Log,
+        transaction_logs: &[Log],
+    ) -> anyhow::Result<MintEvent> {
+        if let Some(parse_mint_event_fn) = &self.parse_mint_event_fn {
+            parse_mint_event_fn(log, transaction_logs)
+        } else {
+            Err(anyhow::anyhow!(
+                "Parsing of mint event in not defined in this dex: {}",
+                self.dex.name
+            ))
+        }
+    }
+
+    /// Parses a liquidity burn event log using this DEX's specific parsing function, where
+    /// `transaction_logs` are the logs the pool emitted earlier in the same transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no parsing function is set or the log cannot be parsed.
+    pub fn parse_burn_event(
+        &self,
+        log: Log,
+        transaction_logs: &[Log],
+    ) -> anyhow::Result<BurnEvent> {
+        if let Some(parse_burn_event_fn) = &self.parse_burn_event_fn {
+            parse_burn_event_fn(log, transaction_logs)
+        } else {
+            Err(anyhow::anyhow!(
+                "Parsing of burn event in not defined in this dex: {}",
+                self.dex.name
+            ))
+        }
+    }
+
+    /// Parses a fee collect event log using this DEX's specific parsing function.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no parsing function is set or the log cannot be parsed.
+    pub fn parse_collect_event(&self, log: Log) -> anyhow::Result<CollectEvent> {
+        if let Some(parse_collect_event_fn) = &self.parse_collect_event_fn {
+            parse_collect_event_fn(log)
+        } else {
+            Err(anyhow::anyhow!(
+                "Parsing of collect event in not defined in this dex: {}",
+                self.dex.name
+            ))
+        }
+    }
+
     /// Convert to trade data from a log using this DEX's specific parsing function.
     pub fn parse_convert_trade_data(
         &self,
@@ -21,6 +21,7 @@ pub mod arbitrum;
 pub mod base;
 pub mod ethereum;
 pub mod extended;
+pub mod parsing;
 
 /// Returns a vector of all Dexes instances across all chains
 #[must_use]
@@ -0,0 +1,40 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Liquidity event parsing for Solidly style constant product pools (Aerodrome V1).
+//!
+//! The mint event is identical to Uniswap V2, however the burn event orders the recipient
+//! before the amounts, which changes the event signature.
+
+use hypersync_client::simple_types::Log;
+
+use crate::{events::burn::BurnEvent, exchanges::parsing::uniswap_v2::parse_full_range_burn_event};
+
+pub use crate::exchanges::parsing::uniswap_v2::{MINT_EVENT, parse_mint_event};
+
+/// The Aerodrome V1 liquidity burn event signature.
+pub const BURN_EVENT: &str = "Burn(address,address,uint256,uint256)";
+
+const BURN_EVENT_SIGNATURE_HASH: &str =
+    "5d624aa9c148153ab3446c1b154f660ee7701e549fe9b62dab7171b1c80e6fa2";
+
+/// Parses an Aerodrome V1 liquidity burn event log.
+///
+/// # Errors
+///
+/// Returns an error if the log is not a valid burn event.
+pub fn parse_burn_event(log: Log, transaction_logs: &[Log]) -> anyhow::Result<BurnEvent> {
+    parse_full_range_burn_event(log, transaction_logs, BURN_EVENT_SIGNATURE_HASH)
+}
@@ -0,0 +1,72 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Event log parsing shared between DEX protocol forks.
+//!
+//! Most DEXs are forks of a small number of reference protocols and emit identical events, so
+//! parsing functions are grouped by the protocol which defined the event layout.
+//!
+//! Liquidity mint and burn parsers also receive the logs the pool emitted earlier in the same
+//! transaction, which constant product pools need to identify the LP token holder.
+
+use alloy::primitives::Address;
+use hypersync_client::simple_types::Log;
+
+pub mod aerodrome;
+pub mod uniswap_v2;
+pub mod uniswap_v3;
+pub mod uniswap_v4;
+
+/// Returns the block number of the log.
+pub(crate) fn parse_block_number(log: &Log) -> anyhow::Result<u64> {
+    match log.block_number {
+        Some(block_number) => Ok(block_number.into()),
+        None => anyhow::bail!("Missing block number in log"),
+    }
+}
+
+/// Returns the address stored in the last 20 bytes of the 32-byte topic at `index`.
+pub(crate) fn parse_topic_address(log: &Log, index: usize, name: &str) -> anyhow::Result<Address> {
+    match log.topics.get(index).and_then(|t| t.as_ref()) {
+        Some(topic) => Ok(Address::from_slice(&topic.as_ref()[12..32])),
+        None => anyhow::bail!("Missing {name} address in topic{index}"),
+    }
+}
+
+/// Returns the sign-extended `int24` stored in the 32-byte topic at `index`.
+pub(crate) fn parse_topic_int24(log: &Log, index: usize, name: &str) -> anyhow::Result<i32> {
+    match log.topics.get(index).and_then(|t| t.as_ref()) {
+        Some(topic) => Ok(i32::from_be_bytes(topic.as_ref()[28..32].try_into()?)),
+        None => anyhow::bail!("Missing {name} in topic{index}"),
+    }
+}
+
+/// Returns the log data, validating it contains at least `words` 32-byte words.
+pub(crate) fn parse_data<'a>(
+    log: &'a Log,
+    event_name: &str,
+    words: usize,
+) -> anyhow::Result<&'a [u8]> {
+    match &log.data {
+        Some(data) => {
+            let data_bytes: &[u8] = data.as_ref();
+            if data_bytes.len() < words * 32 {
+                anyhow::bail!("{event_name} event data is too short");
+            }
+            Ok(data_bytes)
+        }
+        None => anyhow::bail!("Missing data in {event_name} event log"),
+    }
+}
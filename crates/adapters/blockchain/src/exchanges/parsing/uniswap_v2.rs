@@ -0,0 +1,321 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Liquidity event parsing for Uniswap V2 and its forks (SushiSwap V2, BaseSwap V2).
+//!
+//! Constant product pools have no concept of a tick range, so positions are represented as
+//! full range. The pool contract is also the LP token, so a position is owned by the holder of
+//! the LP tokens, and its liquidity is the amount of LP tokens minted or burned, both taken from
+//! the LP token transfers emitted earlier in the same transaction.
+//!
+//! LP token transfers between holders are not tracked, so a burn by a holder which received its
+//! LP tokens through a transfer does not match a tracked position.
+
+use alloy::{
+    primitives::{Address, U256},
+    sol,
+    sol_types::SolType,
+};
+use hypersync_client::simple_types::Log;
+use nautilus_model::defi::math::{MAX_TICK, MIN_TICK};
+
+use crate::{
+    events::{burn::BurnEvent, mint::MintEvent},
+    exchanges::parsing::{parse_block_number, parse_data, parse_topic_address},
+    hypersync::helpers::validate_event_signature_hash,
+};
+
+/// The Uniswap V2 liquidity mint event signature.
+pub const MINT_EVENT: &str = "Mint(address,uint256,uint256)";
+/// The Uniswap V2 liquidity burn event signature.
+pub const BURN_EVENT: &str = "Burn(address,uint256,uint256,address)";
+/// The ERC20 transfer event signature, emitted by constant product pools for LP tokens.
+pub const TRANSFER_EVENT: &str = "Transfer(address,address,uint256)";
+
+pub(crate) const MINT_EVENT_SIGNATURE_HASH: &str =
+    "4c209b5fc8ad50758f13e2e1088ba56a560dff690a1c6fef26394f4c03821c4f";
+const BURN_EVENT_SIGNATURE_HASH: &str =
+    "dccd412f0b1252819cb1fd330b93224ca42612892bb3f4f789976e6d81936496";
+const TRANSFER_EVENT_SIGNATURE_HASH: &str =
+    "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+// Both events only carry the token amounts in the data, the addresses are indexed
+sol! {
+    struct AmountsEventData {
+        uint256 amount0;
+        uint256 amount1;
+    }
+}
+
+pub(crate) fn decode_amounts(log: &Log, event_name: &str) -> anyhow::Result<(U256, U256)> {
+    let data_bytes = parse_data(log, event_name, 2)?;
+    match <AmountsEventData as SolType>::abi_decode(data_bytes) {
+        Ok(decoded) => Ok((decoded.amount0, decoded.amount1)),
+        Err(e) => anyhow::bail!("Failed to decode {event_name} event data: {e}"),
+    }
+}
+
+/// An LP token transfer emitted by a constant product pool.
+#[derive(Debug)]
+struct LpTransfer {
+    from: Address,
+    to: Address,
+    value: U256,
+}
+
+/// Returns the LP token transfers among the `transaction_logs`, in emission order.
+fn parse_lp_transfers(transaction_logs: &[Log]) -> Vec<LpTransfer> {
+    transaction_logs
+        .iter()
+        .filter(|log| {
+            validate_event_signature_hash("Transfer", TRANSFER_EVENT_SIGNATURE_HASH, log).is_ok()
+        })
+        .filter_map(|log| {
+            let from = parse_topic_address(log, 1, "from").ok()?;
+            let to = parse_topic_address(log, 2, "to").ok()?;
+            let data_bytes = parse_data(log, "Transfer", 1).ok()?;
+            Some(LpTransfer {
+                from,
+                to,
+                value: U256::from_be_slice(&data_bytes[..32]),
+            })
+        })
+        .collect()
+}
+
+/// Parses a constant product pool burn event log.
+///
+/// The burned liquidity is the amount the pool burned of its own LP tokens, and the position
+/// owner is the holder which transferred those LP tokens to the pool beforehand.
+pub(crate) fn parse_full_range_burn_event(
+    log: Log,
+    transaction_logs: &[Log],
+    event_signature_hash: &str,
+) -> anyhow::Result<BurnEvent> {
+    validate_event_signature_hash("BurnEvent", event_signature_hash, &log)?;
+
+    let block_number = parse_block_number(&log)?;
+    let (amount0, amount1) = decode_amounts(&log, "Burn")?;
+
+    let transfers = parse_lp_transfers(transaction_logs);
+    let Some(burn_index) = transfers
+        .iter()
+        .rposition(|t| t.to == Address::ZERO && t.from != Address::ZERO)
+    else {
+        anyhow::bail!("Missing LP token burn transfer for Burn event in block {block_number}");
+    };
+    let pool = transfers[burn_index].from;
+    let Some(holder) = transfers[..burn_index]
+        .iter()
+        .rev()
+        .find(|t| t.to == pool && t.from != Address::ZERO)
+    else {
+        anyhow::bail!(
+            "Missing LP token transfer to the pool for Burn event in block {block_number}"
+        );
+    };
+
+    Ok(BurnEvent::new(
+        block_number,
+        holder.from,
+        MIN_TICK,
+        MAX_TICK,
+        u128::try_from(transfers[burn_index].value)?,
+        amount0,
+        amount1,
+    ))
+}
+
+/// Parses a Uniswap V2 style liquidity mint event log.
+///
+/// The position owner is the holder the LP tokens were minted to, and the liquidity is the
+/// amount minted. The minimum liquidity locked on the first mint, and any protocol fee minted
+/// before the deposit, are not attributed to the position.
+///
+/// # Errors
+///
+/// Returns an error if the log is not a valid mint event, or the `transaction_logs` do not
+/// contain the LP token mint transfer.
+pub fn parse_mint_event(log: Log, transaction_logs: &[Log]) -> anyhow::Result<MintEvent> {
+    validate_event_signature_hash("MintEvent", MINT_EVENT_SIGNATURE_HASH, &log)?;
+
+    let block_number = parse_block_number(&log)?;
+    let sender = parse_topic_address(&log, 1, "sender")?;
+    let (amount0, amount1) = decode_amounts(&log, "Mint")?;
+
+    // The deposit is minted last, after any protocol fee and the locked minimum liquidity
+    let Some(minted) = parse_lp_transfers(transaction_logs)
+        .into_iter()
+        .rev()
+        .find(|t| t.from == Address::ZERO && t.to != Address::ZERO)
+    else {
+        anyhow::bail!("Missing LP token mint transfer for Mint event in block {block_number}");
+    };
+
+    Ok(MintEvent::new(
+        block_number,
+        sender,
+        minted.to,
+        MIN_TICK,
+        MAX_TICK,
+        u128::try_from(minted.value)?,
+        amount0,
+        amount1,
+    ))
+}
+
+/// Parses a Uniswap V2 style liquidity burn event log.
+///
+/// # Errors
+///
+/// Returns an error if the log is not a valid burn event, or the `transaction_logs` do not
+/// contain the LP token transfers to the pool and its burn.
+pub fn parse_burn_event(log: Log, transaction_logs: &[Log]) -> anyhow::Result<BurnEvent> {
+    parse_full_range_burn_event(log, transaction_logs, BURN_EVENT_SIGNATURE_HASH)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use serde_json::json;
+
+    use super::*;
+
+    const AMOUNTS_DATA: &str = "0x00000000000000000000000000000000000000000000000000000000003d09000000000000000000000000000000000000000000000000007ce66c50e2840000";
+
+    fn pool() -> Address {
+        "0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc"
+            .parse()
+            .unwrap()
+    }
+
+    fn router() -> Address {
+        "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D"
+            .parse()
+            .unwrap()
+    }
+
+    fn holder() -> Address {
+        Address::repeat_byte(0x22)
+    }
+
+    fn topic(address: Address) -> String {
+        format!("0x{:0>64}", hex::encode(address.as_slice()))
+    }
+
+    fn log(topics: &[String], data: &str) -> Log {
+        let log_json = json!({
+            "removed": null,
+            "log_index": null,
+            "transaction_index": null,
+            "transaction_hash": null,
+            "block_hash": null,
+            "block_number": "0x1581b7e",
+            "address": "0xb4e16d0168e52d35cacd2c6185b44281ec28c9dc",
+            "data": data,
+            "topics": topics,
+        });
+        serde_json::from_value(log_json).expect("Failed to deserialize log")
+    }
+
+    fn transfer_log(from: Address, to: Address, value: u128) -> Log {
+        log(
+            &[
+                format!("0x{TRANSFER_EVENT_SIGNATURE_HASH}"),
+                topic(from),
+                topic(to),
+            ],
+            &format!("0x{value:064x}"),
+        )
+    }
+
+    fn mint_log() -> Log {
+        log(
+            &[format!("0x{MINT_EVENT_SIGNATURE_HASH}"), topic(router())],
+            AMOUNTS_DATA,
+        )
+    }
+
+    fn burn_log() -> Log {
+        log(
+            &[
+                format!("0x{BURN_EVENT_SIGNATURE_HASH}"),
+                topic(router()),
+                topic(Address::repeat_byte(0x11)),
+            ],
+            AMOUNTS_DATA,
+        )
+    }
+
+    #[rstest]
+    fn test_parse_mint_event() {
+        let transaction_logs = [transfer_log(Address::ZERO, holder(), 6_000_000_000_000)];
+
+        let event = parse_mint_event(mint_log(), &transaction_logs).unwrap();
+
+        assert_eq!(event.block_number, 22_551_422);
+        assert_eq!(event.sender, router());
+        assert_eq!(event.owner, holder());
+        assert_eq!(event.tick_lower, MIN_TICK);
+        assert_eq!(event.tick_upper, MAX_TICK);
+        assert_eq!(event.amount0, U256::from(4_000_000u64));
+        assert_eq!(event.amount1, U256::from(9_000_000_000_000_000_000u128));
+        assert_eq!(event.liquidity, 6_000_000_000_000);
+    }
+
+    #[rstest]
+    fn test_parse_mint_event_excludes_locked_minimum_liquidity() {
+        let transaction_logs = [
+            transfer_log(Address::ZERO, Address::ZERO, 1_000),
+            transfer_log(Address::ZERO, holder(), 5_999_999_999_000),
+        ];
+
+        let event = parse_mint_event(mint_log(), &transaction_logs).unwrap();
+
+        assert_eq!(event.owner, holder());
+        assert_eq!(event.liquidity, 5_999_999_999_000);
+    }
+
+    #[rstest]
+    fn test_parse_mint_event_without_transfer() {
+        assert!(parse_mint_event(mint_log(), &[]).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_burn_event() {
+        let transaction_logs = [
+            transfer_log(holder(), pool(), 2_000_000_000_000),
+            transfer_log(pool(), Address::ZERO, 2_000_000_000_000),
+        ];
+
+        let event = parse_burn_event(burn_log(), &transaction_logs).unwrap();
+
+        assert_eq!(event.owner, holder());
+        assert_eq!(event.tick_lower, MIN_TICK);
+        assert_eq!(event.tick_upper, MAX_TICK);
+        assert_eq!(event.liquidity, 2_000_000_000_000);
+        assert_eq!(event.amount0, U256::from(4_000_000u64));
+    }
+
+    #[rstest]
+    fn test_parse_burn_event_without_transfer_to_pool() {
+        let transaction_logs = [transfer_log(pool(), Address::ZERO, 2_000_000_000_000)];
+
+        assert!(parse_burn_event(burn_log(), &transaction_logs).is_err());
+    }
+}
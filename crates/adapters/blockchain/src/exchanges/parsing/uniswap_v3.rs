@@ -0,0 +1,263 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Liquidity event parsing for Uniswap V3 and its forks (PancakeSwap V3, SushiSwap V3,
+//! Aerodrome Slipstream).
+
+use alloy::{sol, sol_types::SolType};
+use hypersync_client::simple_types::Log;
+
+use crate::{
+    events::{burn::BurnEvent, collect::CollectEvent, mint::MintEvent},
+    exchanges::parsing::{parse_block_number, parse_data, parse_topic_address, parse_topic_int24},
+    hypersync::helpers::validate_event_signature_hash,
+};
+
+/// The Uniswap V3 liquidity mint event signature.
+pub const MINT_EVENT: &str = "Mint(address,address,int24,int24,uint128,uint256,uint256)";
+/// The Uniswap V3 liquidity burn event signature.
+pub const BURN_EVENT: &str = "Burn(address,int24,int24,uint128,uint256,uint256)";
+/// The Uniswap V3 fee collect event signature.
+pub const COLLECT_EVENT: &str = "Collect(address,address,int24,int24,uint128,uint128)";
+
+const MINT_EVENT_SIGNATURE_HASH: &str =
+    "7a53080ba414158be7ec69b987b5fb7d07dee101fe85488f0853ae16239d0bde";
+const BURN_EVENT_SIGNATURE_HASH: &str =
+    "0c396cd989a39f4459b5fa1aed6a9a8dcdbc45908acfd67e028cd568da98982c";
+const COLLECT_EVENT_SIGNATURE_HASH: &str =
+    "70935338e69775456a85ddef226c395fb668b63fa0115f5f20610b388e6ca9c0";
+
+// Indexed parameters (owner, tickLower, tickUpper) are stored in topics 1-3,
+// the remaining parameters are ABI encoded in the data
+sol! {
+    struct MintEventData {
+        address sender;
+        uint128 amount;
+        uint256 amount0;
+        uint256 amount1;
+    }
+
+    struct BurnEventData {
+        uint128 amount;
+        uint256 amount0;
+        uint256 amount1;
+    }
+
+    struct CollectEventData {
+        address recipient;
+        uint128 amount0;
+        uint128 amount1;
+    }
+}
+
+/// Parses a Uniswap V3 style liquidity mint event log.
+///
+/// # Errors
+///
+/// Returns an error if the log is not a valid mint event.
+pub fn parse_mint_event(log: Log, _transaction_logs: &[Log]) -> anyhow::Result<MintEvent> {
+    validate_event_signature_hash("MintEvent", MINT_EVENT_SIGNATURE_HASH, &log)?;
+
+    let block_number = parse_block_number(&log)?;
+    let owner = parse_topic_address(&log, 1, "owner")?;
+    let tick_lower = parse_topic_int24(&log, 2, "tickLower")?;
+    let tick_upper = parse_topic_int24(&log, 3, "tickUpper")?;
+
+    let data_bytes = parse_data(&log, "Mint", 4)?;
+    let decoded = match <MintEventData as SolType>::abi_decode(data_bytes) {
+        Ok(decoded) => decoded,
+        Err(e) => anyhow::bail!("Failed to decode mint event data: {e}"),
+    };
+
+    Ok(MintEvent::new(
+        block_number,
+        decoded.sender,
+        owner,
+        tick_lower,
+        tick_upper,
+        decoded.amount,
+        decoded.amount0,
+        decoded.amount1,
+    ))
+}
+
+/// Parses a Uniswap V3 style liquidity burn event log.
+///
+/// # Errors
+///
+/// Returns an error if the log is not a valid burn event.
+pub fn parse_burn_event(log: Log, _transaction_logs: &[Log]) -> anyhow::Result<BurnEvent> {
+    validate_event_signature_hash("BurnEvent", BURN_EVENT_SIGNATURE_HASH, &log)?;
+
+    let block_number = parse_block_number(&log)?;
+    let owner = parse_topic_address(&log, 1, "owner")?;
+    let tick_lower = parse_topic_int24(&log, 2, "tickLower")?;
+    let tick_upper = parse_topic_int24(&log, 3, "tickUpper")?;
+
+    let data_bytes = parse_data(&log, "Burn", 3)?;
+    let decoded = match <BurnEventData as SolType>::abi_decode(data_bytes) {
+        Ok(decoded) => decoded,
+        Err(e) => anyhow::bail!("Failed to decode burn event data: {e}"),
+    };
+
+    Ok(BurnEvent::new(
+        block_number,
+        owner,
+        tick_lower,
+        tick_upper,
+        decoded.amount,
+        decoded.amount0,
+        decoded.amount1,
+    ))
+}
+
+/// Parses a Uniswap V3 style fee collect event log.
+///
+/// # Errors
+///
+/// Returns an error if the log is not a valid collect event.
+pub fn parse_collect_event(log: Log) -> anyhow::Result<CollectEvent> {
+    validate_event_signature_hash("CollectEvent", COLLECT_EVENT_SIGNATURE_HASH, &log)?;
+
+    let block_number = parse_block_number(&log)?;
+    let owner = parse_topic_address(&log, 1, "owner")?;
+    let tick_lower = parse_topic_int24(&log, 2, "tickLower")?;
+    let tick_upper = parse_topic_int24(&log, 3, "tickUpper")?;
+
+    let data_bytes = parse_data(&log, "Collect", 3)?;
+    let decoded = match <CollectEventData as SolType>::abi_decode(data_bytes) {
+        Ok(decoded) => decoded,
+        Err(e) => anyhow::bail!("Failed to decode collect event data: {e}"),
+    };
+
+    Ok(CollectEvent::new(
+        block_number,
+        owner,
+        decoded.recipient,
+        tick_lower,
+        tick_upper,
+        decoded.amount0,
+        decoded.amount1,
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{Address, U256};
+    use rstest::*;
+    use serde_json::json;
+
+    use super::*;
+
+    fn log(topic0: &str, data: &str) -> Log {
+        let log_json = json!({
+            "removed": null,
+            "log_index": null,
+            "transaction_index": null,
+            "transaction_hash": null,
+            "block_hash": null,
+            "block_number": "0x1581b7e",
+            "address": "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640",
+            "data": data,
+            "topics": [
+                topic0,
+                "0x000000000000000000000000c36442b4a4522e871399cd717abdd847ab11fe88",
+                "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffff2764c",
+                "0x00000000000000000000000000000000000000000000000000000000000d89b4"
+            ]
+        });
+        serde_json::from_value(log_json).expect("Failed to deserialize log")
+    }
+
+    #[fixture]
+    fn mint_log() -> Log {
+        log(
+            "0x7a53080ba414158be7ec69b987b5fb7d07dee101fe85488f0853ae16239d0bde",
+            "0x000000000000000000000000c36442b4a4522e871399cd717abdd847ab11fe8800000000000000000000000000000000000000000000000000000000000f424000000000000000000000000000000000000000000000000000000000001e848000000000000000000000000000000000000000000000000029a2241af62c0000",
+        )
+    }
+
+    #[fixture]
+    fn burn_log() -> Log {
+        log(
+            "0x0c396cd989a39f4459b5fa1aed6a9a8dcdbc45908acfd67e028cd568da98982c",
+            "0x000000000000000000000000000000000000000000000000000000000007a12000000000000000000000000000000000000000000000000000000000000f424000000000000000000000000000000000000000000000000014d1120d7b160000",
+        )
+    }
+
+    #[fixture]
+    fn collect_log() -> Log {
+        log(
+            "0x70935338e69775456a85ddef226c395fb668b63fa0115f5f20610b388e6ca9c0",
+            "0x000000000000000000000000111111111111111111111111111111111111111100000000000000000000000000000000000000000000000000000000000f42bb00000000000000000000000000000000000000000000000014d1120d7b1601c8",
+        )
+    }
+
+    fn position_manager() -> Address {
+        "0xC36442b4a4522E871399CD717aBDD847Ab11FE88"
+            .parse()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_parse_mint_event(mint_log: Log) {
+        let event = parse_mint_event(mint_log, &[]).unwrap();
+
+        assert_eq!(event.block_number, 22_551_422);
+        assert_eq!(event.sender, position_manager());
+        assert_eq!(event.owner, position_manager());
+        assert_eq!(event.tick_lower, -887_220);
+        assert_eq!(event.tick_upper, 887_220);
+        assert_eq!(event.liquidity, 1_000_000);
+        assert_eq!(event.amount0, U256::from(2_000_000u64));
+        assert_eq!(event.amount1, U256::from(3_000_000_000_000_000_000u128));
+    }
+
+    #[rstest]
+    fn test_parse_burn_event(burn_log: Log) {
+        let event = parse_burn_event(burn_log, &[]).unwrap();
+
+        assert_eq!(event.owner, position_manager());
+        assert_eq!(event.tick_lower, -887_220);
+        assert_eq!(event.tick_upper, 887_220);
+        assert_eq!(event.liquidity, 500_000);
+        assert_eq!(event.amount0, U256::from(1_000_000u64));
+        assert_eq!(event.amount1, U256::from(1_500_000_000_000_000_000u128));
+    }
+
+    #[rstest]
+    fn test_parse_collect_event(collect_log: Log) {
+        let event = parse_collect_event(collect_log).unwrap();
+
+        assert_eq!(event.owner, position_manager());
+        assert_eq!(event.recipient, Address::repeat_byte(0x11));
+        assert_eq!(event.tick_lower, -887_220);
+        assert_eq!(event.tick_upper, 887_220);
+        assert_eq!(event.amount0, 1_000_123);
+        assert_eq!(event.amount1, 1_500_000_000_000_000_456);
+    }
+
+    #[rstest]
+    fn test_parse_mint_event_with_wrong_signature(burn_log: Log) {
+        let result = parse_mint_event(burn_log, &[]);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid event signature for event 'MintEvent'"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Liquidity event parsing for Uniswap V4.
+//!
+//! Uniswap V4 pools live in the singleton `PoolManager` contract, which emits a single
+//! `ModifyLiquidity` event for both mints and burns. The event does not include token amounts,
+//! and fees are settled as part of liquidity modifications, so there is no collect event.
+
+use alloy::{
+    primitives::{I256, U256},
+    sol,
+    sol_types::SolType,
+};
+use hypersync_client::simple_types::Log;
+
+use crate::{
+    events::{burn::BurnEvent, mint::MintEvent},
+    exchanges::parsing::{parse_block_number, parse_data, parse_topic_address},
+    hypersync::helpers::validate_event_signature_hash,
+};
+
+/// The Uniswap V4 modify liquidity event signature, used for both mints and burns.
+pub const MODIFY_LIQUIDITY_EVENT: &str =
+    "ModifyLiquidity(bytes32,address,int24,int24,int256,bytes32)";
+
+const MODIFY_LIQUIDITY_EVENT_SIGNATURE_HASH: &str =
+    "f208f4912782fd25c7f114ca3723a2d5dd6f3bcc3ac8db5af63baa85f711d5ec";
+
+// The pool ID and sender are indexed, the remaining parameters are ABI encoded in the data
+sol! {
+    struct ModifyLiquidityEventData {
+        int24 tick_lower;
+        int24 tick_upper;
+        int256 liquidity_delta;
+        bytes32 salt;
+    }
+}
+
+struct ModifyLiquidity {
+    block_number: u64,
+    sender: alloy::primitives::Address,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity_delta: I256,
+}
+
+fn parse_modify_liquidity(log: &Log) -> anyhow::Result<ModifyLiquidity> {
+    validate_event_signature_hash(
+        "ModifyLiquidityEvent",
+        MODIFY_LIQUIDITY_EVENT_SIGNATURE_HASH,
+        log,
+    )?;
+
+    let block_number = parse_block_number(log)?;
+    let sender = parse_topic_address(log, 2, "sender")?;
+
+    let data_bytes = parse_data(log, "ModifyLiquidity", 4)?;
+    let decoded = match <ModifyLiquidityEventData as SolType>::abi_decode(data_bytes) {
+        Ok(decoded) => decoded,
+        Err(e) => anyhow::bail!("Failed to decode modify liquidity event data: {e}"),
+    };
+
+    Ok(ModifyLiquidity {
+        block_number,
+        sender,
+        tick_lower: decoded.tick_lower.as_i32(),
+        tick_upper: decoded.tick_upper.as_i32(),
+        liquidity_delta: decoded.liquidity_delta,
+    })
+}
+
+/// Parses a Uniswap V4 modify liquidity event log which adds liquidity.
+///
+/// # Errors
+///
+/// Returns an error if the log is not a valid modify liquidity event or removes liquidity.
+pub fn parse_mint_event(log: Log, _transaction_logs: &[Log]) -> anyhow::Result<MintEvent> {
+    let event = parse_modify_liquidity(&log)?;
+    if !event.liquidity_delta.is_positive() {
+        anyhow::bail!("Modify liquidity event does not add liquidity");
+    }
+
+    Ok(MintEvent::new(
+        event.block_number,
+        event.sender,
+        event.sender,
+        event.tick_lower,
+        event.tick_upper,
+        u128::try_from(event.liquidity_delta.unsigned_abs())?,
+        U256::ZERO,
+        U256::ZERO,
+    ))
+}
+
+/// Parses a Uniswap V4 modify liquidity event log which removes liquidity.
+///
+/// # Errors
+///
+/// Returns an error if the log is not a valid modify liquidity event or adds liquidity.
+pub fn parse_burn_event(log: Log, _transaction_logs: &[Log]) -> anyhow::Result<BurnEvent> {
+    let event = parse_modify_liquidity(&log)?;
+    if !event.liquidity_delta.is_negative() {
+        anyhow::bail!("Modify liquidity event does not remove liquidity");
+    }
+
+    Ok(BurnEvent::new(
+        event.block_number,
+        event.sender,
+        event.tick_lower,
+        event.tick_upper,
+        u128::try_from(event.liquidity_delta.unsigned_abs())?,
+        U256::ZERO,
+        U256::ZERO,
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use serde_json::json;
+
+    use super::*;
+
+    fn log(data: &str) -> Log {
+        let log_json = json!({
+            "removed": null,
+            "log_index": null,
+            "transaction_index": null,
+            "transaction_hash": null,
+            "block_hash": null,
+            "block_number": "0x1581b7e",
+            "address": "0x000000000004444c5dc75cb358380d2e3de08a90",
+            "data": data,
+            "topics": [
+                "0xf208f4912782fd25c7f114ca3723a2d5dd6f3bcc3ac8db5af63baa85f711d5ec",
+                "0xabababababababababababababababababababababababababababababababab",
+                "0x000000000000000000000000bd216513d74c8cf14cf4747e6aaa6420ff64ee9e",
+                null
+            ]
+        });
+        serde_json::from_value(log_json).expect("Failed to deserialize log")
+    }
+
+    #[fixture]
+    fn add_liquidity_log() -> Log {
+        log(
+            "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffda8000000000000000000000000000000000000000000000000000000000000025800000000000000000000000000000000000000000000000000038d7ea4c680000000000000000000000000000000000000000000000000000000000000000000",
+        )
+    }
+
+    #[fixture]
+    fn remove_liquidity_log() -> Log {
+        log(
+            "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffda80000000000000000000000000000000000000000000000000000000000000258fffffffffffffffffffffffffffffffffffffffffffffffffffc72815b3980000000000000000000000000000000000000000000000000000000000000000000",
+        )
+    }
+
+    #[rstest]
+    fn test_parse_mint_event(add_liquidity_log: Log) {
+        let event = parse_mint_event(add_liquidity_log, &[]).unwrap();
+
+        assert_eq!(event.tick_lower, -600);
+        assert_eq!(event.tick_upper, 600);
+        assert_eq!(event.liquidity, 1_000_000_000_000_000);
+        assert!(event.amount0.is_zero());
+    }
+
+    #[rstest]
+    fn test_parse_burn_event(remove_liquidity_log: Log) {
+        let event = parse_burn_event(remove_liquidity_log, &[]).unwrap();
+
+        assert_eq!(event.tick_lower, -600);
+        assert_eq!(event.tick_upper, 600);
+        assert_eq!(event.liquidity, 1_000_000_000_000_000);
+    }
+
+    #[rstest]
+    fn test_parse_mint_event_rejects_removal(remove_liquidity_log: Log) {
+        assert!(parse_mint_event(remove_liquidity_log, &[]).is_err());
+    }
+}
@@ -0,0 +1,349 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conversion of historical DEX swaps into trades and bars for the Parquet data catalog.
+//!
+//! Swaps are mapped onto a DEX venue instrument (see [`Pool::instrument_id`]) so on-chain
+//! history can be backtested with the standard engine alongside CEX data.
+//!
+//! [`Pool::instrument_id`]: nautilus_model::defi::amm::Pool::instrument_id
+
+use nautilus_core::UnixNanos;
+use nautilus_data::aggregation::BarBuilder;
+use nautilus_model::{
+    data::{Bar, BarType, TradeTick, bar::get_bar_interval_ns},
+    defi::swap::Swap,
+    enums::{AggregationSource, AggressorSide, BarAggregation, OrderSide, PriceType},
+    identifiers::TradeId,
+};
+use nautilus_persistence::backend::catalog::ParquetDataCatalog;
+
+/// Converts the given swaps into trade ticks on the DEX venue instrument of each swap's pool.
+///
+/// Swaps are ordered by timestamp and block, and each trade ID is derived from the block number
+/// and the position of the swap within that block (e.g. `22551422-0`). Swaps with a zero
+/// quantity are skipped.
+#[must_use]
+pub fn swaps_to_trades(swaps: &[Swap]) -> Vec<TradeTick> {
+    let mut swaps: Vec<&Swap> = swaps.iter().collect();
+    swaps.sort_by_key(|swap| (swap.timestamp, swap.block));
+
+    let mut trades = Vec::with_capacity(swaps.len());
+    let mut last_block = None;
+    let mut sequence = 0;
+
+    for swap in swaps {
+        if last_block == Some(swap.block) {
+            sequence += 1;
+        } else {
+            last_block = Some(swap.block);
+            sequence = 0;
+        }
+
+        let aggressor_side = match swap.side {
+            OrderSide::Buy => AggressorSide::Buyer,
+            OrderSide::Sell => AggressorSide::Seller,
+            OrderSide::NoOrderSide => AggressorSide::NoAggressor,
+        };
+
+        match TradeTick::new_checked(
+            swap.pool.instrument_id(),
+            swap.price,
+            swap.quantity,
+            aggressor_side,
+            TradeId::new(format!("{}-{sequence}", swap.block)),
+            swap.timestamp,
+            swap.timestamp,
+        ) {
+            Ok(trade) => trades.push(trade),
+            Err(e) => log::warn!("Skipping swap {swap}: {e}"),
+        }
+    }
+
+    trades
+}
+
+/// Aggregates the given trades into bars of the given bar type.
+///
+/// Supports tick bars and time bars from millisecond up to day intervals, where time bars are
+/// aligned to the UNIX epoch and stamped at the close of each interval. Intervals without any
+/// trades produce no bar.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The `bar_type` is not internally aggregated from last prices.
+/// - The `bar_type` aggregation is not supported.
+/// - A trade is for a different instrument than the `bar_type`.
+pub fn aggregate_bars(trades: &[TradeTick], bar_type: BarType) -> anyhow::Result<Vec<Bar>> {
+    if bar_type.aggregation_source() != AggregationSource::Internal {
+        anyhow::bail!("Bar type {bar_type} must be internally aggregated");
+    }
+
+    let spec = bar_type.spec();
+    if spec.price_type != PriceType::Last {
+        anyhow::bail!("Bar type {bar_type} must aggregate last prices");
+    }
+
+    let interval_ns = match spec.aggregation {
+        BarAggregation::Tick => None,
+        BarAggregation::Millisecond
+        | BarAggregation::Second
+        | BarAggregation::Minute
+        | BarAggregation::Hour
+        | BarAggregation::Day => Some(get_bar_interval_ns(&bar_type).as_u64()),
+        aggregation => anyhow::bail!("Unsupported aggregation {aggregation:?} for swap bars"),
+    };
+
+    let Some(first) = trades.first() else {
+        return Ok(Vec::new());
+    };
+
+    let mut builder = BarBuilder::new(bar_type, first.price.precision, first.size.precision);
+    let mut bars = Vec::new();
+    let mut count = 0;
+    let mut bar_close: Option<u64> = None;
+
+    for trade in trades {
+        if trade.instrument_id != bar_type.instrument_id() {
+            anyhow::bail!(
+                "Trade instrument {} does not match bar type {bar_type}",
+                trade.instrument_id
+            );
+        }
+
+        if let Some(interval_ns) = interval_ns {
+            let ts = trade.ts_event.as_u64();
+            let close = ts - ts % interval_ns + interval_ns;
+            if let Some(previous) = bar_close {
+                if close != previous {
+                    let ts_close = UnixNanos::from(previous);
+                    bars.push(builder.build(ts_close, ts_close));
+                }
+            }
+            bar_close = Some(close);
+            builder.update(trade.price, trade.size, trade.ts_event);
+        } else {
+            builder.update(trade.price, trade.size, trade.ts_event);
+            count += 1;
+            if count == spec.step.get() {
+                bars.push(builder.build(trade.ts_event, trade.ts_event));
+                count = 0;
+            }
+        }
+    }
+
+    // Time bars are built for the final interval as all trades in it have been seen
+    if let Some(close) = bar_close {
+        let ts_close = UnixNanos::from(close);
+        bars.push(builder.build(ts_close, ts_close));
+    }
+
+    Ok(bars)
+}
+
+/// Writes the given swaps to the catalog as trade ticks, along with bars for each bar type.
+///
+/// Returns the number of trades written.
+///
+/// # Errors
+///
+/// Returns an error if bar aggregation or writing to the catalog fails.
+pub fn export_swaps_to_catalog(
+    catalog: &ParquetDataCatalog,
+    swaps: &[Swap],
+    bar_types: &[BarType],
+) -> anyhow::Result<usize> {
+    let trades = swaps_to_trades(swaps);
+    if trades.is_empty() {
+        return Ok(0);
+    }
+
+    for bar_type in bar_types {
+        let bars = aggregate_bars(&trades, *bar_type)?;
+        if !bars.is_empty() {
+            catalog.write_to_parquet(bars, None, None, None, None)?;
+        }
+    }
+
+    let count = trades.len();
+    catalog.write_to_parquet(trades, None, None, None, None)?;
+
+    Ok(count)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::Arc};
+
+    use alloy::primitives::Address;
+    use nautilus_model::{
+        defi::{
+            amm::{Pool, SharedPool},
+            chain::chains,
+            dex::{AmmType, Dex},
+            token::Token,
+        },
+        identifiers::InstrumentId,
+        types::{Price, Quantity},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const SECOND: u64 = 1_000_000_000;
+
+    #[fixture]
+    fn pool() -> SharedPool {
+        let chain = Arc::new(chains::ETHEREUM.clone());
+        let dex = Dex::new(
+            chains::ETHEREUM.clone(),
+            "Uniswap V3",
+            "0x1F98431c8aD98523631AE4a59f267346ea31F984",
+            AmmType::CLAMM,
+            "PoolCreated(address,address,uint24,int24,address)",
+            "Swap(address,address,int256,int256,uint160,uint128,int24)",
+        );
+        let token0 = Token::new(
+            chain.clone(),
+            Address::repeat_byte(0x01),
+            "Wrapped Ether".to_string(),
+            "WETH".to_string(),
+            18,
+        );
+        let token1 = Token::new(
+            chain.clone(),
+            Address::repeat_byte(0x02),
+            "USD Coin".to_string(),
+            "USDC".to_string(),
+            6,
+        );
+        Arc::new(Pool::new(
+            chain,
+            dex,
+            Address::repeat_byte(0x03),
+            0,
+            token0,
+            token1,
+            500,
+            10,
+        ))
+    }
+
+    fn swap(
+        pool: &SharedPool,
+        block: u64,
+        ts: u64,
+        side: OrderSide,
+        price: &str,
+        qty: &str,
+    ) -> Swap {
+        Swap::new(
+            pool.chain.clone(),
+            Arc::new(pool.dex.clone()),
+            pool.clone(),
+            block,
+            UnixNanos::from(ts),
+            Address::repeat_byte(0x04),
+            side,
+            Quantity::from(qty),
+            Price::from(price),
+        )
+    }
+
+    #[rstest]
+    fn test_pool_instrument_id(pool: SharedPool) {
+        assert_eq!(
+            pool.instrument_id(),
+            InstrumentId::from("WETH/USDC-500.Ethereum:UniswapV3")
+        );
+    }
+
+    #[rstest]
+    fn test_swaps_to_trades(pool: SharedPool) {
+        let swaps = vec![
+            swap(&pool, 11, 12 * SECOND, OrderSide::Sell, "2501.00", "2.0"),
+            swap(&pool, 10, 0, OrderSide::Buy, "2500.00", "1.0"),
+            swap(&pool, 10, 0, OrderSide::Sell, "2499.00", "0.5"),
+            swap(&pool, 11, 12 * SECOND, OrderSide::Buy, "2502.00", "0.0"),
+        ];
+
+        let trades = swaps_to_trades(&swaps);
+
+        assert_eq!(trades.len(), 3);
+        assert_eq!(trades[0].instrument_id, pool.instrument_id());
+        assert_eq!(trades[0].trade_id, TradeId::new("10-0"));
+        assert_eq!(trades[0].aggressor_side, AggressorSide::Buyer);
+        assert_eq!(trades[1].trade_id, TradeId::new("10-1"));
+        assert_eq!(trades[1].aggressor_side, AggressorSide::Seller);
+        assert_eq!(trades[2].trade_id, TradeId::new("11-0"));
+        assert_eq!(trades[2].ts_event, UnixNanos::from(12 * SECOND));
+    }
+
+    #[rstest]
+    fn test_aggregate_time_bars(pool: SharedPool) {
+        let swaps = vec![
+            swap(&pool, 10, 5 * SECOND, OrderSide::Buy, "2500.00", "1.0"),
+            swap(&pool, 11, 20 * SECOND, OrderSide::Buy, "2510.00", "1.0"),
+            swap(&pool, 12, 40 * SECOND, OrderSide::Sell, "2490.00", "2.0"),
+            swap(&pool, 16, 130 * SECOND, OrderSide::Sell, "2480.00", "0.5"),
+        ];
+        let trades = swaps_to_trades(&swaps);
+        let bar_type =
+            BarType::from_str(&format!("{}-1-MINUTE-LAST-INTERNAL", pool.instrument_id())).unwrap();
+
+        let bars = aggregate_bars(&trades, bar_type).unwrap();
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].open, Price::from("2500.00"));
+        assert_eq!(bars[0].high, Price::from("2510.00"));
+        assert_eq!(bars[0].low, Price::from("2490.00"));
+        assert_eq!(bars[0].close, Price::from("2490.00"));
+        assert_eq!(bars[0].volume, Quantity::from("4.0"));
+        assert_eq!(bars[0].ts_event, UnixNanos::from(60 * SECOND));
+        assert_eq!(bars[1].open, Price::from("2480.00"));
+        assert_eq!(bars[1].ts_event, UnixNanos::from(180 * SECOND));
+    }
+
+    #[rstest]
+    fn test_aggregate_tick_bars(pool: SharedPool) {
+        let swaps = vec![
+            swap(&pool, 10, SECOND, OrderSide::Buy, "2500.00", "1.0"),
+            swap(&pool, 11, 2 * SECOND, OrderSide::Buy, "2510.00", "1.0"),
+            swap(&pool, 12, 3 * SECOND, OrderSide::Sell, "2490.00", "2.0"),
+        ];
+        let trades = swaps_to_trades(&swaps);
+        let bar_type =
+            BarType::from_str(&format!("{}-2-TICK-LAST-INTERNAL", pool.instrument_id())).unwrap();
+
+        let bars = aggregate_bars(&trades, bar_type).unwrap();
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].close, Price::from("2510.00"));
+        assert_eq!(bars[0].volume, Quantity::from("2.0"));
+        assert_eq!(bars[0].ts_event, UnixNanos::from(2 * SECOND));
+    }
+
+    #[rstest]
+    fn test_aggregate_bars_rejects_external_bar_type(pool: SharedPool) {
+        let bar_type =
+            BarType::from_str(&format!("{}-1-MINUTE-LAST-EXTERNAL", pool.instrument_id())).unwrap();
+
+        assert!(aggregate_bars(&[], bar_type).is_err());
+    }
+}
@@ -77,15 +77,78 @@ impl HyperSyncClient {
         event_signature: &str,
         additional_topics: Vec<String>,
     ) -> impl Stream<Item = Log> + use<> {
-        let event_hash = keccak256(event_signature.as_bytes());
-        let topic0 = format!("0x{}", hex::encode(event_hash));
-
         let mut topics_array = Vec::new();
-        topics_array.push(vec![topic0]);
+        topics_array.push(vec![event_signature_topic(event_signature)]);
         for additional_topic in additional_topics {
             topics_array.push(vec![additional_topic]);
         }
 
+        let fields = [
+            "block_number",
+            "data",
+            "topic0",
+            "topic1",
+            "topic2",
+            "topic3",
+        ];
+        self.request_logs_stream(
+            from_block,
+            to_block,
+            contract_address,
+            topics_array,
+            &fields,
+        )
+        .await
+    }
+
+    /// Creates a stream of contract event logs matching any of the `event_signatures`, yielded
+    /// in on-chain (block number, log index) order.
+    ///
+    /// The logs include their address, log index and transaction hash, so that events emitted
+    /// within the same transaction can be related to each other.
+    pub async fn request_contract_multi_events_stream(
+        &self,
+        from_block: u64,
+        to_block: Option<u64>,
+        contract_address: &str,
+        event_signatures: &[&str],
+    ) -> impl Stream<Item = Log> + use<> {
+        let topics_array = vec![
+            event_signatures
+                .iter()
+                .map(|signature| event_signature_topic(signature))
+                .collect(),
+        ];
+
+        let fields = [
+            "block_number",
+            "log_index",
+            "transaction_hash",
+            "address",
+            "data",
+            "topic0",
+            "topic1",
+            "topic2",
+            "topic3",
+        ];
+        self.request_logs_stream(
+            from_block,
+            to_block,
+            contract_address,
+            topics_array,
+            &fields,
+        )
+        .await
+    }
+
+    async fn request_logs_stream(
+        &self,
+        from_block: u64,
+        to_block: Option<u64>,
+        contract_address: &str,
+        topics_array: Vec<Vec<String>>,
+        fields: &[&str],
+    ) -> impl Stream<Item = Log> + use<> {
         let mut query_value = serde_json::json!({
             "from_block": from_block,
             "logs": [{
@@ -95,14 +158,7 @@ impl HyperSyncClient {
                 ]
             }],
             "field_selection": {
-                "log": [
-                    "block_number",
-                    "data",
-                    "topic0",
-                    "topic1",
-                    "topic2",
-                    "topic3",
-                ]
+                "log": fields,
             }
         });
 
@@ -121,6 +177,7 @@ impl HyperSyncClient {
             .await
             .expect("Failed to create stream");
 
+        // HyperSync returns the logs of each response, and the responses, in on-chain order
         async_stream::stream! {
               while let Some(response) = rx.recv().await {
                 let response = response.unwrap();
@@ -236,3 +293,8 @@ impl HyperSyncClient {
         }
     }
 }
+
+/// Returns the hex encoded topic0 hash of the given event signature.
+fn event_signature_topic(event_signature: &str) -> String {
+    format!("0x{}", hex::encode(keccak256(event_signature.as_bytes())))
+}
@@ -43,6 +43,7 @@
 pub mod config;
 pub mod contracts;
 pub mod events;
+pub mod export;
 pub mod rpc;
 
 #[cfg(feature = "hypersync")]
@@ -91,6 +91,7 @@ impl CoreBlockchainRpcClient {
             reconnect_jitter_ms: None,
             reconnect_backoff_factor: None,
             reconnect_delay_max_ms: None,
+            recorder: None,
         };
         let client = WebSocketClient::connect(
             config,
@@ -12,3 +12,91 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
+
+//! Configuration structures for the Coinbase International live clients.
+
+use nautilus_core::env::get_or_env_var;
+use nautilus_model::identifiers::{AccountId, TraderId};
+
+use crate::common::consts::COINBASE_INTX;
+
+/// Configuration for the Coinbase International data client.
+#[derive(Debug, Clone, Default)]
+pub struct CoinbaseIntxDataClientConfig {
+    /// The Coinbase International API public key (falls back to `COINBASE_INTX_API_KEY`).
+    pub api_key: Option<String>,
+    /// The Coinbase International API secret (falls back to `COINBASE_INTX_API_SECRET`).
+    pub api_secret: Option<String>,
+    /// The Coinbase International API passphrase (falls back to `COINBASE_INTX_API_PASSPHRASE`).
+    pub api_passphrase: Option<String>,
+    /// The HTTP base url override.
+    pub base_url_http: Option<String>,
+    /// The WebSocket base url override.
+    pub base_url_ws: Option<String>,
+    /// The timeout (seconds) for HTTP requests.
+    pub http_timeout_secs: Option<u64>,
+    /// The heartbeat interval (seconds) for the WebSocket connection.
+    pub heartbeat_secs: Option<u64>,
+}
+
+impl CoinbaseIntxDataClientConfig {
+    /// Creates a new [`CoinbaseIntxDataClientConfig`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Configuration for the Coinbase International execution client.
+#[derive(Debug, Clone)]
+pub struct CoinbaseIntxExecClientConfig {
+    /// The trader ID for the client.
+    pub trader_id: TraderId,
+    /// The Coinbase International portfolio ID (falls back to `COINBASE_INTX_PORTFOLIO_ID`).
+    pub portfolio_id: Option<String>,
+    /// The Coinbase International API public key (falls back to `COINBASE_INTX_API_KEY`).
+    pub api_key: Option<String>,
+    /// The Coinbase International API secret (falls back to `COINBASE_INTX_API_SECRET`).
+    pub api_secret: Option<String>,
+    /// The Coinbase International API passphrase (falls back to `COINBASE_INTX_API_PASSPHRASE`).
+    pub api_passphrase: Option<String>,
+    /// The HTTP base url override.
+    pub base_url_http: Option<String>,
+    /// The FIX drop copy endpoint override.
+    pub base_url_fix: Option<String>,
+    /// The timeout (seconds) for HTTP requests.
+    pub http_timeout_secs: Option<u64>,
+    /// If order and fill updates should be streamed from the FIX drop copy session.
+    pub use_fix_drop_copy: bool,
+    /// The timeout (seconds) to wait for the FIX drop copy logon.
+    pub fix_logon_timeout_secs: u64,
+}
+
+impl CoinbaseIntxExecClientConfig {
+    /// Creates a new [`CoinbaseIntxExecClientConfig`] instance for the given trader.
+    #[must_use]
+    pub const fn new(trader_id: TraderId) -> Self {
+        Self {
+            trader_id,
+            portfolio_id: None,
+            api_key: None,
+            api_secret: None,
+            api_passphrase: None,
+            base_url_http: None,
+            base_url_fix: None,
+            http_timeout_secs: None,
+            use_fix_drop_copy: true,
+            fix_logon_timeout_secs: 30,
+        }
+    }
+
+    /// Returns the account ID for the configured portfolio.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no portfolio ID is configured and `COINBASE_INTX_PORTFOLIO_ID` is not set.
+    pub fn account_id(&self) -> anyhow::Result<AccountId> {
+        let portfolio_id = get_or_env_var(self.portfolio_id.clone(), "COINBASE_INTX_PORTFOLIO_ID")?;
+        Ok(AccountId::new(format!("{COINBASE_INTX}-{portfolio_id}")))
+    }
+}
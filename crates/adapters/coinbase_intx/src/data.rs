@@ -0,0 +1,448 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Live data client implementation for Coinbase International.
+
+use std::{
+    future::Future,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use futures_util::StreamExt;
+use nautilus_common::{
+    messages::{
+        DataEvent,
+        data::{
+            DataResponse, InstrumentResponse, InstrumentsResponse, RequestInstrument,
+            RequestInstruments, SubscribeBars, SubscribeBookDeltas, SubscribeIndexPrices,
+            SubscribeInstrument, SubscribeMarkPrices, SubscribeQuotes, SubscribeTrades,
+            UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeIndexPrices, UnsubscribeInstrument,
+            UnsubscribeMarkPrices, UnsubscribeQuotes, UnsubscribeTrades,
+        },
+    },
+    runner::get_data_event_sender,
+    runtime::get_runtime,
+};
+use nautilus_core::time::get_atomic_clock_realtime;
+use nautilus_data::client::DataClient;
+use nautilus_model::{
+    data::{Data, OrderBookDeltas_API},
+    identifiers::{ClientId, Venue},
+    instruments::InstrumentAny,
+};
+use tokio::{sync::mpsc::UnboundedSender, task::JoinHandle};
+
+use crate::{
+    common::consts::COINBASE_INTX_VENUE,
+    config::CoinbaseIntxDataClientConfig,
+    http::client::CoinbaseIntxHttpClient,
+    websocket::{
+        CoinbaseIntxWebSocketClient, error::CoinbaseIntxWsError, messages::NautilusWsMessage,
+    },
+};
+
+/// Provides a live data client for [Coinbase International](https://www.coinbase.com/en/international-exchange).
+///
+/// Instruments are loaded over HTTP on connect, market data subscriptions are
+/// streamed over the WebSocket API and forwarded to the `AsyncRunner`.
+#[derive(Debug)]
+pub struct CoinbaseIntxDataClient {
+    client_id: ClientId,
+    config: CoinbaseIntxDataClientConfig,
+    http: CoinbaseIntxHttpClient,
+    ws: Arc<tokio::sync::Mutex<CoinbaseIntxWebSocketClient>>,
+    is_connected: AtomicBool,
+    task_handles: Arc<Mutex<Vec<JoinHandle<()>>>>,
+    data_sender: UnboundedSender<DataEvent>,
+}
+
+impl CoinbaseIntxDataClient {
+    /// Creates a new [`CoinbaseIntxDataClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if credentials are missing from both the config and the environment.
+    pub fn new(client_id: ClientId, config: CoinbaseIntxDataClientConfig) -> anyhow::Result<Self> {
+        let http = CoinbaseIntxHttpClient::with_credentials(
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.api_passphrase.clone(),
+            config.base_url_http.clone(),
+            config.http_timeout_secs,
+        )?;
+        let ws = CoinbaseIntxWebSocketClient::new(
+            config.base_url_ws.clone(),
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.api_passphrase.clone(),
+            config.heartbeat_secs,
+        )?;
+
+        Ok(Self {
+            client_id,
+            config,
+            http,
+            ws: Arc::new(tokio::sync::Mutex::new(ws)),
+            is_connected: AtomicBool::new(false),
+            task_handles: Arc::new(Mutex::new(Vec::new())),
+            data_sender: get_data_event_sender(),
+        })
+    }
+
+    /// Returns the configuration for the client.
+    #[must_use]
+    pub const fn config(&self) -> &CoinbaseIntxDataClientConfig {
+        &self.config
+    }
+
+    /// Loads all instruments from the venue into the HTTP client instrument cache.
+    async fn load_instruments(&self) -> anyhow::Result<Vec<InstrumentAny>> {
+        let instruments = self.http.request_instruments().await?;
+
+        // Instrument cache is shared between clones of the HTTP client
+        self.http.clone().add_instruments(instruments.clone());
+        tracing::info!("Loaded {} instruments", instruments.len());
+
+        Ok(instruments)
+    }
+
+    /// Spawns a WebSocket operation on the runtime, logging any error.
+    fn spawn_ws<F, Fut>(&self, description: &'static str, op: F)
+    where
+        F: FnOnce(CoinbaseIntxWebSocketClient) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), CoinbaseIntxWsError>> + Send + 'static,
+    {
+        let ws = self.ws.clone();
+        get_runtime().spawn(async move {
+            // Clone shares the connected inner client, avoids holding the lock across the request
+            let ws = ws.lock().await.clone();
+            if let Err(e) = op(ws).await {
+                tracing::error!("Failed to {description}: {e}");
+            }
+        });
+    }
+}
+
+/// Forwards a parsed WebSocket message to the data event channel.
+fn handle_ws_message(msg: NautilusWsMessage, sender: &UnboundedSender<DataEvent>) {
+    let data = match msg {
+        NautilusWsMessage::Data(data) => vec![data],
+        NautilusWsMessage::DataVec(data) => data,
+        NautilusWsMessage::Deltas(deltas) => vec![Data::Deltas(OrderBookDeltas_API::new(deltas))],
+        NautilusWsMessage::MarkPrice(mark_price) => vec![Data::MarkPriceUpdate(mark_price)],
+        NautilusWsMessage::IndexPrice(index_price) => vec![Data::IndexPriceUpdate(index_price)],
+        NautilusWsMessage::MarkAndIndex((mark_price, index_price)) => vec![
+            Data::MarkPriceUpdate(mark_price),
+            Data::IndexPriceUpdate(index_price),
+        ],
+        NautilusWsMessage::Instrument(instrument) => {
+            if let Err(e) = sender.send(DataEvent::Instrument(instrument)) {
+                tracing::error!("Failed to send instrument event: {e}");
+            }
+            return;
+        }
+        NautilusWsMessage::OrderEvent(event) => {
+            tracing::warn!("Unexpected order event on data client: {event:?}");
+            return;
+        }
+    };
+
+    for data in data {
+        if let Err(e) = sender.send(DataEvent::Data(data)) {
+            tracing::error!("Failed to send data event: {e}");
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DataClient for CoinbaseIntxDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        Some(*COINBASE_INTX_VENUE)
+    }
+
+    fn start(&self) -> anyhow::Result<()> {
+        tracing::debug!("Starting Coinbase International data client");
+        Ok(())
+    }
+
+    fn stop(&self) -> anyhow::Result<()> {
+        tracing::debug!("Stopping Coinbase International data client");
+
+        for handle in self.task_handles.lock().unwrap().drain(..) {
+            handle.abort();
+        }
+
+        let ws = self.ws.clone();
+        get_runtime().spawn(async move {
+            let mut ws = ws.lock().await;
+            if !ws.is_closed() {
+                if let Err(e) = ws.close().await {
+                    tracing::error!("Error closing WebSocket: {e}");
+                }
+            }
+        });
+
+        self.is_connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn reset(&self) -> anyhow::Result<()> {
+        tracing::debug!("Resetting Coinbase International data client");
+        self.is_connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn dispose(&self) -> anyhow::Result<()> {
+        tracing::debug!("Disposing Coinbase International data client");
+        self.stop()
+    }
+
+    async fn connect(&self) -> anyhow::Result<()> {
+        if self.is_connected() {
+            return Ok(());
+        }
+
+        tracing::debug!("Connecting Coinbase International data client");
+
+        let instruments = self.load_instruments().await?;
+
+        let stream = {
+            let mut ws = self.ws.lock().await;
+            ws.connect(instruments).await?;
+            ws.stream()
+        };
+
+        let sender = self.data_sender.clone();
+        let handle = get_runtime().spawn(async move {
+            tokio::pin!(stream);
+            while let Some(msg) = stream.next().await {
+                handle_ws_message(msg, &sender);
+            }
+        });
+        self.task_handles.lock().unwrap().push(handle);
+
+        self.is_connected.store(true, Ordering::Relaxed);
+        tracing::info!("Coinbase International data client connected");
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> anyhow::Result<()> {
+        tracing::debug!("Disconnecting Coinbase International data client");
+
+        {
+            let mut ws = self.ws.lock().await;
+            if !ws.is_closed() {
+                ws.close().await?;
+            }
+        }
+
+        let handles = std::mem::take(&mut *self.task_handles.lock().unwrap());
+        for handle in handles {
+            handle.abort();
+            if let Err(e) = handle.await {
+                if !e.is_cancelled() {
+                    tracing::error!("Task join error: {e}");
+                }
+            }
+        }
+
+        self.is_connected.store(false, Ordering::Relaxed);
+        tracing::info!("Coinbase International data client disconnected");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.is_connected.load(Ordering::Relaxed)
+    }
+
+    fn is_disconnected(&self) -> bool {
+        !self.is_connected()
+    }
+
+    fn subscribe_instrument(&mut self, cmd: &SubscribeInstrument) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("subscribe instrument", move |ws| async move {
+            ws.subscribe_instruments(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn subscribe_book_deltas(&mut self, cmd: &SubscribeBookDeltas) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("subscribe book deltas", move |ws| async move {
+            ws.subscribe_order_book(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn subscribe_quotes(&mut self, cmd: &SubscribeQuotes) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("subscribe quotes", move |ws| async move {
+            ws.subscribe_quotes(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn subscribe_trades(&mut self, cmd: &SubscribeTrades) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("subscribe trades", move |ws| async move {
+            ws.subscribe_trades(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn subscribe_mark_prices(&mut self, cmd: &SubscribeMarkPrices) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("subscribe mark prices", move |ws| async move {
+            ws.subscribe_mark_prices(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn subscribe_index_prices(&mut self, cmd: &SubscribeIndexPrices) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("subscribe index prices", move |ws| async move {
+            ws.subscribe_index_prices(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn subscribe_bars(&mut self, cmd: &SubscribeBars) -> anyhow::Result<()> {
+        let bar_type = cmd.bar_type;
+        self.spawn_ws("subscribe bars", move |ws| async move {
+            ws.subscribe_bars(bar_type).await
+        });
+        Ok(())
+    }
+
+    fn unsubscribe_instrument(&mut self, cmd: &UnsubscribeInstrument) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("unsubscribe instrument", move |ws| async move {
+            ws.unsubscribe_instruments(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn unsubscribe_book_deltas(&mut self, cmd: &UnsubscribeBookDeltas) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("unsubscribe book deltas", move |ws| async move {
+            ws.unsubscribe_order_book(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn unsubscribe_quotes(&mut self, cmd: &UnsubscribeQuotes) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("unsubscribe quotes", move |ws| async move {
+            ws.unsubscribe_quotes(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn unsubscribe_trades(&mut self, cmd: &UnsubscribeTrades) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("unsubscribe trades", move |ws| async move {
+            ws.unsubscribe_trades(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn unsubscribe_mark_prices(&mut self, cmd: &UnsubscribeMarkPrices) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("unsubscribe mark prices", move |ws| async move {
+            ws.unsubscribe_mark_prices(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn unsubscribe_index_prices(&mut self, cmd: &UnsubscribeIndexPrices) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws("unsubscribe index prices", move |ws| async move {
+            ws.unsubscribe_index_prices(vec![instrument_id]).await
+        });
+        Ok(())
+    }
+
+    fn unsubscribe_bars(&mut self, cmd: &UnsubscribeBars) -> anyhow::Result<()> {
+        let bar_type = cmd.bar_type;
+        self.spawn_ws("unsubscribe bars", move |ws| async move {
+            ws.unsubscribe_bars(bar_type).await
+        });
+        Ok(())
+    }
+
+    fn request_instruments(&self, request: &RequestInstruments) -> anyhow::Result<()> {
+        let http = self.http.clone();
+        let sender = self.data_sender.clone();
+        let client_id = self.client_id;
+        let request = request.clone();
+
+        get_runtime().spawn(async move {
+            match http.request_instruments().await {
+                Ok(instruments) => {
+                    let response = DataResponse::Instruments(InstrumentsResponse::new(
+                        request.request_id,
+                        client_id,
+                        *COINBASE_INTX_VENUE,
+                        instruments,
+                        get_atomic_clock_realtime().get_time_ns(),
+                        request.params,
+                    ));
+                    if let Err(e) = sender.send(DataEvent::Response(response)) {
+                        tracing::error!("Failed to send instruments response: {e}");
+                    }
+                }
+                Err(e) => tracing::error!("Failed to request instruments: {e}"),
+            }
+        });
+
+        Ok(())
+    }
+
+    fn request_instrument(&self, request: &RequestInstrument) -> anyhow::Result<()> {
+        let http = self.http.clone();
+        let sender = self.data_sender.clone();
+        let client_id = self.client_id;
+        let request = request.clone();
+
+        get_runtime().spawn(async move {
+            match http.request_instrument(&request.instrument_id.symbol).await {
+                Ok(instrument) => {
+                    let response = DataResponse::Instrument(Box::new(InstrumentResponse::new(
+                        request.request_id,
+                        client_id,
+                        request.instrument_id,
+                        instrument,
+                        get_atomic_clock_realtime().get_time_ns(),
+                        request.params,
+                    )));
+                    if let Err(e) = sender.send(DataEvent::Response(response)) {
+                        tracing::error!("Failed to send instrument response: {e}");
+                    }
+                }
+                Err(e) => tracing::error!("Failed to request instrument: {e}"),
+            }
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1131 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Live execution client implementation for Coinbase International.
+
+use std::{
+    future::Future,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use nautilus_common::{
+    messages::{
+        ExecutionEvent,
+        execution::{
+            BatchCancelOrders, CancelAllOrders, CancelOrder, GenerateFillReports,
+            GenerateOrderStatusReport, GeneratePositionReports, ModifyOrder, QueryOrder,
+            SubmitOrder, SubmitOrderList,
+        },
+    },
+    runner::get_exec_event_sender,
+    runtime::get_runtime,
+};
+use nautilus_core::{UUID4, UnixNanos, time::get_atomic_clock_realtime};
+use nautilus_execution::client::{ExecutionClient, LiveExecutionClient, base::BaseExecutionClient};
+use nautilus_model::{
+    accounts::AccountAny,
+    enums::{OmsType, OrderSide},
+    events::{
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny, OrderModifyRejected,
+        OrderRejected,
+    },
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, Symbol, TraderId, Venue,
+        VenueOrderId,
+    },
+    instruments::Instrument,
+    orders::{Order, OrderAny},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{AccountBalance, MarginBalance},
+};
+use tokio::{
+    runtime::{Handle, RuntimeFlavor},
+    sync::mpsc::UnboundedSender,
+};
+
+use crate::{
+    config::CoinbaseIntxExecClientConfig, fix::client::CoinbaseIntxFixClient,
+    http::client::CoinbaseIntxHttpClient,
+};
+
+/// Provides a live execution client for [Coinbase International](https://www.coinbase.com/en/international-exchange).
+///
+/// Order commands are sent over the REST API from tasks spawned on the shared runtime,
+/// with the resulting order events sent back to the `AsyncRunner` for processing.
+/// Order status and fill updates are streamed from the FIX drop copy session as execution
+/// reports, which the execution engine reconciles into order events.
+#[derive(Debug)]
+pub struct CoinbaseIntxExecutionClient {
+    core: BaseExecutionClient,
+    config: CoinbaseIntxExecClientConfig,
+    http: CoinbaseIntxHttpClient,
+    fix: Mutex<CoinbaseIntxFixClient>,
+    is_connected: AtomicBool,
+    emitter: OrderEventEmitter,
+}
+
+impl CoinbaseIntxExecutionClient {
+    /// Creates a new [`CoinbaseIntxExecutionClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if credentials are missing from both the config and the environment.
+    pub fn new(
+        core: BaseExecutionClient,
+        config: CoinbaseIntxExecClientConfig,
+    ) -> anyhow::Result<Self> {
+        let http = CoinbaseIntxHttpClient::with_credentials(
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.api_passphrase.clone(),
+            config.base_url_http.clone(),
+            config.http_timeout_secs,
+        )?;
+        let fix = CoinbaseIntxFixClient::new(
+            config.base_url_fix.clone(),
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.api_passphrase.clone(),
+            config.portfolio_id.clone(),
+        )?;
+        let emitter = OrderEventEmitter {
+            trader_id: core.trader_id,
+            account_id: core.account_id,
+            sender: get_exec_event_sender(),
+        };
+
+        Ok(Self {
+            core,
+            config,
+            http,
+            fix: Mutex::new(fix),
+            is_connected: AtomicBool::new(false),
+            emitter,
+        })
+    }
+
+    /// Returns the configuration for the client.
+    #[must_use]
+    pub const fn config(&self) -> &CoinbaseIntxExecClientConfig {
+        &self.config
+    }
+
+    fn submit_single_order(&self, strategy_id: StrategyId, order: &OrderAny) {
+        if order.is_closed() {
+            log::warn!("Cannot submit closed order {}", order.client_order_id());
+            return;
+        }
+
+        self.core.generate_order_submitted(
+            strategy_id,
+            order.instrument_id(),
+            order.client_order_id(),
+            get_atomic_clock_realtime().get_time_ns(),
+        );
+
+        let http = self.http.clone();
+        let emitter = self.emitter.clone();
+        let account_id = self.core.account_id;
+        let instrument_id = order.instrument_id();
+        let client_order_id = order.client_order_id();
+        let order_side = order.order_side();
+        let order_type = order.order_type();
+        let quantity = order.quantity();
+        let time_in_force = order.time_in_force();
+        let expire_time = order.expire_time().map(|t| t.to_datetime_utc());
+        let price = order.price();
+        let trigger_price = order.trigger_price();
+        let post_only = order.is_post_only();
+        let reduce_only = order.is_reduce_only();
+
+        get_runtime().spawn(async move {
+            let result = http
+                .submit_order(
+                    account_id,
+                    client_order_id,
+                    instrument_id.symbol,
+                    order_side,
+                    order_type,
+                    quantity,
+                    time_in_force,
+                    expire_time,
+                    price,
+                    trigger_price,
+                    Some(post_only),
+                    Some(reduce_only),
+                )
+                .await;
+
+            match result {
+                Ok(report) => emitter.order_accepted(
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    report.venue_order_id,
+                    report.ts_last,
+                ),
+                Err(e) => {
+                    emitter.order_rejected(
+                        strategy_id,
+                        instrument_id,
+                        client_order_id,
+                        &e.to_string(),
+                    );
+                }
+            }
+        });
+    }
+
+    fn connect_fix(&self) -> anyhow::Result<()> {
+        let mut fix = self.fix.lock().expect("FIX client mutex poisoned").clone();
+        log::info!(
+            "Logging on to FIX drop copy server: endpoint={}, target_comp_id={}, sender_comp_id={}",
+            fix.endpoint(),
+            fix.target_comp_id(),
+            fix.sender_comp_id(),
+        );
+
+        let http = self.http.clone();
+        let emitter = self.emitter.clone();
+        let handler = move |event| {
+            if let Some(event) = rescale_fix_report(&http, event) {
+                emitter.send(event);
+            }
+        };
+        let logon_timeout = Duration::from_secs(self.config.fix_logon_timeout_secs);
+
+        block_on(async {
+            fix.connect_with_handler(handler).await?;
+
+            let logon = async {
+                while !fix.is_logged_on() {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+            };
+            if tokio::time::timeout(logon_timeout, logon).await.is_err() {
+                fix.close().await?;
+                anyhow::bail!("Timed out logging on to FIX drop copy server");
+            }
+            anyhow::Ok(())
+        })?;
+
+        log::info!("Logged on to FIX drop copy server");
+        *self.fix.lock().expect("FIX client mutex poisoned") = fix;
+        Ok(())
+    }
+
+    fn order_status_reports_for(
+        &self,
+        instrument_id: Option<InstrumentId>,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        let account_id = self.core.account_id;
+        let symbols = self.symbols_for(instrument_id);
+        let http = &self.http;
+
+        block_on(async {
+            let mut reports = Vec::new();
+            for symbol in symbols {
+                reports.extend(
+                    http.request_order_status_reports(account_id, symbol)
+                        .await?,
+                );
+            }
+            Ok(reports)
+        })
+    }
+
+    fn fill_reports_for(
+        &self,
+        instrument_id: Option<InstrumentId>,
+        start: Option<UnixNanos>,
+    ) -> anyhow::Result<Vec<FillReport>> {
+        let account_id = self.core.account_id;
+        let start = start.map(|t| t.to_datetime_utc());
+
+        let mut reports = block_on(self.http.request_fill_reports(account_id, None, start))?;
+        if let Some(instrument_id) = instrument_id {
+            reports.retain(|r| r.instrument_id == instrument_id);
+        }
+        Ok(reports)
+    }
+
+    fn position_status_reports_for(
+        &self,
+        instrument_id: Option<InstrumentId>,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        let account_id = self.core.account_id;
+
+        match instrument_id {
+            Some(instrument_id) => {
+                let report = block_on(
+                    self.http
+                        .request_position_status_report(account_id, instrument_id.symbol),
+                )?;
+                Ok(vec![report])
+            }
+            None => block_on(self.http.request_position_status_reports(account_id)),
+        }
+    }
+
+    fn symbols_for(&self, instrument_id: Option<InstrumentId>) -> Vec<Symbol> {
+        match instrument_id {
+            Some(instrument_id) => vec![instrument_id.symbol],
+            None => self
+                .http
+                .get_cached_symbols()
+                .into_iter()
+                .map(Symbol::from)
+                .collect(),
+        }
+    }
+}
+
+impl ExecutionClient for CoinbaseIntxExecutionClient {
+    fn is_connected(&self) -> bool {
+        self.is_connected.load(Ordering::Relaxed)
+    }
+
+    fn client_id(&self) -> ClientId {
+        self.core.client_id
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.core.account_id
+    }
+
+    fn venue(&self) -> Venue {
+        self.core.venue
+    }
+
+    fn oms_type(&self) -> OmsType {
+        self.core.oms_type
+    }
+
+    fn get_account(&self) -> Option<AccountAny> {
+        self.core.get_account()
+    }
+
+    fn generate_account_state(
+        &self,
+        balances: Vec<AccountBalance>,
+        margins: Vec<MarginBalance>,
+        reported: bool,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        self.core
+            .generate_account_state(balances, margins, reported, ts_event)
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        log::info!("Starting Coinbase International execution client");
+        self.connect()
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        log::info!("Stopping Coinbase International execution client");
+        self.disconnect()
+    }
+
+    fn submit_order(&self, cmd: &SubmitOrder) -> anyhow::Result<()> {
+        self.submit_single_order(cmd.strategy_id, &cmd.order);
+        Ok(())
+    }
+
+    fn submit_order_list(&self, cmd: &SubmitOrderList) -> anyhow::Result<()> {
+        // Coinbase International has no native order list support, submit individually
+        for order in &cmd.order_list.orders {
+            self.submit_single_order(cmd.strategy_id, order);
+        }
+        Ok(())
+    }
+
+    fn modify_order(&self, cmd: &ModifyOrder) -> anyhow::Result<()> {
+        // Modifying an order on Coinbase International assigns it a new client order ID,
+        // which does not map to the Nautilus domain model, so use cancel and replace instead
+        log::error!(
+            "Cannot modify order {}: Coinbase International requires a new client order ID",
+            cmd.client_order_id,
+        );
+        self.emitter.order_modify_rejected(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            cmd.venue_order_id,
+            "UNSUPPORTED: modify requires a new client order ID, use cancel and replace",
+        );
+
+        Ok(())
+    }
+
+    fn cancel_order(&self, cmd: &CancelOrder) -> anyhow::Result<()> {
+        let http = self.http.clone();
+        let emitter = self.emitter.clone();
+        let account_id = self.core.account_id;
+        let cmd = cmd.clone();
+
+        get_runtime().spawn(async move {
+            match http.cancel_order(account_id, cmd.client_order_id).await {
+                Ok(report) => emitter.order_canceled(
+                    cmd.strategy_id,
+                    cmd.instrument_id,
+                    cmd.client_order_id,
+                    report.venue_order_id,
+                    report.ts_last,
+                ),
+                Err(e) => emitter.order_cancel_rejected(
+                    cmd.strategy_id,
+                    cmd.instrument_id,
+                    cmd.client_order_id,
+                    cmd.venue_order_id,
+                    &e.to_string(),
+                ),
+            }
+        });
+
+        Ok(())
+    }
+
+    fn cancel_all_orders(&self, cmd: &CancelAllOrders) -> anyhow::Result<()> {
+        let http = self.http.clone();
+        let emitter = self.emitter.clone();
+        let account_id = self.core.account_id;
+        let strategy_id = cmd.strategy_id;
+        let instrument_id = cmd.instrument_id;
+        let order_side = match cmd.order_side {
+            OrderSide::NoOrderSide => None,
+            side => Some(side),
+        };
+
+        get_runtime().spawn(async move {
+            match http
+                .cancel_orders(account_id, instrument_id.symbol, order_side)
+                .await
+            {
+                Ok(reports) => {
+                    for report in reports {
+                        if let Some(client_order_id) = report.client_order_id {
+                            emitter.order_canceled(
+                                strategy_id,
+                                instrument_id,
+                                client_order_id,
+                                report.venue_order_id,
+                                report.ts_last,
+                            );
+                        }
+                    }
+                }
+                Err(e) => log::error!("Failed to cancel all orders for {instrument_id}: {e}"),
+            }
+        });
+
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&self, cmd: &BatchCancelOrders) -> anyhow::Result<()> {
+        for cancel in &cmd.cancels {
+            self.cancel_order(cancel)?;
+        }
+        Ok(())
+    }
+
+    fn query_order(&self, cmd: &QueryOrder) -> anyhow::Result<()> {
+        let http = self.http.clone();
+        let emitter = self.emitter.clone();
+        let account_id = self.core.account_id;
+        let venue_order_id = cmd.venue_order_id;
+
+        get_runtime().spawn(async move {
+            match http
+                .request_order_status_report(account_id, venue_order_id)
+                .await
+            {
+                Ok(report) => emitter.send(ExecutionEvent::OrderStatusReport(report)),
+                Err(e) => log::error!("Failed to query order {venue_order_id}: {e}"),
+            }
+        });
+
+        Ok(())
+    }
+}
+
+impl LiveExecutionClient for CoinbaseIntxExecutionClient {
+    fn connect(&self) -> anyhow::Result<()> {
+        if self.is_connected() {
+            return Ok(());
+        }
+
+        let account_id = self.core.account_id;
+        let http = &self.http;
+        let account_state = block_on(async {
+            let instruments = http.request_instruments().await?;
+            log::info!("Loaded {} instruments", instruments.len());
+
+            // Instrument cache is shared between clones of the HTTP client
+            http.clone().add_instruments(instruments);
+
+            http.request_account_state(account_id).await
+        })?;
+
+        self.emitter.send(ExecutionEvent::Account(account_state));
+
+        if self.config.use_fix_drop_copy {
+            self.connect_fix()?;
+        }
+
+        self.is_connected.store(true, Ordering::Relaxed);
+        log::info!("Connected Coinbase International execution client ({account_id})");
+        Ok(())
+    }
+
+    fn disconnect(&self) -> anyhow::Result<()> {
+        let mut fix = self.fix.lock().expect("FIX client mutex poisoned").clone();
+        if fix.is_connected() || fix.is_logged_on() {
+            block_on(fix.close())?;
+            log::info!("Disconnected from FIX drop copy server");
+        }
+
+        self.is_connected.store(false, Ordering::Relaxed);
+        log::info!("Disconnected Coinbase International execution client");
+        Ok(())
+    }
+
+    fn generate_order_status_report(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Option<OrderStatusReport>> {
+        let account_id = self.core.account_id;
+        let report = match (cmd.venue_order_id, cmd.client_order_id) {
+            (Some(venue_order_id), _) => block_on(
+                self.http
+                    .request_order_status_report(account_id, venue_order_id),
+            )?,
+            (None, Some(client_order_id)) => block_on(
+                self.http
+                    .request_order_status_report_by_client_order_id(account_id, client_order_id),
+            )?,
+            (None, None) => {
+                log::warn!("Cannot generate order status report without an order ID");
+                return Ok(None);
+            }
+        };
+        Ok(Some(report))
+    }
+
+    fn generate_order_status_reports(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        self.order_status_reports_for(cmd.instrument_id)
+    }
+
+    fn generate_fill_reports(
+        &self,
+        report: GenerateFillReports,
+    ) -> anyhow::Result<Vec<FillReport>> {
+        self.fill_reports_for(report.instrument_id, report.start)
+    }
+
+    fn generate_position_status_reports(
+        &self,
+        cmd: &GeneratePositionReports,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        self.position_status_reports_for(cmd.instrument_id)
+    }
+
+    fn generate_mass_status(
+        &self,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Option<ExecutionMassStatus>> {
+        log::info!("Generating execution mass status");
+
+        let ts_now = get_atomic_clock_realtime().get_time_ns();
+        let start = lookback_mins
+            .map(|mins| UnixNanos::from(ts_now.as_u64().saturating_sub(mins * 60 * 1_000_000_000)));
+
+        let order_reports = self.order_status_reports_for(None)?;
+        let fill_reports = self.fill_reports_for(None, start)?;
+        let position_reports = self.position_status_reports_for(None)?;
+
+        let mut mass_status = ExecutionMassStatus::new(
+            self.core.client_id,
+            self.core.account_id,
+            self.core.venue,
+            ts_now,
+            None,
+        );
+        mass_status.add_order_reports(order_reports);
+        mass_status.add_fill_reports(fill_reports);
+        mass_status.add_position_reports(position_reports);
+
+        Ok(Some(mass_status))
+    }
+}
+
+/// Reinitializes the prices and quantities of a FIX drop copy report at the instrument precision.
+///
+/// The FIX parser uses a default precision of 8, as it has no access to instrument definitions.
+fn rescale_fix_report(
+    http: &CoinbaseIntxHttpClient,
+    event: ExecutionEvent,
+) -> Option<ExecutionEvent> {
+    let instrument_id = match &event {
+        ExecutionEvent::OrderStatusReport(report) => report.instrument_id,
+        ExecutionEvent::FillReport(report) => report.instrument_id,
+        _ => return Some(event),
+    };
+    let Some(instrument) = http.get_instrument(&instrument_id.symbol.inner()) else {
+        log::error!("Cannot process FIX execution report: no instrument found for {instrument_id}");
+        return None;
+    };
+
+    match event {
+        ExecutionEvent::OrderStatusReport(mut report) => {
+            report.quantity = instrument.make_qty(report.quantity.as_f64(), None);
+            report.filled_qty = instrument.make_qty(report.filled_qty.as_f64(), None);
+            report.price = report.price.map(|p| instrument.make_price(p.as_f64()));
+            report.trigger_price = report
+                .trigger_price
+                .map(|p| instrument.make_price(p.as_f64()));
+            Some(ExecutionEvent::OrderStatusReport(report))
+        }
+        ExecutionEvent::FillReport(mut report) => {
+            report.last_qty = instrument.make_qty(report.last_qty.as_f64(), None);
+            report.last_px = instrument.make_price(report.last_px.as_f64());
+            Some(ExecutionEvent::FillReport(report))
+        }
+        event => Some(event),
+    }
+}
+
+/// Runs the future to completion from a synchronous context.
+///
+/// Within a multi-threaded runtime the worker is handed off with `block_in_place`, which is not
+/// permitted on a current-thread runtime, so there the future is driven on a scoped thread.
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| handle.block_on(future))
+        }
+        Ok(_) => std::thread::scope(|scope| {
+            scope
+                .spawn(|| get_runtime().block_on(future))
+                .join()
+                .expect("Thread driving future panicked")
+        }),
+        Err(_) => get_runtime().block_on(future),
+    }
+}
+
+/// Builds order events from spawned tasks and sends them to the `AsyncRunner`.
+#[derive(Debug, Clone)]
+struct OrderEventEmitter {
+    trader_id: TraderId,
+    account_id: AccountId,
+    sender: UnboundedSender<ExecutionEvent>,
+}
+
+impl OrderEventEmitter {
+    fn send(&self, event: ExecutionEvent) {
+        if let Err(e) = self.sender.send(event) {
+            log::error!("Failed to send execution event: {e}");
+        }
+    }
+
+    fn send_order_event(&self, event: OrderEventAny) {
+        self.send(ExecutionEvent::Order(event));
+    }
+
+    fn order_accepted(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) {
+        let event = OrderAccepted::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            self.account_id,
+            UUID4::new(),
+            ts_event,
+            get_atomic_clock_realtime().get_time_ns(),
+            false,
+        );
+        self.send_order_event(OrderEventAny::Accepted(event));
+    }
+
+    fn order_rejected(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        reason: &str,
+    ) {
+        let ts_now = get_atomic_clock_realtime().get_time_ns();
+        let event = OrderRejected::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            self.account_id,
+            reason.into(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+        );
+        self.send_order_event(OrderEventAny::Rejected(event));
+    }
+
+    fn order_modify_rejected(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        reason: &str,
+    ) {
+        let ts_now = get_atomic_clock_realtime().get_time_ns();
+        let event = OrderModifyRejected::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            reason.into(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        );
+        self.send_order_event(OrderEventAny::ModifyRejected(event));
+    }
+
+    fn order_canceled(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) {
+        let event = OrderCanceled::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_event,
+            get_atomic_clock_realtime().get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        );
+        self.send_order_event(OrderEventAny::Canceled(event));
+    }
+
+    fn order_cancel_rejected(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        reason: &str,
+    ) {
+        let ts_now = get_atomic_clock_realtime().get_time_ns();
+        let event = OrderCancelRejected::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            reason.into(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        );
+        self.send_order_event(OrderEventAny::CancelRejected(event));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+#[cfg(target_os = "linux")] // Only run network tests on Linux (CI stability)
+mod tests {
+    use std::{cell::RefCell, net::TcpListener, rc::Rc, time::Duration};
+
+    use axum::{
+        Router,
+        routing::{get, post},
+        serve,
+    };
+    use nautilus_common::{cache::Cache, clock::TestClock, runner::set_exec_event_sender};
+    use nautilus_model::{
+        enums::{AccountType, LiquiditySide, OrderType},
+        identifiers::TradeId,
+        orders::builder::OrderTestBuilder,
+        types::{Money, Price, Quantity},
+    };
+    use rstest::rstest;
+    use tokio::sync::mpsc::UnboundedReceiver;
+
+    use super::*;
+    use crate::common::{consts::COINBASE_INTX_VENUE, testing::load_test_json};
+
+    const PORTFOLIO_ID: &str = "3mnk39ap-1-21";
+
+    fn get_unique_port() -> u16 {
+        // Create a temporary TcpListener to get an available port
+        let listener =
+            TcpListener::bind("127.0.0.1:0").expect("Failed to bind temporary TcpListener");
+        let port = listener.local_addr().unwrap().port();
+
+        // Close the listener to free up the port
+        drop(listener);
+
+        port
+    }
+
+    fn paginated(results: &str) -> String {
+        format!(
+            r#"{{"pagination":{{"ref_datetime":null,"result_limit":100,"result_offset":0}},"results":{results}}}"#
+        )
+    }
+
+    fn create_router() -> Router {
+        let instruments = format!("[{}]", load_test_json("http_get_instruments_ETH-PERP.json"));
+        let balances = load_test_json("http_get_portfolios_balances.json");
+        let order = load_test_json("http_post_orders.json");
+        let submitted = order.clone();
+        let open_orders = paginated(&format!("[{order}]"));
+        let canceled = load_test_json("http_delete_orders.json");
+        let positions = format!(
+            "[{}]",
+            load_test_json("http_get_portfolios_positions_ETH-PERP.json")
+        );
+        let fills = paginated("[]");
+
+        Router::new()
+            .route("/api/v1/instruments", get(|| async { instruments }))
+            .route(
+                "/api/v1/portfolios/{portfolio_id}/balances",
+                get(|| async { balances }),
+            )
+            .route(
+                "/api/v1/portfolios/{portfolio_id}/positions",
+                get(|| async { positions }),
+            )
+            .route(
+                "/api/v1/portfolios/{portfolio_id}/fills",
+                get(|| async { fills }),
+            )
+            .route(
+                "/api/v1/orders",
+                post(|| async { submitted }).get(|| async { open_orders }),
+            )
+            .route(
+                "/api/v1/orders/{order_id}",
+                get(|| async { order }).delete(|| async { canceled }),
+            )
+    }
+
+    fn start_test_server() -> String {
+        let port = get_unique_port();
+        let listener = get_runtime()
+            .block_on(tokio::net::TcpListener::bind(format!("127.0.0.1:{port}")))
+            .unwrap();
+
+        get_runtime().spawn(async move {
+            serve(listener, create_router()).await.unwrap();
+        });
+
+        format!("http://127.0.0.1:{port}")
+    }
+
+    fn create_client() -> (
+        CoinbaseIntxExecutionClient,
+        UnboundedReceiver<ExecutionEvent>,
+    ) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        set_exec_event_sender(tx);
+
+        let mut config = CoinbaseIntxExecClientConfig::new(TraderId::default());
+        config.portfolio_id = Some(PORTFOLIO_ID.to_string());
+        config.api_key = Some("key".to_string());
+        config.api_secret = Some("c2VjcmV0".to_string());
+        config.api_passphrase = Some("passphrase".to_string());
+        config.base_url_http = Some(start_test_server());
+        config.use_fix_drop_copy = false;
+
+        let core = BaseExecutionClient::new(
+            config.trader_id,
+            ClientId::from("COINBASE_INTX"),
+            *COINBASE_INTX_VENUE,
+            OmsType::Netting,
+            config.account_id().unwrap(),
+            AccountType::Margin,
+            None,
+            Rc::new(RefCell::new(TestClock::new())),
+            Rc::new(RefCell::new(Cache::default())),
+        );
+
+        let client = CoinbaseIntxExecutionClient::new(core, config).unwrap();
+        (client, rx)
+    }
+
+    fn recv_event(rx: &mut UnboundedReceiver<ExecutionEvent>) -> ExecutionEvent {
+        get_runtime()
+            .block_on(async { tokio::time::timeout(Duration::from_secs(5), rx.recv()).await })
+            .expect("Timed out waiting for execution event")
+            .expect("Execution event channel closed")
+    }
+
+    #[rstest]
+    fn test_connect_sends_account_state() {
+        let (client, mut rx) = create_client();
+
+        client.connect().unwrap();
+
+        assert!(client.is_connected());
+        match recv_event(&mut rx) {
+            ExecutionEvent::Account(state) => {
+                assert_eq!(state.account_id, client.account_id());
+                assert!(!state.balances.is_empty());
+            }
+            other => panic!("Expected account state, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_submit_order_sends_accepted() {
+        let (client, mut rx) = create_client();
+        client.connect().unwrap();
+        let _ = recv_event(&mut rx); // Account state
+
+        let instrument_id = InstrumentId::from("ETH-PERP.COINBASE_INTX");
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id)
+            .client_order_id(ClientOrderId::from("f346ca69-11b4-4e1b-ae47-85971290c771"))
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from("0.01"))
+            .price(Price::from("3000.00"))
+            .build();
+        let cmd = SubmitOrder::new(
+            client.core.trader_id,
+            client.client_id(),
+            order.strategy_id(),
+            instrument_id,
+            order.client_order_id(),
+            VenueOrderId::from("1"),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        client.submit_order(&cmd).unwrap();
+
+        match recv_event(&mut rx) {
+            ExecutionEvent::Order(OrderEventAny::Accepted(event)) => {
+                assert_eq!(event.client_order_id, cmd.client_order_id);
+                assert_eq!(event.venue_order_id, VenueOrderId::from("2v2ckc1g-1-0"));
+                assert_eq!(event.account_id, client.account_id());
+            }
+            other => panic!("Expected order accepted, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_cancel_order_sends_canceled() {
+        let (client, mut rx) = create_client();
+        client.connect().unwrap();
+        let _ = recv_event(&mut rx); // Account state
+
+        let cmd = CancelOrder::new(
+            client.core.trader_id,
+            client.client_id(),
+            StrategyId::default(),
+            InstrumentId::from("ETH-PERP.COINBASE_INTX"),
+            ClientOrderId::from("f346ca69-11b4-4e1b-ae47-85971290c771"),
+            VenueOrderId::from("2v2ckc1g-1-0"),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        client.cancel_order(&cmd).unwrap();
+
+        match recv_event(&mut rx) {
+            ExecutionEvent::Order(OrderEventAny::Canceled(event)) => {
+                assert_eq!(event.client_order_id, cmd.client_order_id);
+                assert_eq!(event.venue_order_id, Some(cmd.venue_order_id));
+            }
+            other => panic!("Expected order canceled, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_modify_order_sends_modify_rejected() {
+        let (client, mut rx) = create_client();
+
+        let cmd = ModifyOrder::new(
+            client.core.trader_id,
+            client.client_id(),
+            StrategyId::default(),
+            InstrumentId::from("ETH-PERP.COINBASE_INTX"),
+            ClientOrderId::from("f346ca69-11b4-4e1b-ae47-85971290c771"),
+            VenueOrderId::from("2v2ckc1g-1-0"),
+            None,
+            Some(Price::from("3100.00")),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        client.modify_order(&cmd).unwrap();
+
+        match recv_event(&mut rx) {
+            ExecutionEvent::Order(OrderEventAny::ModifyRejected(event)) => {
+                assert_eq!(event.client_order_id, cmd.client_order_id);
+                assert_eq!(event.venue_order_id, Some(cmd.venue_order_id));
+            }
+            other => panic!("Expected order modify rejected, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_generate_order_status_report_by_client_order_id() {
+        let (client, _rx) = create_client();
+        client.connect().unwrap();
+
+        let client_order_id = ClientOrderId::from("f346ca69-11b4-4e1b-ae47-85971290c771");
+        let cmd = GenerateOrderStatusReport::new(
+            UUID4::new(),
+            UnixNanos::default(),
+            Some(InstrumentId::from("ETH-PERP.COINBASE_INTX")),
+            Some(client_order_id),
+            None,
+        );
+
+        let report = client.generate_order_status_report(&cmd).unwrap().unwrap();
+
+        assert_eq!(report.client_order_id, Some(client_order_id));
+        assert_eq!(report.venue_order_id, VenueOrderId::from("2v2ckc1g-1-0"));
+    }
+
+    #[rstest]
+    fn test_generate_mass_status_on_current_thread_runtime() {
+        let (client, _rx) = create_client();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        let mass_status = runtime.block_on(async {
+            client.connect().unwrap();
+            client.generate_mass_status(Some(60)).unwrap().unwrap()
+        });
+
+        assert_eq!(mass_status.order_reports().len(), 1);
+    }
+
+    #[rstest]
+    fn test_rescale_fix_fill_report_to_instrument_precision() {
+        let (client, _rx) = create_client();
+        client.connect().unwrap();
+
+        let report = FillReport::new(
+            client.account_id(),
+            InstrumentId::from("ETH-PERP.COINBASE_INTX"),
+            VenueOrderId::from("2v2ckc1g-1-0"),
+            TradeId::from("1"),
+            OrderSide::Sell,
+            Quantity::new(0.01, 8),
+            Price::new(3000.0, 8),
+            Money::from("0.1 USDC"),
+            LiquiditySide::Maker,
+            Some(ClientOrderId::from("f346ca69-11b4-4e1b-ae47-85971290c771")),
+            None,
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        );
+
+        match rescale_fix_report(&client.http, ExecutionEvent::FillReport(report)) {
+            Some(ExecutionEvent::FillReport(report)) => {
+                assert_eq!(report.last_qty, Quantity::from("0.0100"));
+                assert_eq!(report.last_px, Price::from("3000.00"));
+            }
+            other => panic!("Expected fill report, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_rescale_fix_report_unknown_instrument() {
+        let (client, _rx) = create_client();
+
+        let report = FillReport::new(
+            client.account_id(),
+            InstrumentId::from("BTC-PERP.COINBASE_INTX"),
+            VenueOrderId::from("1"),
+            TradeId::from("1"),
+            OrderSide::Buy,
+            Quantity::new(1.0, 8),
+            Price::new(1.0, 8),
+            Money::from("0 USDC"),
+            LiquiditySide::Taker,
+            None,
+            None,
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        );
+
+        assert!(rescale_fix_report(&client.http, ExecutionEvent::FillReport(report)).is_none());
+    }
+
+    #[rstest]
+    fn test_generate_mass_status() {
+        let (client, _rx) = create_client();
+        client.connect().unwrap();
+
+        let mass_status = client.generate_mass_status(Some(60)).unwrap().unwrap();
+
+        assert_eq!(mass_status.account_id, client.account_id());
+        assert_eq!(mass_status.order_reports().len(), 1);
+        assert!(mass_status.fill_reports().is_empty());
+        assert_eq!(mass_status.position_reports().len(), 1);
+    }
+}
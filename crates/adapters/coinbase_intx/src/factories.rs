@@ -0,0 +1,201 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Factory functions for creating Coinbase International clients for use with `LiveNode`.
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use nautilus_common::{cache::Cache, clock::Clock};
+use nautilus_data::client::DataClient;
+use nautilus_execution::client::{ExecutionClient, base::BaseExecutionClient};
+use nautilus_model::{
+    enums::{AccountType, OmsType},
+    identifiers::ClientId,
+};
+use nautilus_system::factories::{ClientConfig, DataClientFactory, ExecutionClientFactory};
+
+use crate::{
+    common::consts::{COINBASE_INTX, COINBASE_INTX_VENUE},
+    config::{CoinbaseIntxDataClientConfig, CoinbaseIntxExecClientConfig},
+    data::CoinbaseIntxDataClient,
+    execution::CoinbaseIntxExecutionClient,
+};
+
+impl ClientConfig for CoinbaseIntxDataClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl ClientConfig for CoinbaseIntxExecClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Factory for creating Coinbase International data clients.
+#[derive(Debug, Default)]
+pub struct CoinbaseIntxDataClientFactory;
+
+impl CoinbaseIntxDataClientFactory {
+    /// Creates a new [`CoinbaseIntxDataClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl DataClientFactory for CoinbaseIntxDataClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        _cache: Rc<RefCell<Cache>>,
+        _clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn DataClient>> {
+        let config = config
+            .as_any()
+            .downcast_ref::<CoinbaseIntxDataClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for CoinbaseIntxDataClientFactory. Expected CoinbaseIntxDataClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        let client = CoinbaseIntxDataClient::new(ClientId::from(name), config.clone())?;
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        COINBASE_INTX
+    }
+
+    fn config_type(&self) -> &'static str {
+        "CoinbaseIntxDataClientConfig"
+    }
+}
+
+/// Factory for creating Coinbase International execution clients.
+#[derive(Debug, Default)]
+pub struct CoinbaseIntxExecutionClientFactory;
+
+impl CoinbaseIntxExecutionClientFactory {
+    /// Creates a new [`CoinbaseIntxExecutionClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ExecutionClientFactory for CoinbaseIntxExecutionClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        cache: Rc<RefCell<Cache>>,
+        clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn ExecutionClient>> {
+        let config = config
+            .as_any()
+            .downcast_ref::<CoinbaseIntxExecClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for CoinbaseIntxExecutionClientFactory. Expected CoinbaseIntxExecClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        // Coinbase International only supports netting margin accounts for derivatives
+        let core = BaseExecutionClient::new(
+            config.trader_id,
+            ClientId::from(name),
+            *COINBASE_INTX_VENUE,
+            OmsType::Netting,
+            config.account_id()?,
+            AccountType::Margin,
+            None,
+            clock,
+            cache,
+        );
+
+        let client = CoinbaseIntxExecutionClient::new(core, config.clone())?;
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        COINBASE_INTX
+    }
+
+    fn config_type(&self) -> &'static str {
+        "CoinbaseIntxExecClientConfig"
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::clock::TestClock;
+    use nautilus_model::identifiers::TraderId;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct OtherConfig;
+
+    impl ClientConfig for OtherConfig {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    fn exec_config() -> CoinbaseIntxExecClientConfig {
+        let mut config = CoinbaseIntxExecClientConfig::new(TraderId::default());
+        config.portfolio_id = Some("5189861793641175".to_string());
+        config.api_key = Some("key".to_string());
+        config.api_secret = Some("c2VjcmV0".to_string());
+        config.api_passphrase = Some("passphrase".to_string());
+        config
+    }
+
+    #[rstest]
+    fn test_exec_config_account_id() {
+        let account_id = exec_config().account_id().unwrap();
+        assert_eq!(account_id.as_str(), "COINBASE_INTX-5189861793641175");
+    }
+
+    #[rstest]
+    fn test_factory_names() {
+        assert_eq!(CoinbaseIntxDataClientFactory::new().name(), "COINBASE_INTX");
+        assert_eq!(
+            CoinbaseIntxExecutionClientFactory::new().name(),
+            "COINBASE_INTX"
+        );
+    }
+
+    #[rstest]
+    fn test_exec_factory_rejects_invalid_config() {
+        let factory = CoinbaseIntxExecutionClientFactory::new();
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+
+        let result = factory.create("COINBASE_INTX", &OtherConfig, cache, clock);
+
+        assert!(result.is_err());
+    }
+}
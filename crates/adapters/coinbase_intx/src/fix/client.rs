@@ -31,10 +31,14 @@ use std::{
 };
 
 use base64::prelude::*;
-use nautilus_common::logging::{log_task_started, log_task_stopped};
+use nautilus_common::{
+    logging::{log_task_started, log_task_stopped},
+    messages::ExecutionEvent,
+};
 #[cfg(feature = "python")]
 use nautilus_core::python::IntoPyObjectNautilusExt;
-use nautilus_core::{env::get_env_var, time::get_atomic_clock_realtime};
+use nautilus_core::{env::get_or_env_var, time::get_atomic_clock_realtime};
+use nautilus_cryptography::secrets::get_or_resolve_secret;
 use nautilus_model::identifiers::AccountId;
 use nautilus_network::socket::{SocketClient, SocketConfig, WriterCommand};
 #[cfg(feature = "python")]
@@ -92,10 +96,10 @@ impl CoinbaseIntxFixClient {
         portfolio_id: Option<String>,
     ) -> anyhow::Result<Self> {
         let endpoint = endpoint.unwrap_or("fix.international.coinbase.com:6130".to_string());
-        let api_key = api_key.unwrap_or(get_env_var("COINBASE_INTX_API_KEY")?);
-        let api_secret = api_secret.unwrap_or(get_env_var("COINBASE_INTX_API_SECRET")?);
-        let api_passphrase = api_passphrase.unwrap_or(get_env_var("COINBASE_INTX_API_PASSPHRASE")?);
-        let portfolio_id = portfolio_id.unwrap_or(get_env_var("COINBASE_INTX_PORTFOLIO_ID")?);
+        let api_key = get_or_resolve_secret(api_key, "COINBASE_INTX_API_KEY")?;
+        let api_secret = get_or_resolve_secret(api_secret, "COINBASE_INTX_API_SECRET")?;
+        let api_passphrase = get_or_resolve_secret(api_passphrase, "COINBASE_INTX_API_PASSPHRASE")?;
+        let portfolio_id = get_or_env_var(portfolio_id, "COINBASE_INTX_PORTFOLIO_ID")?;
         let sender_comp_id = api_key.to_string();
         let target_comp_id = "CBINTLDC".to_string(); // Drop Copy endpoint
 
@@ -179,11 +183,35 @@ impl CoinbaseIntxFixClient {
     /// # Errors
     ///
     /// Returns an error if network connection or FIX logon fails.
-    pub async fn connect(
-        &mut self,
-        #[cfg(feature = "python")] handler: PyObject,
-        #[cfg(not(feature = "python"))] _handler: (),
-    ) -> anyhow::Result<()> {
+    #[cfg(feature = "python")]
+    pub async fn connect(&mut self, handler: PyObject) -> anyhow::Result<()> {
+        self.connect_with_handler(move |event| {
+            Python::with_gil(|py| {
+                let py_obj = match event {
+                    ExecutionEvent::OrderStatusReport(report) => report.into_py_any_unwrap(py),
+                    ExecutionEvent::FillReport(report) => report.into_py_any_unwrap(py),
+                    _ => return,
+                };
+                call_python(py, &handler, py_obj);
+            });
+        })
+        .await
+    }
+
+    /// Connects to the Coinbase International FIX Drop Copy endpoint, passing each order
+    /// status and fill report parsed from execution reports to the `handler`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if time calculation or unwrap logic inside fails during logon retry setup.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if network connection or FIX logon fails.
+    pub async fn connect_with_handler<F>(&mut self, handler: F) -> anyhow::Result<()>
+    where
+        F: Fn(ExecutionEvent) + Send + Sync + 'static,
+    {
         let config = SocketConfig {
             url: self.endpoint.clone(),
             mode: Mode::Tls,
@@ -245,19 +273,8 @@ impl CoinbaseIntxFixClient {
                                     match convert_to_order_status_report(
                                         &message, account_id, ts_init,
                                     ) {
-                                        #[cfg(feature = "python")]
-                                        Ok(report) => Python::with_gil(|py| {
-                                            call_python(
-                                                py,
-                                                &handler,
-                                                report.into_py_any_unwrap(py),
-                                            );
-                                        }),
-                                        #[cfg(not(feature = "python"))]
-                                        Ok(_report) => {
-                                            tracing::debug!(
-                                                "Order status report handled (Python disabled)"
-                                            );
+                                        Ok(report) => {
+                                            handler(ExecutionEvent::OrderStatusReport(report));
                                         }
                                         Err(e) => {
                                             tracing::error!(
@@ -271,20 +288,7 @@ impl CoinbaseIntxFixClient {
                                     let clock = get_atomic_clock_realtime(); // TODO: Optimize
                                     let ts_init = clock.get_time_ns();
                                     match convert_to_fill_report(&message, account_id, ts_init) {
-                                        #[cfg(feature = "python")]
-                                        Ok(report) => Python::with_gil(|py| {
-                                            call_python(
-                                                py,
-                                                &handler,
-                                                report.into_py_any_unwrap(py),
-                                            );
-                                        }),
-                                        #[cfg(not(feature = "python"))]
-                                        Ok(_report) => {
-                                            tracing::debug!(
-                                                "Fill report handled (Python disabled)"
-                                            );
-                                        }
+                                        Ok(report) => handler(ExecutionEvent::FillReport(report)),
                                         Err(e) => {
                                             tracing::error!(
                                                 "Failed to parse FIX execution report: {e}"
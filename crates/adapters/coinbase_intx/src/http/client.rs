@@ -27,9 +27,8 @@ use std::{
 };
 
 use chrono::{DateTime, Utc};
-use nautilus_core::{
-    UnixNanos, consts::NAUTILUS_USER_AGENT, env::get_env_var, time::get_atomic_clock_realtime,
-};
+use nautilus_core::{UnixNanos, consts::NAUTILUS_USER_AGENT, time::get_atomic_clock_realtime};
+use nautilus_cryptography::secrets::get_or_resolve_secret;
 use nautilus_model::{
     enums::{OrderSide, OrderType, TimeInForce},
     events::AccountState,
@@ -630,9 +629,9 @@ impl CoinbaseIntxHttpClient {
         base_url: Option<String>,
         timeout_secs: Option<u64>,
     ) -> anyhow::Result<Self> {
-        let api_key = api_key.unwrap_or(get_env_var("COINBASE_INTX_API_KEY")?);
-        let api_secret = api_secret.unwrap_or(get_env_var("COINBASE_INTX_API_SECRET")?);
-        let api_passphrase = api_passphrase.unwrap_or(get_env_var("COINBASE_INTX_API_PASSPHRASE")?);
+        let api_key = get_or_resolve_secret(api_key, "COINBASE_INTX_API_KEY")?;
+        let api_secret = get_or_resolve_secret(api_secret, "COINBASE_INTX_API_SECRET")?;
+        let api_passphrase = get_or_resolve_secret(api_passphrase, "COINBASE_INTX_API_PASSPHRASE")?;
         let base_url = base_url.unwrap_or(COINBASE_INTX_REST_URL.to_string());
         Ok(Self {
             inner: Arc::new(CoinbaseIntxHttpInnerClient::with_credentials(
@@ -693,6 +692,16 @@ impl CoinbaseIntxHttpClient {
             .collect()
     }
 
+    /// Returns the cached instrument for the given symbol, if available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instrument cache mutex is poisoned.
+    #[must_use]
+    pub fn get_instrument(&self, symbol: &Ustr) -> Option<InstrumentAny> {
+        self.instruments_cache.lock().unwrap().get(symbol).cloned()
+    }
+
     /// Adds the given instruments into the clients instrument cache.
     ///
     /// # Panics
@@ -815,12 +824,36 @@ impl CoinbaseIntxHttpClient {
         &self,
         account_id: AccountId,
         venue_order_id: VenueOrderId,
+    ) -> anyhow::Result<OrderStatusReport> {
+        self.request_order_status_report_for(account_id, venue_order_id.as_str())
+            .await
+    }
+
+    /// Requests an order status report for the given client order ID from Coinbase International.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails or the response cannot be parsed.
+    pub async fn request_order_status_report_by_client_order_id(
+        &self,
+        account_id: AccountId,
+        client_order_id: ClientOrderId,
+    ) -> anyhow::Result<OrderStatusReport> {
+        // The get order endpoint accepts either the venue or client order ID
+        self.request_order_status_report_for(account_id, client_order_id.as_str())
+            .await
+    }
+
+    async fn request_order_status_report_for(
+        &self,
+        account_id: AccountId,
+        order_id: &str,
     ) -> anyhow::Result<OrderStatusReport> {
         let portfolio_id = account_id.get_issuers_id();
 
         let resp = self
             .inner
-            .http_get_order(venue_order_id.as_str(), portfolio_id)
+            .http_get_order(order_id, portfolio_id)
             .await
             .map_err(|e| anyhow::anyhow!(e))?;
 
@@ -1143,7 +1176,7 @@ impl CoinbaseIntxHttpClient {
             params.price(price.to_string());
         }
         if let Some(trigger_price) = trigger_price {
-            params.price(trigger_price.to_string());
+            params.stop_price(trigger_price.to_string());
         }
         if let Some(quantity) = quantity {
             params.size(quantity.to_string());
@@ -27,6 +27,7 @@
 //! for the [nautilus_trader](https://pypi.org/project/nautilus_trader) Python package,
 //! or as part of a Rust only build.
 //!
+//! - `live`: Enables the live `DataClient` and `ExecutionClient` implementations and their factories (default).
 //! - `python`: Enables Python bindings from [PyO3](https://pyo3.rs).
 
 #![warn(rustc::all)]
@@ -45,3 +46,12 @@ pub mod websocket;
 
 #[cfg(feature = "python")]
 pub mod python;
+
+#[cfg(feature = "live")]
+pub mod data;
+
+#[cfg(feature = "live")]
+pub mod execution;
+
+#[cfg(feature = "live")]
+pub mod factories;
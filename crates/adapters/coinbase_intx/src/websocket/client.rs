@@ -25,9 +25,8 @@ use std::{
 use chrono::Utc;
 use futures_util::{Stream, StreamExt};
 use nautilus_common::{logging::log_task_stopped, runtime::get_runtime};
-use nautilus_core::{
-    consts::NAUTILUS_USER_AGENT, env::get_env_var, time::get_atomic_clock_realtime,
-};
+use nautilus_core::{consts::NAUTILUS_USER_AGENT, time::get_atomic_clock_realtime};
+use nautilus_cryptography::secrets::get_or_resolve_secret;
 use nautilus_model::{
     data::{BarType, Data, OrderBookDeltas_API},
     identifiers::InstrumentId,
@@ -92,9 +91,9 @@ impl CoinbaseIntxWebSocketClient {
         heartbeat: Option<u64>,
     ) -> anyhow::Result<Self> {
         let url = url.unwrap_or(COINBASE_INTX_WS_URL.to_string());
-        let api_key = api_key.unwrap_or(get_env_var("COINBASE_INTX_API_KEY")?);
-        let api_secret = api_secret.unwrap_or(get_env_var("COINBASE_INTX_API_SECRET")?);
-        let api_passphrase = api_passphrase.unwrap_or(get_env_var("COINBASE_INTX_API_PASSPHRASE")?);
+        let api_key = get_or_resolve_secret(api_key, "COINBASE_INTX_API_KEY")?;
+        let api_secret = get_or_resolve_secret(api_secret, "COINBASE_INTX_API_SECRET")?;
+        let api_passphrase = get_or_resolve_secret(api_passphrase, "COINBASE_INTX_API_PASSPHRASE")?;
 
         let credential = Credential::new(api_key, api_secret, api_passphrase);
         let signal = Arc::new(AtomicBool::new(false));
@@ -183,6 +182,7 @@ impl CoinbaseIntxWebSocketClient {
             reconnect_delay_max_ms: None,     // Use default
             reconnect_backoff_factor: None,   // Use default
             reconnect_jitter_ms: None,        // Use default
+            recorder: None,
         };
         let (reader, client) =
             WebSocketClient::connect_stream(config, vec![], None, Some(post_reconnect)).await?;
@@ -38,6 +38,7 @@ use nautilus_common::{
     runner::get_data_event_sender,
 };
 use nautilus_core::time::AtomicTime;
+use nautilus_cryptography::secrets::resolve_secret;
 use nautilus_data::client::DataClient;
 use nautilus_model::{
     enums::BarAggregation,
@@ -61,7 +62,7 @@ use crate::{
 /// Configuration for the Databento data client.
 #[derive(Debug, Clone)]
 pub struct DatabentoDataClientConfig {
-    /// Databento API key.
+    /// Databento API key, or a `secret:` reference to a named secret.
     pub api_key: String,
     /// Path to publishers.json file.
     pub publishers_filepath: PathBuf,
@@ -129,9 +130,12 @@ impl DatabentoDataClient {
     /// Returns an error if client creation or publisher configuration loading fails.
     pub fn new(
         client_id: ClientId,
-        config: DatabentoDataClientConfig,
+        mut config: DatabentoDataClientConfig,
         clock: &'static AtomicTime,
     ) -> anyhow::Result<Self> {
+        // Resolve the API key if it references a named secret
+        config.api_key = resolve_secret(&config.api_key)?;
+
         let historical = DatabentoHistoricalClient::new(
             config.api_key.clone(),
             config.publishers_filepath.clone(),
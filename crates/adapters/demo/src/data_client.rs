@@ -80,6 +80,7 @@ impl MockDataClient {
             reconnect_delay_max_ms: None,
             reconnect_backoff_factor: None,
             reconnect_jitter_ms: None,
+            recorder: None,
         };
 
         let websocket_client = WebSocketClient::connect(config, None, None, None, Vec::new(), None)
@@ -13,9 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 use super::machine::types::ReplayNormalizedRequestOptions;
+use crate::enums::Exchange;
 
 /// Provides a configuration for a Tarid Machine -> Nautilus data -> Parquet replay run.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,3 +31,48 @@ pub struct TardisReplayConfig {
     /// The Tardis Machine replay options.
     pub options: Vec<ReplayNormalizedRequestOptions>,
 }
+
+/// Provides a configuration for a Tardis Machine data client.
+///
+/// When `replay` is set, subscriptions are served from Tardis Machine historical replays,
+/// otherwise they are streamed in real-time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TardisDataClientConfig {
+    /// The Tardis API key for loading instrument definitions (falls back to `TARDIS_API_KEY`).
+    pub api_key: Option<String>,
+    /// The Tardis HTTP API base url override.
+    pub base_url_http: Option<String>,
+    /// The Tardis Machine websocket url (falls back to `TARDIS_MACHINE_WS_URL`).
+    pub base_url_ws: Option<String>,
+    /// If symbols should be normalized with Nautilus conventions.
+    pub normalize_symbols: bool,
+    /// The exchanges to load instrument definitions for on connect.
+    pub exchanges: Vec<Exchange>,
+    /// The historical replay settings, if replaying rather than streaming.
+    pub replay: Option<TardisReplaySettings>,
+}
+
+impl Default for TardisDataClientConfig {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            base_url_http: None,
+            base_url_ws: None,
+            normalize_symbols: true,
+            exchanges: Vec::new(),
+            replay: None,
+        }
+    }
+}
+
+/// Provides the historical replay settings for a Tardis Machine data client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TardisReplaySettings {
+    /// The replay period start date (UTC).
+    pub from: NaiveDate,
+    /// The replay period end date (UTC).
+    pub to: NaiveDate,
+    /// The replay speed multiplier relative to wall-clock time (e.g. 1.0 for wall-clock,
+    /// 10.0 for ten times faster). If `None` then data is replayed as fast as possible.
+    pub speed: Option<f64>,
+}
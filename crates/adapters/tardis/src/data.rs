@@ -0,0 +1,1051 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Data client implementation for streaming and replaying data from a Tardis Machine server.
+
+use std::{
+    collections::HashMap,
+    env,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use futures_util::{StreamExt, pin_mut};
+use nautilus_common::{
+    messages::{
+        DataEvent,
+        data::{
+            SubscribeBars, SubscribeBookDeltas, SubscribeBookDepth10, SubscribeQuotes,
+            SubscribeTrades, UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeBookDepth10,
+            UnsubscribeQuotes, UnsubscribeTrades,
+        },
+    },
+    runner::get_data_event_sender,
+    runtime::get_runtime,
+};
+use nautilus_core::UnixNanos;
+use nautilus_data::client::DataClient;
+use nautilus_model::{
+    data::{BarType, Data, GetTsInit},
+    enums::{BarAggregation, BookType},
+    identifiers::{ClientId, InstrumentId, Venue},
+    instruments::{Instrument, InstrumentAny},
+};
+use tokio::{
+    sync::{mpsc::UnboundedSender, oneshot, watch},
+    time::Instant,
+};
+use ustr::Ustr;
+
+use crate::{
+    config::{TardisDataClientConfig, TardisReplaySettings},
+    enums::Exchange,
+    http::TardisHttpClient,
+    machine::{
+        client::handle_ws_stream,
+        message::WsMessage,
+        parse::parse_tardis_ws_message,
+        replay_normalized, stream_normalized,
+        types::{
+            InstrumentMiniInfo, ReplayNormalizedRequestOptions, StreamNormalizedRequestOptions,
+            TardisInstrumentKey,
+        },
+    },
+    parse::bar_spec_to_tardis_trade_bar_string,
+};
+
+/// Provides a data client for a [Tardis Machine Server](https://docs.tardis.dev/api/tardis-machine).
+///
+/// For real-time data each subscription opens its own `stream-normalized` websocket. When the
+/// config has replay settings, all subscriptions share a single `replay-normalized` websocket,
+/// which is reopened for the combined subscriptions whenever a subscription is added, skipping
+/// data already replayed. Replayed data is paced against a replay clock shared by all
+/// subscriptions, which starts at the replay `from` date when the client connects.
+#[derive(Debug)]
+pub struct TardisDataClient {
+    client_id: ClientId,
+    config: TardisDataClientConfig,
+    base_url_ws: String,
+    instruments: Mutex<HashMap<InstrumentId, Arc<InstrumentMiniInfo>>>,
+    subscriptions: Mutex<HashMap<(InstrumentId, String), Subscription>>,
+    replay: Mutex<Option<watch::Sender<ReplaySubscriptions>>>,
+    replay_start: Mutex<Option<Instant>>,
+    is_connected: AtomicBool,
+    data_sender: UnboundedSender<DataEvent>,
+}
+
+impl TardisDataClient {
+    /// Creates a new [`TardisDataClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `base_url_ws` is not configured and `TARDIS_MACHINE_WS_URL` env var is missing.
+    pub fn new(client_id: ClientId, config: TardisDataClientConfig) -> anyhow::Result<Self> {
+        let base_url_ws = config
+            .base_url_ws
+            .clone()
+            .or_else(|| env::var("TARDIS_MACHINE_WS_URL").ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Tardis Machine `base_url_ws` must be provided or set in the 'TARDIS_MACHINE_WS_URL' environment variable"
+                )
+            })?;
+
+        Ok(Self {
+            client_id,
+            config,
+            base_url_ws,
+            instruments: Mutex::new(HashMap::new()),
+            subscriptions: Mutex::new(HashMap::new()),
+            replay: Mutex::new(None),
+            replay_start: Mutex::new(None),
+            is_connected: AtomicBool::new(false),
+            data_sender: get_data_event_sender(),
+        })
+    }
+
+    /// Returns the configuration for the client.
+    #[must_use]
+    pub const fn config(&self) -> &TardisDataClientConfig {
+        &self.config
+    }
+
+    /// Adds the instrument definition information required to subscribe to and parse data
+    /// for the instrument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instruments lock is poisoned.
+    pub fn add_instrument_info(&self, info: InstrumentMiniInfo) {
+        self.instruments
+            .lock()
+            .unwrap()
+            .insert(info.instrument_id, Arc::new(info));
+    }
+
+    /// Returns whether there is an active subscription for the given Tardis data type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the subscriptions lock is poisoned.
+    #[must_use]
+    pub fn is_subscribed(&self, instrument_id: InstrumentId, data_type: &str) -> bool {
+        self.subscriptions
+            .lock()
+            .unwrap()
+            .contains_key(&(instrument_id, data_type.to_string()))
+    }
+
+    async fn load_instruments(&self) -> anyhow::Result<()> {
+        if self.config.exchanges.is_empty() {
+            return Ok(());
+        }
+
+        let http_client = TardisHttpClient::new(
+            self.config.api_key.as_deref(),
+            self.config.base_url_http.as_deref(),
+            None,
+            self.config.normalize_symbols,
+        )?;
+
+        for exchange in &self.config.exchanges {
+            let instruments = http_client
+                .instruments(exchange.clone(), None, None, None, None, None, None, None)
+                .await?;
+            tracing::info!("Loaded {} instruments for {exchange}", instruments.len());
+
+            for instrument in instruments {
+                self.add_instrument_info(instrument_mini_info(&instrument, exchange.clone()));
+                if let Err(e) = self.data_sender.send(DataEvent::Instrument(instrument)) {
+                    tracing::error!("Failed to send instrument event: {e}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn replay_clock(&self, settings: &TardisReplaySettings) -> Option<ReplayClock> {
+        let speed = settings.speed?;
+        let start = *self
+            .replay_start
+            .lock()
+            .unwrap()
+            .get_or_insert_with(Instant::now);
+
+        // SAFETY: Midnight is always a valid time
+        let from_ns = settings
+            .from
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap_or_default();
+
+        Some(ReplayClock {
+            start,
+            from: UnixNanos::from(from_ns as u64),
+            speed,
+        })
+    }
+
+    fn subscribe_stream(
+        &self,
+        instrument_id: InstrumentId,
+        data_type: String,
+        description: &str,
+    ) -> anyhow::Result<()> {
+        let key = (instrument_id, data_type.clone());
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        if subscriptions.contains_key(&key) {
+            tracing::warn!("Already subscribed to {description} for {instrument_id}");
+            return Ok(());
+        }
+
+        let info = self
+            .instruments
+            .lock()
+            .unwrap()
+            .get(&instrument_id)
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Cannot subscribe {description}: no instrument info for {instrument_id}"
+                )
+            })?;
+
+        match &self.config.replay {
+            Some(settings) => {
+                tracing::info!(
+                    "Subscribing replay: exchange={}, raw_symbol={}, data_type={data_type}",
+                    info.exchange,
+                    info.raw_symbol,
+                );
+                subscriptions.insert(key, Subscription::Replay(info));
+                self.update_replay(settings, &subscriptions);
+            }
+            None => {
+                tracing::info!(
+                    "Subscribing stream: exchange={}, raw_symbol={}, data_type={data_type}",
+                    info.exchange,
+                    info.raw_symbol,
+                );
+                let options = StreamNormalizedRequestOptions {
+                    exchange: info.exchange.clone(),
+                    symbols: Some(vec![info.raw_symbol.to_string()]),
+                    data_types: vec![data_type],
+                    with_disconnect_messages: Some(false),
+                    timeout_interval_ms: None,
+                };
+
+                let (close_tx, close_rx) = oneshot::channel();
+                subscriptions.insert(key, Subscription::Stream(close_tx));
+
+                let base_url = self.base_url_ws.clone();
+                let sender = self.data_sender.clone();
+                get_runtime().spawn(run_stream(base_url, options, info, close_rx, sender));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn unsubscribe_stream(&self, instrument_id: InstrumentId, data_type: String) {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        match subscriptions.remove(&(instrument_id, data_type)) {
+            Some(subscription) => subscription.close(),
+            None => {
+                tracing::warn!("No active stream to unsubscribe for {instrument_id}");
+                return;
+            }
+        }
+
+        if let Some(settings) = &self.config.replay {
+            self.update_replay(settings, &subscriptions);
+        }
+    }
+
+    /// Publishes the active subscriptions to the shared replay connection, starting the
+    /// connection if it is not running.
+    fn update_replay(
+        &self,
+        settings: &TardisReplaySettings,
+        subscriptions: &HashMap<(InstrumentId, String), Subscription>,
+    ) {
+        let replay_subscriptions: ReplaySubscriptions = subscriptions
+            .iter()
+            .filter_map(|((_, data_type), subscription)| match subscription {
+                Subscription::Replay(info) => Some((
+                    (
+                        info.as_tardis_instrument_key(),
+                        normalize_data_type(data_type),
+                    ),
+                    (info.clone(), data_type.clone()),
+                )),
+                Subscription::Stream(_) => None,
+            })
+            .collect();
+
+        let mut replay = self.replay.lock().unwrap();
+        if let Some(tx) = replay.as_ref().filter(|tx| !tx.is_closed()) {
+            tx.send_replace(replay_subscriptions);
+            return;
+        }
+
+        if replay_subscriptions.is_empty() {
+            *replay = None;
+            return;
+        }
+
+        let (tx, rx) = watch::channel(replay_subscriptions);
+        *replay = Some(tx);
+
+        get_runtime().spawn(run_replay(
+            self.base_url_ws.clone(),
+            settings.clone(),
+            self.replay_clock(settings),
+            rx,
+            self.data_sender.clone(),
+        ));
+    }
+
+    fn close_streams(&self) {
+        for (_, subscription) in self.subscriptions.lock().unwrap().drain() {
+            subscription.close();
+        }
+
+        // Dropping the sender closes the shared replay connection
+        self.replay.lock().unwrap().take();
+    }
+}
+
+#[async_trait::async_trait]
+impl DataClient for TardisDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        None // Multi-venue client
+    }
+
+    fn start(&self) -> anyhow::Result<()> {
+        tracing::debug!("Starting Tardis data client");
+        Ok(())
+    }
+
+    fn stop(&self) -> anyhow::Result<()> {
+        tracing::debug!("Stopping Tardis data client");
+        self.close_streams();
+        self.is_connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn reset(&self) -> anyhow::Result<()> {
+        tracing::debug!("Resetting Tardis data client");
+        self.close_streams();
+        *self.replay_start.lock().unwrap() = None;
+        self.is_connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn dispose(&self) -> anyhow::Result<()> {
+        tracing::debug!("Disposing Tardis data client");
+        self.stop()
+    }
+
+    async fn connect(&self) -> anyhow::Result<()> {
+        if self.is_connected() {
+            return Ok(());
+        }
+
+        tracing::debug!("Connecting Tardis data client");
+
+        self.load_instruments().await?;
+
+        if let Some(settings) = &self.config.replay {
+            *self.replay_start.lock().unwrap() = Some(Instant::now());
+            tracing::info!(
+                "Replaying {} to {} at speed {}",
+                settings.from,
+                settings.to,
+                settings
+                    .speed
+                    .map_or_else(|| "unthrottled".to_string(), |s| format!("{s}x")),
+            );
+        }
+
+        self.is_connected.store(true, Ordering::Relaxed);
+        tracing::info!("Tardis data client connected");
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> anyhow::Result<()> {
+        tracing::debug!("Disconnecting Tardis data client");
+        self.close_streams();
+        *self.replay_start.lock().unwrap() = None;
+        self.is_connected.store(false, Ordering::Relaxed);
+        tracing::info!("Tardis data client disconnected");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.is_connected.load(Ordering::Relaxed)
+    }
+
+    fn is_disconnected(&self) -> bool {
+        !self.is_connected()
+    }
+
+    fn subscribe_book_deltas(&mut self, cmd: &SubscribeBookDeltas) -> anyhow::Result<()> {
+        if cmd.book_type == BookType::L3_MBO {
+            anyhow::bail!("Tardis does not support L3_MBO order book data");
+        }
+        self.subscribe_stream(
+            cmd.instrument_id,
+            BOOK_CHANGE.to_string(),
+            "order book deltas",
+        )
+    }
+
+    fn subscribe_book_depth10(&mut self, cmd: &SubscribeBookDepth10) -> anyhow::Result<()> {
+        self.subscribe_stream(
+            cmd.instrument_id,
+            BOOK_SNAPSHOT_10.to_string(),
+            "order book depth",
+        )
+    }
+
+    fn subscribe_quotes(&mut self, cmd: &SubscribeQuotes) -> anyhow::Result<()> {
+        self.subscribe_stream(cmd.instrument_id, QUOTE.to_string(), "quotes")
+    }
+
+    fn subscribe_trades(&mut self, cmd: &SubscribeTrades) -> anyhow::Result<()> {
+        self.subscribe_stream(cmd.instrument_id, TRADE.to_string(), "trades")
+    }
+
+    fn subscribe_bars(&mut self, cmd: &SubscribeBars) -> anyhow::Result<()> {
+        let data_type = bar_type_to_tardis_data_type(&cmd.bar_type)?;
+        self.subscribe_stream(cmd.bar_type.instrument_id(), data_type, "bars")
+    }
+
+    fn unsubscribe_book_deltas(&mut self, cmd: &UnsubscribeBookDeltas) -> anyhow::Result<()> {
+        self.unsubscribe_stream(cmd.instrument_id, BOOK_CHANGE.to_string());
+        Ok(())
+    }
+
+    fn unsubscribe_book_depth10(&mut self, cmd: &UnsubscribeBookDepth10) -> anyhow::Result<()> {
+        self.unsubscribe_stream(cmd.instrument_id, BOOK_SNAPSHOT_10.to_string());
+        Ok(())
+    }
+
+    fn unsubscribe_quotes(&mut self, cmd: &UnsubscribeQuotes) -> anyhow::Result<()> {
+        self.unsubscribe_stream(cmd.instrument_id, QUOTE.to_string());
+        Ok(())
+    }
+
+    fn unsubscribe_trades(&mut self, cmd: &UnsubscribeTrades) -> anyhow::Result<()> {
+        self.unsubscribe_stream(cmd.instrument_id, TRADE.to_string());
+        Ok(())
+    }
+
+    fn unsubscribe_bars(&mut self, cmd: &UnsubscribeBars) -> anyhow::Result<()> {
+        let data_type = bar_type_to_tardis_data_type(&cmd.bar_type)?;
+        self.unsubscribe_stream(cmd.bar_type.instrument_id(), data_type);
+        Ok(())
+    }
+}
+
+const BOOK_CHANGE: &str = "book_change";
+const BOOK_SNAPSHOT_10: &str = "book_snapshot_10_0ms";
+const QUOTE: &str = "quote";
+const TRADE: &str = "trade";
+
+/// Converts the Nautilus `bar_type` to a Tardis trade bar data type.
+fn bar_type_to_tardis_data_type(bar_type: &BarType) -> anyhow::Result<String> {
+    let spec = bar_type.spec();
+    match spec.aggregation {
+        BarAggregation::Millisecond
+        | BarAggregation::Second
+        | BarAggregation::Minute
+        | BarAggregation::Tick
+        | BarAggregation::Volume => Ok(bar_spec_to_tardis_trade_bar_string(&spec)),
+        aggregation => anyhow::bail!("Tardis does not support {aggregation} bars"),
+    }
+}
+
+/// Creates the instrument definition information for parsing from the given `instrument`.
+fn instrument_mini_info(instrument: &InstrumentAny, exchange: Exchange) -> InstrumentMiniInfo {
+    InstrumentMiniInfo::new(
+        instrument.id(),
+        Some(Ustr::from(instrument.raw_symbol().as_str())),
+        exchange,
+        instrument.price_precision(),
+        instrument.size_precision(),
+    )
+}
+
+/// Normalizes time based trade bar data types to the millisecond intervals Tardis Machine
+/// uses to name trade bar messages, e.g. `trade_bar_1m` to `trade_bar_60000ms`.
+fn normalize_data_type(data_type: &str) -> String {
+    let Some(interval) = data_type.strip_prefix("trade_bar_") else {
+        return data_type.to_string();
+    };
+
+    let suffix = interval.trim_start_matches(|c: char| c.is_ascii_digit());
+    let multiplier = match suffix {
+        "s" => 1_000,
+        "m" => 60_000,
+        _ => return data_type.to_string(),
+    };
+
+    match interval[..interval.len() - suffix.len()].parse::<u64>() {
+        Ok(step) => format!("trade_bar_{}ms", step * multiplier),
+        Err(_) => data_type.to_string(),
+    }
+}
+
+/// An active subscription.
+#[derive(Debug)]
+enum Subscription {
+    /// A dedicated `stream-normalized` websocket, closed through the sender.
+    Stream(oneshot::Sender<()>),
+    /// A subscription served by the shared `replay-normalized` websocket.
+    Replay(Arc<InstrumentMiniInfo>),
+}
+
+impl Subscription {
+    fn close(self) {
+        if let Self::Stream(close_tx) = self {
+            // The stream may already have ended
+            let _ = close_tx.send(());
+        }
+    }
+}
+
+/// The Tardis instrument and normalized data type identifying a replayed message.
+type ReplayKey = (TardisInstrumentKey, String);
+
+/// The active replay subscriptions with their instrument info and requested data type.
+type ReplaySubscriptions = HashMap<ReplayKey, (Arc<InstrumentMiniInfo>, String)>;
+
+/// Maps replayed data timestamps to wall-clock instants.
+#[derive(Debug, Clone, Copy)]
+struct ReplayClock {
+    start: Instant,
+    from: UnixNanos,
+    speed: f64,
+}
+
+impl ReplayClock {
+    fn instant_for(&self, ts: UnixNanos) -> Instant {
+        let elapsed_ns = ts.as_u64().saturating_sub(self.from.as_u64()) as f64 / self.speed;
+        self.start + std::time::Duration::from_nanos(elapsed_ns as u64)
+    }
+}
+
+async fn run_stream(
+    base_url: String,
+    options: StreamNormalizedRequestOptions,
+    info: Arc<InstrumentMiniInfo>,
+    mut close_rx: oneshot::Receiver<()>,
+    sender: UnboundedSender<DataEvent>,
+) {
+    let signal = Arc::new(AtomicBool::new(false));
+
+    let ws_stream = tokio::select! {
+        _ = &mut close_rx => return,
+        result = stream_normalized(&base_url, vec![options], signal.clone()) => match result {
+            Ok(stream) => Box::pin(stream),
+            Err(e) => {
+                tracing::error!("Failed to connect to Tardis Machine: {e}");
+                return;
+            }
+        },
+    };
+
+    let stream = handle_ws_stream(ws_stream, Some(info), None);
+    pin_mut!(stream);
+
+    loop {
+        tokio::select! {
+            _ = &mut close_rx => break,
+            data = stream.next() => {
+                let Some(data) = data else {
+                    break;
+                };
+
+                if let Err(e) = sender.send(DataEvent::Data(data)) {
+                    tracing::error!("Failed to send data event: {e}");
+                    break;
+                }
+            }
+        }
+    }
+
+    // Stop reading from the websocket
+    signal.store(true, Ordering::Relaxed);
+}
+
+enum ReplayExit {
+    Resubscribe,
+    Finished,
+    Closed,
+}
+
+async fn run_replay(
+    base_url: String,
+    settings: TardisReplaySettings,
+    replay_clock: Option<ReplayClock>,
+    mut subscriptions_rx: watch::Receiver<ReplaySubscriptions>,
+    sender: UnboundedSender<DataEvent>,
+) {
+    let mut last_ts_init: Option<UnixNanos> = None;
+
+    loop {
+        let mut subscriptions = subscriptions_rx.borrow_and_update().clone();
+        if subscriptions.is_empty() {
+            if subscriptions_rx.changed().await.is_err() {
+                return;
+            }
+            continue;
+        }
+
+        let options = replay_options(&settings, &subscriptions);
+        let signal = Arc::new(AtomicBool::new(false));
+
+        let mut ws_stream = tokio::select! {
+            result = subscriptions_rx.changed() => {
+                if result.is_err() {
+                    return;
+                }
+                continue; // Connect again with the updated subscriptions
+            }
+            result = replay_normalized(&base_url, options, signal.clone()) => match result {
+                Ok(stream) => Box::pin(stream),
+                Err(e) => {
+                    tracing::error!("Failed to connect to Tardis Machine: {e}");
+                    return;
+                }
+            },
+        };
+
+        // A reconnected replay starts again from the `from` date, so skip data already replayed
+        let resume_after = last_ts_init;
+        let mut pending: Option<(ReplayKey, Data)> = None;
+
+        let exit = loop {
+            let deadline = match (&pending, &replay_clock) {
+                (Some((_, data)), Some(replay_clock)) => replay_clock.instant_for(data.ts_init()),
+                _ => Instant::now(),
+            };
+
+            tokio::select! {
+                biased;
+                result = subscriptions_rx.changed() => {
+                    if result.is_err() {
+                        break ReplayExit::Closed;
+                    }
+
+                    let updated = subscriptions_rx.borrow_and_update().clone();
+                    if !updated.keys().all(|key| subscriptions.contains_key(key)) {
+                        break ReplayExit::Resubscribe;
+                    }
+
+                    // Subscriptions were only removed, so keep filtering this connection
+                    subscriptions = updated;
+                }
+                () = tokio::time::sleep_until(deadline), if pending.is_some() => {
+                    let Some((key, data)) = pending.take() else {
+                        continue;
+                    };
+
+                    if !subscriptions.contains_key(&key) {
+                        continue;
+                    }
+
+                    last_ts_init = Some(data.ts_init());
+                    if let Err(e) = sender.send(DataEvent::Data(data)) {
+                        tracing::error!("Failed to send data event: {e}");
+                        break ReplayExit::Closed;
+                    }
+                }
+                msg = ws_stream.next(), if pending.is_none() => match msg {
+                    Some(Ok(msg)) => {
+                        pending = parse_replay_message(msg, &subscriptions).filter(|(_, data)| {
+                            resume_after.is_none_or(|ts| data.ts_init() > ts)
+                        });
+                    }
+                    Some(Err(e)) => {
+                        tracing::error!("Error in replay stream: {e}");
+                        break ReplayExit::Finished;
+                    }
+                    None => break ReplayExit::Finished,
+                },
+            }
+        };
+
+        // Stop reading from the websocket
+        signal.store(true, Ordering::Relaxed);
+
+        match exit {
+            ReplayExit::Resubscribe => tracing::debug!("Reconnecting replay for new subscriptions"),
+            ReplayExit::Finished => {
+                tracing::info!("Replay finished");
+                return;
+            }
+            ReplayExit::Closed => return,
+        }
+    }
+}
+
+fn replay_options(
+    settings: &TardisReplaySettings,
+    subscriptions: &ReplaySubscriptions,
+) -> Vec<ReplayNormalizedRequestOptions> {
+    subscriptions
+        .values()
+        .map(|(info, data_type)| ReplayNormalizedRequestOptions {
+            exchange: info.exchange.clone(),
+            symbols: Some(vec![info.raw_symbol.to_string()]),
+            from: settings.from,
+            to: settings.to,
+            data_types: vec![data_type.clone()],
+            with_disconnect_messages: Some(false),
+        })
+        .collect()
+}
+
+/// Parses the replayed `msg` when it belongs to one of the active `subscriptions`.
+fn parse_replay_message(
+    msg: WsMessage,
+    subscriptions: &ReplaySubscriptions,
+) -> Option<(ReplayKey, Data)> {
+    let (symbol, exchange, data_type) = match &msg {
+        WsMessage::BookChange(msg) => (msg.symbol, msg.exchange.clone(), BOOK_CHANGE),
+        WsMessage::BookSnapshot(msg) => (msg.symbol, msg.exchange.clone(), msg.name.as_str()),
+        WsMessage::Trade(msg) => (msg.symbol, msg.exchange.clone(), TRADE),
+        WsMessage::TradeBar(msg) => (msg.symbol, msg.exchange.clone(), msg.name.as_str()),
+        WsMessage::DerivativeTicker(_) | WsMessage::Disconnect(_) => return None,
+    };
+
+    let key = (
+        TardisInstrumentKey::new(symbol, exchange),
+        data_type.to_string(),
+    );
+    let (info, _) = subscriptions.get(&key)?;
+    let data = parse_tardis_ws_message(msg, info.clone())?;
+    Some((key, data))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+#[cfg(target_os = "linux")] // Only run network tests on Linux (CI stability)
+mod tests {
+    use std::time::Duration;
+
+    use chrono::NaiveDate;
+    use futures_util::SinkExt;
+    use nautilus_common::runner::set_data_event_sender;
+    use nautilus_core::UUID4;
+    use rstest::rstest;
+    use tokio::{
+        net::TcpListener,
+        sync::mpsc::{UnboundedReceiver, unbounded_channel},
+    };
+    use tokio_tungstenite::{
+        accept_hdr_async,
+        tungstenite::{
+            Message,
+            handshake::server::{Request, Response},
+        },
+    };
+
+    use super::*;
+    use crate::tests::load_test_json;
+
+    /// Starts a stand-in Tardis Machine server which sends `count` trade messages on each
+    /// connection, reporting each request path and decoded query.
+    fn start_test_server(count: usize) -> (String, UnboundedReceiver<(String, String)>) {
+        let (path_tx, path_rx) = unbounded_channel();
+        let listener = get_runtime()
+            .block_on(TcpListener::bind("127.0.0.1:0"))
+            .unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        get_runtime().spawn(async move {
+            loop {
+                let (conn, _) = listener.accept().await.unwrap();
+                let path_tx = path_tx.clone();
+                tokio::spawn(async move {
+                    let callback = |request: &Request, response: Response| {
+                        let query = request.uri().query().unwrap_or_default();
+                        let _ = path_tx.send((
+                            request.uri().path().to_string(),
+                            urlencoding::decode(query).unwrap().into_owned(),
+                        ));
+                        Ok(response)
+                    };
+                    let mut websocket = accept_hdr_async(conn, callback).await.unwrap();
+
+                    let trade = load_test_json("trade.json");
+                    for _ in 0..count {
+                        if websocket
+                            .send(Message::Text(trade.clone().into()))
+                            .await
+                            .is_err()
+                        {
+                            return;
+                        }
+                    }
+
+                    // Hold the connection open until the client goes away
+                    while let Some(Ok(_)) = websocket.next().await {}
+                });
+            }
+        });
+
+        (format!("ws://127.0.0.1:{port}"), path_rx)
+    }
+
+    fn create_client(
+        base_url_ws: String,
+        replay: Option<TardisReplaySettings>,
+    ) -> (TardisDataClient, UnboundedReceiver<DataEvent>) {
+        let (tx, rx) = unbounded_channel();
+        set_data_event_sender(tx);
+
+        let config = TardisDataClientConfig {
+            base_url_ws: Some(base_url_ws),
+            replay,
+            ..Default::default()
+        };
+        let client = TardisDataClient::new(ClientId::from("TARDIS"), config).unwrap();
+        client.add_instrument_info(InstrumentMiniInfo::new(
+            instrument_id(),
+            Some(Ustr::from("XBTUSD")),
+            Exchange::Bitmex,
+            1,
+            0,
+        ));
+
+        (client, rx)
+    }
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("XBTUSD.BITMEX")
+    }
+
+    fn subscribe_trades_cmd() -> SubscribeTrades {
+        SubscribeTrades::new(
+            instrument_id(),
+            Some(ClientId::from("TARDIS")),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        )
+    }
+
+    fn subscribe_quotes_cmd() -> SubscribeQuotes {
+        SubscribeQuotes::new(
+            instrument_id(),
+            Some(ClientId::from("TARDIS")),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        )
+    }
+
+    fn replay_settings() -> TardisReplaySettings {
+        TardisReplaySettings {
+            from: NaiveDate::from_ymd_opt(2019, 10, 23).unwrap(),
+            to: NaiveDate::from_ymd_opt(2019, 10, 24).unwrap(),
+            speed: None,
+        }
+    }
+
+    fn assert_no_event<T>(rx: &mut UnboundedReceiver<T>) {
+        let result = get_runtime()
+            .block_on(async { tokio::time::timeout(Duration::from_millis(300), rx.recv()).await });
+        assert!(result.is_err(), "Expected no event");
+    }
+
+    fn recv_data(rx: &mut UnboundedReceiver<DataEvent>) -> Data {
+        let event = get_runtime()
+            .block_on(async { tokio::time::timeout(Duration::from_secs(5), rx.recv()).await })
+            .expect("Timed out waiting for data event")
+            .expect("Data event channel closed");
+
+        match event {
+            DataEvent::Data(data) => data,
+            other => panic!("Expected data event, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_subscribe_trades_streams_data() {
+        let (url, mut path_rx) = start_test_server(2);
+        let (mut client, mut rx) = create_client(url, None);
+        get_runtime().block_on(client.connect()).unwrap();
+
+        client.subscribe_trades(&subscribe_trades_cmd()).unwrap();
+
+        for _ in 0..2 {
+            match recv_data(&mut rx) {
+                Data::Trade(trade) => assert_eq!(trade.instrument_id, instrument_id()),
+                other => panic!("Expected trade, was {other:?}"),
+            }
+        }
+        assert_eq!(
+            get_runtime().block_on(path_rx.recv()).unwrap().0,
+            "/ws-stream-normalized"
+        );
+        assert!(client.is_subscribed(instrument_id(), "trade"));
+
+        get_runtime().block_on(client.disconnect()).unwrap();
+        assert!(!client.is_subscribed(instrument_id(), "trade"));
+    }
+
+    #[rstest]
+    fn test_subscribe_trades_replays_data() {
+        let (url, mut path_rx) = start_test_server(1);
+        let (mut client, mut rx) = create_client(url, Some(replay_settings()));
+        get_runtime().block_on(client.connect()).unwrap();
+
+        client.subscribe_trades(&subscribe_trades_cmd()).unwrap();
+
+        assert!(matches!(recv_data(&mut rx), Data::Trade(_)));
+        assert_eq!(
+            get_runtime().block_on(path_rx.recv()).unwrap().0,
+            "/ws-replay-normalized"
+        );
+    }
+
+    #[rstest]
+    fn test_replay_subscriptions_share_connection() {
+        let (url, mut path_rx) = start_test_server(1);
+        let (mut client, mut rx) = create_client(url, Some(replay_settings()));
+        get_runtime().block_on(client.connect()).unwrap();
+
+        client.subscribe_trades(&subscribe_trades_cmd()).unwrap();
+        assert!(matches!(recv_data(&mut rx), Data::Trade(_)));
+        let (_, query) = get_runtime().block_on(path_rx.recv()).unwrap();
+        assert!(query.contains("\"trade\""));
+
+        // Adding a subscription reopens the replay for both, without repeating replayed data
+        client.subscribe_quotes(&subscribe_quotes_cmd()).unwrap();
+        let (path, query) = get_runtime().block_on(path_rx.recv()).unwrap();
+        assert_eq!(path, "/ws-replay-normalized");
+        assert!(query.contains("\"trade\""));
+        assert!(query.contains("\"quote\""));
+        assert_no_event(&mut rx);
+
+        // Removing a subscription keeps the shared connection
+        let cmd = UnsubscribeQuotes::new(
+            instrument_id(),
+            Some(ClientId::from("TARDIS")),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        );
+        client.unsubscribe_quotes(&cmd).unwrap();
+        assert_no_event(&mut path_rx);
+        assert!(client.is_subscribed(instrument_id(), "trade"));
+        assert!(!client.is_subscribed(instrument_id(), "quote"));
+
+        get_runtime().block_on(client.disconnect()).unwrap();
+    }
+
+    #[rstest]
+    fn test_subscribe_without_instrument_info_errors() {
+        let (url, _path_rx) = start_test_server(0);
+        let (mut client, _rx) = create_client(url, None);
+        let cmd = SubscribeTrades::new(
+            InstrumentId::from("ETHUSD.BITMEX"),
+            Some(ClientId::from("TARDIS")),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        );
+
+        assert!(client.subscribe_trades(&cmd).is_err());
+    }
+
+    #[rstest]
+    fn test_unsubscribe_closes_stream() {
+        let (url, _path_rx) = start_test_server(1);
+        let (mut client, mut rx) = create_client(url, None);
+        client.subscribe_trades(&subscribe_trades_cmd()).unwrap();
+        let _ = recv_data(&mut rx);
+
+        let cmd = UnsubscribeTrades::new(
+            instrument_id(),
+            Some(ClientId::from("TARDIS")),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        );
+        client.unsubscribe_trades(&cmd).unwrap();
+
+        assert!(!client.is_subscribed(instrument_id(), "trade"));
+    }
+
+    #[rstest]
+    fn test_replay_clock_scales_by_speed() {
+        let start = Instant::now();
+        let replay_clock = ReplayClock {
+            start,
+            from: UnixNanos::from(1_000_000_000),
+            speed: 10.0,
+        };
+
+        let instant = replay_clock.instant_for(UnixNanos::from(11_000_000_000));
+
+        assert_eq!(instant - start, Duration::from_secs(1));
+    }
+
+    #[rstest]
+    #[case("trade", "trade")]
+    #[case("trade_bar_500ms", "trade_bar_500ms")]
+    #[case("trade_bar_10s", "trade_bar_10000ms")]
+    #[case("trade_bar_1m", "trade_bar_60000ms")]
+    #[case("trade_bar_100ticks", "trade_bar_100ticks")]
+    fn test_normalize_data_type(#[case] data_type: &str, #[case] expected: &str) {
+        assert_eq!(normalize_data_type(data_type), expected);
+    }
+
+    #[rstest]
+    #[case("1-MINUTE-LAST-EXTERNAL", "trade_bar_1m")]
+    #[case("100-TICK-LAST-EXTERNAL", "trade_bar_100ticks")]
+    fn test_bar_type_to_tardis_data_type(#[case] spec: &str, #[case] expected: &str) {
+        let bar_type = BarType::from(format!("XBTUSD.BITMEX-{spec}").as_str());
+        assert_eq!(bar_type_to_tardis_data_type(&bar_type).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_bar_type_to_tardis_data_type_unsupported() {
+        let bar_type = BarType::from("XBTUSD.BITMEX-1-HOUR-LAST-EXTERNAL");
+        assert!(bar_type_to_tardis_data_type(&bar_type).is_err());
+    }
+}
@@ -0,0 +1,74 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Factory functions for creating Tardis data clients for use with `LiveNode`.
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use nautilus_common::{cache::Cache, clock::Clock};
+use nautilus_data::client::DataClient;
+use nautilus_model::identifiers::ClientId;
+use nautilus_system::factories::{ClientConfig, DataClientFactory};
+
+use crate::{config::TardisDataClientConfig, data::TardisDataClient};
+
+impl ClientConfig for TardisDataClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Factory for creating Tardis data clients.
+#[derive(Debug, Default)]
+pub struct TardisDataClientFactory;
+
+impl TardisDataClientFactory {
+    /// Creates a new [`TardisDataClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl DataClientFactory for TardisDataClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        _cache: Rc<RefCell<Cache>>,
+        _clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn DataClient>> {
+        let config = config
+            .as_any()
+            .downcast_ref::<TardisDataClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for TardisDataClientFactory. Expected TardisDataClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        let client = TardisDataClient::new(ClientId::from(name), config.clone())?;
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        "TARDIS"
+    }
+
+    fn config_type(&self) -> &'static str {
+        "TardisDataClientConfig"
+    }
+}
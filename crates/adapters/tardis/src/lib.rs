@@ -27,6 +27,7 @@
 //! for the [nautilus_trader](https://pypi.org/project/nautilus_trader) Python package,
 //! or as part of a Rust only build.
 //!
+//! - `live`: Enables the `TardisDataClient` implementation and its factory (default).
 //! - `python`: Enables Python bindings from [PyO3](https://pyo3.rs).
 
 #![warn(rustc::all)]
@@ -48,5 +49,11 @@ pub mod replay;
 #[cfg(feature = "python")]
 pub mod python;
 
+#[cfg(feature = "live")]
+pub mod data;
+
+#[cfg(feature = "live")]
+pub mod factories;
+
 #[cfg(test)]
 pub mod tests;
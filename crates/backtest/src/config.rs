@@ -24,6 +24,7 @@ use nautilus_common::{
     msgbus::database::MessageBusConfig,
 };
 use nautilus_core::{UUID4, UnixNanos};
+use nautilus_cryptography::secrets::SecretsConfig;
 use nautilus_data::engine::config::DataEngineConfig;
 use nautilus_execution::engine::config::ExecutionEngineConfig;
 use nautilus_model::{
@@ -213,6 +214,11 @@ impl NautilusKernelConfig for BacktestEngineConfig {
     fn streaming(&self) -> Option<StreamingConfig> {
         self.streaming.clone()
     }
+
+    fn secrets(&self) -> Option<SecretsConfig> {
+        // Backtests do not connect to venues, so secrets resolve from the environment
+        None
+    }
 }
 
 impl Default for BacktestEngineConfig {
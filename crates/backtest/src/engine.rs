@@ -27,6 +27,7 @@ use std::{
     rc::Rc,
 };
 
+use nautilus_common::calendar::TradingCalendar;
 use nautilus_core::{UUID4, UnixNanos};
 use nautilus_data::client::DataClientAdapter;
 use nautilus_execution::models::{fee::FeeModelAny, fill::FillModel, latency::LatencyModel};
@@ -184,7 +185,10 @@ impl BacktestEngine {
         let exec_client = Rc::new(exec_client);
 
         exchange.borrow_mut().register_client(exec_client.clone());
-        self.kernel.exec_engine.register_client(exec_client)?;
+        self.kernel
+            .exec_engine
+            .borrow_mut()
+            .register_client(exec_client)?;
 
         log::info!("Adding exchange {venue} to engine");
 
@@ -195,6 +199,23 @@ impl BacktestEngine {
         todo!("implement change_fill_model")
     }
 
+    /// Sets the trading calendar driving the market status of the instruments of `venue`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the venue has not been added via `add_venue`.
+    pub fn set_trading_calendar(
+        &mut self,
+        venue: Venue,
+        calendar: TradingCalendar,
+    ) -> anyhow::Result<()> {
+        let Some(exchange) = self.venues.get(&venue) else {
+            anyhow::bail!("Cannot set a trading calendar for unknown venue {venue}")
+        };
+        exchange.borrow_mut().set_trading_calendar(calendar);
+        Ok(())
+    }
+
     /// Adds an instrument to the backtest engine for the specified venue.
     ///
     /// # Errors
@@ -432,7 +453,14 @@ mod tests {
         // Check the venue and exec client has been added
         assert_eq!(engine.venues.len(), 1);
         assert!(engine.venues.get(&venue).is_some());
-        assert!(engine.kernel.exec_engine.get_client(&client_id).is_some());
+        assert!(
+            engine
+                .kernel
+                .exec_engine
+                .borrow()
+                .get_client(&client_id)
+                .is_some()
+        );
 
         // Check the instrument has been added
         assert!(
@@ -20,13 +20,20 @@
 #![allow(unused_variables)]
 
 use std::{
+    any::Any,
     cell::RefCell,
-    collections::{BinaryHeap, HashMap, VecDeque},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
     fmt::Debug,
     rc::Rc,
 };
 
-use nautilus_common::{cache::Cache, clock::Clock, messages::execution::TradingCommand};
+use nautilus_common::{
+    cache::Cache,
+    calendar::TradingCalendar,
+    clock::Clock,
+    messages::execution::TradingCommand,
+    msgbus::{self, switchboard::get_instrument_status_topic},
+};
 use nautilus_core::{
     UnixNanos,
     correctness::{FAILED, check_equal},
@@ -105,6 +112,11 @@ pub struct SimulatedExchange {
     matching_engines: HashMap<InstrumentId, OrderMatchingEngine>,
     leverages: HashMap<InstrumentId, Decimal>,
     modules: Vec<Box<dyn SimulationModule>>,
+    trading_calendar: Option<TradingCalendar>,
+    calendar_ts: Option<UnixNanos>,
+    calendar_next_ts: UnixNanos,
+    calendar_instruments: HashSet<InstrumentId>,
+    calendar_uncovered: bool,
     clock: Rc<RefCell<dyn Clock>>,
     cache: Rc<RefCell<Cache>>,
     message_queue: VecDeque<TradingCommand>,
@@ -187,6 +199,11 @@ impl SimulatedExchange {
             matching_engines: HashMap::new(),
             leverages,
             modules,
+            trading_calendar: None,
+            calendar_ts: None,
+            calendar_next_ts: UnixNanos::default(),
+            calendar_instruments: HashSet::new(),
+            calendar_uncovered: false,
             clock,
             cache,
             message_queue: VecDeque::new(),
@@ -224,6 +241,24 @@ impl SimulatedExchange {
         self.latency_model = Some(latency_model);
     }
 
+    /// Sets the trading calendar which drives the market status of the exchange instruments,
+    /// with the [`InstrumentStatus`] of each session open, break and close applied to the
+    /// matching engines and published as time is processed.
+    ///
+    /// Outside the coverage of the calendar there are no sessions, so the instruments are closed.
+    pub fn set_trading_calendar(&mut self, calendar: TradingCalendar) {
+        log::info!(
+            "Setting trading calendar for {} to {}",
+            self.id,
+            calendar.name()
+        );
+        self.trading_calendar = Some(calendar);
+        self.calendar_ts = None;
+        self.calendar_next_ts = UnixNanos::default();
+        self.calendar_instruments.clear();
+        self.calendar_uncovered = false;
+    }
+
     pub fn initialize_account(&mut self) {
         self.generate_fresh_account_state();
     }
@@ -659,12 +694,75 @@ impl SimulatedExchange {
         }
     }
 
+    /// Applies and publishes the market status transitions of the trading calendar (if set)
+    /// since the last time processed, initializing the status of newly added instruments.
+    fn process_trading_calendar(&mut self, ts_now: UnixNanos) {
+        let Some(calendar) = &self.trading_calendar else {
+            return;
+        };
+
+        let uncovered = !calendar.covers_ts(ts_now);
+        if uncovered && !self.calendar_uncovered {
+            log::warn!(
+                "Time {ts_now} is outside the coverage of trading calendar {} for {}, instruments will remain closed",
+                calendar.name(),
+                self.id
+            );
+        }
+        self.calendar_uncovered = uncovered;
+
+        let last_ts = self.calendar_ts.filter(|last_ts| *last_ts < ts_now);
+        self.calendar_ts = Some(self.calendar_ts.map_or(ts_now, |ts| ts.max(ts_now)));
+
+        // No transitions to apply until the next one, unless instruments were added
+        let has_new_instruments = self.calendar_instruments.len() < self.instruments.len();
+        if !has_new_instruments && ts_now < self.calendar_next_ts {
+            return;
+        }
+
+        let transitions =
+            last_ts.map_or_else(Vec::new, |last_ts| calendar.transitions(last_ts, ts_now));
+        self.calendar_next_ts = calendar
+            .next_open(ts_now)
+            .into_iter()
+            .chain(calendar.next_close(ts_now))
+            .min()
+            .unwrap_or(UnixNanos::from(u64::MAX));
+
+        let mut instrument_ids: Vec<InstrumentId> = self.instruments.keys().copied().collect();
+        instrument_ids.sort();
+
+        let mut statuses = Vec::new();
+        for instrument_id in instrument_ids {
+            if self.calendar_instruments.insert(instrument_id) {
+                statuses.push(calendar.instrument_status_at(instrument_id, ts_now));
+            } else {
+                statuses.extend(
+                    transitions
+                        .iter()
+                        .map(|&(ts, action)| calendar.instrument_status(instrument_id, action, ts)),
+                );
+            }
+        }
+        statuses.sort_by_key(|status| status.ts_event);
+
+        for status in statuses {
+            self.process_instrument_status(status);
+            msgbus::publish(
+                get_instrument_status_topic(status.instrument_id),
+                &status as &dyn Any,
+            );
+        }
+    }
+
     /// # Panics
     ///
     /// Panics if popping an inflight command fails during processing.
     pub fn process(&mut self, ts_now: UnixNanos) {
         // TODO implement correct clock fixed time setting self.clock.set_time(ts_now);
 
+        self.process_trading_calendar(ts_now);
+
         // Process inflight commands
         while let Some(inflight) = self.inflight_queue.peek() {
             if inflight.ts > ts_now {
@@ -687,6 +785,11 @@ impl SimulatedExchange {
             module.reset();
         }
 
+        self.calendar_ts = None;
+        self.calendar_next_ts = UnixNanos::default();
+        self.calendar_instruments.clear();
+        self.calendar_uncovered = false;
+
         self.generate_fresh_account_state();
 
         for matching_engine in self.matching_engines.values_mut() {
@@ -777,11 +880,13 @@ mod tests {
 
     use nautilus_common::{
         cache::Cache,
+        calendar::parse_calendars,
         clock::TestClock,
         messages::execution::{SubmitOrder, TradingCommand},
         msgbus::{
             self,
             stubs::{get_message_saving_handler, get_saved_messages},
+            switchboard::get_instrument_status_topic,
         },
     };
     use nautilus_core::{AtomicTime, UUID4, UnixNanos};
@@ -1254,6 +1359,101 @@ mod tests {
         assert_eq!(market_status, MarketStatus::Closed);
     }
 
+    #[rstest]
+    fn test_exchange_process_trading_calendar(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L2_MBP,
+            None,
+        );
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        exchange
+            .borrow_mut()
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+
+        let handler = get_message_saving_handler::<InstrumentStatus>(None);
+        msgbus::subscribe_topic(
+            get_instrument_status_topic(instrument_id),
+            handler.clone(),
+            None,
+        );
+
+        let json = r#"[{
+            "name": "TEST",
+            "timezone": "UTC",
+            "open": "09:00",
+            "close": "16:00",
+            "breaks": [["12:00", "13:00"]]
+        }]"#;
+        let calendar = parse_calendars(json).unwrap().remove(0);
+        exchange.borrow_mut().set_trading_calendar(calendar);
+
+        let market_status = |exchange: &Rc<RefCell<SimulatedExchange>>| {
+            exchange
+                .borrow()
+                .get_matching_engine(&instrument_id)
+                .unwrap()
+                .market_status
+        };
+
+        // Initialized during the break of Monday's session
+        exchange
+            .borrow_mut()
+            .process(UnixNanos::from("2025-01-06T12:30:00Z"));
+        assert_eq!(market_status(&exchange), MarketStatus::Paused);
+        assert_eq!(
+            exchange.borrow().calendar_next_ts,
+            UnixNanos::from("2025-01-06T13:00:00Z")
+        );
+
+        // Nothing to apply before the next transition
+        exchange
+            .borrow_mut()
+            .process(UnixNanos::from("2025-01-06T12:45:00Z"));
+        assert_eq!(market_status(&exchange), MarketStatus::Paused);
+
+        // Break ends, then the session closes
+        exchange
+            .borrow_mut()
+            .process(UnixNanos::from("2025-01-06T18:00:00Z"));
+        assert_eq!(market_status(&exchange), MarketStatus::Closed);
+
+        // Tuesday's session opens
+        exchange
+            .borrow_mut()
+            .process(UnixNanos::from("2025-01-07T10:00:00Z"));
+        assert_eq!(market_status(&exchange), MarketStatus::Open);
+
+        let actions: Vec<(UnixNanos, MarketStatusAction)> =
+            get_saved_messages::<InstrumentStatus>(handler)
+                .iter()
+                .map(|status| (status.ts_event, status.action))
+                .collect();
+        assert_eq!(
+            actions,
+            vec![
+                (
+                    UnixNanos::from("2025-01-06T12:30:00Z"),
+                    MarketStatusAction::Pause
+                ),
+                (
+                    UnixNanos::from("2025-01-06T13:00:00Z"),
+                    MarketStatusAction::Trading
+                ),
+                (
+                    UnixNanos::from("2025-01-06T16:00:00Z"),
+                    MarketStatusAction::Close
+                ),
+                (
+                    UnixNanos::from("2025-01-07T09:00:00Z"),
+                    MarketStatusAction::Trading
+                ),
+            ]
+        );
+    }
+
     #[rstest]
     fn test_accounting() {
         let account_type = AccountType::Margin;
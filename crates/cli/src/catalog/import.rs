@@ -0,0 +1,252 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr};
+
+use nautilus_databento::loader::DatabentoDataLoader;
+use nautilus_model::{
+    data::{Bar, GetTsInit, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick},
+    identifiers::InstrumentId,
+};
+use nautilus_persistence::backend::catalog::{CatalogPathPrefix, ParquetDataCatalog};
+use nautilus_serialization::{arrow::EncodeToRecordBatch, enums::ParquetWriteMode};
+use nautilus_tardis::csv;
+
+use crate::opt::{ImportDatabentoConfig, ImportTardisConfig, TardisCsvType};
+
+/// Imports the Tardis CSV file of `config` into `catalog`, returning the paths written.
+pub fn import_tardis(
+    catalog: &ParquetDataCatalog,
+    config: &ImportTardisConfig,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let file = &config.file;
+    let instrument_id = config
+        .instrument_id
+        .as_deref()
+        .map(InstrumentId::from_str)
+        .transpose()?;
+    let price_precision = config.price_precision;
+    let size_precision = config.size_precision;
+    let to_anyhow = |e: Box<dyn std::error::Error>| anyhow::anyhow!("{e}");
+
+    match config.data_type {
+        TardisCsvType::Deltas => {
+            let deltas =
+                csv::load_deltas(file, price_precision, size_precision, instrument_id, None)
+                    .map_err(to_anyhow)?;
+            write_grouped(catalog, deltas, |delta| delta.instrument_id.to_string())
+        }
+        TardisCsvType::Depth10Snapshot5 => {
+            let depths = csv::load_depth10_from_snapshot5(
+                file,
+                price_precision,
+                size_precision,
+                instrument_id,
+                None,
+            )
+            .map_err(to_anyhow)?;
+            write_grouped(catalog, depths, |depth| depth.instrument_id.to_string())
+        }
+        TardisCsvType::Depth10Snapshot25 => {
+            let depths = csv::load_depth10_from_snapshot25(
+                file,
+                price_precision,
+                size_precision,
+                instrument_id,
+                None,
+            )
+            .map_err(to_anyhow)?;
+            write_grouped(catalog, depths, |depth| depth.instrument_id.to_string())
+        }
+        TardisCsvType::Quotes => {
+            let quotes =
+                csv::load_quote_ticks(file, price_precision, size_precision, instrument_id, None)
+                    .map_err(to_anyhow)?;
+            write_grouped(catalog, quotes, |quote| quote.instrument_id.to_string())
+        }
+        TardisCsvType::Trades => {
+            let trades =
+                csv::load_trade_ticks(file, price_precision, size_precision, instrument_id, None)
+                    .map_err(to_anyhow)?;
+            write_grouped(catalog, trades, |trade| trade.instrument_id.to_string())
+        }
+    }
+}
+
+/// Imports the Databento DBN file of `config` into `catalog`, with the data type
+/// determined by the schema of the file, returning the paths written.
+pub fn import_databento(
+    catalog: &ParquetDataCatalog,
+    config: &ImportDatabentoConfig,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let file = config.file.as_path();
+    let instrument_id = config
+        .instrument_id
+        .as_deref()
+        .map(InstrumentId::from_str)
+        .transpose()?;
+    let price_precision = config.price_precision;
+    let mut loader = DatabentoDataLoader::new(config.publishers.clone())?;
+
+    let Some(schema) = loader.schema_from_file(file)? else {
+        anyhow::bail!("No schema found in {}", file.display());
+    };
+
+    match schema.as_str() {
+        "definition" => {
+            let mut instruments = loader.load_instruments(file, config.use_exchange_as_venue)?;
+            instruments.sort_by_key(GetTsInit::ts_init);
+            let path = catalog.write_to_parquet(
+                instruments,
+                None,
+                None,
+                None,
+                Some(ParquetWriteMode::NewFile),
+            )?;
+            Ok(vec![path])
+        }
+        "mbo" => {
+            let deltas = loader.load_order_book_deltas(file, instrument_id, price_precision)?;
+            write_grouped::<OrderBookDelta>(catalog, deltas, |delta| {
+                delta.instrument_id.to_string()
+            })
+        }
+        "mbp-1" => {
+            let quotes = loader.load_quotes(file, instrument_id, price_precision)?;
+            write_grouped::<QuoteTick>(catalog, quotes, |quote| quote.instrument_id.to_string())
+        }
+        "mbp-10" => {
+            let depths = loader.load_order_book_depth10(file, instrument_id, price_precision)?;
+            write_grouped::<OrderBookDepth10>(catalog, depths, |depth| {
+                depth.instrument_id.to_string()
+            })
+        }
+        "bbo-1s" | "bbo-1m" => {
+            let quotes = loader.load_bbo_quotes(file, instrument_id, price_precision)?;
+            write_grouped::<QuoteTick>(catalog, quotes, |quote| quote.instrument_id.to_string())
+        }
+        "tbbo" => {
+            let trades = loader.load_tbbo_trades(file, instrument_id, price_precision)?;
+            write_grouped::<TradeTick>(catalog, trades, |trade| trade.instrument_id.to_string())
+        }
+        "trades" => {
+            let trades = loader.load_trades(file, instrument_id, price_precision)?;
+            write_grouped::<TradeTick>(catalog, trades, |trade| trade.instrument_id.to_string())
+        }
+        "ohlcv-1s" | "ohlcv-1m" | "ohlcv-1h" | "ohlcv-1d" | "ohlcv-eod" => {
+            let bars = loader.load_bars(file, instrument_id, price_precision, None)?;
+            write_grouped::<Bar>(catalog, bars, |bar| bar.bar_type.to_string())
+        }
+        _ => anyhow::bail!("Unsupported Databento schema `{schema}`"),
+    }
+}
+
+/// Writes `data` to a new file per group of `key` (the identifier of the data
+/// directory), sorted by `ts_init` as the catalog requires.
+fn write_grouped<T>(
+    catalog: &ParquetDataCatalog,
+    data: Vec<T>,
+    key: impl Fn(&T) -> String,
+) -> anyhow::Result<Vec<PathBuf>>
+where
+    T: GetTsInit + EncodeToRecordBatch + CatalogPathPrefix,
+{
+    let mut groups: BTreeMap<String, Vec<T>> = BTreeMap::new();
+    for item in data {
+        groups.entry(key(&item)).or_default().push(item);
+    }
+
+    let mut paths = Vec::with_capacity(groups.len());
+    for (_, mut group) in groups {
+        group.sort_by_key(GetTsInit::ts_init);
+        paths.push(catalog.write_to_parquet(
+            group,
+            None,
+            None,
+            None,
+            Some(ParquetWriteMode::NewFile),
+        )?);
+    }
+
+    Ok(paths)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(instrument_id: &str, ts_init: u64) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from(instrument_id),
+            "1.00000".into(),
+            "1.00001".into(),
+            "100000".into(),
+            "100000".into(),
+            UnixNanos::from(ts_init),
+            UnixNanos::from(ts_init),
+        )
+    }
+
+    #[rstest]
+    fn test_write_grouped_writes_sorted_file_per_instrument() {
+        let mut catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+        let quotes = vec![
+            quote("AUD/USD.SIM", 2_000),
+            quote("GBP/USD.SIM", 1_500),
+            quote("AUD/USD.SIM", 1_000),
+        ];
+
+        let paths =
+            write_grouped(&catalog, quotes, |quote| quote.instrument_id.to_string()).unwrap();
+
+        assert_eq!(paths.len(), 2);
+        let summaries = catalog.list_data().unwrap();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].identifier.as_deref(), Some("AUDUSD.SIM"));
+        assert_eq!((summaries[0].start, summaries[0].end), (1_000, 2_000));
+        assert_eq!(summaries[1].row_count, 1);
+
+        let result: Vec<_> = catalog
+            .query_directory::<QuoteTick>(vec!["AUD/USD.SIM".to_string()], None, None, None)
+            .unwrap()
+            .collect();
+        assert_eq!(result.len(), 2);
+    }
+
+    #[rstest]
+    fn test_import_tardis_invalid_instrument_id_returns_error() {
+        let catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+        let config = ImportTardisConfig {
+            catalog: crate::opt::CatalogConfig {
+                path: "memory:///catalog".to_string(),
+            },
+            file: PathBuf::from("quotes.csv"),
+            data_type: TardisCsvType::Quotes,
+            instrument_id: Some("BTCUSDT".to_string()), // Missing venue
+            price_precision: None,
+            size_precision: None,
+        };
+
+        let result = import_tardis(&catalog, &config);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,178 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod import;
+pub mod query;
+
+use std::{fs::File, io::Write};
+
+use nautilus_core::{UnixNanos, datetime::unix_nanos_to_iso8601};
+use nautilus_persistence::backend::catalog::{CatalogDataSummary, ParquetDataCatalog};
+
+use crate::opt::{
+    CatalogCommand, CatalogConfig, CatalogConsolidateConfig, CatalogListConfig, CatalogOpt,
+    CatalogQueryConfig, CatalogStatsConfig, ConvertFeatherConfig,
+};
+
+const NANOSECONDS_IN_SECOND: u64 = 1_000_000_000;
+
+pub async fn run_catalog_command(opt: CatalogOpt) -> anyhow::Result<()> {
+    // The catalog blocks on its own runtime, so must run outside of the async context
+    tokio::task::spawn_blocking(move || run_blocking(opt.command)).await?
+}
+
+fn run_blocking(command: CatalogCommand) -> anyhow::Result<()> {
+    match command {
+        CatalogCommand::List(config) => list(&config),
+        CatalogCommand::Stats(config) => stats(&config),
+        CatalogCommand::Consolidate(config) => consolidate(config),
+        CatalogCommand::Query(config) => query(config),
+        CatalogCommand::ImportTardis(config) => {
+            let catalog = open_catalog(&config.catalog)?;
+            let paths = import::import_tardis(&catalog, &config)?;
+            log_paths("Imported Tardis data", &paths);
+            Ok(())
+        }
+        CatalogCommand::ImportDatabento(config) => {
+            let catalog = open_catalog(&config.catalog)?;
+            let paths = import::import_databento(&catalog, &config)?;
+            log_paths("Imported Databento data", &paths);
+            Ok(())
+        }
+        CatalogCommand::ConvertFeather(config) => convert_feather(&config),
+    }
+}
+
+fn open_catalog(config: &CatalogConfig) -> anyhow::Result<ParquetDataCatalog> {
+    ParquetDataCatalog::from_uri(&config.path, None, None)
+}
+
+fn list(config: &CatalogListConfig) -> anyhow::Result<()> {
+    let catalog = open_catalog(&config.catalog)?;
+
+    for summary in catalog.list_data()? {
+        if config
+            .data_type
+            .as_ref()
+            .is_some_and(|data_type| *data_type != summary.type_name)
+        {
+            continue;
+        }
+
+        println!(
+            "{:<24} {:<32} {} - {}",
+            summary.type_name,
+            summary.identifier.as_deref().unwrap_or("-"),
+            unix_nanos_to_iso8601(UnixNanos::from(summary.start)),
+            unix_nanos_to_iso8601(UnixNanos::from(summary.end)),
+        );
+    }
+
+    Ok(())
+}
+
+fn stats(config: &CatalogStatsConfig) -> anyhow::Result<()> {
+    let catalog = open_catalog(&config.catalog)?;
+    let min_gap = config.min_gap_secs.saturating_mul(NANOSECONDS_IN_SECOND);
+
+    for summary in catalog.list_data()? {
+        if !matches_filter(&summary, config) {
+            continue;
+        }
+
+        println!(
+            "{} {}: {} rows in {} files",
+            summary.type_name,
+            summary.identifier.as_deref().unwrap_or("-"),
+            summary.row_count,
+            summary.file_count,
+        );
+
+        for (start, end) in catalog.find_gaps(&summary.type_name, summary.identifier, min_gap)? {
+            println!(
+                "  gap {} - {} ({}s)",
+                unix_nanos_to_iso8601(UnixNanos::from(start)),
+                unix_nanos_to_iso8601(UnixNanos::from(end)),
+                (end - start) / NANOSECONDS_IN_SECOND,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn matches_filter(summary: &CatalogDataSummary, config: &CatalogStatsConfig) -> bool {
+    config
+        .data_type
+        .as_ref()
+        .is_none_or(|data_type| *data_type == summary.type_name)
+        && config.identifier.as_ref().is_none_or(|identifier| {
+            // Directories are named after identifiers without slashes (e.g. FX symbols)
+            summary.identifier.as_deref() == Some(identifier.replace('/', "").as_str())
+        })
+}
+
+fn consolidate(config: CatalogConsolidateConfig) -> anyhow::Result<()> {
+    let catalog = open_catalog(&config.catalog)?;
+
+    match config.data_type {
+        Some(data_type) => catalog.consolidate_data(&data_type, config.identifier)?,
+        None => catalog.consolidate_catalog()?,
+    }
+
+    log::info!("Consolidated catalog {}", catalog.uri());
+    Ok(())
+}
+
+fn query(config: CatalogQueryConfig) -> anyhow::Result<()> {
+    let mut catalog = open_catalog(&config.catalog)?;
+    let records = query::query_records(
+        &mut catalog,
+        &config.data_type,
+        config.identifiers,
+        config.start,
+        config.end,
+    )?;
+
+    match config.output {
+        Some(path) => {
+            let mut file = File::create(&path)?;
+            query::write_records(&records, config.format, &mut file)?;
+            file.flush()?;
+            log::info!("Wrote {} records to {}", records.len(), path.display());
+        }
+        None => query::write_records(&records, config.format, &mut std::io::stdout().lock())?,
+    }
+
+    Ok(())
+}
+
+fn convert_feather(config: &ConvertFeatherConfig) -> anyhow::Result<()> {
+    let catalog = open_catalog(&config.catalog)?;
+    let paths = catalog.convert_stream_to_data(&config.instance_id, &config.subdirectory)?;
+
+    log_paths(
+        &format!("Converted feather stream of {}", config.instance_id),
+        &paths,
+    );
+    Ok(())
+}
+
+fn log_paths(message: &str, paths: &[std::path::PathBuf]) {
+    for path in paths {
+        log::info!("Wrote {}", path.display());
+    }
+    log::info!("{message} into {} catalog files", paths.len());
+}
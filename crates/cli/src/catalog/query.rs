@@ -0,0 +1,250 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, io::Write};
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{
+        Bar, Data, GetTsInit, IndexPriceUpdate, InstrumentClose, MarkPriceUpdate, OrderBookDelta,
+        OrderBookDepth10, QuoteTick, TradeTick, to_variant,
+    },
+    events::{AccountState, OrderEventAny, PositionSnapshot},
+    instruments::InstrumentAny,
+};
+use nautilus_persistence::backend::catalog::{CatalogPathPrefix, ParquetDataCatalog};
+use nautilus_serialization::arrow::{DecodeDataFromRecordBatch, DecodeFromRecordBatch};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::opt::OutputFormat;
+
+/// Queries the data of `data_type` (a catalog path prefix such as `quotes`) stored for
+/// `identifiers` (all stored if empty) with `ts_init` between the optional `start` and
+/// `end`, returning each record as a JSON value.
+pub fn query_records(
+    catalog: &mut ParquetDataCatalog,
+    data_type: &str,
+    identifiers: Vec<String>,
+    start: Option<UnixNanos>,
+    end: Option<UnixNanos>,
+) -> anyhow::Result<Vec<Value>> {
+    match data_type {
+        "quotes" => query_data::<QuoteTick>(catalog, identifiers, start, end),
+        "trades" => query_data::<TradeTick>(catalog, identifiers, start, end),
+        "order_book_deltas" => query_data::<OrderBookDelta>(catalog, identifiers, start, end),
+        "order_book_depths" => query_data::<OrderBookDepth10>(catalog, identifiers, start, end),
+        "bars" => query_data::<Bar>(catalog, identifiers, start, end),
+        "mark_prices" => query_data::<MarkPriceUpdate>(catalog, identifiers, start, end),
+        "index_prices" => query_data::<IndexPriceUpdate>(catalog, identifiers, start, end),
+        "instrument_closes" => query_data::<InstrumentClose>(catalog, identifiers, start, end),
+        "instruments" => query_typed::<InstrumentAny>(catalog, identifiers, start, end),
+        "order_events" => query_typed::<OrderEventAny>(catalog, identifiers, start, end),
+        "position_snapshots" => query_typed::<PositionSnapshot>(catalog, identifiers, start, end),
+        "account_states" => query_typed::<AccountState>(catalog, identifiers, start, end),
+        _ => anyhow::bail!("Unsupported data type `{data_type}`"),
+    }
+}
+
+fn query_data<T>(
+    catalog: &mut ParquetDataCatalog,
+    mut identifiers: Vec<String>,
+    start: Option<UnixNanos>,
+    end: Option<UnixNanos>,
+) -> anyhow::Result<Vec<Value>>
+where
+    T: DecodeDataFromRecordBatch + CatalogPathPrefix + TryFrom<Data> + Serialize,
+{
+    if identifiers.is_empty() {
+        identifiers = catalog
+            .list_data()?
+            .into_iter()
+            .filter(|summary| summary.type_name == T::path_prefix())
+            .filter_map(|summary| summary.identifier)
+            .collect();
+    }
+
+    if identifiers.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let data: Vec<Data> = catalog
+        .query_directory::<T>(identifiers, start, end, None)?
+        .collect();
+
+    to_values(to_variant::<T>(data))
+}
+
+fn query_typed<T>(
+    catalog: &ParquetDataCatalog,
+    identifiers: Vec<String>,
+    start: Option<UnixNanos>,
+    end: Option<UnixNanos>,
+) -> anyhow::Result<Vec<Value>>
+where
+    T: DecodeFromRecordBatch + GetTsInit + CatalogPathPrefix + Serialize,
+{
+    if !identifiers.is_empty() {
+        log::warn!(
+            "Identifiers are not applied to {}, querying all records",
+            T::path_prefix()
+        );
+    }
+
+    to_values(catalog.query_typed::<T>(start, end)?)
+}
+
+fn to_values<T: Serialize>(records: Vec<T>) -> anyhow::Result<Vec<Value>> {
+    records
+        .iter()
+        .map(|record| Ok(serde_json::to_value(record)?))
+        .collect()
+}
+
+/// Writes `records` to `writer` in the output `format`.
+pub fn write_records<W: Write>(
+    records: &[Value],
+    format: OutputFormat,
+    writer: &mut W,
+) -> anyhow::Result<()> {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut *writer, records)?;
+            writeln!(writer)?;
+        }
+        OutputFormat::Csv => write_csv(records, writer)?,
+    }
+
+    Ok(())
+}
+
+/// Writes `records` as CSV with a header of all field names in order of appearance,
+/// nested fields flattened to dotted names.
+fn write_csv<W: Write>(records: &[Value], writer: &mut W) -> anyhow::Result<()> {
+    let rows: Vec<Vec<(String, String)>> = records
+        .iter()
+        .map(|record| {
+            let mut fields = Vec::new();
+            flatten(None, record, &mut fields);
+            fields
+        })
+        .collect();
+
+    let mut header: Vec<&str> = Vec::new();
+    for (name, _) in rows.iter().flatten() {
+        if !header.contains(&name.as_str()) {
+            header.push(name);
+        }
+    }
+
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer.write_record(&header)?;
+
+    for row in &rows {
+        let values: HashMap<&str, &str> = row
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        csv_writer.write_record(
+            header
+                .iter()
+                .map(|name| values.get(name).copied().unwrap_or_default()),
+        )?;
+    }
+
+    csv_writer.flush()?;
+    Ok(())
+}
+
+fn flatten(prefix: Option<&str>, value: &Value, fields: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let name = prefix.map_or_else(|| key.clone(), |prefix| format!("{prefix}.{key}"));
+                flatten(Some(&name), value, fields);
+            }
+        }
+        _ => {
+            let text = match value {
+                Value::Null => String::new(),
+                Value::String(text) => text.clone(),
+                _ => value.to_string(),
+            };
+            fields.push((prefix.unwrap_or("value").to_string(), text));
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    fn write(records: &[Value], format: OutputFormat) -> String {
+        let mut buffer = Vec::new();
+        write_records(records, format, &mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[rstest]
+    fn test_write_csv_flattens_nested_fields() {
+        let records = vec![
+            json!({"id": "A", "order": {"price": "1.5", "size": 2}, "ts": 1}),
+            json!({"id": "B,C", "order": {"price": null, "size": 3}, "extra": [1, 2], "ts": 2}),
+        ];
+
+        assert_eq!(
+            write(&records, OutputFormat::Csv),
+            "id,order.price,order.size,ts,extra\n\
+             A,1.5,2,1,\n\
+             \"B,C\",,3,2,\"[1,2]\"\n"
+        );
+    }
+
+    #[rstest]
+    fn test_write_json() {
+        let records = vec![json!({"id": "A"})];
+
+        assert_eq!(
+            write(&records, OutputFormat::Json),
+            "[\n  {\n    \"id\": \"A\"\n  }\n]\n"
+        );
+    }
+
+    #[rstest]
+    fn test_query_records_unsupported_type() {
+        let mut catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+
+        assert!(query_records(&mut catalog, "unknown", Vec::new(), None, None).is_err());
+    }
+
+    #[rstest]
+    fn test_query_records_from_catalog() {
+        let mut catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+        let quote = nautilus_model::data::stubs::quote_ethusdt_binance();
+        catalog
+            .write_to_parquet(vec![quote], None, None, None, None)
+            .unwrap();
+
+        let records = query_records(&mut catalog, "quotes", Vec::new(), None, None).unwrap();
+
+        assert_eq!(records, vec![serde_json::to_value(quote).unwrap()]);
+    }
+}
@@ -30,7 +30,7 @@ pub async fn run_database_command(opt: DatabaseOpt) -> anyhow::Result<()> {
                 config.username,
                 config.password,
                 config.database,
-            );
+            )?;
             let pg = connect_pg(pg_connect_options.clone().into()).await?;
             log::info!(
                 "Connected with Postgres on url: {}",
@@ -51,7 +51,7 @@ pub async fn run_database_command(opt: DatabaseOpt) -> anyhow::Result<()> {
                 config.username,
                 config.password,
                 config.database,
-            );
+            )?;
             let pg = connect_pg(pg_connect_options.clone().into()).await?;
             log::info!(
                 "Connected with Postgres on url: {}",
@@ -21,10 +21,12 @@
 #![deny(clippy::missing_panics_doc)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+mod catalog;
 mod database;
 pub mod opt;
 
 use crate::{
+    catalog::run_catalog_command,
     database::postgres::run_database_command,
     opt::{Commands, NautilusCli},
 };
@@ -37,6 +39,7 @@ use crate::{
 pub async fn run(opt: NautilusCli) -> anyhow::Result<()> {
     match opt.command {
         Commands::Database(database_opt) => run_database_command(database_opt).await?,
+        Commands::Catalog(catalog_opt) => run_catalog_command(catalog_opt).await?,
     }
     Ok(())
 }
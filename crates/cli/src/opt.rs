@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+use nautilus_core::UnixNanos;
 
 #[derive(Debug, Parser)]
 #[clap(version, about, author)]
@@ -25,6 +28,7 @@ pub struct NautilusCli {
 #[derive(Parser, Debug)]
 pub enum Commands {
     Database(DatabaseOpt),
+    Catalog(CatalogOpt),
 }
 
 #[derive(Parser, Debug)]
@@ -64,3 +68,180 @@ pub enum DatabaseCommand {
     /// Drops roles, privileges and deletes all data from the database.
     Drop(DatabaseConfig),
 }
+
+#[derive(Parser, Debug)]
+#[command(about = "Parquet data catalog operations", long_about = None)]
+pub struct CatalogOpt {
+    #[clap(subcommand)]
+    pub command: CatalogCommand,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct CatalogConfig {
+    /// Path or URI of the data catalog, e.g. `/data/catalog` or `s3://bucket/catalog`.
+    #[arg(long, env = "NAUTILUS_CATALOG_PATH")]
+    pub path: String,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct CatalogListConfig {
+    #[command(flatten)]
+    pub catalog: CatalogConfig,
+    /// Only list data of this type, e.g. `quotes`.
+    #[arg(long)]
+    pub data_type: Option<String>,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct CatalogStatsConfig {
+    #[command(flatten)]
+    pub catalog: CatalogConfig,
+    /// Only show data of this type, e.g. `quotes`.
+    #[arg(long)]
+    pub data_type: Option<String>,
+    /// Only show data for this instrument ID or bar type.
+    #[arg(long)]
+    pub identifier: Option<String>,
+    /// Minimum time (seconds) between consecutive records reported as a gap.
+    #[arg(long, default_value_t = 3600)]
+    pub min_gap_secs: u64,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct CatalogConsolidateConfig {
+    #[command(flatten)]
+    pub catalog: CatalogConfig,
+    /// Only consolidate data of this type, e.g. `quotes` (all data if not set).
+    #[arg(long)]
+    pub data_type: Option<String>,
+    /// Only consolidate data for this instrument ID or bar type.
+    #[arg(long, requires = "data_type")]
+    pub identifier: Option<String>,
+}
+
+/// Output format for catalog queries.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Comma-separated values with a header row, nested fields flattened to dotted names.
+    Csv,
+    /// A JSON array of records.
+    Json,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct CatalogQueryConfig {
+    #[command(flatten)]
+    pub catalog: CatalogConfig,
+    /// Data type to query, e.g. `quotes`, `bars` or `order_events`.
+    #[arg(long)]
+    pub data_type: String,
+    /// Instrument IDs or bar types to query (all stored if not set).
+    #[arg(long = "identifier")]
+    pub identifiers: Vec<String>,
+    /// Start of the time range: UNIX nanoseconds, seconds or an RFC 3339 timestamp.
+    #[arg(long, value_parser = parse_timestamp)]
+    pub start: Option<UnixNanos>,
+    /// End of the time range (inclusive): UNIX nanoseconds, seconds or an RFC 3339 timestamp.
+    #[arg(long, value_parser = parse_timestamp)]
+    pub end: Option<UnixNanos>,
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Csv)]
+    pub format: OutputFormat,
+    /// File to write the output to (standard output if not set).
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Tardis CSV dataset types.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TardisCsvType {
+    /// `incremental_book_L2` order book deltas.
+    Deltas,
+    /// `book_snapshot_5` order book depth snapshots.
+    Depth10Snapshot5,
+    /// `book_snapshot_25` order book depth snapshots.
+    Depth10Snapshot25,
+    /// `quotes` top of book quotes.
+    Quotes,
+    /// `trades` trades.
+    Trades,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct ImportTardisConfig {
+    #[command(flatten)]
+    pub catalog: CatalogConfig,
+    /// Path to the Tardis CSV file (optionally gzip compressed).
+    #[arg(long)]
+    pub file: PathBuf,
+    /// Tardis dataset type of the file.
+    #[arg(long, value_enum)]
+    pub data_type: TardisCsvType,
+    /// Instrument ID to assign to all records (derived from each record if not set).
+    #[arg(long)]
+    pub instrument_id: Option<String>,
+    /// Price precision (inferred from the file if not set).
+    #[arg(long)]
+    pub price_precision: Option<u8>,
+    /// Size precision (inferred from the file if not set).
+    #[arg(long)]
+    pub size_precision: Option<u8>,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct ImportDatabentoConfig {
+    #[command(flatten)]
+    pub catalog: CatalogConfig,
+    /// Path to the zstd compressed Databento DBN file (`.dbn.zst`).
+    #[arg(long)]
+    pub file: PathBuf,
+    /// Instrument ID to assign to all records (derived from each record if not set).
+    #[arg(long)]
+    pub instrument_id: Option<String>,
+    /// Price precision (derived from the instrument if not set).
+    #[arg(long)]
+    pub price_precision: Option<u8>,
+    /// Path to the Databento `publishers.json` file (next to the executable if not set).
+    #[arg(long)]
+    pub publishers: Option<PathBuf>,
+    /// Use the exchange rather than the dataset venue for instrument definitions.
+    #[arg(long)]
+    pub use_exchange_as_venue: bool,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct ConvertFeatherConfig {
+    #[command(flatten)]
+    pub catalog: CatalogConfig,
+    /// Instance ID of the session which streamed the feather files.
+    #[arg(long)]
+    pub instance_id: String,
+    /// Catalog subdirectory the session streamed to.
+    #[arg(long, default_value = "live")]
+    pub subdirectory: String,
+}
+
+#[derive(Parser, Debug, Clone)]
+#[command(about = "Parquet data catalog operations", long_about = None)]
+pub enum CatalogCommand {
+    /// Lists the data types and identifiers stored with their time ranges.
+    List(CatalogListConfig),
+    /// Shows the row counts and time gaps of the stored data.
+    Stats(CatalogStatsConfig),
+    /// Consolidates the files of each data directory.
+    Consolidate(CatalogConsolidateConfig),
+    /// Queries data to CSV or JSON.
+    Query(CatalogQueryConfig),
+    /// Imports a Tardis CSV file.
+    ImportTardis(ImportTardisConfig),
+    /// Imports a Databento DBN file.
+    ImportDatabento(ImportDatabentoConfig),
+    /// Converts the feather files streamed by a session into catalog data.
+    ConvertFeather(ConvertFeatherConfig),
+}
+
+fn parse_timestamp(value: &str) -> Result<UnixNanos, String> {
+    value
+        .parse()
+        .map_err(|e| format!("Invalid timestamp `{value}`: {e}"))
+}
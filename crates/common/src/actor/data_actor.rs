@@ -34,10 +34,15 @@ use ahash::{AHashMap, AHashSet};
 use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 use nautilus_core::{UUID4, UnixNanos, correctness::check_predicate_true};
+#[cfg(feature = "indicators")]
+use nautilus_indicators::{
+    indicator::Indicator,
+    pipeline::{IndicatorPipeline, IndicatorPipelineConfig},
+};
 use nautilus_model::{
     data::{
         Bar, BarType, DataType, IndexPriceUpdate, InstrumentStatus, MarkPriceUpdate,
-        OrderBookDeltas, QuoteTick, TradeTick, close::InstrumentClose,
+        OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick, close::InstrumentClose,
     },
     enums::BookType,
     identifiers::{ActorId, ClientId, ComponentId, InstrumentId, TraderId, Venue},
@@ -64,14 +69,14 @@ use crate::{
             BarsResponse, BookResponse, CustomDataResponse, DataCommand, InstrumentResponse,
             InstrumentsResponse, QuotesResponse, RequestBars, RequestBookSnapshot, RequestCommand,
             RequestCustomData, RequestInstrument, RequestInstruments, RequestQuotes, RequestTrades,
-            SubscribeBars, SubscribeBookDeltas, SubscribeBookSnapshots, SubscribeCommand,
-            SubscribeCustomData, SubscribeIndexPrices, SubscribeInstrument,
+            SubscribeBars, SubscribeBookDeltas, SubscribeBookDepth10, SubscribeBookSnapshots,
+            SubscribeCommand, SubscribeCustomData, SubscribeIndexPrices, SubscribeInstrument,
             SubscribeInstrumentClose, SubscribeInstrumentStatus, SubscribeInstruments,
             SubscribeMarkPrices, SubscribeQuotes, SubscribeTrades, TradesResponse, UnsubscribeBars,
-            UnsubscribeBookDeltas, UnsubscribeBookSnapshots, UnsubscribeCommand,
-            UnsubscribeCustomData, UnsubscribeIndexPrices, UnsubscribeInstrument,
-            UnsubscribeInstrumentClose, UnsubscribeInstrumentStatus, UnsubscribeInstruments,
-            UnsubscribeMarkPrices, UnsubscribeQuotes, UnsubscribeTrades,
+            UnsubscribeBookDeltas, UnsubscribeBookDepth10, UnsubscribeBookSnapshots,
+            UnsubscribeCommand, UnsubscribeCustomData, UnsubscribeIndexPrices,
+            UnsubscribeInstrument, UnsubscribeInstrumentClose, UnsubscribeInstrumentStatus,
+            UnsubscribeInstruments, UnsubscribeMarkPrices, UnsubscribeQuotes, UnsubscribeTrades,
         },
         system::ShutdownSystem,
     },
@@ -80,9 +85,10 @@ use crate::{
         handler::{MessageHandler, ShareableMessageHandler, TypedMessageHandler},
         switchboard::{
             self, MessagingSwitchboard, get_bars_topic, get_book_deltas_topic,
-            get_book_snapshots_topic, get_custom_topic, get_index_price_topic,
-            get_instrument_close_topic, get_instrument_status_topic, get_instrument_topic,
-            get_instruments_topic, get_mark_price_topic, get_quotes_topic, get_trades_topic,
+            get_book_depth10_topic, get_book_snapshots_topic, get_custom_topic,
+            get_index_price_topic, get_instrument_close_topic, get_instrument_status_topic,
+            get_instrument_topic, get_instruments_topic, get_mark_price_topic, get_quotes_topic,
+            get_trades_topic,
         },
     },
     signal::Signal,
@@ -98,6 +104,8 @@ pub struct DataActorConfig {
     pub log_events: bool,
     /// If commands should be logged.
     pub log_commands: bool,
+    /// If registered indicator state should be saved to the cache on stop and restored on start.
+    pub persist_indicator_state: bool,
 }
 
 impl Default for DataActorConfig {
@@ -106,6 +114,7 @@ impl Default for DataActorConfig {
             actor_id: None,
             log_events: true,
             log_commands: true,
+            persist_indicator_state: false,
         }
     }
 }
@@ -286,6 +295,15 @@ pub trait DataActor {
         Ok(())
     }
 
+    /// Actions to be performed when receiving an order book depth snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the book depth fails.
+    fn on_book_depth(&mut self, depth: &OrderBookDepth10) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     /// Actions to be performed when receiving an order book.
     ///
     /// # Errors
@@ -461,6 +479,9 @@ pub trait DataActor {
     fn handle_book_deltas(&mut self, deltas: &OrderBookDeltas) {
         log_received(&deltas);
 
+        #[cfg(feature = "indicators")]
+        self.core().indicators.handle_deltas(deltas);
+
         if self.not_running() {
             log_not_running(&deltas);
             return;
@@ -471,10 +492,30 @@ pub trait DataActor {
         }
     }
 
+    /// Handles a received order book depth snapshot.
+    fn handle_book_depth(&mut self, depth: &OrderBookDepth10) {
+        log_received(&depth);
+
+        #[cfg(feature = "indicators")]
+        self.core().indicators.handle_depth(depth);
+
+        if self.not_running() {
+            log_not_running(&depth);
+            return;
+        }
+
+        if let Err(e) = self.on_book_depth(depth) {
+            log_error(&e);
+        }
+    }
+
     /// Handles a received order book reference.
     fn handle_book(&mut self, book: &OrderBook) {
         log_received(&book);
 
+        #[cfg(feature = "indicators")]
+        self.core().indicators.handle_book(book);
+
         if self.not_running() {
             log_not_running(&book);
             return;
@@ -489,6 +530,9 @@ pub trait DataActor {
     fn handle_quote(&mut self, quote: &QuoteTick) {
         log_received(&quote);
 
+        #[cfg(feature = "indicators")]
+        self.core().indicators.handle_quote(quote);
+
         if self.not_running() {
             log_not_running(&quote);
             return;
@@ -503,6 +547,9 @@ pub trait DataActor {
     fn handle_trade(&mut self, trade: &TradeTick) {
         log_received(&trade);
 
+        #[cfg(feature = "indicators")]
+        self.core().indicators.handle_trade(trade);
+
         if self.not_running() {
             log_not_running(&trade);
             return;
@@ -517,6 +564,9 @@ pub trait DataActor {
     fn handle_bar(&mut self, bar: &Bar) {
         log_received(&bar);
 
+        #[cfg(feature = "indicators")]
+        self.core().indicators.handle_bar(bar);
+
         if self.not_running() {
             log_not_running(&bar);
             return;
@@ -670,6 +720,9 @@ pub trait DataActor {
     fn handle_bars_response(&mut self, response: &BarsResponse) {
         log_received(&response);
 
+        #[cfg(feature = "indicators")]
+        self.core().indicators.handle_bars(&response.data);
+
         if let Err(e) = self.on_historical_bars(&response.data) {
             log_error(&e);
         }
@@ -795,6 +848,39 @@ pub trait DataActor {
         );
     }
 
+    /// Subscribe to streaming [`OrderBookDepth10`] for the `instrument_id`.
+    fn subscribe_book_depth10(
+        &mut self,
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        depth: Option<NonZeroUsize>,
+        client_id: Option<ClientId>,
+        managed: bool,
+        params: Option<IndexMap<String, String>>,
+    ) where
+        Self: 'static + Debug + Sized,
+    {
+        let actor_id = self.actor_id().inner();
+        let topic = get_book_depth10_topic(instrument_id);
+
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |depth: &OrderBookDepth10| {
+                get_actor_unchecked::<Self>(&actor_id).handle_book_depth(depth);
+            },
+        )));
+
+        self.core_mut().subscribe_book_depth10(
+            topic,
+            handler,
+            instrument_id,
+            book_type,
+            depth,
+            client_id,
+            managed,
+            params,
+        );
+    }
+
     /// Subscribe to [`OrderBook`] snapshots at a specified interval for the `instrument_id`.
     fn subscribe_book_at_interval(
         &mut self,
@@ -1022,6 +1108,19 @@ pub trait DataActor {
             .unsubscribe_book_deltas(instrument_id, client_id, params);
     }
 
+    /// Unsubscribe from streaming [`OrderBookDepth10`] for the `instrument_id`.
+    fn unsubscribe_book_depth10(
+        &mut self,
+        instrument_id: InstrumentId,
+        client_id: Option<ClientId>,
+        params: Option<IndexMap<String, String>>,
+    ) where
+        Self: 'static + Debug + Sized,
+    {
+        self.core_mut()
+            .unsubscribe_book_depth10(instrument_id, client_id, params);
+    }
+
     /// Unsubscribe from [`OrderBook`] snapshots at a specified interval for the `instrument_id`.
     fn unsubscribe_book_at_interval(
         &mut self,
@@ -1380,6 +1479,11 @@ where
             return Err(e); // Halt state transition
         }
 
+        #[cfg(feature = "indicators")]
+        if self.core().config.persist_indicator_state {
+            self.core().load_indicator_state();
+        }
+
         self.core_mut()
             .transition_state(ComponentTrigger::StartCompleted)?;
 
@@ -1394,6 +1498,13 @@ where
             return Err(e); // Halt state transition
         }
 
+        #[cfg(feature = "indicators")]
+        if self.core().config.persist_indicator_state {
+            if let Err(e) = self.core().save_indicator_state() {
+                log_error(&e);
+            }
+        }
+
         self.core_mut()
             .transition_state(ComponentTrigger::StopCompleted)?;
 
@@ -1665,6 +1776,132 @@ impl DataActorCore {
         msgbus::send(endpoint, command.as_any());
     }
 
+    // -- INDICATORS ------------------------------------------------------------------------------
+
+    /// Registers an indicator to receive quotes for the given `instrument_id`.
+    #[cfg(feature = "indicators")]
+    pub fn register_indicator_for_quotes(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        self.indicators
+            .register_indicator_for_quotes(instrument_id, indicator);
+    }
+
+    /// Registers an indicator to receive trades for the given `instrument_id`.
+    #[cfg(feature = "indicators")]
+    pub fn register_indicator_for_trades(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        self.indicators
+            .register_indicator_for_trades(instrument_id, indicator);
+    }
+
+    /// Registers an indicator to receive order book deltas for the given `instrument_id`.
+    #[cfg(feature = "indicators")]
+    pub fn register_indicator_for_book_deltas(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        self.indicators
+            .register_indicator_for_book_deltas(instrument_id, indicator);
+    }
+
+    /// Registers an indicator to receive order book depth snapshots for the given `instrument_id`.
+    #[cfg(feature = "indicators")]
+    pub fn register_indicator_for_book_depth(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        self.indicators
+            .register_indicator_for_book_depth(instrument_id, indicator);
+    }
+
+    /// Registers an indicator to receive order book snapshots for the given `instrument_id`.
+    #[cfg(feature = "indicators")]
+    pub fn register_indicator_for_book_snapshots(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        self.indicators
+            .register_indicator_for_book_snapshots(instrument_id, indicator);
+    }
+
+    /// Registers an indicator to receive bars for the given `bar_type`.
+    #[cfg(feature = "indicators")]
+    pub fn register_indicator_for_bars(
+        &mut self,
+        bar_type: BarType,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        self.indicators
+            .register_indicator_for_bars(bar_type, indicator);
+    }
+
+    /// Creates an indicator pipeline from `config` and registers it to receive bars for the
+    /// given `bar_type`.
+    #[cfg(feature = "indicators")]
+    pub fn register_pipeline_for_bars(
+        &mut self,
+        bar_type: BarType,
+        config: &IndicatorPipelineConfig,
+    ) -> Rc<RefCell<IndicatorPipeline>> {
+        self.indicators.register_pipeline_for_bars(bar_type, config)
+    }
+
+    /// Returns whether all registered indicators are initialized.
+    #[cfg(feature = "indicators")]
+    pub fn indicators_initialized(&self) -> bool {
+        self.indicators.is_initialized()
+    }
+
+    /// Saves the state of all registered indicators to the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting an indicator state to the cache fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the actor has not been registered with a trader.
+    #[cfg(feature = "indicators")]
+    pub fn save_indicator_state(&self) -> anyhow::Result<()> {
+        let cache = self
+            .cache
+            .as_ref()
+            .expect("DataActor must be registered before saving indicator state");
+        let saved = self
+            .indicators
+            .save_state(self.actor_id, &mut cache.borrow_mut())?;
+
+        log::info!("Saved {saved} indicator state(s)");
+        Ok(())
+    }
+
+    /// Restores the state of all registered indicators from the cache.
+    ///
+    /// Indicators should be registered before calling this method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the actor has not been registered with a trader.
+    #[cfg(feature = "indicators")]
+    pub fn load_indicator_state(&self) {
+        let cache = self
+            .cache
+            .as_ref()
+            .expect("DataActor must be registered before loading indicator state");
+        let loaded = self.indicators.load_state(self.actor_id, &cache.borrow());
+
+        log::info!("Restored {loaded} indicator state(s)");
+    }
+
     // -- SUBSCRIPTIONS ---------------------------------------------------------------------------
 
     fn get_or_create_handler_for_topic<F>(
@@ -1835,6 +2072,38 @@ impl DataActorCore {
         self.send_data_cmd(DataCommand::Subscribe(command));
     }
 
+    /// Helper method for registering book depth subscriptions from the trait.
+    #[allow(clippy::too_many_arguments)]
+    pub fn subscribe_book_depth10(
+        &mut self,
+        topic: MStr<Topic>,
+        handler: ShareableMessageHandler,
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        depth: Option<NonZeroUsize>,
+        client_id: Option<ClientId>,
+        managed: bool,
+        params: Option<IndexMap<String, String>>,
+    ) {
+        self.check_registered();
+        self.topic_handlers.insert(topic, handler.clone());
+        msgbus::subscribe_topic(topic, handler, None);
+
+        let command = SubscribeCommand::BookDepth10(SubscribeBookDepth10 {
+            instrument_id,
+            book_type,
+            client_id,
+            venue: Some(instrument_id.venue),
+            command_id: UUID4::new(),
+            ts_init: self.generate_timestamp_ns(),
+            depth,
+            managed,
+            params,
+        });
+
+        self.send_data_cmd(DataCommand::Subscribe(command));
+    }
+
     /// Helper method for registering book snapshots subscriptions from the trait.
     pub fn subscribe_book_at_interval(
         &mut self,
@@ -2125,6 +2394,32 @@ impl DataActorCore {
         self.send_data_cmd(DataCommand::Unsubscribe(command));
     }
 
+    /// Helper method for unsubscribing from book depth.
+    pub fn unsubscribe_book_depth10(
+        &self,
+        instrument_id: InstrumentId,
+        client_id: Option<ClientId>,
+        params: Option<IndexMap<String, String>>,
+    ) {
+        self.check_registered();
+
+        let topic = get_book_depth10_topic(instrument_id);
+        if let Some(handler) = self.topic_handlers.get(&topic) {
+            msgbus::unsubscribe_topic(topic, handler.clone());
+        };
+
+        let command = UnsubscribeCommand::BookDepth10(UnsubscribeBookDepth10 {
+            instrument_id,
+            client_id,
+            venue: Some(instrument_id.venue),
+            command_id: UUID4::new(),
+            ts_init: self.generate_timestamp_ns(),
+            params,
+        });
+
+        self.send_data_cmd(DataCommand::Unsubscribe(command));
+    }
+
     /// Helper method for unsubscribing from book snapshots at interval.
     pub fn unsubscribe_book_at_interval(
         &mut self,
@@ -18,18 +18,31 @@
 #![allow(unused_variables)]
 #![allow(unused_imports)]
 
-use std::{collections::HashMap, sync::Arc};
+use std::{cell::RefCell, collections::HashMap, fmt::Display, hash::Hash, rc::Rc};
 
-use nautilus_indicators::indicator::Indicator;
-use nautilus_model::{data::BarType, identifiers::InstrumentId};
+use bytes::Bytes;
+use nautilus_indicators::{
+    indicator::Indicator,
+    pipeline::{IndicatorPipeline, IndicatorPipelineConfig},
+};
+use nautilus_model::{
+    data::{Bar, BarType, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick},
+    identifiers::{ActorId, InstrumentId},
+    orderbook::OrderBook,
+};
+
+use crate::cache::Cache;
 
 /// Contains all indicator-related references.
 #[derive(Default)]
 pub(crate) struct Indicators {
-    pub indicators: Vec<Arc<dyn Indicator>>,
-    pub indicators_for_quotes: HashMap<InstrumentId, Vec<Arc<dyn Indicator>>>,
-    pub indicators_for_trades: HashMap<InstrumentId, Vec<Arc<dyn Indicator>>>,
-    pub indicators_for_bars: HashMap<BarType, Vec<Arc<dyn Indicator>>>,
+    pub indicators: Vec<Rc<RefCell<dyn Indicator>>>,
+    pub indicators_for_quotes: HashMap<InstrumentId, Vec<Rc<RefCell<dyn Indicator>>>>,
+    pub indicators_for_trades: HashMap<InstrumentId, Vec<Rc<RefCell<dyn Indicator>>>>,
+    pub indicators_for_deltas: HashMap<InstrumentId, Vec<Rc<RefCell<dyn Indicator>>>>,
+    pub indicators_for_depth: HashMap<InstrumentId, Vec<Rc<RefCell<dyn Indicator>>>>,
+    pub indicators_for_books: HashMap<InstrumentId, Vec<Rc<RefCell<dyn Indicator>>>>,
+    pub indicators_for_bars: HashMap<BarType, Vec<Rc<RefCell<dyn Indicator>>>>,
 }
 
 impl Indicators {
@@ -41,78 +54,263 @@ impl Indicators {
 
         self.indicators
             .iter()
-            .all(|indicator| indicator.initialized())
+            .all(|indicator| indicator.borrow().initialized())
     }
 
     /// Register an indicator to receive quote ticks for the given instrument ID.
     pub fn register_indicator_for_quotes(
         &mut self,
         instrument_id: InstrumentId,
-        indicator: Arc<dyn Indicator>,
+        indicator: Rc<RefCell<dyn Indicator>>,
     ) {
-        // Add to overall indicators if not already present
-        if !self.indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            self.indicators.push(indicator.clone());
-        }
-
-        // Add to instrument-specific quotes indicators
-        let indicators = self.indicators_for_quotes.entry(instrument_id).or_default();
-
-        if !indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            indicators.push(indicator);
-            // TODO: Log registration
-        } else {
-            // TODO: Log error - already registered
-        }
+        register_indicator(
+            &mut self.indicators,
+            &mut self.indicators_for_quotes,
+            instrument_id,
+            indicator,
+        );
     }
 
     /// Register an indicator to receive trade ticks for the given instrument ID.
     pub fn register_indicator_for_trades(
         &mut self,
         instrument_id: InstrumentId,
-        indicator: Arc<dyn Indicator>,
+        indicator: Rc<RefCell<dyn Indicator>>,
     ) {
-        // Add to overall indicators if not already present
-        if !self.indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            self.indicators.push(indicator.clone());
-        }
+        register_indicator(
+            &mut self.indicators,
+            &mut self.indicators_for_trades,
+            instrument_id,
+            indicator,
+        );
+    }
 
-        // Add to instrument-specific trades indicators
-        let indicators = self.indicators_for_trades.entry(instrument_id).or_default();
+    /// Register an indicator to receive order book deltas for the given instrument ID.
+    pub fn register_indicator_for_book_deltas(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        register_indicator(
+            &mut self.indicators,
+            &mut self.indicators_for_deltas,
+            instrument_id,
+            indicator,
+        );
+    }
 
-        if !indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            indicators.push(indicator);
-            // TODO: Log registration
-        } else {
-            // TODO: Log error - already registered
-        }
+    /// Register an indicator to receive order book depth snapshots for the given instrument ID.
+    pub fn register_indicator_for_book_depth(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        register_indicator(
+            &mut self.indicators,
+            &mut self.indicators_for_depth,
+            instrument_id,
+            indicator,
+        );
+    }
+
+    /// Register an indicator to receive order book snapshots for the given instrument ID.
+    pub fn register_indicator_for_book_snapshots(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        register_indicator(
+            &mut self.indicators,
+            &mut self.indicators_for_books,
+            instrument_id,
+            indicator,
+        );
     }
 
     /// Register an indicator to receive bar data for the given bar type.
     pub fn register_indicator_for_bars(
         &mut self,
         bar_type: BarType,
-        indicator: Arc<dyn Indicator>,
+        indicator: Rc<RefCell<dyn Indicator>>,
     ) {
-        // Add to overall indicators if not already present
-        if !self.indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            self.indicators.push(indicator.clone());
+        register_indicator(
+            &mut self.indicators,
+            &mut self.indicators_for_bars,
+            bar_type.standard(),
+            indicator,
+        );
+    }
+
+    /// Creates an indicator pipeline from `config` and registers it to receive bar data for
+    /// the given bar type.
+    ///
+    /// Returns the pipeline so its output can be read by the actor.
+    pub fn register_pipeline_for_bars(
+        &mut self,
+        bar_type: BarType,
+        config: &IndicatorPipelineConfig,
+    ) -> Rc<RefCell<IndicatorPipeline>> {
+        let pipeline = Rc::new(RefCell::new(IndicatorPipeline::new(config)));
+        self.register_indicator_for_bars(bar_type, pipeline.clone());
+        pipeline
+    }
+
+    /// Updates the indicators registered for the quotes instrument ID.
+    pub fn handle_quote(&self, quote: &QuoteTick) {
+        if let Some(indicators) = self.indicators_for_quotes.get(&quote.instrument_id) {
+            for indicator in indicators {
+                indicator.borrow_mut().handle_quote(quote);
+            }
+        }
+    }
+
+    /// Updates the indicators registered for the trades instrument ID.
+    pub fn handle_trade(&self, trade: &TradeTick) {
+        if let Some(indicators) = self.indicators_for_trades.get(&trade.instrument_id) {
+            for indicator in indicators {
+                indicator.borrow_mut().handle_trade(trade);
+            }
+        }
+    }
+
+    /// Updates the indicators registered for the deltas instrument ID.
+    pub fn handle_deltas(&self, deltas: &OrderBookDeltas) {
+        if let Some(indicators) = self.indicators_for_deltas.get(&deltas.instrument_id) {
+            for indicator in indicators {
+                indicator.borrow_mut().handle_deltas(deltas);
+            }
+        }
+    }
+
+    /// Updates the indicators registered for the depth instrument ID.
+    pub fn handle_depth(&self, depth: &OrderBookDepth10) {
+        if let Some(indicators) = self.indicators_for_depth.get(&depth.instrument_id) {
+            for indicator in indicators {
+                indicator.borrow_mut().handle_depth(depth);
+            }
+        }
+    }
+
+    /// Updates the indicators registered for the books instrument ID.
+    pub fn handle_book(&self, book: &OrderBook) {
+        if let Some(indicators) = self.indicators_for_books.get(&book.instrument_id) {
+            for indicator in indicators {
+                indicator.borrow_mut().handle_book(book);
+            }
+        }
+    }
+
+    /// Updates the indicators registered for the bars standard bar type.
+    pub fn handle_bar(&self, bar: &Bar) {
+        if let Some(indicators) = self.indicators_for_bars.get(&bar.bar_type.standard()) {
+            for indicator in indicators {
+                indicator.borrow_mut().handle_bar(bar);
+            }
+        }
+    }
+
+    /// Updates the indicators registered for bars from historical `bars` in a single pass.
+    pub fn handle_bars(&self, bars: &[Bar]) {
+        for bar in bars {
+            self.handle_bar(bar);
         }
+    }
 
-        // Get standard bar type
-        let standard_bar_type = bar_type.standard();
+    /// Saves a state snapshot of each registered indicator to the `cache`.
+    ///
+    /// Indicators which do not support state snapshots are skipped with a warning.
+    /// Returns the number of indicator states saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting a snapshot to the cache fails.
+    pub fn save_state(&self, actor_id: ActorId, cache: &mut Cache) -> anyhow::Result<usize> {
+        let mut saved = 0;
 
-        // Add to bar type-specific indicators
-        let indicators = self
-            .indicators_for_bars
-            .entry(standard_bar_type)
-            .or_default();
+        for (index, indicator) in self.indicators.iter().enumerate() {
+            let indicator = indicator.borrow();
+            let key = state_key(actor_id, index, &*indicator);
 
-        if !indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            indicators.push(indicator);
-            // TODO: Log registration
-        } else {
-            // TODO: Log error - already registered
+            match indicator.snapshot_state() {
+                Ok(state) => {
+                    cache.add(&key, Bytes::from(state))?;
+                    saved += 1;
+                }
+                Err(e) => log::warn!("Not saving {} state: {e}", indicator.name()),
+            }
         }
+
+        Ok(saved)
     }
+
+    /// Restores the state of each registered indicator from snapshots in the `cache`.
+    ///
+    /// Indicators without a saved snapshot, or whose snapshot fails to restore, are left as is.
+    /// Returns the number of indicator states restored.
+    pub fn load_state(&self, actor_id: ActorId, cache: &Cache) -> usize {
+        let mut loaded = 0;
+
+        for (index, indicator) in self.indicators.iter().enumerate() {
+            let mut indicator = indicator.borrow_mut();
+            let key = state_key(actor_id, index, &*indicator);
+
+            let state = match cache.get(&key) {
+                Ok(Some(state)) => state,
+                Ok(None) => {
+                    log::debug!("No saved state for {}", indicator.name());
+                    continue;
+                }
+                Err(e) => {
+                    log::warn!("Invalid indicator state key {key}: {e}");
+                    continue;
+                }
+            };
+
+            match indicator.restore_state(state) {
+                Ok(()) => loaded += 1,
+                Err(e) => log::warn!("Not restoring {} state: {e}", indicator.name()),
+            }
+        }
+
+        loaded
+    }
+}
+
+/// Adds `indicator` to the overall `indicators` (if not already present) and to the
+/// indicators registered under `key` in `registry`.
+fn register_indicator<K>(
+    indicators: &mut Vec<Rc<RefCell<dyn Indicator>>>,
+    registry: &mut HashMap<K, Vec<Rc<RefCell<dyn Indicator>>>>,
+    key: K,
+    indicator: Rc<RefCell<dyn Indicator>>,
+) where
+    K: Copy + Eq + Hash + Display,
+{
+    if !indicators.iter().any(|i| Rc::ptr_eq(i, &indicator)) {
+        indicators.push(indicator.clone());
+    }
+
+    let registered = registry.entry(key).or_default();
+
+    if registered.iter().any(|i| Rc::ptr_eq(i, &indicator)) {
+        log::error!(
+            "Indicator {} already registered for {key}",
+            indicator.borrow().name()
+        );
+        return;
+    }
+
+    log::info!(
+        "Registered indicator {} for {key}",
+        indicator.borrow().name()
+    );
+    registered.push(indicator);
+}
+
+/// Returns the cache key for an indicator state snapshot.
+///
+/// Keys are scoped by actor and registration order, so indicators must be registered in
+/// the same order on each start for their state to be restored.
+fn state_key(actor_id: ActorId, index: usize, indicator: &dyn Indicator) -> String {
+    format!("{actor_id}:indicator:{index}:{}", indicator.name())
 }
@@ -24,13 +24,13 @@ use std::{
 
 use bytes::Bytes;
 use log::LevelFilter;
-use nautilus_core::UnixNanos;
+use nautilus_core::{UUID4, UnixNanos};
 use nautilus_model::{
     data::{
         Bar, BarType, BookOrder, DataType, IndexPriceUpdate, InstrumentStatus, MarkPriceUpdate,
-        OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick,
+        OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
         close::InstrumentClose,
-        stubs::{stub_instrument_close, stub_instrument_status},
+        stubs::{stub_depth10, stub_instrument_close, stub_instrument_status},
     },
     enums::{BookAction, BookType, OrderSide},
     identifiers::{ActorId, ClientId, TraderId, Venue},
@@ -58,10 +58,10 @@ use crate::{
     msgbus::{
         self, MessageBus, get_message_bus,
         switchboard::{
-            MessagingSwitchboard, get_bars_topic, get_book_deltas_topic, get_book_snapshots_topic,
-            get_custom_topic, get_index_price_topic, get_instrument_close_topic,
-            get_instrument_status_topic, get_instrument_topic, get_instruments_topic,
-            get_mark_price_topic, get_quotes_topic, get_trades_topic,
+            MessagingSwitchboard, get_bars_topic, get_book_deltas_topic, get_book_depth10_topic,
+            get_book_snapshots_topic, get_custom_topic, get_index_price_topic,
+            get_instrument_close_topic, get_instrument_status_topic, get_instrument_topic,
+            get_instruments_topic, get_mark_price_topic, get_quotes_topic, get_trades_topic,
         },
     },
     testing::init_logger_for_testing,
@@ -76,6 +76,7 @@ struct TestDataActor {
     pub received_data: Vec<String>, // Use string for simplicity
     pub received_books: Vec<OrderBook>,
     pub received_deltas: Vec<OrderBookDelta>,
+    pub received_depths: Vec<OrderBookDepth10>,
     pub received_quotes: Vec<QuoteTick>,
     pub received_trades: Vec<TradeTick>,
     pub received_bars: Vec<Bar>,
@@ -123,6 +124,11 @@ impl DataActor for TestDataActor {
         Ok(())
     }
 
+    fn on_book_depth(&mut self, depth: &OrderBookDepth10) -> anyhow::Result<()> {
+        self.received_depths.push(*depth);
+        Ok(())
+    }
+
     fn on_book(&mut self, book: &OrderBook) -> anyhow::Result<()> {
         self.received_books.push(book.clone());
         Ok(())
@@ -197,6 +203,7 @@ impl TestDataActor {
             received_data: Vec::new(),
             received_books: Vec::new(),
             received_deltas: Vec::new(),
+            received_depths: Vec::new(),
             received_quotes: Vec::new(),
             received_trades: Vec::new(),
             received_bars: Vec::new(),
@@ -473,6 +480,37 @@ fn test_unsubscribe_book_deltas(
     assert_eq!(actor.received_deltas.len(), 1);
 }
 
+#[rstest]
+fn test_subscribe_and_receive_book_depth10(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+) {
+    let actor_id = register_data_actor(clock.clone(), cache.clone(), trader_id);
+    let actor = get_actor_unchecked::<TestDataActor>(&actor_id);
+    actor.start().unwrap();
+
+    let depth = stub_depth10();
+    actor.subscribe_book_depth10(
+        depth.instrument_id,
+        BookType::L2_MBP,
+        None,
+        None,
+        false,
+        None,
+    );
+
+    let topic = get_book_depth10_topic(depth.instrument_id);
+    msgbus::publish(topic, &depth);
+
+    assert_eq!(actor.received_depths, vec![depth]);
+
+    actor.unsubscribe_book_depth10(depth.instrument_id, None, None);
+    msgbus::publish(topic, &depth);
+
+    assert_eq!(actor.received_depths.len(), 1);
+}
+
 #[rstest]
 fn test_subscribe_and_receive_book_at_interval(
     clock: Rc<RefCell<TestClock>>,
@@ -1258,3 +1296,174 @@ fn test_request_data(
     assert_eq!(actor.received_data.len(), 1);
     assert_eq!(actor.received_data[0], "Any { .. }");
 }
+
+#[cfg(feature = "indicators")]
+#[rstest]
+fn test_indicator_state_saved_on_stop_and_restored_on_start(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+) {
+    use nautilus_indicators::{
+        average::ema::ExponentialMovingAverage,
+        indicator::{Indicator, MovingAverage},
+    };
+
+    let config = DataActorConfig {
+        actor_id: Some(ActorId::from("ACTOR-001")),
+        persist_indicator_state: true,
+        ..Default::default()
+    };
+    let bar_type = BarType::from("AUD/USD.SIM-1-MINUTE-LAST-EXTERNAL");
+
+    let ema = Rc::new(RefCell::new(ExponentialMovingAverage::new(3, None)));
+    let mut actor = TestDataActor::new(config.clone());
+    actor
+        .register(trader_id, clock.clone(), cache.clone())
+        .unwrap();
+    actor
+        .core
+        .register_indicator_for_bars(bar_type, ema.clone());
+    actor.start().unwrap();
+    for i in 0..5 {
+        ema.borrow_mut().update_raw(f64::from(i));
+    }
+    actor.stop().unwrap();
+
+    // Simulate a restart with a fresh indicator
+    let restored = Rc::new(RefCell::new(ExponentialMovingAverage::new(3, None)));
+    let mut actor = TestDataActor::new(config);
+    actor.register(trader_id, clock, cache).unwrap();
+    actor
+        .core
+        .register_indicator_for_bars(bar_type, restored.clone());
+    actor.start().unwrap();
+
+    assert!(actor.core.indicators_initialized());
+    assert!(restored.borrow().initialized());
+    assert_eq!(restored.borrow().count(), 5);
+    assert_eq!(restored.borrow().value(), ema.borrow().value());
+}
+
+#[cfg(feature = "indicators")]
+#[rstest]
+fn test_pipeline_warmed_up_from_historical_bars_and_updated_by_live_bars(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+) {
+    use nautilus_indicators::{
+        average::MovingAverageType,
+        indicator::Indicator,
+        pipeline::{IndicatorPipelineConfig, PipelineSource, PipelineStageConfig},
+    };
+
+    let mut actor = TestDataActor::new(DataActorConfig::default());
+    actor.register(trader_id, clock, cache).unwrap();
+
+    let bar = Bar::default();
+    let config = IndicatorPipelineConfig::new(
+        PipelineSource::Close,
+        vec![
+            PipelineStageConfig::MovingAverage {
+                ma_type: MovingAverageType::Exponential,
+                period: 2,
+            },
+            PipelineStageConfig::ZScore { period: 2 },
+        ],
+    );
+    let pipeline = actor.core.register_pipeline_for_bars(bar.bar_type, &config);
+
+    let response = BarsResponse::new(
+        UUID4::new(),
+        ClientId::new("TestClient"),
+        bar.bar_type,
+        vec![bar; 3],
+        UnixNanos::default(),
+        None,
+    );
+    actor.handle_bars_response(&response);
+
+    assert!(pipeline.borrow().initialized());
+    assert_eq!(pipeline.borrow().stage_values()[0], bar.close.as_f64());
+
+    actor.start().unwrap();
+    actor.handle_bar(&bar);
+
+    assert_eq!(actor.received_bars.len(), 4);
+    assert_eq!(pipeline.borrow().value, 0.0);
+}
+
+#[cfg(feature = "indicators")]
+#[rstest]
+fn test_book_indicators_updated_from_deltas_depth_and_snapshots(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+) {
+    use nautilus_indicators::{book::ofi::OrderFlowImbalance, indicator::Indicator};
+
+    let actor_id = register_data_actor(clock.clone(), cache.clone(), trader_id);
+    let actor = get_actor_unchecked::<TestDataActor>(&actor_id);
+
+    let depth = stub_depth10();
+    let instrument_id = depth.instrument_id;
+    let book_type = BookType::L2_MBP;
+
+    let deltas_ofi = Rc::new(RefCell::new(OrderFlowImbalance::new(10, Some(book_type))));
+    let depth_ofi = Rc::new(RefCell::new(OrderFlowImbalance::new(10, Some(book_type))));
+    let book_ofi = Rc::new(RefCell::new(OrderFlowImbalance::new(10, Some(book_type))));
+    actor
+        .core
+        .register_indicator_for_book_deltas(instrument_id, deltas_ofi.clone());
+    actor
+        .core
+        .register_indicator_for_book_depth(instrument_id, depth_ofi.clone());
+    actor
+        .core
+        .register_indicator_for_book_snapshots(instrument_id, book_ofi.clone());
+    actor.start().unwrap();
+
+    actor.subscribe_book_deltas(instrument_id, book_type, None, None, false, None);
+    actor.subscribe_book_depth10(instrument_id, book_type, None, None, false, None);
+    actor.subscribe_book_at_interval(
+        instrument_id,
+        book_type,
+        None,
+        NonZeroUsize::new(1_000).unwrap(),
+        None,
+        None,
+    );
+
+    let order = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("100.00"),
+        Quantity::from("100"),
+        1,
+    );
+    let delta = OrderBookDelta::new(
+        instrument_id,
+        BookAction::Add,
+        order,
+        0,
+        1,
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    );
+    let deltas = OrderBookDeltas::new(instrument_id, vec![delta]);
+    let mut book = OrderBook::new(instrument_id, book_type);
+    book.apply_depth(&depth);
+
+    // Each indicator only receives the data type it was registered for
+    msgbus::publish(get_book_deltas_topic(instrument_id), &deltas);
+    assert!(deltas_ofi.borrow().has_inputs());
+    assert!(!depth_ofi.borrow().has_inputs());
+    assert!(!book_ofi.borrow().has_inputs());
+
+    msgbus::publish(get_book_depth10_topic(instrument_id), &depth);
+    assert!(depth_ofi.borrow().has_inputs());
+    assert!(!book_ofi.borrow().has_inputs());
+
+    msgbus::publish(get_book_snapshots_topic(instrument_id), &book);
+    assert!(book_ofi.borrow().has_inputs());
+}
@@ -0,0 +1,900 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Exchange trading calendars and session schedules.
+//!
+//! A [`TradingCalendar`] describes the regular session of an exchange in its local timezone,
+//! along with holidays, early closes, late opens and intraday breaks. A session whose open
+//! is later than its close (such as CME Globex opening at 17:00 for a 16:00 close) starts on
+//! the calendar day before its trading date.
+//!
+//! Calendars are defined in JSON (see [`CalendarDefinition`]), and the definitions of major
+//! exchanges are bundled and available through [`builtin_calendar`].
+//!
+//! Holidays and early closes are only known for the trading dates a calendar covers
+//! (`valid_from` to `valid_until`). Querying a single date outside the coverage is an error,
+//! and no sessions are returned outside it.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+    sync::LazyLock,
+};
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::InstrumentStatus, enums::MarketStatusAction, identifiers::InstrumentId,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+/// The maximum number of days searched for the next session transition.
+const MAX_SEARCH_DAYS: usize = 366;
+
+static BUILTIN_CALENDARS: LazyLock<Vec<TradingCalendar>> = LazyLock::new(|| {
+    parse_calendars(include_str!("calendars.json")).expect("Bundled calendars should be valid")
+});
+
+/// Represents the definition of a trading calendar, as loaded from a data file.
+///
+/// Times are local to the calendar `timezone`, in `HH:MM` or `HH:MM:SS` format.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CalendarDefinition {
+    /// The calendar name (typically the exchange MIC).
+    pub name: String,
+    /// Alternative names the calendar can be looked up by.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// The calendar description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The IANA timezone of the exchange (e.g. `America/New_York`).
+    pub timezone: String,
+    /// The regular session open time.
+    pub open: NaiveTime,
+    /// The regular session close time.
+    pub close: NaiveTime,
+    /// The weekdays of the trading dates (Monday to Friday if empty).
+    #[serde(default)]
+    pub weekdays: Vec<Weekday>,
+    /// The intraday breaks (e.g. lunch or maintenance breaks) as start and end times.
+    #[serde(default)]
+    pub breaks: Vec<(NaiveTime, NaiveTime)>,
+    /// The trading dates on which the exchange is closed.
+    #[serde(default)]
+    pub holidays: Vec<NaiveDate>,
+    /// The close times of trading dates with an early close.
+    #[serde(default)]
+    pub early_closes: BTreeMap<NaiveDate, NaiveTime>,
+    /// The open times of trading dates with a late open.
+    #[serde(default)]
+    pub late_opens: BTreeMap<NaiveDate, NaiveTime>,
+    /// The first trading date covered by the calendar (unbounded if `None`).
+    #[serde(default)]
+    pub valid_from: Option<NaiveDate>,
+    /// The last trading date covered by the calendar (unbounded if `None`).
+    #[serde(default)]
+    pub valid_until: Option<NaiveDate>,
+}
+
+/// Represents the trading session of a single trading date.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradingSession {
+    /// The trading date of the session.
+    pub date: NaiveDate,
+    /// UNIX timestamp (nanoseconds) when the session opens.
+    pub open: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the session closes.
+    pub close: UnixNanos,
+    /// The intraday breaks of the session as start and end UNIX timestamps (nanoseconds).
+    pub breaks: Vec<(UnixNanos, UnixNanos)>,
+}
+
+impl TradingSession {
+    /// Returns whether the market is open at `ts` during this session.
+    #[must_use]
+    pub fn is_open(&self, ts: UnixNanos) -> bool {
+        self.open <= ts
+            && ts < self.close
+            && !self
+                .breaks
+                .iter()
+                .any(|(start, end)| *start <= ts && ts < *end)
+    }
+
+    /// Returns the market status transitions of the session in time order.
+    #[must_use]
+    pub fn transitions(&self) -> Vec<(UnixNanos, MarketStatusAction)> {
+        let mut transitions = Vec::with_capacity(2 + 2 * self.breaks.len());
+        transitions.push((self.open, MarketStatusAction::Trading));
+        for (start, end) in &self.breaks {
+            transitions.push((*start, MarketStatusAction::Pause));
+            transitions.push((*end, MarketStatusAction::Trading));
+        }
+        transitions.push((self.close, MarketStatusAction::Close));
+        transitions
+    }
+}
+
+/// Provides the trading sessions of an exchange, accounting for holidays, early closes,
+/// late opens and intraday breaks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradingCalendar {
+    name: Ustr,
+    aliases: Vec<Ustr>,
+    description: Option<String>,
+    timezone: Tz,
+    open: NaiveTime,
+    close: NaiveTime,
+    weekdays: Vec<Weekday>,
+    breaks: Vec<(NaiveTime, NaiveTime)>,
+    holidays: BTreeSet<NaiveDate>,
+    early_closes: BTreeMap<NaiveDate, NaiveTime>,
+    late_opens: BTreeMap<NaiveDate, NaiveTime>,
+    valid_from: Option<NaiveDate>,
+    valid_until: Option<NaiveDate>,
+}
+
+impl TradingCalendar {
+    /// Creates a new [`TradingCalendar`] instance from the given `definition`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The timezone is not a valid IANA timezone.
+    /// - The open and close times are equal.
+    /// - A break does not end after it starts.
+    /// - The coverage ends before it starts.
+    pub fn from_definition(definition: CalendarDefinition) -> anyhow::Result<Self> {
+        let timezone: Tz = definition.timezone.parse().map_err(|e| {
+            anyhow::anyhow!(
+                "Invalid timezone `{}` for calendar {}: {e}",
+                definition.timezone,
+                definition.name
+            )
+        })?;
+
+        if definition.open == definition.close {
+            anyhow::bail!(
+                "Open and close times of calendar {} must differ",
+                definition.name
+            );
+        }
+
+        if let Some((start, end)) = definition.breaks.iter().find(|(start, end)| start >= end) {
+            anyhow::bail!(
+                "Break {start}-{end} of calendar {} must end after it starts",
+                definition.name
+            );
+        }
+
+        if let (Some(valid_from), Some(valid_until)) =
+            (definition.valid_from, definition.valid_until)
+        {
+            if valid_from > valid_until {
+                anyhow::bail!(
+                    "Coverage {valid_from} to {valid_until} of calendar {} must not end before it starts",
+                    definition.name
+                );
+            }
+        }
+
+        let weekdays = if definition.weekdays.is_empty() {
+            vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ]
+        } else {
+            definition.weekdays
+        };
+
+        Ok(Self {
+            name: Ustr::from(&definition.name),
+            aliases: definition
+                .aliases
+                .iter()
+                .map(|alias| Ustr::from(alias))
+                .collect(),
+            description: definition.description,
+            timezone,
+            open: definition.open,
+            close: definition.close,
+            weekdays,
+            breaks: definition.breaks,
+            holidays: definition.holidays.into_iter().collect(),
+            early_closes: definition.early_closes,
+            late_opens: definition.late_opens,
+            valid_from: definition.valid_from,
+            valid_until: definition.valid_until,
+        })
+    }
+
+    /// Creates a new [`TradingCalendar`] instance from a session template of regular
+    /// `open` and `close` times on `weekdays` (Monday to Friday if empty), without holidays
+    /// or coverage bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the open and close times are equal.
+    pub fn from_session_template(
+        name: &str,
+        timezone: Tz,
+        open: NaiveTime,
+        close: NaiveTime,
+        weekdays: Vec<Weekday>,
+    ) -> anyhow::Result<Self> {
+        Self::from_definition(CalendarDefinition {
+            name: name.to_string(),
+            aliases: Vec::new(),
+            description: None,
+            timezone: timezone.name().to_string(),
+            open,
+            close,
+            weekdays,
+            breaks: Vec::new(),
+            holidays: Vec::new(),
+            early_closes: BTreeMap::new(),
+            late_opens: BTreeMap::new(),
+            valid_from: None,
+            valid_until: None,
+        })
+    }
+
+    /// Returns the name of the calendar.
+    #[must_use]
+    pub const fn name(&self) -> Ustr {
+        self.name
+    }
+
+    /// Returns the description of the calendar.
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the timezone of the calendar.
+    #[must_use]
+    pub const fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Returns whether the calendar has the given `name` or alias (case-insensitive).
+    #[must_use]
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the first trading date covered by the calendar, if bounded.
+    #[must_use]
+    pub const fn valid_from(&self) -> Option<NaiveDate> {
+        self.valid_from
+    }
+
+    /// Returns the last trading date covered by the calendar, if bounded.
+    #[must_use]
+    pub const fn valid_until(&self) -> Option<NaiveDate> {
+        self.valid_until
+    }
+
+    /// Returns whether trading `date` is within the coverage of the calendar.
+    #[must_use]
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        self.valid_from.is_none_or(|valid_from| valid_from <= date)
+            && self
+                .valid_until
+                .is_none_or(|valid_until| date <= valid_until)
+    }
+
+    /// Returns whether the local date of `ts` is within the coverage of the calendar.
+    #[must_use]
+    pub fn covers_ts(&self, ts: UnixNanos) -> bool {
+        self.covers(self.local_date(ts))
+    }
+
+    /// Returns whether `date` is a trading date (a trading weekday which is not a holiday).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `date` is outside the coverage of the calendar.
+    pub fn is_trading_day(&self, date: NaiveDate) -> anyhow::Result<bool> {
+        self.check_covers(date)?;
+        Ok(self.is_trading_date(date))
+    }
+
+    /// Returns the trading session of `date`, or `None` if it is not a trading date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `date` is outside the coverage of the calendar.
+    pub fn session(&self, date: NaiveDate) -> anyhow::Result<Option<TradingSession>> {
+        self.check_covers(date)?;
+        Ok(self.covered_session(date))
+    }
+
+    /// Returns the trading sessions overlapping the range from `start` to `end`, in time order.
+    ///
+    /// Sessions of trading dates outside the coverage of the calendar are not returned.
+    #[must_use]
+    pub fn sessions(&self, start: UnixNanos, end: UnixNanos) -> Vec<TradingSession> {
+        let first = self.local_date(start) - Duration::days(1);
+        let last = self.local_date(end) + Duration::days(1);
+
+        self.covered_days(first)
+            .take_while(|date| *date <= last)
+            .filter_map(|date| self.covered_session(date))
+            .filter(|session| session.open <= end && start < session.close)
+            .collect()
+    }
+
+    /// Returns the trading session open at `ts` (including during its breaks), if any.
+    #[must_use]
+    pub fn session_at(&self, ts: UnixNanos) -> Option<TradingSession> {
+        self.sessions(ts, ts)
+            .into_iter()
+            .find(|session| session.open <= ts && ts < session.close)
+    }
+
+    /// Returns the first trading session closing at or after `ts` (the session open at `ts`
+    /// if any), or `None` if there is no session within a year or the coverage of the calendar.
+    #[must_use]
+    pub fn next_session(&self, ts: UnixNanos) -> Option<TradingSession> {
+        let first = self.local_date(ts) - Duration::days(1);
+
+        self.covered_days(first)
+            .take(MAX_SEARCH_DAYS)
+            .filter_map(|date| self.covered_session(date))
+            .find(|session| ts <= session.close)
+    }
+
+    /// Returns whether the market is open at `ts`.
+    #[must_use]
+    pub fn is_open(&self, ts: UnixNanos) -> bool {
+        self.session_at(ts)
+            .is_some_and(|session| session.is_open(ts))
+    }
+
+    /// Returns the next time after `ts` the market opens (at a session open or the end of a
+    /// break), or `None` if there is no open within a year or the coverage of the calendar.
+    #[must_use]
+    pub fn next_open(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.next_transition(ts, MarketStatusAction::Trading)
+    }
+
+    /// Returns the next time after `ts` the market closes (at a session close or the start
+    /// of a break), or `None` if there is no close within a year or the coverage of the calendar.
+    #[must_use]
+    pub fn next_close(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.next_transition(ts, MarketStatusAction::Close)
+            .into_iter()
+            .chain(self.next_transition(ts, MarketStatusAction::Pause))
+            .min()
+    }
+
+    /// Returns the market status transitions after `start` up to and including `end`,
+    /// in time order.
+    #[must_use]
+    pub fn transitions(
+        &self,
+        start: UnixNanos,
+        end: UnixNanos,
+    ) -> Vec<(UnixNanos, MarketStatusAction)> {
+        self.sessions(start, end)
+            .iter()
+            .flat_map(TradingSession::transitions)
+            .filter(|(ts, _)| start < *ts && *ts <= end)
+            .collect()
+    }
+
+    /// Returns the [`InstrumentStatus`] updates for `instrument_id` of the market status
+    /// transitions after `start` up to and including `end`, in time order.
+    #[must_use]
+    pub fn instrument_statuses(
+        &self,
+        instrument_id: InstrumentId,
+        start: UnixNanos,
+        end: UnixNanos,
+    ) -> Vec<InstrumentStatus> {
+        self.transitions(start, end)
+            .into_iter()
+            .map(|(ts, action)| self.instrument_status(instrument_id, action, ts))
+            .collect()
+    }
+
+    /// Returns the [`InstrumentStatus`] of `instrument_id` at `ts`, which is `Trading`
+    /// while the market is open, `Pause` during a break, and otherwise `Close`.
+    #[must_use]
+    pub fn instrument_status_at(
+        &self,
+        instrument_id: InstrumentId,
+        ts: UnixNanos,
+    ) -> InstrumentStatus {
+        let action = match self.session_at(ts) {
+            Some(session) if session.is_open(ts) => MarketStatusAction::Trading,
+            Some(_) => MarketStatusAction::Pause,
+            None => MarketStatusAction::Close,
+        };
+        self.instrument_status(instrument_id, action, ts)
+    }
+
+    /// Returns the [`InstrumentStatus`] of `instrument_id` for the market status `action`
+    /// at `ts`, e.g. for the [`Self::transitions`] of the calendar.
+    #[must_use]
+    pub fn instrument_status(
+        &self,
+        instrument_id: InstrumentId,
+        action: MarketStatusAction,
+        ts: UnixNanos,
+    ) -> InstrumentStatus {
+        InstrumentStatus::new(
+            instrument_id,
+            action,
+            ts,
+            ts,
+            None,
+            Some(self.name),
+            Some(action == MarketStatusAction::Trading),
+            None,
+            None,
+        )
+    }
+
+    fn check_covers(&self, date: NaiveDate) -> anyhow::Result<()> {
+        if !self.covers(date) {
+            anyhow::bail!(
+                "Date {date} is outside the coverage of calendar {} ({} to {})",
+                self.name,
+                self.valid_from
+                    .map_or_else(|| "unbounded".to_string(), |d| d.to_string()),
+                self.valid_until
+                    .map_or_else(|| "unbounded".to_string(), |d| d.to_string()),
+            );
+        }
+        Ok(())
+    }
+
+    fn is_trading_date(&self, date: NaiveDate) -> bool {
+        self.weekdays.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// Returns the days from `first` (or the start of the coverage if later) up to the end
+    /// of the coverage.
+    fn covered_days(&self, first: NaiveDate) -> impl Iterator<Item = NaiveDate> + '_ {
+        let first = self
+            .valid_from
+            .map_or(first, |valid_from| first.max(valid_from));
+        first.iter_days().take_while(|date| {
+            self.valid_until
+                .is_none_or(|valid_until| *date <= valid_until)
+        })
+    }
+
+    /// Returns the trading session of `date`, which must be within the coverage.
+    fn covered_session(&self, date: NaiveDate) -> Option<TradingSession> {
+        if !self.is_trading_date(date) {
+            return None;
+        }
+
+        let open_time = self.late_opens.get(&date).copied().unwrap_or(self.open);
+        let close_time = self.early_closes.get(&date).copied().unwrap_or(self.close);
+        let open = self.to_unix_nanos(date, open_time);
+        let close = self.to_unix_nanos(date, close_time);
+
+        let breaks = self
+            .breaks
+            .iter()
+            .map(|(start, end)| {
+                (
+                    self.to_unix_nanos(date, *start),
+                    self.to_unix_nanos(date, *end),
+                )
+            })
+            .filter(|(start, end)| open < *start && *end < close)
+            .collect();
+
+        Some(TradingSession {
+            date,
+            open,
+            close,
+            breaks,
+        })
+    }
+
+    fn next_transition(&self, ts: UnixNanos, action: MarketStatusAction) -> Option<UnixNanos> {
+        let first = self.local_date(ts) - Duration::days(1);
+
+        self.covered_days(first)
+            .take(MAX_SEARCH_DAYS)
+            .filter_map(|date| self.covered_session(date))
+            .flat_map(|session| session.transitions())
+            .find(|(transition_ts, transition_action)| {
+                *transition_ts > ts && *transition_action == action
+            })
+            .map(|(transition_ts, _)| transition_ts)
+    }
+
+    fn local_date(&self, ts: UnixNanos) -> NaiveDate {
+        ts.to_datetime_utc()
+            .with_timezone(&self.timezone)
+            .date_naive()
+    }
+
+    /// Converts the local `time` of the session of trading `date` to a UNIX timestamp.
+    ///
+    /// For sessions opening the day before their trading date, times at or after the
+    /// regular open fall on the previous calendar day. Local times skipped by a daylight
+    /// saving transition resolve to the first valid time after the gap.
+    fn to_unix_nanos(&self, date: NaiveDate, time: NaiveTime) -> UnixNanos {
+        let date = if self.open > self.close && time >= self.open {
+            date - Duration::days(1)
+        } else {
+            date
+        };
+
+        let mut local = NaiveDateTime::new(date, time);
+        loop {
+            if let Some(datetime) = self.timezone.from_local_datetime(&local).earliest() {
+                return UnixNanos::from(datetime.with_timezone(&Utc));
+            }
+            local += Duration::minutes(1);
+        }
+    }
+}
+
+/// Parses the trading calendars defined in a JSON array of [`CalendarDefinition`]s.
+///
+/// # Errors
+///
+/// Returns an error if the JSON is invalid or any definition is invalid.
+pub fn parse_calendars(json: &str) -> anyhow::Result<Vec<TradingCalendar>> {
+    let definitions: Vec<CalendarDefinition> = serde_json::from_str(json)?;
+    definitions
+        .into_iter()
+        .map(TradingCalendar::from_definition)
+        .collect()
+}
+
+/// Loads the trading calendars defined in the JSON file at `path`.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or its definitions are invalid.
+pub fn load_calendars<P: AsRef<Path>>(path: P) -> anyhow::Result<Vec<TradingCalendar>> {
+    let path = path.as_ref();
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read calendars from {}: {e}", path.display()))?;
+    parse_calendars(&json)
+}
+
+/// Returns the bundled trading calendar with the given `name` or alias (case-insensitive),
+/// such as `XNYS`, `XNAS`, `CMES`, `XLON` or `XEUR`.
+///
+/// The bundled calendars cover the holidays and early closes of 2024 to 2026, and are
+/// bounded to those years (see [`TradingCalendar::covers`]).
+#[must_use]
+pub fn builtin_calendar(name: &str) -> Option<TradingCalendar> {
+    BUILTIN_CALENDARS
+        .iter()
+        .find(|calendar| calendar.matches_name(name))
+        .cloned()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ts(rfc3339: &str) -> UnixNanos {
+        UnixNanos::from(rfc3339)
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    fn nyse() -> TradingCalendar {
+        builtin_calendar("XNYS").unwrap()
+    }
+
+    fn cme() -> TradingCalendar {
+        builtin_calendar("CMES").unwrap()
+    }
+
+    fn tokyo() -> TradingCalendar {
+        let json = r#"[{
+            "name": "XTKS",
+            "timezone": "Asia/Tokyo",
+            "open": "09:00",
+            "close": "15:30",
+            "breaks": [["11:30", "12:30"]],
+            "late_opens": {"2025-01-06": "10:00"}
+        }]"#;
+        parse_calendars(json).unwrap().remove(0)
+    }
+
+    #[rstest]
+    #[case("XNYS")]
+    #[case("nyse")]
+    #[case("XNAS")]
+    #[case("GLBX")]
+    #[case("XLON")]
+    #[case("EUREX")]
+    fn test_builtin_calendar(#[case] name: &str) {
+        assert!(builtin_calendar(name).is_some());
+    }
+
+    #[rstest]
+    fn test_builtin_calendar_unknown() {
+        assert!(builtin_calendar("XXXX").is_none());
+    }
+
+    #[rstest]
+    fn test_parse_calendars_invalid_timezone() {
+        let json =
+            r#"[{"name": "X", "timezone": "Mars/Olympus", "open": "09:00", "close": "16:00"}]"#;
+
+        assert!(parse_calendars(json).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_calendars_invalid_break() {
+        let json = r#"[{
+            "name": "X",
+            "timezone": "UTC",
+            "open": "09:00",
+            "close": "16:00",
+            "breaks": [["12:00", "11:00"]]
+        }]"#;
+
+        assert!(parse_calendars(json).is_err());
+    }
+
+    #[rstest]
+    #[case("2025-04-18", false)] // Good Friday
+    #[case("2025-04-19", false)] // Saturday
+    #[case("2025-04-21", true)]
+    fn test_is_trading_day(#[case] value: &str, #[case] expected: bool) {
+        assert_eq!(nyse().is_trading_day(date(value)).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_parse_calendars_invalid_coverage() {
+        let json = r#"[{
+            "name": "X",
+            "timezone": "UTC",
+            "open": "09:00",
+            "close": "16:00",
+            "valid_from": "2025-01-01",
+            "valid_until": "2024-12-31"
+        }]"#;
+
+        assert!(parse_calendars(json).is_err());
+    }
+
+    #[rstest]
+    #[case("2023-12-29", false)]
+    #[case("2024-01-02", true)]
+    #[case("2026-12-31", true)]
+    #[case("2027-01-04", false)]
+    fn test_covers(#[case] value: &str, #[case] expected: bool) {
+        let calendar = nyse();
+
+        assert_eq!(calendar.covers(date(value)), expected);
+        assert_eq!(calendar.is_trading_day(date(value)).is_ok(), expected);
+        assert_eq!(calendar.session(date(value)).is_ok(), expected);
+    }
+
+    #[rstest]
+    fn test_sessions_outside_coverage() {
+        let calendar = nyse();
+
+        let sessions = calendar.sessions(ts("2026-12-30T00:00:00Z"), ts("2027-01-06T00:00:00Z"));
+
+        assert_eq!(
+            sessions.iter().map(|s| s.date).collect::<Vec<_>>(),
+            vec![date("2026-12-30"), date("2026-12-31")]
+        );
+        assert!(!calendar.covers_ts(ts("2027-01-04T15:00:00Z")));
+        assert!(!calendar.is_open(ts("2027-01-04T15:00:00Z")));
+        assert_eq!(calendar.next_open(ts("2026-12-31T21:00:00Z")), None);
+    }
+
+    #[rstest]
+    fn test_session_regular_across_dst() {
+        let calendar = nyse();
+
+        let winter = calendar.session(date("2025-03-07")).unwrap().unwrap();
+        let summer = calendar.session(date("2025-03-10")).unwrap().unwrap();
+
+        assert_eq!(winter.open, ts("2025-03-07T14:30:00Z"));
+        assert_eq!(winter.close, ts("2025-03-07T21:00:00Z"));
+        assert_eq!(summer.open, ts("2025-03-10T13:30:00Z"));
+        assert_eq!(summer.close, ts("2025-03-10T20:00:00Z"));
+    }
+
+    #[rstest]
+    fn test_session_early_close() {
+        let session = nyse().session(date("2025-11-28")).unwrap().unwrap();
+
+        assert_eq!(session.close, ts("2025-11-28T18:00:00Z"));
+    }
+
+    #[rstest]
+    fn test_session_overnight_opens_previous_day() {
+        let session = cme().session(date("2025-03-03")).unwrap().unwrap(); // Monday
+
+        assert_eq!(session.open, ts("2025-03-02T23:00:00Z")); // Sunday 17:00 CT
+        assert_eq!(session.close, ts("2025-03-03T22:00:00Z")); // Monday 16:00 CT
+    }
+
+    #[rstest]
+    #[case("2025-03-03T22:30:00Z", false)] // Maintenance break
+    #[case("2025-03-03T23:30:00Z", true)]
+    #[case("2025-04-18T15:00:00Z", false)] // Good Friday
+    #[case("2025-03-08T12:00:00Z", false)] // Saturday
+    fn test_is_open_overnight(#[case] value: &str, #[case] expected: bool) {
+        assert_eq!(cme().is_open(ts(value)), expected);
+    }
+
+    #[rstest]
+    fn test_next_open_and_close_over_holiday_weekend() {
+        let calendar = nyse();
+        let thursday_close = ts("2025-04-17T20:00:00Z");
+
+        assert_eq!(
+            calendar.next_open(thursday_close),
+            Some(ts("2025-04-21T13:30:00Z"))
+        );
+        assert_eq!(
+            calendar.next_close(thursday_close),
+            Some(ts("2025-04-21T20:00:00Z"))
+        );
+    }
+
+    #[rstest]
+    fn test_next_open_without_trading_days() {
+        let json = r#"[{
+            "name": "X",
+            "timezone": "UTC",
+            "open": "09:00",
+            "close": "16:00",
+            "weekdays": ["Sat"]
+        }]"#;
+        let calendar = parse_calendars(json).unwrap().remove(0);
+
+        assert_eq!(
+            calendar.next_open(ts("2025-01-06T00:00:00Z")),
+            Some(ts("2025-01-11T09:00:00Z"))
+        );
+    }
+
+    #[rstest]
+    #[case("2025-04-17T15:00:00Z", "2025-04-17")]
+    #[case("2025-04-17T20:00:00Z", "2025-04-17")] // At the close
+    #[case("2025-04-17T20:00:01Z", "2025-04-21")] // Over Good Friday and the weekend
+    fn test_next_session(#[case] value: &str, #[case] expected: &str) {
+        let session = nyse().next_session(ts(value)).unwrap();
+
+        assert_eq!(session.date, date(expected));
+    }
+
+    #[rstest]
+    fn test_from_session_template() {
+        let calendar = TradingCalendar::from_session_template(
+            "FX",
+            chrono_tz::America::New_York,
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            Vec::new(),
+        );
+        assert!(calendar.is_err());
+
+        let calendar = TradingCalendar::from_session_template(
+            "FX",
+            chrono_tz::America::New_York,
+            NaiveTime::from_hms_opt(17, 0, 1).unwrap(),
+            NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            Vec::new(),
+        )
+        .unwrap();
+        let session = calendar.session(date("2025-01-06")).unwrap().unwrap();
+
+        assert_eq!(calendar.name(), Ustr::from("FX"));
+        assert_eq!(session.open, ts("2025-01-05T22:00:01Z"));
+        assert_eq!(session.close, ts("2025-01-06T22:00:00Z"));
+    }
+
+    #[rstest]
+    fn test_breaks_and_late_open() {
+        let calendar = tokyo();
+
+        let session = calendar.session(date("2025-01-06")).unwrap().unwrap();
+        assert_eq!(session.open, ts("2025-01-06T01:00:00Z"));
+        assert_eq!(
+            session.breaks,
+            vec![(ts("2025-01-06T02:30:00Z"), ts("2025-01-06T03:30:00Z"))]
+        );
+        assert!(!calendar.is_open(ts("2025-01-06T03:00:00Z")));
+        assert_eq!(
+            calendar.next_close(ts("2025-01-06T01:00:00Z")),
+            Some(ts("2025-01-06T02:30:00Z"))
+        );
+        assert_eq!(
+            calendar.next_open(ts("2025-01-06T03:00:00Z")),
+            Some(ts("2025-01-06T03:30:00Z"))
+        );
+    }
+
+    #[rstest]
+    fn test_transitions() {
+        // The open at the start is excluded
+        let transitions =
+            tokyo().transitions(ts("2025-01-07T00:00:00Z"), ts("2025-01-07T23:00:00Z"));
+
+        assert_eq!(
+            transitions,
+            vec![
+                (ts("2025-01-07T02:30:00Z"), MarketStatusAction::Pause),
+                (ts("2025-01-07T03:30:00Z"), MarketStatusAction::Trading),
+                (ts("2025-01-07T06:30:00Z"), MarketStatusAction::Close),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_instrument_statuses() {
+        let instrument_id = InstrumentId::from("ESM5.GLBX");
+        let statuses = cme().instrument_statuses(
+            instrument_id,
+            ts("2025-04-17T00:00:00Z"),
+            ts("2025-04-21T00:00:00Z"),
+        );
+
+        // Thursday close, no Good Friday session, then the Sunday open of Monday's session
+        let actions: Vec<_> = statuses.iter().map(|s| (s.ts_event, s.action)).collect();
+        assert_eq!(
+            actions,
+            vec![
+                (ts("2025-04-17T21:00:00Z"), MarketStatusAction::Close),
+                (ts("2025-04-20T22:00:00Z"), MarketStatusAction::Trading),
+            ]
+        );
+        assert_eq!(statuses[0].instrument_id, instrument_id);
+        assert_eq!(statuses[1].is_trading, Some(true));
+    }
+
+    #[rstest]
+    #[case("2025-01-07T01:00:00Z", MarketStatusAction::Trading)]
+    #[case("2025-01-07T03:00:00Z", MarketStatusAction::Pause)]
+    #[case("2025-01-07T07:00:00Z", MarketStatusAction::Close)]
+    fn test_instrument_status_at(#[case] value: &str, #[case] expected: MarketStatusAction) {
+        let status = tokyo().instrument_status_at(InstrumentId::from("7203.XTKS"), ts(value));
+
+        assert_eq!(status.action, expected);
+    }
+}
@@ -34,8 +34,12 @@ use nautilus_core::{
 use tokio::sync::Mutex;
 use ustr::Ustr;
 
-use crate::timer::{
-    LiveTimer, TestTimer, TimeEvent, TimeEventCallback, TimeEventHandlerV2, create_valid_interval,
+use crate::{
+    cron::CronSchedule,
+    timer::{
+        LiveCronTimer, LiveTimer, TestCronTimer, TestTimer, TimeEvent, TimeEventCallback,
+        TimeEventHandlerV2, create_valid_interval,
+    },
 };
 
 /// Represents a type of clock.
@@ -107,6 +111,24 @@ pub trait Clock: Debug {
         allow_past: Option<bool>,
     ) -> anyhow::Result<()>;
 
+    /// Set a `Timer` to alert at each time matching the cron `schedule` after the current
+    /// time, until the optional stop time. Optional callback gets used to handle generated
+    /// events.
+    ///
+    /// Fire times are computed from the schedule as the timer fires, so are the same for
+    /// backtest and live clocks, including across daylight saving transitions.
+    /// # Errors
+    ///
+    /// Returns an error if `name` is invalid, no callback is available, or the schedule
+    /// has no fire time before the stop time.
+    fn set_cron_timer(
+        &mut self,
+        name: &str,
+        schedule: CronSchedule,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<TimeEventCallback>,
+    ) -> anyhow::Result<()>;
+
     /// Returns the time interval in which the timer `name` is triggered.
     ///
     /// If the timer doesn't exist `None` is returned.
@@ -130,6 +152,7 @@ pub struct TestClock {
     time: AtomicTime,
     // Use btree map to ensure stable ordering when scanning for timers in `advance_time`
     timers: BTreeMap<Ustr, TestTimer>,
+    cron_timers: BTreeMap<Ustr, TestCronTimer>,
     default_callback: Option<TimeEventCallback>,
     callbacks: HashMap<Ustr, TimeEventCallback>,
     heap: BinaryHeap<TimeEvent>,
@@ -142,6 +165,7 @@ impl TestClock {
         Self {
             time: AtomicTime::new(false, UnixNanos::default()),
             timers: BTreeMap::new(),
+            cron_timers: BTreeMap::new(),
             default_callback: None,
             callbacks: HashMap::new(),
             heap: BinaryHeap::new(),
@@ -154,6 +178,12 @@ impl TestClock {
         &self.timers
     }
 
+    /// Returns a reference to the internal cron timers for the clock.
+    #[must_use]
+    pub const fn get_cron_timers(&self) -> &BTreeMap<Ustr, TestCronTimer> {
+        &self.cron_timers
+    }
+
     /// Advances the internal clock to the specified `to_time_ns` and optionally sets the clock to that time.
     ///
     /// This function ensures that the clock behaves in a non-decreasing manner. If `set_time` is `true`,
@@ -187,6 +217,11 @@ impl TestClock {
 
             !timer.is_expired()
         });
+        self.cron_timers.retain(|_, timer| {
+            events.extend(timer.advance(to_time_ns));
+
+            !timer.is_expired()
+        });
 
         events.sort_by(|a, b| a.ts_event.cmp(&b.ts_event));
         events
@@ -217,6 +252,11 @@ impl TestClock {
                 self.heap.push(event);
             });
 
+            !timer.is_expired()
+        });
+        self.cron_timers.retain(|_, timer| {
+            self.heap.extend(timer.advance(to_time_ns));
+
             !timer.is_expired()
         });
     }
@@ -293,6 +333,12 @@ impl Clock for TestClock {
             .iter()
             .filter(|(_, timer)| !timer.is_expired())
             .map(|(k, _)| k.as_str())
+            .chain(
+                self.cron_timers
+                    .iter()
+                    .filter(|(_, timer)| !timer.is_expired())
+                    .map(|(k, _)| k.as_str()),
+            )
             .collect()
     }
 
@@ -301,6 +347,11 @@ impl Clock for TestClock {
             .iter()
             .filter(|(_, timer)| !timer.is_expired())
             .count()
+            + self
+                .cron_timers
+                .iter()
+                .filter(|(_, timer)| !timer.is_expired())
+                .count()
     }
 
     fn register_default_handler(&mut self, callback: TimeEventCallback) {
@@ -434,15 +485,57 @@ impl Clock for TestClock {
         Ok(())
     }
 
+    fn set_cron_timer(
+        &mut self,
+        name: &str,
+        schedule: CronSchedule,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<TimeEventCallback>,
+    ) -> anyhow::Result<()> {
+        check_valid_string(name, stringify!(name))?;
+        check_predicate_true(
+            callback.is_some() | self.default_callback.is_some(),
+            "No callbacks provided",
+        )?;
+
+        let name = Ustr::from(name);
+
+        match callback {
+            Some(callback) => self.callbacks.insert(name, callback),
+            None => None,
+        };
+
+        self.cancel_timer(name.as_str());
+
+        let timer = TestCronTimer::new(name, schedule, self.get_time_ns(), stop_time_ns);
+        if timer.is_expired() {
+            anyhow::bail!(
+                "Timer '{name}' schedule {} has no fire time before the stop time",
+                timer.schedule
+            );
+        }
+        self.cron_timers.insert(name, timer);
+
+        Ok(())
+    }
+
     fn next_time_ns(&self, name: &str) -> Option<UnixNanos> {
-        self.timers
-            .get(&Ustr::from(name))
-            .map(|timer| timer.next_time_ns())
+        let name = Ustr::from(name);
+        match self.timers.get(&name) {
+            Some(timer) => Some(timer.next_time_ns()),
+            None => self
+                .cron_timers
+                .get(&name)
+                .and_then(|timer| timer.next_time_ns()),
+        }
     }
 
     fn cancel_timer(&mut self, name: &str) {
-        let timer = self.timers.remove(&Ustr::from(name));
-        if let Some(mut timer) = timer {
+        let name = Ustr::from(name);
+        if let Some(mut timer) = self.timers.remove(&name) {
+            timer.cancel();
+        }
+        if let Some(mut timer) = self.cron_timers.remove(&name) {
             timer.cancel();
         }
     }
@@ -451,13 +544,18 @@ impl Clock for TestClock {
         for timer in &mut self.timers.values_mut() {
             timer.cancel();
         }
+        for timer in &mut self.cron_timers.values_mut() {
+            timer.cancel();
+        }
 
         self.timers.clear();
+        self.cron_timers.clear();
     }
 
     fn reset(&mut self) {
         self.time = AtomicTime::new(false, UnixNanos::default());
         self.timers = BTreeMap::new();
+        self.cron_timers = BTreeMap::new();
         self.heap = BinaryHeap::new();
         self.callbacks = HashMap::new();
     }
@@ -470,6 +568,7 @@ impl Clock for TestClock {
 pub struct LiveClock {
     time: &'static AtomicTime,
     timers: HashMap<Ustr, LiveTimer>,
+    cron_timers: HashMap<Ustr, LiveCronTimer>,
     default_callback: Option<TimeEventCallback>,
     pub heap: Arc<Mutex<BinaryHeap<TimeEvent>>>,
     #[allow(dead_code)]
@@ -483,6 +582,7 @@ impl LiveClock {
         Self {
             time: get_atomic_clock_realtime(),
             timers: HashMap::new(),
+            cron_timers: HashMap::new(),
             default_callback: None,
             heap: Arc::new(Mutex::new(BinaryHeap::new())),
             callbacks: HashMap::new(),
@@ -499,9 +599,15 @@ impl LiveClock {
         &self.timers
     }
 
+    #[must_use]
+    pub const fn get_cron_timers(&self) -> &HashMap<Ustr, LiveCronTimer> {
+        &self.cron_timers
+    }
+
     // Clean up expired timers. Retain only live ones
     fn clear_expired_timers(&mut self) {
         self.timers.retain(|_, timer| !timer.is_expired());
+        self.cron_timers.retain(|_, timer| !timer.is_expired());
     }
 }
 
@@ -542,6 +648,12 @@ impl Clock for LiveClock {
             .iter()
             .filter(|(_, timer)| !timer.is_expired())
             .map(|(k, _)| k.as_str())
+            .chain(
+                self.cron_timers
+                    .iter()
+                    .filter(|(_, timer)| !timer.is_expired())
+                    .map(|(k, _)| k.as_str()),
+            )
             .collect()
     }
 
@@ -550,6 +662,11 @@ impl Clock for LiveClock {
             .iter()
             .filter(|(_, timer)| !timer.is_expired())
             .count()
+            + self
+                .cron_timers
+                .iter()
+                .filter(|(_, timer)| !timer.is_expired())
+                .count()
     }
 
     fn register_default_handler(&mut self, handler: TimeEventCallback) {
@@ -739,15 +856,79 @@ impl Clock for LiveClock {
         Ok(())
     }
 
+    fn set_cron_timer(
+        &mut self,
+        name: &str,
+        schedule: CronSchedule,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<TimeEventCallback>,
+    ) -> anyhow::Result<()> {
+        check_valid_string(name, stringify!(name))?;
+        check_predicate_true(
+            callback.is_some() | self.default_callback.is_some(),
+            "No callbacks provided",
+        )?;
+
+        let name = Ustr::from(name);
+
+        let callback = match callback {
+            Some(callback) => callback,
+            None => self.default_callback.clone().unwrap(),
+        };
+
+        #[cfg(feature = "clock_v2")]
+        {
+            self.callbacks.insert(name, callback.clone());
+        }
+
+        self.cancel_timer(name.as_str());
+
+        let ts_now = self.get_time_ns();
+
+        #[cfg(not(feature = "clock_v2"))]
+        let mut timer = LiveCronTimer::new(name, schedule, ts_now, stop_time_ns, callback);
+
+        #[cfg(feature = "clock_v2")]
+        let mut timer = LiveCronTimer::new(
+            name,
+            schedule,
+            ts_now,
+            stop_time_ns,
+            callback,
+            self.heap.clone(),
+        );
+
+        if timer.next_time_ns().is_none() {
+            anyhow::bail!(
+                "Timer '{name}' schedule {} has no fire time before the stop time",
+                timer.schedule
+            );
+        }
+        timer.start();
+
+        self.clear_expired_timers();
+        self.cron_timers.insert(name, timer);
+
+        Ok(())
+    }
+
     fn next_time_ns(&self, name: &str) -> Option<UnixNanos> {
-        self.timers
-            .get(&Ustr::from(name))
-            .map(|timer| timer.next_time_ns())
+        let name = Ustr::from(name);
+        match self.timers.get(&name) {
+            Some(timer) => Some(timer.next_time_ns()),
+            None => self
+                .cron_timers
+                .get(&name)
+                .and_then(|timer| timer.next_time_ns()),
+        }
     }
 
     fn cancel_timer(&mut self, name: &str) {
-        let timer = self.timers.remove(&Ustr::from(name));
-        if let Some(mut timer) = timer {
+        let name = Ustr::from(name);
+        if let Some(mut timer) = self.timers.remove(&name) {
+            timer.cancel();
+        }
+        if let Some(mut timer) = self.cron_timers.remove(&name) {
             timer.cancel();
         }
     }
@@ -756,12 +937,17 @@ impl Clock for LiveClock {
         for timer in &mut self.timers.values_mut() {
             timer.cancel();
         }
+        for timer in &mut self.cron_timers.values_mut() {
+            timer.cancel();
+        }
 
         self.timers.clear();
+        self.cron_timers.clear();
     }
 
     fn reset(&mut self) {
         self.timers = HashMap::new();
+        self.cron_timers = HashMap::new();
         self.heap = Arc::new(Mutex::new(BinaryHeap::new()));
         self.callbacks = HashMap::new();
     }
@@ -1013,4 +1199,73 @@ mod tests {
         // Verify no timer was created
         assert_eq!(test_clock.timer_count(), 0);
     }
+
+    #[rstest]
+    fn test_cron_timer_fires_on_advance(mut test_clock: TestClock) {
+        test_clock.set_time(UnixNanos::from("2025-01-06T00:00:00Z"));
+        let schedule = CronSchedule::new("30 14 * * MON-FRI", chrono_tz::UTC).unwrap();
+        test_clock
+            .set_cron_timer("cron_timer", schedule, None, None)
+            .unwrap();
+
+        assert_eq!(test_clock.timer_names(), vec!["cron_timer"]);
+        assert_eq!(
+            test_clock.next_time_ns("cron_timer"),
+            Some(UnixNanos::from("2025-01-06T14:30:00Z"))
+        );
+
+        let events = test_clock.advance_time(UnixNanos::from("2025-01-08T00:00:00Z"), true);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].name.as_str(), "cron_timer");
+        assert_eq!(events[0].ts_event, UnixNanos::from("2025-01-06T14:30:00Z"));
+        assert_eq!(events[1].ts_event, UnixNanos::from("2025-01-07T14:30:00Z"));
+        assert_eq!(test_clock.timer_count(), 1);
+    }
+
+    #[rstest]
+    fn test_cron_timer_expires_at_stop_time(mut test_clock: TestClock) {
+        test_clock.set_time(UnixNanos::from("2025-01-06T00:00:00Z"));
+        let schedule = CronSchedule::new("0 * * * *", chrono_tz::UTC).unwrap();
+        let stop_time = UnixNanos::from("2025-01-06T02:00:00Z");
+        test_clock
+            .set_cron_timer("cron_timer", schedule, Some(stop_time), None)
+            .unwrap();
+
+        let events = test_clock.advance_time(UnixNanos::from("2025-01-07T00:00:00Z"), true);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].ts_event, stop_time);
+        assert_eq!(test_clock.timer_count(), 0);
+    }
+
+    #[rstest]
+    fn test_cron_timer_cancellation(mut test_clock: TestClock) {
+        let schedule = CronSchedule::new("*/5 * * * *", chrono_tz::UTC).unwrap();
+        test_clock
+            .set_cron_timer("cron_timer", schedule, None, None)
+            .unwrap();
+        assert_eq!(test_clock.timer_count(), 1);
+
+        test_clock.cancel_timer("cron_timer");
+
+        assert_eq!(test_clock.timer_count(), 0);
+        assert_eq!(test_clock.next_time_ns("cron_timer"), None);
+    }
+
+    #[rstest]
+    fn test_cron_timer_without_fire_time_before_stop_time(mut test_clock: TestClock) {
+        test_clock.set_time(UnixNanos::from("2025-01-06T00:00:00Z"));
+        let schedule = CronSchedule::new("0 12 * * *", chrono_tz::UTC).unwrap();
+
+        let result = test_clock.set_cron_timer(
+            "cron_timer",
+            schedule,
+            Some(UnixNanos::from("2025-01-06T11:00:00Z")),
+            None,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(test_clock.timer_count(), 0);
+    }
 }
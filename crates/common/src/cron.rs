@@ -0,0 +1,481 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Cron schedules for timers firing at calendar times in a timezone.
+//!
+//! Expressions have five fields (`minute hour day-of-month month day-of-week`), or six with a
+//! leading seconds field. Each field accepts `*`, values, ranges (`1-5`), lists (`1,15`) and
+//! steps (`*/15`, `0-30/10`). Months and weekdays also accept names (`JAN`, `MON`), and
+//! Sunday is either `0` or `7`. When both the day-of-month and day-of-week are restricted, a
+//! day matching either fires, as with standard cron.
+//!
+//! Fire times are evaluated in the local time of the schedule timezone, so they follow
+//! daylight saving transitions: local times skipped by a transition fire at the first valid
+//! time after the gap, and repeated local times fire once.
+
+use std::fmt::Display;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+use nautilus_core::UnixNanos;
+
+use crate::calendar::TradingCalendar;
+
+/// The maximum number of days searched for the next fire time (covers leap days).
+const MAX_SEARCH_DAYS: usize = 8 * 366;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Represents a cron schedule evaluated in the local time of a timezone.
+///
+/// # Examples
+///
+/// Every weekday at 15:55 New York time:
+///
+/// ```
+/// use nautilus_common::cron::CronSchedule;
+///
+/// let schedule = CronSchedule::new("55 15 * * MON-FRI", chrono_tz::America::New_York).unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    timezone: Tz,
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    days_of_month_restricted: bool,
+    days_of_week_restricted: bool,
+    calendar: Option<TradingCalendar>,
+}
+
+impl CronSchedule {
+    /// Creates a new [`CronSchedule`] instance from the cron `expression` in `timezone`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression does not have five or six fields, or a field
+    /// is invalid or out of range.
+    pub fn new(expression: &str, timezone: Tz) -> anyhow::Result<Self> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let (seconds, fields) = match fields.len() {
+            5 => ("0", fields.as_slice()),
+            6 => (fields[0], &fields[1..]),
+            n => anyhow::bail!(
+                "Invalid cron expression '{expression}': expected 5 or 6 fields, was {n}"
+            ),
+        };
+
+        let parse = |field: &str, name: &str, min: u32, max: u32, names: &[&str]| {
+            parse_field(field, min, max, names).map_err(|e| {
+                anyhow::anyhow!(
+                    "Invalid {name} field '{field}' in cron expression '{expression}': {e}"
+                )
+            })
+        };
+
+        let mut days_of_week = parse(fields[4], "day-of-week", 0, 7, &WEEKDAY_NAMES)?;
+        if days_of_week & (1 << 7) != 0 {
+            // Sunday as 7
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            expression: expression.to_string(),
+            timezone,
+            seconds: parse(seconds, "seconds", 0, 59, &[])?,
+            minutes: parse(fields[0], "minute", 0, 59, &[])?,
+            hours: parse(fields[1], "hour", 0, 23, &[])?,
+            days_of_month: parse(fields[2], "day-of-month", 1, 31, &[])?,
+            months: parse(fields[3], "month", 1, 12, &MONTH_NAMES)?,
+            days_of_week,
+            days_of_month_restricted: !fields[2].starts_with('*'),
+            days_of_week_restricted: !fields[4].starts_with('*'),
+            calendar: None,
+        })
+    }
+
+    /// Restricts the schedule to fire only on the trading days of `calendar`, as determined
+    /// by the local date in the schedule timezone.
+    ///
+    /// The schedule does not fire on dates outside the coverage of `calendar`.
+    #[must_use]
+    pub fn with_calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// Returns the cron expression of the schedule.
+    #[must_use]
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Returns the timezone the schedule is evaluated in.
+    #[must_use]
+    pub const fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Returns the trading calendar the schedule is restricted to, if any.
+    #[must_use]
+    pub const fn calendar(&self) -> Option<&TradingCalendar> {
+        self.calendar.as_ref()
+    }
+
+    /// Returns the first fire time strictly after `ts`, or `None` if the schedule does not
+    /// fire within eight years (e.g. `0 0 30 2 *`) or the coverage of its calendar.
+    #[must_use]
+    pub fn next_after(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        let local = ts
+            .to_datetime_utc()
+            .with_timezone(&self.timezone)
+            .naive_local();
+        let first = local.date();
+        let first_time = self.first_time(local);
+
+        first
+            .iter_days()
+            .take(MAX_SEARCH_DAYS)
+            .filter(|date| self.matches_date(*date))
+            .find_map(|date| {
+                let from = if date == first {
+                    first_time
+                } else {
+                    NaiveTime::MIN
+                };
+                self.times_from(from)
+                    .map(|time| self.to_unix_nanos(date, time))
+                    .find(|fire_ts| *fire_ts > ts)
+            })
+    }
+
+    /// Returns whether the local date of `ts` is outside the coverage of the calendar the
+    /// schedule is restricted to (never if none is set).
+    #[must_use]
+    pub fn is_outside_calendar(&self, ts: UnixNanos) -> bool {
+        self.calendar.as_ref().is_some_and(|calendar| {
+            let date = ts
+                .to_datetime_utc()
+                .with_timezone(&self.timezone)
+                .date_naive();
+            !calendar.covers(date)
+        })
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !has_bit(self.months, date.month()) {
+            return false;
+        }
+
+        let day_of_month = has_bit(self.days_of_month, date.day());
+        let day_of_week = has_bit(self.days_of_week, date.weekday().num_days_from_sunday());
+        let matches_day = match (self.days_of_month_restricted, self.days_of_week_restricted) {
+            (true, true) => day_of_month || day_of_week,
+            (true, false) => day_of_month,
+            (false, true) => day_of_week,
+            (false, false) => true,
+        };
+
+        matches_day
+            && self
+                .calendar
+                .as_ref()
+                .is_none_or(|calendar| calendar.is_trading_day(date).unwrap_or(false))
+    }
+
+    /// Returns the time of day to scan the first date from for fire times after `local`.
+    fn first_time(&self, local: NaiveDateTime) -> NaiveTime {
+        // Times skipped by a daylight saving gap fire just after it, so if `local` is within
+        // a minute after a gap then earlier times of the date may still fire after it
+        let before = local - Duration::minutes(1);
+        let after_gap = self
+            .timezone
+            .from_local_datetime(&before)
+            .earliest()
+            .is_none();
+
+        if before.date() == local.date() && !after_gap {
+            local.time()
+        } else {
+            NaiveTime::MIN
+        }
+    }
+
+    /// Returns the fire times of day at or after `from`, in time order.
+    fn times_from(&self, from: NaiveTime) -> impl Iterator<Item = NaiveTime> + '_ {
+        bits(self.hours)
+            .filter(move |hour| *hour >= from.hour())
+            .flat_map(move |hour| {
+                bits(self.minutes)
+                    .filter(move |minute| hour > from.hour() || *minute >= from.minute())
+                    .flat_map(move |minute| {
+                        bits(self.seconds)
+                            .filter_map(move |second| NaiveTime::from_hms_opt(hour, minute, second))
+                    })
+            })
+            .filter(move |time| *time >= from)
+    }
+
+    fn to_unix_nanos(&self, date: NaiveDate, time: NaiveTime) -> UnixNanos {
+        let mut local = NaiveDateTime::new(date, time);
+        loop {
+            if let Some(datetime) = self.timezone.from_local_datetime(&local).earliest() {
+                return UnixNanos::from(datetime.with_timezone(&Utc));
+            }
+            local += Duration::minutes(1);
+        }
+    }
+}
+
+impl Display for CronSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.expression, self.timezone)?;
+        if let Some(calendar) = &self.calendar {
+            write!(f, " on {} trading days", calendar.name())?;
+        }
+        Ok(())
+    }
+}
+
+fn has_bit(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn bits(mask: u64) -> impl Iterator<Item = u32> {
+    (0..64).filter(move |bit| has_bit(mask, *bit))
+}
+
+/// Parses a cron field into a bit mask of the values in `min..=max`.
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> anyhow::Result<u64> {
+    let mut mask = 0;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>()?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            anyhow::bail!("step must be positive");
+        }
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(start, names, min)?,
+                parse_value(end, names, min)?,
+            )
+        } else {
+            let value = parse_value(range, names, min)?;
+            // A single value with a step runs to the maximum (e.g. `5/15`)
+            (value, if part.contains('/') { max } else { value })
+        };
+
+        if start < min || end > max || start > end {
+            anyhow::bail!("values must be within {min}-{max}");
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+
+    Ok(mask)
+}
+
+fn parse_value(value: &str, names: &[&str], offset: u32) -> anyhow::Result<u32> {
+    if let Some(index) = names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(value))
+    {
+        return Ok(index as u32 + offset);
+    }
+    Ok(value.parse()?)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono_tz::{America::New_York, UTC};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::calendar::builtin_calendar;
+
+    fn ts(rfc3339: &str) -> UnixNanos {
+        UnixNanos::from(rfc3339)
+    }
+
+    fn fire_times(schedule: &CronSchedule, start: &str, count: usize) -> Vec<UnixNanos> {
+        let mut times = Vec::new();
+        let mut current = ts(start);
+        for _ in 0..count {
+            current = schedule.next_after(current).unwrap();
+            times.push(current);
+        }
+        times
+    }
+
+    #[rstest]
+    #[case("* * * *")]
+    #[case("* * * * * * *")]
+    #[case("60 * * * *")]
+    #[case("* 24 * * *")]
+    #[case("* * 0 * *")]
+    #[case("* * * 13 *")]
+    #[case("* * * * 8")]
+    #[case("*/0 * * * *")]
+    #[case("5-1 * * * *")]
+    #[case("* * * * FOO")]
+    fn test_new_invalid(#[case] expression: &str) {
+        assert!(CronSchedule::new(expression, UTC).is_err());
+    }
+
+    #[rstest]
+    #[case("*/15 * * * *", "2025-01-01T00:07:00Z", "2025-01-01T00:15:00Z")]
+    #[case("5/20 * * * *", "2025-01-01T00:26:00Z", "2025-01-01T00:45:00Z")]
+    #[case("0 9 * * *", "2025-01-01T09:00:00Z", "2025-01-02T09:00:00Z")]
+    #[case("30 */6 * * *", "2025-01-01T06:30:00Z", "2025-01-01T12:30:00Z")]
+    #[case("0 0 1 JAN,jul *", "2025-01-01T00:00:00Z", "2025-07-01T00:00:00Z")]
+    #[case("0 0 * * 7", "2025-01-01T00:00:00Z", "2025-01-05T00:00:00Z")] // Sunday
+    #[case("0 0 29 2 *", "2025-01-01T00:00:00Z", "2028-02-29T00:00:00Z")]
+    #[case("*/10 * * * * *", "2025-01-01T00:00:05Z", "2025-01-01T00:00:10Z")]
+    #[case("15,45 9,13 * * *", "2025-01-01T13:20:00Z", "2025-01-01T13:45:00Z")]
+    #[case("15,45 9,13 * * *", "2025-01-01T13:45:00Z", "2025-01-02T09:15:00Z")]
+    fn test_next_after(#[case] expression: &str, #[case] after: &str, #[case] expected: &str) {
+        let schedule = CronSchedule::new(expression, UTC).unwrap();
+
+        assert_eq!(schedule.next_after(ts(after)), Some(ts(expected)));
+    }
+
+    #[rstest]
+    fn test_next_after_never_fires() {
+        let schedule = CronSchedule::new("0 0 30 2 *", UTC).unwrap();
+
+        assert_eq!(schedule.next_after(ts("2025-01-01T00:00:00Z")), None);
+    }
+
+    #[rstest]
+    fn test_day_of_month_or_day_of_week() {
+        // The 13th of the month or any Friday
+        let schedule = CronSchedule::new("0 0 13 * FRI", UTC).unwrap();
+
+        assert_eq!(
+            fire_times(&schedule, "2025-06-01T00:00:00Z", 3),
+            vec![
+                ts("2025-06-06T00:00:00Z"),
+                ts("2025-06-13T00:00:00Z"),
+                ts("2025-06-20T00:00:00Z"),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_weekdays_across_dst_change() {
+        let schedule = CronSchedule::new("55 15 * * MON-FRI", New_York).unwrap();
+
+        // Friday before and Monday after the March 9 2025 change to daylight saving time
+        assert_eq!(
+            fire_times(&schedule, "2025-03-07T00:00:00Z", 2),
+            vec![ts("2025-03-07T20:55:00Z"), ts("2025-03-10T19:55:00Z")]
+        );
+    }
+
+    #[rstest]
+    fn test_skipped_local_time_fires_after_gap() {
+        let schedule = CronSchedule::new("30 2 * * *", New_York).unwrap();
+
+        // 02:30 does not exist on 2025-03-09, so fires at 03:00 EDT
+        assert_eq!(
+            fire_times(&schedule, "2025-03-08T12:00:00Z", 2),
+            vec![ts("2025-03-09T07:00:00Z"), ts("2025-03-10T06:30:00Z")]
+        );
+    }
+
+    #[rstest]
+    fn test_skipped_local_time_fires_after_gap_from_within_first_minute() {
+        let schedule = CronSchedule::new("30 30 2 * * *", New_York).unwrap();
+
+        // 02:30:30 does not exist on 2025-03-09, so fires at 03:00:30 EDT
+        assert_eq!(
+            schedule.next_after(ts("2025-03-09T07:00:10Z")),
+            Some(ts("2025-03-09T07:00:30Z"))
+        );
+    }
+
+    #[rstest]
+    fn test_repeated_local_time_fires_once() {
+        let schedule = CronSchedule::new("30 1 * * *", New_York).unwrap();
+
+        // 01:30 occurs twice on 2025-11-02, fires at the first (EDT) occurrence only
+        assert_eq!(
+            fire_times(&schedule, "2025-11-01T12:00:00Z", 2),
+            vec![ts("2025-11-02T05:30:00Z"), ts("2025-11-03T06:30:00Z")]
+        );
+    }
+
+    #[rstest]
+    fn test_with_calendar_skips_holidays() {
+        let calendar = builtin_calendar("XNYS").unwrap();
+        let schedule = CronSchedule::new("55 15 * * *", New_York)
+            .unwrap()
+            .with_calendar(calendar);
+
+        // Thursday, then over Good Friday and the weekend to Monday
+        assert_eq!(
+            fire_times(&schedule, "2025-04-17T00:00:00Z", 2),
+            vec![ts("2025-04-17T19:55:00Z"), ts("2025-04-21T19:55:00Z")]
+        );
+        assert_eq!(
+            schedule.to_string(),
+            "55 15 * * * (America/New_York) on XNYS trading days"
+        );
+    }
+
+    #[rstest]
+    fn test_with_calendar_outside_coverage() {
+        let calendar = builtin_calendar("XNYS").unwrap();
+        let schedule = CronSchedule::new("55 15 * * *", New_York)
+            .unwrap()
+            .with_calendar(calendar);
+
+        // The bundled calendar covers up to 2026
+        assert_eq!(
+            schedule.next_after(ts("2026-12-31T00:00:00Z")),
+            Some(ts("2026-12-31T20:55:00Z"))
+        );
+        assert_eq!(schedule.next_after(ts("2026-12-31T20:55:00Z")), None);
+        assert_eq!(
+            schedule.next_after(ts("2023-12-29T00:00:00Z")),
+            Some(ts("2024-01-02T20:55:00Z"))
+        );
+        assert!(schedule.is_outside_calendar(ts("2023-12-29T00:00:00Z")));
+        assert!(!schedule.is_outside_calendar(ts("2026-12-31T00:00:00Z")));
+        assert!(
+            !CronSchedule::new("55 15 * * *", New_York)
+                .unwrap()
+                .is_outside_calendar(ts("2023-12-29T00:00:00Z"))
+        );
+    }
+}
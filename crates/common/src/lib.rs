@@ -39,8 +39,10 @@
 
 pub mod actor;
 pub mod cache;
+pub mod calendar;
 pub mod clock;
 pub mod component;
+pub mod cron;
 pub mod custom;
 pub mod enums;
 pub mod factories;
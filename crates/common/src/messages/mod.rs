@@ -13,7 +13,12 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_model::data::Data;
+use nautilus_model::{
+    data::Data,
+    events::{AccountState, OrderEventAny},
+    instruments::InstrumentAny,
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+};
 
 pub mod data;
 pub mod execution;
@@ -28,4 +33,17 @@ pub use data::{DataResponse, SubscribeCommand, UnsubscribeCommand};
 pub enum DataEvent {
     Response(DataResponse),
     Data(Data),
+    Instrument(InstrumentAny),
+}
+
+/// Represents an execution event sent from a live execution client to the runner.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum ExecutionEvent {
+    Account(AccountState),
+    Order(OrderEventAny),
+    OrderStatusReport(OrderStatusReport),
+    FillReport(FillReport),
+    PositionStatusReport(PositionStatusReport),
+    MassStatus(ExecutionMassStatus),
 }
@@ -38,7 +38,7 @@ pub struct DatabaseConfig {
     pub port: Option<u16>,
     /// The account username for the database connection.
     pub username: Option<String>,
-    /// The account password for the database connection.
+    /// The account password for the database connection, or a `secret:` reference to a named secret.
     pub password: Option<String>,
     /// If the database should use an SSL-enabled connection.
     pub ssl: bool,
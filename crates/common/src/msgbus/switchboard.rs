@@ -234,6 +234,21 @@ impl MessagingSwitchboard {
         "ExecEngine.process".into()
     }
 
+    #[must_use]
+    pub fn exec_engine_reconcile_report() -> MStr<Endpoint> {
+        "ExecEngine.reconcile_report".into()
+    }
+
+    #[must_use]
+    pub fn exec_engine_reconcile_mass_status() -> MStr<Endpoint> {
+        "ExecEngine.reconcile_mass_status".into()
+    }
+
+    #[must_use]
+    pub fn portfolio_update_account() -> MStr<Endpoint> {
+        "Portfolio.update_account".into()
+    }
+
     #[must_use]
     pub fn get_custom_topic(&mut self, data_type: &DataType) -> MStr<Topic> {
         *self
@@ -23,7 +23,7 @@ use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
     clock::Clock,
-    messages::{DataEvent, data::DataCommand},
+    messages::{DataEvent, ExecutionEvent, data::DataCommand},
     timer::TimeEvent,
 };
 
@@ -155,12 +155,47 @@ pub fn get_data_event_sender() -> UnboundedSender<DataEvent> {
         .expect("Should be able to access thread local storage")
 }
 
+/// Sets the global execution event sender.
+///
+/// This should be called by the AsyncRunner when it creates the channel.
+///
+/// # Panics
+///
+/// Panics if thread-local storage cannot be accessed or a sender is already set.
+pub fn set_exec_event_sender(sender: UnboundedSender<ExecutionEvent>) {
+    EXEC_EVT_SENDER
+        .try_with(|s| {
+            assert!(s.set(sender).is_ok(), "Execution event sender already set");
+        })
+        .expect("Should be able to access thread local storage");
+}
+
+/// Gets a cloned execution event sender.
+///
+/// This allows execution clients to send order events and reports from async tasks
+/// back to the AsyncRunner for processing on the main thread.
+///
+/// # Panics
+///
+/// Panics if thread-local storage cannot be accessed or the sender is uninitialized.
+#[must_use]
+pub fn get_exec_event_sender() -> UnboundedSender<ExecutionEvent> {
+    EXEC_EVT_SENDER
+        .try_with(|s| {
+            s.get()
+                .expect("Execution event sender should be initialized by AsyncRunner")
+                .clone()
+        })
+        .expect("Should be able to access thread local storage")
+}
+
 thread_local! {
     static CLOCK: OnceCell<GlobalClock> = OnceCell::new();
     static DATA_EVT_QUEUE: OnceCell<GlobalDataQueue> = OnceCell::new();
     static DATA_CMD_QUEUE: DataCommandQueue = Rc::new(RefCell::new(VecDeque::new()));
     // TODO: Potentially redundant but added to simplify the abstraction layers for now
     static DATA_EVT_SENDER: OnceCell<UnboundedSender<DataEvent>> = const { OnceCell::new() };
+    static EXEC_EVT_SENDER: OnceCell<UnboundedSender<ExecutionEvent>> = const { OnceCell::new() };
 }
 
 // Represents different event types for the runner.
@@ -169,4 +204,5 @@ thread_local! {
 pub enum RunnerEvent {
     Time(TimeEvent),
     Data(DataEvent),
+    Exec(ExecutionEvent),
 }
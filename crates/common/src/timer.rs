@@ -48,7 +48,7 @@ use tokio::{
 };
 use ustr::Ustr;
 
-use crate::runtime::get_runtime;
+use crate::{cron::CronSchedule, runtime::get_runtime};
 
 /// Creates a valid nanoseconds interval that is guaranteed to be positive.
 ///
@@ -358,6 +358,125 @@ impl Iterator for TestTimer {
     }
 }
 
+/// A test timer firing on a cron schedule for use with a `TestClock`.
+///
+/// `TestCronTimer` generates an event at each fire time of its [`CronSchedule`] as time is
+/// advanced, expiring after its stop time or when the schedule has no further fire times.
+#[derive(Clone, Debug)]
+pub struct TestCronTimer {
+    /// The name of the timer.
+    pub name: Ustr,
+    /// The schedule of the timer events.
+    pub schedule: CronSchedule,
+    /// The optional stop time of the timer in UNIX nanoseconds.
+    pub stop_time_ns: Option<UnixNanos>,
+    next_time_ns: Option<UnixNanos>,
+    is_expired: bool,
+}
+
+impl TestCronTimer {
+    /// Creates a new [`TestCronTimer`] instance, firing at the schedule times after
+    /// `start_time_ns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid string.
+    #[must_use]
+    pub fn new(
+        name: Ustr,
+        schedule: CronSchedule,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    ) -> Self {
+        check_valid_string(name, stringify!(name)).expect(FAILED);
+
+        let next_time_ns = first_cron_time(name, &schedule, start_time_ns, stop_time_ns);
+        Self {
+            name,
+            schedule,
+            stop_time_ns,
+            next_time_ns,
+            is_expired: next_time_ns.is_none(),
+        }
+    }
+
+    /// Returns the next time in UNIX nanoseconds when the timer will fire, if any.
+    #[must_use]
+    pub const fn next_time_ns(&self) -> Option<UnixNanos> {
+        self.next_time_ns
+    }
+
+    /// Returns whether the timer is expired.
+    #[must_use]
+    pub const fn is_expired(&self) -> bool {
+        self.is_expired
+    }
+
+    /// Advance the test timer forward to the given time, returning a [`TimeEvent`] for
+    /// each fire time <= the given `to_time_ns`.
+    pub fn advance(&mut self, to_time_ns: UnixNanos) -> Vec<TimeEvent> {
+        let mut events = Vec::new();
+
+        while let Some(next_time_ns) = self.next_time_ns {
+            if self.is_expired || next_time_ns > to_time_ns {
+                break;
+            }
+
+            events.push(TimeEvent::new(
+                self.name,
+                UUID4::new(),
+                next_time_ns,
+                next_time_ns,
+            ));
+            self.next_time_ns = next_cron_time(&self.schedule, next_time_ns, self.stop_time_ns);
+            self.is_expired = self.next_time_ns.is_none();
+        }
+
+        events
+    }
+
+    /// Cancels the timer (the timer will not generate an event).
+    pub const fn cancel(&mut self) {
+        self.is_expired = true;
+    }
+}
+
+/// Returns the first fire time of `schedule` for the timer `name` after `start_time_ns`,
+/// unless after the optional stop time.
+///
+/// Warns once when the schedule does not fire because of the coverage of its calendar, as
+/// later fire times are then not searched for again.
+fn first_cron_time(
+    name: Ustr,
+    schedule: &CronSchedule,
+    start_time_ns: UnixNanos,
+    stop_time_ns: Option<UnixNanos>,
+) -> Option<UnixNanos> {
+    let next_time_ns = schedule.next_after(start_time_ns);
+
+    if let Some(calendar) = schedule.calendar() {
+        if next_time_ns.is_none() || schedule.is_outside_calendar(start_time_ns) {
+            log::warn!(
+                "Cron timer '{name}' for {schedule} does not fire on dates outside the coverage of calendar {}",
+                calendar.name()
+            );
+        }
+    }
+
+    next_time_ns.filter(|next| stop_time_ns.is_none_or(|stop_time_ns| *next <= stop_time_ns))
+}
+
+/// Returns the next fire time of `schedule` after `ts`, unless after the optional stop time.
+fn next_cron_time(
+    schedule: &CronSchedule,
+    ts: UnixNanos,
+    stop_time_ns: Option<UnixNanos>,
+) -> Option<UnixNanos> {
+    schedule
+        .next_after(ts)
+        .filter(|next| stop_time_ns.is_none_or(|stop_time_ns| *next <= stop_time_ns))
+}
+
 /// A live timer for use with a `LiveClock`.
 ///
 /// `LiveTimer` triggers events at specified intervals in a real-time environment,
@@ -554,6 +673,169 @@ impl LiveTimer {
     }
 }
 
+/// A live timer firing on a cron schedule for use with a `LiveClock`.
+///
+/// `LiveCronTimer` sleeps until each fire time of its [`CronSchedule`] on Tokio's async
+/// runtime, expiring after its stop time or when the schedule has no further fire times.
+#[derive(Debug)]
+pub struct LiveCronTimer {
+    /// The name of the timer.
+    pub name: Ustr,
+    /// The schedule of the timer events.
+    pub schedule: CronSchedule,
+    /// The optional stop time of the timer in UNIX nanoseconds.
+    pub stop_time_ns: Option<UnixNanos>,
+    next_time_ns: Arc<AtomicU64>,
+    callback: TimeEventCallback,
+    task_handle: Option<JoinHandle<()>>,
+    #[cfg(feature = "clock_v2")]
+    heap: Arc<Mutex<BinaryHeap<TimeEvent>>>,
+}
+
+impl LiveCronTimer {
+    /// Creates a new [`LiveCronTimer`] instance, firing at the schedule times after
+    /// `start_time_ns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid string.
+    #[must_use]
+    #[cfg(not(feature = "clock_v2"))]
+    pub fn new(
+        name: Ustr,
+        schedule: CronSchedule,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: TimeEventCallback,
+    ) -> Self {
+        check_valid_string(name, stringify!(name)).expect(FAILED);
+
+        log::debug!("Creating cron timer '{name}' for {schedule}");
+        let next_time_ns = first_cron_time(name, &schedule, start_time_ns, stop_time_ns);
+        Self {
+            name,
+            schedule,
+            stop_time_ns,
+            next_time_ns: Arc::new(AtomicU64::new(next_time_ns.map_or(0, |ts| ts.as_u64()))),
+            callback,
+            task_handle: None,
+        }
+    }
+
+    /// Creates a new [`LiveCronTimer`] instance, firing at the schedule times after
+    /// `start_time_ns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid string.
+    #[must_use]
+    #[cfg(feature = "clock_v2")]
+    pub fn new(
+        name: Ustr,
+        schedule: CronSchedule,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: TimeEventCallback,
+        heap: Arc<Mutex<BinaryHeap<TimeEvent>>>,
+    ) -> Self {
+        check_valid_string(name, stringify!(name)).expect(FAILED);
+
+        log::debug!("Creating cron timer '{name}' for {schedule}");
+        let next_time_ns = first_cron_time(name, &schedule, start_time_ns, stop_time_ns);
+        Self {
+            name,
+            schedule,
+            stop_time_ns,
+            next_time_ns: Arc::new(AtomicU64::new(next_time_ns.map_or(0, |ts| ts.as_u64()))),
+            callback,
+            task_handle: None,
+            heap,
+        }
+    }
+
+    /// Returns the next time in UNIX nanoseconds when the timer will fire, if any.
+    #[must_use]
+    pub fn next_time_ns(&self) -> Option<UnixNanos> {
+        match self.next_time_ns.load(atomic::Ordering::SeqCst) {
+            0 => None,
+            next_time_ns => Some(UnixNanos::from(next_time_ns)),
+        }
+    }
+
+    /// Returns whether the timer is expired.
+    ///
+    /// An expired timer will not trigger any further events.
+    /// A timer that has not been started is not expired.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.task_handle
+            .as_ref()
+            .is_some_and(tokio::task::JoinHandle::is_finished)
+    }
+
+    /// Starts the timer.
+    ///
+    /// Time events will begin triggering at the schedule fire times.
+    /// The generated events are handled by the provided callback function.
+    #[allow(unused_variables)] // callback is used
+    pub fn start(&mut self) {
+        let event_name = self.name;
+        let schedule = self.schedule.clone();
+        let stop_time_ns = self.stop_time_ns;
+        let callback = self.callback.clone();
+        let next_time_atomic = self.next_time_ns.clone();
+
+        #[cfg(feature = "clock_v2")]
+        let heap = self.heap.clone();
+
+        let rt = get_runtime();
+        let handle = rt.spawn(async move {
+            let clock = get_atomic_clock_realtime();
+            let mut next_time_ns = UnixNanos::from(next_time_atomic.load(atomic::Ordering::SeqCst));
+
+            while next_time_ns != 0 {
+                let delay_ns = next_time_ns.saturating_sub(clock.get_time_ns().as_u64());
+                tokio::time::sleep(Duration::from_nanos(delay_ns)).await;
+                let now_ns = clock.get_time_ns();
+
+                #[cfg(feature = "python")]
+                {
+                    match callback {
+                        TimeEventCallback::Python(ref callback) => {
+                            call_python_with_time_event(event_name, next_time_ns, now_ns, callback);
+                        }
+                        // Note: Clock v1 style path should not be called with Rust callback
+                        TimeEventCallback::Rust(_) => {}
+                    }
+                }
+
+                #[cfg(feature = "clock_v2")]
+                {
+                    let event = TimeEvent::new(event_name, UUID4::new(), next_time_ns, now_ns);
+                    heap.lock().await.push(event);
+                }
+
+                // Prepare next fire time, zero once the timer expires
+                next_time_ns =
+                    next_cron_time(&schedule, next_time_ns, stop_time_ns).unwrap_or_default();
+                next_time_atomic.store(next_time_ns.as_u64(), atomic::Ordering::SeqCst);
+            }
+        });
+
+        self.task_handle = Some(handle);
+    }
+
+    /// Cancels the timer.
+    ///
+    /// The timer will not generate a final event.
+    pub fn cancel(&mut self) {
+        log::debug!("Cancel cron timer '{}'", self.name);
+        if let Some(ref handle) = self.task_handle {
+            handle.abort();
+        }
+    }
+}
+
 #[cfg(feature = "python")]
 fn call_python_with_time_event(
     name: Ustr,
@@ -589,7 +871,8 @@ mod tests {
     use rstest::*;
     use ustr::Ustr;
 
-    use super::{TestTimer, TimeEvent};
+    use super::{TestCronTimer, TestTimer, TimeEvent};
+    use crate::cron::CronSchedule;
 
     #[rstest]
     fn test_test_timer_pop_event() {
@@ -684,4 +967,68 @@ mod tests {
         let events: Vec<TimeEvent> = timer.advance(UnixNanos::from(10)).collect();
         assert_eq!(events.len(), 1, "Expected one event at the 10 ns boundary");
     }
+
+    #[rstest]
+    fn test_test_cron_timer_advance() {
+        let schedule = CronSchedule::new("0 * * * *", chrono_tz::UTC).unwrap();
+        let mut timer = TestCronTimer::new(
+            Ustr::from("TEST_TIMER"),
+            schedule,
+            UnixNanos::from("2025-01-01T00:00:00Z"),
+            None,
+        );
+
+        assert_eq!(
+            timer.next_time_ns(),
+            Some(UnixNanos::from("2025-01-01T01:00:00Z"))
+        );
+        assert!(
+            timer
+                .advance(UnixNanos::from("2025-01-01T00:59:59Z"))
+                .is_empty()
+        );
+
+        let events = timer.advance(UnixNanos::from("2025-01-01T03:00:00Z"));
+        let times: Vec<UnixNanos> = events.iter().map(|event| event.ts_event).collect();
+        assert_eq!(
+            times,
+            vec![
+                UnixNanos::from("2025-01-01T01:00:00Z"),
+                UnixNanos::from("2025-01-01T02:00:00Z"),
+                UnixNanos::from("2025-01-01T03:00:00Z"),
+            ]
+        );
+        assert!(!timer.is_expired());
+    }
+
+    #[rstest]
+    fn test_test_cron_timer_expires_at_stop_time() {
+        let schedule = CronSchedule::new("0 * * * *", chrono_tz::UTC).unwrap();
+        let mut timer = TestCronTimer::new(
+            Ustr::from("TEST_TIMER"),
+            schedule,
+            UnixNanos::from("2025-01-01T00:00:00Z"),
+            Some(UnixNanos::from("2025-01-01T02:00:00Z")),
+        );
+
+        assert_eq!(
+            timer.advance(UnixNanos::from("2025-01-02T00:00:00Z")).len(),
+            2
+        );
+        assert!(timer.is_expired());
+        assert_eq!(timer.next_time_ns(), None);
+    }
+
+    #[rstest]
+    fn test_test_cron_timer_without_fire_time_is_expired() {
+        let schedule = CronSchedule::new("0 0 30 2 *", chrono_tz::UTC).unwrap();
+        let timer = TestCronTimer::new(
+            Ustr::from("TEST_TIMER"),
+            schedule,
+            UnixNanos::default(),
+            None,
+        );
+
+        assert!(timer.is_expired());
+    }
 }
@@ -24,3 +24,15 @@ pub fn get_env_var(key: &str) -> anyhow::Result<String> {
         Err(_) => anyhow::bail!("environment variable '{key}' must be set"),
     }
 }
+
+/// Returns the provided `value` if some, otherwise the environment variable for the given `key`.
+///
+/// # Errors
+///
+/// Returns an error if `value` is `None` and the environment variable is not set.
+pub fn get_or_env_var(value: Option<String>, key: &str) -> anyhow::Result<String> {
+    match value {
+        Some(value) => Ok(value),
+        None => get_env_var(key),
+    }
+}
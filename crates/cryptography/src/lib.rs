@@ -40,6 +40,7 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 
 pub mod providers;
+pub mod secrets;
 pub mod signing;
 pub mod tls;
 
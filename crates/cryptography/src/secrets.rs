@@ -0,0 +1,692 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pluggable providers for credentials and other secrets.
+//!
+//! Configuration values can reference a secret by name using the `secret:` prefix
+//! (e.g. `secret:BINANCE_API_KEY`), which is resolved at runtime through the process-wide
+//! [`SecretResolver`] so that plaintext keys never need to be stored in config files.
+//!
+//! The following providers are available:
+//! - [`EnvSecretProvider`]: reads secrets from environment variables.
+//! - [`EncryptedFileSecretProvider`]: reads secrets from a file encrypted with AES-256-GCM,
+//!   keyed by a passphrase through PBKDF2-HMAC-SHA256.
+//! - [`CommandSecretProvider`]: reads secrets from the output of an external command
+//!   (e.g. `pass`, `op` or `vault`).
+//!
+//! A node installs its resolver at startup from a [`SecretsConfig`].
+
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    num::NonZeroU32,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::OnceLock,
+};
+
+use base64::prelude::*;
+use ring::{
+    aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey},
+    pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
+
+/// The prefix which marks a configuration value as a reference to a named secret.
+pub const SECRET_REF_PREFIX: &str = "secret:";
+
+/// The header line of an encrypted secrets file.
+const FILE_HEADER: &str = "NAUTILUS-SECRETS-V1";
+
+/// The default environment variable holding the passphrase for an encrypted secrets file.
+pub const DEFAULT_PASSPHRASE_ENV_VAR: &str = "NAUTILUS_SECRETS_PASSPHRASE";
+
+/// The default number of PBKDF2 iterations used when encrypting a secrets file.
+pub const DEFAULT_PBKDF2_ITERATIONS: u32 = 600_000;
+
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+static SECRET_RESOLVER: OnceLock<SecretResolver> = OnceLock::new();
+
+/// Provides secrets by name from a particular backend.
+pub trait SecretProvider: Debug + Send + Sync {
+    /// Returns the name of the provider (used for logging).
+    fn name(&self) -> &'static str;
+
+    /// Returns the secret for the given `key`, or `None` if this provider does not hold it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails while looking up the secret.
+    fn get_secret(&self, key: &str) -> anyhow::Result<Option<String>>;
+}
+
+/// Provides secrets from environment variables, with an optional variable name prefix.
+#[derive(Clone, Debug, Default)]
+pub struct EnvSecretProvider {
+    prefix: Option<String>,
+}
+
+impl EnvSecretProvider {
+    /// Creates a new [`EnvSecretProvider`] instance.
+    ///
+    /// If a `prefix` is given then a secret `key` is read from the `{prefix}{key}` variable.
+    #[must_use]
+    pub const fn new(prefix: Option<String>) -> Self {
+        Self { prefix }
+    }
+}
+
+impl SecretProvider for EnvSecretProvider {
+    fn name(&self) -> &'static str {
+        "env"
+    }
+
+    fn get_secret(&self, key: &str) -> anyhow::Result<Option<String>> {
+        let var = match &self.prefix {
+            Some(prefix) => format!("{prefix}{key}"),
+            None => key.to_string(),
+        };
+        Ok(std::env::var(var).ok())
+    }
+}
+
+/// Provides secrets from a file encrypted with AES-256-GCM, keyed by a passphrase.
+///
+/// The file is decrypted once on load and the secrets are then held in memory.
+pub struct EncryptedFileSecretProvider {
+    secrets: BTreeMap<String, String>,
+}
+
+impl Debug for EncryptedFileSecretProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the secret values
+        f.debug_struct(stringify!(EncryptedFileSecretProvider))
+            .field("keys", &self.secrets.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl EncryptedFileSecretProvider {
+    /// Loads and decrypts the secrets file at `path` with the given `passphrase`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or decrypted.
+    pub fn from_file<P: AsRef<Path>>(path: P, passphrase: &str) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read secrets file {}: {e}", path.display()))?;
+        Self::from_encrypted(&data, passphrase)
+    }
+
+    /// Decrypts the given encrypted secrets file contents with the given `passphrase`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contents are malformed or the passphrase is incorrect.
+    pub fn from_encrypted(data: &str, passphrase: &str) -> anyhow::Result<Self> {
+        let secrets = decrypt_secrets(data, passphrase)?;
+        Ok(Self { secrets })
+    }
+
+    /// Returns the names of the secrets held by this provider.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.secrets.keys().map(String::as_str)
+    }
+}
+
+impl SecretProvider for EncryptedFileSecretProvider {
+    fn name(&self) -> &'static str {
+        "encrypted-file"
+    }
+
+    fn get_secret(&self, key: &str) -> anyhow::Result<Option<String>> {
+        Ok(self.secrets.get(key).cloned())
+    }
+}
+
+/// Provides secrets from the standard output of an external command.
+///
+/// Any `{key}` placeholder in the arguments is replaced with the secret name, otherwise the
+/// secret name is appended as the final argument (e.g. `pass show trading/<key>`).
+/// A non-zero exit status is treated as the secret not being held by this provider.
+#[derive(Clone, Debug)]
+pub struct CommandSecretProvider {
+    program: String,
+    args: Vec<String>,
+}
+
+impl CommandSecretProvider {
+    /// Creates a new [`CommandSecretProvider`] instance.
+    #[must_use]
+    pub const fn new(program: String, args: Vec<String>) -> Self {
+        Self { program, args }
+    }
+
+    fn command_args(&self, key: &str) -> Vec<String> {
+        let mut has_placeholder = false;
+        let mut args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| {
+                if arg.contains("{key}") {
+                    has_placeholder = true;
+                    arg.replace("{key}", key)
+                } else {
+                    arg.clone()
+                }
+            })
+            .collect();
+
+        if !has_placeholder {
+            args.push(key.to_string());
+        }
+        args
+    }
+}
+
+impl SecretProvider for CommandSecretProvider {
+    fn name(&self) -> &'static str {
+        "command"
+    }
+
+    fn get_secret(&self, key: &str) -> anyhow::Result<Option<String>> {
+        let output = Command::new(&self.program)
+            .args(self.command_args(key))
+            .output()
+            .map_err(|e| anyhow::anyhow!("Failed to execute '{}': {e}", self.program))?;
+
+        if !output.status.success() {
+            tracing::debug!(
+                "Secret command '{}' exited with {} for '{key}'",
+                self.program,
+                output.status
+            );
+            return Ok(None);
+        }
+
+        let value = String::from_utf8(output.stdout)?;
+        let value = value.trim_end_matches(['\r', '\n']);
+        if value.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(value.to_string()))
+    }
+}
+
+/// Resolves secrets by querying a chain of providers in order.
+#[derive(Debug)]
+pub struct SecretResolver {
+    providers: Vec<Box<dyn SecretProvider>>,
+}
+
+impl Default for SecretResolver {
+    /// Creates a resolver which reads secrets from environment variables only.
+    fn default() -> Self {
+        Self::new(vec![Box::new(EnvSecretProvider::default())])
+    }
+}
+
+impl SecretResolver {
+    /// Creates a new [`SecretResolver`] instance which queries the given `providers` in order.
+    #[must_use]
+    pub fn new(providers: Vec<Box<dyn SecretProvider>>) -> Self {
+        Self { providers }
+    }
+
+    /// Appends a provider to the end of the chain.
+    #[must_use]
+    pub fn with_provider(mut self, provider: Box<dyn SecretProvider>) -> Self {
+        self.providers.push(provider);
+        self
+    }
+
+    /// Returns the secret for the given `key` from the first provider which holds it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a provider fails, or if no provider holds the secret.
+    pub fn resolve(&self, key: &str) -> anyhow::Result<String> {
+        for provider in &self.providers {
+            if let Some(secret) = provider.get_secret(key)? {
+                tracing::debug!("Resolved secret '{key}' from {} provider", provider.name());
+                return Ok(secret);
+            }
+        }
+        anyhow::bail!("secret '{key}' not found in any secret provider")
+    }
+
+    /// Resolves the given configuration `value`.
+    ///
+    /// Values prefixed with `secret:` are resolved by name, all other values are returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` references a secret which cannot be resolved.
+    pub fn resolve_value(&self, value: &str) -> anyhow::Result<String> {
+        match value.strip_prefix(SECRET_REF_PREFIX) {
+            Some(key) => self.resolve(key),
+            None => Ok(value.to_string()),
+        }
+    }
+}
+
+/// Configuration for the process-wide [`SecretResolver`].
+///
+/// Providers are queried in the order: environment variables, encrypted file, then command.
+#[derive(Clone, Debug, Default)]
+pub struct SecretsConfig {
+    /// The prefix for environment variable names (e.g. `NT_` reads `NT_<key>`).
+    pub env_prefix: Option<String>,
+    /// The path to an encrypted secrets file.
+    pub encrypted_file: Option<PathBuf>,
+    /// The environment variable holding the passphrase for the encrypted secrets file
+    /// (defaults to `NAUTILUS_SECRETS_PASSPHRASE`).
+    pub passphrase_env_var: Option<String>,
+    /// The external program to read secrets from (e.g. `pass`).
+    pub command: Option<String>,
+    /// The arguments for the external program, see [`CommandSecretProvider`].
+    pub command_args: Vec<String>,
+}
+
+impl SecretsConfig {
+    /// Builds the [`SecretResolver`] for this configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encrypted secrets file passphrase is not set, or the file
+    /// cannot be read or decrypted.
+    pub fn build_resolver(&self) -> anyhow::Result<SecretResolver> {
+        let mut resolver = SecretResolver::new(vec![Box::new(EnvSecretProvider::new(
+            self.env_prefix.clone(),
+        ))]);
+
+        if let Some(path) = &self.encrypted_file {
+            let var = self
+                .passphrase_env_var
+                .as_deref()
+                .unwrap_or(DEFAULT_PASSPHRASE_ENV_VAR);
+            let passphrase = std::env::var(var).map_err(|_| {
+                anyhow::anyhow!("Secrets file passphrase environment variable '{var}' not set")
+            })?;
+            resolver = resolver.with_provider(Box::new(EncryptedFileSecretProvider::from_file(
+                path,
+                &passphrase,
+            )?));
+        }
+
+        if let Some(program) = &self.command {
+            resolver = resolver.with_provider(Box::new(CommandSecretProvider::new(
+                program.clone(),
+                self.command_args.clone(),
+            )));
+        }
+
+        Ok(resolver)
+    }
+}
+
+/// Sets the process-wide secret resolver.
+///
+/// This should be called once at startup, before any adapter configs are resolved.
+///
+/// # Errors
+///
+/// Returns an error if the resolver has already been set or used.
+pub fn set_secret_resolver(resolver: SecretResolver) -> anyhow::Result<()> {
+    SECRET_RESOLVER
+        .set(resolver)
+        .map_err(|_| anyhow::anyhow!("Secret resolver already initialized"))
+}
+
+/// Returns the process-wide secret resolver, defaulting to environment variables only.
+pub fn get_secret_resolver() -> &'static SecretResolver {
+    SECRET_RESOLVER.get_or_init(SecretResolver::default)
+}
+
+/// Resolves the given configuration `value` with the process-wide secret resolver.
+///
+/// # Errors
+///
+/// Returns an error if `value` references a secret which cannot be resolved.
+pub fn resolve_secret(value: &str) -> anyhow::Result<String> {
+    get_secret_resolver().resolve_value(value)
+}
+
+/// Returns the provided `value` if some, otherwise the secret named `key`, resolving any
+/// secret reference with the process-wide secret resolver.
+///
+/// This is a drop-in replacement for `get_or_env_var` for credentials, as the default
+/// resolver reads secrets from environment variables.
+///
+/// # Errors
+///
+/// Returns an error if the secret cannot be resolved.
+pub fn get_or_resolve_secret(value: Option<String>, key: &str) -> anyhow::Result<String> {
+    let resolver = get_secret_resolver();
+    match value {
+        Some(value) => resolver.resolve_value(&value),
+        None => {
+            let value = resolver.resolve(key)?;
+            resolver.resolve_value(&value)
+        }
+    }
+}
+
+/// Encrypts the given secrets with the `passphrase`, returning the secrets file contents.
+///
+/// # Errors
+///
+/// Returns an error if a secret name is invalid or encryption fails.
+pub fn encrypt_secrets(
+    secrets: &BTreeMap<String, String>,
+    passphrase: &str,
+) -> anyhow::Result<String> {
+    encrypt_secrets_with_iterations(secrets, passphrase, DEFAULT_PBKDF2_ITERATIONS)
+}
+
+/// Encrypts the given secrets with the `passphrase` using the given number of PBKDF2
+/// `iterations`, returning the secrets file contents.
+///
+/// # Errors
+///
+/// Returns an error if a secret name is invalid, `iterations` is zero or encryption fails.
+pub fn encrypt_secrets_with_iterations(
+    secrets: &BTreeMap<String, String>,
+    passphrase: &str,
+    iterations: u32,
+) -> anyhow::Result<String> {
+    let mut plaintext = String::new();
+    for (key, value) in secrets {
+        if key.is_empty() || key.contains(['=', '\n']) {
+            anyhow::bail!("Invalid secret name '{key}'");
+        }
+        if value.contains('\n') {
+            anyhow::bail!("Secret '{key}' must not contain newlines");
+        }
+        plaintext.push_str(key);
+        plaintext.push('=');
+        plaintext.push_str(value);
+        plaintext.push('\n');
+    }
+
+    let rng = SystemRandom::new();
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut salt)
+        .map_err(|_| anyhow::anyhow!("Failed to generate salt"))?;
+    rng.fill(&mut nonce)
+        .map_err(|_| anyhow::anyhow!("Failed to generate nonce"))?;
+
+    let key = derive_key(passphrase, &salt, iterations)?;
+    let mut in_out = plaintext.into_bytes();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(FILE_HEADER.as_bytes()),
+        &mut in_out,
+    )
+    .map_err(|_| anyhow::anyhow!("Failed to encrypt secrets"))?;
+
+    let mut payload = Vec::with_capacity(SALT_LEN + NONCE_LEN + in_out.len());
+    payload.extend_from_slice(&salt);
+    payload.extend_from_slice(&nonce);
+    payload.extend_from_slice(&in_out);
+
+    Ok(format!(
+        "{FILE_HEADER}:{iterations}\n{}\n",
+        BASE64_STANDARD.encode(payload)
+    ))
+}
+
+/// Decrypts the given secrets file contents with the `passphrase`.
+///
+/// # Errors
+///
+/// Returns an error if the contents are malformed or the passphrase is incorrect.
+pub fn decrypt_secrets(data: &str, passphrase: &str) -> anyhow::Result<BTreeMap<String, String>> {
+    let mut lines = data.lines();
+    let header = lines.next().unwrap_or_default();
+    let iterations = match header.split_once(':') {
+        Some((FILE_HEADER, iterations)) => iterations.parse::<u32>()?,
+        _ => anyhow::bail!("Invalid secrets file header"),
+    };
+    let payload = BASE64_STANDARD.decode(lines.next().unwrap_or_default().trim())?;
+    if payload.len() < SALT_LEN + NONCE_LEN + AES_256_GCM.tag_len() {
+        anyhow::bail!("Secrets file payload too short");
+    }
+
+    let (salt, rest) = payload.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let key = derive_key(passphrase, salt, iterations)?;
+    let nonce = Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| anyhow::anyhow!("Invalid secrets file nonce"))?;
+
+    let mut in_out = ciphertext.to_vec();
+    let plaintext = key
+        .open_in_place(nonce, Aad::from(FILE_HEADER.as_bytes()), &mut in_out)
+        .map_err(|_| anyhow::anyhow!("Failed to decrypt secrets file (incorrect passphrase?)"))?;
+    let plaintext = std::str::from_utf8(plaintext)?;
+
+    let mut secrets = BTreeMap::new();
+    for line in plaintext.lines().filter(|line| !line.is_empty()) {
+        let Some((key, value)) = line.split_once('=') else {
+            anyhow::bail!("Invalid secrets file entry");
+        };
+        secrets.insert(key.to_string(), value.to_string());
+    }
+    Ok(secrets)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], iterations: u32) -> anyhow::Result<LessSafeKey> {
+    let iterations = NonZeroU32::new(iterations)
+        .ok_or_else(|| anyhow::anyhow!("Iterations must be positive"))?;
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    let key = UnboundKey::new(&AES_256_GCM, &key)
+        .map_err(|_| anyhow::anyhow!("Failed to create encryption key"))?;
+    Ok(LessSafeKey::new(key))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    // Low iteration count to keep tests fast
+    const ITERATIONS: u32 = 1_000;
+
+    fn secrets() -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("API_KEY".to_string(), "my-api-key".to_string()),
+            ("API_SECRET".to_string(), "c2VjcmV0PT0=".to_string()),
+        ])
+    }
+
+    #[rstest]
+    fn test_encrypt_decrypt_round_trip() {
+        let data = encrypt_secrets_with_iterations(&secrets(), "passphrase", ITERATIONS).unwrap();
+
+        assert!(data.starts_with("NAUTILUS-SECRETS-V1:1000\n"));
+        assert!(!data.contains("my-api-key"));
+        assert_eq!(decrypt_secrets(&data, "passphrase").unwrap(), secrets());
+    }
+
+    #[rstest]
+    fn test_decrypt_with_wrong_passphrase() {
+        let data = encrypt_secrets_with_iterations(&secrets(), "passphrase", ITERATIONS).unwrap();
+
+        assert!(decrypt_secrets(&data, "wrong").is_err());
+    }
+
+    #[rstest]
+    fn test_decrypt_invalid_header() {
+        assert!(decrypt_secrets("NOT-SECRETS:1000\nAAAA\n", "passphrase").is_err());
+    }
+
+    #[rstest]
+    fn test_encrypt_invalid_secret_name() {
+        let secrets = BTreeMap::from([("A=B".to_string(), "value".to_string())]);
+
+        assert!(encrypt_secrets_with_iterations(&secrets, "passphrase", ITERATIONS).is_err());
+    }
+
+    #[rstest]
+    fn test_encrypted_file_provider() {
+        let data = encrypt_secrets_with_iterations(&secrets(), "passphrase", ITERATIONS).unwrap();
+        let provider = EncryptedFileSecretProvider::from_encrypted(&data, "passphrase").unwrap();
+
+        assert_eq!(
+            provider.get_secret("API_KEY").unwrap(),
+            Some("my-api-key".to_string())
+        );
+        assert_eq!(provider.get_secret("MISSING").unwrap(), None);
+        assert!(!format!("{provider:?}").contains("my-api-key"));
+    }
+
+    #[rstest]
+    fn test_env_provider() {
+        let provider = EnvSecretProvider::default();
+
+        assert!(provider.get_secret("PATH").unwrap().is_some());
+        assert_eq!(
+            provider
+                .get_secret("NAUTILUS_TEST_SECRET_DOES_NOT_EXIST")
+                .unwrap(),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_env_provider_with_prefix() {
+        let provider = EnvSecretProvider::new(Some("PA".to_string()));
+
+        assert_eq!(
+            provider.get_secret("TH").unwrap(),
+            std::env::var("PATH").ok()
+        );
+    }
+
+    #[rstest]
+    fn test_command_args_placeholder() {
+        let provider = CommandSecretProvider::new(
+            "vault".to_string(),
+            vec![
+                "kv".to_string(),
+                "get".to_string(),
+                "-field={key}".to_string(),
+                "trading".to_string(),
+            ],
+        );
+
+        assert_eq!(
+            provider.command_args("API_KEY"),
+            vec!["kv", "get", "-field=API_KEY", "trading"]
+        );
+    }
+
+    #[rstest]
+    fn test_command_args_appended() {
+        let provider = CommandSecretProvider::new("pass".to_string(), vec!["show".to_string()]);
+
+        assert_eq!(provider.command_args("API_KEY"), vec!["show", "API_KEY"]);
+    }
+
+    #[cfg(unix)]
+    #[rstest]
+    fn test_command_provider() {
+        let provider =
+            CommandSecretProvider::new("echo".to_string(), vec!["value-{key}".to_string()]);
+
+        assert_eq!(
+            provider.get_secret("API_KEY").unwrap(),
+            Some("value-API_KEY".to_string())
+        );
+    }
+
+    #[cfg(unix)]
+    #[rstest]
+    fn test_command_provider_failure_returns_none() {
+        let provider = CommandSecretProvider::new("false".to_string(), Vec::new());
+
+        assert_eq!(provider.get_secret("API_KEY").unwrap(), None);
+    }
+
+    #[rstest]
+    fn test_resolver_chain() {
+        let data = encrypt_secrets_with_iterations(&secrets(), "passphrase", ITERATIONS).unwrap();
+        let resolver = SecretResolver::default().with_provider(Box::new(
+            EncryptedFileSecretProvider::from_encrypted(&data, "passphrase").unwrap(),
+        ));
+
+        assert!(resolver.resolve("PATH").is_ok());
+        assert_eq!(resolver.resolve("API_KEY").unwrap(), "my-api-key");
+        assert!(resolver.resolve("MISSING").is_err());
+    }
+
+    #[rstest]
+    fn test_resolve_value() {
+        let data = encrypt_secrets_with_iterations(&secrets(), "passphrase", ITERATIONS).unwrap();
+        let resolver = SecretResolver::new(vec![Box::new(
+            EncryptedFileSecretProvider::from_encrypted(&data, "passphrase").unwrap(),
+        )]);
+
+        assert_eq!(
+            resolver.resolve_value("plain-value").unwrap(),
+            "plain-value"
+        );
+        assert_eq!(
+            resolver.resolve_value("secret:API_SECRET").unwrap(),
+            "c2VjcmV0PT0="
+        );
+        assert!(resolver.resolve_value("secret:MISSING").is_err());
+    }
+
+    #[cfg(unix)]
+    #[rstest]
+    fn test_secrets_config_build_resolver() {
+        let config = SecretsConfig {
+            command: Some("echo".to_string()),
+            command_args: vec!["value-{key}".to_string()],
+            ..Default::default()
+        };
+        let resolver = config.build_resolver().unwrap();
+
+        assert!(resolver.resolve("PATH").is_ok());
+        assert_eq!(resolver.resolve("API_KEY").unwrap(), "value-API_KEY");
+    }
+
+    #[rstest]
+    fn test_secrets_config_build_resolver_without_passphrase() {
+        let config = SecretsConfig {
+            encrypted_file: Some(PathBuf::from("secrets.enc")),
+            passphrase_env_var: Some("NAUTILUS_TEST_UNSET_PASSPHRASE".to_string()),
+            ..Default::default()
+        };
+
+        assert!(config.build_resolver().is_err());
+    }
+}
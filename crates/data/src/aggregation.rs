@@ -20,8 +20,9 @@
 
 use std::{any::Any, cell::RefCell, fmt::Debug, ops::Add, rc::Rc};
 
-use chrono::TimeDelta;
+use chrono::{Datelike, NaiveDate, TimeDelta, Weekday};
 use nautilus_common::{
+    calendar::TradingCalendar,
     clock::Clock,
     timer::{TimeEvent, TimeEventCallback},
 };
@@ -845,6 +846,9 @@ where
 /// Provides a means of building time bars aggregated from quote and trades.
 ///
 /// At each aggregation time interval, a bar is created and sent to the handler.
+///
+/// When a session calendar is set, `DAY` and `WEEK` bars are instead aligned to the trading
+/// sessions of the calendar, see [`TimeBarAggregator::set_session_calendar`].
 pub struct TimeBarAggregator<H>
 where
     H: FnMut(Bar),
@@ -865,6 +869,9 @@ where
     batch_next_close_ns: UnixNanos,
     time_bars_origin_offset: Option<TimeDelta>,
     skip_first_non_full_bar: bool,
+    session_calendar: Option<TradingCalendar>,
+    current_session: Option<(UnixNanos, UnixNanos, UnixNanos)>, // (from, open, close)
+    session_uncovered: bool,
 }
 
 impl<H: FnMut(Bar)> Debug for TimeBarAggregator<H> {
@@ -878,6 +885,10 @@ impl<H: FnMut(Bar)> Debug for TimeBarAggregator<H> {
             .field("interval_ns", &self.interval_ns)
             .field("bar_build_delay", &self.bar_build_delay)
             .field("skip_first_non_full_bar", &self.skip_first_non_full_bar)
+            .field(
+                "session_calendar",
+                &self.session_calendar.as_ref().map(TradingCalendar::name),
+            )
             .finish()
     }
 }
@@ -959,7 +970,42 @@ where
             batch_next_close_ns: UnixNanos::default(),
             time_bars_origin_offset,
             skip_first_non_full_bar,
+            session_calendar: None,
+            current_session: None,
+            session_uncovered: false,
+        }
+    }
+
+    /// Sets the trading `calendar` the bars are aligned to, which can be an exchange calendar
+    /// or a session template (see [`TradingCalendar::from_session_template`]).
+    ///
+    /// Session-aligned `DAY` bars open at the session open and close at the session close
+    /// (respecting holidays, early closes and late opens), and `WEEK` bars span the sessions
+    /// of each ISO week of trading dates. Updates outside the sessions are excluded, so bars
+    /// match the official daily OHLC of the venue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bar aggregation is not `DAY` or `WEEK` with a step of 1.
+    pub fn set_session_calendar(&mut self, calendar: TradingCalendar) -> anyhow::Result<()> {
+        let spec = self.bar_type().spec();
+        if !matches!(spec.aggregation, BarAggregation::Day | BarAggregation::Week)
+            || spec.step.get() != 1
+        {
+            anyhow::bail!(
+                "Session-aligned bars require a step 1 DAY or WEEK aggregation, was {spec}"
+            );
         }
+
+        self.session_calendar = Some(calendar);
+        self.current_session = None;
+        Ok(())
+    }
+
+    /// Returns the trading calendar the bars are aligned to, if any.
+    #[must_use]
+    pub const fn session_calendar(&self) -> Option<&TradingCalendar> {
+        self.session_calendar.as_ref()
     }
 
     /// Starts the time bar aggregator, scheduling periodic bar builds on the clock.
@@ -972,6 +1018,10 @@ where
     ///
     /// Panics if the underlying clock timer registration fails.
     pub fn start(&mut self, callback: NewBarCallback<H>) -> anyhow::Result<()> {
+        if self.session_calendar.is_some() {
+            return self.start_session_timer(callback);
+        }
+
         let now = self.clock.borrow().utc_now();
         let mut start_time =
             get_time_bar_start(now, &self.bar_type(), self.time_bars_origin_offset);
@@ -1011,6 +1061,76 @@ where
         Ok(())
     }
 
+    fn start_session_timer(&mut self, callback: NewBarCallback<H>) -> anyhow::Result<()> {
+        let now_ns = self.clock.borrow().timestamp_ns();
+        let Some((open_ns, close_ns)) = self.session_period(now_ns) else {
+            anyhow::bail!(
+                "No trading session found for {} after {now_ns}",
+                self.timer_name
+            );
+        };
+
+        if now_ns <= open_ns {
+            self.skip_first_non_full_bar = false;
+        }
+
+        self.stored_open_ns = open_ns;
+        self.next_close_ns = close_ns;
+
+        self.clock.borrow_mut().set_time_alert_ns(
+            &self.timer_name,
+            close_ns + self.bar_build_delay_ns(),
+            Some(callback.into()),
+            None,
+        )?;
+
+        log::debug!("Started session timer {}", self.timer_name);
+        Ok(())
+    }
+
+    const fn bar_build_delay_ns(&self) -> u64 {
+        self.bar_build_delay * 1_000
+    }
+
+    /// Returns the open and close of the session period closing at or after `ts`.
+    fn session_period(&self, ts: UnixNanos) -> Option<(UnixNanos, UnixNanos)> {
+        let calendar = self.session_calendar.as_ref()?;
+        get_session_period(calendar, self.bar_type().spec().aggregation, ts)
+    }
+
+    /// Returns whether `ts` is within a session of the calendar (always if none is set),
+    /// with the session open included if `include_open`.
+    ///
+    /// The session closing at or after `ts` is cached until `ts` passes its close.
+    fn is_in_session(&mut self, ts: UnixNanos, include_open: bool) -> bool {
+        let Some(calendar) = &self.session_calendar else {
+            return true;
+        };
+
+        let session = match self.current_session {
+            Some((from, open, close)) if from <= ts && ts <= close => Some((open, close)),
+            _ => {
+                let session = calendar
+                    .next_session(ts)
+                    .map(|session| (session.open, session.close));
+                self.current_session = session.map(|(open, close)| (ts, open, close));
+
+                let uncovered = session.is_none() && !calendar.covers_ts(ts);
+                if uncovered && !self.session_uncovered {
+                    log::warn!(
+                        "Time {ts} is outside the coverage of trading calendar {} for {}, updates will be excluded",
+                        calendar.name(),
+                        self.timer_name
+                    );
+                }
+                self.session_uncovered = uncovered;
+                session
+            }
+        };
+
+        session.is_some_and(|(open, _)| open < ts || (include_open && open == ts))
+    }
+
     /// Stops the time bar aggregator.
     pub fn stop(&mut self) {
         self.clock.borrow_mut().cancel_timer(&self.timer_name);
@@ -1025,6 +1145,13 @@ where
         let spec = self.bar_type().spec();
         self.core.batch_mode = true;
 
+        if self.session_calendar.is_some() {
+            let (open_ns, close_ns) = self.session_period(time_ns).unwrap_or_default();
+            self.batch_open_ns = open_ns;
+            self.batch_next_close_ns = close_ns;
+            return;
+        }
+
         let time = time_ns.to_datetime_utc();
         let start_time = get_time_bar_start(time, &self.bar_type(), self.time_bars_origin_offset);
         self.batch_open_ns = UnixNanos::from(start_time);
@@ -1090,7 +1217,12 @@ where
 
         if time_ns > self.batch_next_close_ns {
             // Ensure batch times are coherent with last builder update
-            if self.bar_type().spec().aggregation == BarAggregation::Month {
+            if self.session_calendar.is_some() {
+                if let Some((open_ns, close_ns)) = self.session_period(time_ns) {
+                    self.batch_open_ns = open_ns;
+                    self.batch_next_close_ns = close_ns;
+                }
+            } else if self.bar_type().spec().aggregation == BarAggregation::Month {
                 while self.batch_next_close_ns < time_ns {
                     self.batch_next_close_ns =
                         add_n_months_nanos(self.batch_next_close_ns, step).expect(FAILED);
@@ -1112,7 +1244,12 @@ where
             self.build_and_send(ts_event, time_ns);
             self.batch_open_ns = self.batch_next_close_ns;
 
-            if self.bar_type().spec().aggregation == BarAggregation::Month {
+            if self.session_calendar.is_some() {
+                if let Some((open_ns, close_ns)) = self.session_period(time_ns + 1) {
+                    self.batch_open_ns = open_ns;
+                    self.batch_next_close_ns = close_ns;
+                }
+            } else if self.bar_type().spec().aggregation == BarAggregation::Month {
                 self.batch_next_close_ns =
                     add_n_months_nanos(self.batch_next_close_ns, step).expect(FAILED);
             } else {
@@ -1127,6 +1264,11 @@ where
     }
 
     fn build_bar(&mut self, event: TimeEvent) {
+        if self.session_calendar.is_some() {
+            self.build_session_bar(event);
+            return;
+        }
+
         if !self.core.builder.initialized {
             self.build_on_next_tick = true;
             self.stored_close_ns = self.next_close_ns;
@@ -1161,6 +1303,42 @@ where
                 .unwrap_or_default();
         }
     }
+
+    fn build_session_bar(&mut self, event: TimeEvent) {
+        let open_ns = self.stored_open_ns;
+        let close_ns = self.next_close_ns;
+
+        if self.core.builder.initialized
+            && (self.build_with_no_updates || self.core.builder.count > 0)
+        {
+            let ts_event = self.bar_ts_event(open_ns, close_ns);
+            self.build_and_send(ts_event, event.ts_event);
+        }
+
+        // Only the bar of the session the aggregator started in can be partial
+        self.skip_first_non_full_bar = false;
+
+        let Some((open_ns, close_ns)) = self.session_period(close_ns + 1) else {
+            log::warn!(
+                "No trading session found for {} after {close_ns}, stopping",
+                self.timer_name
+            );
+            return;
+        };
+
+        self.stored_open_ns = open_ns;
+        self.next_close_ns = close_ns;
+
+        self.clock
+            .borrow_mut()
+            .set_time_alert_ns(
+                &self.timer_name,
+                close_ns + self.bar_build_delay_ns(),
+                None,
+                None,
+            )
+            .expect(FAILED);
+    }
 }
 
 impl<H: FnMut(Bar)> BarAggregator for TimeBarAggregator<H>
@@ -1196,7 +1374,9 @@ where
             self.batch_pre_update(ts_event);
         }
 
-        self.core.apply_update(price, size, ts_event);
+        if self.is_in_session(ts_event, true) {
+            self.core.apply_update(price, size, ts_event);
+        }
 
         if self.build_on_next_tick {
             if ts_event <= self.stored_close_ns {
@@ -1219,7 +1399,10 @@ where
             self.batch_pre_update(ts_init);
         }
 
-        self.core.builder.update_bar(bar, volume, ts_init);
+        // Bars closing at the session open cover the time before the session
+        if self.is_in_session(ts_init, false) {
+            self.core.builder.update_bar(bar, volume, ts_init);
+        }
 
         if self.build_on_next_tick {
             if ts_init <= self.stored_close_ns {
@@ -1251,6 +1434,31 @@ where
     }
 }
 
+/// Returns the open and close of the session period of `calendar` closing at or after `ts`,
+/// which is a single trading session for `DAY` bars, and the trading sessions of an ISO week
+/// of trading dates for `WEEK` bars.
+fn get_session_period(
+    calendar: &TradingCalendar,
+    aggregation: BarAggregation,
+    ts: UnixNanos,
+) -> Option<(UnixNanos, UnixNanos)> {
+    let session = calendar.next_session(ts)?;
+    if aggregation != BarAggregation::Week {
+        return Some((session.open, session.close));
+    }
+
+    let week = session.date.iso_week();
+    let monday = NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Mon)?;
+    // Dates of the week outside the coverage of the calendar have no sessions
+    let sessions: Vec<_> = monday
+        .iter_days()
+        .take(7)
+        .filter_map(|date| calendar.session(date).ok().flatten())
+        .collect();
+
+    Some((sessions.first()?.open, sessions.last()?.close))
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -1258,7 +1466,7 @@ where
 mod tests {
     use std::sync::{Arc, Mutex};
 
-    use nautilus_common::clock::TestClock;
+    use nautilus_common::{calendar::builtin_calendar, clock::TestClock};
     use nautilus_core::UUID4;
     use nautilus_model::{
         data::{BarSpecification, BarType},
@@ -2259,4 +2467,194 @@ mod tests {
         let handler_guard = handler.lock().unwrap();
         assert_eq!(handler_guard.len(), 0);
     }
+
+    fn session_bar_aggregator(
+        bar_type: BarType,
+        instrument: &InstrumentAny,
+        clock: Rc<RefCell<TestClock>>,
+        handler: Arc<Mutex<Vec<Bar>>>,
+    ) -> TimeBarAggregator<impl FnMut(Bar) + 'static> {
+        let mut aggregator = TimeBarAggregator::new(
+            bar_type,
+            instrument.price_precision(),
+            instrument.size_precision(),
+            clock,
+            move |bar: Bar| handler.lock().unwrap().push(bar),
+            false, // await_partial
+            false, // build_with_no_updates
+            true,  // timestamp_on_close
+            BarIntervalType::LeftOpen,
+            None, // time_bars_origin_offset
+            0,    // bar_build_delay
+            false,
+        );
+        aggregator
+            .set_session_calendar(builtin_calendar("XNAS").unwrap())
+            .unwrap();
+        aggregator
+    }
+
+    #[rstest]
+    #[case(BarSpecification::new(1, BarAggregation::Hour, PriceType::Last))]
+    #[case(BarSpecification::new(2, BarAggregation::Day, PriceType::Last))]
+    fn test_time_bar_aggregator_session_calendar_requires_day_or_week(
+        equity_aapl: Equity,
+        #[case] bar_spec: BarSpecification,
+    ) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_type = BarType::new(instrument.id(), bar_spec, AggregationSource::Internal);
+        let mut aggregator = TimeBarAggregator::new(
+            bar_type,
+            instrument.price_precision(),
+            instrument.size_precision(),
+            Rc::new(RefCell::new(TestClock::new())),
+            |_bar: Bar| {},
+            false,
+            true,
+            true,
+            BarIntervalType::LeftOpen,
+            None,
+            0,
+            false,
+        );
+
+        let result = aggregator.set_session_calendar(builtin_calendar("XNAS").unwrap());
+
+        assert!(result.is_err());
+        assert!(aggregator.session_calendar().is_none());
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_session_aligned_day_bars(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_spec = BarSpecification::new(1, BarAggregation::Day, PriceType::Last);
+        let bar_type = BarType::new(instrument.id(), bar_spec, AggregationSource::Internal);
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        clock
+            .borrow_mut()
+            .set_time(UnixNanos::from("2025-01-06T12:00:00Z"));
+        let handler = Arc::new(Mutex::new(Vec::new()));
+        let aggregator = Rc::new(RefCell::new(session_bar_aggregator(
+            bar_type,
+            &instrument,
+            clock.clone(),
+            handler.clone(),
+        )));
+
+        aggregator
+            .borrow_mut()
+            .start(NewBarCallback::new(aggregator.clone()))
+            .unwrap();
+        let session_close = UnixNanos::from("2025-01-06T21:00:00Z");
+        assert_eq!(
+            clock.borrow().next_time_ns(&bar_type.to_string()),
+            Some(session_close)
+        );
+
+        for (price, ts) in [
+            ("150.00", "2025-01-06T13:00:00Z"), // Pre-market
+            ("100.00", "2025-01-06T14:30:00Z"), // Session open
+            ("102.00", "2025-01-06T18:00:00Z"),
+            ("101.00", "2025-01-06T21:00:00Z"), // Session close
+            ("50.00", "2025-01-06T22:00:00Z"),  // After hours
+        ] {
+            aggregator.borrow_mut().update(
+                Price::from(price),
+                Quantity::from(1),
+                UnixNanos::from(ts),
+            );
+        }
+
+        let events = clock
+            .borrow_mut()
+            .advance_time(UnixNanos::from("2025-01-07T00:00:00Z"), true);
+        for event in events {
+            aggregator.borrow_mut().build_bar(event);
+        }
+
+        let handler_guard = handler.lock().unwrap();
+        assert_eq!(handler_guard.len(), 1);
+        let bar = handler_guard[0];
+        assert_eq!(bar.open, Price::from("100.00"));
+        assert_eq!(bar.high, Price::from("102.00"));
+        assert_eq!(bar.low, Price::from("100.00"));
+        assert_eq!(bar.close, Price::from("101.00"));
+        assert_eq!(bar.volume, Quantity::from(3));
+        assert_eq!(bar.ts_event, session_close);
+        assert_eq!(bar.ts_init, session_close);
+        assert_eq!(
+            clock.borrow().next_time_ns(&bar_type.to_string()),
+            Some(UnixNanos::from("2025-01-07T21:00:00Z"))
+        );
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_session_excludes_updates_outside_calendar_coverage(
+        equity_aapl: Equity,
+    ) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_spec = BarSpecification::new(1, BarAggregation::Day, PriceType::Last);
+        let bar_type = BarType::new(instrument.id(), bar_spec, AggregationSource::Internal);
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let handler = Arc::new(Mutex::new(Vec::new()));
+        let mut aggregator = session_bar_aggregator(bar_type, &instrument, clock, handler);
+
+        // The builtin calendar covers trading dates until the end of 2026
+        let ts = UnixNanos::from("2027-03-01T15:00:00Z");
+        aggregator.update(Price::from("100.00"), Quantity::from(1), ts);
+
+        assert!(aggregator.session_uncovered);
+        assert!(aggregator.current_session.is_none());
+        assert!(aggregator.core.builder.volume.is_zero());
+
+        let ts = UnixNanos::from("2026-03-02T15:00:00Z");
+        aggregator.update(Price::from("100.00"), Quantity::from(1), ts);
+
+        assert!(!aggregator.session_uncovered);
+        assert_eq!(
+            aggregator.current_session,
+            Some((
+                ts,
+                UnixNanos::from("2026-03-02T14:30:00Z"),
+                UnixNanos::from("2026-03-02T21:00:00Z"),
+            ))
+        );
+        assert_eq!(aggregator.core.builder.volume, Quantity::from(1));
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_session_aligned_week_bars_in_batch(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_spec = BarSpecification::new(1, BarAggregation::Week, PriceType::Last);
+        let bar_type = BarType::new(instrument.id(), bar_spec, AggregationSource::Internal);
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let handler = Arc::new(Mutex::new(Vec::new()));
+        let batch_handler = Arc::new(Mutex::new(Vec::new()));
+        let batch_handler_clone = Arc::clone(&batch_handler);
+        let mut aggregator = session_bar_aggregator(bar_type, &instrument, clock, handler.clone());
+
+        aggregator.start_batch_update(
+            Box::new(move |bar: Bar| batch_handler_clone.lock().unwrap().push(bar)),
+            UnixNanos::from("2025-04-14T00:00:00Z"),
+        );
+
+        // The week of Good Friday closes on Thursday
+        for (price, ts) in [
+            ("100.00", "2025-04-14T14:00:00Z"),
+            ("105.00", "2025-04-17T19:00:00Z"),
+            ("110.00", "2025-04-18T15:00:00Z"), // Holiday
+            ("107.00", "2025-04-21T14:00:00Z"),
+        ] {
+            aggregator.update(Price::from(price), Quantity::from(1), UnixNanos::from(ts));
+        }
+
+        let bars = batch_handler.lock().unwrap();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].open, Price::from("100.00"));
+        assert_eq!(bars[0].high, Price::from("105.00"));
+        assert_eq!(bars[0].close, Price::from("105.00"));
+        assert_eq!(bars[0].volume, Quantity::from(2));
+        assert_eq!(bars[0].ts_event, UnixNanos::from("2025-04-17T20:00:00Z"));
+        assert!(handler.lock().unwrap().is_empty());
+    }
 }
@@ -69,11 +69,12 @@ impl MessageHandler for BookUpdater {
     fn handle(&self, message: &dyn Any) {
         // TODO: Temporary handler implementation (this will be removed soon)
         if let Some(data) = message.downcast_ref::<Data>() {
-            if let Some(book) = self
-                .cache
-                .borrow_mut()
-                .order_book_mut(&data.instrument_id())
-            {
+            let Some(instrument_id) = data.instrument_id() else {
+                log::error!("Invalid data type for book update, was {data:?}");
+                return;
+            };
+
+            if let Some(book) = self.cache.borrow_mut().order_book_mut(&instrument_id) {
                 match data {
                     Data::Delta(delta) => book.apply_delta(delta),
                     Data::Deltas(deltas) => book.apply_deltas(deltas),
@@ -15,9 +15,10 @@
 
 use std::{collections::HashMap, time::Duration};
 
+use nautilus_common::calendar::TradingCalendar;
 use nautilus_model::{
     enums::{BarAggregation, BarIntervalType},
-    identifiers::ClientId,
+    identifiers::{ClientId, Venue},
 };
 
 /// Configuration for `DataEngine` instances.
@@ -36,6 +37,9 @@ pub struct DataEngineConfig {
     pub time_bars_interval_type: BarIntervalType,
     /// A dictionary mapping time bar aggregations to their origin time offsets.
     pub time_bars_origins: HashMap<BarAggregation, Duration>,
+    /// A dictionary mapping venues to the trading calendars (or session templates) which
+    /// the `DAY` and `WEEK` time bars of their instruments are aligned to.
+    pub time_bars_session_calendars: HashMap<Venue, TradingCalendar>,
     /// If data objects timestamp sequencing will be validated and handled.
     pub validate_data_sequence: bool,
     /// If order book deltas should be buffered until the `F_LAST` flag is set for a delta.
@@ -43,6 +47,12 @@ pub struct DataEngineConfig {
     /// The client IDs declared for external stream processing.
     /// The data engine will not attempt to send data commands to these client IDs.
     pub external_clients: Option<Vec<ClientId>>,
+    /// If data requested from clients to fill intervals missing from the registered
+    /// catalogs will be written to the first registered catalog.
+    pub write_requested_data_to_catalog: bool,
+    /// The duration after which a request served from the catalogs is answered with the
+    /// data received so far, if its backfill requests have not all been responded to.
+    pub catalog_request_timeout: Duration,
     /// If debug mode is active (will provide extra debug logging).
     pub debug: bool,
 }
@@ -56,9 +66,12 @@ impl DataEngineConfig {
         time_bars_interval_type: BarIntervalType,
         time_bars_skip_first_non_full_bar: bool,
         time_bars_origins: HashMap<BarAggregation, Duration>,
+        time_bars_session_calendars: HashMap<Venue, TradingCalendar>,
         validate_data_sequence: bool,
         buffer_deltas: bool,
         external_clients: Option<Vec<ClientId>>,
+        write_requested_data_to_catalog: bool,
+        catalog_request_timeout: Duration,
         debug: bool,
     ) -> Self {
         Self {
@@ -67,9 +80,12 @@ impl DataEngineConfig {
             time_bars_skip_first_non_full_bar,
             time_bars_interval_type,
             time_bars_origins,
+            time_bars_session_calendars,
             validate_data_sequence,
             buffer_deltas,
             external_clients,
+            write_requested_data_to_catalog,
+            catalog_request_timeout,
             debug,
         }
     }
@@ -84,9 +100,12 @@ impl Default for DataEngineConfig {
             validate_data_sequence: false,
             buffer_deltas: false,
             external_clients: None,
+            write_requested_data_to_catalog: false,
+            catalog_request_timeout: Duration::from_secs(60),
             debug: false,
             time_bars_skip_first_non_full_bar: false,
             time_bars_origins: HashMap::new(),
+            time_bars_session_calendars: HashMap::new(),
         }
     }
 }
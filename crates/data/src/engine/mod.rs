@@ -35,7 +35,7 @@ mod handlers;
 use std::{
     any::Any,
     cell::{Ref, RefCell},
-    collections::hash_map::Entry,
+    collections::{VecDeque, hash_map::Entry},
     fmt::Display,
     num::NonZeroUsize,
     rc::Rc,
@@ -51,15 +51,22 @@ use nautilus_common::{
     clock::Clock,
     logging::{RECV, RES},
     messages::data::{
-        DataCommand, DataResponse, RequestCommand, SubscribeBars, SubscribeBookDeltas,
-        SubscribeBookDepth10, SubscribeBookSnapshots, SubscribeCommand, UnsubscribeBars,
+        BarsResponse, DataCommand, DataResponse, QuotesResponse, RequestBars, RequestCommand,
+        RequestQuotes, RequestTrades, SubscribeBars, SubscribeBookDeltas, SubscribeBookDepth10,
+        SubscribeBookSnapshots, SubscribeCommand, TradesResponse, UnsubscribeBars,
         UnsubscribeBookDeltas, UnsubscribeBookDepth10, UnsubscribeBookSnapshots,
         UnsubscribeCommand,
     },
-    msgbus::{self, MStr, Topic, handler::ShareableMessageHandler, switchboard},
+    msgbus::{
+        self, MStr, Topic,
+        core::Endpoint,
+        handler::{ShareableMessageHandler, TypedMessageHandler},
+        switchboard::{self, MessagingSwitchboard},
+    },
     timer::TimeEventCallback,
 };
 use nautilus_core::{
+    UUID4, UnixNanos,
     correctness::{
         FAILED, check_key_in_map, check_key_not_in_map, check_predicate_false, check_predicate_true,
     },
@@ -67,8 +74,8 @@ use nautilus_core::{
 };
 use nautilus_model::{
     data::{
-        Bar, BarType, Data, DataType, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick,
-        TradeTick,
+        Bar, BarType, CustomDataAny, Data, DataType, GetTsInit, OrderBookDelta, OrderBookDeltas,
+        OrderBookDepth10, QuoteTick, TradeTick,
         close::InstrumentClose,
         prices::{IndexPriceUpdate, MarkPriceUpdate},
     },
@@ -77,7 +84,9 @@ use nautilus_model::{
     instruments::{Instrument, InstrumentAny, SyntheticInstrument},
     orderbook::OrderBook,
 };
-use nautilus_persistence::backend::catalog::ParquetDataCatalog;
+use nautilus_persistence::backend::catalog::{
+    CatalogPathPrefix, ParquetDataCatalog, merge_intervals, missing_intervals,
+};
 use ustr::Ustr;
 
 use crate::{
@@ -88,6 +97,25 @@ use crate::{
     client::DataClientAdapter,
 };
 
+/// A historical data request being served from the registered catalogs, awaiting
+/// backfill responses from a data client for the intervals the catalogs do not cover.
+#[derive(Debug)]
+struct CatalogRequest {
+    request: RequestCommand,
+    data: Vec<Data>,
+    client_id: Option<ClientId>,
+    pending: usize,
+}
+
+/// A backfill request sent to a data client on behalf of a [`CatalogRequest`].
+#[derive(Debug)]
+struct CatalogBackfill {
+    parent_id: UUID4,
+    catalog: Ustr,
+    start: u64,
+    end: u64,
+}
+
 /// Provides a high-performance `DataEngine` for all environments.
 #[derive(Debug)]
 pub struct DataEngine {
@@ -96,7 +124,10 @@ pub struct DataEngine {
     clients: IndexMap<ClientId, DataClientAdapter>,
     default_client: Option<DataClientAdapter>,
     external_clients: AHashSet<ClientId>,
-    catalogs: AHashMap<Ustr, ParquetDataCatalog>,
+    catalogs: IndexMap<Ustr, ParquetDataCatalog>,
+    catalog_requests: AHashMap<UUID4, CatalogRequest>,
+    catalog_backfills: AHashMap<UUID4, CatalogBackfill>,
+    pending_responses: VecDeque<DataResponse>,
     routing_map: IndexMap<Venue, ClientId>,
     book_intervals: AHashMap<NonZeroUsize, AHashSet<InstrumentId>>,
     book_updaters: AHashMap<InstrumentId, Rc<BookUpdater>>,
@@ -133,7 +164,10 @@ impl DataEngine {
             clients: IndexMap::new(),
             default_client: None,
             external_clients,
-            catalogs: AHashMap::new(),
+            catalogs: IndexMap::new(),
+            catalog_requests: AHashMap::new(),
+            catalog_backfills: AHashMap::new(),
+            pending_responses: VecDeque::new(),
             routing_map: IndexMap::new(),
             book_intervals: AHashMap::new(),
             book_updaters: AHashMap::new(),
@@ -160,8 +194,78 @@ impl DataEngine {
         self.cache.borrow()
     }
 
+    /// Registers the engine's execute, process and response endpoint handlers with the message bus.
+    ///
+    /// The process endpoint accepts [`Data`] as well as instruments and custom data. An
+    /// endpoint expiring catalog requests whose backfills are not received in time is also
+    /// registered.
+    pub fn register_message_handlers(engine: &Rc<RefCell<Self>>) {
+        let engine_ref = engine.clone();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |cmd: &DataCommand| {
+                engine_ref.borrow_mut().execute(cmd);
+                Self::send_pending_responses(&engine_ref);
+            },
+        )));
+        msgbus::register(MessagingSwitchboard::data_engine_execute(), handler);
+
+        let engine_ref = engine.clone();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::with_any(
+            move |data: &dyn Any| engine_ref.borrow_mut().process(data),
+        )));
+        msgbus::register(MessagingSwitchboard::data_engine_process(), handler);
+
+        let engine_ref = engine.clone();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |resp: &DataResponse| {
+                // Send once the engine is released, so the requester may issue further requests
+                let resp = engine_ref.borrow_mut().handle_response(resp.clone());
+                if let Some(resp) = resp {
+                    msgbus::send_response(resp.correlation_id(), &resp);
+                }
+            },
+        )));
+        msgbus::register(MessagingSwitchboard::data_engine_response(), handler);
+
+        let engine_ref = engine.clone();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |parent_id: &UUID4| {
+                engine_ref.borrow_mut().expire_catalog_request(parent_id);
+                Self::send_pending_responses(&engine_ref);
+            },
+        )));
+        msgbus::register(catalog_request_expiry_endpoint(), handler);
+    }
+
+    /// Processes and sends the responses deferred while executing commands on the `engine`
+    /// (e.g. requests served entirely from the registered catalogs).
+    ///
+    /// Each response is sent after the engine borrow is released, so the requester may
+    /// issue further requests from its response handler.
+    pub fn send_pending_responses(engine: &Rc<RefCell<Self>>) {
+        loop {
+            let resp = {
+                let mut engine = engine.borrow_mut();
+                let Some(resp) = engine.pending_responses.pop_front() else {
+                    break;
+                };
+                engine.handle_response(resp)
+            };
+
+            if let Some(resp) = resp {
+                msgbus::send_response(resp.correlation_id(), &resp);
+            }
+        }
+    }
+
     /// Registers the `catalog` with the engine with an optional specific `name`.
     ///
+    /// Quote, trade and bar requests with a `start` are served from the registered catalogs
+    /// first, and only the intervals they do not cover are requested from data clients.
+    /// When `write_requested_data_to_catalog` is configured, backfilled data is written to
+    /// the catalog which served the request (the first catalog holding data for the
+    /// requested range, then for the identifier, otherwise the first registered catalog).
+    ///
     /// # Panics
     ///
     /// Panics if a catalog with the same `name` has already been registered.
@@ -536,10 +640,14 @@ impl DataEngine {
 
     /// Sends a [`RequestCommand`] to a suitable data client implementation.
     ///
+    /// Requests served entirely from the registered catalogs are answered once the engine
+    /// is released, see [`DataEngine::send_pending_responses`].
+    ///
     /// # Errors
     ///
     /// Returns an error if no client is found for the given client ID or venue,
-    /// or if the client fails to process the request.
+    /// if the client fails to process the request, or if a registered catalog
+    /// cannot be read.
     pub fn execute_request(&mut self, req: &RequestCommand) -> anyhow::Result<()> {
         // Skip requests for external clients
         if let Some(cid) = req.client_id() {
@@ -547,34 +655,266 @@ impl DataEngine {
                 return Ok(());
             }
         }
+        if !self.catalogs.is_empty() && self.execute_catalog_request(req)? {
+            return Ok(());
+        }
         if let Some(client) = self.get_client(req.client_id(), req.venue()) {
-            match req {
-                RequestCommand::Data(req) => client.request_data(req),
-                RequestCommand::Instrument(req) => client.request_instrument(req),
-                RequestCommand::Instruments(req) => client.request_instruments(req),
-                RequestCommand::BookSnapshot(req) => client.request_book_snapshot(req),
-                RequestCommand::Quotes(req) => client.request_quotes(req),
-                RequestCommand::Trades(req) => client.request_trades(req),
-                RequestCommand::Bars(req) => client.request_bars(req),
+            send_request(client, req)
+        } else {
+            anyhow::bail!(
+                "Cannot handle request: no client found for {:?} {:?}",
+                req.client_id(),
+                req.venue()
+            );
+        }
+    }
+
+    /// Serves `req` from the registered catalogs, requesting any intervals they do not cover
+    /// from a data client. Returns `false` if the request should be forwarded unchanged.
+    fn execute_catalog_request(&mut self, req: &RequestCommand) -> anyhow::Result<bool> {
+        let Some((type_name, identifier, start, end)) = self.catalog_request_bounds(req) else {
+            return Ok(false);
+        };
+
+        let mut catalog_intervals = Vec::with_capacity(self.catalogs.len());
+        let mut serving: Option<(Ustr, bool)> = None; // (name, overlaps request)
+        for (name, catalog) in &self.catalogs {
+            let intervals = catalog.get_intervals(type_name, Some(identifier.clone()))?;
+            let overlaps = intervals.iter().any(|&(s, e)| s <= end && e >= start);
+            if !intervals.is_empty() && serving.is_none_or(|(_, prev)| overlaps && !prev) {
+                serving = Some((*name, overlaps));
             }
+            catalog_intervals.push(intervals);
+        }
+        let covered = merge_intervals(catalog_intervals.concat());
+        let missing = missing_intervals(start, end, &covered);
+        let is_uncovered = missing == [(start, end)];
+
+        if is_uncovered && !self.config.write_requested_data_to_catalog {
+            return Ok(false); // Nothing to serve from or write to the catalogs
+        }
+
+        let data = if is_uncovered {
+            Vec::new()
         } else {
+            self.query_catalogs(req, &identifier, start, end, &catalog_intervals)?
+        };
+
+        if missing.is_empty() {
+            log::debug!("Serving {} from catalogs", req.request_id());
+            let client_id = req.client_id().copied().unwrap_or_else(catalog_client_id);
+            let ts_init = self.clock.borrow().timestamp_ns();
+            // Deferred until the engine is released (see `send_pending_responses`)
+            self.pending_responses
+                .push_back(catalog_response(req, client_id, data, ts_init));
+            return Ok(true);
+        }
+
+        if self.get_client(req.client_id(), req.venue()).is_none() {
             anyhow::bail!(
                 "Cannot handle request: no client found for {:?} {:?}",
                 req.client_id(),
                 req.venue()
             );
         }
+
+        let parent_id = *req.request_id();
+        log::debug!(
+            "Requesting {} missing interval(s) for {parent_id} from client",
+            missing.len()
+        );
+
+        // SAFETY: Client existence checked above
+        let client = self.get_client(req.client_id(), req.venue()).unwrap();
+        let mut sent = Vec::with_capacity(missing.len());
+        for &(start, end) in &missing {
+            let backfill = backfill_request(req, start, end);
+            if let Err(e) = send_request(client, &backfill) {
+                log::error!("Error requesting missing interval for {parent_id} from client: {e}");
+                break;
+            }
+            sent.push((*backfill.request_id(), start, end));
+        }
+
+        if sent.is_empty() {
+            // Serve what the catalogs hold rather than leaving the request unanswered
+            let client_id = req.client_id().copied().unwrap_or_else(catalog_client_id);
+            let ts_init = self.clock.borrow().timestamp_ns();
+            self.pending_responses
+                .push_back(catalog_response(req, client_id, data, ts_init));
+            return Ok(true);
+        }
+
+        let catalog = serving
+            .map(|(name, _)| name)
+            .or_else(|| self.catalogs.keys().next().copied())
+            .expect("Catalogs should not be empty");
+        for &(request_id, start, end) in &sent {
+            self.catalog_backfills.insert(
+                request_id,
+                CatalogBackfill {
+                    parent_id,
+                    catalog,
+                    start,
+                    end,
+                },
+            );
+        }
+
+        self.catalog_requests.insert(
+            parent_id,
+            CatalogRequest {
+                request: req.clone(),
+                data,
+                client_id: req.client_id().copied(),
+                pending: sent.len(),
+            },
+        );
+        self.set_catalog_request_timer(parent_id);
+
+        Ok(true)
+    }
+
+    /// Sets the timer expiring the catalog request `parent_id` if its backfills are not
+    /// received within the configured `catalog_request_timeout`.
+    fn set_catalog_request_timer(&self, parent_id: UUID4) {
+        let timeout_ns = self.config.catalog_request_timeout.as_nanos() as u64;
+        let mut clock = self.clock.borrow_mut();
+        let alert_time_ns = UnixNanos::from(clock.timestamp_ns().as_u64() + timeout_ns);
+        let callback = TimeEventCallback::Rust(Rc::new(move |_event| {
+            msgbus::send(catalog_request_expiry_endpoint(), &parent_id as &dyn Any);
+        }));
+
+        if let Err(e) = clock.set_time_alert_ns(
+            &catalog_request_timer_name(&parent_id),
+            alert_time_ns,
+            Some(callback),
+            None,
+        ) {
+            log::error!("Error setting catalog request timer for {parent_id}: {e}");
+        }
+    }
+
+    /// Expires the catalog request `parent_id`, responding with the data received so far.
+    ///
+    /// Backfill responses arriving after the request has expired are handled as
+    /// regular responses.
+    pub fn expire_catalog_request(&mut self, parent_id: &UUID4) {
+        let Some(request) = self.catalog_requests.remove(parent_id) else {
+            return; // Already responded to
+        };
+        self.catalog_backfills
+            .retain(|_, backfill| backfill.parent_id != *parent_id);
+
+        log::warn!(
+            "Catalog request {parent_id} expired with {} backfill(s) pending",
+            request.pending
+        );
+
+        let client_id = request.client_id.unwrap_or_else(catalog_client_id);
+        let ts_init = self.clock.borrow().timestamp_ns();
+        self.pending_responses.push_back(catalog_response(
+            &request.request,
+            client_id,
+            request.data,
+            ts_init,
+        ));
+    }
+
+    /// Returns the catalog type name, identifier and inclusive UNIX nanosecond bounds
+    /// for requests which can be served from catalogs.
+    fn catalog_request_bounds(
+        &self,
+        req: &RequestCommand,
+    ) -> Option<(&'static str, String, u64, u64)> {
+        let (type_name, identifier, start, end) = match req {
+            RequestCommand::Quotes(req) => (
+                QuoteTick::path_prefix(),
+                req.instrument_id.to_string(),
+                req.start?,
+                req.end,
+            ),
+            RequestCommand::Trades(req) => (
+                TradeTick::path_prefix(),
+                req.instrument_id.to_string(),
+                req.start?,
+                req.end,
+            ),
+            RequestCommand::Bars(req) => (
+                Bar::path_prefix(),
+                req.bar_type.to_string(),
+                req.start?,
+                req.end,
+            ),
+            _ => return None,
+        };
+
+        let start = UnixNanos::from(start).as_u64();
+        let end = end
+            .map_or_else(|| self.clock.borrow().timestamp_ns(), UnixNanos::from)
+            .as_u64();
+
+        (start <= end).then_some((type_name, identifier, start, end))
+    }
+
+    /// Queries the data for `req` between `start` and `end` from the registered catalogs,
+    /// given the `intervals` stored in each catalog.
+    ///
+    /// Each part of the range is only queried from the first catalog covering it, so data
+    /// stored in several catalogs is returned once.
+    fn query_catalogs(
+        &mut self,
+        req: &RequestCommand,
+        identifier: &str,
+        start: u64,
+        end: u64,
+        intervals: &[Vec<(u64, u64)>],
+    ) -> anyhow::Result<Vec<Data>> {
+        let mut data = Vec::new();
+        let mut served: Vec<(u64, u64)> = Vec::new();
+
+        for (catalog, intervals) in self.catalogs.values_mut().zip(intervals) {
+            let ranges: Vec<(u64, u64)> = intervals
+                .iter()
+                .filter(|&&(s, e)| s <= end && e >= start)
+                .flat_map(|&(s, e)| missing_intervals(s.max(start), e.min(end), &served))
+                .collect();
+
+            for &(range_start, range_end) in &ranges {
+                let range_start = Some(UnixNanos::from(range_start));
+                let range_end = Some(UnixNanos::from(range_end));
+                data.extend(match req {
+                    RequestCommand::Quotes(_) => {
+                        catalog.query_identifier::<QuoteTick>(identifier, range_start, range_end)?
+                    }
+                    RequestCommand::Trades(_) => {
+                        catalog.query_identifier::<TradeTick>(identifier, range_start, range_end)?
+                    }
+                    RequestCommand::Bars(_) => {
+                        catalog.query_identifier::<Bar>(identifier, range_start, range_end)?
+                    }
+                    _ => Vec::new(),
+                });
+            }
+
+            served = merge_intervals([served, ranges].concat());
+        }
+
+        Ok(data)
     }
 
     /// Processes a dynamically-typed data message.
     ///
-    /// Currently supports `InstrumentAny`; unrecognized types are logged as errors.
+    /// Currently supports `InstrumentAny` and `CustomDataAny`; unrecognized types are
+    /// logged as errors.
     pub fn process(&mut self, data: &dyn Any) {
         // TODO: Eventually these could be added to the `Data` enum? process here for now
         if let Some(data) = data.downcast_ref::<Data>() {
             self.process_data(data.clone()); // TODO: Optimize (not necessary if we change handler)
         } else if let Some(instrument) = data.downcast_ref::<InstrumentAny>() {
             self.handle_instrument(instrument.clone());
+        } else if let Some(custom) = data.downcast_ref::<CustomDataAny>() {
+            self.handle_custom_data(custom);
         } else {
             log::error!("Cannot process data {data:?}, type is unrecognized");
         }
@@ -592,13 +932,29 @@ impl DataEngine {
             Data::MarkPriceUpdate(mark_price) => self.handle_mark_price(mark_price),
             Data::IndexPriceUpdate(index_price) => self.handle_index_price(index_price),
             Data::InstrumentClose(close) => self.handle_instrument_close(close),
+            Data::Custom(custom) => self.handle_custom_data(&custom),
         }
     }
 
     /// Processes a `DataResponse`, handling and publishing the response message.
-    pub fn response(&self, resp: DataResponse) {
+    ///
+    /// Responses to catalog backfill requests are held until all backfills for the
+    /// originating request have been received, then merged with the catalog data.
+    pub fn response(&mut self, resp: DataResponse) {
+        if let Some(resp) = self.handle_response(resp) {
+            msgbus::send_response(resp.correlation_id(), &resp);
+        }
+    }
+
+    /// Processes a `DataResponse`, returning the response once it is ready to be sent.
+    fn handle_response(&mut self, resp: DataResponse) -> Option<DataResponse> {
         log::debug!("{RECV}{RES} {resp:?}");
 
+        if let Some(backfill) = self.catalog_backfills.remove(resp.correlation_id()) {
+            let resp = self.handle_backfill_response(backfill, resp)?;
+            return self.handle_response(resp);
+        }
+
         match &resp {
             DataResponse::Instrument(resp) => {
                 self.handle_instrument_response(resp.data.clone());
@@ -612,7 +968,146 @@ impl DataEngine {
             _ => todo!(),
         }
 
-        msgbus::send_response(resp.correlation_id(), &resp);
+        Some(resp)
+    }
+
+    /// Merges a backfill `resp` into its originating catalog request, returning the response
+    /// to the originating request once all of its backfills have been received.
+    fn handle_backfill_response(
+        &mut self,
+        backfill: CatalogBackfill,
+        resp: DataResponse,
+    ) -> Option<DataResponse> {
+        if self.config.write_requested_data_to_catalog {
+            self.write_backfill_to_catalog(&backfill, &resp);
+        }
+
+        let Some(request) = self.catalog_requests.get_mut(&backfill.parent_id) else {
+            log::error!("No catalog request found for {}", backfill.parent_id);
+            return None;
+        };
+
+        let client_id = match resp {
+            DataResponse::Quotes(resp) => {
+                request.data.extend(resp.data.into_iter().map(Data::Quote));
+                Some(resp.client_id)
+            }
+            DataResponse::Trades(resp) => {
+                request.data.extend(resp.data.into_iter().map(Data::Trade));
+                Some(resp.client_id)
+            }
+            DataResponse::Bars(resp) => {
+                request.data.extend(resp.data.into_iter().map(Data::Bar));
+                Some(resp.client_id)
+            }
+            _ => None,
+        };
+        request.client_id = request.client_id.or(client_id);
+        request.pending -= 1;
+
+        if request.pending > 0 {
+            return None;
+        }
+
+        // SAFETY: Request existence checked above
+        let request = self.catalog_requests.remove(&backfill.parent_id).unwrap();
+        let timer_name = catalog_request_timer_name(&backfill.parent_id);
+        let mut clock = self.clock.borrow_mut();
+        if clock.timer_names().contains(&timer_name.as_str()) {
+            clock.cancel_timer(&timer_name);
+        }
+        drop(clock);
+
+        let client_id = request.client_id.unwrap_or_else(catalog_client_id);
+        let ts_init = self.clock.borrow().timestamp_ns();
+        Some(catalog_response(
+            &request.request,
+            client_id,
+            request.data,
+            ts_init,
+        ))
+    }
+
+    fn write_backfill_to_catalog(&self, backfill: &CatalogBackfill, resp: &DataResponse) {
+        let Some(catalog) = self.catalogs.get(&backfill.catalog) else {
+            log::error!("Catalog <{}> is no longer registered", backfill.catalog);
+            return;
+        };
+
+        // The metadata is only required to record intervals without data
+        let (start, end) = (backfill.start, backfill.end);
+        let result = match resp {
+            DataResponse::Quotes(resp) => {
+                let sample = resp
+                    .data
+                    .first()
+                    .map(|q| (q.bid_price.precision, q.bid_size.precision));
+                self.backfill_precisions(&resp.instrument_id, sample).map(
+                    |(price_precision, size_precision)| {
+                        let metadata = QuoteTick::get_metadata(
+                            &resp.instrument_id,
+                            price_precision,
+                            size_precision,
+                        );
+                        catalog.write_interval(sorted_by_ts_init(&resp.data), &metadata, start, end)
+                    },
+                )
+            }
+            DataResponse::Trades(resp) => {
+                let sample = resp
+                    .data
+                    .first()
+                    .map(|t| (t.price.precision, t.size.precision));
+                self.backfill_precisions(&resp.instrument_id, sample).map(
+                    |(price_precision, size_precision)| {
+                        let metadata = TradeTick::get_metadata(
+                            &resp.instrument_id,
+                            price_precision,
+                            size_precision,
+                        );
+                        catalog.write_interval(sorted_by_ts_init(&resp.data), &metadata, start, end)
+                    },
+                )
+            }
+            DataResponse::Bars(resp) => {
+                let sample = resp
+                    .data
+                    .first()
+                    .map(|b| (b.open.precision, b.volume.precision));
+                self.backfill_precisions(&resp.bar_type.instrument_id(), sample)
+                    .map(|(price_precision, size_precision)| {
+                        let metadata =
+                            Bar::get_metadata(&resp.bar_type, price_precision, size_precision);
+                        catalog.write_interval(sorted_by_ts_init(&resp.data), &metadata, start, end)
+                    })
+            }
+            _ => return,
+        };
+
+        match result {
+            Some(Ok(_)) => {}
+            Some(Err(e)) => log::error!("Error writing backfilled data to catalog: {e}"),
+            None => log::warn!(
+                "Cannot record backfilled interval {start}-{end} to catalog: \
+                no data or instrument for {}",
+                resp.correlation_id()
+            ),
+        }
+    }
+
+    /// Returns the price and size precisions for writing backfilled data, from the `sample`
+    /// of the data if any, otherwise from the cached instrument.
+    fn backfill_precisions(
+        &self,
+        instrument_id: &InstrumentId,
+        sample: Option<(u8, u8)>,
+    ) -> Option<(u8, u8)> {
+        sample.or_else(|| {
+            self.cache
+                .borrow()
+                .instrument(instrument_id)
+                .map(|instrument| (instrument.price_precision(), instrument.size_precision()))
+        })
     }
 
     // -- DATA HANDLERS ---------------------------------------------------------------------------
@@ -774,6 +1269,12 @@ impl DataEngine {
         msgbus::publish(topic, &close as &dyn Any);
     }
 
+    fn handle_custom_data(&mut self, custom: &CustomDataAny) {
+        // Publish the inner value, so subscribers can downcast to their own type
+        let topic = switchboard::get_custom_topic(&custom.data_type());
+        msgbus::publish(topic, custom.as_any());
+    }
+
     // -- SUBSCRIPTION HANDLERS -------------------------------------------------------------------
 
     fn subscribe_book_deltas(&mut self, cmd: &SubscribeBookDeltas) -> anyhow::Result<()> {
@@ -1100,7 +1601,7 @@ impl DataEngine {
         let size_precision = instrument.size_precision();
 
         if bar_type.spec().is_time_aggregated() {
-            Box::new(TimeBarAggregator::new(
+            let mut aggregator = TimeBarAggregator::new(
                 bar_type,
                 price_precision,
                 size_precision,
@@ -1113,7 +1614,22 @@ impl DataEngine {
                 None,  // TODO: Implement
                 20,    // TODO: TBD, composite bar build delay
                 false, // TODO: skip_first_non_full_bar, make it config dependent
-            ))
+            );
+
+            // Align daily and weekly bars to the trading sessions of the venue if configured
+            let venue = bar_type.instrument_id().venue;
+            if matches!(
+                bar_type.spec().aggregation,
+                BarAggregation::Day | BarAggregation::Week
+            ) {
+                if let Some(calendar) = config.time_bars_session_calendars.get(&venue) {
+                    if let Err(e) = aggregator.set_session_calendar(calendar.clone()) {
+                        log::warn!("Cannot align {bar_type} bars to {venue} sessions: {e}");
+                    }
+                }
+            }
+
+            Box::new(aggregator)
         } else {
             match bar_type.spec().aggregation {
                 BarAggregation::Tick => Box::new(TickBarAggregator::new(
@@ -1239,6 +1755,116 @@ impl DataEngine {
 }
 
 #[inline(always)]
+fn send_request(client: &DataClientAdapter, req: &RequestCommand) -> anyhow::Result<()> {
+    match req {
+        RequestCommand::Data(req) => client.request_data(req),
+        RequestCommand::Instrument(req) => client.request_instrument(req),
+        RequestCommand::Instruments(req) => client.request_instruments(req),
+        RequestCommand::BookSnapshot(req) => client.request_book_snapshot(req),
+        RequestCommand::Quotes(req) => client.request_quotes(req),
+        RequestCommand::Trades(req) => client.request_trades(req),
+        RequestCommand::Bars(req) => client.request_bars(req),
+    }
+}
+
+fn catalog_client_id() -> ClientId {
+    ClientId::new("CATALOG")
+}
+
+fn catalog_request_expiry_endpoint() -> MStr<Endpoint> {
+    "DataEngine.expire_catalog_request".into()
+}
+
+fn catalog_request_timer_name(parent_id: &UUID4) -> String {
+    format!("DataEngine.catalog_request.{parent_id}")
+}
+
+/// Returns a copy of `req` for the inclusive `start` to `end` interval with a new request ID.
+fn backfill_request(req: &RequestCommand, start: u64, end: u64) -> RequestCommand {
+    let start = Some(UnixNanos::from(start).to_datetime_utc());
+    let end = Some(UnixNanos::from(end).to_datetime_utc());
+    let request_id = UUID4::new();
+
+    match req {
+        RequestCommand::Quotes(req) => RequestCommand::Quotes(RequestQuotes {
+            start,
+            end,
+            request_id,
+            ..req.clone()
+        }),
+        RequestCommand::Trades(req) => RequestCommand::Trades(RequestTrades {
+            start,
+            end,
+            request_id,
+            ..req.clone()
+        }),
+        RequestCommand::Bars(req) => RequestCommand::Bars(RequestBars {
+            start,
+            end,
+            request_id,
+            ..req.clone()
+        }),
+        _ => req.clone(),
+    }
+}
+
+/// Builds the response to `req` from the merged catalog and backfilled `data`.
+///
+/// The data is sorted by `ts_init` (keeping the order of records with equal timestamps),
+/// and when the request has a `limit` only the most recent records are kept.
+fn catalog_response(
+    req: &RequestCommand,
+    client_id: ClientId,
+    mut data: Vec<Data>,
+    ts_init: UnixNanos,
+) -> DataResponse {
+    data.sort_by_key(GetTsInit::ts_init);
+
+    let limit = match req {
+        RequestCommand::Quotes(req) => req.limit,
+        RequestCommand::Trades(req) => req.limit,
+        RequestCommand::Bars(req) => req.limit,
+        _ => None,
+    };
+    if let Some(limit) = limit {
+        data.drain(..data.len().saturating_sub(limit.get()));
+    }
+
+    match req {
+        RequestCommand::Trades(req) => DataResponse::Trades(TradesResponse::new(
+            req.request_id,
+            client_id,
+            req.instrument_id,
+            data.into_iter().filter_map(|d| d.try_into().ok()).collect(),
+            ts_init,
+            req.params.clone(),
+        )),
+        RequestCommand::Bars(req) => DataResponse::Bars(BarsResponse::new(
+            req.request_id,
+            client_id,
+            req.bar_type,
+            data.into_iter().filter_map(|d| d.try_into().ok()).collect(),
+            ts_init,
+            req.params.clone(),
+        )),
+        RequestCommand::Quotes(req) => DataResponse::Quotes(QuotesResponse::new(
+            req.request_id,
+            client_id,
+            req.instrument_id,
+            data.into_iter().filter_map(|d| d.try_into().ok()).collect(),
+            ts_init,
+            req.params.clone(),
+        )),
+        _ => unreachable!("Catalog requests are only made for quotes, trades and bars"),
+    }
+}
+
+fn sorted_by_ts_init<T: GetTsInit + Clone>(data: &[T]) -> Vec<T> {
+    let mut data = data.to_vec();
+    data.sort_by_key(GetTsInit::ts_init);
+    data
+}
+
 fn log_error_on_cache_insert<T: Display>(e: &T) {
     log::error!("Error on cache insert: {e}");
 }
@@ -15,21 +15,23 @@
 
 mod common;
 
-use std::{any::Any, cell::RefCell, num::NonZeroUsize, rc::Rc};
+use std::{
+    any::Any, cell::RefCell, collections::HashMap, num::NonZeroUsize, rc::Rc, time::Duration,
+};
 
 use common::mocks::MockDataClient;
 use nautilus_common::{
     cache::Cache,
     clock::{Clock, TestClock},
     messages::data::{
-        DataCommand, RequestBars, RequestBookSnapshot, RequestCommand, RequestCustomData,
-        RequestInstrument, RequestInstruments, RequestQuotes, RequestTrades, SubscribeBars,
-        SubscribeBookDeltas, SubscribeBookDepth10, SubscribeBookSnapshots, SubscribeCommand,
-        SubscribeCustomData, SubscribeIndexPrices, SubscribeInstrument, SubscribeMarkPrices,
-        SubscribeQuotes, SubscribeTrades, UnsubscribeBars, UnsubscribeBookDeltas,
-        UnsubscribeBookSnapshots, UnsubscribeCommand, UnsubscribeCustomData,
-        UnsubscribeIndexPrices, UnsubscribeInstrument, UnsubscribeMarkPrices, UnsubscribeQuotes,
-        UnsubscribeTrades,
+        DataCommand, DataResponse, QuotesResponse, RequestBars, RequestBookSnapshot,
+        RequestCommand, RequestCustomData, RequestInstrument, RequestInstruments, RequestQuotes,
+        RequestTrades, SubscribeBars, SubscribeBookDeltas, SubscribeBookDepth10,
+        SubscribeBookSnapshots, SubscribeCommand, SubscribeCustomData, SubscribeIndexPrices,
+        SubscribeInstrument, SubscribeMarkPrices, SubscribeQuotes, SubscribeTrades,
+        UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeBookSnapshots, UnsubscribeCommand,
+        UnsubscribeCustomData, UnsubscribeIndexPrices, UnsubscribeInstrument,
+        UnsubscribeMarkPrices, UnsubscribeQuotes, UnsubscribeTrades,
     },
     msgbus::{
         self, MessageBus,
@@ -39,19 +41,23 @@ use nautilus_common::{
     },
 };
 use nautilus_core::{UUID4, UnixNanos};
-use nautilus_data::{client::DataClientAdapter, engine::DataEngine};
+use nautilus_data::{
+    client::DataClientAdapter,
+    engine::{DataEngine, config::DataEngineConfig},
+};
 use nautilus_model::{
     data::{
-        Bar, BarType, Data, DataType, OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10,
-        QuoteTick, TradeTick,
+        Bar, BarType, CustomDataAny, CustomDataType, Data, DataType, GetTsInit, OrderBookDeltas,
+        OrderBookDeltas_API, OrderBookDepth10, QuoteTick, TradeTick,
         prices::{IndexPriceUpdate, MarkPriceUpdate},
         stubs::{stub_delta, stub_deltas, stub_depth10},
     },
     enums::{BookType, PriceType},
-    identifiers::{ClientId, TraderId, Venue},
+    identifiers::{ClientId, InstrumentId, TraderId, Venue},
     instruments::{CurrencyPair, Instrument, InstrumentAny, stubs::audusd_sim},
-    types::Price,
+    types::{Price, Quantity},
 };
+use nautilus_persistence::backend::catalog::ParquetDataCatalog;
 use rstest::*;
 
 #[fixture]
@@ -1078,6 +1084,581 @@ fn test_execute_request_bars(
     assert_eq!(recorder.borrow()[0], cmd);
 }
 
+fn quote_at(instrument_id: InstrumentId, ts: u64) -> QuoteTick {
+    QuoteTick::new(
+        instrument_id,
+        Price::from("1.00000"),
+        Price::from("1.00001"),
+        Quantity::from(100_000),
+        Quantity::from(100_000),
+        ts.into(),
+        ts.into(),
+    )
+}
+
+fn quote_metadata(instrument_id: InstrumentId) -> HashMap<String, String> {
+    QuoteTick::get_metadata(&instrument_id, 5, 0)
+}
+
+fn bar_at(bar_type: BarType, ts: u64) -> Bar {
+    Bar::new(
+        bar_type,
+        Price::from("1.00001"),
+        Price::from("1.00004"),
+        Price::from("1.00000"),
+        Price::from("1.00002"),
+        Quantity::from(100_000),
+        ts.into(),
+        ts.into(),
+    )
+}
+
+fn request_quotes_between(
+    instrument_id: InstrumentId,
+    start: u64,
+    end: u64,
+    limit: Option<NonZeroUsize>,
+    client_id: ClientId,
+) -> RequestQuotes {
+    RequestQuotes::new(
+        instrument_id,
+        Some(UnixNanos::from(start).to_datetime_utc()),
+        Some(UnixNanos::from(end).to_datetime_utc()),
+        limit,
+        Some(client_id),
+        UUID4::new(),
+        UnixNanos::default(),
+        None, // params
+    )
+}
+
+#[rstest]
+#[case(None, vec![1_000, 2_000])]
+#[case(NonZeroUsize::new(1), vec![2_000])]
+fn test_execute_request_quotes_served_from_catalog(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    audusd_sim: CurrencyPair,
+    client_id: ClientId,
+    venue: Venue,
+    #[case] limit: Option<NonZeroUsize>,
+    #[case] expected_ts: Vec<u64>,
+) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let quotes = vec![
+        quote_at(audusd_sim.id, 1_000),
+        quote_at(audusd_sim.id, 2_000),
+    ];
+    catalog
+        .write_interval(quotes, &quote_metadata(audusd_sim.id), 1_000, 3_000)
+        .unwrap();
+
+    let recorder: Rc<RefCell<Vec<DataCommand>>> = Rc::new(RefCell::new(Vec::new()));
+    {
+        let mut data_engine = data_engine.borrow_mut();
+        data_engine.register_catalog(catalog, None);
+        register_mock_client(
+            clock,
+            cache,
+            client_id,
+            venue,
+            None,
+            &recorder,
+            &mut data_engine,
+        );
+    }
+
+    let req = request_quotes_between(audusd_sim.id, 1_000, 3_000, limit, client_id);
+    let handler = get_message_saving_handler::<DataResponse>(None);
+    msgbus::register_response_handler(&req.request_id, handler.clone());
+    data_engine
+        .borrow_mut()
+        .execute(&DataCommand::Request(RequestCommand::Quotes(req)));
+
+    // Responses served from catalogs are deferred until the engine is released
+    assert!(get_saved_messages::<DataResponse>(handler.clone()).is_empty());
+    DataEngine::send_pending_responses(&data_engine);
+
+    assert!(recorder.borrow().is_empty());
+    let responses = get_saved_messages::<DataResponse>(handler);
+    assert_eq!(responses.len(), 1);
+    let DataResponse::Quotes(resp) = &responses[0] else {
+        panic!("Expected quotes response");
+    };
+    let ts: Vec<u64> = resp.data.iter().map(|q| q.ts_init.as_u64()).collect();
+    assert_eq!(ts, expected_ts);
+}
+
+#[rstest]
+fn test_execute_request_bars_served_from_catalog(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let bar_type = BarType::from("AUD/USD.SIM-1-MINUTE-LAST-EXTERNAL");
+    let bars = vec![bar_at(bar_type, 1_000), bar_at(bar_type, 2_000)];
+    catalog
+        .write_interval(bars, &Bar::get_metadata(&bar_type, 5, 0), 1_000, 3_000)
+        .unwrap();
+
+    let recorder: Rc<RefCell<Vec<DataCommand>>> = Rc::new(RefCell::new(Vec::new()));
+    {
+        let mut data_engine = data_engine.borrow_mut();
+        data_engine.register_catalog(catalog, None);
+        register_mock_client(
+            clock,
+            cache,
+            client_id,
+            venue,
+            None,
+            &recorder,
+            &mut data_engine,
+        );
+    }
+
+    let req = RequestBars::new(
+        bar_type,
+        Some(UnixNanos::from(1_000).to_datetime_utc()),
+        Some(UnixNanos::from(3_000).to_datetime_utc()),
+        None, // limit
+        Some(client_id),
+        UUID4::new(),
+        UnixNanos::default(),
+        None, // params
+    );
+    let handler = get_message_saving_handler::<DataResponse>(None);
+    msgbus::register_response_handler(&req.request_id, handler.clone());
+    data_engine
+        .borrow_mut()
+        .execute(&DataCommand::Request(RequestCommand::Bars(req)));
+    DataEngine::send_pending_responses(&data_engine);
+
+    assert!(recorder.borrow().is_empty());
+    let responses = get_saved_messages::<DataResponse>(handler);
+    assert_eq!(responses.len(), 1);
+    let DataResponse::Bars(resp) = &responses[0] else {
+        panic!("Expected bars response");
+    };
+    let ts: Vec<u64> = resp.data.iter().map(|b| b.ts_init.as_u64()).collect();
+    assert_eq!(resp.bar_type, bar_type);
+    assert_eq!(ts, vec![1_000, 2_000]);
+}
+
+#[rstest]
+fn test_execute_request_quotes_served_once_from_overlapping_catalogs(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    audusd_sim: CurrencyPair,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    // Identical ticks within a catalog are kept, while data held by both catalogs is not
+    let quotes = vec![
+        quote_at(audusd_sim.id, 1_000),
+        quote_at(audusd_sim.id, 1_000),
+        quote_at(audusd_sim.id, 2_000),
+    ];
+    let first_dir = tempfile::tempdir().unwrap();
+    let first = ParquetDataCatalog::new(first_dir.path().to_path_buf(), None);
+    first
+        .write_interval(quotes.clone(), &quote_metadata(audusd_sim.id), 1_000, 2_000)
+        .unwrap();
+    let second_dir = tempfile::tempdir().unwrap();
+    let second = ParquetDataCatalog::new(second_dir.path().to_path_buf(), None);
+    second
+        .write_interval(quotes, &quote_metadata(audusd_sim.id), 1_000, 3_000)
+        .unwrap();
+
+    let recorder: Rc<RefCell<Vec<DataCommand>>> = Rc::new(RefCell::new(Vec::new()));
+    {
+        let mut data_engine = data_engine.borrow_mut();
+        data_engine.register_catalog(first, Some("first".to_string()));
+        data_engine.register_catalog(second, Some("second".to_string()));
+        register_mock_client(
+            clock,
+            cache,
+            client_id,
+            venue,
+            None,
+            &recorder,
+            &mut data_engine,
+        );
+    }
+
+    let req = request_quotes_between(audusd_sim.id, 1_000, 3_000, None, client_id);
+    let handler = get_message_saving_handler::<DataResponse>(None);
+    msgbus::register_response_handler(&req.request_id, handler.clone());
+    data_engine
+        .borrow_mut()
+        .execute(&DataCommand::Request(RequestCommand::Quotes(req)));
+    DataEngine::send_pending_responses(&data_engine);
+
+    let responses = get_saved_messages::<DataResponse>(handler);
+    let DataResponse::Quotes(resp) = &responses[0] else {
+        panic!("Expected quotes response");
+    };
+    let ts: Vec<u64> = resp.data.iter().map(|q| q.ts_init.as_u64()).collect();
+    assert_eq!(ts, vec![1_000, 1_000, 2_000]);
+}
+
+#[rstest]
+fn test_execute_request_quotes_backfills_missing_interval_from_client(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    audusd_sim: CurrencyPair,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let quotes = vec![
+        quote_at(audusd_sim.id, 1_000),
+        quote_at(audusd_sim.id, 2_000),
+    ];
+    catalog
+        .write_interval(quotes, &quote_metadata(audusd_sim.id), 1_000, 2_000)
+        .unwrap();
+
+    let config = DataEngineConfig {
+        write_requested_data_to_catalog: true,
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock.clone(), cache.clone(), Some(config));
+    data_engine.register_catalog(catalog, None);
+    let recorder: Rc<RefCell<Vec<DataCommand>>> = Rc::new(RefCell::new(Vec::new()));
+    register_mock_client(
+        clock,
+        cache,
+        client_id,
+        venue,
+        None,
+        &recorder,
+        &mut data_engine,
+    );
+
+    let req = request_quotes_between(audusd_sim.id, 1_000, 4_000, None, client_id);
+    let handler = get_message_saving_handler::<DataResponse>(None);
+    msgbus::register_response_handler(&req.request_id, handler.clone());
+    data_engine.execute(&DataCommand::Request(RequestCommand::Quotes(req.clone())));
+
+    let backfill = match &recorder.borrow()[..] {
+        [DataCommand::Request(RequestCommand::Quotes(backfill))] => backfill.clone(),
+        other => panic!("Expected a single backfill request, was {other:?}"),
+    };
+    assert_ne!(backfill.request_id, req.request_id);
+    assert_eq!(
+        backfill.start,
+        Some(UnixNanos::from(2_001).to_datetime_utc())
+    );
+    assert_eq!(backfill.end, req.end);
+    assert!(get_saved_messages::<DataResponse>(handler.clone()).is_empty());
+
+    data_engine.response(DataResponse::Quotes(QuotesResponse::new(
+        backfill.request_id,
+        client_id,
+        audusd_sim.id,
+        vec![quote_at(audusd_sim.id, 3_000)],
+        UnixNanos::default(),
+        None,
+    )));
+
+    let responses = get_saved_messages::<DataResponse>(handler);
+    assert_eq!(responses.len(), 1);
+    let DataResponse::Quotes(resp) = &responses[0] else {
+        panic!("Expected quotes response");
+    };
+    let ts: Vec<u64> = resp.data.iter().map(|q| q.ts_init.as_u64()).collect();
+    assert_eq!(resp.correlation_id, req.request_id);
+    assert_eq!(resp.client_id, client_id);
+    assert_eq!(ts, vec![1_000, 2_000, 3_000]);
+
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    assert_eq!(
+        catalog
+            .get_intervals("quotes", Some(audusd_sim.id.to_string()))
+            .unwrap(),
+        vec![(1_000, 4_000)]
+    );
+}
+
+#[rstest]
+fn test_execute_request_quotes_backfill_written_to_serving_catalog(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    audusd_sim: CurrencyPair,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    let empty_dir = tempfile::tempdir().unwrap();
+    let serving_dir = tempfile::tempdir().unwrap();
+    let serving = ParquetDataCatalog::new(serving_dir.path().to_path_buf(), None);
+    serving
+        .write_interval(
+            vec![quote_at(audusd_sim.id, 1_000)],
+            &quote_metadata(audusd_sim.id),
+            1_000,
+            2_000,
+        )
+        .unwrap();
+
+    let config = DataEngineConfig {
+        write_requested_data_to_catalog: true,
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock.clone(), cache.clone(), Some(config));
+    data_engine.register_catalog(
+        ParquetDataCatalog::new(empty_dir.path().to_path_buf(), None),
+        Some("empty".to_string()),
+    );
+    data_engine.register_catalog(serving, Some("serving".to_string()));
+    let recorder: Rc<RefCell<Vec<DataCommand>>> = Rc::new(RefCell::new(Vec::new()));
+    register_mock_client(
+        clock,
+        cache,
+        client_id,
+        venue,
+        None,
+        &recorder,
+        &mut data_engine,
+    );
+
+    let req = request_quotes_between(audusd_sim.id, 1_000, 4_000, None, client_id);
+    data_engine.execute(&DataCommand::Request(RequestCommand::Quotes(req)));
+
+    let backfill = match &recorder.borrow()[..] {
+        [DataCommand::Request(RequestCommand::Quotes(backfill))] => backfill.clone(),
+        other => panic!("Expected a single backfill request, was {other:?}"),
+    };
+    data_engine.response(DataResponse::Quotes(QuotesResponse::new(
+        backfill.request_id,
+        client_id,
+        audusd_sim.id,
+        vec![quote_at(audusd_sim.id, 3_000)],
+        UnixNanos::default(),
+        None,
+    )));
+
+    let identifier = Some(audusd_sim.id.to_string());
+    let serving = ParquetDataCatalog::new(serving_dir.path().to_path_buf(), None);
+    let empty = ParquetDataCatalog::new(empty_dir.path().to_path_buf(), None);
+    assert_eq!(
+        serving.get_intervals("quotes", identifier.clone()).unwrap(),
+        vec![(1_000, 4_000)]
+    );
+    assert!(
+        empty
+            .get_intervals("quotes", identifier)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[rstest]
+fn test_execute_request_quotes_records_empty_backfilled_interval(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    audusd_sim: CurrencyPair,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    catalog
+        .write_interval(
+            vec![quote_at(audusd_sim.id, 1_000)],
+            &quote_metadata(audusd_sim.id),
+            1_000,
+            2_000,
+        )
+        .unwrap();
+
+    let config = DataEngineConfig {
+        write_requested_data_to_catalog: true,
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock.clone(), cache.clone(), Some(config));
+    data_engine.register_catalog(catalog, None);
+    let recorder: Rc<RefCell<Vec<DataCommand>>> = Rc::new(RefCell::new(Vec::new()));
+    register_mock_client(
+        clock,
+        cache,
+        client_id,
+        venue,
+        None,
+        &recorder,
+        &mut data_engine,
+    );
+
+    let req = request_quotes_between(audusd_sim.id, 1_000, 4_000, None, client_id);
+    data_engine.execute(&DataCommand::Request(RequestCommand::Quotes(req)));
+
+    let backfill = match &recorder.borrow()[..] {
+        [DataCommand::Request(RequestCommand::Quotes(backfill))] => backfill.clone(),
+        other => panic!("Expected a single backfill request, was {other:?}"),
+    };
+    data_engine.response(DataResponse::Quotes(QuotesResponse::new(
+        backfill.request_id,
+        client_id,
+        audusd_sim.id,
+        Vec::new(), // No data for the interval
+        UnixNanos::default(),
+        None,
+    )));
+
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    assert_eq!(
+        catalog
+            .get_intervals("quotes", Some(audusd_sim.id.to_string()))
+            .unwrap(),
+        vec![(1_000, 4_000)]
+    );
+}
+
+#[rstest]
+fn test_catalog_request_expires_with_catalog_data(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    audusd_sim: CurrencyPair,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    catalog
+        .write_interval(
+            vec![quote_at(audusd_sim.id, 1_000)],
+            &quote_metadata(audusd_sim.id),
+            1_000,
+            2_000,
+        )
+        .unwrap();
+
+    let config = DataEngineConfig {
+        catalog_request_timeout: Duration::from_secs(1),
+        ..Default::default()
+    };
+    let data_engine = Rc::new(RefCell::new(DataEngine::new(
+        clock.clone(),
+        cache.clone(),
+        Some(config),
+    )));
+    DataEngine::register_message_handlers(&data_engine);
+    let recorder: Rc<RefCell<Vec<DataCommand>>> = Rc::new(RefCell::new(Vec::new()));
+    {
+        let mut data_engine = data_engine.borrow_mut();
+        data_engine.register_catalog(catalog, None);
+        register_mock_client(
+            clock.clone(),
+            cache,
+            client_id,
+            venue,
+            None,
+            &recorder,
+            &mut data_engine,
+        );
+    }
+
+    let req = request_quotes_between(audusd_sim.id, 1_000, 4_000, None, client_id);
+    let handler = get_message_saving_handler::<DataResponse>(None);
+    msgbus::register_response_handler(&req.request_id, handler.clone());
+    data_engine
+        .borrow_mut()
+        .execute(&DataCommand::Request(RequestCommand::Quotes(req)));
+    assert_eq!(recorder.borrow().len(), 1);
+    assert!(get_saved_messages::<DataResponse>(handler.clone()).is_empty());
+
+    // The client never responds to the backfill request
+    let events = clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(2_000_000_000), true);
+    let handlers = clock.borrow().match_handlers(events);
+    for time_handler in handlers {
+        time_handler.callback.call(time_handler.event);
+    }
+
+    let responses = get_saved_messages::<DataResponse>(handler);
+    assert_eq!(responses.len(), 1);
+    let DataResponse::Quotes(resp) = &responses[0] else {
+        panic!("Expected quotes response");
+    };
+    let ts: Vec<u64> = resp.data.iter().map(|q| q.ts_init.as_u64()).collect();
+    assert_eq!(ts, vec![1_000]);
+}
+
+#[rstest]
+fn test_catalog_response_handler_can_issue_request(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    audusd_sim: CurrencyPair,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let quotes = vec![
+        quote_at(audusd_sim.id, 1_000),
+        quote_at(audusd_sim.id, 2_000),
+    ];
+    catalog
+        .write_interval(quotes, &quote_metadata(audusd_sim.id), 1_000, 3_000)
+        .unwrap();
+
+    let data_engine = Rc::new(RefCell::new(DataEngine::new(
+        clock.clone(),
+        cache.clone(),
+        None,
+    )));
+    DataEngine::register_message_handlers(&data_engine);
+    {
+        let mut data_engine = data_engine.borrow_mut();
+        data_engine.register_catalog(catalog, None);
+        let recorder: Rc<RefCell<Vec<DataCommand>>> = Rc::new(RefCell::new(Vec::new()));
+        register_mock_client(
+            clock,
+            cache,
+            client_id,
+            venue,
+            None,
+            &recorder,
+            &mut data_engine,
+        );
+    }
+
+    let first = request_quotes_between(audusd_sim.id, 1_000, 3_000, None, client_id);
+    let second = request_quotes_between(audusd_sim.id, 2_000, 3_000, None, client_id);
+
+    // The response handler for the first request issues the second request to the engine
+    let second_cmd = DataCommand::Request(RequestCommand::Quotes(second.clone()));
+    let first_handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+        move |_: &DataResponse| {
+            let endpoint = MessagingSwitchboard::data_engine_execute();
+            msgbus::send(endpoint, &second_cmd as &dyn Any);
+        },
+    )));
+    msgbus::register_response_handler(&first.request_id, first_handler);
+    let second_handler = get_message_saving_handler::<DataResponse>(None);
+    msgbus::register_response_handler(&second.request_id, second_handler.clone());
+
+    let cmd = DataCommand::Request(RequestCommand::Quotes(first));
+    let endpoint = MessagingSwitchboard::data_engine_execute();
+    msgbus::send(endpoint, &cmd as &dyn Any);
+
+    let responses = get_saved_messages::<DataResponse>(second_handler);
+    assert_eq!(responses.len(), 1);
+    let DataResponse::Quotes(resp) = &responses[0] else {
+        panic!("Expected quotes response");
+    };
+    let ts: Vec<u64> = resp.data.iter().map(|q| q.ts_init.as_u64()).collect();
+    assert_eq!(ts, vec![2_000]);
+}
+
 // ------------------------------------------------------------------------------------------------
 // Test process data flows
 // ------------------------------------------------------------------------------------------------
@@ -1374,6 +1955,48 @@ fn test_process_mark_price(
     assert!(messages.contains(&mark_price));
 }
 
+#[derive(Clone, Debug, PartialEq)]
+struct Sentiment {
+    score: f64,
+    ts_init: UnixNanos,
+}
+
+impl GetTsInit for Sentiment {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl CustomDataType for Sentiment {
+    fn type_name() -> &'static str {
+        "Sentiment"
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+#[rstest]
+fn test_process_custom_data(data_engine: Rc<RefCell<DataEngine>>) {
+    let sentiment = Sentiment {
+        score: 0.5,
+        ts_init: UnixNanos::from(1),
+    };
+    let handler = get_message_saving_handler::<Sentiment>(None);
+    let topic = switchboard::get_custom_topic(&DataType::new("Sentiment", None));
+    msgbus::subscribe_topic(topic, handler.clone(), None);
+
+    let custom = CustomDataAny::new(sentiment.clone());
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::Custom(custom.clone()));
+    data_engine.process(&custom as &dyn Any);
+    let messages = get_saved_messages::<Sentiment>(handler);
+
+    assert_eq!(topic.as_str(), "data.Sentiment");
+    assert_eq!(messages, vec![sentiment.clone(), sentiment]);
+}
+
 #[rstest]
 fn test_process_index_price(
     audusd_sim: CurrencyPair,
@@ -23,6 +23,7 @@
 pub mod config;
 
 use std::{
+    any::Any,
     cell::{RefCell, RefMut},
     collections::{HashMap, HashSet},
     fmt::Debug,
@@ -42,23 +43,30 @@ use nautilus_common::{
     },
     msgbus::{
         self, get_message_bus,
-        switchboard::{self},
+        handler::{ShareableMessageHandler, TypedMessageHandler},
+        switchboard::{self, MessagingSwitchboard},
     },
 };
-use nautilus_core::UUID4;
+use nautilus_core::{UUID4, UnixNanos};
 use nautilus_model::{
-    enums::{ContingencyType, OmsType, OrderSide, PositionSide},
+    enums::{ContingencyType, OmsType, OrderSide, OrderStatus, PositionSide},
     events::{
-        OrderDenied, OrderEvent, OrderEventAny, OrderFilled, PositionChanged, PositionClosed,
-        PositionOpened,
+        OrderAccepted, OrderCanceled, OrderDenied, OrderEvent, OrderEventAny, OrderExpired,
+        OrderFilled, OrderRejected, PositionChanged, PositionClosed, PositionOpened,
+    },
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, Venue,
+        VenueOrderId,
     },
-    identifiers::{ClientId, InstrumentId, PositionId, StrategyId, Venue},
     instruments::{Instrument, InstrumentAny},
     orderbook::own::{OwnOrderBook, should_handle_own_book_order},
     orders::{Order, OrderAny, OrderError},
     position::Position,
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
     types::{Money, Price, Quantity},
 };
+use rust_decimal::{Decimal, prelude::FromPrimitive};
+use ustr::Ustr;
 
 use crate::client::ExecutionClient;
 
@@ -134,6 +142,38 @@ impl ExecutionEngine {
 
     // -- REGISTRATION ----------------------------------------------------------------------------
 
+    /// Registers the engine's event and reconciliation endpoint handlers with the message bus.
+    pub fn register_message_handlers(engine: &Rc<RefCell<Self>>) {
+        let engine_ref = engine.clone();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |event: &OrderEventAny| engine_ref.borrow_mut().process(event),
+        )));
+        msgbus::register(MessagingSwitchboard::exec_engine_process(), handler);
+
+        // Order status, fill and position reports share a single endpoint
+        let engine_ref = engine.clone();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::with_any(
+            move |report: &dyn Any| {
+                engine_ref.borrow_mut().reconcile_report(report);
+            },
+        )));
+        msgbus::register(
+            MessagingSwitchboard::exec_engine_reconcile_report(),
+            handler,
+        );
+
+        let engine_ref = engine.clone();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |mass_status: &ExecutionMassStatus| {
+                engine_ref.borrow_mut().reconcile_mass_status(mass_status);
+            },
+        )));
+        msgbus::register(
+            MessagingSwitchboard::exec_engine_reconcile_mass_status(),
+            handler,
+        );
+    }
+
     /// Registers a new execution client.
     ///
     /// # Errors
@@ -256,6 +296,282 @@ impl ExecutionEngine {
         self.execute_command(command);
     }
 
+    // -- RECONCILIATION --------------------------------------------------------------------------
+
+    /// Reconciles an execution report received from an execution client.
+    ///
+    /// The `report` may be an [`OrderStatusReport`], [`FillReport`] or [`PositionStatusReport`].
+    /// Returns whether the report was reconciled with the cached state.
+    pub fn reconcile_report(&mut self, report: &dyn Any) -> bool {
+        if let Some(report) = report.downcast_ref::<OrderStatusReport>() {
+            self.reconcile_order_status_report(report)
+        } else if let Some(report) = report.downcast_ref::<FillReport>() {
+            self.reconcile_fill_report(report)
+        } else if let Some(report) = report.downcast_ref::<PositionStatusReport>() {
+            self.reconcile_position_report(report)
+        } else {
+            log::error!("Cannot reconcile report: unrecognized report type");
+            false
+        }
+    }
+
+    /// Reconciles the order, fill and position reports of an execution mass status.
+    ///
+    /// Fills for each order are applied in event order before its order status report.
+    /// Returns whether all reports were reconciled with the cached state.
+    pub fn reconcile_mass_status(&mut self, mass_status: &ExecutionMassStatus) -> bool {
+        log::info!(
+            "Reconciling {} for {}",
+            stringify!(ExecutionMassStatus),
+            mass_status.venue
+        );
+
+        let order_reports = mass_status.order_reports();
+        let mut fill_reports = mass_status.fill_reports();
+        let mut reconciled = true;
+
+        for (venue_order_id, report) in &order_reports {
+            if let Some(mut fills) = fill_reports.shift_remove(venue_order_id) {
+                fills.sort_by_key(|fill| fill.ts_event);
+                for fill in &fills {
+                    reconciled &= self.reconcile_fill_report(fill);
+                }
+            }
+            reconciled &= self.reconcile_order_status_report(report);
+        }
+
+        // Fills for orders without a status report
+        for fill in fill_reports.values().flatten() {
+            reconciled &= self.reconcile_fill_report(fill);
+        }
+
+        for report in mass_status.position_reports().values().flatten() {
+            reconciled &= self.reconcile_position_report(report);
+        }
+
+        if reconciled {
+            log::info!("Reconciliation for {} succeeded", mass_status.venue);
+        } else {
+            log::warn!("Reconciliation for {} failed", mass_status.venue);
+        }
+
+        reconciled
+    }
+
+    /// Reconciles an order status report, generating the events required to align the
+    /// cached order status with the venue.
+    ///
+    /// Returns whether the order was reconciled.
+    pub fn reconcile_order_status_report(&mut self, report: &OrderStatusReport) -> bool {
+        let Some(order) = self.find_order(report.client_order_id, report.venue_order_id) else {
+            log::warn!("Cannot reconcile {report}: order not found in the cache");
+            return false;
+        };
+
+        if order.is_closed() {
+            if matches!(
+                report.order_status,
+                OrderStatus::Accepted
+                    | OrderStatus::Triggered
+                    | OrderStatus::PendingCancel
+                    | OrderStatus::PendingUpdate
+                    | OrderStatus::PartiallyFilled
+            ) {
+                log::warn!(
+                    "Cannot reconcile {report}: order {} is already closed",
+                    order.client_order_id(),
+                );
+                return false;
+            }
+            return true; // Already reconciled
+        }
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+
+        if order.status() == OrderStatus::Submitted
+            && report.order_status != OrderStatus::Rejected
+            && report.order_status != OrderStatus::Canceled
+        {
+            self.process(&accepted_event(
+                &order,
+                report.venue_order_id,
+                report.account_id,
+                report.ts_accepted,
+                ts_init,
+            ));
+        }
+
+        let event = match report.order_status {
+            OrderStatus::Rejected if order.status() == OrderStatus::Submitted => {
+                let reason = report.cancel_reason.as_deref().unwrap_or("UNKNOWN");
+                Some(OrderEventAny::Rejected(OrderRejected::new(
+                    order.trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    order.client_order_id(),
+                    report.account_id,
+                    Ustr::from(reason),
+                    UUID4::new(),
+                    report.ts_last,
+                    ts_init,
+                    true,
+                )))
+            }
+            OrderStatus::Canceled => Some(OrderEventAny::Canceled(OrderCanceled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                report.ts_last,
+                ts_init,
+                true,
+                Some(report.venue_order_id),
+                Some(report.account_id),
+            ))),
+            OrderStatus::Expired => Some(OrderEventAny::Expired(OrderExpired::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                report.ts_last,
+                ts_init,
+                true,
+                Some(report.venue_order_id),
+                Some(report.account_id),
+            ))),
+            _ => None,
+        };
+
+        let filled_qty = order.filled_qty();
+        if let Some(event) = event {
+            self.process(&event);
+        }
+
+        if report.filled_qty > filled_qty {
+            log::warn!(
+                "Cannot reconcile {report}: fill reports required for filled_qty {} (cached {filled_qty})",
+                report.filled_qty,
+            );
+            return false;
+        }
+
+        true
+    }
+
+    /// Reconciles a fill report, generating an [`OrderFilled`] event if the trade has not
+    /// already been applied to the cached order.
+    ///
+    /// Returns whether the fill was reconciled.
+    pub fn reconcile_fill_report(&mut self, report: &FillReport) -> bool {
+        let Some(order) = self.find_order(report.client_order_id, report.venue_order_id) else {
+            log::warn!("Cannot reconcile {report}: order not found in the cache");
+            return false;
+        };
+
+        if order.trade_ids().contains(&&report.trade_id) {
+            return true; // Already applied
+        }
+
+        if report.last_qty > order.leaves_qty() {
+            log::warn!(
+                "Cannot reconcile {report}: last_qty exceeds leaves_qty {} of order {}",
+                order.leaves_qty(),
+                order.client_order_id(),
+            );
+            return false;
+        }
+
+        let Some(currency) = self
+            .cache
+            .borrow()
+            .instrument(&report.instrument_id)
+            .map(Instrument::quote_currency)
+        else {
+            log::warn!(
+                "Cannot reconcile {report}: no instrument found for {}",
+                report.instrument_id
+            );
+            return false;
+        };
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+
+        if order.status() == OrderStatus::Submitted {
+            self.process(&accepted_event(
+                &order,
+                report.venue_order_id,
+                report.account_id,
+                report.ts_event,
+                ts_init,
+            ));
+        }
+
+        let filled = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            report.account_id,
+            report.trade_id,
+            report.order_side,
+            order.order_type(),
+            report.last_qty,
+            report.last_px,
+            currency,
+            report.liquidity_side,
+            UUID4::new(),
+            report.ts_event,
+            ts_init,
+            true,
+            report.venue_position_id,
+            Some(report.commission),
+        );
+        self.process(&OrderEventAny::Filled(filled));
+
+        true
+    }
+
+    /// Reconciles a position status report against the net quantity of open positions in
+    /// the cache for the instrument.
+    ///
+    /// Returns whether the cached net position matches the report.
+    #[must_use]
+    pub fn reconcile_position_report(&self, report: &PositionStatusReport) -> bool {
+        let cache = self.cache.borrow();
+        let signed_qty: f64 = cache
+            .positions_open(None, Some(&report.instrument_id), None, None)
+            .iter()
+            .map(|position| position.signed_qty)
+            .sum();
+        let cached_qty = Decimal::from_f64(signed_qty)
+            .unwrap_or_default()
+            .round_dp(u32::from(report.quantity.precision));
+
+        if cached_qty != report.signed_decimal_qty {
+            log::warn!(
+                "Cannot reconcile {report}: cached net position {cached_qty} differs from {}",
+                report.signed_decimal_qty,
+            );
+            return false;
+        }
+
+        true
+    }
+
+    fn find_order(
+        &self,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: VenueOrderId,
+    ) -> Option<OrderAny> {
+        let cache = self.cache.borrow();
+        let client_order_id =
+            client_order_id.or_else(|| cache.client_order_id(&venue_order_id).copied())?;
+        cache.order(&client_order_id).cloned()
+    }
+
     // -- COMMAND HANDLERS ------------------------------------------------------------------------
 
     fn execute_command(&self, command: &TradingCommand) {
@@ -708,19 +1024,25 @@ impl ExecutionEngine {
             return;
         };
 
-        let mut position = match self.cache.borrow().position(&position_id) {
-            Some(pos) if !pos.is_closed() => pos.clone(),
-            _ => self
-                .open_position(instrument.clone(), None, fill, oms_type)
-                .unwrap(),
+        let cached_position = self.cache.borrow().position(&position_id).cloned();
+        let position = match cached_position {
+            Some(mut position) if !position.is_closed() => {
+                if self.will_flip_position(&position, fill) {
+                    self.flip_position(instrument, &mut position, fill, oms_type);
+                } else {
+                    self.update_position(&mut position, fill);
+                }
+                position
+            }
+            _ => match self.open_position(instrument, None, fill, oms_type) {
+                Ok(position) => position,
+                Err(e) => {
+                    log::error!("Cannot handle order fill: failed to open position: {e}");
+                    return;
+                }
+            },
         };
 
-        if self.will_flip_position(&position, fill) {
-            self.flip_position(instrument, &mut position, fill, oms_type);
-        } else {
-            self.update_position(&mut position, fill);
-        }
-
         if matches!(order.contingency_type(), Some(ContingencyType::Oto)) && position.is_open() {
             for client_order_id in order.linked_order_ids().unwrap_or_default() {
                 let mut cache = self.cache.borrow_mut();
@@ -1074,6 +1396,27 @@ impl ExecutionEngine {
     }
 }
 
+fn accepted_event(
+    order: &OrderAny,
+    venue_order_id: VenueOrderId,
+    account_id: AccountId,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> OrderEventAny {
+    OrderEventAny::Accepted(OrderAccepted::new(
+        order.trader_id(),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        venue_order_id,
+        account_id,
+        UUID4::new(),
+        ts_event,
+        ts_init,
+        true,
+    ))
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -1082,7 +1425,15 @@ mod tests {
     use std::{cell::RefCell, rc::Rc};
 
     use nautilus_common::{cache::Cache, clock::TestClock, msgbus::MessageBus};
-    use rstest::fixture;
+    use nautilus_model::{
+        accounts::{AccountAny, CashAccount},
+        enums::{LiquiditySide, OrderType, TimeInForce},
+        events::account::stubs::cash_account_state,
+        identifiers::{TradeId, stubs::account_id},
+        instruments::{CurrencyPair, InstrumentAny, stubs::audusd_sim},
+        orders::{OrderTestBuilder, stubs::TestOrderEventStubs},
+    };
+    use rstest::{fixture, rstest};
 
     use super::*;
 
@@ -1110,5 +1461,192 @@ mod tests {
         ExecutionEngine::new(clock, cache, config)
     }
 
-    // TODO: After Implementing ExecutionClient & Strategy
+    fn engine_with_submitted_order(
+        instrument: &CurrencyPair,
+    ) -> (ExecutionEngine, Rc<RefCell<Cache>>, OrderAny) {
+        let cache = Rc::new(RefCell::new(Cache::new(None, None)));
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let engine = _get_exec_engine(cache.clone(), clock, None);
+
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let submitted = TestOrderEventStubs::submitted(&order, account_id());
+        order.apply(submitted).unwrap();
+
+        {
+            let mut cache = cache.borrow_mut();
+            cache
+                .add_instrument(InstrumentAny::CurrencyPair(*instrument))
+                .unwrap();
+            cache
+                .add_account(AccountAny::Cash(CashAccount::new(
+                    cash_account_state(),
+                    true,
+                )))
+                .unwrap();
+            cache.add_order(order.clone(), None, None, false).unwrap();
+        }
+
+        (engine, cache, order)
+    }
+
+    fn status_report(order: &OrderAny, status: OrderStatus, filled_qty: u64) -> OrderStatusReport {
+        OrderStatusReport::new(
+            account_id(),
+            order.instrument_id(),
+            Some(order.client_order_id()),
+            VenueOrderId::from("V-1"),
+            order.order_side(),
+            order.order_type(),
+            TimeInForce::Gtc,
+            status,
+            order.quantity(),
+            Quantity::from(filled_qty),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+            UnixNanos::from(2),
+            None,
+        )
+    }
+
+    fn fill_report(order: &OrderAny, trade_id: &str, last_qty: u64) -> FillReport {
+        FillReport::new(
+            account_id(),
+            order.instrument_id(),
+            VenueOrderId::from("V-1"),
+            TradeId::from(trade_id),
+            order.order_side(),
+            Quantity::from(last_qty),
+            Price::from("1.00000"),
+            Money::from("2 USD"),
+            LiquiditySide::Maker,
+            Some(order.client_order_id()),
+            None,
+            UnixNanos::from(3),
+            UnixNanos::from(3),
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_reconcile_order_status_report_accepts_submitted_order(audusd_sim: CurrencyPair) {
+        let (mut engine, cache, order) = engine_with_submitted_order(&audusd_sim);
+        let report = status_report(&order, OrderStatus::Accepted, 0);
+
+        assert!(engine.reconcile_report(&report));
+
+        let cache = cache.borrow();
+        let cached = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(cached.status(), OrderStatus::Accepted);
+        assert_eq!(cached.venue_order_id(), Some(VenueOrderId::from("V-1")));
+    }
+
+    #[rstest]
+    fn test_reconcile_order_status_report_canceled(audusd_sim: CurrencyPair) {
+        let (mut engine, cache, order) = engine_with_submitted_order(&audusd_sim);
+        engine.reconcile_report(&status_report(&order, OrderStatus::Accepted, 0));
+
+        assert!(engine.reconcile_report(&status_report(&order, OrderStatus::Canceled, 0)));
+        assert_eq!(
+            cache
+                .borrow()
+                .order(&order.client_order_id())
+                .unwrap()
+                .status(),
+            OrderStatus::Canceled
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_order_status_report_requires_fills(audusd_sim: CurrencyPair) {
+        let (mut engine, _cache, order) = engine_with_submitted_order(&audusd_sim);
+        let report = status_report(&order, OrderStatus::PartiallyFilled, 50_000);
+
+        assert!(!engine.reconcile_report(&report));
+    }
+
+    #[rstest]
+    fn test_reconcile_fill_report_fills_order_once(audusd_sim: CurrencyPair) {
+        let (mut engine, cache, order) = engine_with_submitted_order(&audusd_sim);
+        let report = fill_report(&order, "T-1", 100_000);
+
+        assert!(engine.reconcile_report(&report));
+        assert!(engine.reconcile_report(&report)); // Duplicate trade is ignored
+
+        let cache = cache.borrow();
+        let cached = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(cached.status(), OrderStatus::Filled);
+        assert_eq!(cached.filled_qty(), Quantity::from(100_000));
+        assert_eq!(cached.trade_ids().len(), 1);
+    }
+
+    #[rstest]
+    fn test_registered_handlers_route_events_and_reports(audusd_sim: CurrencyPair) {
+        let (engine, cache, order) = engine_with_submitted_order(&audusd_sim);
+        let engine = Rc::new(RefCell::new(engine));
+        ExecutionEngine::register_message_handlers(&engine);
+
+        let accepted = accepted_event(
+            &order,
+            VenueOrderId::from("V-1"),
+            account_id(),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        nautilus_common::msgbus::send(MessagingSwitchboard::exec_engine_process(), &accepted);
+        assert_eq!(
+            cache
+                .borrow()
+                .order(&order.client_order_id())
+                .unwrap()
+                .status(),
+            OrderStatus::Accepted
+        );
+
+        let report = fill_report(&order, "T-1", 100_000);
+        nautilus_common::msgbus::send(
+            MessagingSwitchboard::exec_engine_reconcile_report(),
+            &report,
+        );
+        assert_eq!(
+            cache
+                .borrow()
+                .order(&order.client_order_id())
+                .unwrap()
+                .status(),
+            OrderStatus::Filled
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_applies_fills_before_status(audusd_sim: CurrencyPair) {
+        let (mut engine, cache, order) = engine_with_submitted_order(&audusd_sim);
+        let mut mass_status = ExecutionMassStatus::new(
+            ClientId::from("SIM"),
+            account_id(),
+            audusd_sim.id.venue,
+            UnixNanos::from(4),
+            None,
+        );
+        mass_status.add_order_reports(vec![status_report(
+            &order,
+            OrderStatus::PartiallyFilled,
+            50_000,
+        )]);
+        mass_status.add_fill_reports(vec![
+            fill_report(&order, "T-2", 20_000),
+            fill_report(&order, "T-1", 30_000),
+        ]);
+
+        assert!(engine.reconcile_mass_status(&mass_status));
+
+        let cache = cache.borrow();
+        let cached = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(cached.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(cached.filled_qty(), Quantity::from(50_000));
+    }
 }
@@ -480,6 +480,14 @@ impl OrderMatchingEngine {
         {
             self.market_status = MarketStatus::Open;
         }
+        // Check if market is paused or suspended and trading resumes
+        if matches!(
+            self.market_status,
+            MarketStatus::Paused | MarketStatus::Suspended
+        ) && action == MarketStatusAction::Trading
+        {
+            self.market_status = MarketStatus::Open;
+        }
         // Check if market is open and market pauses
         if self.market_status == MarketStatus::Open && action == MarketStatusAction::Pause {
             self.market_status = MarketStatus::Paused;
@@ -488,8 +496,8 @@ impl OrderMatchingEngine {
         if self.market_status == MarketStatus::Open && action == MarketStatusAction::Suspend {
             self.market_status = MarketStatus::Suspended;
         }
-        // Check if market is open and we halt or close
-        if self.market_status == MarketStatus::Open
+        // Check if market is not closed and we halt or close
+        if self.market_status != MarketStatus::Closed
             && (action == MarketStatusAction::Halt || action == MarketStatusAction::Close)
         {
             self.market_status = MarketStatus::Closed;
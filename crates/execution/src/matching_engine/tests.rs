@@ -31,8 +31,9 @@ use nautilus_core::{UUID4, UnixNanos};
 use nautilus_model::{
     data::{BookOrder, TradeTick, stubs::OrderBookDeltaTestBuilder},
     enums::{
-        AccountType, AggressorSide, BookAction, BookType, ContingencyType, LiquiditySide, OmsType,
-        OrderSide, OrderType, TimeInForce, TrailingOffsetType,
+        AccountType, AggressorSide, BookAction, BookType, ContingencyType, LiquiditySide,
+        MarketStatus, MarketStatusAction, OmsType, OrderSide, OrderType, TimeInForce,
+        TrailingOffsetType,
     },
     events::{
         OrderEventAny, OrderEventType, OrderFilled, OrderRejected,
@@ -2683,3 +2684,30 @@ fn test_updating_of_contingent_orders(
     assert_eq!(updated.client_order_id, client_order_id_contingent);
     assert_eq!(updated.quantity, Quantity::from("2.000"));
 }
+
+#[rstest]
+#[case(MarketStatusAction::Pause, MarketStatus::Paused)]
+#[case(MarketStatusAction::Suspend, MarketStatus::Suspended)]
+fn test_process_status_resumes_trading(
+    instrument_eth_usdt: InstrumentAny,
+    #[case] interruption: MarketStatusAction,
+    #[case] interrupted_status: MarketStatus,
+) {
+    let mut engine = get_order_matching_engine(instrument_eth_usdt, None, None, None, None);
+
+    engine.process_status(interruption);
+    assert_eq!(engine.market_status, interrupted_status);
+
+    engine.process_status(MarketStatusAction::Trading);
+    assert_eq!(engine.market_status, MarketStatus::Open);
+}
+
+#[rstest]
+fn test_process_status_closes_paused_market(instrument_eth_usdt: InstrumentAny) {
+    let mut engine = get_order_matching_engine(instrument_eth_usdt, None, None, None, None);
+
+    engine.process_status(MarketStatusAction::Pause);
+    engine.process_status(MarketStatusAction::Close);
+
+    assert_eq!(engine.market_status, MarketStatus::Closed);
+}
@@ -20,7 +20,12 @@ use nautilus_model::{
     enums::PriceType,
 };
 
-use crate::indicator::{Indicator, MovingAverage};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    indicator::{Indicator, MovingAverage},
+    state::{check_state_period, decode_state, encode_state},
+};
 
 #[repr(C)]
 #[derive(Debug)]
@@ -38,6 +43,15 @@ pub struct ExponentialMovingAverage {
     has_inputs: bool,
 }
 
+#[derive(Serialize, Deserialize)]
+struct ExponentialMovingAverageState {
+    period: usize,
+    value: f64,
+    count: usize,
+    has_inputs: bool,
+    initialized: bool,
+}
+
 impl Display for ExponentialMovingAverage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}({})", self.name(), self.period)
@@ -75,6 +89,27 @@ impl Indicator for ExponentialMovingAverage {
         self.has_inputs = false;
         self.initialized = false;
     }
+
+    fn snapshot_state(&self) -> anyhow::Result<Vec<u8>> {
+        encode_state(&ExponentialMovingAverageState {
+            period: self.period,
+            value: self.value,
+            count: self.count,
+            has_inputs: self.has_inputs,
+            initialized: self.initialized,
+        })
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> anyhow::Result<()> {
+        let state: ExponentialMovingAverageState = decode_state(&self.name(), state)?;
+        check_state_period(&self.name(), self.period, state.period)?;
+
+        self.value = state.value;
+        self.count = state.count;
+        self.has_inputs = state.has_inputs;
+        self.initialized = state.initialized;
+        Ok(())
+    }
 }
 
 impl ExponentialMovingAverage {
@@ -320,4 +355,30 @@ mod tests {
             "Underflow: EMA value collapsed to zero for sub-normal inputs"
         );
     }
+
+    #[rstest]
+    fn test_restore_state_resumes_identically() {
+        let mut ema = ExponentialMovingAverage::new(3, None);
+        for i in 0..5 {
+            ema.update_raw(f64::from(i));
+        }
+        let state = ema.snapshot_state().unwrap();
+
+        let mut restored = ExponentialMovingAverage::new(3, None);
+        restored.restore_state(&state).unwrap();
+        ema.update_raw(10.0);
+        restored.update_raw(10.0);
+
+        assert!(restored.initialized());
+        assert!(restored.has_inputs());
+        assert_eq!(restored.count(), ema.count());
+        assert_eq!(restored.value(), ema.value());
+    }
+
+    #[rstest]
+    fn test_restore_state_with_invalid_bytes_errors() {
+        let mut ema = ExponentialMovingAverage::new(3, None);
+
+        assert!(ema.restore_state(b"not-a-state").is_err());
+    }
 }
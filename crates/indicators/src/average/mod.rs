@@ -26,7 +26,10 @@ pub mod vidya;
 pub mod vwap;
 pub mod wma;
 
+use std::str::FromStr;
+
 use nautilus_model::enums::PriceType;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use strum::{AsRefStr, Display, EnumIter, EnumString, FromRepr};
 
 use crate::{
@@ -67,6 +70,8 @@ pub enum MovingAverageType {
     Hull,
 }
 
+nautilus_model::enum_strum_serde!(MovingAverageType);
+
 #[derive(Debug)]
 pub struct MovingAverageFactory;
 
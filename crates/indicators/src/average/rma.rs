@@ -20,7 +20,12 @@ use nautilus_model::{
     enums::PriceType,
 };
 
-use crate::indicator::{Indicator, MovingAverage};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    indicator::{Indicator, MovingAverage},
+    state::{check_state_period, decode_state, encode_state},
+};
 
 #[repr(C)]
 #[derive(Debug)]
@@ -38,6 +43,15 @@ pub struct WilderMovingAverage {
     has_inputs: bool,
 }
 
+#[derive(Serialize, Deserialize)]
+struct WilderMovingAverageState {
+    period: usize,
+    value: f64,
+    count: usize,
+    has_inputs: bool,
+    initialized: bool,
+}
+
 impl Display for WilderMovingAverage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}({})", self.name(), self.period)
@@ -74,6 +88,27 @@ impl Indicator for WilderMovingAverage {
         self.has_inputs = false;
         self.initialized = false;
     }
+
+    fn snapshot_state(&self) -> anyhow::Result<Vec<u8>> {
+        encode_state(&WilderMovingAverageState {
+            period: self.period,
+            value: self.value,
+            count: self.count,
+            has_inputs: self.has_inputs,
+            initialized: self.initialized,
+        })
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> anyhow::Result<()> {
+        let state: WilderMovingAverageState = decode_state(&self.name(), state)?;
+        check_state_period(&self.name(), self.period, state.period)?;
+
+        self.value = state.value;
+        self.count = state.count;
+        self.has_inputs = state.has_inputs;
+        self.initialized = state.initialized;
+        Ok(())
+    }
 }
 
 impl WilderMovingAverage {
@@ -336,4 +371,22 @@ mod tests {
         assert!(rma.has_inputs());
         assert_eq!(rma.count(), 1);
     }
+
+    #[rstest]
+    fn test_restore_state_resumes_identically() {
+        let mut rma = WilderMovingAverage::new(3, None);
+        for i in 0..5 {
+            rma.update_raw(f64::from(i));
+        }
+        let state = rma.snapshot_state().unwrap();
+
+        let mut restored = WilderMovingAverage::new(3, None);
+        restored.restore_state(&state).unwrap();
+        rma.update_raw(10.0);
+        restored.update_raw(10.0);
+
+        assert!(restored.initialized());
+        assert_eq!(restored.count(), rma.count());
+        assert_eq!(restored.value(), rma.value());
+    }
 }
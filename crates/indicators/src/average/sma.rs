@@ -21,7 +21,12 @@ use nautilus_model::{
     enums::PriceType,
 };
 
-use crate::indicator::{Indicator, MovingAverage};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    indicator::{Indicator, MovingAverage},
+    state::{check_state_period, decode_state, encode_state},
+};
 
 const MAX_PERIOD: usize = 1_024;
 
@@ -41,6 +46,16 @@ pub struct SimpleMovingAverage {
     pub initialized: bool,
 }
 
+#[derive(Serialize, Deserialize)]
+struct SimpleMovingAverageState {
+    period: usize,
+    value: f64,
+    sum: f64,
+    count: usize,
+    buf: Vec<f64>,
+    initialized: bool,
+}
+
 impl Display for SimpleMovingAverage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}({})", self.name(), self.period)
@@ -79,6 +94,32 @@ impl Indicator for SimpleMovingAverage {
         self.buf.clear();
         self.initialized = false;
     }
+
+    fn snapshot_state(&self) -> anyhow::Result<Vec<u8>> {
+        encode_state(&SimpleMovingAverageState {
+            period: self.period,
+            value: self.value,
+            sum: self.sum,
+            count: self.count,
+            buf: self.buf.iter().copied().collect(),
+            initialized: self.initialized,
+        })
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> anyhow::Result<()> {
+        let state: SimpleMovingAverageState = decode_state(&self.name(), state)?;
+        check_state_period(&self.name(), self.period, state.period)?;
+
+        self.buf.clear();
+        for price in state.buf {
+            let _ = self.buf.push_back(price);
+        }
+        self.value = state.value;
+        self.sum = state.sum;
+        self.count = state.count;
+        self.initialized = state.initialized;
+        Ok(())
+    }
 }
 
 impl MovingAverage for SimpleMovingAverage {
@@ -465,4 +506,32 @@ mod tests {
             );
         }
     }
+
+    #[rstest]
+    fn test_restore_state_resumes_identically() {
+        let mut sma = SimpleMovingAverage::new(5, None);
+        for i in 0..8 {
+            sma.update_raw(f64::from(i));
+        }
+        let state = sma.snapshot_state().unwrap();
+
+        let mut restored = SimpleMovingAverage::new(5, None);
+        restored.restore_state(&state).unwrap();
+        sma.update_raw(100.0);
+        restored.update_raw(100.0);
+
+        assert!(restored.initialized());
+        assert_eq!(restored.count(), sma.count());
+        assert_eq!(restored.value(), sma.value());
+    }
+
+    #[rstest]
+    fn test_restore_state_with_different_period_errors() {
+        let sma = SimpleMovingAverage::new(5, None);
+        let state = sma.snapshot_state().unwrap();
+
+        let mut other = SimpleMovingAverage::new(10, None);
+
+        assert!(other.restore_state(&state).is_err());
+    }
 }
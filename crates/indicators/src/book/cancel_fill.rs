@@ -0,0 +1,224 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{
+    enums::OrderSide,
+    orderbook::{BookLevelStats, OrderBook},
+};
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the ratio of canceled size to filled size at the top of
+/// book for an L3 (MBO) order book.
+///
+/// High ratios indicate liquidity which is frequently pulled before it trades.
+#[repr(C)]
+#[derive(Debug, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct BookCancelFillRatio {
+    pub bid_value: f64,
+    pub ask_value: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for BookCancelFillRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}()", self.name())
+    }
+}
+
+impl Indicator for BookCancelFillRatio {
+    fn name(&self) -> String {
+        stringify!(BookCancelFillRatio).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        self.update(book);
+    }
+
+    fn reset(&mut self) {
+        self.bid_value = 0.0;
+        self.ask_value = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl BookCancelFillRatio {
+    /// Creates a new [`BookCancelFillRatio`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bid_value: 0.0,
+            ask_value: 0.0,
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update(&mut self, book: &OrderBook) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let bid_stats = book
+            .best_bid_price()
+            .and_then(|price| book.level_stats(OrderSide::Buy, price));
+        let ask_stats = book
+            .best_ask_price()
+            .and_then(|price| book.level_stats(OrderSide::Sell, price));
+
+        if let Some(ratio) = bid_stats
+            .as_ref()
+            .and_then(BookLevelStats::cancel_fill_ratio)
+        {
+            self.bid_value = ratio;
+        }
+        if let Some(ratio) = ask_stats
+            .as_ref()
+            .and_then(BookLevelStats::cancel_fill_ratio)
+        {
+            self.ask_value = ratio;
+        }
+
+        let (canceled, filled) = [bid_stats, ask_stats].into_iter().flatten().fold(
+            (0, 0),
+            |(canceled, filled), stats| {
+                (
+                    canceled + stats.cancel_size_raw,
+                    filled + stats.fill_size_raw,
+                )
+            },
+        );
+
+        if filled > 0 {
+            self.value = canceled as f64 / filled as f64;
+            self.initialized = true;
+        }
+        // No fills at the top of book yet
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::BookOrder,
+        enums::BookType,
+        identifiers::InstrumentId,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn book_order(side: OrderSide, price: &str, size: u64, order_id: u64) -> BookOrder {
+        BookOrder::new(side, Price::from(price), Quantity::from(size), order_id)
+    }
+
+    fn stub_book() -> OrderBook {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        book.add(book_order(OrderSide::Buy, "100.00", 100, 1), 0, 1, 1.into());
+        book.add(book_order(OrderSide::Buy, "100.00", 100, 2), 0, 2, 2.into());
+        book.add(
+            book_order(OrderSide::Sell, "101.00", 100, 3),
+            0,
+            3,
+            3.into(),
+        );
+        book.add(
+            book_order(OrderSide::Sell, "101.00", 100, 4),
+            0,
+            4,
+            4.into(),
+        );
+        book
+    }
+
+    #[rstest]
+    fn test_initialized() {
+        let indicator = BookCancelFillRatio::new();
+        let display_str = format!("{indicator}");
+        assert_eq!(display_str, "BookCancelFillRatio()");
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.count, 0);
+        assert!(!indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_no_fills_not_initialized() {
+        let mut indicator = BookCancelFillRatio::new();
+        let book = stub_book();
+        indicator.handle_book(&book);
+
+        assert_eq!(indicator.count, 1);
+        assert!(indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_book() {
+        let mut indicator = BookCancelFillRatio::new();
+        let mut book = stub_book();
+
+        // Bid: fill 50, cancel 100
+        book.update(book_order(OrderSide::Buy, "100.00", 50, 1), 0, 5, 5.into());
+        book.delete(book_order(OrderSide::Buy, "100.00", 100, 2), 0, 6, 6.into());
+        // Ask: fill 100, no cancels
+        book.update(book_order(OrderSide::Sell, "101.00", 0, 3), 0, 7, 7.into());
+        indicator.handle_book(&book);
+
+        assert_eq!(indicator.bid_value, 2.0);
+        assert_eq!(indicator.ask_value, 0.0);
+        assert!((indicator.value - 100.0 / 150.0).abs() < 1e-12);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut indicator = BookCancelFillRatio::new();
+        let mut book = stub_book();
+        book.update(book_order(OrderSide::Buy, "100.00", 50, 1), 0, 5, 5.into());
+        indicator.handle_book(&book);
+        indicator.reset();
+
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+        assert!(!indicator.initialized);
+        assert!(!indicator.has_inputs);
+    }
+}
@@ -0,0 +1,207 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{data::OrderBookDepth10, orderbook::OrderBook};
+
+use crate::{
+    book::{levels_from_book, levels_from_depth},
+    indicator::Indicator,
+};
+
+/// An indicator which calculates the order book imbalance over multiple levels.
+///
+/// The value is `(bid_size - ask_size) / (bid_size + ask_size)` summed over the top
+/// `depth` levels of each side, and is bounded within [-1, 1].
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct BookDepthImbalance {
+    pub depth: usize,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for BookDepthImbalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.depth)
+    }
+}
+
+impl Indicator for BookDepthImbalance {
+    fn name(&self) -> String {
+        stringify!(BookDepthImbalance).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = levels_from_book(book, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = levels_from_depth(depth, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl BookDepthImbalance {
+    /// Creates a new [`BookDepthImbalance`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `depth` is not positive (> 0).
+    #[must_use]
+    pub fn new(depth: usize) -> Self {
+        assert!(depth > 0, "BookDepthImbalance: depth must be > 0");
+
+        Self {
+            depth,
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with `(price, size)` levels for each side, best level first.
+    pub fn update(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let bid_size: f64 = bids.iter().take(self.depth).map(|(_, size)| size).sum();
+        let ask_size: f64 = asks.iter().take(self.depth).map(|(_, size)| size).sum();
+        let total = bid_size + ask_size;
+
+        if bid_size > 0.0 && ask_size > 0.0 {
+            self.value = (bid_size - ask_size) / total;
+            self.initialized = true;
+        }
+        // No market yet
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::stubs::stub_depth10,
+        identifiers::InstrumentId,
+        stubs::{stub_order_book_mbp, stub_order_book_mbp_appl_xnas},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let indicator = BookDepthImbalance::new(5);
+        assert_eq!(format!("{indicator}"), "BookDepthImbalance(5)");
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.count, 0);
+        assert!(!indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "depth must be > 0")]
+    fn test_new_with_zero_depth_panics() {
+        let _ = BookDepthImbalance::new(0);
+    }
+
+    #[rstest]
+    fn test_handle_book_balanced() {
+        let mut indicator = BookDepthImbalance::new(5);
+        let book = stub_order_book_mbp_appl_xnas();
+        indicator.handle_book(&book);
+
+        assert_eq!(indicator.count, 1);
+        assert_eq!(indicator.value, 0.0);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_book_with_bid_imbalance() {
+        let mut indicator = BookDepthImbalance::new(2);
+        let book = stub_order_book_mbp(
+            InstrumentId::from("AAPL.XNAS"),
+            101.0,
+            100.0,
+            100.0,
+            300.0, // <-- Larger bid side
+            2,
+            0.01,
+            0,
+            100.0,
+            10,
+        );
+        indicator.handle_book(&book);
+
+        // Bid levels 300 + 400, ask levels 100 + 200
+        assert!((indicator.value - 0.4).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_depth(stub_depth10: OrderBookDepth10) {
+        let mut indicator = BookDepthImbalance::new(3);
+        indicator.handle_depth(&stub_depth10);
+
+        assert_eq!(indicator.value, 0.0);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_update_one_sided_not_initialized() {
+        let mut indicator = BookDepthImbalance::new(3);
+        indicator.update(&[(100.0, 10.0)], &[]);
+
+        assert!(indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut indicator = BookDepthImbalance::new(3);
+        indicator.update(&[(100.0, 30.0)], &[(101.0, 10.0)]);
+        indicator.reset();
+
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+        assert!(!indicator.initialized);
+        assert!(!indicator.has_inputs);
+    }
+}
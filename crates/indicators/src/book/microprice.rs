@@ -0,0 +1,186 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{
+    data::{OrderBookDepth10, QuoteTick},
+    orderbook::OrderBook,
+};
+
+use crate::{
+    book::{levels_from_book, levels_from_depth},
+    indicator::Indicator,
+};
+
+/// An indicator which calculates the micro-price from the top of book.
+///
+/// The micro-price is the mid-price weighted by the size imbalance of the best bid and
+/// ask: `(bid_price * ask_size + ask_price * bid_size) / (bid_size + ask_size)`.
+#[repr(C)]
+#[derive(Debug, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct MicroPrice {
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for MicroPrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}()", self.name())
+    }
+}
+
+impl Indicator for MicroPrice {
+    fn name(&self) -> String {
+        stringify!(MicroPrice).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = levels_from_book(book, 1);
+        self.update(bids.first().copied(), asks.first().copied());
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = levels_from_depth(depth, 1);
+        self.update(bids.first().copied(), asks.first().copied());
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update(
+            Some((quote.bid_price.as_f64(), quote.bid_size.as_f64())),
+            Some((quote.ask_price.as_f64(), quote.ask_size.as_f64())),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MicroPrice {
+    /// Creates a new [`MicroPrice`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the best bid and ask as `(price, size)` pairs.
+    pub fn update(&mut self, best_bid: Option<(f64, f64)>, best_ask: Option<(f64, f64)>) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        if let (Some((bid_price, bid_size)), Some((ask_price, ask_size))) = (best_bid, best_ask) {
+            let total = bid_size + ask_size;
+            if total > 0.0 {
+                self.value = bid_price.mul_add(ask_size, ask_price * bid_size) / total;
+                self.initialized = true;
+            }
+        }
+        // No market yet
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::stubs::stub_order_book_mbp_appl_xnas;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::stub_quote;
+
+    #[rstest]
+    fn test_initialized() {
+        let indicator = MicroPrice::new();
+        assert_eq!(format!("{indicator}"), "MicroPrice()");
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.count, 0);
+        assert!(!indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_book_balanced_equals_mid() {
+        let mut indicator = MicroPrice::new();
+        let book = stub_order_book_mbp_appl_xnas();
+        indicator.handle_book(&book);
+
+        assert_eq!(indicator.value, 100.5);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_quote(stub_quote: QuoteTick) {
+        let mut indicator = MicroPrice::new();
+        indicator.handle_quote(&stub_quote);
+
+        assert_eq!(indicator.value, 1501.0);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_update_skews_toward_thin_side() {
+        let mut indicator = MicroPrice::new();
+        indicator.update(Some((100.0, 300.0)), Some((101.0, 100.0)));
+
+        // Larger bid size pushes the micro-price toward the ask
+        assert_eq!(indicator.value, 100.75);
+    }
+
+    #[rstest]
+    fn test_update_one_sided_not_initialized() {
+        let mut indicator = MicroPrice::new();
+        indicator.update(Some((100.0, 300.0)), None);
+
+        assert!(indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut indicator = MicroPrice::new();
+        indicator.update(Some((100.0, 300.0)), Some((101.0, 100.0)));
+        indicator.reset();
+
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+        assert!(!indicator.initialized);
+        assert!(!indicator.has_inputs);
+    }
+}
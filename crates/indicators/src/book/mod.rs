@@ -15,4 +15,55 @@
 
 //! Order book specific indicators.
 
+use nautilus_model::{data::OrderBookDepth10, orderbook::OrderBook};
+
+pub mod cancel_fill;
+pub mod depth_imbalance;
 pub mod imbalance;
+pub mod microprice;
+pub mod ofi;
+pub mod pressure;
+pub mod queue_age;
+pub mod slope;
+pub mod vpin;
+pub mod weighted_mid;
+
+/// Price and size pairs for the top levels of one side of a book, best level first.
+pub(crate) type BookSideLevels = Vec<(f64, f64)>;
+
+/// Returns the top `depth` price levels as `(price, size)` pairs for each side of `book`.
+pub(crate) fn levels_from_book(book: &OrderBook, depth: usize) -> (BookSideLevels, BookSideLevels) {
+    let bids = book
+        .bids(Some(depth))
+        .map(|level| (level.price.value.as_f64(), level.size()))
+        .collect();
+    let asks = book
+        .asks(Some(depth))
+        .map(|level| (level.price.value.as_f64(), level.size()))
+        .collect();
+    (bids, asks)
+}
+
+/// Returns the top `depth` price levels as `(price, size)` pairs for each side of `depth10`.
+///
+/// Empty levels (zero size) are excluded.
+pub(crate) fn levels_from_depth(
+    depth10: &OrderBookDepth10,
+    depth: usize,
+) -> (BookSideLevels, BookSideLevels) {
+    let bids = depth10
+        .bids
+        .iter()
+        .filter(|order| order.size.is_positive())
+        .take(depth)
+        .map(|order| (order.price.as_f64(), order.size.as_f64()))
+        .collect();
+    let asks = depth10
+        .asks
+        .iter()
+        .filter(|order| order.size.is_positive())
+        .take(depth)
+        .map(|order| (order.price.as_f64(), order.size.as_f64()))
+        .collect();
+    (bids, asks)
+}
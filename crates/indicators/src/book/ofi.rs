@@ -0,0 +1,304 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use arraydeque::{ArrayDeque, Wrapping};
+use nautilus_model::{
+    data::{OrderBookDeltas, OrderBookDepth10, QuoteTick},
+    enums::BookType,
+    orderbook::OrderBook,
+};
+
+use crate::{
+    book::{levels_from_book, levels_from_depth},
+    indicator::Indicator,
+};
+
+const MAX_WINDOW: usize = 1_024;
+
+/// An indicator which calculates the order flow imbalance (OFI) of the top of book.
+///
+/// Each top of book update contributes the net change in best bid and ask liquidity,
+/// following Cont, Kukanov and Stoikov (2014). The value is the sum of contributions over
+/// the last `window` updates.
+///
+/// When handling deltas, the indicator maintains an internal order book of `book_type`.
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct OrderFlowImbalance {
+    pub window: usize,
+    pub book_type: BookType,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    sum: f64,
+    buf: ArrayDeque<f64, MAX_WINDOW, Wrapping>,
+    last_bid: Option<(f64, f64)>,
+    last_ask: Option<(f64, f64)>,
+    book: Option<OrderBook>,
+}
+
+impl Display for OrderFlowImbalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.window)
+    }
+}
+
+impl Indicator for OrderFlowImbalance {
+    fn name(&self) -> String {
+        stringify!(OrderFlowImbalance).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_deltas(&mut self, deltas: &OrderBookDeltas) {
+        let book = self
+            .book
+            .get_or_insert_with(|| OrderBook::new(deltas.instrument_id, self.book_type));
+        book.apply_deltas(deltas);
+
+        let (bids, asks) = levels_from_book(book, 1);
+        self.update(bids.first().copied(), asks.first().copied());
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = levels_from_depth(depth, 1);
+        self.update(bids.first().copied(), asks.first().copied());
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = levels_from_book(book, 1);
+        self.update(bids.first().copied(), asks.first().copied());
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update(
+            Some((quote.bid_price.as_f64(), quote.bid_size.as_f64())),
+            Some((quote.ask_price.as_f64(), quote.ask_size.as_f64())),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.sum = 0.0;
+        self.buf.clear();
+        self.last_bid = None;
+        self.last_ask = None;
+        self.book = None;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl OrderFlowImbalance {
+    /// Creates a new [`OrderFlowImbalance`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not positive (> 0) or exceeds the maximum window.
+    #[must_use]
+    pub fn new(window: usize, book_type: Option<BookType>) -> Self {
+        assert!(window > 0, "OrderFlowImbalance: window must be > 0");
+        assert!(
+            window <= MAX_WINDOW,
+            "OrderFlowImbalance: window {window} exceeds MAX_WINDOW ({MAX_WINDOW})"
+        );
+
+        Self {
+            window,
+            book_type: book_type.unwrap_or(BookType::L2_MBP),
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+            sum: 0.0,
+            buf: ArrayDeque::new(),
+            last_bid: None,
+            last_ask: None,
+            book: None,
+        }
+    }
+
+    /// Updates the indicator with the best bid and ask as `(price, size)` pairs.
+    pub fn update(&mut self, best_bid: Option<(f64, f64)>, best_ask: Option<(f64, f64)>) {
+        self.has_inputs = true;
+
+        let (Some(bid), Some(ask)) = (best_bid, best_ask) else {
+            return; // No market yet
+        };
+
+        if let (Some(last_bid), Some(last_ask)) = (self.last_bid, self.last_ask) {
+            let contribution = bid_flow(last_bid, bid) - ask_flow(last_ask, ask);
+            self.push(contribution);
+        }
+
+        self.last_bid = Some(bid);
+        self.last_ask = Some(ask);
+    }
+
+    fn push(&mut self, contribution: f64) {
+        if self.buf.len() == self.window {
+            if let Some(oldest) = self.buf.pop_front() {
+                self.sum -= oldest;
+            }
+        }
+
+        let _ = self.buf.push_back(contribution);
+        self.sum += contribution;
+        self.count += 1;
+
+        self.value = self.sum;
+        self.initialized = self.buf.len() >= self.window;
+    }
+}
+
+fn bid_flow(last: (f64, f64), current: (f64, f64)) -> f64 {
+    let (last_price, last_size) = last;
+    let (price, size) = current;
+
+    let mut flow = 0.0;
+    if price >= last_price {
+        flow += size;
+    }
+    if price <= last_price {
+        flow -= last_size;
+    }
+    flow
+}
+
+fn ask_flow(last: (f64, f64), current: (f64, f64)) -> f64 {
+    let (last_price, last_size) = last;
+    let (price, size) = current;
+
+    let mut flow = 0.0;
+    if price <= last_price {
+        flow += size;
+    }
+    if price >= last_price {
+        flow -= last_size;
+    }
+    flow
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::stub_deltas;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let indicator = OrderFlowImbalance::new(3, None);
+        assert_eq!(format!("{indicator}"), "OrderFlowImbalance(3)");
+        assert_eq!(indicator.book_type, BookType::L2_MBP);
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.count, 0);
+        assert!(!indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "window must be > 0")]
+    fn test_new_with_zero_window_panics() {
+        let _ = OrderFlowImbalance::new(0, None);
+    }
+
+    #[rstest]
+    fn test_first_update_has_no_contribution() {
+        let mut indicator = OrderFlowImbalance::new(3, None);
+        indicator.update(Some((100.0, 10.0)), Some((101.0, 10.0)));
+
+        assert!(indicator.has_inputs);
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_size_changes_at_unchanged_prices() {
+        let mut indicator = OrderFlowImbalance::new(3, None);
+        indicator.update(Some((100.0, 10.0)), Some((101.0, 10.0)));
+        indicator.update(Some((100.0, 15.0)), Some((101.0, 8.0)));
+
+        // Bid +5, ask -2 => +7
+        assert_eq!(indicator.value, 7.0);
+    }
+
+    #[rstest]
+    fn test_price_improvements() {
+        let mut indicator = OrderFlowImbalance::new(3, None);
+        indicator.update(Some((100.0, 10.0)), Some((101.0, 10.0)));
+        // Bid ticks up (new bid size counts), ask ticks up (old ask size removed)
+        indicator.update(Some((100.5, 4.0)), Some((101.5, 6.0)));
+
+        assert_eq!(indicator.value, 14.0);
+    }
+
+    #[rstest]
+    fn test_rolling_window() {
+        let mut indicator = OrderFlowImbalance::new(2, None);
+        indicator.update(Some((100.0, 10.0)), Some((101.0, 10.0)));
+        indicator.update(Some((100.0, 11.0)), Some((101.0, 10.0))); // +1
+        indicator.update(Some((100.0, 13.0)), Some((101.0, 10.0))); // +2
+        assert!(indicator.initialized);
+        assert_eq!(indicator.value, 3.0);
+
+        indicator.update(Some((100.0, 13.0)), Some((101.0, 14.0))); // -4
+        assert_eq!(indicator.value, -2.0);
+        assert_eq!(indicator.count, 3);
+    }
+
+    #[rstest]
+    fn test_handle_deltas(stub_deltas: OrderBookDeltas) {
+        let mut indicator = OrderFlowImbalance::new(2, None);
+        indicator.handle_deltas(&stub_deltas);
+        indicator.handle_deltas(&stub_deltas);
+
+        // Snapshot replayed with the same top of book contributes zero
+        assert_eq!(indicator.count, 1);
+        assert_eq!(indicator.value, 0.0);
+        assert!(indicator.book.is_some());
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut indicator = OrderFlowImbalance::new(2, None);
+        indicator.update(Some((100.0, 10.0)), Some((101.0, 10.0)));
+        indicator.update(Some((100.0, 15.0)), Some((101.0, 10.0)));
+        indicator.reset();
+
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+        assert!(indicator.last_bid.is_none());
+        assert!(indicator.book.is_none());
+        assert!(!indicator.initialized);
+        assert!(!indicator.has_inputs);
+    }
+}
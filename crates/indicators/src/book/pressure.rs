@@ -0,0 +1,219 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{data::OrderBookDepth10, orderbook::OrderBook};
+
+use crate::{
+    book::{levels_from_book, levels_from_depth},
+    indicator::Indicator,
+};
+
+/// An indicator which calculates the order book pressure over multiple levels.
+///
+/// Each level's size is weighted by the inverse of its relative distance from the
+/// mid-price, so liquidity close to the touch dominates. The value is
+/// `(bid_pressure - ask_pressure) / (bid_pressure + ask_pressure)`, bounded within [-1, 1].
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct BookPressure {
+    pub depth: usize,
+    pub bid_value: f64,
+    pub ask_value: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for BookPressure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.depth)
+    }
+}
+
+impl Indicator for BookPressure {
+    fn name(&self) -> String {
+        stringify!(BookPressure).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = levels_from_book(book, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = levels_from_depth(depth, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn reset(&mut self) {
+        self.bid_value = 0.0;
+        self.ask_value = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl BookPressure {
+    /// Creates a new [`BookPressure`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `depth` is not positive (> 0).
+    #[must_use]
+    pub fn new(depth: usize) -> Self {
+        assert!(depth > 0, "BookPressure: depth must be > 0");
+
+        Self {
+            depth,
+            bid_value: 0.0,
+            ask_value: 0.0,
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with `(price, size)` levels for each side, best level first.
+    pub fn update(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let (Some((best_bid, _)), Some((best_ask, _))) = (bids.first(), asks.first()) else {
+            return; // No market yet
+        };
+
+        if best_ask <= best_bid {
+            return; // Locked or crossed book, distances from mid are undefined
+        }
+
+        let mid = (best_bid + best_ask) / 2.0;
+        let bid_pressure = self.side_pressure(bids, mid);
+        let ask_pressure = self.side_pressure(asks, mid);
+        let total = bid_pressure + ask_pressure;
+
+        if total > 0.0 {
+            self.bid_value = bid_pressure;
+            self.ask_value = ask_pressure;
+            self.value = (bid_pressure - ask_pressure) / total;
+            self.initialized = true;
+        }
+    }
+
+    fn side_pressure(&self, levels: &[(f64, f64)], mid: f64) -> f64 {
+        levels
+            .iter()
+            .take(self.depth)
+            .map(|(price, size)| size * mid / (price - mid).abs())
+            .sum()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{data::stubs::stub_depth10, stubs::stub_order_book_mbp_appl_xnas};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let indicator = BookPressure::new(5);
+        assert_eq!(format!("{indicator}"), "BookPressure(5)");
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.count, 0);
+        assert!(!indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "depth must be > 0")]
+    fn test_new_with_zero_depth_panics() {
+        let _ = BookPressure::new(0);
+    }
+
+    #[rstest]
+    fn test_handle_book_symmetric() {
+        let mut indicator = BookPressure::new(5);
+        let book = stub_order_book_mbp_appl_xnas();
+        indicator.handle_book(&book);
+
+        assert!(indicator.value.abs() < 1e-9);
+        assert!(indicator.bid_value > 0.0);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_depth_symmetric(stub_depth10: OrderBookDepth10) {
+        let mut indicator = BookPressure::new(10);
+        indicator.handle_depth(&stub_depth10);
+
+        assert!(indicator.value.abs() < 1e-9);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_near_liquidity_dominates() {
+        let mut indicator = BookPressure::new(2);
+        // Mid 100.0: bid 10 at distance 1, ask 10 at distance 1 and 30 at distance 3
+        indicator.update(&[(99.0, 10.0)], &[(101.0, 10.0), (103.0, 30.0)]);
+
+        assert_eq!(indicator.bid_value, 1_000.0);
+        assert_eq!(indicator.ask_value, 2_000.0);
+        assert!((indicator.value - (-1.0 / 3.0)).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_crossed_book_not_initialized() {
+        let mut indicator = BookPressure::new(2);
+        indicator.update(&[(101.0, 10.0)], &[(100.0, 10.0)]);
+
+        assert!(indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut indicator = BookPressure::new(2);
+        indicator.update(&[(99.0, 10.0)], &[(101.0, 10.0)]);
+        indicator.reset();
+
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.bid_value, 0.0);
+        assert!(!indicator.initialized);
+        assert!(!indicator.has_inputs);
+    }
+}
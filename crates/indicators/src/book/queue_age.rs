@@ -0,0 +1,184 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{enums::OrderSide, orderbook::OrderBook};
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the mean age (nanoseconds) of resting orders at the
+/// top of book for an L3 (MBO) order book.
+///
+/// Order ages are measured against the timestamp of the last event applied to the book.
+#[repr(C)]
+#[derive(Debug, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct BookQueueAge {
+    pub bid_value: f64,
+    pub ask_value: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for BookQueueAge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}()", self.name())
+    }
+}
+
+impl Indicator for BookQueueAge {
+    fn name(&self) -> String {
+        stringify!(BookQueueAge).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        self.update(book);
+    }
+
+    fn reset(&mut self) {
+        self.bid_value = 0.0;
+        self.ask_value = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl BookQueueAge {
+    /// Creates a new [`BookQueueAge`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bid_value: 0.0,
+            ask_value: 0.0,
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    pub fn update(&mut self, book: &OrderBook) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let bid_age = book
+            .best_bid_price()
+            .and_then(|price| book.avg_order_age(OrderSide::Buy, price));
+        let ask_age = book
+            .best_ask_price()
+            .and_then(|price| book.avg_order_age(OrderSide::Sell, price));
+
+        if let (Some(bid_age), Some(ask_age)) = (bid_age, ask_age) {
+            self.bid_value = bid_age;
+            self.ask_value = ask_age;
+            self.value = (bid_age + ask_age) / 2.0;
+
+            self.initialized = true;
+        }
+        // No market yet
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::BookOrder,
+        enums::BookType,
+        identifiers::InstrumentId,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn book_order(side: OrderSide, price: &str, order_id: u64) -> BookOrder {
+        BookOrder::new(side, Price::from(price), Quantity::from(100), order_id)
+    }
+
+    #[rstest]
+    fn test_initialized() {
+        let indicator = BookQueueAge::new();
+        let display_str = format!("{indicator}");
+        assert_eq!(display_str, "BookQueueAge()");
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.count, 0);
+        assert!(!indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_one_sided_book_not_initialized() {
+        let mut indicator = BookQueueAge::new();
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        book.add(book_order(OrderSide::Buy, "100.00", 1), 0, 1, 1_000.into());
+        indicator.handle_book(&book);
+
+        assert_eq!(indicator.count, 1);
+        assert!(indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_book() {
+        let mut indicator = BookQueueAge::new();
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        book.add(book_order(OrderSide::Buy, "100.00", 1), 0, 1, 1_000.into());
+        book.add(book_order(OrderSide::Buy, "100.00", 2), 0, 2, 3_000.into());
+        book.add(book_order(OrderSide::Sell, "101.00", 3), 0, 3, 4_000.into());
+        book.add(book_order(OrderSide::Buy, "99.00", 4), 0, 4, 6_000.into());
+        indicator.handle_book(&book);
+
+        assert_eq!(indicator.bid_value, 4_000.0);
+        assert_eq!(indicator.ask_value, 2_000.0);
+        assert_eq!(indicator.value, 3_000.0);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut indicator = BookQueueAge::new();
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        book.add(book_order(OrderSide::Buy, "100.00", 1), 0, 1, 1_000.into());
+        book.add(book_order(OrderSide::Sell, "101.00", 2), 0, 2, 2_000.into());
+        indicator.handle_book(&book);
+        indicator.reset();
+
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.bid_value, 0.0);
+        assert_eq!(indicator.ask_value, 0.0);
+        assert!(!indicator.initialized);
+        assert!(!indicator.has_inputs);
+    }
+}
@@ -0,0 +1,225 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{data::OrderBookDepth10, orderbook::OrderBook};
+
+use crate::{
+    book::{levels_from_book, levels_from_depth},
+    indicator::Indicator,
+};
+
+/// An indicator which calculates the order book slope over multiple levels.
+///
+/// The slope of each side is the cumulative size of the top `depth` levels divided by
+/// the relative price distance of the deepest of those levels from the mid-price, i.e.
+/// how much liquidity is available per unit of price impact. The value is
+/// `(bid_slope - ask_slope) / (bid_slope + ask_slope)`, bounded within [-1, 1].
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct BookSlope {
+    pub depth: usize,
+    pub bid_value: f64,
+    pub ask_value: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for BookSlope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.depth)
+    }
+}
+
+impl Indicator for BookSlope {
+    fn name(&self) -> String {
+        stringify!(BookSlope).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = levels_from_book(book, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = levels_from_depth(depth, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn reset(&mut self) {
+        self.bid_value = 0.0;
+        self.ask_value = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl BookSlope {
+    /// Creates a new [`BookSlope`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `depth` is not positive (> 0).
+    #[must_use]
+    pub fn new(depth: usize) -> Self {
+        assert!(depth > 0, "BookSlope: depth must be > 0");
+
+        Self {
+            depth,
+            bid_value: 0.0,
+            ask_value: 0.0,
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with `(price, size)` levels for each side, best level first.
+    pub fn update(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let (Some((best_bid, _)), Some((best_ask, _))) = (bids.first(), asks.first()) else {
+            return; // No market yet
+        };
+
+        if best_ask <= best_bid {
+            return; // Locked or crossed book, distances from mid are undefined
+        }
+
+        let mid = (best_bid + best_ask) / 2.0;
+        let bid_slope = self.side_slope(bids, mid);
+        let ask_slope = self.side_slope(asks, mid);
+        let total = bid_slope + ask_slope;
+
+        if total > 0.0 {
+            self.bid_value = bid_slope;
+            self.ask_value = ask_slope;
+            self.value = (bid_slope - ask_slope) / total;
+            self.initialized = true;
+        }
+    }
+
+    fn side_slope(&self, levels: &[(f64, f64)], mid: f64) -> f64 {
+        let levels = &levels[..levels.len().min(self.depth)];
+        let Some((deepest_price, _)) = levels.last() else {
+            return 0.0;
+        };
+
+        let cumulative_size: f64 = levels.iter().map(|(_, size)| size).sum();
+        let distance = (deepest_price - mid).abs() / mid;
+
+        cumulative_size / distance
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{data::stubs::stub_depth10, stubs::stub_order_book_mbp_appl_xnas};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let indicator = BookSlope::new(5);
+        assert_eq!(format!("{indicator}"), "BookSlope(5)");
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.count, 0);
+        assert!(!indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "depth must be > 0")]
+    fn test_new_with_zero_depth_panics() {
+        let _ = BookSlope::new(0);
+    }
+
+    #[rstest]
+    fn test_handle_book_symmetric() {
+        let mut indicator = BookSlope::new(5);
+        let book = stub_order_book_mbp_appl_xnas();
+        indicator.handle_book(&book);
+
+        assert!(indicator.value.abs() < 1e-9);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_depth_symmetric(stub_depth10: OrderBookDepth10) {
+        let mut indicator = BookSlope::new(3);
+        indicator.handle_depth(&stub_depth10);
+
+        assert!(indicator.value.abs() < 1e-9);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_steeper_bid_side() {
+        let mut indicator = BookSlope::new(2);
+        // Mid 100.0: bids 30 within 1.0 of mid, asks 30 within 2.0 of mid
+        indicator.update(
+            &[(99.5, 10.0), (99.0, 20.0)],
+            &[(100.5, 10.0), (102.0, 20.0)],
+        );
+
+        assert!((indicator.bid_value - 3_000.0).abs() < 1e-9);
+        assert!((indicator.ask_value - 1_500.0).abs() < 1e-9);
+        assert!((indicator.value - 1.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_one_sided_not_initialized() {
+        let mut indicator = BookSlope::new(2);
+        indicator.update(&[(99.0, 10.0)], &[]);
+
+        assert!(indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut indicator = BookSlope::new(2);
+        indicator.update(&[(99.0, 10.0)], &[(101.0, 10.0)]);
+        indicator.reset();
+
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+        assert!(!indicator.initialized);
+        assert!(!indicator.has_inputs);
+    }
+}
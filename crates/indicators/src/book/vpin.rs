@@ -0,0 +1,288 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use arraydeque::{ArrayDeque, Wrapping};
+use nautilus_model::{data::TradeTick, enums::AggressorSide};
+
+use crate::indicator::Indicator;
+
+const MAX_WINDOW: usize = 1_024;
+
+/// An indicator which calculates the Volume-Synchronized Probability of Informed Trading
+/// (VPIN), a measure of trade flow toxicity.
+///
+/// Traded volume is partitioned into buckets of equal `bucket_size`, and the value is the
+/// mean absolute buy/sell volume imbalance over the last `window` buckets divided by the
+/// bucket size, bounded within [0, 1].
+///
+/// Trades are classified by their aggressor side. Trades without an aggressor are
+/// classified by the tick rule against the previous trade price.
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct Vpin {
+    pub bucket_size: f64,
+    pub window: usize,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    buy_volume: f64,
+    sell_volume: f64,
+    imbalance_sum: f64,
+    imbalances: ArrayDeque<f64, MAX_WINDOW, Wrapping>,
+    last_price: Option<f64>,
+    last_side: AggressorSide,
+}
+
+impl Display for Vpin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.bucket_size, self.window)
+    }
+}
+
+impl Indicator for Vpin {
+    fn name(&self) -> String {
+        stringify!(Vpin).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update(
+            trade.price.as_f64(),
+            trade.size.as_f64(),
+            trade.aggressor_side,
+        );
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.buy_volume = 0.0;
+        self.sell_volume = 0.0;
+        self.imbalance_sum = 0.0;
+        self.imbalances.clear();
+        self.last_price = None;
+        self.last_side = AggressorSide::NoAggressor;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl Vpin {
+    /// Creates a new [`Vpin`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket_size` is not positive, or `window` is not positive (> 0) or
+    /// exceeds the maximum window.
+    #[must_use]
+    pub fn new(bucket_size: f64, window: usize) -> Self {
+        assert!(bucket_size > 0.0, "Vpin: bucket_size must be > 0");
+        assert!(window > 0, "Vpin: window must be > 0");
+        assert!(
+            window <= MAX_WINDOW,
+            "Vpin: window {window} exceeds MAX_WINDOW ({MAX_WINDOW})"
+        );
+
+        Self {
+            bucket_size,
+            window,
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+            buy_volume: 0.0,
+            sell_volume: 0.0,
+            imbalance_sum: 0.0,
+            imbalances: ArrayDeque::new(),
+            last_price: None,
+            last_side: AggressorSide::NoAggressor,
+        }
+    }
+
+    /// Updates the indicator with a trade of `size` at `price`.
+    pub fn update(&mut self, price: f64, size: f64, aggressor_side: AggressorSide) {
+        self.has_inputs = true;
+
+        let side = self.classify(price, aggressor_side);
+        self.last_price = Some(price);
+
+        let mut remaining = size;
+        while remaining > 0.0 {
+            let filled = self.buy_volume + self.sell_volume;
+            let volume = remaining.min(self.bucket_size - filled);
+
+            match side {
+                AggressorSide::Buyer => self.buy_volume += volume,
+                AggressorSide::Seller => self.sell_volume += volume,
+                AggressorSide::NoAggressor => {
+                    self.buy_volume += volume / 2.0;
+                    self.sell_volume += volume / 2.0;
+                }
+            }
+            remaining -= volume;
+
+            if self.buy_volume + self.sell_volume >= self.bucket_size {
+                self.close_bucket();
+            }
+        }
+    }
+
+    fn classify(&mut self, price: f64, aggressor_side: AggressorSide) -> AggressorSide {
+        let side = match (aggressor_side, self.last_price) {
+            (AggressorSide::NoAggressor, Some(last_price)) if price > last_price => {
+                AggressorSide::Buyer
+            }
+            (AggressorSide::NoAggressor, Some(last_price)) if price < last_price => {
+                AggressorSide::Seller
+            }
+            (AggressorSide::NoAggressor, _) => self.last_side,
+            (side, _) => side,
+        };
+        self.last_side = side;
+        side
+    }
+
+    fn close_bucket(&mut self) {
+        let imbalance = (self.buy_volume - self.sell_volume).abs();
+        self.buy_volume = 0.0;
+        self.sell_volume = 0.0;
+
+        if self.imbalances.len() == self.window {
+            if let Some(oldest) = self.imbalances.pop_front() {
+                self.imbalance_sum -= oldest;
+            }
+        }
+
+        let _ = self.imbalances.push_back(imbalance);
+        self.imbalance_sum += imbalance;
+        self.count += 1;
+
+        self.value = self.imbalance_sum / (self.imbalances.len() as f64 * self.bucket_size);
+        self.initialized = self.imbalances.len() >= self.window;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::stub_trade;
+
+    #[rstest]
+    fn test_initialized() {
+        let indicator = Vpin::new(10.0, 3);
+        assert_eq!(format!("{indicator}"), "Vpin(10,3)");
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.count, 0);
+        assert!(!indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "bucket_size must be > 0")]
+    fn test_new_with_zero_bucket_size_panics() {
+        let _ = Vpin::new(0.0, 3);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "window must be > 0")]
+    fn test_new_with_zero_window_panics() {
+        let _ = Vpin::new(10.0, 0);
+    }
+
+    #[rstest]
+    fn test_handle_trade_partial_bucket(stub_trade: TradeTick) {
+        let mut indicator = Vpin::new(10.0, 2);
+        indicator.handle_trade(&stub_trade);
+
+        assert!(indicator.has_inputs);
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_one_sided_flow_is_fully_toxic() {
+        let mut indicator = Vpin::new(10.0, 2);
+        indicator.update(100.0, 20.0, AggressorSide::Buyer);
+
+        assert_eq!(indicator.count, 2);
+        assert_eq!(indicator.value, 1.0);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_balanced_flow() {
+        let mut indicator = Vpin::new(10.0, 2);
+        indicator.update(100.0, 5.0, AggressorSide::Buyer);
+        indicator.update(100.0, 5.0, AggressorSide::Seller);
+
+        assert_eq!(indicator.count, 1);
+        assert_eq!(indicator.value, 0.0);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_trade_split_across_buckets() {
+        let mut indicator = Vpin::new(10.0, 2);
+        indicator.update(100.0, 6.0, AggressorSide::Seller);
+        // Completes the first bucket (6 sell, 4 buy) and starts the second with 4 buy
+        indicator.update(100.0, 8.0, AggressorSide::Buyer);
+        indicator.update(100.0, 6.0, AggressorSide::Buyer);
+
+        // Imbalances 2 and 10 over 2 buckets of size 10
+        assert_eq!(indicator.count, 2);
+        assert_eq!(indicator.value, 0.6);
+    }
+
+    #[rstest]
+    fn test_tick_rule_classification() {
+        let mut indicator = Vpin::new(10.0, 1);
+        indicator.update(100.0, 4.0, AggressorSide::Buyer);
+        indicator.update(99.0, 4.0, AggressorSide::NoAggressor); // Down-tick => sell
+        indicator.update(99.0, 2.0, AggressorSide::NoAggressor); // Unchanged => sell
+
+        assert_eq!(indicator.value, 0.2);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut indicator = Vpin::new(10.0, 1);
+        indicator.update(100.0, 10.0, AggressorSide::Buyer);
+        indicator.reset();
+
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+        assert!(indicator.last_price.is_none());
+        assert!(!indicator.initialized);
+        assert!(!indicator.has_inputs);
+    }
+}
@@ -0,0 +1,206 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{data::OrderBookDepth10, orderbook::OrderBook};
+
+use crate::{
+    book::{levels_from_book, levels_from_depth},
+    indicator::Indicator,
+};
+
+/// An indicator which calculates a size-weighted mid-price over multiple book levels.
+///
+/// The volume-weighted average price of the top `depth` levels is calculated for each
+/// side, and the two are then combined weighted by the opposite side's total size. With
+/// a `depth` of 1 this is equivalent to the micro-price.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct WeightedMidPrice {
+    pub depth: usize,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for WeightedMidPrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.depth)
+    }
+}
+
+impl Indicator for WeightedMidPrice {
+    fn name(&self) -> String {
+        stringify!(WeightedMidPrice).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = levels_from_book(book, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = levels_from_depth(depth, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl WeightedMidPrice {
+    /// Creates a new [`WeightedMidPrice`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `depth` is not positive (> 0).
+    #[must_use]
+    pub fn new(depth: usize) -> Self {
+        assert!(depth > 0, "WeightedMidPrice: depth must be > 0");
+
+        Self {
+            depth,
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with `(price, size)` levels for each side, best level first.
+    pub fn update(&mut self, bids: &[(f64, f64)], asks: &[(f64, f64)]) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let (Some((bid_vwap, bid_size)), Some((ask_vwap, ask_size))) =
+            (self.side_vwap(bids), self.side_vwap(asks))
+        else {
+            return; // No market yet
+        };
+
+        self.value = bid_vwap.mul_add(ask_size, ask_vwap * bid_size) / (bid_size + ask_size);
+        self.initialized = true;
+    }
+
+    fn side_vwap(&self, levels: &[(f64, f64)]) -> Option<(f64, f64)> {
+        let (notional, size) = levels
+            .iter()
+            .take(self.depth)
+            .fold((0.0, 0.0), |(notional, total), (price, size)| {
+                (price.mul_add(*size, notional), total + size)
+            });
+
+        if size > 0.0 {
+            Some((notional / size, size))
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{data::stubs::stub_depth10, stubs::stub_order_book_mbp_appl_xnas};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let indicator = WeightedMidPrice::new(3);
+        assert_eq!(format!("{indicator}"), "WeightedMidPrice(3)");
+        assert_eq!(indicator.value, 0.0);
+        assert_eq!(indicator.count, 0);
+        assert!(!indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "depth must be > 0")]
+    fn test_new_with_zero_depth_panics() {
+        let _ = WeightedMidPrice::new(0);
+    }
+
+    #[rstest]
+    fn test_handle_book_symmetric() {
+        let mut indicator = WeightedMidPrice::new(5);
+        let book = stub_order_book_mbp_appl_xnas();
+        indicator.handle_book(&book);
+
+        assert!((indicator.value - 100.5).abs() < 1e-9);
+        assert!(indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_depth(stub_depth10: OrderBookDepth10) {
+        let mut indicator = WeightedMidPrice::new(2);
+        indicator.handle_depth(&stub_depth10);
+
+        // Bid VWAP (99 * 100 + 98 * 200) / 300, ask VWAP (100 * 100 + 101 * 200) / 300
+        let bid_vwap = 29_500.0 / 300.0;
+        let ask_vwap = 30_200.0 / 300.0;
+        assert!((indicator.value - (bid_vwap + ask_vwap) / 2.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_depth_one_equals_microprice() {
+        let mut indicator = WeightedMidPrice::new(1);
+        indicator.update(&[(100.0, 300.0), (99.0, 1.0)], &[(101.0, 100.0)]);
+
+        assert_eq!(indicator.value, 100.75);
+    }
+
+    #[rstest]
+    fn test_update_one_sided_not_initialized() {
+        let mut indicator = WeightedMidPrice::new(2);
+        indicator.update(&[], &[(101.0, 100.0)]);
+
+        assert!(indicator.has_inputs);
+        assert!(!indicator.initialized);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut indicator = WeightedMidPrice::new(2);
+        indicator.update(&[(100.0, 300.0)], &[(101.0, 100.0)]);
+        indicator.reset();
+
+        assert_eq!(indicator.count, 0);
+        assert_eq!(indicator.value, 0.0);
+        assert!(!indicator.initialized);
+        assert!(!indicator.has_inputs);
+    }
+}
@@ -61,6 +61,29 @@ pub trait Indicator {
     }
 
     fn reset(&mut self);
+
+    /// Returns a serialized snapshot of the indicators internal state.
+    ///
+    /// The snapshot can be passed to [`Indicator::restore_state`] on an indicator constructed
+    /// with the same parameters, allowing it to resume without a warmup period.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indicator does not support state snapshots, or serialization fails.
+    fn snapshot_state(&self) -> anyhow::Result<Vec<u8>> {
+        anyhow::bail!("`snapshot_state` {IMPL_ERR} `{}`", self.name())
+    }
+
+    /// Restores the indicators internal state from a snapshot taken with
+    /// [`Indicator::snapshot_state`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indicator does not support state snapshots, the snapshot cannot be
+    /// deserialized, or it was taken from an indicator with different parameters.
+    fn restore_state(&mut self, state: &[u8]) -> anyhow::Result<()> {
+        anyhow::bail!("`restore_state` {IMPL_ERR} `{}`", self.name())
+    }
 }
 
 pub trait MovingAverage: Indicator {
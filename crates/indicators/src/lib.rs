@@ -39,7 +39,9 @@ pub mod average;
 pub mod book;
 pub mod indicator;
 pub mod momentum;
+pub mod pipeline;
 pub mod ratio;
+pub mod state;
 pub mod testing;
 pub mod volatility;
 
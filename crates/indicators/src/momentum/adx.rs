@@ -0,0 +1,272 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::data::Bar;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, MovingAverage},
+};
+
+/// An indicator which calculates the Average Directional Index (ADX) along with the
+/// positive and negative Directional Indicators (+DI/-DI) of the Directional Movement
+/// System (DMI).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators", unsendable)
+)]
+pub struct AverageDirectionalIndex {
+    pub period: usize,
+    pub ma_type: MovingAverageType,
+    pub pos_di: f64,
+    pub neg_di: f64,
+    pub dx: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    tr_ma: Box<dyn MovingAverage + Send + 'static>,
+    pos_dm_ma: Box<dyn MovingAverage + Send + 'static>,
+    neg_dm_ma: Box<dyn MovingAverage + Send + 'static>,
+    adx_ma: Box<dyn MovingAverage + Send + 'static>,
+    has_inputs: bool,
+    previous_high: f64,
+    previous_low: f64,
+    previous_close: f64,
+}
+
+impl Display for AverageDirectionalIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.ma_type)
+    }
+}
+
+impl Indicator for AverageDirectionalIndex {
+    fn name(&self) -> String {
+        stringify!(AverageDirectionalIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.tr_ma.reset();
+        self.pos_dm_ma.reset();
+        self.neg_dm_ma.reset();
+        self.adx_ma.reset();
+        self.previous_high = 0.0;
+        self.previous_low = 0.0;
+        self.previous_close = 0.0;
+        self.pos_di = 0.0;
+        self.neg_di = 0.0;
+        self.dx = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl AverageDirectionalIndex {
+    /// Creates a new [`AverageDirectionalIndex`] instance.
+    ///
+    /// The default moving average type is [`MovingAverageType::Wilder`], as per the
+    /// original definition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize, ma_type: Option<MovingAverageType>) -> Self {
+        assert!(period > 0, "AverageDirectionalIndex: period must be > 0");
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Wilder);
+
+        Self {
+            period,
+            ma_type,
+            pos_di: 0.0,
+            neg_di: 0.0,
+            dx: 0.0,
+            value: 0.0,
+            count: 0,
+            tr_ma: MovingAverageFactory::create(ma_type, period),
+            pos_dm_ma: MovingAverageFactory::create(ma_type, period),
+            neg_dm_ma: MovingAverageFactory::create(ma_type, period),
+            adx_ma: MovingAverageFactory::create(ma_type, period),
+            has_inputs: false,
+            initialized: false,
+            previous_high: 0.0,
+            previous_low: 0.0,
+            previous_close: 0.0,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        if !self.has_inputs {
+            // Directional movement requires a previous bar
+            self.previous_high = high;
+            self.previous_low = low;
+            self.previous_close = close;
+            self.has_inputs = true;
+            return;
+        }
+
+        let up = high - self.previous_high;
+        let down = self.previous_low - low;
+        let pos_dm = if up > down && up > 0.0 { up } else { 0.0 };
+        let neg_dm = if down > up && down > 0.0 { down } else { 0.0 };
+        let true_range = f64::max(high, self.previous_close) - f64::min(low, self.previous_close);
+
+        self.tr_ma.update_raw(true_range);
+        self.pos_dm_ma.update_raw(pos_dm);
+        self.neg_dm_ma.update_raw(neg_dm);
+
+        let tr = self.tr_ma.value();
+        if tr > 0.0 {
+            self.pos_di = 100.0 * self.pos_dm_ma.value() / tr;
+            self.neg_di = 100.0 * self.neg_dm_ma.value() / tr;
+        }
+
+        let di_sum = self.pos_di + self.neg_di;
+        self.dx = if di_sum > 0.0 {
+            100.0 * (self.pos_di - self.neg_di).abs() / di_sum
+        } else {
+            0.0
+        };
+
+        // ADX is only meaningful once the directional indicators are warmed up
+        if self.tr_ma.initialized() {
+            self.adx_ma.update_raw(self.dx);
+            self.value = self.adx_ma.value();
+            self.initialized = self.adx_ma.initialized();
+        }
+
+        self.previous_high = high;
+        self.previous_low = low;
+        self.previous_close = close;
+        self.count += 1;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{adx_10, bar_ethusdt_binance_minute_bid};
+
+    #[rstest]
+    fn test_name_returns_expected_string(adx_10: AverageDirectionalIndex) {
+        assert_eq!(adx_10.name(), "AverageDirectionalIndex");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(adx_10: AverageDirectionalIndex) {
+        assert_eq!(format!("{adx_10}"), "AverageDirectionalIndex(10,SIMPLE)");
+    }
+
+    #[rstest]
+    fn test_default_ma_type_is_wilder() {
+        let adx = AverageDirectionalIndex::new(14, None);
+        assert_eq!(adx.ma_type, MovingAverageType::Wilder);
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(adx_10: AverageDirectionalIndex) {
+        assert!(!adx_10.initialized());
+        assert!(!adx_10.has_inputs());
+    }
+
+    #[rstest]
+    fn test_first_input_sets_previous_values_only(mut adx_10: AverageDirectionalIndex) {
+        adx_10.update_raw(2.0, 1.0, 1.5);
+
+        assert!(adx_10.has_inputs());
+        assert_eq!(adx_10.count, 0);
+        assert_eq!(adx_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_all_higher_inputs(mut adx_10: AverageDirectionalIndex) {
+        for i in 0..25 {
+            let low = f64::from(i);
+            adx_10.update_raw(low + 1.0, low, low);
+        }
+
+        assert!(adx_10.initialized());
+        assert_eq!(adx_10.pos_di, 50.0);
+        assert_eq!(adx_10.neg_di, 0.0);
+        assert_eq!(adx_10.dx, 100.0);
+        assert_eq!(adx_10.value, 100.0);
+    }
+
+    #[rstest]
+    fn test_value_with_all_lower_inputs(mut adx_10: AverageDirectionalIndex) {
+        for i in 0..25 {
+            let high = 100.0 - f64::from(i);
+            adx_10.update_raw(high, high - 1.0, high);
+        }
+
+        assert!(adx_10.initialized());
+        assert_eq!(adx_10.pos_di, 0.0);
+        assert_eq!(adx_10.neg_di, 50.0);
+        assert_eq!(adx_10.value, 100.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut adx_10: AverageDirectionalIndex, bar_ethusdt_binance_minute_bid: Bar) {
+        adx_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+        adx_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(adx_10.has_inputs());
+        assert_eq!(adx_10.count, 1);
+        assert_eq!(adx_10.dx, 0.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(
+        mut adx_10: AverageDirectionalIndex,
+    ) {
+        for i in 0..25 {
+            let low = f64::from(i);
+            adx_10.update_raw(low + 1.0, low, low);
+        }
+
+        adx_10.reset();
+
+        assert!(!adx_10.initialized());
+        assert!(!adx_10.has_inputs());
+        assert_eq!(adx_10.count, 0);
+        assert_eq!(adx_10.value, 0.0);
+        assert_eq!(adx_10.pos_di, 0.0);
+        assert_eq!(adx_10.neg_di, 0.0);
+        assert_eq!(adx_10.previous_close, 0.0);
+    }
+}
@@ -0,0 +1,239 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use nautilus_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// Bound applied to the smoothed normalized price to keep the log transform finite.
+const MAX_NORMALIZED: f64 = 0.999;
+
+/// An indicator which calculates the Ehlers Fisher Transform of the bar midpoint.
+///
+/// The bar midpoint is normalized into (-1, 1) over the rolling `period` range, smoothed, and
+/// converted with the Fisher transform to produce a near-Gaussian oscillator. The `signal` is
+/// the previous transform value.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct FisherTransform {
+    pub period: usize,
+    pub value: f64,
+    pub signal: f64,
+    pub initialized: bool,
+    has_inputs: bool,
+    normalized: f64,
+    mids: VecDeque<f64>,
+}
+
+impl Display for FisherTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for FisherTransform {
+    fn name(&self) -> String {
+        stringify!(FisherTransform).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into());
+    }
+
+    fn reset(&mut self) {
+        self.mids.clear();
+        self.value = 0.0;
+        self.signal = 0.0;
+        self.normalized = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl FisherTransform {
+    /// Creates a new [`FisherTransform`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "FisherTransform: period must be > 0");
+
+        Self {
+            period,
+            value: 0.0,
+            signal: 0.0,
+            initialized: false,
+            has_inputs: false,
+            normalized: 0.0,
+            mids: VecDeque::with_capacity(period),
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64) {
+        let mid = f64::midpoint(high, low);
+
+        if self.mids.len() == self.period {
+            self.mids.pop_front();
+        }
+        self.mids.push_back(mid);
+
+        let max = self.mids.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let min = self.mids.iter().copied().fold(f64::INFINITY, f64::min);
+        let range = max - min;
+
+        let raw = if range > 0.0 {
+            2.0 * ((mid - min) / range - 0.5)
+        } else {
+            0.0
+        };
+
+        self.normalized =
+            (0.33 * raw + 0.67 * self.normalized).clamp(-MAX_NORMALIZED, MAX_NORMALIZED);
+
+        self.signal = self.value;
+        self.value =
+            0.5 * ((1.0 + self.normalized) / (1.0 - self.normalized)).ln() + 0.5 * self.signal;
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.mids.len() >= self.period {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, fisher_10};
+
+    #[rstest]
+    fn test_name_returns_expected_string(fisher_10: FisherTransform) {
+        assert_eq!(fisher_10.name(), "FisherTransform");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(fisher_10: FisherTransform) {
+        assert_eq!(format!("{fisher_10}"), "FisherTransform(10)");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "period must be > 0")]
+    fn test_new_with_zero_period_panics() {
+        let _ = FisherTransform::new(0);
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(fisher_10: FisherTransform) {
+        assert!(!fisher_10.initialized());
+        assert!(!fisher_10.has_inputs());
+    }
+
+    #[rstest]
+    fn test_value_with_one_input(mut fisher_10: FisherTransform) {
+        fisher_10.update_raw(2.0, 1.0);
+
+        assert!(fisher_10.has_inputs());
+        assert!(!fisher_10.initialized());
+        assert_eq!(fisher_10.value, 0.0);
+        assert_eq!(fisher_10.signal, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_rising_inputs_is_positive(mut fisher_10: FisherTransform) {
+        for i in 0..10 {
+            let low = f64::from(i);
+            fisher_10.update_raw(low + 1.0, low);
+        }
+
+        assert!(fisher_10.initialized());
+        assert!(fisher_10.value > 0.0);
+        assert!(fisher_10.value > fisher_10.signal);
+    }
+
+    #[rstest]
+    fn test_value_with_falling_inputs_is_negative(mut fisher_10: FisherTransform) {
+        for i in (0..10).rev() {
+            let low = f64::from(i);
+            fisher_10.update_raw(low + 1.0, low);
+        }
+
+        assert!(fisher_10.initialized());
+        assert!(fisher_10.value < 0.0);
+        assert!(fisher_10.value < fisher_10.signal);
+    }
+
+    #[rstest]
+    fn test_second_value_after_range_expansion(mut fisher_10: FisherTransform) {
+        fisher_10.update_raw(1.0, 1.0);
+        fisher_10.update_raw(3.0, 3.0);
+
+        // Normalized: 0.33 * 1.0 = 0.33
+        let expected = 0.5 * (1.33_f64 / 0.67).ln();
+        assert!((fisher_10.value - expected).abs() < 1e-12);
+        assert_eq!(fisher_10.signal, 0.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut fisher_10: FisherTransform, bar_ethusdt_binance_minute_bid: Bar) {
+        fisher_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(fisher_10.has_inputs());
+        assert_eq!(fisher_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut fisher_10: FisherTransform) {
+        for i in 0..10 {
+            let low = f64::from(i);
+            fisher_10.update_raw(low + 1.0, low);
+        }
+
+        fisher_10.reset();
+
+        assert!(!fisher_10.initialized());
+        assert!(!fisher_10.has_inputs());
+        assert_eq!(fisher_10.value, 0.0);
+        assert_eq!(fisher_10.signal, 0.0);
+        assert_eq!(fisher_10.normalized, 0.0);
+        assert!(fisher_10.mids.is_empty());
+    }
+}
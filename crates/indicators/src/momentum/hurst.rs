@@ -0,0 +1,256 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use nautilus_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// The minimum lag used when estimating the exponent.
+const MIN_LAG: usize = 2;
+
+/// An indicator which estimates the rolling Hurst exponent of log prices.
+///
+/// For each lag in `2..=max_lag` the standard deviation of lagged log price differences is
+/// computed over the window, and the exponent is the least-squares slope of log(std dev)
+/// against log(lag). Values near 0.5 indicate a random walk, above 0.5 a trending
+/// (persistent) series, and below 0.5 a mean-reverting (anti-persistent) series.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct HurstExponent {
+    pub period: usize,
+    pub max_lag: usize,
+    pub value: f64,
+    pub initialized: bool,
+    has_inputs: bool,
+    log_prices: VecDeque<f64>,
+}
+
+impl Display for HurstExponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.max_lag)
+    }
+}
+
+impl Indicator for HurstExponent {
+    fn name(&self) -> String {
+        stringify!(HurstExponent).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.log_prices.clear();
+        self.value = 0.5;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl HurstExponent {
+    /// Creates a new [`HurstExponent`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_lag` is less than 3, or `period` is not greater than `max_lag`.
+    #[must_use]
+    pub fn new(period: usize, max_lag: usize) -> Self {
+        assert!(
+            max_lag > MIN_LAG,
+            "HurstExponent: max_lag must be > {MIN_LAG}"
+        );
+        assert!(period > max_lag, "HurstExponent: period must be > max_lag");
+
+        Self {
+            period,
+            max_lag,
+            value: 0.5,
+            initialized: false,
+            has_inputs: false,
+            log_prices: VecDeque::with_capacity(period),
+        }
+    }
+
+    pub fn update_raw(&mut self, close: f64) {
+        if close <= 0.0 {
+            return; // Log price undefined
+        }
+
+        self.has_inputs = true;
+
+        if self.log_prices.len() == self.period {
+            self.log_prices.pop_front();
+        }
+        self.log_prices.push_back(close.ln());
+
+        if self.log_prices.len() < self.period {
+            return;
+        }
+
+        self.initialized = true;
+
+        if let Some(value) = self.estimate() {
+            self.value = value;
+        }
+    }
+
+    fn estimate(&self) -> Option<f64> {
+        let mut points = Vec::with_capacity(self.max_lag - 1);
+
+        for lag in MIN_LAG..=self.max_lag {
+            let diffs: Vec<f64> = self
+                .log_prices
+                .iter()
+                .zip(self.log_prices.iter().skip(lag))
+                .map(|(a, b)| b - a)
+                .collect();
+            let n = diffs.len() as f64;
+            let mean = diffs.iter().sum::<f64>() / n;
+            let variance = diffs.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / n;
+
+            // Lags with no dispersion carry no scaling information
+            if variance > 0.0 {
+                points.push(((lag as f64).ln(), 0.5 * variance.ln()));
+            }
+        }
+
+        if points.len() < 2 {
+            return None;
+        }
+
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let covariance: f64 = points
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+        Some(covariance / variance)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, hurst_100_10};
+
+    #[rstest]
+    fn test_name_returns_expected_string(hurst_100_10: HurstExponent) {
+        assert_eq!(hurst_100_10.name(), "HurstExponent");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(hurst_100_10: HurstExponent) {
+        assert_eq!(format!("{hurst_100_10}"), "HurstExponent(100,10)");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "period must be > max_lag")]
+    fn test_new_with_period_not_above_max_lag_panics() {
+        let _ = HurstExponent::new(10, 10);
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(hurst_100_10: HurstExponent) {
+        assert!(!hurst_100_10.initialized());
+        assert!(!hurst_100_10.has_inputs());
+        assert_eq!(hurst_100_10.value, 0.5);
+    }
+
+    #[rstest]
+    fn test_non_positive_input_is_ignored(mut hurst_100_10: HurstExponent) {
+        hurst_100_10.update_raw(0.0);
+
+        assert!(!hurst_100_10.has_inputs());
+    }
+
+    #[rstest]
+    fn test_mean_reverting_series(mut hurst_100_10: HurstExponent) {
+        for i in 0..100 {
+            hurst_100_10.update_raw(if i % 2 == 0 { 1.0 } else { 2.0 });
+        }
+
+        assert!(hurst_100_10.initialized());
+        assert!(hurst_100_10.value.abs() < 0.01);
+    }
+
+    #[rstest]
+    fn test_trending_series(mut hurst_100_10: HurstExponent) {
+        for i in 0..100 {
+            let t = f64::from(i);
+            hurst_100_10.update_raw((0.001 * t * t).exp());
+        }
+
+        assert!(hurst_100_10.initialized());
+        assert!(hurst_100_10.value > 0.9);
+    }
+
+    #[rstest]
+    fn test_constant_series_keeps_previous_value(mut hurst_100_10: HurstExponent) {
+        for _ in 0..100 {
+            hurst_100_10.update_raw(1.0);
+        }
+
+        assert!(hurst_100_10.initialized());
+        assert_eq!(hurst_100_10.value, 0.5);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut hurst_100_10: HurstExponent, bar_ethusdt_binance_minute_bid: Bar) {
+        hurst_100_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(hurst_100_10.has_inputs());
+        assert!(!hurst_100_10.initialized());
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut hurst_100_10: HurstExponent) {
+        for i in 0..100 {
+            hurst_100_10.update_raw(if i % 2 == 0 { 1.0 } else { 2.0 });
+        }
+
+        hurst_100_10.reset();
+
+        assert!(!hurst_100_10.initialized());
+        assert!(!hurst_100_10.has_inputs());
+        assert_eq!(hurst_100_10.value, 0.5);
+        assert!(hurst_100_10.log_prices.is_empty());
+    }
+}
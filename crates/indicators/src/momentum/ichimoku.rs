@@ -0,0 +1,308 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use nautilus_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the Ichimoku Kinko Hyo (Ichimoku Cloud) lines.
+///
+/// The leading spans (Senkou Span A/B) are plotted `displacement` bars ahead of the bar
+/// on which they are calculated. The `senkou_span_a` and `senkou_span_b` values are
+/// those calculated `displacement` bars ago, i.e. the cloud at the current bar, whereas
+/// `leading_span_a` and `leading_span_b` are the values calculated on the current bar.
+/// The lagging span (Chikou Span) is the current close, plotted `displacement` bars back.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct IchimokuCloud {
+    pub tenkan_period: usize,
+    pub kijun_period: usize,
+    pub senkou_period: usize,
+    pub displacement: usize,
+    pub tenkan_sen: f64,
+    pub kijun_sen: f64,
+    pub leading_span_a: f64,
+    pub leading_span_b: f64,
+    pub senkou_span_a: f64,
+    pub senkou_span_b: f64,
+    pub chikou_span: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    leading_spans: VecDeque<(f64, f64)>,
+}
+
+impl Display for IchimokuCloud {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{},{})",
+            self.name(),
+            self.tenkan_period,
+            self.kijun_period,
+            self.senkou_period,
+            self.displacement,
+        )
+    }
+}
+
+impl Indicator for IchimokuCloud {
+    fn name(&self) -> String {
+        stringify!(IchimokuCloud).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+        self.leading_spans.clear();
+        self.tenkan_sen = 0.0;
+        self.kijun_sen = 0.0;
+        self.leading_span_a = 0.0;
+        self.leading_span_b = 0.0;
+        self.senkou_span_a = 0.0;
+        self.senkou_span_b = 0.0;
+        self.chikou_span = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl IchimokuCloud {
+    /// Creates a new [`IchimokuCloud`] instance.
+    ///
+    /// Defaults to the traditional (9, 26, 52, 26) parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any period is not positive (> 0), or if `tenkan_period` and `kijun_period`
+    /// exceed `senkou_period`.
+    #[must_use]
+    pub fn new(
+        tenkan_period: Option<usize>,
+        kijun_period: Option<usize>,
+        senkou_period: Option<usize>,
+        displacement: Option<usize>,
+    ) -> Self {
+        let tenkan_period = tenkan_period.unwrap_or(9);
+        let kijun_period = kijun_period.unwrap_or(26);
+        let senkou_period = senkou_period.unwrap_or(52);
+        let displacement = displacement.unwrap_or(26);
+
+        assert!(
+            tenkan_period > 0 && kijun_period > 0 && displacement > 0,
+            "IchimokuCloud: periods must be > 0"
+        );
+        assert!(
+            tenkan_period <= senkou_period && kijun_period <= senkou_period,
+            "IchimokuCloud: senkou_period must be >= tenkan_period and kijun_period"
+        );
+
+        Self {
+            tenkan_period,
+            kijun_period,
+            senkou_period,
+            displacement,
+            tenkan_sen: 0.0,
+            kijun_sen: 0.0,
+            leading_span_a: 0.0,
+            leading_span_b: 0.0,
+            senkou_span_a: 0.0,
+            senkou_span_b: 0.0,
+            chikou_span: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+            highs: VecDeque::with_capacity(senkou_period),
+            lows: VecDeque::with_capacity(senkou_period),
+            leading_spans: VecDeque::with_capacity(displacement + 1),
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        self.has_inputs = true;
+
+        if self.highs.len() == self.senkou_period {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        self.highs.push_back(high);
+        self.lows.push_back(low);
+
+        self.tenkan_sen = self.midpoint(self.tenkan_period);
+        self.kijun_sen = self.midpoint(self.kijun_period);
+        self.leading_span_a = f64::midpoint(self.tenkan_sen, self.kijun_sen);
+        self.leading_span_b = self.midpoint(self.senkou_period);
+        self.chikou_span = close;
+
+        self.leading_spans
+            .push_back((self.leading_span_a, self.leading_span_b));
+        if self.leading_spans.len() > self.displacement {
+            if let Some((span_a, span_b)) = self.leading_spans.pop_front() {
+                self.senkou_span_a = span_a;
+                self.senkou_span_b = span_b;
+            }
+        }
+
+        // Initialization logic
+        self.count += 1;
+        if !self.initialized {
+            // The cloud at the current bar is complete once a full senkou window is displaced
+            self.initialized = self.count >= self.senkou_period + self.displacement;
+        }
+    }
+
+    fn midpoint(&self, period: usize) -> f64 {
+        let start = self.highs.len().saturating_sub(period);
+        let highest = self
+            .highs
+            .range(start..)
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let lowest = self
+            .lows
+            .range(start..)
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+        f64::midpoint(highest, lowest)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, ichimoku_3_5_8_4};
+
+    #[rstest]
+    fn test_name_returns_expected_string(ichimoku_3_5_8_4: IchimokuCloud) {
+        assert_eq!(ichimoku_3_5_8_4.name(), "IchimokuCloud");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(ichimoku_3_5_8_4: IchimokuCloud) {
+        assert_eq!(format!("{ichimoku_3_5_8_4}"), "IchimokuCloud(3,5,8,4)");
+    }
+
+    #[rstest]
+    fn test_default_parameters() {
+        let ichimoku = IchimokuCloud::new(None, None, None, None);
+        assert_eq!(ichimoku.tenkan_period, 9);
+        assert_eq!(ichimoku.kijun_period, 26);
+        assert_eq!(ichimoku.senkou_period, 52);
+        assert_eq!(ichimoku.displacement, 26);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "senkou_period must be >= tenkan_period and kijun_period")]
+    fn test_new_with_invalid_senkou_period_panics() {
+        let _ = IchimokuCloud::new(Some(9), Some(26), Some(20), None);
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(ichimoku_3_5_8_4: IchimokuCloud) {
+        assert!(!ichimoku_3_5_8_4.initialized());
+        assert!(!ichimoku_3_5_8_4.has_inputs());
+    }
+
+    #[rstest]
+    fn test_lines_with_rising_inputs(mut ichimoku_3_5_8_4: IchimokuCloud) {
+        for i in 0..8 {
+            let low = f64::from(i);
+            ichimoku_3_5_8_4.update_raw(low + 2.0, low, low + 1.0);
+        }
+
+        // Last bar: low 7, high 9
+        assert_eq!(ichimoku_3_5_8_4.tenkan_sen, 7.0); // (9 + 5) / 2
+        assert_eq!(ichimoku_3_5_8_4.kijun_sen, 6.0); // (9 + 3) / 2
+        assert_eq!(ichimoku_3_5_8_4.leading_span_a, 6.5);
+        assert_eq!(ichimoku_3_5_8_4.leading_span_b, 4.5); // (9 + 0) / 2
+        assert_eq!(ichimoku_3_5_8_4.chikou_span, 8.0);
+        assert!(!ichimoku_3_5_8_4.initialized());
+    }
+
+    #[rstest]
+    fn test_senkou_spans_are_displaced(mut ichimoku_3_5_8_4: IchimokuCloud) {
+        let mut leading = Vec::new();
+        for i in 0..12 {
+            let low = f64::from(i);
+            ichimoku_3_5_8_4.update_raw(low + 2.0, low, low + 1.0);
+            leading.push((
+                ichimoku_3_5_8_4.leading_span_a,
+                ichimoku_3_5_8_4.leading_span_b,
+            ));
+        }
+
+        assert!(ichimoku_3_5_8_4.initialized());
+        assert_eq!(ichimoku_3_5_8_4.senkou_span_a, leading[7].0);
+        assert_eq!(ichimoku_3_5_8_4.senkou_span_b, leading[7].1);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut ichimoku_3_5_8_4: IchimokuCloud, bar_ethusdt_binance_minute_bid: Bar) {
+        ichimoku_3_5_8_4.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(ichimoku_3_5_8_4.has_inputs());
+        assert_eq!(ichimoku_3_5_8_4.tenkan_sen, 1522.5); // (1550 + 1495) / 2
+        assert_eq!(ichimoku_3_5_8_4.chikou_span, 1522.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(
+        mut ichimoku_3_5_8_4: IchimokuCloud,
+    ) {
+        for i in 0..12 {
+            let low = f64::from(i);
+            ichimoku_3_5_8_4.update_raw(low + 2.0, low, low + 1.0);
+        }
+
+        ichimoku_3_5_8_4.reset();
+
+        assert!(!ichimoku_3_5_8_4.initialized());
+        assert!(!ichimoku_3_5_8_4.has_inputs());
+        assert_eq!(ichimoku_3_5_8_4.count, 0);
+        assert_eq!(ichimoku_3_5_8_4.tenkan_sen, 0.0);
+        assert_eq!(ichimoku_3_5_8_4.senkou_span_a, 0.0);
+        assert!(ichimoku_3_5_8_4.highs.is_empty());
+        assert!(ichimoku_3_5_8_4.leading_spans.is_empty());
+    }
+}
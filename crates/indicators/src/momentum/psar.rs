@@ -0,0 +1,309 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates Wilder's Parabolic Stop and Reverse (SAR).
+///
+/// The `direction` is 1 when long (the SAR trails below price) and -1 when short (the SAR
+/// trails above price). The acceleration factor starts at `af_start`, increases by
+/// `af_step` on each new extreme point, and is capped at `af_max`.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct ParabolicSar {
+    pub af_start: f64,
+    pub af_step: f64,
+    pub af_max: f64,
+    pub value: f64,
+    pub direction: i8,
+    pub extreme_point: f64,
+    pub acceleration: f64,
+    pub initialized: bool,
+    has_inputs: bool,
+    previous_high: f64,
+    previous_low: f64,
+    previous_high2: f64,
+    previous_low2: f64,
+}
+
+impl Display for ParabolicSar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{})",
+            self.name(),
+            self.af_start,
+            self.af_step,
+            self.af_max
+        )
+    }
+}
+
+impl Indicator for ParabolicSar {
+    fn name(&self) -> String {
+        stringify!(ParabolicSar).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.direction = 0;
+        self.extreme_point = 0.0;
+        self.acceleration = self.af_start;
+        self.previous_high = 0.0;
+        self.previous_low = 0.0;
+        self.previous_high2 = 0.0;
+        self.previous_low2 = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl ParabolicSar {
+    /// Creates a new [`ParabolicSar`] instance.
+    ///
+    /// Defaults to Wilder's (0.02, 0.02, 0.2) acceleration parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the acceleration parameters are not positive, or `af_start` exceeds `af_max`.
+    #[must_use]
+    pub fn new(af_start: Option<f64>, af_step: Option<f64>, af_max: Option<f64>) -> Self {
+        let af_start = af_start.unwrap_or(0.02);
+        let af_step = af_step.unwrap_or(0.02);
+        let af_max = af_max.unwrap_or(0.2);
+
+        assert!(
+            af_start > 0.0 && af_step > 0.0 && af_max > 0.0,
+            "ParabolicSar: acceleration parameters must be > 0"
+        );
+        assert!(
+            af_start <= af_max,
+            "ParabolicSar: af_start must be <= af_max"
+        );
+
+        Self {
+            af_start,
+            af_step,
+            af_max,
+            value: 0.0,
+            direction: 0,
+            extreme_point: 0.0,
+            acceleration: af_start,
+            initialized: false,
+            has_inputs: false,
+            previous_high: 0.0,
+            previous_low: 0.0,
+            previous_high2: 0.0,
+            previous_low2: 0.0,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64) {
+        if !self.has_inputs {
+            // The initial trend requires a previous bar
+            self.has_inputs = true;
+            self.set_previous(high, low);
+            self.set_previous(high, low);
+            return;
+        }
+
+        if self.initialized {
+            self.step(high, low);
+        } else {
+            if high >= self.previous_high {
+                self.direction = 1;
+                self.value = self.previous_low.min(low);
+                self.extreme_point = high;
+            } else {
+                self.direction = -1;
+                self.value = self.previous_high.max(high);
+                self.extreme_point = low;
+            }
+            self.acceleration = self.af_start;
+            self.initialized = true;
+        }
+
+        self.set_previous(high, low);
+    }
+
+    fn step(&mut self, high: f64, low: f64) {
+        let sar = self.value + self.acceleration * (self.extreme_point - self.value);
+
+        if self.direction == 1 {
+            // SAR may not move above the prior two lows
+            let sar = sar.min(self.previous_low).min(self.previous_low2);
+            if low < sar {
+                self.reverse(-1, low);
+            } else {
+                self.value = sar;
+                if high > self.extreme_point {
+                    self.extreme_point = high;
+                    self.accelerate();
+                }
+            }
+        } else {
+            // SAR may not move below the prior two highs
+            let sar = sar.max(self.previous_high).max(self.previous_high2);
+            if high > sar {
+                self.reverse(1, high);
+            } else {
+                self.value = sar;
+                if low < self.extreme_point {
+                    self.extreme_point = low;
+                    self.accelerate();
+                }
+            }
+        }
+    }
+
+    fn reverse(&mut self, direction: i8, extreme_point: f64) {
+        self.direction = direction;
+        self.value = self.extreme_point;
+        self.extreme_point = extreme_point;
+        self.acceleration = self.af_start;
+    }
+
+    fn accelerate(&mut self) {
+        self.acceleration = (self.acceleration + self.af_step).min(self.af_max);
+    }
+
+    fn set_previous(&mut self, high: f64, low: f64) {
+        self.previous_high2 = self.previous_high;
+        self.previous_low2 = self.previous_low;
+        self.previous_high = high;
+        self.previous_low = low;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, psar};
+
+    #[rstest]
+    fn test_name_returns_expected_string(psar: ParabolicSar) {
+        assert_eq!(psar.name(), "ParabolicSar");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(psar: ParabolicSar) {
+        assert_eq!(format!("{psar}"), "ParabolicSar(0.02,0.02,0.2)");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "af_start must be <= af_max")]
+    fn test_new_with_invalid_acceleration_panics() {
+        let _ = ParabolicSar::new(Some(0.5), None, Some(0.2));
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(psar: ParabolicSar) {
+        assert!(!psar.initialized());
+        assert!(!psar.has_inputs());
+    }
+
+    #[rstest]
+    fn test_initial_long_trend(mut psar: ParabolicSar) {
+        psar.update_raw(2.0, 0.0);
+        assert!(!psar.initialized());
+
+        psar.update_raw(3.0, 1.0);
+        assert!(psar.initialized());
+        assert_eq!(psar.direction, 1);
+        assert_eq!(psar.value, 0.0);
+        assert_eq!(psar.extreme_point, 3.0);
+    }
+
+    #[rstest]
+    fn test_rising_inputs_accelerate(mut psar: ParabolicSar) {
+        for i in 0..10 {
+            let low = f64::from(i);
+            psar.update_raw(low + 2.0, low);
+        }
+
+        assert_eq!(psar.direction, 1);
+        assert_eq!(psar.extreme_point, 11.0);
+        assert!((psar.acceleration - 0.18).abs() < 1e-12);
+        assert!(psar.value < 8.0);
+    }
+
+    #[rstest]
+    fn test_reversal_on_break_below_sar(mut psar: ParabolicSar) {
+        for i in 0..5 {
+            let low = f64::from(i);
+            psar.update_raw(low + 2.0, low);
+        }
+        let extreme_point = psar.extreme_point;
+
+        psar.update_raw(1.0, -5.0);
+
+        assert_eq!(psar.direction, -1);
+        assert_eq!(psar.value, extreme_point);
+        assert_eq!(psar.extreme_point, -5.0);
+        assert_eq!(psar.acceleration, 0.02);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut psar: ParabolicSar, bar_ethusdt_binance_minute_bid: Bar) {
+        psar.handle_bar(&bar_ethusdt_binance_minute_bid);
+        psar.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(psar.initialized());
+        assert_eq!(psar.direction, 1);
+        assert_eq!(psar.value, 1495.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut psar: ParabolicSar) {
+        for i in 0..5 {
+            let low = f64::from(i);
+            psar.update_raw(low + 2.0, low);
+        }
+
+        psar.reset();
+
+        assert!(!psar.initialized());
+        assert!(!psar.has_inputs());
+        assert_eq!(psar.value, 0.0);
+        assert_eq!(psar.direction, 0);
+        assert_eq!(psar.acceleration, 0.02);
+        assert_eq!(psar.previous_high, 0.0);
+    }
+}
@@ -20,9 +20,12 @@ use nautilus_model::{
     enums::PriceType,
 };
 
+use serde::{Deserialize, Serialize};
+
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
     indicator::{Indicator, MovingAverage},
+    state::{check_state_period, decode_state, encode_state},
 };
 
 /// An indicator which calculates a relative strength index (RSI) across a rolling window.
@@ -45,6 +48,18 @@ pub struct RelativeStrengthIndex {
     rsi_max: f64,
 }
 
+#[derive(Serialize, Deserialize)]
+struct RelativeStrengthIndexState {
+    period: usize,
+    value: f64,
+    count: usize,
+    has_inputs: bool,
+    initialized: bool,
+    last_value: f64,
+    average_gain: Vec<u8>,
+    average_loss: Vec<u8>,
+}
+
 impl Display for RelativeStrengthIndex {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}({},{})", self.name(), self.period, self.ma_type)
@@ -77,12 +92,41 @@ impl Indicator for RelativeStrengthIndex {
     }
 
     fn reset(&mut self) {
+        self.average_gain.reset();
+        self.average_loss.reset();
         self.value = 0.0;
         self.last_value = 0.0;
         self.count = 0;
         self.has_inputs = false;
         self.initialized = false;
     }
+
+    fn snapshot_state(&self) -> anyhow::Result<Vec<u8>> {
+        encode_state(&RelativeStrengthIndexState {
+            period: self.period,
+            value: self.value,
+            count: self.count,
+            has_inputs: self.has_inputs,
+            initialized: self.initialized,
+            last_value: self.last_value,
+            average_gain: self.average_gain.snapshot_state()?,
+            average_loss: self.average_loss.snapshot_state()?,
+        })
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> anyhow::Result<()> {
+        let state: RelativeStrengthIndexState = decode_state(&self.name(), state)?;
+        check_state_period(&self.name(), self.period, state.period)?;
+
+        self.average_gain.restore_state(&state.average_gain)?;
+        self.average_loss.restore_state(&state.average_loss)?;
+        self.value = state.value;
+        self.count = state.count;
+        self.has_inputs = state.has_inputs;
+        self.initialized = state.initialized;
+        self.last_value = state.last_value;
+        Ok(())
+    }
 }
 
 impl RelativeStrengthIndex {
@@ -244,4 +288,29 @@ mod tests {
         assert_eq!(rsi_10.count, 1);
         assert_eq!(rsi_10.value, 1.0);
     }
+
+    #[rstest]
+    fn test_restore_state_resumes_identically(mut rsi_10: RelativeStrengthIndex) {
+        for i in 0..15 {
+            rsi_10.update_raw(f64::from(i % 4));
+        }
+        let state = rsi_10.snapshot_state().unwrap();
+
+        let mut restored = RelativeStrengthIndex::new(10, None);
+        restored.restore_state(&state).unwrap();
+        rsi_10.update_raw(2.5);
+        restored.update_raw(2.5);
+
+        assert!(restored.initialized());
+        assert_eq!(restored.count, rsi_10.count);
+        assert_eq!(restored.value, rsi_10.value);
+    }
+
+    #[rstest]
+    fn test_restore_state_with_different_period_errors(rsi_10: RelativeStrengthIndex) {
+        let state = rsi_10.snapshot_state().unwrap();
+        let mut other = RelativeStrengthIndex::new(14, None);
+
+        assert!(other.restore_state(&state).is_err());
+    }
 }
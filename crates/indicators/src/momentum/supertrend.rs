@@ -0,0 +1,246 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::data::Bar;
+
+use crate::{average::MovingAverageType, indicator::Indicator, volatility::atr::AverageTrueRange};
+
+/// An indicator which calculates the SuperTrend, a trailing stop line derived from
+/// Average True Range (ATR) bands around the median price.
+///
+/// The `direction` is 1 for an uptrend (the value tracks the lower band) and -1 for a
+/// downtrend (the value tracks the upper band).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators", unsendable)
+)]
+pub struct SuperTrend {
+    pub period: usize,
+    pub multiplier: f64,
+    pub upper: f64,
+    pub lower: f64,
+    pub value: f64,
+    pub direction: i8,
+    pub initialized: bool,
+    atr: AverageTrueRange,
+    has_inputs: bool,
+    previous_close: f64,
+}
+
+impl Display for SuperTrend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.multiplier)
+    }
+}
+
+impl Indicator for SuperTrend {
+    fn name(&self) -> String {
+        stringify!(SuperTrend).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.upper = 0.0;
+        self.lower = 0.0;
+        self.value = 0.0;
+        self.direction = 0;
+        self.previous_close = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl SuperTrend {
+    /// Creates a new [`SuperTrend`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0), or `multiplier` is not positive.
+    #[must_use]
+    pub fn new(period: usize, multiplier: f64) -> Self {
+        assert!(period > 0, "SuperTrend: period must be > 0");
+        assert!(multiplier > 0.0, "SuperTrend: multiplier must be > 0");
+
+        Self {
+            period,
+            multiplier,
+            upper: 0.0,
+            lower: 0.0,
+            value: 0.0,
+            direction: 0,
+            initialized: false,
+            atr: AverageTrueRange::new(period, Some(MovingAverageType::Simple), Some(true), None),
+            has_inputs: false,
+            previous_close: 0.0,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        self.has_inputs = true;
+        self.atr.update_raw(high, low, close);
+
+        if !self.atr.initialized {
+            self.previous_close = close;
+            return;
+        }
+
+        let median = f64::midpoint(high, low);
+        let band = self.multiplier * self.atr.value;
+        let basic_upper = median + band;
+        let basic_lower = median - band;
+
+        if self.initialized {
+            // Bands only tighten unless the previous close broke through them
+            if basic_upper < self.upper || self.previous_close > self.upper {
+                self.upper = basic_upper;
+            }
+            if basic_lower > self.lower || self.previous_close < self.lower {
+                self.lower = basic_lower;
+            }
+
+            self.direction = match self.direction {
+                1 if close < self.lower => -1,
+                -1 if close > self.upper => 1,
+                direction => direction,
+            };
+        } else {
+            self.upper = basic_upper;
+            self.lower = basic_lower;
+            self.direction = if close >= median { 1 } else { -1 };
+            self.initialized = true;
+        }
+
+        self.value = if self.direction == 1 {
+            self.lower
+        } else {
+            self.upper
+        };
+        self.previous_close = close;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, supertrend_3};
+
+    #[rstest]
+    fn test_name_returns_expected_string(supertrend_3: SuperTrend) {
+        assert_eq!(supertrend_3.name(), "SuperTrend");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(supertrend_3: SuperTrend) {
+        assert_eq!(format!("{supertrend_3}"), "SuperTrend(3,2)");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "multiplier must be > 0")]
+    fn test_new_with_invalid_multiplier_panics() {
+        let _ = SuperTrend::new(3, 0.0);
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(supertrend_3: SuperTrend) {
+        assert!(!supertrend_3.initialized());
+        assert!(!supertrend_3.has_inputs());
+        assert_eq!(supertrend_3.direction, 0);
+    }
+
+    #[rstest]
+    fn test_initialized_after_atr_warmup(mut supertrend_3: SuperTrend) {
+        supertrend_3.update_raw(11.0, 9.0, 10.0);
+        supertrend_3.update_raw(11.0, 9.0, 10.0);
+        assert!(!supertrend_3.initialized());
+
+        supertrend_3.update_raw(11.0, 9.0, 10.0);
+        assert!(supertrend_3.initialized());
+        assert_eq!(supertrend_3.upper, 14.0); // 10 + 2 * 2
+        assert_eq!(supertrend_3.lower, 6.0); // 10 - 2 * 2
+        assert_eq!(supertrend_3.direction, 1);
+        assert_eq!(supertrend_3.value, 6.0);
+    }
+
+    #[rstest]
+    fn test_lower_band_ratchets_up_in_uptrend(mut supertrend_3: SuperTrend) {
+        for _ in 0..3 {
+            supertrend_3.update_raw(11.0, 9.0, 10.0);
+        }
+        // Median rises by 1 with unchanged ATR of 2
+        supertrend_3.update_raw(12.0, 10.0, 11.0);
+        supertrend_3.update_raw(12.0, 10.0, 11.0);
+
+        assert_eq!(supertrend_3.direction, 1);
+        assert!(supertrend_3.lower > 6.0);
+        assert_eq!(supertrend_3.value, supertrend_3.lower);
+    }
+
+    #[rstest]
+    fn test_direction_flips_when_close_breaks_lower_band(mut supertrend_3: SuperTrend) {
+        for _ in 0..3 {
+            supertrend_3.update_raw(11.0, 9.0, 10.0);
+        }
+        supertrend_3.update_raw(6.0, 4.0, 4.5);
+
+        assert_eq!(supertrend_3.direction, -1);
+        assert_eq!(supertrend_3.value, supertrend_3.upper);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut supertrend_3: SuperTrend, bar_ethusdt_binance_minute_bid: Bar) {
+        for _ in 0..3 {
+            supertrend_3.handle_bar(&bar_ethusdt_binance_minute_bid);
+        }
+
+        assert!(supertrend_3.initialized());
+        assert_eq!(supertrend_3.direction, -1); // Close below the median price
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut supertrend_3: SuperTrend) {
+        for _ in 0..5 {
+            supertrend_3.update_raw(11.0, 9.0, 10.0);
+        }
+
+        supertrend_3.reset();
+
+        assert!(!supertrend_3.initialized());
+        assert!(!supertrend_3.has_inputs());
+        assert_eq!(supertrend_3.value, 0.0);
+        assert_eq!(supertrend_3.direction, 0);
+        assert_eq!(supertrend_3.previous_close, 0.0);
+    }
+}
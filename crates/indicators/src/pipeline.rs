@@ -0,0 +1,558 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Composite indicator pipelines, where each stage consumes the output of the previous stage.
+//!
+//! A pipeline is declared with an [`IndicatorPipelineConfig`], for example an RSI of an EMA
+//! of bar closes, or a z-score of a spread fed through [`IndicatorPipeline::update_raw`].
+
+use std::fmt::{Debug, Display};
+
+use nautilus_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, MovingAverage},
+    momentum::{roc::RateOfChange, rsi::RelativeStrengthIndex},
+    ratio::zscore::ZScore,
+    state::{decode_state, encode_state},
+};
+
+/// The bar price which feeds the first stage of an [`IndicatorPipeline`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PipelineSource {
+    Open,
+    High,
+    Low,
+    #[default]
+    Close,
+    /// The midpoint of the bar high and low.
+    Median,
+    /// The average of the bar high, low and close.
+    Typical,
+}
+
+impl PipelineSource {
+    /// Returns the source price from the given `bar`.
+    #[must_use]
+    pub fn extract(&self, bar: &Bar) -> f64 {
+        match self {
+            Self::Open => bar.open.as_f64(),
+            Self::High => bar.high.as_f64(),
+            Self::Low => bar.low.as_f64(),
+            Self::Close => bar.close.as_f64(),
+            Self::Median => f64::midpoint(bar.high.as_f64(), bar.low.as_f64()),
+            Self::Typical => (bar.high.as_f64() + bar.low.as_f64() + bar.close.as_f64()) / 3.0,
+        }
+    }
+}
+
+/// Configuration for a single stage of an [`IndicatorPipeline`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum PipelineStageConfig {
+    MovingAverage {
+        ma_type: MovingAverageType,
+        period: usize,
+    },
+    RelativeStrengthIndex {
+        period: usize,
+    },
+    RateOfChange {
+        period: usize,
+        #[serde(default)]
+        use_log: bool,
+    },
+    ZScore {
+        period: usize,
+    },
+}
+
+/// Configuration for an [`IndicatorPipeline`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IndicatorPipelineConfig {
+    /// The bar price which feeds the first stage.
+    #[serde(default)]
+    pub source: PipelineSource,
+    /// The stages in order, each consuming the output of the previous stage.
+    pub stages: Vec<PipelineStageConfig>,
+}
+
+impl IndicatorPipelineConfig {
+    /// Creates a new [`IndicatorPipelineConfig`] instance.
+    #[must_use]
+    pub const fn new(source: PipelineSource, stages: Vec<PipelineStageConfig>) -> Self {
+        Self { source, stages }
+    }
+}
+
+#[derive(Debug)]
+enum PipelineStage {
+    MovingAverage(usize, Box<dyn MovingAverage + Send + 'static>),
+    RelativeStrengthIndex(RelativeStrengthIndex),
+    RateOfChange(RateOfChange),
+    ZScore(ZScore),
+}
+
+impl PipelineStage {
+    fn new(config: &PipelineStageConfig) -> Self {
+        match *config {
+            PipelineStageConfig::MovingAverage { ma_type, period } => {
+                Self::MovingAverage(period, MovingAverageFactory::create(ma_type, period))
+            }
+            PipelineStageConfig::RelativeStrengthIndex { period } => {
+                Self::RelativeStrengthIndex(RelativeStrengthIndex::new(period, None))
+            }
+            PipelineStageConfig::RateOfChange { period, use_log } => {
+                Self::RateOfChange(RateOfChange::new(period, Some(use_log)))
+            }
+            PipelineStageConfig::ZScore { period } => Self::ZScore(ZScore::new(period)),
+        }
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        match self {
+            Self::MovingAverage(_, ma) => ma.update_raw(value),
+            Self::RelativeStrengthIndex(rsi) => rsi.update_raw(value),
+            Self::RateOfChange(roc) => roc.update_raw(value),
+            Self::ZScore(zscore) => zscore.update_raw(value),
+        }
+    }
+
+    fn value(&self) -> f64 {
+        match self {
+            Self::MovingAverage(_, ma) => ma.value(),
+            Self::RelativeStrengthIndex(rsi) => rsi.value,
+            Self::RateOfChange(roc) => roc.value,
+            Self::ZScore(zscore) => zscore.value,
+        }
+    }
+
+    fn initialized(&self) -> bool {
+        match self {
+            Self::MovingAverage(_, ma) => ma.initialized(),
+            Self::RelativeStrengthIndex(rsi) => rsi.initialized,
+            Self::RateOfChange(roc) => roc.initialized,
+            Self::ZScore(zscore) => zscore.initialized,
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Self::MovingAverage(_, ma) => ma.reset(),
+            Self::RelativeStrengthIndex(rsi) => rsi.reset(),
+            Self::RateOfChange(roc) => roc.reset(),
+            Self::ZScore(zscore) => zscore.reset(),
+        }
+    }
+
+    fn snapshot_state(&self) -> anyhow::Result<Vec<u8>> {
+        match self {
+            Self::MovingAverage(_, ma) => ma.snapshot_state(),
+            Self::RelativeStrengthIndex(rsi) => rsi.snapshot_state(),
+            Self::RateOfChange(roc) => roc.snapshot_state(),
+            Self::ZScore(zscore) => zscore.snapshot_state(),
+        }
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> anyhow::Result<()> {
+        match self {
+            Self::MovingAverage(_, ma) => ma.restore_state(state),
+            Self::RelativeStrengthIndex(rsi) => rsi.restore_state(state),
+            Self::RateOfChange(roc) => roc.restore_state(state),
+            Self::ZScore(zscore) => zscore.restore_state(state),
+        }
+    }
+}
+
+impl Display for PipelineStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MovingAverage(period, ma) => write!(f, "{}({period})", ma.name()),
+            Self::RelativeStrengthIndex(rsi) => write!(f, "{rsi}"),
+            Self::RateOfChange(roc) => write!(f, "{}({})", roc.name(), roc.period),
+            Self::ZScore(zscore) => write!(f, "{zscore}"),
+        }
+    }
+}
+
+/// An indicator which chains other indicators, feeding each stage the output of the previous one.
+///
+/// A stage only passes its output downstream once it is initialized, so the pipeline is
+/// initialized once its final stage is, and warms up in a single pass over its inputs.
+#[derive(Debug)]
+pub struct IndicatorPipeline {
+    pub source: PipelineSource,
+    pub value: f64,
+    pub initialized: bool,
+    has_inputs: bool,
+    stages: Vec<PipelineStage>,
+}
+
+impl Display for IndicatorPipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stages: Vec<String> = self.stages.iter().map(ToString::to_string).collect();
+        write!(f, "{}({})", self.name(), stages.join(" -> "))
+    }
+}
+
+impl Indicator for IndicatorPipeline {
+    fn name(&self) -> String {
+        stringify!(IndicatorPipeline).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(PriceType::Mid).into());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(self.source.extract(bar));
+    }
+
+    fn reset(&mut self) {
+        for stage in &mut self.stages {
+            stage.reset();
+        }
+        self.value = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+
+    fn snapshot_state(&self) -> anyhow::Result<Vec<u8>> {
+        let stages = self
+            .stages
+            .iter()
+            .map(PipelineStage::snapshot_state)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        encode_state(&IndicatorPipelineState {
+            value: self.value,
+            initialized: self.initialized,
+            has_inputs: self.has_inputs,
+            stages,
+        })
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> anyhow::Result<()> {
+        let state: IndicatorPipelineState = decode_state(&self.name(), state)?;
+        anyhow::ensure!(
+            state.stages.len() == self.stages.len(),
+            "Cannot restore `{}` state: snapshot has {} stage(s), expected {}",
+            self.name(),
+            state.stages.len(),
+            self.stages.len(),
+        );
+
+        for (stage, stage_state) in self.stages.iter_mut().zip(&state.stages) {
+            stage.restore_state(stage_state)?;
+        }
+        self.value = state.value;
+        self.initialized = state.initialized;
+        self.has_inputs = state.has_inputs;
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct IndicatorPipelineState {
+    value: f64,
+    initialized: bool,
+    has_inputs: bool,
+    stages: Vec<Vec<u8>>,
+}
+
+impl IndicatorPipeline {
+    /// Creates a new [`IndicatorPipeline`] instance from the given `config`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `config` has no stages, or a stage configuration is invalid.
+    #[must_use]
+    pub fn new(config: &IndicatorPipelineConfig) -> Self {
+        assert!(
+            !config.stages.is_empty(),
+            "IndicatorPipeline: config must have at least one stage"
+        );
+
+        Self {
+            source: config.source,
+            value: 0.0,
+            initialized: false,
+            has_inputs: false,
+            stages: config.stages.iter().map(PipelineStage::new).collect(),
+        }
+    }
+
+    /// Returns the number of stages in the pipeline.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Returns whether the pipeline has no stages (never true for a constructed pipeline).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Returns the current output value of each stage, in order.
+    #[must_use]
+    pub fn stage_values(&self) -> Vec<f64> {
+        self.stages.iter().map(PipelineStage::value).collect()
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        self.has_inputs = true;
+
+        let mut input = value;
+        for stage in &mut self.stages {
+            stage.update_raw(input);
+
+            if !stage.initialized() {
+                return; // Downstream stages only receive warmed up values
+            }
+            input = stage.value();
+        }
+
+        self.value = input;
+        self.initialized = true;
+    }
+
+    /// Warms up the pipeline from historical `bars` in a single pass.
+    pub fn warm_up(&mut self, bars: &[Bar]) {
+        for bar in bars {
+            self.handle_bar(bar);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        average::ema::ExponentialMovingAverage,
+        stubs::{bar_ethusdt_binance_minute_bid, rsi_of_ema_pipeline},
+    };
+
+    #[rstest]
+    fn test_name_returns_expected_string(rsi_of_ema_pipeline: IndicatorPipeline) {
+        assert_eq!(rsi_of_ema_pipeline.name(), "IndicatorPipeline");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(rsi_of_ema_pipeline: IndicatorPipeline) {
+        assert_eq!(
+            format!("{rsi_of_ema_pipeline}"),
+            "IndicatorPipeline(ExponentialMovingAverage(3) -> RelativeStrengthIndex(3,EXPONENTIAL))"
+        );
+    }
+
+    #[rstest]
+    #[should_panic(expected = "config must have at least one stage")]
+    fn test_new_without_stages_panics() {
+        let _ =
+            IndicatorPipeline::new(&IndicatorPipelineConfig::new(PipelineSource::Close, vec![]));
+    }
+
+    #[rstest]
+    fn test_config_deserializes_from_json() {
+        let json = r#"{
+            "source": "TYPICAL",
+            "stages": [
+                {"type": "MovingAverage", "ma_type": "EXPONENTIAL", "period": 10},
+                {"type": "ZScore", "period": 20}
+            ]
+        }"#;
+
+        let config: IndicatorPipelineConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.source, PipelineSource::Typical);
+        assert_eq!(
+            config.stages,
+            vec![
+                PipelineStageConfig::MovingAverage {
+                    ma_type: MovingAverageType::Exponential,
+                    period: 10,
+                },
+                PipelineStageConfig::ZScore { period: 20 },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(rsi_of_ema_pipeline: IndicatorPipeline) {
+        assert!(!rsi_of_ema_pipeline.initialized());
+        assert!(!rsi_of_ema_pipeline.has_inputs());
+        assert_eq!(rsi_of_ema_pipeline.len(), 2);
+    }
+
+    #[rstest]
+    fn test_downstream_stage_waits_for_upstream_initialization(
+        mut rsi_of_ema_pipeline: IndicatorPipeline,
+    ) {
+        rsi_of_ema_pipeline.update_raw(1.0);
+        rsi_of_ema_pipeline.update_raw(2.0);
+
+        assert!(rsi_of_ema_pipeline.has_inputs());
+        assert!(!rsi_of_ema_pipeline.initialized());
+        assert_eq!(rsi_of_ema_pipeline.stage_values()[1], 0.0);
+    }
+
+    #[rstest]
+    fn test_pipeline_matches_manual_chain(mut rsi_of_ema_pipeline: IndicatorPipeline) {
+        let mut ema = ExponentialMovingAverage::new(3, None);
+        let mut rsi = RelativeStrengthIndex::new(3, None);
+
+        for i in 0..10 {
+            let value = f64::from(i % 4) + f64::from(i) * 0.5;
+            rsi_of_ema_pipeline.update_raw(value);
+
+            ema.update_raw(value);
+            if ema.initialized() {
+                rsi.update_raw(ema.value());
+            }
+        }
+
+        assert!(rsi_of_ema_pipeline.initialized());
+        assert_eq!(rsi_of_ema_pipeline.value, rsi.value);
+        assert_eq!(
+            rsi_of_ema_pipeline.stage_values(),
+            vec![ema.value(), rsi.value]
+        );
+    }
+
+    #[rstest]
+    fn test_zscore_of_spread() {
+        let config = IndicatorPipelineConfig::new(
+            PipelineSource::Close,
+            vec![PipelineStageConfig::ZScore { period: 4 }],
+        );
+        let mut pipeline = IndicatorPipeline::new(&config);
+
+        for spread in [2.0, 4.0, 4.0, 6.0] {
+            pipeline.update_raw(spread);
+        }
+
+        assert!(pipeline.initialized());
+        assert!((pipeline.value - 2.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_source_extracts_bar_prices(bar_ethusdt_binance_minute_bid: Bar) {
+        assert_eq!(
+            PipelineSource::Open.extract(&bar_ethusdt_binance_minute_bid),
+            1500.0
+        );
+        assert_eq!(
+            PipelineSource::Median.extract(&bar_ethusdt_binance_minute_bid),
+            1522.5
+        );
+        assert!(
+            (PipelineSource::Typical.extract(&bar_ethusdt_binance_minute_bid) - 4567.0 / 3.0).abs()
+                < 1e-9
+        );
+    }
+
+    #[rstest]
+    fn test_warm_up(
+        mut rsi_of_ema_pipeline: IndicatorPipeline,
+        bar_ethusdt_binance_minute_bid: Bar,
+    ) {
+        let bars = vec![bar_ethusdt_binance_minute_bid; 6];
+
+        rsi_of_ema_pipeline.warm_up(&bars);
+
+        assert!(rsi_of_ema_pipeline.initialized());
+        assert_eq!(rsi_of_ema_pipeline.stage_values()[0], 1522.0);
+    }
+
+    #[rstest]
+    fn test_restore_state_resumes_identically(mut rsi_of_ema_pipeline: IndicatorPipeline) {
+        for i in 0..10 {
+            rsi_of_ema_pipeline.update_raw(f64::from(i % 4));
+        }
+        let state = rsi_of_ema_pipeline.snapshot_state().unwrap();
+
+        let config = IndicatorPipelineConfig::new(
+            PipelineSource::Close,
+            vec![
+                PipelineStageConfig::MovingAverage {
+                    ma_type: MovingAverageType::Exponential,
+                    period: 3,
+                },
+                PipelineStageConfig::RelativeStrengthIndex { period: 3 },
+            ],
+        );
+        let mut restored = IndicatorPipeline::new(&config);
+        restored.restore_state(&state).unwrap();
+        rsi_of_ema_pipeline.update_raw(2.5);
+        restored.update_raw(2.5);
+
+        assert!(restored.initialized());
+        assert_eq!(restored.value, rsi_of_ema_pipeline.value);
+    }
+
+    #[rstest]
+    fn test_snapshot_with_unsupported_stage_errors() {
+        let config = IndicatorPipelineConfig::new(
+            PipelineSource::Close,
+            vec![PipelineStageConfig::RateOfChange {
+                period: 3,
+                use_log: false,
+            }],
+        );
+        let pipeline = IndicatorPipeline::new(&config);
+
+        assert!(pipeline.snapshot_state().is_err());
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(
+        mut rsi_of_ema_pipeline: IndicatorPipeline,
+    ) {
+        for i in 0..10 {
+            rsi_of_ema_pipeline.update_raw(f64::from(i % 4));
+        }
+
+        rsi_of_ema_pipeline.reset();
+
+        assert!(!rsi_of_ema_pipeline.initialized());
+        assert!(!rsi_of_ema_pipeline.has_inputs());
+        assert_eq!(rsi_of_ema_pipeline.value, 0.0);
+        assert_eq!(rsi_of_ema_pipeline.stage_values(), vec![0.0, 0.0]);
+    }
+}
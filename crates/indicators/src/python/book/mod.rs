@@ -13,4 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod cancel_fill;
+pub mod depth_imbalance;
 pub mod imbalance;
+pub mod microprice;
+pub mod ofi;
+pub mod pressure;
+pub mod queue_age;
+pub mod slope;
+pub mod vpin;
+pub mod weighted_mid;
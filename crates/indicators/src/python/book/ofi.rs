@@ -0,0 +1,109 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{OrderBookDeltas, OrderBookDepth10, QuoteTick},
+    enums::BookType,
+    orderbook::OrderBook,
+};
+use pyo3::prelude::*;
+
+use crate::{book::ofi::OrderFlowImbalance, indicator::Indicator};
+
+#[pymethods]
+impl OrderFlowImbalance {
+    #[new]
+    #[pyo3(signature = (window, book_type=None))]
+    fn py_new(window: usize, book_type: Option<BookType>) -> Self {
+        Self::new(window, book_type)
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "window")]
+    const fn py_window(&self) -> usize {
+        self.window
+    }
+
+    #[getter]
+    #[pyo3(name = "book_type")]
+    const fn py_book_type(&self) -> BookType {
+        self.book_type
+    }
+
+    #[getter]
+    #[pyo3(name = "count")]
+    const fn py_count(&self) -> usize {
+        self.count
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    const fn py_value(&self) -> f64 {
+        self.value
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    const fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "handle_deltas")]
+    fn py_handle_deltas(&mut self, deltas: &OrderBookDeltas) {
+        self.handle_deltas(deltas);
+    }
+
+    #[pyo3(name = "handle_depth")]
+    fn py_handle_depth(&mut self, depth: &OrderBookDepth10) {
+        self.handle_depth(depth);
+    }
+
+    #[pyo3(name = "handle_book")]
+    fn py_handle_book(&mut self, book: &OrderBook) {
+        self.handle_book(book);
+    }
+
+    #[pyo3(name = "handle_quote")]
+    fn py_handle_quote(&mut self, quote: &QuoteTick) {
+        self.handle_quote(quote);
+    }
+
+    #[pyo3(name = "update")]
+    #[pyo3(signature = (best_bid=None, best_ask=None))]
+    fn py_update(&mut self, best_bid: Option<(f64, f64)>, best_ask: Option<(f64, f64)>) {
+        self.update(best_bid, best_ask);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
@@ -0,0 +1,88 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{data::TradeTick, enums::AggressorSide};
+use pyo3::prelude::*;
+
+use crate::{book::vpin::Vpin, indicator::Indicator};
+
+#[pymethods]
+impl Vpin {
+    #[new]
+    fn py_new(bucket_size: f64, window: usize) -> Self {
+        Self::new(bucket_size, window)
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "bucket_size")]
+    const fn py_bucket_size(&self) -> f64 {
+        self.bucket_size
+    }
+
+    #[getter]
+    #[pyo3(name = "window")]
+    const fn py_window(&self) -> usize {
+        self.window
+    }
+
+    #[getter]
+    #[pyo3(name = "count")]
+    const fn py_count(&self) -> usize {
+        self.count
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    const fn py_value(&self) -> f64 {
+        self.value
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    const fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "handle_trade")]
+    fn py_handle_trade(&mut self, trade: &TradeTick) {
+        self.handle_trade(trade);
+    }
+
+    #[pyo3(name = "update")]
+    fn py_update(&mut self, price: f64, size: f64, aggressor_side: AggressorSide) {
+        self.update(price, size, aggressor_side);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
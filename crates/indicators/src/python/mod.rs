@@ -44,11 +44,21 @@ pub fn indicators(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::average::wma::WeightedMovingAverage>()?;
 
     // Book
+    m.add_class::<crate::book::cancel_fill::BookCancelFillRatio>()?;
+    m.add_class::<crate::book::depth_imbalance::BookDepthImbalance>()?;
     m.add_class::<crate::book::imbalance::BookImbalanceRatio>()?;
+    m.add_class::<crate::book::microprice::MicroPrice>()?;
+    m.add_class::<crate::book::ofi::OrderFlowImbalance>()?;
+    m.add_class::<crate::book::pressure::BookPressure>()?;
+    m.add_class::<crate::book::queue_age::BookQueueAge>()?;
+    m.add_class::<crate::book::slope::BookSlope>()?;
+    m.add_class::<crate::book::vpin::Vpin>()?;
+    m.add_class::<crate::book::weighted_mid::WeightedMidPrice>()?;
 
     // Ratio
     m.add_class::<crate::ratio::efficiency_ratio::EfficiencyRatio>()?;
     m.add_class::<crate::ratio::spread_analyzer::SpreadAnalyzer>()?;
+    m.add_class::<crate::ratio::zscore::ZScore>()?;
 
     // Momentum
     m.add_class::<crate::momentum::rsi::RelativeStrengthIndex>()?;
@@ -68,6 +78,12 @@ pub fn indicators(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::momentum::roc::RateOfChange>()?;
     m.add_class::<crate::momentum::macd::MovingAverageConvergenceDivergence>()?;
     m.add_class::<crate::momentum::obv::OnBalanceVolume>()?;
+    m.add_class::<crate::momentum::adx::AverageDirectionalIndex>()?;
+    m.add_class::<crate::momentum::ichimoku::IchimokuCloud>()?;
+    m.add_class::<crate::momentum::supertrend::SuperTrend>()?;
+    m.add_class::<crate::momentum::psar::ParabolicSar>()?;
+    m.add_class::<crate::momentum::fisher::FisherTransform>()?;
+    m.add_class::<crate::momentum::hurst::HurstExponent>()?;
 
     // Volatility
     m.add_class::<crate::volatility::atr::AverageTrueRange>()?;
@@ -77,5 +93,9 @@ pub fn indicators(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::volatility::kc::KeltnerChannel>()?;
     m.add_class::<crate::volatility::fuzzy::FuzzyCandlesticks>()?;
     m.add_class::<crate::volatility::kp::KeltnerPosition>()?;
+    m.add_class::<crate::volatility::chandelier::ChandelierExit>()?;
+    m.add_class::<crate::volatility::parkinson::ParkinsonVolatility>()?;
+    m.add_class::<crate::volatility::garman_klass::GarmanKlassVolatility>()?;
+    m.add_class::<crate::volatility::yang_zhang::YangZhangVolatility>()?;
     Ok(())
 }
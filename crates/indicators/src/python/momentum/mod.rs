@@ -13,6 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod adx;
 pub mod amat;
 pub mod aroon;
 pub mod bb;
@@ -20,13 +21,18 @@ pub mod bias;
 pub mod cci;
 pub mod cmo;
 pub mod dm;
+pub mod fisher;
+pub mod hurst;
+pub mod ichimoku;
 pub mod kvo;
 pub mod macd;
 pub mod obv;
 pub mod pressure;
+pub mod psar;
 pub mod psl;
 pub mod roc;
 pub mod rsi;
 pub mod stochastics;
+pub mod supertrend;
 pub mod swings;
 pub mod vhf;
@@ -0,0 +1,111 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::data::Bar;
+use pyo3::prelude::*;
+
+use crate::{indicator::Indicator, momentum::psar::ParabolicSar};
+
+#[pymethods]
+impl ParabolicSar {
+    #[new]
+    #[pyo3(signature = (af_start=None, af_step=None, af_max=None))]
+    #[must_use]
+    pub fn py_new(af_start: Option<f64>, af_step: Option<f64>, af_max: Option<f64>) -> Self {
+        Self::new(af_start, af_step, af_max)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ParabolicSar({},{},{})",
+            self.af_start, self.af_step, self.af_max
+        )
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "af_start")]
+    const fn py_af_start(&self) -> f64 {
+        self.af_start
+    }
+
+    #[getter]
+    #[pyo3(name = "af_step")]
+    const fn py_af_step(&self) -> f64 {
+        self.af_step
+    }
+
+    #[getter]
+    #[pyo3(name = "af_max")]
+    const fn py_af_max(&self) -> f64 {
+        self.af_max
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    const fn py_value(&self) -> f64 {
+        self.value
+    }
+
+    #[getter]
+    #[pyo3(name = "direction")]
+    const fn py_direction(&self) -> i8 {
+        self.direction
+    }
+
+    #[getter]
+    #[pyo3(name = "extreme_point")]
+    const fn py_extreme_point(&self) -> f64 {
+        self.extreme_point
+    }
+
+    #[getter]
+    #[pyo3(name = "acceleration")]
+    const fn py_acceleration(&self) -> f64 {
+        self.acceleration
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    const fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "update_raw")]
+    fn py_update_raw(&mut self, high: f64, low: f64) {
+        self.update_raw(high, low);
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn py_handle_bar(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
@@ -15,3 +15,4 @@
 
 pub mod efficiency_ratio;
 pub mod spread_analyzer;
+pub mod zscore;
@@ -14,9 +14,13 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod atr;
+pub mod chandelier;
 pub mod dc;
 pub mod fuzzy;
+pub mod garman_klass;
 pub mod kc;
 pub mod kp;
+pub mod parkinson;
 pub mod rvi;
 pub mod vr;
+pub mod yang_zhang;
@@ -17,3 +17,4 @@
 
 pub mod efficiency_ratio;
 pub mod spread_analyzer;
+pub mod zscore;
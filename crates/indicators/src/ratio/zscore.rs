@@ -0,0 +1,286 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use nautilus_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    indicator::Indicator,
+    state::{check_state_period, decode_state, encode_state},
+};
+
+/// An indicator which calculates the rolling z-score of its inputs.
+///
+/// The value is the number of (population) standard deviations the latest input lies from the
+/// mean of the last `period` inputs, and is zero when the window has no dispersion.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct ZScore {
+    pub period: usize,
+    pub mean: f64,
+    pub std: f64,
+    pub value: f64,
+    pub initialized: bool,
+    has_inputs: bool,
+    inputs: VecDeque<f64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ZScoreState {
+    period: usize,
+    mean: f64,
+    std: f64,
+    value: f64,
+    initialized: bool,
+    has_inputs: bool,
+    inputs: Vec<f64>,
+}
+
+impl Display for ZScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for ZScore {
+    fn name(&self) -> String {
+        stringify!(ZScore).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(PriceType::Mid).into());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.inputs.clear();
+        self.mean = 0.0;
+        self.std = 0.0;
+        self.value = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+
+    fn snapshot_state(&self) -> anyhow::Result<Vec<u8>> {
+        encode_state(&ZScoreState {
+            period: self.period,
+            mean: self.mean,
+            std: self.std,
+            value: self.value,
+            initialized: self.initialized,
+            has_inputs: self.has_inputs,
+            inputs: self.inputs.iter().copied().collect(),
+        })
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> anyhow::Result<()> {
+        let state: ZScoreState = decode_state(&self.name(), state)?;
+        check_state_period(&self.name(), self.period, state.period)?;
+
+        self.inputs = state.inputs.into();
+        self.mean = state.mean;
+        self.std = state.std;
+        self.value = state.value;
+        self.initialized = state.initialized;
+        self.has_inputs = state.has_inputs;
+        Ok(())
+    }
+}
+
+impl ZScore {
+    /// Creates a new [`ZScore`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "ZScore: period must be > 0");
+
+        Self {
+            period,
+            mean: 0.0,
+            std: 0.0,
+            value: 0.0,
+            initialized: false,
+            has_inputs: false,
+            inputs: VecDeque::with_capacity(period),
+        }
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        if self.inputs.len() == self.period {
+            self.inputs.pop_front();
+        }
+        self.inputs.push_back(value);
+
+        let n = self.inputs.len() as f64;
+        self.mean = self.inputs.iter().sum::<f64>() / n;
+        let variance = self
+            .inputs
+            .iter()
+            .map(|x| (x - self.mean).powi(2))
+            .sum::<f64>()
+            / n;
+        self.std = variance.sqrt();
+
+        self.value = if self.std > 0.0 {
+            (value - self.mean) / self.std
+        } else {
+            0.0
+        };
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.inputs.len() >= self.period {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, stub_quote, zscore_4};
+
+    #[rstest]
+    fn test_name_returns_expected_string(zscore_4: ZScore) {
+        assert_eq!(zscore_4.name(), "ZScore");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(zscore_4: ZScore) {
+        assert_eq!(format!("{zscore_4}"), "ZScore(4)");
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(zscore_4: ZScore) {
+        assert!(!zscore_4.initialized());
+        assert!(!zscore_4.has_inputs());
+    }
+
+    #[rstest]
+    fn test_value_with_constant_inputs_is_zero(mut zscore_4: ZScore) {
+        for _ in 0..4 {
+            zscore_4.update_raw(5.0);
+        }
+
+        assert!(zscore_4.initialized());
+        assert_eq!(zscore_4.value, 0.0);
+        assert_eq!(zscore_4.std, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_known_window(mut zscore_4: ZScore) {
+        for value in [2.0, 4.0, 4.0, 6.0] {
+            zscore_4.update_raw(value);
+        }
+
+        // Mean 4.0, population std sqrt(2)
+        assert_eq!(zscore_4.mean, 4.0);
+        assert!((zscore_4.value - 2.0 / 2.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_window_rolls(mut zscore_4: ZScore) {
+        for value in [100.0, 2.0, 4.0, 4.0, 6.0] {
+            zscore_4.update_raw(value);
+        }
+
+        assert_eq!(zscore_4.inputs.len(), 4);
+        assert_eq!(zscore_4.mean, 4.0);
+    }
+
+    #[rstest]
+    fn test_handle_quote(mut zscore_4: ZScore, stub_quote: QuoteTick) {
+        zscore_4.handle_quote(&stub_quote);
+
+        assert!(zscore_4.has_inputs());
+        assert_eq!(zscore_4.mean, 1501.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut zscore_4: ZScore, bar_ethusdt_binance_minute_bid: Bar) {
+        zscore_4.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(zscore_4.has_inputs());
+        assert_eq!(zscore_4.mean, 1522.0);
+    }
+
+    #[rstest]
+    fn test_restore_state_resumes_identically(mut zscore_4: ZScore) {
+        for value in [2.0, 4.0, 4.0, 6.0] {
+            zscore_4.update_raw(value);
+        }
+        let state = zscore_4.snapshot_state().unwrap();
+
+        let mut restored = ZScore::new(4);
+        restored.restore_state(&state).unwrap();
+        zscore_4.update_raw(8.0);
+        restored.update_raw(8.0);
+
+        assert!(restored.initialized());
+        assert_eq!(restored.value, zscore_4.value);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut zscore_4: ZScore) {
+        for value in [2.0, 4.0, 4.0, 6.0] {
+            zscore_4.update_raw(value);
+        }
+
+        zscore_4.reset();
+
+        assert!(!zscore_4.initialized());
+        assert!(!zscore_4.has_inputs());
+        assert_eq!(zscore_4.value, 0.0);
+        assert_eq!(zscore_4.mean, 0.0);
+        assert!(zscore_4.inputs.is_empty());
+    }
+}
@@ -0,0 +1,50 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Helpers for snapshotting and restoring indicator state.
+
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Encodes the given indicator `state` as bytes.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn encode_state<T: Serialize>(state: &T) -> anyhow::Result<Vec<u8>> {
+    Ok(serde_json::to_vec(state)?)
+}
+
+/// Decodes an indicator state of type `T` from the given `bytes`.
+///
+/// # Errors
+///
+/// Returns an error if deserialization fails.
+pub fn decode_state<T: DeserializeOwned>(name: &str, bytes: &[u8]) -> anyhow::Result<T> {
+    serde_json::from_slice(bytes)
+        .map_err(|e| anyhow::anyhow!("Failed to decode `{name}` state: {e}"))
+}
+
+/// Checks a snapshot `period` matches the `expected` period of the indicator being restored.
+///
+/// # Errors
+///
+/// Returns an error if the periods differ.
+pub fn check_state_period(name: &str, expected: usize, period: usize) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        period == expected,
+        "Cannot restore `{name}` state: snapshot period {period} does not match {expected}"
+    );
+    Ok(())
+}
@@ -31,17 +31,22 @@ use crate::{
         vwap::VolumeWeightedAveragePrice, wma::WeightedMovingAverage,
     },
     momentum::{
-        amat::ArcherMovingAveragesTrends, bb::BollingerBands, bias::Bias,
-        cci::CommodityChannelIndex, cmo::ChandeMomentumOscillator, dm::DirectionalMovement,
-        kvo::KlingerVolumeOscillator, macd::MovingAverageConvergenceDivergence,
-        obv::OnBalanceVolume, pressure::Pressure, psl::PsychologicalLine, roc::RateOfChange,
-        rsi::RelativeStrengthIndex, stochastics::Stochastics, swings::Swings,
+        adx::AverageDirectionalIndex, amat::ArcherMovingAveragesTrends, bb::BollingerBands,
+        bias::Bias, cci::CommodityChannelIndex, cmo::ChandeMomentumOscillator,
+        dm::DirectionalMovement, fisher::FisherTransform, hurst::HurstExponent,
+        ichimoku::IchimokuCloud, kvo::KlingerVolumeOscillator,
+        macd::MovingAverageConvergenceDivergence, obv::OnBalanceVolume, pressure::Pressure,
+        psar::ParabolicSar, psl::PsychologicalLine, roc::RateOfChange, rsi::RelativeStrengthIndex,
+        stochastics::Stochastics, supertrend::SuperTrend, swings::Swings,
         vhf::VerticalHorizontalFilter,
     },
-    ratio::{efficiency_ratio::EfficiencyRatio, spread_analyzer::SpreadAnalyzer},
+    pipeline::{IndicatorPipeline, IndicatorPipelineConfig, PipelineSource, PipelineStageConfig},
+    ratio::{efficiency_ratio::EfficiencyRatio, spread_analyzer::SpreadAnalyzer, zscore::ZScore},
     volatility::{
-        dc::DonchianChannel, fuzzy::FuzzyCandlesticks, kc::KeltnerChannel, kp::KeltnerPosition,
-        rvi::RelativeVolatilityIndex, vr::VolatilityRatio,
+        chandelier::ChandelierExit, dc::DonchianChannel, fuzzy::FuzzyCandlesticks,
+        garman_klass::GarmanKlassVolatility, kc::KeltnerChannel, kp::KeltnerPosition,
+        parkinson::ParkinsonVolatility, rvi::RelativeVolatilityIndex, vr::VolatilityRatio,
+        yang_zhang::YangZhangVolatility,
     },
 };
 
@@ -168,6 +173,11 @@ pub fn spread_analyzer_10() -> SpreadAnalyzer {
     SpreadAnalyzer::new(10, InstrumentId::from("ETHUSDT-PERP.BINANCE"))
 }
 
+#[fixture]
+pub fn zscore_4() -> ZScore {
+    ZScore::new(4)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Momentum
 ////////////////////////////////////////////////////////////////////////////////
@@ -251,6 +261,36 @@ pub fn obv_10() -> OnBalanceVolume {
     OnBalanceVolume::new(10)
 }
 
+#[fixture]
+pub fn adx_10() -> AverageDirectionalIndex {
+    AverageDirectionalIndex::new(10, Some(MovingAverageType::Simple))
+}
+
+#[fixture]
+pub fn ichimoku_3_5_8_4() -> IchimokuCloud {
+    IchimokuCloud::new(Some(3), Some(5), Some(8), Some(4))
+}
+
+#[fixture]
+pub fn supertrend_3() -> SuperTrend {
+    SuperTrend::new(3, 2.0)
+}
+
+#[fixture]
+pub fn psar() -> ParabolicSar {
+    ParabolicSar::new(None, None, None)
+}
+
+#[fixture]
+pub fn fisher_10() -> FisherTransform {
+    FisherTransform::new(10)
+}
+
+#[fixture]
+pub fn hurst_100_10() -> HurstExponent {
+    HurstExponent::new(100, 10)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Volatility
 ////////////////////////////////////////////////////////////////////////////////
@@ -308,3 +348,40 @@ pub fn roc_10() -> RateOfChange {
 pub fn fuzzy_candlesticks_10() -> FuzzyCandlesticks {
     FuzzyCandlesticks::new(10, 0.1, 0.15, 0.2, 0.3)
 }
+
+#[fixture]
+pub fn chandelier_3() -> ChandelierExit {
+    ChandelierExit::new(3, 3.0)
+}
+
+#[fixture]
+pub fn parkinson_10() -> ParkinsonVolatility {
+    ParkinsonVolatility::new(10)
+}
+
+#[fixture]
+pub fn gk_10() -> GarmanKlassVolatility {
+    GarmanKlassVolatility::new(10)
+}
+
+#[fixture]
+pub fn yz_10() -> YangZhangVolatility {
+    YangZhangVolatility::new(10)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Pipelines
+////////////////////////////////////////////////////////////////////////////////
+#[fixture]
+pub fn rsi_of_ema_pipeline() -> IndicatorPipeline {
+    IndicatorPipeline::new(&IndicatorPipelineConfig::new(
+        PipelineSource::Close,
+        vec![
+            PipelineStageConfig::MovingAverage {
+                ma_type: MovingAverageType::Exponential,
+                period: 3,
+            },
+            PipelineStageConfig::RelativeStrengthIndex { period: 3 },
+        ],
+    ))
+}
@@ -17,11 +17,25 @@ use std::fmt::{Debug, Display};
 
 use nautilus_model::data::Bar;
 
+use serde::{Deserialize, Serialize};
+
 use crate::{
     average::{MovingAverageFactory, MovingAverageType},
     indicator::{Indicator, MovingAverage},
+    state::{check_state_period, decode_state, encode_state},
 };
 
+#[derive(Serialize, Deserialize)]
+struct AverageTrueRangeState {
+    period: usize,
+    value: f64,
+    count: usize,
+    has_inputs: bool,
+    initialized: bool,
+    previous_close: f64,
+    ma: Vec<u8>,
+}
+
 /// An indicator which calculates a Average True Range (ATR) across a rolling window.
 #[repr(C)]
 #[derive(Debug)]
@@ -74,12 +88,38 @@ impl Indicator for AverageTrueRange {
     }
 
     fn reset(&mut self) {
+        self.ma.reset();
         self.previous_close = 0.0;
         self.value = 0.0;
         self.count = 0;
         self.has_inputs = false;
         self.initialized = false;
     }
+
+    fn snapshot_state(&self) -> anyhow::Result<Vec<u8>> {
+        encode_state(&AverageTrueRangeState {
+            period: self.period,
+            value: self.value,
+            count: self.count,
+            has_inputs: self.has_inputs,
+            initialized: self.initialized,
+            previous_close: self.previous_close,
+            ma: self.ma.snapshot_state()?,
+        })
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> anyhow::Result<()> {
+        let state: AverageTrueRangeState = decode_state(&self.name(), state)?;
+        check_state_period(&self.name(), self.period, state.period)?;
+
+        self.ma.restore_state(&state.ma)?;
+        self.value = state.value;
+        self.count = state.count;
+        self.has_inputs = state.has_inputs;
+        self.initialized = state.initialized;
+        self.previous_close = state.previous_close;
+        Ok(())
+    }
 }
 
 impl AverageTrueRange {
@@ -287,4 +327,23 @@ mod tests {
         assert!(!atr.initialized);
         assert_eq!(atr.value, 0.0);
     }
+
+    #[rstest]
+    fn test_restore_state_resumes_identically() {
+        let mut atr = AverageTrueRange::new(10, Some(MovingAverageType::Simple), None, None);
+        for i in 0..12 {
+            let close = f64::from(i);
+            atr.update_raw(close + 1.5, close - 0.5, close);
+        }
+        let state = atr.snapshot_state().unwrap();
+
+        let mut restored = AverageTrueRange::new(10, Some(MovingAverageType::Simple), None, None);
+        restored.restore_state(&state).unwrap();
+        atr.update_raw(20.0, 5.0, 10.0);
+        restored.update_raw(20.0, 5.0, 10.0);
+
+        assert!(restored.initialized);
+        assert_eq!(restored.count, atr.count);
+        assert_eq!(restored.value, atr.value);
+    }
 }
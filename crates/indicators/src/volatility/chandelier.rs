@@ -0,0 +1,209 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use nautilus_model::data::Bar;
+
+use crate::{average::MovingAverageType, indicator::Indicator, volatility::atr::AverageTrueRange};
+
+/// An indicator which calculates the Chandelier Exit trailing stop levels.
+///
+/// The long stop hangs `multiplier` ATRs below the highest high of the last `period` bars,
+/// and the short stop sits `multiplier` ATRs above the lowest low.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators", unsendable)
+)]
+pub struct ChandelierExit {
+    pub period: usize,
+    pub multiplier: f64,
+    pub long_stop: f64,
+    pub short_stop: f64,
+    pub initialized: bool,
+    has_inputs: bool,
+    atr: AverageTrueRange,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+}
+
+impl Display for ChandelierExit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.multiplier)
+    }
+}
+
+impl Indicator for ChandelierExit {
+    fn name(&self) -> String {
+        stringify!(ChandelierExit).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into(), (&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.highs.clear();
+        self.lows.clear();
+        self.long_stop = 0.0;
+        self.short_stop = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl ChandelierExit {
+    /// Creates a new [`ChandelierExit`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` or `multiplier` is not positive.
+    #[must_use]
+    pub fn new(period: usize, multiplier: f64) -> Self {
+        assert!(period > 0, "ChandelierExit: period must be > 0");
+        assert!(multiplier > 0.0, "ChandelierExit: multiplier must be > 0");
+
+        Self {
+            period,
+            multiplier,
+            long_stop: 0.0,
+            short_stop: 0.0,
+            initialized: false,
+            has_inputs: false,
+            atr: AverageTrueRange::new(period, Some(MovingAverageType::Simple), Some(true), None),
+            highs: VecDeque::with_capacity(period),
+            lows: VecDeque::with_capacity(period),
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        self.has_inputs = true;
+        self.atr.update_raw(high, low, close);
+
+        if self.highs.len() == self.period {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        self.highs.push_back(high);
+        self.lows.push_back(low);
+
+        let highest = self.highs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let lowest = self.lows.iter().copied().fold(f64::INFINITY, f64::min);
+        let offset = self.multiplier * self.atr.value;
+
+        self.long_stop = highest - offset;
+        self.short_stop = lowest + offset;
+
+        if !self.initialized && self.atr.initialized && self.highs.len() >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, chandelier_3};
+
+    #[rstest]
+    fn test_name_returns_expected_string(chandelier_3: ChandelierExit) {
+        assert_eq!(chandelier_3.name(), "ChandelierExit");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(chandelier_3: ChandelierExit) {
+        assert_eq!(format!("{chandelier_3}"), "ChandelierExit(3,3)");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "multiplier must be > 0")]
+    fn test_new_with_zero_multiplier_panics() {
+        let _ = ChandelierExit::new(3, 0.0);
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(chandelier_3: ChandelierExit) {
+        assert!(!chandelier_3.initialized());
+        assert!(!chandelier_3.has_inputs());
+    }
+
+    #[rstest]
+    fn test_stops_with_constant_range(mut chandelier_3: ChandelierExit) {
+        for _ in 0..3 {
+            chandelier_3.update_raw(11.0, 9.0, 10.0);
+        }
+
+        assert!(chandelier_3.initialized());
+        assert_eq!(chandelier_3.long_stop, 5.0);
+        assert_eq!(chandelier_3.short_stop, 15.0);
+    }
+
+    #[rstest]
+    fn test_stops_use_rolling_extremes(mut chandelier_3: ChandelierExit) {
+        chandelier_3.update_raw(20.0, 18.0, 19.0);
+        for _ in 0..3 {
+            chandelier_3.update_raw(11.0, 9.0, 10.0);
+        }
+
+        // The 20.0 high has rolled out of the window
+        assert_eq!(chandelier_3.long_stop, 11.0 - 3.0 * chandelier_3.atr.value);
+        assert_eq!(chandelier_3.short_stop, 9.0 + 3.0 * chandelier_3.atr.value);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut chandelier_3: ChandelierExit, bar_ethusdt_binance_minute_bid: Bar) {
+        chandelier_3.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(chandelier_3.has_inputs());
+        assert!(!chandelier_3.initialized());
+        assert!(chandelier_3.long_stop < 1550.0);
+        assert!(chandelier_3.short_stop > 1495.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut chandelier_3: ChandelierExit) {
+        for _ in 0..3 {
+            chandelier_3.update_raw(11.0, 9.0, 10.0);
+        }
+
+        chandelier_3.reset();
+
+        assert!(!chandelier_3.initialized());
+        assert!(!chandelier_3.has_inputs());
+        assert_eq!(chandelier_3.long_stop, 0.0);
+        assert_eq!(chandelier_3.short_stop, 0.0);
+        assert!(chandelier_3.highs.is_empty());
+        assert!(!chandelier_3.atr.initialized);
+    }
+}
@@ -0,0 +1,206 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    f64::consts::LN_2,
+    fmt::{Debug, Display},
+};
+
+use nautilus_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the Garman-Klass (OHLC) realized volatility estimator.
+///
+/// The value is the per-bar volatility (standard deviation of log returns) estimated over the
+/// rolling `period`, and is not annualized.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct GarmanKlassVolatility {
+    pub period: usize,
+    pub value: f64,
+    pub initialized: bool,
+    has_inputs: bool,
+    terms: VecDeque<f64>,
+    sum: f64,
+}
+
+impl Display for GarmanKlassVolatility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for GarmanKlassVolatility {
+    fn name(&self) -> String {
+        stringify!(GarmanKlassVolatility).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.open).into(),
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.terms.clear();
+        self.sum = 0.0;
+        self.value = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl GarmanKlassVolatility {
+    /// Creates a new [`GarmanKlassVolatility`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "GarmanKlassVolatility: period must be > 0");
+
+        Self {
+            period,
+            value: 0.0,
+            initialized: false,
+            has_inputs: false,
+            terms: VecDeque::with_capacity(period),
+            sum: 0.0,
+        }
+    }
+
+    pub fn update_raw(&mut self, open: f64, high: f64, low: f64, close: f64) {
+        if open <= 0.0 || high <= 0.0 || low <= 0.0 || close <= 0.0 {
+            return; // Log prices undefined
+        }
+
+        let log_hl = (high / low).ln();
+        let log_co = (close / open).ln();
+        let term = 0.5 * log_hl.powi(2) - (2.0 * LN_2 - 1.0) * log_co.powi(2);
+
+        if self.terms.len() == self.period {
+            self.sum -= self.terms.pop_front().unwrap_or(0.0);
+        }
+        self.terms.push_back(term);
+        self.sum += term;
+
+        let variance = self.sum / self.terms.len() as f64;
+        self.value = variance.max(0.0).sqrt();
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.terms.len() >= self.period {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::E;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, gk_10};
+
+    #[rstest]
+    fn test_name_returns_expected_string(gk_10: GarmanKlassVolatility) {
+        assert_eq!(gk_10.name(), "GarmanKlassVolatility");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(gk_10: GarmanKlassVolatility) {
+        assert_eq!(format!("{gk_10}"), "GarmanKlassVolatility(10)");
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(gk_10: GarmanKlassVolatility) {
+        assert!(!gk_10.initialized());
+        assert!(!gk_10.has_inputs());
+    }
+
+    #[rstest]
+    fn test_value_with_unchanged_close(mut gk_10: GarmanKlassVolatility) {
+        for _ in 0..10 {
+            gk_10.update_raw(1.0, E, 1.0, 1.0);
+        }
+
+        assert!(gk_10.initialized());
+        assert!((gk_10.value - 0.5_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_value_with_full_range_move(mut gk_10: GarmanKlassVolatility) {
+        gk_10.update_raw(1.0, E, 1.0, E);
+
+        // 0.5 - (2 ln 2 - 1)
+        let expected = (0.5 - (2.0 * LN_2 - 1.0)).sqrt();
+        assert!((gk_10.value - expected).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_non_positive_input_is_ignored(mut gk_10: GarmanKlassVolatility) {
+        gk_10.update_raw(0.0, 1.0, 1.0, 1.0);
+
+        assert!(!gk_10.has_inputs());
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut gk_10: GarmanKlassVolatility, bar_ethusdt_binance_minute_bid: Bar) {
+        gk_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(gk_10.has_inputs());
+        assert!(gk_10.value > 0.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut gk_10: GarmanKlassVolatility) {
+        for _ in 0..10 {
+            gk_10.update_raw(1.0, E, 1.0, 1.0);
+        }
+
+        gk_10.reset();
+
+        assert!(!gk_10.initialized());
+        assert!(!gk_10.has_inputs());
+        assert_eq!(gk_10.value, 0.0);
+        assert_eq!(gk_10.sum, 0.0);
+        assert!(gk_10.terms.is_empty());
+    }
+}
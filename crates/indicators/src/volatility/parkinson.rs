@@ -0,0 +1,200 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    f64::consts::LN_2,
+    fmt::{Debug, Display},
+};
+
+use nautilus_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the Parkinson (high-low range) realized volatility estimator.
+///
+/// The value is the per-bar volatility (standard deviation of log returns) estimated over the
+/// rolling `period`, and is not annualized.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct ParkinsonVolatility {
+    pub period: usize,
+    pub value: f64,
+    pub initialized: bool,
+    has_inputs: bool,
+    terms: VecDeque<f64>,
+    sum: f64,
+}
+
+impl Display for ParkinsonVolatility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for ParkinsonVolatility {
+    fn name(&self) -> String {
+        stringify!(ParkinsonVolatility).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.high).into(), (&bar.low).into());
+    }
+
+    fn reset(&mut self) {
+        self.terms.clear();
+        self.sum = 0.0;
+        self.value = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl ParkinsonVolatility {
+    /// Creates a new [`ParkinsonVolatility`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "ParkinsonVolatility: period must be > 0");
+
+        Self {
+            period,
+            value: 0.0,
+            initialized: false,
+            has_inputs: false,
+            terms: VecDeque::with_capacity(period),
+            sum: 0.0,
+        }
+    }
+
+    pub fn update_raw(&mut self, high: f64, low: f64) {
+        if high <= 0.0 || low <= 0.0 {
+            return; // Log range undefined
+        }
+
+        let term = (high / low).ln().powi(2);
+
+        if self.terms.len() == self.period {
+            self.sum -= self.terms.pop_front().unwrap_or(0.0);
+        }
+        self.terms.push_back(term);
+        self.sum += term;
+
+        let variance = self.sum / (4.0 * LN_2 * self.terms.len() as f64);
+        self.value = variance.max(0.0).sqrt();
+
+        // Initialization logic
+        if !self.initialized {
+            self.has_inputs = true;
+            if self.terms.len() >= self.period {
+                self.initialized = true;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::E;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, parkinson_10};
+
+    #[rstest]
+    fn test_name_returns_expected_string(parkinson_10: ParkinsonVolatility) {
+        assert_eq!(parkinson_10.name(), "ParkinsonVolatility");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(parkinson_10: ParkinsonVolatility) {
+        assert_eq!(format!("{parkinson_10}"), "ParkinsonVolatility(10)");
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(parkinson_10: ParkinsonVolatility) {
+        assert!(!parkinson_10.initialized());
+        assert!(!parkinson_10.has_inputs());
+    }
+
+    #[rstest]
+    fn test_value_with_constant_range(mut parkinson_10: ParkinsonVolatility) {
+        for _ in 0..10 {
+            parkinson_10.update_raw(E, 1.0);
+        }
+
+        assert!(parkinson_10.initialized());
+        assert!((parkinson_10.value - (1.0 / (4.0 * LN_2)).sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_value_with_no_range_is_zero(mut parkinson_10: ParkinsonVolatility) {
+        parkinson_10.update_raw(1.0, 1.0);
+
+        assert!(parkinson_10.has_inputs());
+        assert_eq!(parkinson_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_non_positive_input_is_ignored(mut parkinson_10: ParkinsonVolatility) {
+        parkinson_10.update_raw(1.0, 0.0);
+
+        assert!(!parkinson_10.has_inputs());
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut parkinson_10: ParkinsonVolatility, bar_ethusdt_binance_minute_bid: Bar) {
+        parkinson_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        let expected = ((1550.0_f64 / 1495.0).ln().powi(2) / (4.0 * LN_2)).sqrt();
+        assert!((parkinson_10.value - expected).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(
+        mut parkinson_10: ParkinsonVolatility,
+    ) {
+        for _ in 0..10 {
+            parkinson_10.update_raw(E, 1.0);
+        }
+
+        parkinson_10.reset();
+
+        assert!(!parkinson_10.initialized());
+        assert!(!parkinson_10.has_inputs());
+        assert_eq!(parkinson_10.value, 0.0);
+        assert_eq!(parkinson_10.sum, 0.0);
+        assert!(parkinson_10.terms.is_empty());
+    }
+}
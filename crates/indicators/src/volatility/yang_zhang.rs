@@ -0,0 +1,254 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Display},
+};
+
+use nautilus_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the Yang-Zhang (OHLC with overnight gaps) realized volatility
+/// estimator.
+///
+/// Combines the overnight (previous close to open) variance, the open to close variance and the
+/// Rogers-Satchell variance using the minimum-variance weighting. The value is the per-bar
+/// volatility estimated over the rolling `period`, and is not annualized.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct YangZhangVolatility {
+    pub period: usize,
+    pub value: f64,
+    pub initialized: bool,
+    has_inputs: bool,
+    previous_close: f64,
+    overnight: VecDeque<f64>,
+    open_close: VecDeque<f64>,
+    rogers_satchell: VecDeque<f64>,
+}
+
+impl Display for YangZhangVolatility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for YangZhangVolatility {
+    fn name(&self) -> String {
+        stringify!(YangZhangVolatility).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(
+            (&bar.open).into(),
+            (&bar.high).into(),
+            (&bar.low).into(),
+            (&bar.close).into(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.overnight.clear();
+        self.open_close.clear();
+        self.rogers_satchell.clear();
+        self.previous_close = 0.0;
+        self.value = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl YangZhangVolatility {
+    /// Creates a new [`YangZhangVolatility`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is less than 2.
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 1, "YangZhangVolatility: period must be > 1");
+
+        Self {
+            period,
+            value: 0.0,
+            initialized: false,
+            has_inputs: false,
+            previous_close: 0.0,
+            overnight: VecDeque::with_capacity(period),
+            open_close: VecDeque::with_capacity(period),
+            rogers_satchell: VecDeque::with_capacity(period),
+        }
+    }
+
+    pub fn update_raw(&mut self, open: f64, high: f64, low: f64, close: f64) {
+        if open <= 0.0 || high <= 0.0 || low <= 0.0 || close <= 0.0 {
+            return; // Log prices undefined
+        }
+
+        if !self.has_inputs {
+            // The overnight return requires a previous close
+            self.has_inputs = true;
+            self.previous_close = close;
+            return;
+        }
+
+        if self.overnight.len() == self.period {
+            self.overnight.pop_front();
+            self.open_close.pop_front();
+            self.rogers_satchell.pop_front();
+        }
+
+        let log_ho = (high / open).ln();
+        let log_lo = (low / open).ln();
+        let log_co = (close / open).ln();
+
+        self.overnight.push_back((open / self.previous_close).ln());
+        self.open_close.push_back(log_co);
+        self.rogers_satchell
+            .push_back(log_ho * (log_ho - log_co) + log_lo * (log_lo - log_co));
+        self.previous_close = close;
+
+        let n = self.overnight.len();
+        if n < 2 {
+            return;
+        }
+
+        let n_f64 = n as f64;
+        let k = 0.34 / (1.34 + (n_f64 + 1.0) / (n_f64 - 1.0));
+        let rs_variance = self.rogers_satchell.iter().sum::<f64>() / n_f64;
+        let variance = sample_variance(&self.overnight)
+            + k * sample_variance(&self.open_close)
+            + (1.0 - k) * rs_variance;
+
+        self.value = variance.max(0.0).sqrt();
+
+        if !self.initialized && n >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+fn sample_variance(values: &VecDeque<f64>) -> f64 {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::E;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{bar_ethusdt_binance_minute_bid, yz_10};
+
+    #[rstest]
+    fn test_name_returns_expected_string(yz_10: YangZhangVolatility) {
+        assert_eq!(yz_10.name(), "YangZhangVolatility");
+    }
+
+    #[rstest]
+    fn test_str_repr_returns_expected_string(yz_10: YangZhangVolatility) {
+        assert_eq!(format!("{yz_10}"), "YangZhangVolatility(10)");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "period must be > 1")]
+    fn test_new_with_period_one_panics() {
+        let _ = YangZhangVolatility::new(1);
+    }
+
+    #[rstest]
+    fn test_initialized_without_inputs_returns_false(yz_10: YangZhangVolatility) {
+        assert!(!yz_10.initialized());
+        assert!(!yz_10.has_inputs());
+    }
+
+    #[rstest]
+    fn test_first_input_sets_previous_close_only(mut yz_10: YangZhangVolatility) {
+        yz_10.update_raw(1.0, E, 1.0, 1.0);
+
+        assert!(yz_10.has_inputs());
+        assert_eq!(yz_10.previous_close, 1.0);
+        assert!(yz_10.overnight.is_empty());
+        assert_eq!(yz_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_without_gaps_or_drift(mut yz_10: YangZhangVolatility) {
+        for _ in 0..11 {
+            yz_10.update_raw(1.0, E, 1.0, 1.0);
+        }
+
+        // Only the Rogers-Satchell component contributes (per-bar term = 1.0)
+        let k: f64 = 0.34 / (1.34 + 11.0 / 9.0);
+        assert!(yz_10.initialized());
+        assert!((yz_10.value - (1.0 - k).sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_overnight_gaps_increase_value(mut yz_10: YangZhangVolatility) {
+        for i in 0..11 {
+            let open = if i % 2 == 0 { 1.0 } else { E };
+            yz_10.update_raw(open, open * E, open, open);
+        }
+        let k: f64 = 0.34 / (1.34 + 11.0 / 9.0);
+
+        assert!(yz_10.value > (1.0 - k).sqrt());
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut yz_10: YangZhangVolatility, bar_ethusdt_binance_minute_bid: Bar) {
+        yz_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+
+        assert!(yz_10.has_inputs());
+        assert_eq!(yz_10.previous_close, 1522.0);
+    }
+
+    #[rstest]
+    fn test_reset_successfully_returns_indicator_to_fresh_state(mut yz_10: YangZhangVolatility) {
+        for _ in 0..11 {
+            yz_10.update_raw(1.0, E, 1.0, 1.0);
+        }
+
+        yz_10.reset();
+
+        assert!(!yz_10.initialized());
+        assert!(!yz_10.has_inputs());
+        assert_eq!(yz_10.value, 0.0);
+        assert_eq!(yz_10.previous_close, 0.0);
+        assert!(yz_10.overnight.is_empty());
+        assert!(yz_10.open_close.is_empty());
+        assert!(yz_10.rogers_satchell.is_empty());
+    }
+}
@@ -88,7 +88,8 @@ impl PostgresCacheDatabase {
     ///
     /// # Errors
     ///
-    /// Returns an error if establishing the database connection fails.
+    /// Returns an error if the connection options cannot be resolved or establishing the
+    /// database connection fails.
     ///
     /// # Panics
     ///
@@ -101,7 +102,8 @@ impl PostgresCacheDatabase {
         database: Option<String>,
     ) -> Result<Self, sqlx::Error> {
         let pg_connect_options =
-            get_postgres_connect_options(host, port, username, password, database);
+            get_postgres_connect_options(host, port, username, password, database)
+                .map_err(|e| sqlx::Error::Configuration(e.into()))?;
         let pool = connect_pg(pg_connect_options.clone().into()).await.unwrap();
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<DatabaseQuery>();
 
@@ -159,7 +161,7 @@ impl PostgresCacheDatabase {
 ///
 /// Returns an error if connecting to the database or initializing the cache adapter fails.
 pub async fn get_pg_cache_database() -> anyhow::Result<PostgresCacheDatabase> {
-    let connect_options = get_postgres_connect_options(None, None, None, None, None);
+    let connect_options = get_postgres_connect_options(None, None, None, None, None)?;
     Ok(PostgresCacheDatabase::connect(
         Some(connect_options.host),
         Some(connect_options.port),
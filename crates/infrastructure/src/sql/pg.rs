@@ -13,7 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use anyhow::Context;
 use derive_builder::Builder;
+use nautilus_cryptography::secrets::resolve_secret;
 use sqlx::{ConnectOptions, PgPool, postgres::PgConnectOptions};
 
 #[derive(Debug, Clone, Builder)]
@@ -95,28 +97,31 @@ impl From<PostgresConnectOptions> for PgConnectOptions {
 
 /// Constructs `PostgresConnectOptions` by merging provided arguments, environment variables, and defaults.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Panics if an environment variable for port cannot be parsed into a `u16`.
-#[must_use]
+/// Returns an error if:
+/// - The `POSTGRES_PORT` environment variable cannot be parsed into a `u16`.
+/// - The password references a secret which cannot be resolved.
 pub fn get_postgres_connect_options(
     host: Option<String>,
     port: Option<u16>,
     username: Option<String>,
     password: Option<String>,
     database: Option<String>,
-) -> PostgresConnectOptions {
+) -> anyhow::Result<PostgresConnectOptions> {
     let defaults = PostgresConnectOptions::default_administrator();
     let host = host
         .or_else(|| std::env::var("POSTGRES_HOST").ok())
         .unwrap_or(defaults.host);
-    let port = port
-        .or_else(|| {
-            std::env::var("POSTGRES_PORT")
-                .map(|port| port.parse::<u16>().unwrap())
-                .ok()
-        })
-        .unwrap_or(defaults.port);
+    let port = match port {
+        Some(port) => port,
+        None => match std::env::var("POSTGRES_PORT") {
+            Ok(port) => port
+                .parse::<u16>()
+                .with_context(|| format!("Invalid POSTGRES_PORT '{port}'"))?,
+            Err(_) => defaults.port,
+        },
+    };
     let username = username
         .or_else(|| std::env::var("POSTGRES_USERNAME").ok())
         .unwrap_or(defaults.username);
@@ -125,8 +130,13 @@ pub fn get_postgres_connect_options(
         .unwrap_or(defaults.database);
     let password = password
         .or_else(|| std::env::var("POSTGRES_PASSWORD").ok())
+        .map(|password| resolve_secret(&password))
+        .transpose()
+        .context("Failed to resolve Postgres password")?
         .unwrap_or(defaults.password);
-    PostgresConnectOptions::new(host, port, username, password, database)
+    Ok(PostgresConnectOptions::new(
+        host, port, username, password, database,
+    ))
 }
 
 /// Connects to a Postgres database with the provided connection `options` returning a connection pool.
@@ -22,6 +22,7 @@ use nautilus_common::{
     msgbus::database::MessageBusConfig,
 };
 use nautilus_core::UUID4;
+use nautilus_cryptography::secrets::SecretsConfig;
 use nautilus_data::engine::config::DataEngineConfig;
 use nautilus_execution::engine::config::ExecutionEngineConfig;
 use nautilus_model::identifiers::TraderId;
@@ -226,6 +227,8 @@ pub struct LiveNodeConfig {
     pub portfolio: Option<PortfolioConfig>,
     /// The configuration for streaming to feather files.
     pub streaming: Option<StreamingConfig>,
+    /// The configuration for resolving secret references in client configs.
+    pub secrets: Option<SecretsConfig>,
     /// The live data engine configuration.
     pub data_engine: LiveDataEngineConfig,
     /// The live risk engine configuration.
@@ -257,6 +260,7 @@ impl Default for LiveNodeConfig {
             msgbus: None,
             portfolio: None,
             streaming: None,
+            secrets: None,
             data_engine: LiveDataEngineConfig::default(),
             risk_engine: LiveRiskEngineConfig::default(),
             exec_engine: LiveExecEngineConfig::default(),
@@ -342,6 +346,10 @@ impl NautilusKernelConfig for LiveNodeConfig {
     fn streaming(&self) -> Option<nautilus_persistence::config::StreamingConfig> {
         self.streaming.clone()
     }
+
+    fn secrets(&self) -> Option<SecretsConfig> {
+        self.secrets.clone()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -17,7 +17,7 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc, time::Duration};
 
 use anyhow::Context;
 use nautilus_common::{
@@ -27,14 +27,16 @@ use nautilus_common::{
     enums::Environment,
 };
 use nautilus_core::UUID4;
+use nautilus_cryptography::secrets::SecretsConfig;
 use nautilus_data::client::DataClientAdapter;
 use nautilus_model::identifiers::TraderId;
+use nautilus_persistence::recorder::SessionRecorder;
 use nautilus_system::{
     config::NautilusKernelConfig,
     factories::{ClientConfig, DataClientFactory, ExecutionClientFactory},
     kernel::NautilusKernel,
 };
-use tokio::sync::mpsc::UnboundedSender;
+use tokio::{sync::mpsc::UnboundedSender, time::Interval};
 
 use crate::{config::LiveNodeConfig, runner::AsyncRunner};
 
@@ -49,6 +51,7 @@ pub struct LiveNode {
     runner: AsyncRunner,
     signal_tx: Option<UnboundedSender<()>>,
     config: LiveNodeConfig,
+    recorder: Option<SessionRecorder>,
     is_running: bool,
 }
 
@@ -90,6 +93,7 @@ impl LiveNode {
         let clock = Rc::new(RefCell::new(LiveClock::new()));
         let kernel = NautilusKernel::new(name, config.clone())?;
         let (runner, signal_tx) = AsyncRunner::new(clock.clone());
+        let recorder = build_recorder(&config, &kernel)?;
 
         log::info!("LiveNode built successfully with kernel config");
 
@@ -99,6 +103,7 @@ impl LiveNode {
             runner,
             signal_tx: Some(signal_tx),
             config,
+            recorder,
             is_running: false,
         })
     }
@@ -115,6 +120,11 @@ impl LiveNode {
 
         log::info!("Starting LiveNode");
 
+        if let Some(recorder) = &mut self.recorder {
+            recorder.clear_existing().await?;
+            recorder.start();
+        }
+
         self.kernel.start_async().await;
         self.is_running = true;
 
@@ -135,6 +145,12 @@ impl LiveNode {
         log::info!("Stopping LiveNode");
 
         self.kernel.stop_async().await;
+
+        if let Some(recorder) = &mut self.recorder {
+            recorder.stop();
+            recorder.flush().await?;
+        }
+
         self.is_running = false;
 
         log::info!("LiveNode stopped successfully");
@@ -154,33 +170,62 @@ impl LiveNode {
 
         self.start().await?;
 
-        tokio::select! {
-            // Run on main thread
-            _ = self.runner.run() => {
-                log::info!("AsyncRunner finished");
-            }
-            // Handle SIGINT signal
-            result = tokio::signal::ctrl_c() => {
-                match result {
-                    Ok(()) => {
-                        log::info!("Received SIGINT, shutting down");
-                        if let Err(e) = signal_tx.send(()) {
-                            log::error!("Failed to send shutdown signal: {e}");
+        self.run_until_shutdown(signal_tx).await;
+
+        log::debug!("AsyncRunner and signal handling finished"); // TODO: Temp logging
+
+        self.stop().await?;
+        Ok(())
+    }
+
+    /// Runs the [`AsyncRunner`] until it finishes or a SIGINT is received, periodically
+    /// flushing the session recorder (if configured).
+    async fn run_until_shutdown(&mut self, signal_tx: UnboundedSender<()>) {
+        let runner = self.runner.run();
+        let ctrl_c = tokio::signal::ctrl_c();
+        tokio::pin!(runner, ctrl_c);
+
+        let mut flush_interval = self
+            .recorder
+            .as_ref()
+            .map(SessionRecorder::flush_interval_ms)
+            .filter(|interval_ms| *interval_ms > 0)
+            .map(|interval_ms| tokio::time::interval(Duration::from_millis(interval_ms)));
+
+        loop {
+            tokio::select! {
+                // Run on main thread
+                _ = &mut runner => {
+                    log::info!("AsyncRunner finished");
+                    break;
+                }
+                // Handle SIGINT signal
+                result = &mut ctrl_c => {
+                    match result {
+                        Ok(()) => {
+                            log::info!("Received SIGINT, shutting down");
+                            if let Err(e) = signal_tx.send(()) {
+                                log::error!("Failed to send shutdown signal: {e}");
+                            }
+                            // Give the AsyncRunner a moment to process the shutdown signal
+                            tokio::time::sleep(Duration::from_millis(100)).await;
+                        }
+                        Err(e) => {
+                            log::error!("Failed to listen for SIGINT: {e}");
                         }
-                        // Give the AsyncRunner a moment to process the shutdown signal
-                        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
                     }
-                    Err(e) => {
-                        log::error!("Failed to listen for SIGINT: {e}");
+                    break;
+                }
+                // Periodically write recorded data
+                () = tick(flush_interval.as_mut()) => {
+                    if let Some(recorder) = &self.recorder {
+                        if let Err(e) = recorder.flush().await {
+                            log::error!("Failed to flush session recorder: {e}");
+                        }
                     }
                 }
             }
         }
-
-        log::debug!("AsyncRunner and signal handling finished"); // TODO: Temp logging
-
-        self.stop().await?;
-        Ok(())
     }
 
     /// Gets the node's environment.
@@ -348,6 +393,13 @@ impl LiveNodeBuilder {
         self
     }
 
+    /// Set the secrets configuration used to resolve secret references in client configs.
+    #[must_use]
+    pub fn with_secrets_config(mut self, config: SecretsConfig) -> Self {
+        self.config.secrets = Some(config);
+        self
+    }
+
     /// Adds a data client with both factory and configuration.
     ///
     /// # Errors
@@ -419,8 +471,9 @@ impl LiveNodeBuilder {
         );
 
         let clock = Rc::new(RefCell::new(LiveClock::new()));
-        let kernel = NautilusKernel::new("LiveNode".to_string(), self.config.clone())?;
+        let mut kernel = NautilusKernel::new("LiveNode".to_string(), self.config.clone())?;
         let (runner, signal_tx) = AsyncRunner::new(clock.clone());
+        let recorder = build_recorder(&self.config, &kernel)?;
 
         // Create and register data clients
         for (name, factory) in self.data_client_factories.into_iter() {
@@ -461,8 +514,13 @@ impl LiveNodeBuilder {
 
                 log::info!("Registering execution client '{name}' with execution engine");
 
-                // TODO: Implement when ExecutionEngine has a register_client method
-                // kernel.exec_engine().register_client(client);
+                let client_id = client.client_id();
+                kernel
+                    .exec_engine
+                    .borrow_mut()
+                    .register_client(Rc::from(client))?;
+
+                log::info!("Successfully registered execution client '{name}' ({client_id})");
             } else {
                 log::warn!("No config found for execution client factory '{name}'");
             }
@@ -476,11 +534,40 @@ impl LiveNodeBuilder {
             runner,
             signal_tx: Some(signal_tx),
             config: self.config,
+            recorder,
             is_running: false,
         })
     }
 }
 
+/// Builds the [`SessionRecorder`] for the streaming configuration of `config`, if any.
+fn build_recorder(
+    config: &LiveNodeConfig,
+    kernel: &NautilusKernel,
+) -> anyhow::Result<Option<SessionRecorder>> {
+    config
+        .streaming
+        .as_ref()
+        .map(|streaming| {
+            SessionRecorder::from_config(
+                streaming,
+                &kernel.instance_id().to_string(),
+                kernel.clock(),
+            )
+        })
+        .transpose()
+}
+
+/// Completes on the next tick of `interval`, or never if there is none.
+async fn tick(interval: Option<&mut Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -18,9 +18,11 @@ use std::{cell::RefCell, fmt::Debug, rc::Rc};
 use futures::StreamExt;
 use nautilus_common::{
     clock::{Clock, LiveClock},
-    messages::DataEvent,
+    messages::{DataEvent, ExecutionEvent},
     msgbus::{self, switchboard::MessagingSwitchboard},
-    runner::{DataQueue, RunnerEvent, set_data_event_sender, set_data_evt_queue},
+    runner::{
+        DataQueue, RunnerEvent, set_data_event_sender, set_data_evt_queue, set_exec_event_sender,
+    },
 };
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
@@ -47,6 +49,7 @@ pub trait Runner {
 pub struct AsyncRunner {
     pub clock: Rc<RefCell<LiveClock>>,
     data_rx: UnboundedReceiver<DataEvent>,
+    exec_rx: UnboundedReceiver<ExecutionEvent>,
     signal_rx: UnboundedReceiver<()>,
 }
 
@@ -61,17 +64,22 @@ impl Debug for AsyncRunner {
 impl AsyncRunner {
     pub fn new(clock: Rc<RefCell<LiveClock>>) -> (Self, UnboundedSender<()>) {
         let (data_tx, data_rx) = tokio::sync::mpsc::unbounded_channel::<DataEvent>();
+        let (exec_tx, exec_rx) = tokio::sync::mpsc::unbounded_channel::<ExecutionEvent>();
         let (signal_tx, signal_rx) = tokio::sync::mpsc::unbounded_channel::<()>();
 
         // Set up the global data event sender for direct access
         set_data_event_sender(data_tx.clone());
 
+        // Set up the global execution event sender for execution clients
+        set_exec_event_sender(exec_tx);
+
         // Also keep the existing AsyncDataQueue for backward compatibility
         set_data_evt_queue(Rc::new(RefCell::new(AsyncDataQueue(data_tx))));
 
         let runner = Self {
             clock,
             data_rx,
+            exec_rx,
             signal_rx,
         };
 
@@ -82,7 +90,8 @@ impl AsyncRunner {
 impl AsyncRunner {
     /// Runs the async runner event loop.
     ///
-    /// This method processes data events, time events, and signal events in an async loop.
+    /// This method processes data events, execution events, time events, and signal events
+    /// in an async loop.
     /// It will run until a signal is received or the event streams are closed.
     pub async fn run(&mut self) {
         log::info!("Starting AsyncRunner");
@@ -91,11 +100,17 @@ impl AsyncRunner {
 
         let data_engine_process = MessagingSwitchboard::data_engine_process();
         let data_engine_response = MessagingSwitchboard::data_engine_response();
+        let exec_engine_process = MessagingSwitchboard::exec_engine_process();
+        let exec_engine_reconcile_report = MessagingSwitchboard::exec_engine_reconcile_report();
+        let exec_engine_reconcile_mass_status =
+            MessagingSwitchboard::exec_engine_reconcile_mass_status();
+        let portfolio_update_account = MessagingSwitchboard::portfolio_update_account();
 
         loop {
             // Collect the next event to process, including signal events
             let next_event = tokio::select! {
                 Some(resp) = self.data_rx.recv() => RunnerEvent::Data(resp),
+                Some(event) = self.exec_rx.recv() => RunnerEvent::Exec(event),
                 Some(event) = time_event_stream.next() => RunnerEvent::Time(event),
                 Some(_) = self.signal_rx.recv() => {
                     tracing::info!("AsyncRunner received signal, shutting down");
@@ -109,6 +124,27 @@ impl AsyncRunner {
                 RunnerEvent::Data(event) => match event {
                     DataEvent::Data(data) => msgbus::send(data_engine_process, &data),
                     DataEvent::Response(resp) => msgbus::send(data_engine_response, &resp),
+                    DataEvent::Instrument(instrument) => {
+                        msgbus::send(data_engine_process, &instrument);
+                    }
+                },
+                RunnerEvent::Exec(event) => match event {
+                    ExecutionEvent::Account(state) => {
+                        msgbus::send(portfolio_update_account, &state);
+                    }
+                    ExecutionEvent::Order(event) => msgbus::send(exec_engine_process, &event),
+                    ExecutionEvent::OrderStatusReport(report) => {
+                        msgbus::send(exec_engine_reconcile_report, &report);
+                    }
+                    ExecutionEvent::FillReport(report) => {
+                        msgbus::send(exec_engine_reconcile_report, &report);
+                    }
+                    ExecutionEvent::PositionStatusReport(report) => {
+                        msgbus::send(exec_engine_reconcile_report, &report);
+                    }
+                    ExecutionEvent::MassStatus(status) => {
+                        msgbus::send(exec_engine_reconcile_mass_status, &status);
+                    }
                 },
             }
         }
@@ -116,19 +152,57 @@ impl AsyncRunner {
 }
 
 #[cfg(test)]
-#[cfg(feature = "clock_v2")]
 mod tests {
-    use std::{cell::RefCell, rc::Rc};
+    use std::{cell::RefCell, rc::Rc, time::Duration};
 
-    use futures::StreamExt;
     use nautilus_common::{
+        cache::Cache,
         clock::LiveClock,
-        runner::{get_global_clock, set_global_clock},
-        timer::{TimeEvent, TimeEventCallback},
+        messages::{DataEvent, ExecutionEvent},
+        runner::{get_data_event_sender, get_exec_event_sender},
     };
+    use nautilus_data::engine::DataEngine;
+    use nautilus_execution::engine::ExecutionEngine;
+    use nautilus_model::{
+        enums::{OrderStatus, OrderType},
+        identifiers::{VenueOrderId, stubs::account_id},
+        instruments::{Instrument, InstrumentAny, stubs::audusd_sim},
+        orders::{Order, OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::Quantity,
+    };
+    use tokio::sync::mpsc::UnboundedSender;
+
+    use super::AsyncRunner;
 
+    /// Runs the `runner` until `is_handled` returns true, then stops it with `signal_tx`.
+    async fn run_until_handled(
+        runner: &mut AsyncRunner,
+        signal_tx: &UnboundedSender<()>,
+        is_handled: impl Fn() -> bool,
+    ) {
+        let stop = async {
+            while !is_handled() {
+                tokio::task::yield_now().await;
+            }
+            signal_tx.send(()).unwrap();
+        };
+
+        let result = tokio::time::timeout(Duration::from_secs(5), async {
+            tokio::join!(runner.run(), stop)
+        })
+        .await;
+        assert!(result.is_ok(), "Runner did not handle the event");
+    }
+
+    #[cfg(feature = "clock_v2")]
     #[tokio::test]
     async fn test_global_live_clock() {
+        use futures::StreamExt;
+        use nautilus_common::{
+            runner::{get_global_clock, set_global_clock},
+            timer::{TimeEvent, TimeEventCallback},
+        };
+
         let live_clock = Rc::new(RefCell::new(LiveClock::new()));
         set_global_clock(live_clock.clone());
         let alert_time = live_clock.borrow().get_time_ns() + 100;
@@ -151,4 +225,72 @@ mod tests {
                 .is_some()
         );
     }
+
+    #[tokio::test]
+    async fn test_exec_event_reaches_exec_engine() {
+        let clock = Rc::new(RefCell::new(LiveClock::new()));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let (mut runner, signal_tx) = AsyncRunner::new(clock.clone());
+
+        let exec_engine = Rc::new(RefCell::new(ExecutionEngine::new(
+            clock,
+            cache.clone(),
+            None,
+        )));
+        ExecutionEngine::register_message_handlers(&exec_engine);
+
+        let mut order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim().id)
+            .quantity(Quantity::from(100_000))
+            .build();
+        order
+            .apply(TestOrderEventStubs::submitted(&order, account_id()))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+
+        let accepted =
+            TestOrderEventStubs::accepted(&order, account_id(), VenueOrderId::from("V-1"));
+        get_exec_event_sender()
+            .send(ExecutionEvent::Order(accepted))
+            .unwrap();
+
+        let client_order_id = order.client_order_id();
+        run_until_handled(&mut runner, &signal_tx, || {
+            cache
+                .borrow()
+                .order(&client_order_id)
+                .is_some_and(|order| order.status() == OrderStatus::Accepted)
+        })
+        .await;
+
+        let cache = cache.borrow();
+        let order = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.venue_order_id(), Some(VenueOrderId::from("V-1")));
+    }
+
+    #[tokio::test]
+    async fn test_instrument_event_reaches_data_engine() {
+        let clock = Rc::new(RefCell::new(LiveClock::new()));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let (mut runner, signal_tx) = AsyncRunner::new(clock.clone());
+
+        let data_engine = Rc::new(RefCell::new(DataEngine::new(clock, cache.clone(), None)));
+        DataEngine::register_message_handlers(&data_engine);
+
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        get_data_event_sender()
+            .send(DataEvent::Instrument(instrument.clone()))
+            .unwrap();
+
+        run_until_handled(&mut runner, &signal_tx, || {
+            cache.borrow().instrument(&instrument.id()).is_some()
+        })
+        .await;
+
+        assert!(cache.borrow().instrument(&instrument.id()).is_some());
+    }
 }
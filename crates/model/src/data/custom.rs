@@ -0,0 +1,264 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! User-defined custom data types which can flow through the platform alongside market data.
+
+use std::{any::Any, fmt::Debug, sync::Arc};
+
+use nautilus_core::UnixNanos;
+
+use super::{DataType, GetTsInit};
+use crate::identifiers::InstrumentId;
+
+/// A user-defined data type, such as alternative data (sentiment scores, on-chain metrics),
+/// which can be carried by [`Data::Custom`](super::Data::Custom).
+pub trait CustomDataType: GetTsInit + Clone + Debug + PartialEq + Send + Sync + 'static {
+    /// Returns the unique name of the data type, used for its message bus topic
+    /// (`data.{type_name}`) and catalog path.
+    fn type_name() -> &'static str;
+
+    /// Returns the UNIX timestamp (nanoseconds) when the data event occurred.
+    fn ts_event(&self) -> UnixNanos;
+
+    /// Returns the instrument ID the data relates to, if any.
+    fn instrument_id(&self) -> Option<InstrumentId> {
+        None
+    }
+}
+
+/// Object-safe view of a [`CustomDataType`], implemented for all custom data types.
+trait DynCustomData: Debug + Send + Sync {
+    fn type_name(&self) -> &'static str;
+    fn ts_event(&self) -> UnixNanos;
+    fn ts_init(&self) -> UnixNanos;
+    fn instrument_id(&self) -> Option<InstrumentId>;
+    fn as_any(&self) -> &dyn Any;
+    fn eq_dyn(&self, other: &dyn DynCustomData) -> bool;
+}
+
+impl<T: CustomDataType> DynCustomData for T {
+    fn type_name(&self) -> &'static str {
+        <T as CustomDataType>::type_name()
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        CustomDataType::ts_event(self)
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        GetTsInit::ts_init(self)
+    }
+
+    fn instrument_id(&self) -> Option<InstrumentId> {
+        CustomDataType::instrument_id(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn DynCustomData) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+}
+
+/// The shared value behind a [`CustomDataAny`].
+#[derive(Clone, Debug)]
+struct CustomDataValue(Arc<dyn DynCustomData>);
+
+/// Wraps a [`CustomDataType`] value allowing polymorphism.
+///
+/// The value is boxed to keep [`Data`](super::Data) a C compatible enum, in the same
+/// way as [`OrderBookDeltas_API`](super::OrderBookDeltas_API).
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct CustomDataAny(Box<CustomDataValue>);
+
+impl CustomDataAny {
+    /// Creates a new [`CustomDataAny`] instance wrapping `value`.
+    pub fn new<T: CustomDataType>(value: T) -> Self {
+        Self(Box::new(CustomDataValue(Arc::new(value))))
+    }
+
+    /// Returns the name of the wrapped data type.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.0.0.type_name()
+    }
+
+    /// Returns the [`DataType`] of the wrapped data, which determines its message bus topic.
+    #[must_use]
+    pub fn data_type(&self) -> DataType {
+        DataType::new(self.type_name(), None)
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) when the data event occurred.
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        self.0.0.ts_event()
+    }
+
+    /// Returns the instrument ID the wrapped data relates to, if any.
+    #[must_use]
+    pub fn instrument_id(&self) -> Option<InstrumentId> {
+        self.0.0.instrument_id()
+    }
+
+    /// Returns the wrapped value, for publishing to handlers which downcast it.
+    #[must_use]
+    pub fn as_any(&self) -> &dyn Any {
+        self.0.0.as_any()
+    }
+
+    /// Returns a reference to the wrapped value if it is of type `T`.
+    #[must_use]
+    pub fn downcast_ref<T: CustomDataType>(&self) -> Option<&T> {
+        self.as_any().downcast_ref::<T>()
+    }
+}
+
+impl PartialEq for CustomDataAny {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.0.eq_dyn(other.0.0.as_ref())
+    }
+}
+
+impl GetTsInit for CustomDataAny {
+    fn ts_init(&self) -> UnixNanos {
+        self.0.0.ts_init()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::Data;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Sentiment {
+        instrument_id: InstrumentId,
+        score: f64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    }
+
+    impl GetTsInit for Sentiment {
+        fn ts_init(&self) -> UnixNanos {
+            self.ts_init
+        }
+    }
+
+    impl CustomDataType for Sentiment {
+        fn type_name() -> &'static str {
+            "Sentiment"
+        }
+
+        fn ts_event(&self) -> UnixNanos {
+            self.ts_event
+        }
+
+        fn instrument_id(&self) -> Option<InstrumentId> {
+            Some(self.instrument_id)
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct BlockHeight {
+        height: u64,
+        ts_init: UnixNanos,
+    }
+
+    impl GetTsInit for BlockHeight {
+        fn ts_init(&self) -> UnixNanos {
+            self.ts_init
+        }
+    }
+
+    impl CustomDataType for BlockHeight {
+        fn type_name() -> &'static str {
+            "BlockHeight"
+        }
+
+        fn ts_event(&self) -> UnixNanos {
+            self.ts_init
+        }
+    }
+
+    fn sentiment(score: f64) -> Sentiment {
+        Sentiment {
+            instrument_id: InstrumentId::from("AAPL.XNAS"),
+            score,
+            ts_event: 1.into(),
+            ts_init: 2.into(),
+        }
+    }
+
+    #[rstest]
+    fn test_custom_data_any_accessors() {
+        let custom = CustomDataAny::new(sentiment(0.5));
+
+        assert_eq!(custom.type_name(), "Sentiment");
+        assert_eq!(custom.data_type().topic(), "Sentiment");
+        assert_eq!(custom.ts_event(), 1);
+        assert_eq!(custom.ts_init(), 2);
+        assert_eq!(
+            custom.instrument_id(),
+            Some(InstrumentId::from("AAPL.XNAS"))
+        );
+        assert_eq!(custom.downcast_ref::<Sentiment>(), Some(&sentiment(0.5)));
+        assert!(custom.downcast_ref::<BlockHeight>().is_none());
+    }
+
+    #[rstest]
+    fn test_custom_data_any_equality() {
+        let block = BlockHeight {
+            height: 100,
+            ts_init: 2.into(),
+        };
+
+        assert_eq!(
+            CustomDataAny::new(sentiment(0.5)),
+            CustomDataAny::new(sentiment(0.5))
+        );
+        assert_ne!(
+            CustomDataAny::new(sentiment(0.5)),
+            CustomDataAny::new(sentiment(0.7))
+        );
+        assert_ne!(
+            CustomDataAny::new(sentiment(0.5)),
+            CustomDataAny::new(block)
+        );
+    }
+
+    #[rstest]
+    fn test_data_custom_variant() {
+        let data = Data::from(CustomDataAny::new(sentiment(0.5)));
+
+        assert_eq!(data.ts_init(), 2);
+        assert_eq!(data.instrument_id(), Some(InstrumentId::from("AAPL.XNAS")));
+        assert!(!data.is_order_book_data());
+        assert_eq!(
+            CustomDataAny::try_from(data)
+                .unwrap()
+                .downcast_ref::<Sentiment>(),
+            Some(&sentiment(0.5))
+        );
+    }
+}
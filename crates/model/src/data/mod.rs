@@ -18,6 +18,7 @@
 pub mod bar;
 pub mod bet;
 pub mod close;
+pub mod custom;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
@@ -46,6 +47,7 @@ use serde_json::to_string;
 // Re-exports
 #[rustfmt::skip]  // Keep these grouped
 pub use bar::{Bar, BarSpecification, BarType};
+pub use custom::{CustomDataAny, CustomDataType};
 pub use delta::OrderBookDelta;
 pub use deltas::{OrderBookDeltas, OrderBookDeltas_API};
 pub use depth::{DEPTH10_LEN, OrderBookDepth10};
@@ -77,6 +79,8 @@ pub enum Data {
     MarkPriceUpdate(MarkPriceUpdate), // TODO: Rename to MarkPrice once Cython gone
     IndexPriceUpdate(IndexPriceUpdate), // TODO: Rename to IndexPrice once Cython gone
     InstrumentClose(InstrumentClose),
+    #[serde(skip)] // User-defined types are not serializable
+    Custom(CustomDataAny),
 }
 
 macro_rules! impl_try_from_data {
@@ -113,6 +117,7 @@ impl_try_from_data!(Bar, Bar);
 impl_try_from_data!(MarkPriceUpdate, MarkPriceUpdate);
 impl_try_from_data!(IndexPriceUpdate, IndexPriceUpdate);
 impl_try_from_data!(InstrumentClose, InstrumentClose);
+impl_try_from_data!(Custom, CustomDataAny);
 
 pub fn to_variant<T: TryFrom<Data>>(data: Vec<Data>) -> Vec<T> {
     data.into_iter()
@@ -122,17 +127,20 @@ pub fn to_variant<T: TryFrom<Data>>(data: Vec<Data>) -> Vec<T> {
 
 impl Data {
     /// Returns the instrument ID for the data.
-    pub fn instrument_id(&self) -> InstrumentId {
+    ///
+    /// Returns `None` for custom data which does not relate to an instrument.
+    pub fn instrument_id(&self) -> Option<InstrumentId> {
         match self {
-            Self::Delta(delta) => delta.instrument_id,
-            Self::Deltas(deltas) => deltas.instrument_id,
-            Self::Depth10(depth) => depth.instrument_id,
-            Self::Quote(quote) => quote.instrument_id,
-            Self::Trade(trade) => trade.instrument_id,
-            Self::Bar(bar) => bar.bar_type.instrument_id(),
-            Self::MarkPriceUpdate(mark_price) => mark_price.instrument_id,
-            Self::IndexPriceUpdate(index_price) => index_price.instrument_id,
-            Self::InstrumentClose(close) => close.instrument_id,
+            Self::Delta(delta) => Some(delta.instrument_id),
+            Self::Deltas(deltas) => Some(deltas.instrument_id),
+            Self::Depth10(depth) => Some(depth.instrument_id),
+            Self::Quote(quote) => Some(quote.instrument_id),
+            Self::Trade(trade) => Some(trade.instrument_id),
+            Self::Bar(bar) => Some(bar.bar_type.instrument_id()),
+            Self::MarkPriceUpdate(mark_price) => Some(mark_price.instrument_id),
+            Self::IndexPriceUpdate(index_price) => Some(index_price.instrument_id),
+            Self::InstrumentClose(close) => Some(close.instrument_id),
+            Self::Custom(custom) => custom.instrument_id(),
         }
     }
 
@@ -158,6 +166,7 @@ impl GetTsInit for Data {
             Self::MarkPriceUpdate(p) => p.ts_init,
             Self::IndexPriceUpdate(p) => p.ts_init,
             Self::InstrumentClose(c) => c.ts_init,
+            Self::Custom(c) => c.ts_init(),
         }
     }
 }
@@ -221,6 +230,12 @@ impl From<InstrumentClose> for Data {
     }
 }
 
+impl From<CustomDataAny> for Data {
+    fn from(value: CustomDataAny) -> Self {
+        Self::Custom(value)
+    }
+}
+
 /// Represents a data type including metadata.
 #[derive(Clone, Serialize, Deserialize)]
 #[cfg_attr(
@@ -17,7 +17,10 @@ use std::sync::Arc;
 
 use alloy_primitives::Address;
 
-use crate::defi::{chain::SharedChain, dex::Dex, token::Token};
+use crate::{
+    defi::{chain::SharedChain, dex::Dex, token::Token},
+    identifiers::{InstrumentId, Symbol, Venue},
+};
 
 /// Represents a liquidity pool in a decentralized exchange.
 #[derive(Debug, Clone)]
@@ -74,4 +77,19 @@ impl Pool {
     pub fn ticker(&self) -> String {
         format!("{}/{}", self.token0.symbol, self.token1.symbol)
     }
+
+    /// Returns the instrument ID representing this pool on its DEX venue.
+    ///
+    /// The symbol combines the pool ticker and fee tier (e.g. `WETH/USDC-500`) and the venue
+    /// combines the chain and DEX name (e.g. `Ethereum:UniswapV3`).
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        let symbol = Symbol::new(format!("{}-{}", self.ticker(), self.fee));
+        let venue = Venue::new(format!(
+            "{}:{}",
+            self.chain.name,
+            self.dex.name.replace(' ', "")
+        ));
+        InstrumentId::new(symbol, venue)
+    }
 }
@@ -47,6 +47,12 @@ pub struct Dex {
     pub pool_created_event: Cow<'static, str>,
     /// The event signature or identifier used to detect swap events.
     pub swap_created_event: Cow<'static, str>,
+    /// The event signature or identifier used to detect liquidity mint events.
+    pub mint_created_event: Cow<'static, str>,
+    /// The event signature or identifier used to detect liquidity burn events.
+    pub burn_created_event: Cow<'static, str>,
+    /// The event signature or identifier used to detect fee collect events.
+    pub collect_created_event: Cow<'static, str>,
     /// The type of automated market maker (AMM) algorithm used by this DEX.
     pub amm_type: AmmType,
     /// Collection of liquidity pools managed by this DEX.
@@ -74,11 +80,28 @@ impl Dex {
             factory: factory.into(),
             pool_created_event: pool_created_event.into(),
             swap_created_event: swap_created_event.into(),
+            mint_created_event: Cow::Borrowed(""),
+            burn_created_event: Cow::Borrowed(""),
+            collect_created_event: Cow::Borrowed(""),
             amm_type,
             pairs: vec![],
         }
     }
 
+    /// Sets the event signatures used to detect liquidity mint, burn and fee collect events.
+    ///
+    /// An empty signature indicates the DEX does not emit that event.
+    pub fn set_liquidity_events(
+        &mut self,
+        mint_created_event: impl Into<Cow<'static, str>>,
+        burn_created_event: impl Into<Cow<'static, str>>,
+        collect_created_event: impl Into<Cow<'static, str>>,
+    ) {
+        self.mint_created_event = mint_created_event.into();
+        self.burn_created_event = burn_created_event.into();
+        self.collect_created_event = collect_created_event.into();
+    }
+
     /// Returns a unique identifier for this DEX, combining chain and name.
     ///
     /// Format: "{chain_id}:{name_snake_case}"
@@ -0,0 +1,197 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use alloy_primitives::Address;
+use nautilus_core::UnixNanos;
+use strum::{AsRefStr, EnumString};
+
+use crate::{
+    defi::{amm::SharedPool, chain::SharedChain, dex::SharedDex},
+    types::Quantity,
+};
+
+/// Represents the type of a liquidity update on a decentralized exchange pool.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AsRefStr, EnumString, strum::Display)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum PoolLiquidityUpdateType {
+    /// Liquidity was added to the pool (mint).
+    Mint,
+    /// Liquidity was removed from the pool (burn).
+    Burn,
+}
+
+/// Represents liquidity being added to or removed from a position in a DEX liquidity pool.
+///
+/// For constant product pools (Uniswap V2 style) the position always spans the full tick range.
+#[derive(Debug, Clone)]
+pub struct PoolLiquidityUpdate {
+    /// The blockchain network where the update occurred.
+    pub chain: SharedChain,
+    /// The decentralized exchange where the update was executed.
+    pub dex: SharedDex,
+    /// The DEX liquidity pool.
+    pub pool: SharedPool,
+    /// The type of the liquidity update.
+    pub kind: PoolLiquidityUpdateType,
+    /// The blockchain block number at which the update was executed.
+    pub block: u64,
+    /// The blockchain address of the position owner.
+    pub owner: Address,
+    /// The amount of position liquidity added or removed.
+    pub position_liquidity: u128,
+    /// The amount of token0 deposited or withdrawn.
+    pub amount0: Quantity,
+    /// The amount of token1 deposited or withdrawn.
+    pub amount1: Quantity,
+    /// The lower tick of the position range.
+    pub tick_lower: i32,
+    /// The upper tick of the position range.
+    pub tick_upper: i32,
+    /// The timestamp of the update in Unix nanoseconds.
+    pub timestamp: UnixNanos,
+}
+
+impl PoolLiquidityUpdate {
+    /// Creates a new [`PoolLiquidityUpdate`] instance with the specified properties.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        chain: SharedChain,
+        dex: SharedDex,
+        pool: SharedPool,
+        kind: PoolLiquidityUpdateType,
+        block: u64,
+        owner: Address,
+        position_liquidity: u128,
+        amount0: Quantity,
+        amount1: Quantity,
+        tick_lower: i32,
+        tick_upper: i32,
+        timestamp: UnixNanos,
+    ) -> Self {
+        Self {
+            chain,
+            dex,
+            pool,
+            kind,
+            block,
+            owner,
+            position_liquidity,
+            amount0,
+            amount1,
+            tick_lower,
+            tick_upper,
+            timestamp,
+        }
+    }
+}
+
+impl Display for PoolLiquidityUpdate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PoolLiquidityUpdate(chain={}, dex={}, pool={}, kind={}, owner={}, liquidity={}, amount0={}, amount1={}, ticks=[{}, {}])",
+            self.chain.name,
+            self.dex.name,
+            self.pool.ticker(),
+            self.kind,
+            self.owner,
+            self.position_liquidity,
+            self.amount0,
+            self.amount1,
+            self.tick_lower,
+            self.tick_upper,
+        )
+    }
+}
+
+/// Represents tokens collected from a position in a DEX liquidity pool.
+///
+/// The collected amounts include both accrued fees and any principal previously withdrawn
+/// from the position by a burn.
+#[derive(Debug, Clone)]
+pub struct PoolFeeCollect {
+    /// The blockchain network where the collect occurred.
+    pub chain: SharedChain,
+    /// The decentralized exchange where the collect was executed.
+    pub dex: SharedDex,
+    /// The DEX liquidity pool.
+    pub pool: SharedPool,
+    /// The blockchain block number at which the collect was executed.
+    pub block: u64,
+    /// The blockchain address of the position owner.
+    pub owner: Address,
+    /// The amount of token0 collected.
+    pub amount0: Quantity,
+    /// The amount of token1 collected.
+    pub amount1: Quantity,
+    /// The lower tick of the position range.
+    pub tick_lower: i32,
+    /// The upper tick of the position range.
+    pub tick_upper: i32,
+    /// The timestamp of the collect in Unix nanoseconds.
+    pub timestamp: UnixNanos,
+}
+
+impl PoolFeeCollect {
+    /// Creates a new [`PoolFeeCollect`] instance with the specified properties.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        chain: SharedChain,
+        dex: SharedDex,
+        pool: SharedPool,
+        block: u64,
+        owner: Address,
+        amount0: Quantity,
+        amount1: Quantity,
+        tick_lower: i32,
+        tick_upper: i32,
+        timestamp: UnixNanos,
+    ) -> Self {
+        Self {
+            chain,
+            dex,
+            pool,
+            block,
+            owner,
+            amount0,
+            amount1,
+            tick_lower,
+            tick_upper,
+            timestamp,
+        }
+    }
+}
+
+impl Display for PoolFeeCollect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PoolFeeCollect(chain={}, dex={}, pool={}, owner={}, amount0={}, amount1={}, ticks=[{}, {}])",
+            self.chain.name,
+            self.dex.name,
+            self.pool.ticker(),
+            self.owner,
+            self.amount0,
+            self.amount1,
+            self.tick_lower,
+            self.tick_upper,
+        )
+    }
+}
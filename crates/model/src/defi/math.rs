@@ -0,0 +1,704 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fixed-point math for concentrated liquidity AMMs (Uniswap V3/V4 style).
+//!
+//! These functions port the Uniswap V3 core libraries (`FullMath`, `TickMath`, `SqrtPriceMath`
+//! and `SwapMath`) so that simulated results match on-chain results to the wei, including
+//! rounding direction.
+
+use alloy_primitives::{U256, U512};
+
+/// The minimum tick that may be passed to [`get_sqrt_ratio_at_tick`].
+pub const MIN_TICK: i32 = -887_272;
+
+/// The maximum tick that may be passed to [`get_sqrt_ratio_at_tick`].
+pub const MAX_TICK: i32 = 887_272;
+
+/// The minimum value that can be returned from [`get_sqrt_ratio_at_tick`].
+pub const MIN_SQRT_RATIO: U256 = U256::from_limbs([4_295_128_739, 0, 0, 0]);
+
+/// The maximum value that can be returned from [`get_sqrt_ratio_at_tick`].
+pub const MAX_SQRT_RATIO: U256 =
+    U256::from_limbs([0x5d95_1d52_6398_8d26, 0xefd1_fc6a_5064_8849, 0xfffd_8963, 0]);
+
+/// The denominator for fees expressed in hundredths of a basis point (pips).
+pub const FEE_DENOMINATOR: u32 = 1_000_000;
+
+/// The number of fractional bits in a Q64.96 square root price.
+pub const RESOLUTION: usize = 96;
+
+/// Returns 2^96, the fixed-point scaling factor for Q64.96 values.
+#[must_use]
+pub fn q96() -> U256 {
+    U256::from(1u8) << RESOLUTION
+}
+
+/// Returns 2^128, the fixed-point scaling factor for Q128.128 fee growth values.
+#[must_use]
+pub fn q128() -> U256 {
+    U256::from(1u8) << 128
+}
+
+/// Calculates `floor(a * b / denominator)` with full 512-bit intermediate precision.
+///
+/// # Errors
+///
+/// Returns an error if `denominator` is zero or the result overflows 256 bits.
+pub fn mul_div(a: U256, b: U256, denominator: U256) -> anyhow::Result<U256> {
+    if denominator.is_zero() {
+        anyhow::bail!("`mul_div` denominator was zero");
+    }
+
+    let result = U512::from(a) * U512::from(b) / U512::from(denominator);
+    if result > U512::from(U256::MAX) {
+        anyhow::bail!("`mul_div` result overflows 256 bits");
+    }
+    Ok(U256::from(result))
+}
+
+/// Calculates `ceil(a * b / denominator)` with full 512-bit intermediate precision.
+///
+/// # Errors
+///
+/// Returns an error if `denominator` is zero or the result overflows 256 bits.
+pub fn mul_div_rounding_up(a: U256, b: U256, denominator: U256) -> anyhow::Result<U256> {
+    let result = mul_div(a, b, denominator)?;
+    if (U512::from(a) * U512::from(b) % U512::from(denominator)).is_zero() {
+        return Ok(result);
+    }
+
+    match result.checked_add(U256::from(1u8)) {
+        Some(result) => Ok(result),
+        None => anyhow::bail!("`mul_div_rounding_up` result overflows 256 bits"),
+    }
+}
+
+/// Calculates `ceil(a / b)`.
+///
+/// # Panics
+///
+/// Panics if `b` is zero.
+#[must_use]
+pub fn div_rounding_up(a: U256, b: U256) -> U256 {
+    let quotient = a / b;
+    if (a % b).is_zero() {
+        quotient
+    } else {
+        quotient + U256::from(1u8)
+    }
+}
+
+/// Adds a signed liquidity delta to liquidity.
+///
+/// # Errors
+///
+/// Returns an error if the result underflows zero or overflows `u128`.
+pub fn add_liquidity_delta(liquidity: u128, delta: i128) -> anyhow::Result<u128> {
+    let result = if delta < 0 {
+        liquidity.checked_sub(delta.unsigned_abs())
+    } else {
+        liquidity.checked_add(delta as u128)
+    };
+    result
+        .ok_or_else(|| anyhow::anyhow!("Invalid liquidity delta {delta} for liquidity {liquidity}"))
+}
+
+/// Returns the Q64.96 square root price `sqrt(1.0001^tick) * 2^96` for the given `tick`.
+///
+/// # Errors
+///
+/// Returns an error if `tick` is outside [`MIN_TICK`, `MAX_TICK`].
+pub fn get_sqrt_ratio_at_tick(tick: i32) -> anyhow::Result<U256> {
+    const MULTIPLIERS: [u128; 19] = [
+        0xfff9_7272_373d_4132_59a4_6990_580e_213a,
+        0xfff2_e50f_5f65_6932_ef12_357c_f3c7_fdcc,
+        0xffe5_caca_7e10_e4e6_1c36_24ea_a094_1cd0,
+        0xffcb_9843_d60f_6159_c9db_5883_5c92_6644,
+        0xff97_3b41_fa98_c081_472e_6896_dfb2_54c0,
+        0xff2e_a164_66c9_6a38_43ec_78b3_26b5_2861,
+        0xfe5d_ee04_6a99_a2a8_11c4_61f1_969c_3053,
+        0xfcbe_86c7_900a_88ae_dcff_c83b_479a_a3a4,
+        0xf987_a725_3ac4_1317_6f2b_074c_f781_5e54,
+        0xf339_2b08_22b7_0005_940c_7a39_8e4b_70f3,
+        0xe715_9475_a2c2_9b74_43b2_9c7f_a6e8_89d9,
+        0xd097_f3bd_fd20_22b8_845a_d8f7_92aa_5825,
+        0xa9f7_4646_2d87_0fdf_8a65_dc1f_90e0_61e5,
+        0x70d8_69a1_56d2_a1b8_90bb_3df6_2baf_32f7,
+        0x31be_135f_97d0_8fd9_8123_1505_542f_cfa6,
+        0x09aa_508b_5b7a_84e1_c677_de54_f3e9_9bc9,
+        0x005d_6af8_dedb_8119_6699_c329_225e_e604,
+        0x0000_2216_e584_f5fa_1ea9_2604_1bed_fe98,
+        0x0000_0000_048a_1703_91f7_dc42_444e_8fa2,
+    ];
+
+    let abs_tick = tick.unsigned_abs();
+    if abs_tick > MAX_TICK as u32 {
+        anyhow::bail!("Tick {tick} out of range [{MIN_TICK}, {MAX_TICK}]");
+    }
+
+    let mut ratio = if abs_tick & 0x1 != 0 {
+        U256::from(0xfffc_b933_bd6f_ad37_aa2d_162d_1a59_4001_u128)
+    } else {
+        q128()
+    };
+
+    for (i, multiplier) in MULTIPLIERS.iter().enumerate() {
+        if abs_tick & (1 << (i + 1)) != 0 {
+            ratio = (ratio * U256::from(*multiplier)) >> 128;
+        }
+    }
+
+    if tick > 0 {
+        ratio = U256::MAX / ratio;
+    }
+
+    // Divide by 2^32 rounding up to go from Q128.128 to Q128.96
+    let remainder = ratio & U256::from(u32::MAX);
+    let sqrt_price_x96 = (ratio >> 32) + U256::from(u8::from(!remainder.is_zero()));
+    Ok(sqrt_price_x96)
+}
+
+/// Returns the greatest tick such that `get_sqrt_ratio_at_tick(tick) <= sqrt_price_x96`.
+///
+/// # Errors
+///
+/// Returns an error if `sqrt_price_x96` is outside [`MIN_SQRT_RATIO`, `MAX_SQRT_RATIO`).
+pub fn get_tick_at_sqrt_ratio(sqrt_price_x96: U256) -> anyhow::Result<i32> {
+    if sqrt_price_x96 < MIN_SQRT_RATIO || sqrt_price_x96 >= MAX_SQRT_RATIO {
+        anyhow::bail!("Square root price {sqrt_price_x96} out of range");
+    }
+
+    // Binary search over the monotonic tick -> sqrt price mapping, which yields the same
+    // result as the on-chain log2 approximation and its correction step
+    let mut low = MIN_TICK;
+    let mut high = MAX_TICK;
+    while low < high {
+        let mid = low + (high - low + 1) / 2;
+        if get_sqrt_ratio_at_tick(mid)? <= sqrt_price_x96 {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    Ok(low)
+}
+
+/// Returns the amount of token0 required to cover a position of `liquidity` between the two
+/// square root prices.
+///
+/// # Errors
+///
+/// Returns an error if either price is zero or an intermediate result overflows.
+pub fn get_amount0_delta(
+    sqrt_ratio_a_x96: U256,
+    sqrt_ratio_b_x96: U256,
+    liquidity: u128,
+    round_up: bool,
+) -> anyhow::Result<U256> {
+    let (sqrt_ratio_a_x96, sqrt_ratio_b_x96) = if sqrt_ratio_a_x96 > sqrt_ratio_b_x96 {
+        (sqrt_ratio_b_x96, sqrt_ratio_a_x96)
+    } else {
+        (sqrt_ratio_a_x96, sqrt_ratio_b_x96)
+    };
+
+    if sqrt_ratio_a_x96.is_zero() {
+        anyhow::bail!("Square root price cannot be zero");
+    }
+
+    let numerator1 = U256::from(liquidity) << RESOLUTION;
+    let numerator2 = sqrt_ratio_b_x96 - sqrt_ratio_a_x96;
+
+    if round_up {
+        Ok(div_rounding_up(
+            mul_div_rounding_up(numerator1, numerator2, sqrt_ratio_b_x96)?,
+            sqrt_ratio_a_x96,
+        ))
+    } else {
+        Ok(mul_div(numerator1, numerator2, sqrt_ratio_b_x96)? / sqrt_ratio_a_x96)
+    }
+}
+
+/// Returns the amount of token1 required to cover a position of `liquidity` between the two
+/// square root prices.
+///
+/// # Errors
+///
+/// Returns an error if an intermediate result overflows.
+pub fn get_amount1_delta(
+    sqrt_ratio_a_x96: U256,
+    sqrt_ratio_b_x96: U256,
+    liquidity: u128,
+    round_up: bool,
+) -> anyhow::Result<U256> {
+    let diff = if sqrt_ratio_a_x96 > sqrt_ratio_b_x96 {
+        sqrt_ratio_a_x96 - sqrt_ratio_b_x96
+    } else {
+        sqrt_ratio_b_x96 - sqrt_ratio_a_x96
+    };
+
+    if round_up {
+        mul_div_rounding_up(U256::from(liquidity), diff, q96())
+    } else {
+        mul_div(U256::from(liquidity), diff, q96())
+    }
+}
+
+fn get_next_sqrt_price_from_amount0_rounding_up(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount: U256,
+    add: bool,
+) -> anyhow::Result<U256> {
+    if amount.is_zero() {
+        return Ok(sqrt_price_x96);
+    }
+
+    let numerator1 = U256::from(liquidity) << RESOLUTION;
+    let (product, overflowed) = amount.overflowing_mul(sqrt_price_x96);
+
+    if add {
+        if !overflowed {
+            if let Some(denominator) = numerator1.checked_add(product) {
+                return mul_div_rounding_up(numerator1, sqrt_price_x96, denominator);
+            }
+        }
+        Ok(div_rounding_up(
+            numerator1,
+            (numerator1 / sqrt_price_x96).saturating_add(amount),
+        ))
+    } else {
+        if overflowed || numerator1 <= product {
+            anyhow::bail!("Insufficient token0 liquidity for output amount {amount}");
+        }
+        mul_div_rounding_up(numerator1, sqrt_price_x96, numerator1 - product)
+    }
+}
+
+fn get_next_sqrt_price_from_amount1_rounding_down(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount: U256,
+    add: bool,
+) -> anyhow::Result<U256> {
+    let max_u160 = (U256::from(1u8) << 160) - U256::from(1u8);
+    let liquidity = U256::from(liquidity);
+
+    if add {
+        let quotient = if amount <= max_u160 {
+            (amount << RESOLUTION) / liquidity
+        } else {
+            mul_div(amount, q96(), liquidity)?
+        };
+        Ok(sqrt_price_x96 + quotient)
+    } else {
+        let quotient = if amount <= max_u160 {
+            div_rounding_up(amount << RESOLUTION, liquidity)
+        } else {
+            mul_div_rounding_up(amount, q96(), liquidity)?
+        };
+        if sqrt_price_x96 <= quotient {
+            anyhow::bail!("Insufficient token1 liquidity for output amount {amount}");
+        }
+        Ok(sqrt_price_x96 - quotient)
+    }
+}
+
+/// Returns the next square root price given an input amount of token0 or token1.
+///
+/// # Errors
+///
+/// Returns an error if `sqrt_price_x96` or `liquidity` is zero, or the price overflows.
+pub fn get_next_sqrt_price_from_input(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount_in: U256,
+    zero_for_one: bool,
+) -> anyhow::Result<U256> {
+    if sqrt_price_x96.is_zero() || liquidity == 0 {
+        anyhow::bail!("Square root price and liquidity must be positive");
+    }
+
+    if zero_for_one {
+        get_next_sqrt_price_from_amount0_rounding_up(sqrt_price_x96, liquidity, amount_in, true)
+    } else {
+        get_next_sqrt_price_from_amount1_rounding_down(sqrt_price_x96, liquidity, amount_in, true)
+    }
+}
+
+/// Returns the next square root price given an output amount of token0 or token1.
+///
+/// # Errors
+///
+/// Returns an error if `sqrt_price_x96` or `liquidity` is zero, or the output exceeds reserves.
+pub fn get_next_sqrt_price_from_output(
+    sqrt_price_x96: U256,
+    liquidity: u128,
+    amount_out: U256,
+    zero_for_one: bool,
+) -> anyhow::Result<U256> {
+    if sqrt_price_x96.is_zero() || liquidity == 0 {
+        anyhow::bail!("Square root price and liquidity must be positive");
+    }
+
+    if zero_for_one {
+        get_next_sqrt_price_from_amount1_rounding_down(sqrt_price_x96, liquidity, amount_out, false)
+    } else {
+        get_next_sqrt_price_from_amount0_rounding_up(sqrt_price_x96, liquidity, amount_out, false)
+    }
+}
+
+/// The result of computing a single swap step within one tick range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapStep {
+    /// The square root price after the step.
+    pub sqrt_price_next_x96: U256,
+    /// The amount swapped in, excluding fees.
+    pub amount_in: U256,
+    /// The amount swapped out.
+    pub amount_out: U256,
+    /// The fee amount charged on the input.
+    pub fee_amount: U256,
+}
+
+/// Computes a single swap step from `sqrt_price_current_x96` towards `sqrt_price_target_x96`.
+///
+/// The `amount_remaining` is the input amount remaining when `exact_input`, otherwise the output
+/// amount remaining. The `fee_pips` is the fee in hundredths of a basis point.
+///
+/// # Errors
+///
+/// Returns an error if an intermediate result overflows.
+pub fn compute_swap_step(
+    sqrt_price_current_x96: U256,
+    sqrt_price_target_x96: U256,
+    liquidity: u128,
+    amount_remaining: U256,
+    exact_input: bool,
+    fee_pips: u32,
+) -> anyhow::Result<SwapStep> {
+    let zero_for_one = sqrt_price_current_x96 >= sqrt_price_target_x96;
+    let fee = U256::from(fee_pips);
+    let fee_complement = U256::from(FEE_DENOMINATOR - fee_pips);
+
+    let mut amount_in = U256::ZERO;
+    let mut amount_out = U256::ZERO;
+
+    let sqrt_price_next_x96 = if exact_input {
+        let amount_remaining_less_fee = mul_div(
+            amount_remaining,
+            fee_complement,
+            U256::from(FEE_DENOMINATOR),
+        )?;
+        amount_in = if zero_for_one {
+            get_amount0_delta(
+                sqrt_price_target_x96,
+                sqrt_price_current_x96,
+                liquidity,
+                true,
+            )?
+        } else {
+            get_amount1_delta(
+                sqrt_price_current_x96,
+                sqrt_price_target_x96,
+                liquidity,
+                true,
+            )?
+        };
+        if amount_remaining_less_fee >= amount_in {
+            sqrt_price_target_x96
+        } else {
+            get_next_sqrt_price_from_input(
+                sqrt_price_current_x96,
+                liquidity,
+                amount_remaining_less_fee,
+                zero_for_one,
+            )?
+        }
+    } else {
+        amount_out = if zero_for_one {
+            get_amount1_delta(
+                sqrt_price_target_x96,
+                sqrt_price_current_x96,
+                liquidity,
+                false,
+            )?
+        } else {
+            get_amount0_delta(
+                sqrt_price_current_x96,
+                sqrt_price_target_x96,
+                liquidity,
+                false,
+            )?
+        };
+        if amount_remaining >= amount_out {
+            sqrt_price_target_x96
+        } else {
+            get_next_sqrt_price_from_output(
+                sqrt_price_current_x96,
+                liquidity,
+                amount_remaining,
+                zero_for_one,
+            )?
+        }
+    };
+
+    let reached_target = sqrt_price_target_x96 == sqrt_price_next_x96;
+
+    if zero_for_one {
+        if !(reached_target && exact_input) {
+            amount_in =
+                get_amount0_delta(sqrt_price_next_x96, sqrt_price_current_x96, liquidity, true)?;
+        }
+        if !reached_target || exact_input {
+            amount_out = get_amount1_delta(
+                sqrt_price_next_x96,
+                sqrt_price_current_x96,
+                liquidity,
+                false,
+            )?;
+        }
+    } else {
+        if !(reached_target && exact_input) {
+            amount_in =
+                get_amount1_delta(sqrt_price_current_x96, sqrt_price_next_x96, liquidity, true)?;
+        }
+        if !reached_target || exact_input {
+            amount_out = get_amount0_delta(
+                sqrt_price_current_x96,
+                sqrt_price_next_x96,
+                liquidity,
+                false,
+            )?;
+        }
+    }
+
+    // Cap the output amount to not exceed the remaining output amount
+    if !exact_input && amount_out > amount_remaining {
+        amount_out = amount_remaining;
+    }
+
+    let fee_amount = if exact_input && sqrt_price_next_x96 != sqrt_price_target_x96 {
+        // Take the remainder of the maximum input as fee
+        amount_remaining - amount_in
+    } else {
+        mul_div_rounding_up(amount_in, fee, fee_complement)?
+    };
+
+    Ok(SwapStep {
+        sqrt_price_next_x96,
+        amount_in,
+        amount_out,
+        fee_amount,
+    })
+}
+
+/// Converts a 256-bit unsigned integer to an `f64` (lossy).
+#[must_use]
+pub fn u256_to_f64(value: U256) -> f64 {
+    value.as_limbs().iter().rev().fold(0.0, |acc, limb| {
+        acc.mul_add(18_446_744_073_709_551_616.0, *limb as f64)
+    })
+}
+
+/// Converts a Q64.96 square root price to the price of token0 in units of token1, adjusted
+/// for the token decimals.
+#[must_use]
+pub fn sqrt_price_x96_to_price(sqrt_price_x96: U256, decimals0: u8, decimals1: u8) -> f64 {
+    let sqrt_price = u256_to_f64(sqrt_price_x96) / 2f64.powi(RESOLUTION as i32);
+    sqrt_price * sqrt_price * 10f64.powi(i32::from(decimals0) - i32::from(decimals1))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn expand_to_18_decimals(n: u64) -> u128 {
+        u128::from(n) * 1_000_000_000_000_000_000
+    }
+
+    fn u256(value: &str) -> U256 {
+        U256::from_str_radix(value, 10).unwrap()
+    }
+
+    // Square root prices for 1:1, 121:100 and 101:100 as encoded by the Uniswap test suite
+    fn price_1_1() -> U256 {
+        q96()
+    }
+
+    fn price_121_100() -> U256 {
+        u256("87150978765690771352898345369")
+    }
+
+    fn price_101_100() -> U256 {
+        u256("79623317895830914510639640423")
+    }
+
+    #[rstest]
+    fn test_get_sqrt_ratio_at_tick_bounds() {
+        assert_eq!(get_sqrt_ratio_at_tick(0).unwrap(), q96());
+        assert_eq!(get_sqrt_ratio_at_tick(MIN_TICK).unwrap(), MIN_SQRT_RATIO);
+        assert_eq!(get_sqrt_ratio_at_tick(MAX_TICK).unwrap(), MAX_SQRT_RATIO);
+        assert!(get_sqrt_ratio_at_tick(MIN_TICK - 1).is_err());
+        assert!(get_sqrt_ratio_at_tick(MAX_TICK + 1).is_err());
+    }
+
+    #[rstest]
+    #[case(MIN_SQRT_RATIO, MIN_TICK)]
+    #[case(MAX_SQRT_RATIO - U256::from(1u8), MAX_TICK - 1)]
+    #[case(q96(), 0)]
+    fn test_get_tick_at_sqrt_ratio(#[case] sqrt_price_x96: U256, #[case] expected: i32) {
+        assert_eq!(get_tick_at_sqrt_ratio(sqrt_price_x96).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(-50_000)]
+    #[case(-1)]
+    #[case(1)]
+    #[case(200_000)]
+    fn test_tick_round_trip(#[case] tick: i32) {
+        let sqrt_price_x96 = get_sqrt_ratio_at_tick(tick).unwrap();
+        assert_eq!(get_tick_at_sqrt_ratio(sqrt_price_x96).unwrap(), tick);
+        assert_eq!(
+            get_tick_at_sqrt_ratio(sqrt_price_x96 - U256::from(1u8)).unwrap(),
+            tick - 1
+        );
+    }
+
+    #[rstest]
+    fn test_get_tick_at_sqrt_ratio_out_of_range() {
+        assert!(get_tick_at_sqrt_ratio(MIN_SQRT_RATIO - U256::from(1u8)).is_err());
+        assert!(get_tick_at_sqrt_ratio(MAX_SQRT_RATIO).is_err());
+    }
+
+    #[rstest]
+    fn test_mul_div_rounding() {
+        let a = U256::from(10u8);
+        let b = U256::from(10u8);
+        let d = U256::from(3u8);
+        assert_eq!(mul_div(a, b, d).unwrap(), U256::from(33u8));
+        assert_eq!(mul_div_rounding_up(a, b, d).unwrap(), U256::from(34u8));
+        assert!(mul_div(a, b, U256::ZERO).is_err());
+        assert!(mul_div(U256::MAX, U256::MAX, U256::from(1u8)).is_err());
+    }
+
+    #[rstest]
+    fn test_mul_div_full_precision() {
+        // (2^256 - 1)^2 / (2^256 - 1) = 2^256 - 1 requires a 512-bit intermediate
+        assert_eq!(mul_div(U256::MAX, U256::MAX, U256::MAX).unwrap(), U256::MAX);
+    }
+
+    #[rstest]
+    fn test_get_amount0_delta() {
+        let liquidity = expand_to_18_decimals(1);
+        let up = get_amount0_delta(price_1_1(), price_121_100(), liquidity, true).unwrap();
+        let down = get_amount0_delta(price_1_1(), price_121_100(), liquidity, false).unwrap();
+        assert_eq!(up, u256("90909090909090910"));
+        assert_eq!(down, u256("90909090909090909"));
+    }
+
+    #[rstest]
+    fn test_get_amount1_delta() {
+        let liquidity = expand_to_18_decimals(1);
+        let up = get_amount1_delta(price_1_1(), price_121_100(), liquidity, true).unwrap();
+        let down = get_amount1_delta(price_1_1(), price_121_100(), liquidity, false).unwrap();
+        assert_eq!(up, u256("100000000000000000"));
+        assert_eq!(down, u256("99999999999999999"));
+    }
+
+    #[rstest]
+    fn test_get_amount_delta_zero_liquidity() {
+        assert!(
+            get_amount0_delta(price_1_1(), price_121_100(), 0, true)
+                .unwrap()
+                .is_zero()
+        );
+        assert!(
+            get_amount1_delta(price_1_1(), price_121_100(), 0, true)
+                .unwrap()
+                .is_zero()
+        );
+    }
+
+    #[rstest]
+    fn test_compute_swap_step_exact_in_capped_at_target() {
+        let step = compute_swap_step(
+            price_1_1(),
+            price_101_100(),
+            expand_to_18_decimals(2),
+            U256::from(expand_to_18_decimals(1)),
+            true,
+            600,
+        )
+        .unwrap();
+
+        assert_eq!(step.sqrt_price_next_x96, price_101_100());
+        assert_eq!(step.amount_in, u256("9975124224178055"));
+        assert_eq!(step.amount_out, u256("9925619580021728"));
+        assert_eq!(step.fee_amount, u256("5988667735148"));
+    }
+
+    #[rstest]
+    fn test_compute_swap_step_exact_out_capped_at_target() {
+        let step = compute_swap_step(
+            price_1_1(),
+            price_101_100(),
+            expand_to_18_decimals(2),
+            U256::from(expand_to_18_decimals(1)),
+            false,
+            600,
+        )
+        .unwrap();
+
+        assert_eq!(step.sqrt_price_next_x96, price_101_100());
+        assert_eq!(step.amount_in, u256("9975124224178055"));
+        assert_eq!(step.amount_out, u256("9925619580021728"));
+        assert_eq!(step.fee_amount, u256("5988667735148"));
+    }
+
+    #[rstest]
+    fn test_compute_swap_step_exact_in_fully_spent() {
+        let step = compute_swap_step(
+            price_1_1(),
+            u256("250541448375047931186413801569"), // sqrt(10) * 2^96
+            expand_to_18_decimals(2),
+            U256::from(expand_to_18_decimals(1)),
+            true,
+            600,
+        )
+        .unwrap();
+
+        assert_eq!(
+            step.sqrt_price_next_x96,
+            u256("118818475322642227089037862318")
+        );
+        assert_eq!(step.amount_in, u256("999400000000000000"));
+        assert_eq!(step.amount_out, u256("666399946655997866"));
+        assert_eq!(step.fee_amount, u256("600000000000000"));
+    }
+
+    #[rstest]
+    fn test_sqrt_price_x96_to_price() {
+        assert!((sqrt_price_x96_to_price(q96(), 18, 18) - 1.0).abs() < 1e-12);
+        assert!((sqrt_price_x96_to_price(price_121_100(), 18, 18) - 1.21).abs() < 1e-12);
+        assert!((sqrt_price_x96_to_price(q96(), 18, 6) - 1e12).abs() < 1e-0);
+    }
+}
@@ -18,6 +18,10 @@ pub mod block;
 pub mod chain;
 pub mod dex;
 pub mod hex;
+pub mod liquidity;
+pub mod math;
+pub mod pool_state;
+pub mod position;
 pub mod rpc;
 pub mod swap;
 pub mod token;
@@ -0,0 +1,991 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Concentrated liquidity pool state reconstruction and swap simulation.
+//!
+//! [`PoolState`] mirrors the storage of a Uniswap V3 pool (and the equivalent V4 pool manager
+//! state, ignoring hooks) so that the pool can be rebuilt by replaying mint, burn and swap
+//! events, and then used to quote swaps and derive an order book view.
+
+use std::collections::{BTreeMap, HashMap};
+
+use alloy_primitives::{I256, U256};
+
+use crate::defi::{
+    amm::SharedPool,
+    math::{
+        MAX_SQRT_RATIO, MAX_TICK, MIN_SQRT_RATIO, MIN_TICK, add_liquidity_delta, compute_swap_step,
+        get_amount0_delta, get_sqrt_ratio_at_tick, get_tick_at_sqrt_ratio, mul_div, q128,
+        sqrt_price_x96_to_price, u256_to_f64,
+    },
+};
+
+/// Liquidity and fee accounting stored for an initialized tick.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TickInfo {
+    /// The total position liquidity that references this tick.
+    pub liquidity_gross: u128,
+    /// The liquidity added (subtracted) when the tick is crossed left to right (right to left).
+    pub liquidity_net: i128,
+    /// The fee growth per unit of liquidity of token0 on the other side of this tick.
+    pub fee_growth_outside_0_x128: U256,
+    /// The fee growth per unit of liquidity of token1 on the other side of this tick.
+    pub fee_growth_outside_1_x128: U256,
+}
+
+/// The result of a simulated swap against a [`PoolState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapQuote {
+    /// The total amount of the input token paid, including fees.
+    pub amount_in: U256,
+    /// The total amount of the output token received.
+    pub amount_out: U256,
+    /// The total fee paid in the input token.
+    pub fee_amount: U256,
+    /// The square root price of the pool after the swap.
+    pub sqrt_price_x96_after: U256,
+    /// The current tick of the pool after the swap.
+    pub tick_after: i32,
+    /// The in-range liquidity of the pool after the swap.
+    pub liquidity_after: u128,
+    /// The number of initialized ticks crossed during the swap.
+    pub ticks_crossed: u32,
+}
+
+/// A single price level of a [`PoolBookView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoolBookLevel {
+    /// The price of token0 in units of token1 at the far edge of the level.
+    pub price: f64,
+    /// The amount of token0 available within the level.
+    pub size: f64,
+}
+
+/// An order book representation of the liquidity in a concentrated liquidity pool.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PoolBookView {
+    /// The bid levels (pool buys token0), ordered from best to worst price.
+    pub bids: Vec<PoolBookLevel>,
+    /// The ask levels (pool sells token0), ordered from best to worst price.
+    pub asks: Vec<PoolBookLevel>,
+}
+
+/// A tick crossed during a swap, along with the global fee growth at the time of crossing.
+#[derive(Debug, Clone, Copy)]
+struct CrossedTick {
+    tick: i32,
+    fee_growth_global_0_x128: U256,
+    fee_growth_global_1_x128: U256,
+}
+
+/// The full outcome of a simulated swap, used to either quote or commit the swap.
+#[derive(Debug, Clone)]
+struct SwapOutcome {
+    quote: SwapQuote,
+    fee_growth_global_0_x128: U256,
+    fee_growth_global_1_x128: U256,
+    crossed: Vec<CrossedTick>,
+}
+
+/// Represents the reconstructed state of a concentrated liquidity pool.
+#[derive(Debug, Clone)]
+pub struct PoolState {
+    /// The pool definition this state belongs to.
+    pub pool: SharedPool,
+    /// The current Q64.96 square root price, zero until the pool is initialized.
+    pub sqrt_price_x96: U256,
+    /// The current tick.
+    pub tick: i32,
+    /// The currently in-range liquidity.
+    pub liquidity: u128,
+    /// The global fee growth per unit of liquidity of token0.
+    pub fee_growth_global_0_x128: U256,
+    /// The global fee growth per unit of liquidity of token1.
+    pub fee_growth_global_1_x128: U256,
+    ticks: BTreeMap<i32, TickInfo>,
+    tick_bitmap: HashMap<i16, U256>,
+}
+
+impl PoolState {
+    /// Creates a new uninitialized [`PoolState`] instance for the given `pool`.
+    #[must_use]
+    pub fn new(pool: SharedPool) -> Self {
+        Self {
+            pool,
+            sqrt_price_x96: U256::ZERO,
+            tick: 0,
+            liquidity: 0,
+            fee_growth_global_0_x128: U256::ZERO,
+            fee_growth_global_1_x128: U256::ZERO,
+            ticks: BTreeMap::new(),
+            tick_bitmap: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the pool has been initialized with a starting price.
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        !self.sqrt_price_x96.is_zero()
+    }
+
+    /// Returns the tick info for the given `tick`, if initialized.
+    #[must_use]
+    pub fn tick_info(&self, tick: i32) -> Option<&TickInfo> {
+        self.ticks.get(&tick)
+    }
+
+    /// Returns an iterator over all initialized ticks in ascending order.
+    pub fn initialized_ticks(&self) -> impl Iterator<Item = (&i32, &TickInfo)> {
+        self.ticks.iter()
+    }
+
+    /// Returns the current price of token0 in units of token1, adjusted for token decimals.
+    #[must_use]
+    pub fn price(&self) -> f64 {
+        sqrt_price_x96_to_price(
+            self.sqrt_price_x96,
+            self.pool.token0.decimals,
+            self.pool.token1.decimals,
+        )
+    }
+
+    /// Initializes the pool with the given starting square root price.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool is already initialized or the price is out of range.
+    pub fn initialize(&mut self, sqrt_price_x96: U256) -> anyhow::Result<()> {
+        if self.is_initialized() {
+            anyhow::bail!("Pool {} already initialized", self.pool.address);
+        }
+
+        self.tick = get_tick_at_sqrt_ratio(sqrt_price_x96)?;
+        self.sqrt_price_x96 = sqrt_price_x96;
+        Ok(())
+    }
+
+    /// Applies a mint of `liquidity` to the range [`tick_lower`, `tick_upper`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool is uninitialized, the ticks are invalid, or liquidity overflows.
+    pub fn apply_mint(
+        &mut self,
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity: u128,
+    ) -> anyhow::Result<()> {
+        let delta = i128::try_from(liquidity)?;
+        self.modify_position(tick_lower, tick_upper, delta)
+    }
+
+    /// Applies a burn of `liquidity` from the range [`tick_lower`, `tick_upper`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool is uninitialized, the ticks are invalid, or liquidity underflows.
+    pub fn apply_burn(
+        &mut self,
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity: u128,
+    ) -> anyhow::Result<()> {
+        let delta = i128::try_from(liquidity)?;
+        self.modify_position(tick_lower, tick_upper, -delta)
+    }
+
+    /// Applies a swap event emitted by the pool.
+    ///
+    /// The swap is simulated up to the reported price so that fee growth and tick crossings are
+    /// accounted for, then the reported price, tick and liquidity are taken as authoritative.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool is uninitialized or the simulation fails.
+    pub fn apply_swap(
+        &mut self,
+        amount0: I256,
+        amount1: I256,
+        sqrt_price_x96: U256,
+        liquidity: u128,
+        tick: i32,
+    ) -> anyhow::Result<()> {
+        self.check_initialized()?;
+
+        let zero_for_one = amount0.is_positive();
+        let amount_in = if zero_for_one {
+            amount0.unsigned_abs()
+        } else {
+            amount1.unsigned_abs()
+        };
+
+        if !amount_in.is_zero() && sqrt_price_x96 != self.sqrt_price_x96 {
+            let outcome = self.simulate(zero_for_one, true, amount_in, Some(sqrt_price_x96))?;
+            self.commit(&outcome, zero_for_one)?;
+        }
+
+        self.sqrt_price_x96 = sqrt_price_x96;
+        self.tick = tick;
+        self.liquidity = liquidity;
+        Ok(())
+    }
+
+    /// Quotes a swap of an exact `amount_in` of the input token.
+    ///
+    /// The swap stops early if `sqrt_price_limit_x96` is reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool is uninitialized or the price limit is invalid.
+    pub fn quote_exact_input(
+        &self,
+        zero_for_one: bool,
+        amount_in: U256,
+        sqrt_price_limit_x96: Option<U256>,
+    ) -> anyhow::Result<SwapQuote> {
+        Ok(self
+            .simulate(zero_for_one, true, amount_in, sqrt_price_limit_x96)?
+            .quote)
+    }
+
+    /// Quotes a swap for an exact `amount_out` of the output token.
+    ///
+    /// The swap stops early if `sqrt_price_limit_x96` is reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool is uninitialized or the price limit is invalid.
+    pub fn quote_exact_output(
+        &self,
+        zero_for_one: bool,
+        amount_out: U256,
+        sqrt_price_limit_x96: Option<U256>,
+    ) -> anyhow::Result<SwapQuote> {
+        Ok(self
+            .simulate(zero_for_one, false, amount_out, sqrt_price_limit_x96)?
+            .quote)
+    }
+
+    /// Executes a swap against the pool state, updating price, liquidity and fee growth.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool is uninitialized or the price limit is invalid.
+    pub fn swap(
+        &mut self,
+        zero_for_one: bool,
+        exact_input: bool,
+        amount: U256,
+        sqrt_price_limit_x96: Option<U256>,
+    ) -> anyhow::Result<SwapQuote> {
+        let outcome = self.simulate(zero_for_one, exact_input, amount, sqrt_price_limit_x96)?;
+        self.commit(&outcome, zero_for_one)?;
+        Ok(outcome.quote)
+    }
+
+    /// Returns the fee growth per unit of liquidity inside the range [`tick_lower`, `tick_upper`).
+    ///
+    /// Values are modular (as on-chain) and only meaningful as differences between snapshots.
+    #[must_use]
+    pub fn fee_growth_inside(&self, tick_lower: i32, tick_upper: i32) -> (U256, U256) {
+        let lower = self.ticks.get(&tick_lower).copied().unwrap_or_default();
+        let upper = self.ticks.get(&tick_upper).copied().unwrap_or_default();
+
+        let (below_0, below_1) = if self.tick >= tick_lower {
+            (
+                lower.fee_growth_outside_0_x128,
+                lower.fee_growth_outside_1_x128,
+            )
+        } else {
+            (
+                self.fee_growth_global_0_x128
+                    .wrapping_sub(lower.fee_growth_outside_0_x128),
+                self.fee_growth_global_1_x128
+                    .wrapping_sub(lower.fee_growth_outside_1_x128),
+            )
+        };
+
+        let (above_0, above_1) = if self.tick < tick_upper {
+            (
+                upper.fee_growth_outside_0_x128,
+                upper.fee_growth_outside_1_x128,
+            )
+        } else {
+            (
+                self.fee_growth_global_0_x128
+                    .wrapping_sub(upper.fee_growth_outside_0_x128),
+                self.fee_growth_global_1_x128
+                    .wrapping_sub(upper.fee_growth_outside_1_x128),
+            )
+        };
+
+        (
+            self.fee_growth_global_0_x128
+                .wrapping_sub(below_0)
+                .wrapping_sub(above_0),
+            self.fee_growth_global_1_x128
+                .wrapping_sub(below_1)
+                .wrapping_sub(above_1),
+        )
+    }
+
+    /// Derives an order book view with up to `depth` levels per side.
+    ///
+    /// Each level spans one tick spacing, with the size being the amount of token0 the pool
+    /// would buy (bids) or sell (asks) when the price moves across the level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool is uninitialized.
+    pub fn book_view(&self, depth: usize) -> anyhow::Result<PoolBookView> {
+        self.check_initialized()?;
+
+        Ok(PoolBookView {
+            bids: self.book_side(depth, true)?,
+            asks: self.book_side(depth, false)?,
+        })
+    }
+
+    fn book_side(&self, depth: usize, zero_for_one: bool) -> anyhow::Result<Vec<PoolBookLevel>> {
+        let spacing = self.tick_spacing();
+        let decimals0 = self.pool.token0.decimals;
+        let decimals1 = self.pool.token1.decimals;
+        let scale0 = 10f64.powi(i32::from(decimals0));
+
+        let mut levels = Vec::with_capacity(depth);
+        let mut liquidity = self.liquidity;
+        let mut sqrt_price = self.sqrt_price_x96;
+        let mut tick = self.tick;
+
+        while levels.len() < depth {
+            // Jump directly to the next initialized tick when there is no liquidity in range
+            let boundary = if liquidity == 0 {
+                let next = if zero_for_one {
+                    self.ticks.range(..=tick).next_back()
+                } else {
+                    self.ticks.range(tick + 1..).next()
+                };
+                match next {
+                    Some((next_tick, _)) => *next_tick,
+                    None => break,
+                }
+            } else if zero_for_one {
+                tick.div_euclid(spacing) * spacing
+            } else {
+                (tick.div_euclid(spacing) + 1) * spacing
+            };
+            let boundary = boundary.clamp(MIN_TICK, MAX_TICK);
+            let boundary_sqrt_price = get_sqrt_ratio_at_tick(boundary)?;
+
+            if liquidity > 0 && boundary_sqrt_price != sqrt_price {
+                let amount0 = get_amount0_delta(sqrt_price, boundary_sqrt_price, liquidity, false)?;
+                levels.push(PoolBookLevel {
+                    price: sqrt_price_x96_to_price(boundary_sqrt_price, decimals0, decimals1),
+                    size: u256_to_f64(amount0) / scale0,
+                });
+            }
+
+            if let Some(info) = self.ticks.get(&boundary) {
+                let net = if zero_for_one {
+                    -info.liquidity_net
+                } else {
+                    info.liquidity_net
+                };
+                liquidity = add_liquidity_delta(liquidity, net)?;
+            }
+
+            if boundary == MIN_TICK || boundary == MAX_TICK {
+                break;
+            }
+
+            sqrt_price = boundary_sqrt_price;
+            tick = if zero_for_one { boundary - 1 } else { boundary };
+        }
+
+        Ok(levels)
+    }
+
+    fn check_initialized(&self) -> anyhow::Result<()> {
+        if !self.is_initialized() {
+            anyhow::bail!("Pool {} not initialized", self.pool.address);
+        }
+        Ok(())
+    }
+
+    fn tick_spacing(&self) -> i32 {
+        i32::try_from(self.pool.tick_spacing.max(1)).unwrap_or(i32::MAX)
+    }
+
+    fn modify_position(
+        &mut self,
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity_delta: i128,
+    ) -> anyhow::Result<()> {
+        self.check_initialized()?;
+
+        if tick_lower >= tick_upper {
+            anyhow::bail!("Invalid tick range [{tick_lower}, {tick_upper})");
+        }
+        if tick_lower < MIN_TICK || tick_upper > MAX_TICK {
+            anyhow::bail!("Tick range [{tick_lower}, {tick_upper}) out of bounds");
+        }
+        if liquidity_delta == 0 {
+            return Ok(());
+        }
+
+        let flipped_lower = self.update_tick(tick_lower, liquidity_delta, false)?;
+        let flipped_upper = self.update_tick(tick_upper, liquidity_delta, true)?;
+
+        if flipped_lower {
+            self.flip_tick(tick_lower);
+        }
+        if flipped_upper {
+            self.flip_tick(tick_upper);
+        }
+
+        // Clear tick data that is no longer needed
+        if liquidity_delta < 0 {
+            if flipped_lower {
+                self.ticks.remove(&tick_lower);
+            }
+            if flipped_upper {
+                self.ticks.remove(&tick_upper);
+            }
+        }
+
+        if tick_lower <= self.tick && self.tick < tick_upper {
+            self.liquidity = add_liquidity_delta(self.liquidity, liquidity_delta)?;
+        }
+
+        Ok(())
+    }
+
+    fn update_tick(
+        &mut self,
+        tick: i32,
+        liquidity_delta: i128,
+        upper: bool,
+    ) -> anyhow::Result<bool> {
+        let current_tick = self.tick;
+        let fee_growth_global_0_x128 = self.fee_growth_global_0_x128;
+        let fee_growth_global_1_x128 = self.fee_growth_global_1_x128;
+
+        let info = self.ticks.entry(tick).or_default();
+        let liquidity_gross_before = info.liquidity_gross;
+        let liquidity_gross_after = add_liquidity_delta(liquidity_gross_before, liquidity_delta)?;
+
+        let flipped = (liquidity_gross_after == 0) != (liquidity_gross_before == 0);
+
+        if liquidity_gross_before == 0 {
+            // By convention, all growth before a tick was initialized happened below the tick
+            if tick <= current_tick {
+                info.fee_growth_outside_0_x128 = fee_growth_global_0_x128;
+                info.fee_growth_outside_1_x128 = fee_growth_global_1_x128;
+            }
+        }
+
+        info.liquidity_gross = liquidity_gross_after;
+        info.liquidity_net = if upper {
+            info.liquidity_net.checked_sub(liquidity_delta)
+        } else {
+            info.liquidity_net.checked_add(liquidity_delta)
+        }
+        .ok_or_else(|| anyhow::anyhow!("Liquidity net overflow at tick {tick}"))?;
+
+        Ok(flipped)
+    }
+
+    fn bitmap_position(compressed: i32) -> (i16, u8) {
+        ((compressed >> 8) as i16, (compressed & 0xff) as u8)
+    }
+
+    fn compress(&self, tick: i32) -> i32 {
+        tick.div_euclid(self.tick_spacing())
+    }
+
+    fn flip_tick(&mut self, tick: i32) {
+        let (word_pos, bit_pos) = Self::bitmap_position(self.compress(tick));
+        let word = self.tick_bitmap.entry(word_pos).or_default();
+        *word ^= U256::from(1u8) << bit_pos;
+    }
+
+    /// Returns the next initialized tick contained in the same bitmap word as `tick`, searching
+    /// to the left (less than or equal) when `lte`, otherwise to the right.
+    fn next_initialized_tick_within_one_word(&self, tick: i32, lte: bool) -> (i32, bool) {
+        let spacing = self.tick_spacing();
+        let compressed = self.compress(tick);
+        let one = U256::from(1u8);
+
+        if lte {
+            let (word_pos, bit_pos) = Self::bitmap_position(compressed);
+            let mask = (one << bit_pos) - one + (one << bit_pos);
+            let word = self.tick_bitmap.get(&word_pos).copied().unwrap_or_default();
+            let masked = word & mask;
+
+            if masked.is_zero() {
+                ((compressed - i32::from(bit_pos)) * spacing, false)
+            } else {
+                let msb = 255 - masked.leading_zeros() as i32;
+                ((compressed - (i32::from(bit_pos) - msb)) * spacing, true)
+            }
+        } else {
+            let (word_pos, bit_pos) = Self::bitmap_position(compressed + 1);
+            let mask = !((one << bit_pos) - one);
+            let word = self.tick_bitmap.get(&word_pos).copied().unwrap_or_default();
+            let masked = word & mask;
+
+            if masked.is_zero() {
+                (
+                    (compressed + 1 + (255 - i32::from(bit_pos))) * spacing,
+                    false,
+                )
+            } else {
+                let lsb = masked.trailing_zeros() as i32;
+                (
+                    (compressed + 1 + (lsb - i32::from(bit_pos))) * spacing,
+                    true,
+                )
+            }
+        }
+    }
+
+    fn simulate(
+        &self,
+        zero_for_one: bool,
+        exact_input: bool,
+        amount: U256,
+        sqrt_price_limit_x96: Option<U256>,
+    ) -> anyhow::Result<SwapOutcome> {
+        self.check_initialized()?;
+
+        if amount.is_zero() {
+            anyhow::bail!("Swap amount cannot be zero");
+        }
+
+        let one = U256::from(1u8);
+        let sqrt_price_limit_x96 = sqrt_price_limit_x96.unwrap_or(if zero_for_one {
+            MIN_SQRT_RATIO + one
+        } else {
+            MAX_SQRT_RATIO - one
+        });
+
+        let valid_limit = if zero_for_one {
+            sqrt_price_limit_x96 < self.sqrt_price_x96 && sqrt_price_limit_x96 > MIN_SQRT_RATIO
+        } else {
+            sqrt_price_limit_x96 > self.sqrt_price_x96 && sqrt_price_limit_x96 < MAX_SQRT_RATIO
+        };
+        if !valid_limit {
+            anyhow::bail!(
+                "Invalid price limit {sqrt_price_limit_x96} for current price {}",
+                self.sqrt_price_x96
+            );
+        }
+
+        let fee_pips = self.pool.fee;
+        let mut amount_remaining = amount;
+        let mut amount_in = U256::ZERO;
+        let mut amount_out = U256::ZERO;
+        let mut fee_amount = U256::ZERO;
+        let mut sqrt_price_x96 = self.sqrt_price_x96;
+        let mut tick = self.tick;
+        let mut liquidity = self.liquidity;
+        let mut fee_growth_global_x128 = if zero_for_one {
+            self.fee_growth_global_0_x128
+        } else {
+            self.fee_growth_global_1_x128
+        };
+        let mut crossed = Vec::new();
+
+        while !amount_remaining.is_zero() && sqrt_price_x96 != sqrt_price_limit_x96 {
+            let sqrt_price_start_x96 = sqrt_price_x96;
+
+            let (tick_next, initialized) =
+                self.next_initialized_tick_within_one_word(tick, zero_for_one);
+            let tick_next = tick_next.clamp(MIN_TICK, MAX_TICK);
+            let sqrt_price_next_x96 = get_sqrt_ratio_at_tick(tick_next)?;
+
+            let use_limit = if zero_for_one {
+                sqrt_price_next_x96 < sqrt_price_limit_x96
+            } else {
+                sqrt_price_next_x96 > sqrt_price_limit_x96
+            };
+            let sqrt_price_target_x96 = if use_limit {
+                sqrt_price_limit_x96
+            } else {
+                sqrt_price_next_x96
+            };
+
+            let step = compute_swap_step(
+                sqrt_price_x96,
+                sqrt_price_target_x96,
+                liquidity,
+                amount_remaining,
+                exact_input,
+                fee_pips,
+            )?;
+            sqrt_price_x96 = step.sqrt_price_next_x96;
+
+            if exact_input {
+                amount_remaining -= step.amount_in + step.fee_amount;
+            } else {
+                amount_remaining -= step.amount_out;
+            }
+            amount_in += step.amount_in + step.fee_amount;
+            amount_out += step.amount_out;
+            fee_amount += step.fee_amount;
+
+            if liquidity > 0 {
+                fee_growth_global_x128 = fee_growth_global_x128.wrapping_add(mul_div(
+                    step.fee_amount,
+                    q128(),
+                    U256::from(liquidity),
+                )?);
+            }
+
+            if sqrt_price_x96 == sqrt_price_next_x96 {
+                if initialized {
+                    let (fee_growth_global_0_x128, fee_growth_global_1_x128) = if zero_for_one {
+                        (fee_growth_global_x128, self.fee_growth_global_1_x128)
+                    } else {
+                        (self.fee_growth_global_0_x128, fee_growth_global_x128)
+                    };
+                    crossed.push(CrossedTick {
+                        tick: tick_next,
+                        fee_growth_global_0_x128,
+                        fee_growth_global_1_x128,
+                    });
+
+                    let liquidity_net = self
+                        .ticks
+                        .get(&tick_next)
+                        .map_or(0, |info| info.liquidity_net);
+                    let liquidity_net = if zero_for_one {
+                        -liquidity_net
+                    } else {
+                        liquidity_net
+                    };
+                    liquidity = add_liquidity_delta(liquidity, liquidity_net)?;
+                }
+                tick = if zero_for_one {
+                    tick_next - 1
+                } else {
+                    tick_next
+                };
+            } else if sqrt_price_x96 != sqrt_price_start_x96 {
+                tick = get_tick_at_sqrt_ratio(sqrt_price_x96)?;
+            }
+        }
+
+        let (fee_growth_global_0_x128, fee_growth_global_1_x128) = if zero_for_one {
+            (fee_growth_global_x128, self.fee_growth_global_1_x128)
+        } else {
+            (self.fee_growth_global_0_x128, fee_growth_global_x128)
+        };
+
+        Ok(SwapOutcome {
+            quote: SwapQuote {
+                amount_in,
+                amount_out,
+                fee_amount,
+                sqrt_price_x96_after: sqrt_price_x96,
+                tick_after: tick,
+                liquidity_after: liquidity,
+                ticks_crossed: crossed.len() as u32,
+            },
+            fee_growth_global_0_x128,
+            fee_growth_global_1_x128,
+            crossed,
+        })
+    }
+
+    fn commit(&mut self, outcome: &SwapOutcome, zero_for_one: bool) -> anyhow::Result<()> {
+        for crossed in &outcome.crossed {
+            if let Some(info) = self.ticks.get_mut(&crossed.tick) {
+                info.fee_growth_outside_0_x128 = crossed
+                    .fee_growth_global_0_x128
+                    .wrapping_sub(info.fee_growth_outside_0_x128);
+                info.fee_growth_outside_1_x128 = crossed
+                    .fee_growth_global_1_x128
+                    .wrapping_sub(info.fee_growth_outside_1_x128);
+            } else {
+                anyhow::bail!("Crossed uninitialized tick {}", crossed.tick);
+            }
+        }
+
+        if zero_for_one {
+            self.fee_growth_global_0_x128 = outcome.fee_growth_global_0_x128;
+        } else {
+            self.fee_growth_global_1_x128 = outcome.fee_growth_global_1_x128;
+        }
+        self.sqrt_price_x96 = outcome.quote.sqrt_price_x96_after;
+        self.tick = outcome.quote.tick_after;
+        self.liquidity = outcome.quote.liquidity_after;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use alloy_primitives::Address;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::defi::{
+        amm::Pool,
+        chain::chains,
+        dex::{AmmType, Dex},
+        math::q96,
+        token::Token,
+    };
+
+    const E18: u128 = 1_000_000_000_000_000_000;
+
+    fn u256(value: &str) -> U256 {
+        U256::from_str_radix(value, 10).unwrap()
+    }
+
+    #[fixture]
+    fn pool_state() -> PoolState {
+        let chain = Arc::new(chains::ETHEREUM.clone());
+        let dex = Dex::new(
+            chains::ETHEREUM.clone(),
+            "Uniswap V3",
+            "0x1F98431c8aD98523631AE4a59f267346ea31F984",
+            AmmType::CLAMM,
+            "PoolCreated(address,address,uint24,int24,address)",
+            "Swap(address,address,int256,int256,uint160,uint128,int24)",
+        );
+        let token0 = Token::new(
+            chain.clone(),
+            Address::repeat_byte(0x01),
+            "Token A".to_string(),
+            "TKA".to_string(),
+            18,
+        );
+        let token1 = Token::new(
+            chain.clone(),
+            Address::repeat_byte(0x02),
+            "Token B".to_string(),
+            "TKB".to_string(),
+            18,
+        );
+        let pool = Pool::new(
+            chain,
+            dex,
+            Address::repeat_byte(0x03),
+            0,
+            token0,
+            token1,
+            3000,
+            60,
+        );
+
+        let mut state = PoolState::new(Arc::new(pool));
+        state.initialize(q96()).unwrap();
+        state.apply_mint(-120, 120, E18).unwrap();
+        state.apply_mint(-1200, 1200, E18).unwrap();
+        state
+    }
+
+    #[rstest]
+    fn test_mint_updates_liquidity_and_ticks(pool_state: PoolState) {
+        assert_eq!(pool_state.tick, 0);
+        assert_eq!(pool_state.liquidity, 2 * E18);
+        assert_eq!(pool_state.initialized_ticks().count(), 4);
+
+        let lower = pool_state.tick_info(-120).unwrap();
+        assert_eq!(lower.liquidity_gross, E18);
+        assert_eq!(lower.liquidity_net, E18 as i128);
+
+        let upper = pool_state.tick_info(120).unwrap();
+        assert_eq!(upper.liquidity_net, -(E18 as i128));
+    }
+
+    #[rstest]
+    fn test_burn_clears_ticks(mut pool_state: PoolState) {
+        pool_state.apply_burn(-120, 120, E18).unwrap();
+
+        assert_eq!(pool_state.liquidity, E18);
+        assert!(pool_state.tick_info(-120).is_none());
+        assert!(pool_state.tick_info(120).is_none());
+        assert!(pool_state.apply_burn(-120, 120, E18).is_err());
+    }
+
+    #[rstest]
+    fn test_initialize_twice_fails(mut pool_state: PoolState) {
+        assert!(pool_state.initialize(q96()).is_err());
+    }
+
+    #[rstest]
+    fn test_quote_exact_input_within_range(pool_state: PoolState) {
+        let quote = pool_state
+            .quote_exact_input(true, U256::from(10u128.pow(16)), None)
+            .unwrap();
+
+        assert_eq!(quote.amount_in, U256::from(10u128.pow(16)));
+        assert_eq!(quote.amount_out, u256("9920546077802156"));
+        assert_eq!(quote.fee_amount, u256("30000000000000"));
+        assert_eq!(
+            quote.sqrt_price_x96_after,
+            u256("78835169195823159145205102899")
+        );
+        assert_eq!(quote.tick_after, -100);
+        assert_eq!(quote.liquidity_after, 2 * E18);
+        assert_eq!(quote.ticks_crossed, 0);
+    }
+
+    #[rstest]
+    fn test_quote_exact_input_crossing_tick(pool_state: PoolState) {
+        let quote = pool_state
+            .quote_exact_input(true, U256::from(2 * 10u128.pow(16)), None)
+            .unwrap();
+
+        assert_eq!(quote.amount_out, u256("19712835500178989"));
+        assert_eq!(quote.fee_amount, u256("60000000000001"));
+        assert_eq!(
+            quote.sqrt_price_x96_after,
+            u256("78140272871046568473555756586")
+        );
+        assert_eq!(quote.tick_after, -277);
+        assert_eq!(quote.liquidity_after, E18);
+        assert_eq!(quote.ticks_crossed, 1);
+    }
+
+    #[rstest]
+    fn test_quote_exact_output_crossing_tick(pool_state: PoolState) {
+        let quote = pool_state
+            .quote_exact_output(false, U256::from(2 * 10u128.pow(16)), None)
+            .unwrap();
+
+        assert_eq!(quote.amount_in, u256("20296190674634249"));
+        assert_eq!(quote.amount_out, U256::from(2 * 10u128.pow(16)));
+        assert_eq!(quote.fee_amount, u256("60888572023904"));
+        assert_eq!(
+            quote.sqrt_price_x96_after,
+            u256("80354594289157601524947980159")
+        );
+        assert_eq!(quote.tick_after, 282);
+        assert_eq!(quote.ticks_crossed, 1);
+    }
+
+    #[rstest]
+    fn test_quote_drains_all_liquidity(pool_state: PoolState) {
+        let quote = pool_state
+            .quote_exact_input(true, U256::from(10u128.pow(30)), None)
+            .unwrap();
+
+        assert_eq!(quote.liquidity_after, 0);
+        assert_eq!(quote.ticks_crossed, 2);
+        assert_eq!(quote.sqrt_price_x96_after, MIN_SQRT_RATIO + U256::from(1u8));
+        assert!(quote.amount_in < U256::from(10u128.pow(30)));
+    }
+
+    #[rstest]
+    fn test_quote_does_not_mutate_state(pool_state: PoolState) {
+        let _ = pool_state
+            .quote_exact_input(true, U256::from(2 * 10u128.pow(16)), None)
+            .unwrap();
+
+        assert_eq!(pool_state.sqrt_price_x96, q96());
+        assert_eq!(pool_state.liquidity, 2 * E18);
+        assert!(pool_state.fee_growth_global_0_x128.is_zero());
+    }
+
+    #[rstest]
+    fn test_quote_with_invalid_price_limit(pool_state: PoolState) {
+        let result = pool_state.quote_exact_input(true, U256::from(1000u32), Some(q96()));
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_swap_updates_fee_growth(mut pool_state: PoolState) {
+        let quote = pool_state
+            .swap(true, true, U256::from(10u128.pow(16)), None)
+            .unwrap();
+
+        let expected = mul_div(quote.fee_amount, q128(), U256::from(2 * E18)).unwrap();
+        assert_eq!(pool_state.fee_growth_global_0_x128, expected);
+        assert!(pool_state.fee_growth_global_1_x128.is_zero());
+        assert_eq!(pool_state.tick, -100);
+
+        let (inside_0, _) = pool_state.fee_growth_inside(-120, 120);
+        assert_eq!(inside_0, expected);
+    }
+
+    #[rstest]
+    fn test_swap_crossing_updates_fee_growth_outside(mut pool_state: PoolState) {
+        pool_state
+            .swap(true, true, U256::from(2 * 10u128.pow(16)), None)
+            .unwrap();
+
+        let lower = pool_state.tick_info(-120).unwrap();
+        assert!(!lower.fee_growth_outside_0_x128.is_zero());
+
+        // The narrow position earned fees only while in range
+        let (narrow_0, _) = pool_state.fee_growth_inside(-120, 120);
+        let (wide_0, _) = pool_state.fee_growth_inside(-1200, 1200);
+        assert!(narrow_0 < wide_0);
+    }
+
+    #[rstest]
+    fn test_apply_swap_event(mut pool_state: PoolState) {
+        let sqrt_price_x96 = u256("78140272871046568473555756586");
+        pool_state
+            .apply_swap(
+                I256::try_from(2 * 10i128.pow(16)).unwrap(),
+                I256::try_from(-19_712_835_500_178_989i128).unwrap(),
+                sqrt_price_x96,
+                E18,
+                -277,
+            )
+            .unwrap();
+
+        assert_eq!(pool_state.sqrt_price_x96, sqrt_price_x96);
+        assert_eq!(pool_state.tick, -277);
+        assert_eq!(pool_state.liquidity, E18);
+        assert!(!pool_state.fee_growth_global_0_x128.is_zero());
+    }
+
+    #[rstest]
+    fn test_book_view(pool_state: PoolState) {
+        let book = pool_state.book_view(3).unwrap();
+
+        assert_eq!(book.bids.len(), 3);
+        assert_eq!(book.asks.len(), 3);
+        assert!(book.bids[0].price < 1.0);
+        assert!(book.asks[0].price > 1.0);
+        assert!(book.bids.windows(2).all(|w| w[0].price > w[1].price));
+        assert!(book.asks.windows(2).all(|w| w[0].price < w[1].price));
+
+        // Third level is outside the narrow position so has less liquidity
+        assert!(book.asks[2].size < book.asks[1].size);
+        assert!(book.bids[2].size < book.bids[1].size);
+    }
+
+    #[rstest]
+    fn test_book_view_stops_without_liquidity(pool_state: PoolState) {
+        let book = pool_state.book_view(100).unwrap();
+
+        // 20 levels of 60 ticks within the wide position on each side
+        assert_eq!(book.bids.len(), 20);
+        assert_eq!(book.asks.len(), 20);
+    }
+}
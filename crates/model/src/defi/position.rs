@@ -0,0 +1,585 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Liquidity provider (LP) position tracking for DEX liquidity pools.
+
+use std::fmt::Display;
+
+use alloy_primitives::{Address, U256};
+
+use crate::defi::{
+    amm::SharedPool,
+    liquidity::{PoolFeeCollect, PoolLiquidityUpdate, PoolLiquidityUpdateType},
+    math::{
+        get_amount0_delta, get_amount1_delta, get_sqrt_ratio_at_tick, mul_div, q128,
+        sqrt_price_x96_to_price, u256_to_f64,
+    },
+    pool_state::PoolState,
+};
+
+/// Represents a liquidity provider position in a DEX liquidity pool.
+///
+/// A position is identified by its pool, owner and tick range, and is built up by applying the
+/// mint, burn and collect events for that position. Token amounts are in human units (adjusted
+/// for token decimals), and values are expressed in units of token1.
+#[derive(Debug, Clone)]
+pub struct PoolPosition {
+    /// The pool the position belongs to.
+    pub pool: SharedPool,
+    /// The blockchain address of the position owner.
+    pub owner: Address,
+    /// The lower tick of the position range.
+    pub tick_lower: i32,
+    /// The upper tick of the position range.
+    pub tick_upper: i32,
+    /// The current liquidity of the position.
+    pub liquidity: u128,
+    /// The total amount of token0 deposited.
+    pub deposited0: f64,
+    /// The total amount of token1 deposited.
+    pub deposited1: f64,
+    /// The total amount of token0 withdrawn.
+    pub withdrawn0: f64,
+    /// The total amount of token1 withdrawn.
+    pub withdrawn1: f64,
+    /// The total fees collected in token0.
+    pub collected_fees0: f64,
+    /// The total fees collected in token1.
+    pub collected_fees1: f64,
+    /// The fees accrued in token0 which have not yet been collected.
+    pub unclaimed_fees0: f64,
+    /// The fees accrued in token1 which have not yet been collected.
+    pub unclaimed_fees1: f64,
+    uncollected_principal0: f64,
+    uncollected_principal1: f64,
+    fee_growth_inside_0_last_x128: U256,
+    fee_growth_inside_1_last_x128: U256,
+}
+
+impl PoolPosition {
+    /// Creates a new empty [`PoolPosition`] instance.
+    #[must_use]
+    pub fn new(pool: SharedPool, owner: Address, tick_lower: i32, tick_upper: i32) -> Self {
+        Self {
+            pool,
+            owner,
+            tick_lower,
+            tick_upper,
+            liquidity: 0,
+            deposited0: 0.0,
+            deposited1: 0.0,
+            withdrawn0: 0.0,
+            withdrawn1: 0.0,
+            collected_fees0: 0.0,
+            collected_fees1: 0.0,
+            unclaimed_fees0: 0.0,
+            unclaimed_fees1: 0.0,
+            uncollected_principal0: 0.0,
+            uncollected_principal1: 0.0,
+            fee_growth_inside_0_last_x128: U256::ZERO,
+            fee_growth_inside_1_last_x128: U256::ZERO,
+        }
+    }
+
+    /// Applies a mint or burn liquidity update to the position.
+    ///
+    /// When tracking fees from a [`PoolState`], call [`PoolPosition::update_fees`] before each
+    /// update so that fees are accrued on the liquidity held prior to the update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update is for a different position, or burns more liquidity
+    /// than the position holds.
+    pub fn apply_update(&mut self, update: &PoolLiquidityUpdate) -> anyhow::Result<()> {
+        self.check_position(
+            update.pool.address,
+            update.owner,
+            update.tick_lower,
+            update.tick_upper,
+        )?;
+
+        let amount0 = update.amount0.as_f64();
+        let amount1 = update.amount1.as_f64();
+
+        match update.kind {
+            PoolLiquidityUpdateType::Mint => {
+                self.liquidity = self
+                    .liquidity
+                    .checked_add(update.position_liquidity)
+                    .ok_or_else(|| anyhow::anyhow!("Position liquidity overflow"))?;
+                self.deposited0 += amount0;
+                self.deposited1 += amount1;
+            }
+            PoolLiquidityUpdateType::Burn => {
+                self.liquidity = self
+                    .liquidity
+                    .checked_sub(update.position_liquidity)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Cannot burn {} liquidity from position with {}",
+                            update.position_liquidity,
+                            self.liquidity
+                        )
+                    })?;
+                self.withdrawn0 += amount0;
+                self.withdrawn1 += amount1;
+                // Burned amounts are owed to the position until collected
+                self.uncollected_principal0 += amount0;
+                self.uncollected_principal1 += amount1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies a collect to the position, splitting the collected amounts into withdrawn
+    /// principal and fees.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collect is for a different position.
+    pub fn apply_collect(&mut self, collect: &PoolFeeCollect) -> anyhow::Result<()> {
+        self.check_position(
+            collect.pool.address,
+            collect.owner,
+            collect.tick_lower,
+            collect.tick_upper,
+        )?;
+
+        let amount0 = collect.amount0.as_f64();
+        let amount1 = collect.amount1.as_f64();
+
+        let principal0 = amount0.min(self.uncollected_principal0);
+        let principal1 = amount1.min(self.uncollected_principal1);
+        self.uncollected_principal0 -= principal0;
+        self.uncollected_principal1 -= principal1;
+
+        let fees0 = amount0 - principal0;
+        let fees1 = amount1 - principal1;
+        self.collected_fees0 += fees0;
+        self.collected_fees1 += fees1;
+        self.unclaimed_fees0 = (self.unclaimed_fees0 - fees0).max(0.0);
+        self.unclaimed_fees1 = (self.unclaimed_fees1 - fees1).max(0.0);
+
+        Ok(())
+    }
+
+    /// Accrues uncollected fees from the fee growth inside the position range of `state`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `state` is for a different pool.
+    pub fn update_fees(&mut self, state: &PoolState) -> anyhow::Result<()> {
+        if state.pool.address != self.pool.address {
+            anyhow::bail!(
+                "Pool state {} does not match position pool {}",
+                state.pool.address,
+                self.pool.address
+            );
+        }
+
+        let (inside_0, inside_1) = state.fee_growth_inside(self.tick_lower, self.tick_upper);
+        let liquidity = U256::from(self.liquidity);
+
+        let owed_0 = mul_div(
+            inside_0.wrapping_sub(self.fee_growth_inside_0_last_x128),
+            liquidity,
+            q128(),
+        )?;
+        let owed_1 = mul_div(
+            inside_1.wrapping_sub(self.fee_growth_inside_1_last_x128),
+            liquidity,
+            q128(),
+        )?;
+
+        self.unclaimed_fees0 += u256_to_f64(owed_0) / self.scale0();
+        self.unclaimed_fees1 += u256_to_f64(owed_1) / self.scale1();
+        self.fee_growth_inside_0_last_x128 = inside_0;
+        self.fee_growth_inside_1_last_x128 = inside_1;
+        Ok(())
+    }
+
+    /// Returns the total fees accrued (collected plus unclaimed) as (token0, token1).
+    #[must_use]
+    pub fn fees_accrued(&self) -> (f64, f64) {
+        (
+            self.collected_fees0 + self.unclaimed_fees0,
+            self.collected_fees1 + self.unclaimed_fees1,
+        )
+    }
+
+    /// Returns the token amounts currently held by the position liquidity at the given square
+    /// root price, as (token0, token1).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position ticks are out of range.
+    pub fn amounts(&self, sqrt_price_x96: U256) -> anyhow::Result<(f64, f64)> {
+        let sqrt_price_a_x96 = get_sqrt_ratio_at_tick(self.tick_lower)?;
+        let sqrt_price_b_x96 = get_sqrt_ratio_at_tick(self.tick_upper)?;
+        let sqrt_price_x96 = sqrt_price_x96.clamp(sqrt_price_a_x96, sqrt_price_b_x96);
+
+        let amount0 = get_amount0_delta(sqrt_price_x96, sqrt_price_b_x96, self.liquidity, false)?;
+        let amount1 = get_amount1_delta(sqrt_price_a_x96, sqrt_price_x96, self.liquidity, false)?;
+
+        Ok((
+            u256_to_f64(amount0) / self.scale0(),
+            u256_to_f64(amount1) / self.scale1(),
+        ))
+    }
+
+    /// Returns the value of the position liquidity at the given square root price, in units
+    /// of token1 (excluding fees).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position ticks are out of range.
+    pub fn value(&self, sqrt_price_x96: U256) -> anyhow::Result<f64> {
+        let (amount0, amount1) = self.amounts(sqrt_price_x96)?;
+        Ok(amount0.mul_add(self.price(sqrt_price_x96), amount1))
+    }
+
+    /// Returns the impermanent loss of the position at the given square root price.
+    ///
+    /// This is the value of the position liquidity relative to simply holding the net deposited
+    /// tokens, minus one. A value of -0.05 means the position is worth 5% less than holding.
+    /// Returns zero when there are no net deposits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the position ticks are out of range.
+    pub fn impermanent_loss(&self, sqrt_price_x96: U256) -> anyhow::Result<f64> {
+        let price = self.price(sqrt_price_x96);
+        let hold0 = self.deposited0 - self.withdrawn0;
+        let hold1 = self.deposited1 - self.withdrawn1;
+        let hold_value = hold0.mul_add(price, hold1);
+
+        if hold_value <= 0.0 {
+            return Ok(0.0);
+        }
+
+        Ok(self.value(sqrt_price_x96)? / hold_value - 1.0)
+    }
+
+    fn price(&self, sqrt_price_x96: U256) -> f64 {
+        sqrt_price_x96_to_price(
+            sqrt_price_x96,
+            self.pool.token0.decimals,
+            self.pool.token1.decimals,
+        )
+    }
+
+    fn scale0(&self) -> f64 {
+        10f64.powi(i32::from(self.pool.token0.decimals))
+    }
+
+    fn scale1(&self) -> f64 {
+        10f64.powi(i32::from(self.pool.token1.decimals))
+    }
+
+    fn check_position(
+        &self,
+        pool: Address,
+        owner: Address,
+        tick_lower: i32,
+        tick_upper: i32,
+    ) -> anyhow::Result<()> {
+        if pool != self.pool.address
+            || owner != self.owner
+            || tick_lower != self.tick_lower
+            || tick_upper != self.tick_upper
+        {
+            anyhow::bail!(
+                "Event for pool {pool} owner {owner} range [{tick_lower}, {tick_upper}) does not match {self}"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Display for PoolPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PoolPosition(pool={}, owner={}, ticks=[{}, {}], liquidity={})",
+            self.pool.ticker(),
+            self.owner,
+            self.tick_lower,
+            self.tick_upper,
+            self.liquidity,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use nautilus_core::UnixNanos;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{
+        defi::{
+            amm::Pool,
+            chain::chains,
+            dex::{AmmType, Dex},
+            math::q96,
+            token::Token,
+        },
+        types::Quantity,
+    };
+
+    const E18: u128 = 1_000_000_000_000_000_000;
+
+    #[fixture]
+    fn pool() -> SharedPool {
+        let chain = Arc::new(chains::ETHEREUM.clone());
+        let dex = Dex::new(
+            chains::ETHEREUM.clone(),
+            "Uniswap V3",
+            "0x1F98431c8aD98523631AE4a59f267346ea31F984",
+            AmmType::CLAMM,
+            "PoolCreated(address,address,uint24,int24,address)",
+            "Swap(address,address,int256,int256,uint160,uint128,int24)",
+        );
+        let token0 = Token::new(
+            chain.clone(),
+            Address::repeat_byte(0x01),
+            "Token A".to_string(),
+            "TKA".to_string(),
+            18,
+        );
+        let token1 = Token::new(
+            chain.clone(),
+            Address::repeat_byte(0x02),
+            "Token B".to_string(),
+            "TKB".to_string(),
+            18,
+        );
+        Arc::new(Pool::new(
+            chain,
+            dex,
+            Address::repeat_byte(0x03),
+            0,
+            token0,
+            token1,
+            3000,
+            60,
+        ))
+    }
+
+    fn owner() -> Address {
+        Address::repeat_byte(0x04)
+    }
+
+    fn update(
+        pool: &SharedPool,
+        kind: PoolLiquidityUpdateType,
+        liquidity: u128,
+        amount0: &str,
+        amount1: &str,
+    ) -> PoolLiquidityUpdate {
+        PoolLiquidityUpdate::new(
+            pool.chain.clone(),
+            Arc::new(pool.dex.clone()),
+            pool.clone(),
+            kind,
+            1,
+            owner(),
+            liquidity,
+            Quantity::from(amount0),
+            Quantity::from(amount1),
+            -600,
+            600,
+            UnixNanos::default(),
+        )
+    }
+
+    fn collect(pool: &SharedPool, amount0: &str, amount1: &str) -> PoolFeeCollect {
+        PoolFeeCollect::new(
+            pool.chain.clone(),
+            Arc::new(pool.dex.clone()),
+            pool.clone(),
+            2,
+            owner(),
+            Quantity::from(amount0),
+            Quantity::from(amount1),
+            -600,
+            600,
+            UnixNanos::default(),
+        )
+    }
+
+    fn minted_position(pool: &SharedPool) -> PoolPosition {
+        let mut position = PoolPosition::new(pool.clone(), owner(), -600, 600);
+        let (amount0, amount1) = position_amounts(pool, E18);
+        position
+            .apply_update(&update(
+                pool,
+                PoolLiquidityUpdateType::Mint,
+                E18,
+                &amount0,
+                &amount1,
+            ))
+            .unwrap();
+        position
+    }
+
+    // Token amounts required to mint `liquidity` in [-600, 600) at price 1.0
+    fn position_amounts(pool: &SharedPool, liquidity: u128) -> (String, String) {
+        let mut position = PoolPosition::new(pool.clone(), owner(), -600, 600);
+        position.liquidity = liquidity;
+        let (amount0, amount1) = position.amounts(q96()).unwrap();
+        (format!("{amount0:.9}"), format!("{amount1:.9}"))
+    }
+
+    #[rstest]
+    fn test_amounts_in_and_out_of_range(pool: SharedPool) {
+        let position = minted_position(&pool);
+
+        let (amount0, amount1) = position.amounts(q96()).unwrap();
+        assert!((amount0 - amount1).abs() < 1e-9);
+        assert!(amount0 > 0.0);
+
+        // Below the range the position is entirely token0
+        let below = get_sqrt_ratio_at_tick(-1200).unwrap();
+        let (amount0, amount1) = position.amounts(below).unwrap();
+        assert!(amount0 > 0.0);
+        assert_eq!(amount1, 0.0);
+
+        // Above the range the position is entirely token1
+        let above = get_sqrt_ratio_at_tick(1200).unwrap();
+        let (amount0, amount1) = position.amounts(above).unwrap();
+        assert_eq!(amount0, 0.0);
+        assert!(amount1 > 0.0);
+    }
+
+    #[rstest]
+    fn test_impermanent_loss(pool: SharedPool) {
+        let position = minted_position(&pool);
+
+        let il_at_entry = position.impermanent_loss(q96()).unwrap();
+        assert!(il_at_entry.abs() < 1e-6);
+
+        let il_up = position
+            .impermanent_loss(get_sqrt_ratio_at_tick(400).unwrap())
+            .unwrap();
+        let il_down = position
+            .impermanent_loss(get_sqrt_ratio_at_tick(-400).unwrap())
+            .unwrap();
+        assert!(il_up < 0.0);
+        assert!(il_down < 0.0);
+
+        // Loss keeps growing once the price leaves the range
+        let il_far = position
+            .impermanent_loss(get_sqrt_ratio_at_tick(2000).unwrap())
+            .unwrap();
+        assert!(il_far < il_up);
+    }
+
+    #[rstest]
+    fn test_impermanent_loss_without_deposits(pool: SharedPool) {
+        let position = PoolPosition::new(pool, owner(), -600, 600);
+        assert_eq!(position.impermanent_loss(q96()).unwrap(), 0.0);
+    }
+
+    #[rstest]
+    fn test_burn_and_collect_splits_principal_and_fees(pool: SharedPool) {
+        let mut position = minted_position(&pool);
+
+        position
+            .apply_update(&update(
+                &pool,
+                PoolLiquidityUpdateType::Burn,
+                E18 / 2,
+                "1.0",
+                "1.0",
+            ))
+            .unwrap();
+        assert_eq!(position.liquidity, E18 / 2);
+        assert_eq!(position.withdrawn0, 1.0);
+
+        position
+            .apply_collect(&collect(&pool, "1.25", "1.5"))
+            .unwrap();
+
+        assert_eq!(position.fees_accrued(), (0.25, 0.5));
+        assert_eq!(position.collected_fees0, 0.25);
+        assert_eq!(position.collected_fees1, 0.5);
+    }
+
+    #[rstest]
+    fn test_burn_more_than_liquidity_fails(pool: SharedPool) {
+        let mut position = minted_position(&pool);
+        let result = position.apply_update(&update(
+            &pool,
+            PoolLiquidityUpdateType::Burn,
+            2 * E18,
+            "0",
+            "0",
+        ));
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_update_for_other_position_fails(pool: SharedPool) {
+        let mut position = PoolPosition::new(pool.clone(), owner(), -120, 120);
+        let result =
+            position.apply_update(&update(&pool, PoolLiquidityUpdateType::Mint, E18, "1", "1"));
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_update_fees_from_pool_state(pool: SharedPool) {
+        let mut state = PoolState::new(pool.clone());
+        state.initialize(q96()).unwrap();
+        state.apply_mint(-600, 600, E18).unwrap();
+        state.apply_mint(-1200, 1200, E18).unwrap();
+
+        let mut position = PoolPosition::new(pool.clone(), owner(), -600, 600);
+        position.update_fees(&state).unwrap();
+        let (amount0, amount1) = position_amounts(&pool, E18);
+        position
+            .apply_update(&update(
+                &pool,
+                PoolLiquidityUpdateType::Mint,
+                E18,
+                &amount0,
+                &amount1,
+            ))
+            .unwrap();
+
+        let quote = state
+            .swap(true, true, U256::from(10u128.pow(16)), None)
+            .unwrap();
+        position.update_fees(&state).unwrap();
+
+        // The position holds half of the in-range liquidity so earns half of the fees
+        let expected = u256_to_f64(quote.fee_amount) / 2.0 / 1e18;
+        let (fees0, fees1) = position.fees_accrued();
+        assert!((fees0 - expected).abs() < 1e-12);
+        assert_eq!(fees1, 0.0);
+
+        position
+            .apply_collect(&collect(&pool, "0.000015", "0"))
+            .unwrap();
+        assert!((position.collected_fees0 - 0.000015).abs() < 1e-12);
+        assert!(position.unclaimed_fees0 < 1e-12);
+    }
+}
@@ -19,6 +19,7 @@ use nautilus_core::{UUID4, UnixNanos};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    data::GetTsInit,
     enums::AccountType,
     identifiers::AccountId,
     types::{AccountBalance, Currency, MarginBalance},
@@ -109,6 +110,12 @@ impl Display for AccountState {
     }
 }
 
+impl GetTsInit for AccountState {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
 impl PartialEq for AccountState {
     fn eq(&self, other: &Self) -> bool {
         self.account_id == other.account_id
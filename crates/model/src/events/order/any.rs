@@ -13,13 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::UnixNanos;
+use nautilus_core::{UUID4, UnixNanos};
 use serde::{Deserialize, Serialize};
 use strum::Display;
 use ustr::Ustr;
 
 use super::{OrderEvent, OrderEventType};
 use crate::{
+    data::GetTsInit,
     events::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEmulated,
         OrderExpired, OrderFilled, OrderInitialized, OrderModifyRejected, OrderPendingCancel,
@@ -228,6 +229,28 @@ impl OrderEventAny {
         }
     }
 
+    #[must_use]
+    pub fn event_id(&self) -> UUID4 {
+        match self {
+            Self::Initialized(event) => event.event_id,
+            Self::Denied(event) => event.event_id,
+            Self::Emulated(event) => event.event_id,
+            Self::Released(event) => event.event_id,
+            Self::Submitted(event) => event.event_id,
+            Self::Accepted(event) => event.event_id,
+            Self::Rejected(event) => event.event_id,
+            Self::Canceled(event) => event.event_id,
+            Self::Expired(event) => event.event_id,
+            Self::Triggered(event) => event.event_id,
+            Self::PendingUpdate(event) => event.event_id,
+            Self::PendingCancel(event) => event.event_id,
+            Self::ModifyRejected(event) => event.event_id,
+            Self::CancelRejected(event) => event.event_id,
+            Self::Updated(event) => event.event_id,
+            Self::Filled(event) => event.event_id,
+        }
+    }
+
     #[must_use]
     pub fn ts_event(&self) -> UnixNanos {
         match self {
@@ -250,6 +273,28 @@ impl OrderEventAny {
         }
     }
 
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Initialized(event) => event.ts_init,
+            Self::Denied(event) => event.ts_init,
+            Self::Emulated(event) => event.ts_init,
+            Self::Released(event) => event.ts_init,
+            Self::Submitted(event) => event.ts_init,
+            Self::Accepted(event) => event.ts_init,
+            Self::Rejected(event) => event.ts_init,
+            Self::Canceled(event) => event.ts_init,
+            Self::Expired(event) => event.ts_init,
+            Self::Triggered(event) => event.ts_init,
+            Self::PendingUpdate(event) => event.ts_init,
+            Self::PendingCancel(event) => event.ts_init,
+            Self::ModifyRejected(event) => event.ts_init,
+            Self::CancelRejected(event) => event.ts_init,
+            Self::Updated(event) => event.ts_init,
+            Self::Filled(event) => event.ts_init,
+        }
+    }
+
     #[must_use]
     pub fn message(&self) -> Option<Ustr> {
         match self {
@@ -273,6 +318,12 @@ impl OrderEventAny {
     }
 }
 
+impl GetTsInit for OrderEventAny {
+    fn ts_init(&self) -> UnixNanos {
+        OrderEventAny::ts_init(self)
+    }
+}
+
 /// Converts an `OrderEventAny` into an `OrderFilled`.
 ///
 /// # Panics
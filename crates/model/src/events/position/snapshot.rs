@@ -17,6 +17,7 @@ use nautilus_core::UnixNanos;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    data::GetTsInit,
     enums::{OrderSide, PositionSide},
     identifiers::{AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, TraderId},
     position::Position,
@@ -116,3 +117,9 @@ impl PositionSnapshot {
         }
     }
 }
+
+impl GetTsInit for PositionSnapshot {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use enum_dispatch::enum_dispatch;
+use nautilus_core::UnixNanos;
 use serde::{Deserialize, Serialize};
 
 use super::{
@@ -22,7 +23,10 @@ use super::{
     currency_pair::CurrencyPair, equity::Equity, futures_contract::FuturesContract,
     futures_spread::FuturesSpread, option_contract::OptionContract, option_spread::OptionSpread,
 };
-use crate::types::{Price, Quantity};
+use crate::{
+    data::GetTsInit,
+    types::{Price, Quantity},
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[enum_dispatch(Instrument)]
@@ -60,6 +64,12 @@ impl InstrumentAny {
     }
 }
 
+impl GetTsInit for InstrumentAny {
+    fn ts_init(&self) -> UnixNanos {
+        Instrument::ts_init(self)
+    }
+}
+
 impl PartialEq for InstrumentAny {
     fn eq(&self, other: &Self) -> bool {
         self.id() == other.id()
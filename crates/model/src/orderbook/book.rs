@@ -18,18 +18,24 @@
 use std::{collections::HashSet, fmt::Display};
 
 use indexmap::IndexMap;
-use nautilus_core::UnixNanos;
+use nautilus_core::{UnixNanos, nanos::DurationNanos};
 use rust_decimal::Decimal;
 
 use super::{
-    aggregation::pre_process_order, analysis, display::pprint_book, level::BookLevel,
+    aggregation::pre_process_order,
+    analysis,
+    display::pprint_book,
+    level::{BookLevel, BookLevelStats},
     own::OwnOrderBook,
 };
 use crate::{
-    data::{BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick},
+    data::{
+        BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
+        order::OrderId,
+    },
     enums::{BookAction, BookType, OrderSide, OrderSideSpecified, OrderStatus},
     identifiers::InstrumentId,
-    orderbook::{InvalidBookOperation, ladder::BookLadder},
+    orderbook::{BookPrice, InvalidBookOperation, ladder::BookLadder},
     types::{Price, Quantity},
 };
 
@@ -91,8 +97,8 @@ impl OrderBook {
             sequence: 0,
             ts_last: UnixNanos::default(),
             update_count: 0,
-            bids: BookLadder::new(OrderSideSpecified::Buy),
-            asks: BookLadder::new(OrderSideSpecified::Sell),
+            bids: BookLadder::new(OrderSideSpecified::Buy, book_type),
+            asks: BookLadder::new(OrderSideSpecified::Sell, book_type),
         }
     }
 
@@ -109,8 +115,8 @@ impl OrderBook {
     pub fn add(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
         let order = pre_process_order(self.book_type, order, flags);
         match order.side.as_specified() {
-            OrderSideSpecified::Buy => self.bids.add(order),
-            OrderSideSpecified::Sell => self.asks.add(order),
+            OrderSideSpecified::Buy => self.bids.add(order, ts_event),
+            OrderSideSpecified::Sell => self.asks.add(order, ts_event),
         }
 
         self.increment(sequence, ts_event);
@@ -120,8 +126,8 @@ impl OrderBook {
     pub fn update(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
         let order = pre_process_order(self.book_type, order, flags);
         match order.side.as_specified() {
-            OrderSideSpecified::Buy => self.bids.update(order),
-            OrderSideSpecified::Sell => self.asks.update(order),
+            OrderSideSpecified::Buy => self.bids.update(order, ts_event),
+            OrderSideSpecified::Sell => self.asks.update(order, ts_event),
         }
 
         self.increment(sequence, ts_event);
@@ -446,6 +452,100 @@ impl OrderBook {
         }
     }
 
+    /// Returns the price level containing the order with the given `order_id`.
+    ///
+    /// Order IDs are only meaningful for L3 (MBO) books.
+    #[must_use]
+    pub fn level_for_order(&self, order_id: OrderId) -> Option<&BookLevel> {
+        self.bids
+            .level_for_order(order_id)
+            .or_else(|| self.asks.level_for_order(order_id))
+    }
+
+    /// Returns the price level at `price` on the given `side`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `side` is [`OrderSide::NoOrderSide`].
+    #[must_use]
+    pub fn level(&self, side: OrderSide, price: Price) -> Option<&BookLevel> {
+        let book_price = BookPrice::new(price, side.as_specified());
+        self.ladder(book_price.side).levels.get(&book_price)
+    }
+
+    /// Returns the number of orders ahead of the order with the given `order_id` at its
+    /// price level, or `None` if the order is not in the book.
+    #[must_use]
+    pub fn queue_position(&self, order_id: OrderId) -> Option<usize> {
+        self.level_for_order(order_id)?.queue_position(order_id)
+    }
+
+    /// Returns the orders ahead of the order with the given `order_id` at its price level
+    /// in FIFO order, or `None` if the order is not in the book.
+    #[must_use]
+    pub fn orders_ahead(&self, order_id: OrderId) -> Option<Vec<BookOrder>> {
+        self.level_for_order(order_id)?.orders_ahead(order_id)
+    }
+
+    /// Returns the total size ahead of the order with the given `order_id` at its price
+    /// level, or `None` if the order is not in the book.
+    #[must_use]
+    pub fn size_ahead(&self, order_id: OrderId) -> Option<f64> {
+        self.level_for_order(order_id)?.size_ahead(order_id)
+    }
+
+    /// Returns the age of the order with the given `order_id` in its queue as of the last
+    /// book event, or `None` if the order is not in the book.
+    ///
+    /// Queue times are only tracked for L3 (MBO) books.
+    #[must_use]
+    pub fn order_age(&self, order_id: OrderId) -> Option<DurationNanos> {
+        self.bids
+            .order_age(order_id, self.ts_last)
+            .or_else(|| self.asks.order_age(order_id, self.ts_last))
+    }
+
+    /// Returns the mean age (nanoseconds) of orders at `price` on the given `side` as of
+    /// the last book event, or `None` if there are no orders with a known queue time.
+    ///
+    /// Queue times are only tracked for L3 (MBO) books.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `side` is [`OrderSide::NoOrderSide`].
+    #[must_use]
+    pub fn avg_order_age(&self, side: OrderSide, price: Price) -> Option<f64> {
+        let book_price = BookPrice::new(price, side.as_specified());
+        self.ladder(book_price.side)
+            .avg_order_age(&book_price, self.ts_last)
+    }
+
+    /// Returns the queue activity statistics at `price` on the given `side`, or `None` if
+    /// no activity has been tracked there.
+    ///
+    /// Statistics are only tracked for L3 (MBO) books, and are retained after a level empties.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `side` is [`OrderSide::NoOrderSide`].
+    #[must_use]
+    pub fn level_stats(&self, side: OrderSide, price: Price) -> Option<BookLevelStats> {
+        let book_price = BookPrice::new(price, side.as_specified());
+        self.ladder(book_price.side).level_stats(&book_price)
+    }
+
+    /// Returns the ratio of canceled size to filled size at `price` on the given `side`.
+    ///
+    /// Returns `None` if no size has been filled at `price`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `side` is [`OrderSide::NoOrderSide`].
+    #[must_use]
+    pub fn cancel_fill_ratio(&self, side: OrderSide, price: Price) -> Option<f64> {
+        self.level_stats(side, price)?.cancel_fill_ratio()
+    }
+
     /// Return a formatted string representation of the order book.
     #[must_use]
     pub fn pprint(&self, num_levels: usize) -> String {
@@ -518,13 +618,20 @@ impl OrderBook {
         Ok(())
     }
 
+    const fn ladder(&self, side: OrderSideSpecified) -> &BookLadder {
+        match side {
+            OrderSideSpecified::Buy => &self.bids,
+            OrderSideSpecified::Sell => &self.asks,
+        }
+    }
+
     fn update_book_bid(&mut self, order: BookOrder, ts_event: UnixNanos) {
         if let Some(top_bids) = self.bids.top() {
             if let Some(top_bid) = top_bids.first() {
                 self.bids.remove(top_bid.order_id, 0, ts_event);
             }
         }
-        self.bids.add(order);
+        self.bids.add(order, ts_event);
     }
 
     fn update_book_ask(&mut self, order: BookOrder, ts_event: UnixNanos) {
@@ -533,7 +640,7 @@ impl OrderBook {
                 self.asks.remove(top_ask.order_id, 0, ts_event);
             }
         }
-        self.asks.add(order);
+        self.asks.add(order, ts_event);
     }
 }
 
@@ -21,12 +21,12 @@ use std::{
     fmt::{Debug, Display, Formatter},
 };
 
-use nautilus_core::UnixNanos;
+use nautilus_core::{UnixNanos, nanos::DurationNanos};
 
 use crate::{
     data::order::{BookOrder, OrderId},
-    enums::OrderSideSpecified,
-    orderbook::BookLevel,
+    enums::{BookType, OrderSideSpecified},
+    orderbook::{BookLevel, BookLevelStats},
     types::{Price, Quantity},
 };
 
@@ -76,22 +76,38 @@ impl Display for BookPrice {
     }
 }
 
+/// The maximum number of emptied price levels whose queue statistics are retained.
+const MAX_EMPTY_LEVEL_STATS: usize = 1_000;
+
 /// Represents a ladder of price levels for one side of an order book.
+///
+/// For L3 (MBO) books the ladder also tracks the time each order joined its queue and
+/// per-price queue activity statistics. Statistics are keyed by price rather than held
+/// on the level, so they survive when an emptied level is removed from the ladder (up to
+/// [`MAX_EMPTY_LEVEL_STATS`] emptied levels, dropping those furthest from the top first).
 #[derive(Clone, Debug)]
 pub(crate) struct BookLadder {
     pub side: OrderSideSpecified,
     pub levels: BTreeMap<BookPrice, BookLevel>,
     pub cache: HashMap<u64, BookPrice>,
+    pub track_queue: bool,
+    pub ts_added: HashMap<OrderId, UnixNanos>,
+    pub stats: BTreeMap<BookPrice, BookLevelStats>,
 }
 
 impl BookLadder {
     /// Creates a new [`Ladder`] instance.
+    ///
+    /// Queue times and level statistics are only tracked for [`BookType::L3_MBO`] books.
     #[must_use]
-    pub fn new(side: OrderSideSpecified) -> Self {
+    pub fn new(side: OrderSideSpecified, book_type: BookType) -> Self {
         Self {
             side,
             levels: BTreeMap::new(),
             cache: HashMap::new(),
+            track_queue: book_type == BookType::L3_MBO,
+            ts_added: HashMap::new(),
+            stats: BTreeMap::new(),
         }
     }
 
@@ -110,21 +126,45 @@ impl BookLadder {
 
     #[allow(dead_code)] // Used in tests
     /// Adds multiple orders to the ladder.
-    pub fn add_bulk(&mut self, orders: Vec<BookOrder>) {
+    pub fn add_bulk(&mut self, orders: Vec<BookOrder>, ts_event: UnixNanos) {
         for order in orders {
-            self.add(order);
+            self.add(order, ts_event);
         }
     }
 
-    /// Removes all orders and price levels from the ladder.
+    /// Removes all orders, price levels and queue statistics from the ladder.
     pub fn clear(&mut self) {
         self.levels.clear();
         self.cache.clear();
+        self.ts_added.clear();
+        self.stats.clear();
     }
 
     /// Adds an order to the ladder at its price level.
-    pub fn add(&mut self, order: BookOrder) {
+    ///
+    /// When tracking queues, `ts_event` is recorded as the time the order joined the queue
+    /// (an order already in the ladder retains its original queue time, and only an
+    /// increase of its size is recorded as added size).
+    pub fn add(&mut self, order: BookOrder, ts_event: UnixNanos) {
         let book_price = order.to_book_price();
+
+        if self.track_queue {
+            let prev_size_raw = self
+                .levels
+                .get(&book_price)
+                .and_then(|level| level.orders.get(&order.order_id))
+                .map(|prev| prev.size.raw);
+            let stats = self.stats.entry(book_price).or_default();
+            match prev_size_raw {
+                Some(prev) => stats.add_size_raw += order.size.raw.saturating_sub(prev),
+                None => {
+                    stats.add_count += 1;
+                    stats.add_size_raw += order.size.raw;
+                }
+            }
+            self.ts_added.entry(order.order_id).or_insert(ts_event);
+        }
+
         self.cache.insert(order.order_id, book_price);
 
         match self.levels.get_mut(&book_price) {
@@ -139,12 +179,46 @@ impl BookLadder {
     }
 
     /// Updates an existing order in the ladder, moving it to a new price level if needed.
-    pub fn update(&mut self, order: BookOrder) {
+    ///
+    /// When tracking queues, a size reduction at an unchanged price is recorded as a fill and
+    /// a size increase as added size, while an update to a zero size removes the order and is
+    /// recorded as a cancel. A price change is recorded as a modify at the old level and the
+    /// order rejoins the back of the new queue at `ts_event`.
+    ///
+    /// Fills are inferred from size reductions, as book updates do not carry the reason for
+    /// the change. Feeds which report fills separately and then delete the filled order (e.g.
+    /// Databento MBO, where a fully filled order is deleted by a cancel after the fill) record
+    /// those fills as cancels.
+    pub fn update(&mut self, order: BookOrder, ts_event: UnixNanos) {
         let price = self.cache.get(&order.order_id).copied();
         if let Some(price) = price {
             if let Some(level) = self.levels.get_mut(&price) {
                 if order.price == level.price.value {
                     // Update at current price level
+                    if self.track_queue {
+                        let prev_size_raw = level.orders.get(&order.order_id).map(|o| o.size.raw);
+                        let stats = self.stats.entry(price).or_default();
+                        match prev_size_raw {
+                            Some(prev) if order.size.raw == 0 => {
+                                stats.cancel_count += 1;
+                                stats.cancel_size_raw += prev;
+                            }
+                            Some(prev) if order.size.raw < prev => {
+                                stats.fill_count += 1;
+                                stats.fill_size_raw += prev - order.size.raw;
+                            }
+                            Some(prev) => stats.add_size_raw += order.size.raw - prev,
+                            None if order.size.raw > 0 => {
+                                stats.add_count += 1;
+                                stats.add_size_raw += order.size.raw;
+                                self.ts_added.entry(order.order_id).or_insert(ts_event);
+                            }
+                            None => {}
+                        }
+                        if order.size.raw == 0 {
+                            self.ts_added.remove(&order.order_id);
+                        }
+                    }
                     level.update(order);
                     return;
                 }
@@ -152,13 +226,18 @@ impl BookLadder {
                 // Price update: delete and insert at new level
                 self.cache.remove(&order.order_id);
                 level.delete(&order);
-                if level.is_empty() {
-                    self.levels.remove(&price);
+                let is_empty = level.is_empty();
+                if self.track_queue {
+                    self.stats.entry(price).or_default().modify_count += 1;
+                    self.ts_added.remove(&order.order_id);
+                }
+                if is_empty {
+                    self.remove_level(&price);
                 }
             }
         }
 
-        self.add(order);
+        self.add(order, ts_event);
     }
 
     /// Deletes an order from the ladder.
@@ -167,17 +246,92 @@ impl BookLadder {
     }
 
     /// Removes an order by its ID from the ladder.
+    ///
+    /// When tracking queues, the removal is recorded as a cancel.
     pub fn remove(&mut self, order_id: OrderId, sequence: u64, ts_event: UnixNanos) {
         if let Some(price) = self.cache.remove(&order_id) {
             if let Some(level) = self.levels.get_mut(&price) {
+                if self.track_queue {
+                    if let Some(order) = level.orders.get(&order_id) {
+                        let stats = self.stats.entry(price).or_default();
+                        stats.cancel_count += 1;
+                        stats.cancel_size_raw += order.size.raw;
+                    }
+                    self.ts_added.remove(&order_id);
+                }
                 level.remove_by_id(order_id, sequence, ts_event);
                 if level.is_empty() {
-                    self.levels.remove(&price);
+                    self.remove_level(&price);
                 }
             }
         }
     }
 
+    /// Removes the emptied level at `price`, dropping the statistics of the emptied level
+    /// furthest from the top once more than [`MAX_EMPTY_LEVEL_STATS`] are retained.
+    fn remove_level(&mut self, price: &BookPrice) {
+        self.levels.remove(price);
+
+        if self.stats.len() > self.levels.len() + MAX_EMPTY_LEVEL_STATS {
+            let furthest = self
+                .stats
+                .keys()
+                .rev()
+                .find(|price| !self.levels.contains_key(price))
+                .copied();
+            if let Some(furthest) = furthest {
+                self.stats.remove(&furthest);
+            }
+        }
+    }
+
+    /// Returns the price level containing the order with the given `order_id`.
+    #[must_use]
+    pub fn level_for_order(&self, order_id: OrderId) -> Option<&BookLevel> {
+        self.cache
+            .get(&order_id)
+            .and_then(|price| self.levels.get(price))
+    }
+
+    /// Returns the time the order with the given `order_id` joined its queue, or `None`
+    /// if unknown or queues are not tracked.
+    #[must_use]
+    pub fn order_ts_added(&self, order_id: OrderId) -> Option<UnixNanos> {
+        self.ts_added.get(&order_id).copied()
+    }
+
+    /// Returns the age of the order with the given `order_id` at `ts_now`, or `None` if
+    /// the queue time is unknown.
+    #[must_use]
+    pub fn order_age(&self, order_id: OrderId, ts_now: UnixNanos) -> Option<DurationNanos> {
+        self.order_ts_added(order_id)
+            .map(|ts_added| ts_now.as_u64().saturating_sub(ts_added.as_u64()))
+    }
+
+    /// Returns the mean age (nanoseconds) at `ts_now` of orders at the given `price` with
+    /// a known queue time, or `None` if there are none.
+    #[must_use]
+    pub fn avg_order_age(&self, price: &BookPrice, ts_now: UnixNanos) -> Option<f64> {
+        let level = self.levels.get(price)?;
+        let ages: Vec<DurationNanos> = level
+            .orders
+            .keys()
+            .filter_map(|order_id| self.order_age(*order_id, ts_now))
+            .collect();
+
+        if ages.is_empty() {
+            return None;
+        }
+        Some(ages.iter().map(|age| *age as f64).sum::<f64>() / ages.len() as f64)
+    }
+
+    /// Returns the queue activity statistics for the given `price`, or `None` if no
+    /// activity has been tracked at that price.
+    #[must_use]
+    pub fn level_stats(&self, price: &BookPrice) -> Option<BookLevelStats> {
+        self.stats.get(price).copied()
+    }
+
     /// Returns the total size of all orders in the ladder.
     #[must_use]
     #[allow(dead_code)] // Used in tests
@@ -257,23 +411,23 @@ mod tests {
 
     use crate::{
         data::order::BookOrder,
-        enums::{OrderSide, OrderSideSpecified},
-        orderbook::ladder::{BookLadder, BookPrice},
-        types::{Price, Quantity},
+        enums::{BookType, OrderSide, OrderSideSpecified},
+        orderbook::ladder::{BookLadder, BookPrice, MAX_EMPTY_LEVEL_STATS},
+        types::{Price, Quantity, fixed::FIXED_SCALAR, quantity::QuantityRaw},
     };
 
     #[rstest]
     fn test_is_empty() {
-        let ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         assert!(ladder.is_empty(), "A new ladder should be empty");
     }
 
     #[rstest]
     fn test_is_empty_after_add() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         assert!(ladder.is_empty(), "Ladder should start empty");
         let order = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(100), 1);
-        ladder.add(order);
+        ladder.add(order, 0.into());
         assert!(
             !ladder.is_empty(),
             "Ladder should not be empty after adding an order"
@@ -282,8 +436,8 @@ mod tests {
 
     #[rstest]
     fn test_add_bulk_empty() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
-        ladder.add_bulk(vec![]);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
+        ladder.add_bulk(vec![], 0.into());
         assert!(
             ladder.is_empty(),
             "Adding an empty vector should leave the ladder empty"
@@ -292,13 +446,13 @@ mod tests {
 
     #[rstest]
     fn test_add_bulk_orders() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let orders = vec![
             BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 1),
             BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(30), 2),
             BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(50), 3),
         ];
-        ladder.add_bulk(orders);
+        ladder.add_bulk(orders, 0.into());
         // All orders share the same price, so there should be one price level.
         assert_eq!(ladder.len(), 1, "Ladder should have one price level");
         let orders_in_level = ladder.top().unwrap().get_orders();
@@ -336,10 +490,10 @@ mod tests {
 
     #[rstest]
     fn test_add_single_order() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 0);
 
-        ladder.add(order);
+        ladder.add(order, 0.into());
         assert_eq!(ladder.len(), 1);
         assert_eq!(ladder.sizes(), 20.0);
         assert_eq!(ladder.exposures(), 200.0);
@@ -348,13 +502,13 @@ mod tests {
 
     #[rstest]
     fn test_add_multiple_buy_orders() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order1 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 0);
         let order2 = BookOrder::new(OrderSide::Buy, Price::from("9.00"), Quantity::from(30), 1);
         let order3 = BookOrder::new(OrderSide::Buy, Price::from("9.00"), Quantity::from(50), 2);
         let order4 = BookOrder::new(OrderSide::Buy, Price::from("8.00"), Quantity::from(200), 3);
 
-        ladder.add_bulk(vec![order1, order2, order3, order4]);
+        ladder.add_bulk(vec![order1, order2, order3, order4], 0.into());
         assert_eq!(ladder.len(), 3);
         assert_eq!(ladder.sizes(), 300.0);
         assert_eq!(ladder.exposures(), 2520.0);
@@ -363,7 +517,7 @@ mod tests {
 
     #[rstest]
     fn test_add_multiple_sell_orders() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Sell);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Sell, BookType::L2_MBP);
         let order1 = BookOrder::new(OrderSide::Sell, Price::from("11.00"), Quantity::from(20), 0);
         let order2 = BookOrder::new(OrderSide::Sell, Price::from("12.00"), Quantity::from(30), 1);
         let order3 = BookOrder::new(OrderSide::Sell, Price::from("12.00"), Quantity::from(50), 2);
@@ -374,7 +528,7 @@ mod tests {
             0,
         );
 
-        ladder.add_bulk(vec![order1, order2, order3, order4]);
+        ladder.add_bulk(vec![order1, order2, order3, order4], 0.into());
         assert_eq!(ladder.len(), 3);
         assert_eq!(ladder.sizes(), 300.0);
         assert_eq!(ladder.exposures(), 3780.0);
@@ -383,12 +537,12 @@ mod tests {
 
     #[rstest]
     fn test_add_to_same_price_level() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order1 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 1);
         let order2 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(30), 2);
 
-        ladder.add(order1);
-        ladder.add(order2);
+        ladder.add(order1, 0.into());
+        ladder.add(order2, 0.into());
 
         assert_eq!(ladder.len(), 1);
         assert_eq!(ladder.sizes(), 50.0);
@@ -397,37 +551,37 @@ mod tests {
 
     #[rstest]
     fn test_add_descending_buy_orders() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order1 = BookOrder::new(OrderSide::Buy, Price::from("9.00"), Quantity::from(20), 1);
         let order2 = BookOrder::new(OrderSide::Buy, Price::from("8.00"), Quantity::from(30), 2);
 
-        ladder.add(order1);
-        ladder.add(order2);
+        ladder.add(order1, 0.into());
+        ladder.add(order2, 0.into());
 
         assert_eq!(ladder.top().unwrap().price.value, Price::from("9.00"));
     }
 
     #[rstest]
     fn test_add_ascending_sell_orders() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Sell);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Sell, BookType::L2_MBP);
         let order1 = BookOrder::new(OrderSide::Sell, Price::from("8.00"), Quantity::from(20), 1);
         let order2 = BookOrder::new(OrderSide::Sell, Price::from("9.00"), Quantity::from(30), 2);
 
-        ladder.add(order1);
-        ladder.add(order2);
+        ladder.add(order1, 0.into());
+        ladder.add(order2, 0.into());
 
         assert_eq!(ladder.top().unwrap().price.value, Price::from("8.00"));
     }
 
     #[rstest]
     fn test_update_buy_order_price() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order = BookOrder::new(OrderSide::Buy, Price::from("11.00"), Quantity::from(20), 1);
 
-        ladder.add(order);
+        ladder.add(order, 0.into());
         let order = BookOrder::new(OrderSide::Buy, Price::from("11.10"), Quantity::from(20), 1);
 
-        ladder.update(order);
+        ladder.update(order, 0.into());
         assert_eq!(ladder.len(), 1);
         assert_eq!(ladder.sizes(), 20.0);
         assert_eq!(ladder.exposures(), 222.0);
@@ -436,14 +590,14 @@ mod tests {
 
     #[rstest]
     fn test_update_sell_order_price() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Sell);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Sell, BookType::L2_MBP);
         let order = BookOrder::new(OrderSide::Sell, Price::from("11.00"), Quantity::from(20), 1);
 
-        ladder.add(order);
+        ladder.add(order, 0.into());
 
         let order = BookOrder::new(OrderSide::Sell, Price::from("11.10"), Quantity::from(20), 1);
 
-        ladder.update(order);
+        ladder.update(order, 0.into());
         assert_eq!(ladder.len(), 1);
         assert_eq!(ladder.sizes(), 20.0);
         assert_eq!(ladder.exposures(), 222.0);
@@ -452,14 +606,14 @@ mod tests {
 
     #[rstest]
     fn test_update_buy_order_size() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order = BookOrder::new(OrderSide::Buy, Price::from("11.00"), Quantity::from(20), 1);
 
-        ladder.add(order);
+        ladder.add(order, 0.into());
 
         let order = BookOrder::new(OrderSide::Buy, Price::from("11.00"), Quantity::from(10), 1);
 
-        ladder.update(order);
+        ladder.update(order, 0.into());
         assert_eq!(ladder.len(), 1);
         assert_eq!(ladder.sizes(), 10.0);
         assert_eq!(ladder.exposures(), 110.0);
@@ -468,14 +622,14 @@ mod tests {
 
     #[rstest]
     fn test_update_sell_order_size() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Sell);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Sell, BookType::L2_MBP);
         let order = BookOrder::new(OrderSide::Sell, Price::from("11.00"), Quantity::from(20), 1);
 
-        ladder.add(order);
+        ladder.add(order, 0.into());
 
         let order = BookOrder::new(OrderSide::Sell, Price::from("11.00"), Quantity::from(10), 1);
 
-        ladder.update(order);
+        ladder.update(order, 0.into());
         assert_eq!(ladder.len(), 1);
         assert_eq!(ladder.sizes(), 10.0);
         assert_eq!(ladder.exposures(), 110.0);
@@ -484,7 +638,7 @@ mod tests {
 
     #[rstest]
     fn test_delete_non_existing_order() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 1);
 
         ladder.delete(order, 0, 0.into());
@@ -494,10 +648,10 @@ mod tests {
 
     #[rstest]
     fn test_delete_buy_order() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order = BookOrder::new(OrderSide::Buy, Price::from("11.00"), Quantity::from(20), 1);
 
-        ladder.add(order);
+        ladder.add(order, 0.into());
 
         let order = BookOrder::new(OrderSide::Buy, Price::from("11.00"), Quantity::from(10), 1);
 
@@ -510,10 +664,10 @@ mod tests {
 
     #[rstest]
     fn test_delete_sell_order() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Sell);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Sell, BookType::L2_MBP);
         let order = BookOrder::new(OrderSide::Sell, Price::from("10.00"), Quantity::from(10), 1);
 
-        ladder.add(order);
+        ladder.add(order, 0.into());
 
         let order = BookOrder::new(OrderSide::Sell, Price::from("10.00"), Quantity::from(10), 1);
 
@@ -526,7 +680,7 @@ mod tests {
 
     #[rstest]
     fn test_ladder_sizes_empty() {
-        let ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         assert_eq!(
             ladder.sizes(),
             0.0,
@@ -536,7 +690,7 @@ mod tests {
 
     #[rstest]
     fn test_ladder_exposures_empty() {
-        let ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         assert_eq!(
             ladder.exposures(),
             0.0,
@@ -546,11 +700,11 @@ mod tests {
 
     #[rstest]
     fn test_ladder_sizes() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order1 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 1);
         let order2 = BookOrder::new(OrderSide::Buy, Price::from("9.50"), Quantity::from(30), 2);
-        ladder.add(order1);
-        ladder.add(order2);
+        ladder.add(order1, 0.into());
+        ladder.add(order2, 0.into());
 
         let expected_size = 20.0 + 30.0;
         assert_eq!(
@@ -562,11 +716,11 @@ mod tests {
 
     #[rstest]
     fn test_ladder_exposures() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order1 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 1);
         let order2 = BookOrder::new(OrderSide::Buy, Price::from("9.50"), Quantity::from(30), 2);
-        ladder.add(order1);
-        ladder.add(order2);
+        ladder.add(order1, 0.into());
+        ladder.add(order2, 0.into());
 
         let expected_exposure = 10.00 * 20.0 + 9.50 * 30.0;
         assert_eq!(
@@ -578,11 +732,11 @@ mod tests {
 
     #[rstest]
     fn test_iter_returns_fifo() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order1 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 1);
         let order2 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(30), 2);
-        ladder.add(order1);
-        ladder.add(order2);
+        ladder.add(order1, 0.into());
+        ladder.add(order2, 0.into());
         let orders: Vec<BookOrder> = ladder.top().unwrap().iter().copied().collect();
         assert_eq!(
             orders,
@@ -593,10 +747,10 @@ mod tests {
 
     #[rstest]
     fn test_update_missing_order_inserts() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 1);
         // Call update on an order that hasn't been added yet (upsert behavior)
-        ladder.update(order);
+        ladder.update(order, 0.into());
         assert_eq!(
             ladder.len(),
             1,
@@ -613,11 +767,11 @@ mod tests {
 
     #[rstest]
     fn test_cache_consistency_after_operations() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order1 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 1);
         let order2 = BookOrder::new(OrderSide::Buy, Price::from("9.00"), Quantity::from(30), 2);
-        ladder.add(order1);
-        ladder.add(order2);
+        ladder.add(order1, 0.into());
+        ladder.add(order2, 0.into());
 
         // Ensure that each order in the cache is present in the corresponding price level.
         for (order_id, price) in &ladder.cache {
@@ -634,7 +788,7 @@ mod tests {
 
     #[rstest]
     fn test_simulate_fills_with_empty_book() {
-        let ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
         let order = BookOrder::new(OrderSide::Buy, Price::max(2), Quantity::from(500), 1);
 
         let fills = ladder.simulate_fills(&order);
@@ -650,7 +804,7 @@ mod tests {
         #[case] price: Price,
         #[case] ladder_side: OrderSideSpecified,
     ) {
-        let ladder = BookLadder::new(ladder_side);
+        let ladder = BookLadder::new(ladder_side, BookType::L2_MBP);
         let order = BookOrder {
             price, // <-- Simulate a MARKET order
             size: Quantity::from(500),
@@ -671,14 +825,17 @@ mod tests {
         #[case] ladder_side: OrderSideSpecified,
         #[case] ladder_price: Price,
     ) {
-        let mut ladder = BookLadder::new(ladder_side);
+        let mut ladder = BookLadder::new(ladder_side, BookType::L2_MBP);
 
-        ladder.add(BookOrder {
-            price: ladder_price,
-            size: Quantity::from(100),
-            side: ladder_side.as_order_side(),
-            order_id: 1,
-        });
+        ladder.add(
+            BookOrder {
+                price: ladder_price,
+                size: Quantity::from(100),
+                side: ladder_side.as_order_side(),
+                order_id: 1,
+            },
+            0.into(),
+        );
 
         let order = BookOrder {
             price: Price::from("50.00"),
@@ -694,14 +851,17 @@ mod tests {
 
     #[rstest]
     fn test_simulate_order_fills_sell_when_far_from_market() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
 
-        ladder.add(BookOrder {
-            price: Price::from("100.00"),
-            size: Quantity::from(100),
-            side: OrderSide::Buy,
-            order_id: 1,
-        });
+        ladder.add(
+            BookOrder {
+                price: Price::from("100.00"),
+                size: Quantity::from(100),
+                side: OrderSide::Buy,
+                order_id: 1,
+            },
+            0.into(),
+        );
 
         let order = BookOrder {
             price: Price::from("150.00"), // <-- Simulate a MARKET order
@@ -717,28 +877,31 @@ mod tests {
 
     #[rstest]
     fn test_simulate_order_fills_buy() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Sell);
-
-        ladder.add_bulk(vec![
-            BookOrder {
-                price: Price::from("100.00"),
-                size: Quantity::from(100),
-                side: OrderSide::Sell,
-                order_id: 1,
-            },
-            BookOrder {
-                price: Price::from("101.00"),
-                size: Quantity::from(200),
-                side: OrderSide::Sell,
-                order_id: 2,
-            },
-            BookOrder {
-                price: Price::from("102.00"),
-                size: Quantity::from(400),
-                side: OrderSide::Sell,
-                order_id: 3,
-            },
-        ]);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Sell, BookType::L2_MBP);
+
+        ladder.add_bulk(
+            vec![
+                BookOrder {
+                    price: Price::from("100.00"),
+                    size: Quantity::from(100),
+                    side: OrderSide::Sell,
+                    order_id: 1,
+                },
+                BookOrder {
+                    price: Price::from("101.00"),
+                    size: Quantity::from(200),
+                    side: OrderSide::Sell,
+                    order_id: 2,
+                },
+                BookOrder {
+                    price: Price::from("102.00"),
+                    size: Quantity::from(400),
+                    side: OrderSide::Sell,
+                    order_id: 3,
+                },
+            ],
+            0.into(),
+        );
 
         let order = BookOrder {
             price: Price::max(2), // <-- Simulate a MARKET order
@@ -766,28 +929,31 @@ mod tests {
 
     #[rstest]
     fn test_simulate_order_fills_sell() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
-
-        ladder.add_bulk(vec![
-            BookOrder {
-                price: Price::from("102.00"),
-                size: Quantity::from(100),
-                side: OrderSide::Buy,
-                order_id: 1,
-            },
-            BookOrder {
-                price: Price::from("101.00"),
-                size: Quantity::from(200),
-                side: OrderSide::Buy,
-                order_id: 2,
-            },
-            BookOrder {
-                price: Price::from("100.00"),
-                size: Quantity::from(400),
-                side: OrderSide::Buy,
-                order_id: 3,
-            },
-        ]);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
+
+        ladder.add_bulk(
+            vec![
+                BookOrder {
+                    price: Price::from("102.00"),
+                    size: Quantity::from(100),
+                    side: OrderSide::Buy,
+                    order_id: 1,
+                },
+                BookOrder {
+                    price: Price::from("101.00"),
+                    size: Quantity::from(200),
+                    side: OrderSide::Buy,
+                    order_id: 2,
+                },
+                BookOrder {
+                    price: Price::from("100.00"),
+                    size: Quantity::from(400),
+                    side: OrderSide::Buy,
+                    order_id: 3,
+                },
+            ],
+            0.into(),
+        );
 
         let order = BookOrder {
             price: Price::min(2), // <-- Simulate a MARKET order
@@ -815,28 +981,31 @@ mod tests {
 
     #[rstest]
     fn test_simulate_order_fills_sell_with_size_at_limit_of_precision() {
-        let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
-
-        ladder.add_bulk(vec![
-            BookOrder {
-                price: Price::from("102.00"),
-                size: Quantity::from("100.000000000"),
-                side: OrderSide::Buy,
-                order_id: 1,
-            },
-            BookOrder {
-                price: Price::from("101.00"),
-                size: Quantity::from("200.000000000"),
-                side: OrderSide::Buy,
-                order_id: 2,
-            },
-            BookOrder {
-                price: Price::from("100.00"),
-                size: Quantity::from("400.000000000"),
-                side: OrderSide::Buy,
-                order_id: 3,
-            },
-        ]);
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
+
+        ladder.add_bulk(
+            vec![
+                BookOrder {
+                    price: Price::from("102.00"),
+                    size: Quantity::from("100.000000000"),
+                    side: OrderSide::Buy,
+                    order_id: 1,
+                },
+                BookOrder {
+                    price: Price::from("101.00"),
+                    size: Quantity::from("200.000000000"),
+                    side: OrderSide::Buy,
+                    order_id: 2,
+                },
+                BookOrder {
+                    price: Price::from("100.00"),
+                    size: Quantity::from("400.000000000"),
+                    side: OrderSide::Buy,
+                    order_id: 3,
+                },
+            ],
+            0.into(),
+        );
 
         let order = BookOrder {
             price: Price::min(2),                  // <-- Simulate a MARKET order
@@ -867,16 +1036,129 @@ mod tests {
         let max_price = Price::max(1);
         let min_price = Price::min(1);
 
-        let mut ladder_buy = BookLadder::new(OrderSideSpecified::Buy);
-        let mut ladder_sell = BookLadder::new(OrderSideSpecified::Sell);
+        let mut ladder_buy = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
+        let mut ladder_sell = BookLadder::new(OrderSideSpecified::Sell, BookType::L2_MBP);
 
         let order_buy = BookOrder::new(OrderSide::Buy, min_price, Quantity::from(1), 1);
         let order_sell = BookOrder::new(OrderSide::Sell, max_price, Quantity::from(1), 1);
 
-        ladder_buy.add(order_buy);
-        ladder_sell.add(order_sell);
+        ladder_buy.add(order_buy, 0.into());
+        ladder_sell.add(order_sell, 0.into());
 
         assert_eq!(ladder_buy.top().unwrap().price.value, min_price);
         assert_eq!(ladder_sell.top().unwrap().price.value, max_price);
     }
+
+    #[rstest]
+    fn test_order_age_l3() {
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L3_MBO);
+        let order1 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 1);
+        let order2 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(20), 2);
+        ladder.add(order1, 100.into());
+        ladder.add(order2, 300.into());
+        let book_price = order1.to_book_price();
+
+        assert_eq!(ladder.order_ts_added(1), Some(100.into()));
+        assert_eq!(ladder.order_age(1, 1_000.into()), Some(900));
+        assert_eq!(ladder.avg_order_age(&book_price, 1_000.into()), Some(800.0));
+
+        ladder.delete(order1, 0, 1_000.into());
+        assert_eq!(ladder.order_age(1, 1_000.into()), None);
+        assert_eq!(ladder.avg_order_age(&book_price, 1_000.into()), Some(700.0));
+    }
+
+    #[rstest]
+    fn test_order_age_not_tracked_l2() {
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L2_MBP);
+        let order = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 1);
+        ladder.add(order, 100.into());
+
+        assert_eq!(ladder.order_ts_added(1), None);
+        assert_eq!(
+            ladder.avg_order_age(&order.to_book_price(), 1_000.into()),
+            None
+        );
+        assert_eq!(ladder.level_stats(&order.to_book_price()), None);
+    }
+
+    #[rstest]
+    fn test_level_stats_l3() {
+        let mut ladder = BookLadder::new(OrderSideSpecified::Sell, BookType::L3_MBO);
+        let order1 = BookOrder::new(OrderSide::Sell, Price::from("1.00"), Quantity::from(10), 1);
+        let order2 = BookOrder::new(OrderSide::Sell, Price::from("1.00"), Quantity::from(20), 2);
+        ladder.add(order1, 0.into());
+        ladder.add(order2, 0.into());
+
+        // Partial fill of order 1, increase order 2, then cancel both
+        ladder.update(
+            BookOrder::new(OrderSide::Sell, Price::from("1.00"), Quantity::from(4), 1),
+            0.into(),
+        );
+        ladder.update(
+            BookOrder::new(OrderSide::Sell, Price::from("1.00"), Quantity::from(25), 2),
+            0.into(),
+        );
+        ladder.remove(1, 0, 0.into());
+        ladder.remove(2, 0, 0.into());
+        assert!(ladder.is_empty());
+
+        let stats = ladder.level_stats(&order1.to_book_price()).unwrap();
+        let scalar = FIXED_SCALAR as QuantityRaw;
+        assert_eq!(stats.add_count, 2);
+        assert_eq!(stats.add_size_raw, 35 * scalar);
+        assert_eq!(stats.fill_count, 1);
+        assert_eq!(stats.fill_size_raw, 6 * scalar);
+        assert_eq!(stats.cancel_count, 2);
+        assert_eq!(stats.cancel_size_raw, 29 * scalar);
+        assert_eq!(stats.modify_count, 0);
+        assert_eq!(stats.cancel_fill_ratio(), Some(29.0 / 6.0));
+    }
+
+    #[rstest]
+    fn test_level_stats_l3_readd_and_zero_size_update() {
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L3_MBO);
+        let order = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 1);
+        ladder.add(order, 0.into());
+
+        // Re-adding a resting order only records the size increase
+        ladder.add(
+            BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(15), 1),
+            0.into(),
+        );
+        ladder.update(
+            BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(0), 1),
+            0.into(),
+        );
+
+        let stats = ladder.level_stats(&order.to_book_price()).unwrap();
+        let scalar = FIXED_SCALAR as QuantityRaw;
+        assert_eq!(stats.add_count, 1);
+        assert_eq!(stats.add_size_raw, 15 * scalar);
+        assert_eq!(stats.fill_count, 0);
+        assert_eq!(stats.cancel_count, 1);
+        assert_eq!(stats.cancel_size_raw, 15 * scalar);
+    }
+
+    #[rstest]
+    fn test_level_stats_l3_emptied_levels_are_capped() {
+        let mut ladder = BookLadder::new(OrderSideSpecified::Buy, BookType::L3_MBO);
+        let count = MAX_EMPTY_LEVEL_STATS as u64 + 10;
+        for order_id in 1..=count {
+            let price = Price::new(order_id as f64, 0);
+            ladder.add(
+                BookOrder::new(OrderSide::Buy, price, Quantity::from(1), order_id),
+                0.into(),
+            );
+            ladder.remove(order_id, 0, 0.into());
+        }
+
+        assert!(ladder.is_empty());
+        assert_eq!(ladder.stats.len(), MAX_EMPTY_LEVEL_STATS);
+
+        // The lowest bids are furthest from the top
+        let lowest = BookPrice::new(Price::new(1.0, 0), OrderSideSpecified::Buy);
+        let highest = BookPrice::new(Price::new(count as f64, 0), OrderSideSpecified::Buy);
+        assert_eq!(ladder.level_stats(&lowest), None);
+        assert!(ladder.level_stats(&highest).is_some());
+    }
 }
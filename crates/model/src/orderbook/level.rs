@@ -28,6 +28,44 @@ use crate::{
     types::{fixed::FIXED_SCALAR, quantity::QuantityRaw},
 };
 
+/// Represents queue activity statistics for a price level.
+///
+/// Statistics are only tracked for L3 (MBO) books where each order is tracked individually,
+/// and are retained by the ladder when the level is emptied (for a bounded number of emptied
+/// levels). Size reductions of resting orders at an unchanged price are counted as fills,
+/// deletions of resting orders (or updates to a zero size) are counted as cancels, and
+/// resting orders moved to another price are counted as modifies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BookLevelStats {
+    /// The count of orders added to the level.
+    pub add_count: u64,
+    /// The total size added to the level (raw units).
+    pub add_size_raw: QuantityRaw,
+    /// The count of orders canceled (deleted) from the level.
+    pub cancel_count: u64,
+    /// The total size canceled from the level (raw units).
+    pub cancel_size_raw: QuantityRaw,
+    /// The count of orders modified to another price from the level.
+    pub modify_count: u64,
+    /// The count of fill events (size reductions) at the level.
+    pub fill_count: u64,
+    /// The total size filled at the level (raw units).
+    pub fill_size_raw: QuantityRaw,
+}
+
+impl BookLevelStats {
+    /// Returns the ratio of canceled size to filled size.
+    ///
+    /// Returns `None` if no size has been filled at the level.
+    #[must_use]
+    pub fn cancel_fill_ratio(&self) -> Option<f64> {
+        if self.fill_size_raw == 0 {
+            return None;
+        }
+        Some(self.cancel_size_raw as f64 / self.fill_size_raw as f64)
+    }
+}
+
 /// Represents a discrete price level in an order book.
 ///
 /// Orders are stored in an [`IndexMap`] which preserves FIFO (insertion) order.
@@ -54,10 +92,7 @@ impl BookLevel {
     /// Creates a new [`BookLevel`] from an order, using the order's price and side.
     #[must_use]
     pub fn from_order(order: BookOrder) -> Self {
-        let mut level = Self {
-            price: order.to_book_price(),
-            orders: IndexMap::new(),
-        };
+        let mut level = Self::new(order.to_book_price());
         level.add(order);
         level
     }
@@ -175,6 +210,49 @@ impl BookLevel {
             &BookIntegrityError::OrderNotFound(order_id, sequence, ts_event)
         );
     }
+
+    /// Returns the zero-based queue position of the order with the given `order_id`
+    /// (equal to the number of orders ahead of it), or `None` if not at this level.
+    #[must_use]
+    pub fn queue_position(&self, order_id: OrderId) -> Option<usize> {
+        self.orders.get_index_of(&order_id)
+    }
+
+    /// Returns the orders ahead of the order with the given `order_id` in FIFO order,
+    /// or `None` if not at this level.
+    #[must_use]
+    pub fn orders_ahead(&self, order_id: OrderId) -> Option<Vec<BookOrder>> {
+        let position = self.queue_position(order_id)?;
+        Some(self.orders.values().take(position).copied().collect())
+    }
+
+    /// Returns the total size ahead of the order with the given `order_id` as raw integer
+    /// units, or `None` if not at this level.
+    #[must_use]
+    pub fn size_ahead_raw(&self, order_id: OrderId) -> Option<QuantityRaw> {
+        let position = self.queue_position(order_id)?;
+        Some(
+            self.orders
+                .values()
+                .take(position)
+                .map(|o| o.size.raw)
+                .sum(),
+        )
+    }
+
+    /// Returns the total size ahead of the order with the given `order_id` as a float,
+    /// or `None` if not at this level.
+    #[must_use]
+    pub fn size_ahead(&self, order_id: OrderId) -> Option<f64> {
+        let position = self.queue_position(order_id)?;
+        Some(
+            self.orders
+                .values()
+                .take(position)
+                .map(|o| o.size.as_f64())
+                .sum(),
+        )
+    }
 }
 
 impl PartialEq for BookLevel {
@@ -653,4 +731,25 @@ mod tests {
             (60.0 * FIXED_SCALAR).round() as QuantityRaw
         );
     }
+
+    #[rstest]
+    fn test_queue_position_and_orders_ahead() {
+        let mut level =
+            BookLevel::new(BookPrice::new(Price::from("1.00"), OrderSideSpecified::Buy));
+        let order1 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 1);
+        let order2 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(20), 2);
+        let order3 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(30), 3);
+        level.add_bulk(vec![order1, order2, order3]);
+
+        assert_eq!(level.queue_position(1), Some(0));
+        assert_eq!(level.queue_position(3), Some(2));
+        assert_eq!(level.queue_position(4), None);
+        assert_eq!(level.orders_ahead(3).unwrap(), vec![order1, order2]);
+        assert_eq!(level.size_ahead(3), Some(30.0));
+        assert_eq!(
+            level.size_ahead_raw(2),
+            Some(10 * FIXED_SCALAR as QuantityRaw)
+        );
+        assert_eq!(level.size_ahead(4), None);
+    }
 }
@@ -32,6 +32,6 @@ pub use crate::orderbook::{
     book::OrderBook,
     error::{BookIntegrityError, InvalidBookOperation},
     ladder::BookPrice,
-    level::BookLevel,
+    level::{BookLevel, BookLevelStats},
     own::OwnBookOrder,
 };
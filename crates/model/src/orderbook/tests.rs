@@ -1782,6 +1782,217 @@ fn test_book_group_with_status_filter() {
     assert_eq!(grouped_bids.get(&dec!(100.0)), Some(&dec!(60))); // 100 - 40 = 60 (only ACCEPTED is filtered)
 }
 
+#[rstest]
+fn test_book_l3_queue_position_and_size_ahead() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+    let price = Price::from("100.00");
+
+    book.add(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(100), 1),
+        0,
+        1,
+        1_000.into(),
+    );
+    book.add(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(200), 2),
+        0,
+        2,
+        2_000.into(),
+    );
+    book.add(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(300), 3),
+        0,
+        3,
+        3_000.into(),
+    );
+
+    assert_eq!(book.queue_position(1), Some(0));
+    assert_eq!(book.queue_position(3), Some(2));
+    assert_eq!(book.queue_position(99), None);
+    assert_eq!(book.size_ahead(1), Some(0.0));
+    assert_eq!(book.size_ahead(3), Some(300.0));
+    assert_eq!(
+        book.orders_ahead(3)
+            .unwrap()
+            .iter()
+            .map(|o| o.order_id)
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    book.delete(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(100), 1),
+        0,
+        4,
+        4_000.into(),
+    );
+
+    assert_eq!(book.queue_position(3), Some(1));
+    assert_eq!(book.size_ahead(3), Some(200.0));
+}
+
+#[rstest]
+fn test_book_l3_order_age() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+    let order = BookOrder::new(
+        OrderSide::Sell,
+        Price::from("101.00"),
+        Quantity::from(100),
+        1,
+    );
+
+    book.add(order, 0, 1, 1_000.into());
+    book.add(
+        BookOrder::new(
+            OrderSide::Sell,
+            Price::from("102.00"),
+            Quantity::from(100),
+            2,
+        ),
+        0,
+        2,
+        5_000.into(),
+    );
+
+    // Partial fill keeps the original queue time
+    let partial = BookOrder::new(
+        OrderSide::Sell,
+        Price::from("101.00"),
+        Quantity::from(40),
+        1,
+    );
+    book.update(partial, 0, 3, 8_000.into());
+    assert_eq!(book.order_age(1), Some(7_000));
+
+    // Price modification loses queue priority
+    let modified = BookOrder::new(
+        OrderSide::Sell,
+        Price::from("101.50"),
+        Quantity::from(40),
+        1,
+    );
+    book.update(modified, 0, 4, 9_000.into());
+    assert_eq!(book.order_age(1), Some(0));
+    assert_eq!(book.order_age(2), Some(4_000));
+    assert_eq!(book.order_age(99), None);
+}
+
+#[rstest]
+fn test_book_l3_cancel_fill_ratio() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+    let price = Price::from("100.00");
+
+    book.add(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(100), 1),
+        0,
+        1,
+        1.into(),
+    );
+    book.add(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(100), 2),
+        0,
+        2,
+        2.into(),
+    );
+    book.add(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(100), 3),
+        0,
+        3,
+        3.into(),
+    );
+
+    assert_eq!(book.cancel_fill_ratio(OrderSide::Buy, price), None);
+
+    // Fill 50 of order 1, cancel order 2
+    book.update(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(50), 1),
+        0,
+        4,
+        4.into(),
+    );
+    book.delete(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(100), 2),
+        0,
+        5,
+        5.into(),
+    );
+
+    let stats = book.level_stats(OrderSide::Buy, price).unwrap();
+    assert_eq!(stats.add_count, 3);
+    assert_eq!(stats.fill_count, 1);
+    assert_eq!(stats.cancel_count, 1);
+    assert_eq!(book.cancel_fill_ratio(OrderSide::Buy, price), Some(2.0));
+    assert_eq!(book.cancel_fill_ratio(OrderSide::Sell, price), None);
+}
+
+#[rstest]
+fn test_book_l3_price_modify_counts_as_modify_and_stats_survive_empty_level() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+    let price1 = Price::from("100.00");
+    let price2 = Price::from("101.00");
+
+    book.add(
+        BookOrder::new(OrderSide::Buy, price1, Quantity::from(100), 1),
+        0,
+        1,
+        10.into(),
+    );
+    book.update(
+        BookOrder::new(OrderSide::Buy, price2, Quantity::from(100), 1),
+        0,
+        2,
+        20.into(),
+    );
+    book.add(
+        BookOrder::new(OrderSide::Buy, price2, Quantity::from(100), 2),
+        0,
+        3,
+        40.into(),
+    );
+
+    // Emptied level is removed but its statistics are retained
+    assert!(book.level(OrderSide::Buy, price1).is_none());
+    let stats = book.level_stats(OrderSide::Buy, price1).unwrap();
+    assert_eq!(stats.add_count, 1);
+    assert_eq!(stats.modify_count, 1);
+    assert_eq!(stats.cancel_count, 0);
+
+    let stats = book.level_stats(OrderSide::Buy, price2).unwrap();
+    assert_eq!(stats.add_count, 2);
+    assert_eq!(stats.modify_count, 0);
+    assert_eq!(book.avg_order_age(OrderSide::Buy, price2), Some(10.0));
+
+    book.clear(3, 50.into());
+    assert_eq!(book.level_stats(OrderSide::Buy, price1), None);
+}
+
+#[rstest]
+fn test_book_l2_does_not_track_queue() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+    let price = Price::from("100.00");
+
+    book.add(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(100), 1),
+        0,
+        1,
+        1.into(),
+    );
+    book.delete(
+        BookOrder::new(OrderSide::Buy, price, Quantity::from(100), 1),
+        0,
+        2,
+        2.into(),
+    );
+
+    assert_eq!(book.level_stats(OrderSide::Buy, price), None);
+    assert_eq!(book.avg_order_age(OrderSide::Buy, price), None);
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // OwnOrderBook
 ////////////////////////////////////////////////////////////////////////////////
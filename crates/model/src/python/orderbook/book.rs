@@ -16,12 +16,18 @@
 use std::collections::HashSet;
 
 use indexmap::IndexMap;
-use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
+use nautilus_core::{
+    nanos::DurationNanos,
+    python::{to_pyruntime_err, to_pyvalue_err},
+};
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
 
 use crate::{
-    data::{BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick},
+    data::{
+        BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
+        order::OrderId,
+    },
     enums::{BookType, OrderSide, OrderStatus},
     identifiers::InstrumentId,
     orderbook::{BookLevel, OrderBook, analysis::book_check_integrity, own::OwnOrderBook},
@@ -317,6 +323,42 @@ impl OrderBook {
         self.simulate_fills(order)
     }
 
+    #[pyo3(name = "queue_position")]
+    fn py_queue_position(&self, order_id: OrderId) -> Option<usize> {
+        self.queue_position(order_id)
+    }
+
+    #[pyo3(name = "orders_ahead")]
+    fn py_orders_ahead(&self, order_id: OrderId) -> Option<Vec<BookOrder>> {
+        self.orders_ahead(order_id)
+    }
+
+    #[pyo3(name = "size_ahead")]
+    fn py_size_ahead(&self, order_id: OrderId) -> Option<f64> {
+        self.size_ahead(order_id)
+    }
+
+    #[pyo3(name = "order_age")]
+    fn py_order_age(&self, order_id: OrderId) -> Option<DurationNanos> {
+        self.order_age(order_id)
+    }
+
+    #[pyo3(name = "avg_order_age")]
+    fn py_avg_order_age(&self, side: OrderSide, price: Price) -> PyResult<Option<f64>> {
+        if side == OrderSide::NoOrderSide {
+            return Err(to_pyvalue_err("`side` must be BUY or SELL"));
+        }
+        Ok(self.avg_order_age(side, price))
+    }
+
+    #[pyo3(name = "cancel_fill_ratio")]
+    fn py_cancel_fill_ratio(&self, side: OrderSide, price: Price) -> PyResult<Option<f64>> {
+        if side == OrderSide::NoOrderSide {
+            return Err(to_pyvalue_err("`side` must be BUY or SELL"));
+        }
+        Ok(self.cancel_fill_ratio(side, price))
+    }
+
     #[pyo3(name = "pprint")]
     #[pyo3(signature = (num_levels=3))]
     fn py_pprint(&self, num_levels: usize) -> String {
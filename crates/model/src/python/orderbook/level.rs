@@ -16,7 +16,7 @@
 use pyo3::prelude::*;
 
 use crate::{
-    data::order::BookOrder,
+    data::order::{BookOrder, OrderId},
     orderbook::BookLevel,
     types::{price::Price, quantity::QuantityRaw},
 };
@@ -77,4 +77,19 @@ impl BookLevel {
     fn py_get_orders(&self) -> Vec<BookOrder> {
         self.get_orders()
     }
+
+    #[pyo3(name = "queue_position")]
+    fn py_queue_position(&self, order_id: OrderId) -> Option<usize> {
+        self.queue_position(order_id)
+    }
+
+    #[pyo3(name = "orders_ahead")]
+    fn py_orders_ahead(&self, order_id: OrderId) -> Option<Vec<BookOrder>> {
+        self.orders_ahead(order_id)
+    }
+
+    #[pyo3(name = "size_ahead")]
+    fn py_size_ahead(&self, order_id: OrderId) -> Option<f64> {
+        self.size_ahead(order_id)
+    }
 }
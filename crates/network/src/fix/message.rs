@@ -0,0 +1,640 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX message builder and parser.
+//!
+//! A [`FixMessage`] holds its fields in wire order (so repeating groups are preserved). The
+//! standard header and trailer are written by [`FixMessage::encode`] from a [`FixHeader`],
+//! and validated by [`FixMessage::parse`]. Session level messages have a typed representation
+//! in [`AdminMessage`].
+
+use std::str::FromStr;
+
+use chrono::DateTime;
+use nautilus_core::UnixNanos;
+
+/// Standard FIX tags used by the session layer.
+pub mod fix_tag {
+    pub const BEGIN_SEQ_NO: u32 = 7;
+    pub const BEGIN_STRING: u32 = 8;
+    pub const BODY_LENGTH: u32 = 9;
+    pub const CHECKSUM: u32 = 10;
+    pub const END_SEQ_NO: u32 = 16;
+    pub const MSG_SEQ_NUM: u32 = 34;
+    pub const MSG_TYPE: u32 = 35;
+    pub const NEW_SEQ_NO: u32 = 36;
+    pub const POSS_DUP_FLAG: u32 = 43;
+    pub const REF_SEQ_NUM: u32 = 45;
+    pub const SENDER_COMP_ID: u32 = 49;
+    pub const SENDING_TIME: u32 = 52;
+    pub const TARGET_COMP_ID: u32 = 56;
+    pub const TEXT: u32 = 58;
+    pub const ENCRYPT_METHOD: u32 = 98;
+    pub const HEART_BT_INT: u32 = 108;
+    pub const TEST_REQ_ID: u32 = 112;
+    pub const ORIG_SENDING_TIME: u32 = 122;
+    pub const GAP_FILL_FLAG: u32 = 123;
+    pub const RESET_SEQ_NUM_FLAG: u32 = 141;
+    pub const REF_TAG_ID: u32 = 371;
+    pub const REF_MSG_TYPE: u32 = 372;
+    pub const SESSION_REJECT_REASON: u32 = 373;
+    pub const USERNAME: u32 = 553;
+    pub const PASSWORD: u32 = 554;
+}
+
+/// Session level (admin) FIX message types.
+pub mod fix_msg_type {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const RESEND_REQUEST: &str = "2";
+    pub const REJECT: &str = "3";
+    pub const SEQUENCE_RESET: &str = "4";
+    pub const LOGOUT: &str = "5";
+    pub const LOGON: &str = "A";
+}
+
+/// The FIX field delimiter (SOH).
+pub const FIX_DELIMITER: u8 = b'\x01';
+
+/// The FIX 4.4 `BeginString`.
+pub const FIX44_BEGIN_STRING: &str = "FIX.4.4";
+
+/// Tags written by [`FixMessage::encode`] as part of the standard header and trailer.
+const HEADER_TAGS: [u32; 10] = [
+    fix_tag::BEGIN_STRING,
+    fix_tag::BODY_LENGTH,
+    fix_tag::MSG_TYPE,
+    fix_tag::SENDER_COMP_ID,
+    fix_tag::TARGET_COMP_ID,
+    fix_tag::MSG_SEQ_NUM,
+    fix_tag::POSS_DUP_FLAG,
+    fix_tag::SENDING_TIME,
+    fix_tag::ORIG_SENDING_TIME,
+    fix_tag::CHECKSUM,
+];
+
+/// Returns whether the given `msg_type` is a session level (admin) message type.
+#[must_use]
+pub fn is_admin_msg_type(msg_type: &str) -> bool {
+    matches!(
+        msg_type,
+        fix_msg_type::HEARTBEAT
+            | fix_msg_type::TEST_REQUEST
+            | fix_msg_type::RESEND_REQUEST
+            | fix_msg_type::REJECT
+            | fix_msg_type::SEQUENCE_RESET
+            | fix_msg_type::LOGOUT
+            | fix_msg_type::LOGON
+    )
+}
+
+/// Formats the given UNIX timestamp as a FIX UTC timestamp with milliseconds
+/// (e.g. `20250322-12:34:56.789`).
+#[must_use]
+pub fn format_fix_timestamp(ts: UnixNanos) -> String {
+    let nanos = i64::try_from(ts.as_u64()).unwrap_or(i64::MAX);
+    DateTime::from_timestamp_nanos(nanos)
+        .format("%Y%m%d-%H:%M:%S%.3f")
+        .to_string()
+}
+
+/// Computes the FIX checksum (sum of all bytes modulo 256) of `data`.
+#[must_use]
+pub fn fix_checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0_u8, |acc, &b| acc.wrapping_add(b))
+}
+
+/// The standard header values written when encoding a [`FixMessage`].
+#[derive(Clone, Debug)]
+pub struct FixHeader<'a> {
+    /// The `BeginString` (e.g. `FIX.4.4`).
+    pub begin_string: &'a str,
+    /// The `SenderCompID`.
+    pub sender_comp_id: &'a str,
+    /// The `TargetCompID`.
+    pub target_comp_id: &'a str,
+    /// The `MsgSeqNum`.
+    pub msg_seq_num: u64,
+    /// The `SendingTime`.
+    pub sending_time: UnixNanos,
+    /// The `OrigSendingTime` of a resent message, which also sets `PossDupFlag=Y`.
+    pub orig_sending_time: Option<&'a str>,
+}
+
+/// Represents a FIX message as an ordered sequence of tag/value fields.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixMessage {
+    fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    /// Creates a new [`FixMessage`] of the given `msg_type` with no body fields.
+    #[must_use]
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            fields: vec![(fix_tag::MSG_TYPE, msg_type.to_string())],
+        }
+    }
+
+    /// Returns the message with the given field appended.
+    #[must_use]
+    pub fn with_field(mut self, tag: u32, value: impl ToString) -> Self {
+        self.add_field(tag, value);
+        self
+    }
+
+    /// Appends a field to the message (repeated tags are kept, as used by repeating groups).
+    pub fn add_field(&mut self, tag: u32, value: impl ToString) -> &mut Self {
+        self.fields.push((tag, value.to_string()));
+        self
+    }
+
+    /// Sets the first occurrence of `tag` to `value`, appending the field if not present.
+    pub fn set_field(&mut self, tag: u32, value: impl ToString) -> &mut Self {
+        match self.fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some(field) => field.1 = value.to_string(),
+            None => self.fields.push((tag, value.to_string())),
+        }
+        self
+    }
+
+    /// Removes all occurrences of `tag` from the message.
+    pub fn remove_field(&mut self, tag: u32) -> &mut Self {
+        self.fields.retain(|(t, _)| *t != tag);
+        self
+    }
+
+    /// Returns all fields in wire order.
+    #[must_use]
+    pub fn fields(&self) -> &[(u32, String)] {
+        &self.fields
+    }
+
+    /// Returns the value of the first occurrence of `tag`.
+    #[must_use]
+    pub fn get_field(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(t, _)| *t == tag)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns the value of the first occurrence of `tag`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tag is missing.
+    pub fn get_field_checked(&self, tag: u32) -> anyhow::Result<&str> {
+        self.get_field(tag)
+            .ok_or_else(|| anyhow::anyhow!("Missing tag {tag}"))
+    }
+
+    /// Returns the values of all occurrences of `tag` in wire order.
+    #[must_use]
+    pub fn get_fields(&self, tag: u32) -> Vec<&str> {
+        self.fields
+            .iter()
+            .filter(|(t, _)| *t == tag)
+            .map(|(_, v)| v.as_str())
+            .collect()
+    }
+
+    /// Returns the value of `tag` parsed as `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tag is missing or the value cannot be parsed.
+    pub fn get_parsed<T>(&self, tag: u32) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        let value = self.get_field_checked(tag)?;
+        value
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid value '{value}' for tag {tag}: {e}"))
+    }
+
+    /// Returns whether the boolean field `tag` is present and set to `Y`.
+    #[must_use]
+    pub fn get_flag(&self, tag: u32) -> bool {
+        self.get_field(tag) == Some("Y")
+    }
+
+    /// Returns the `MsgType`.
+    #[must_use]
+    pub fn msg_type(&self) -> Option<&str> {
+        self.get_field(fix_tag::MSG_TYPE)
+    }
+
+    /// Returns the `MsgSeqNum`.
+    #[must_use]
+    pub fn msg_seq_num(&self) -> Option<u64> {
+        self.get_field(fix_tag::MSG_SEQ_NUM)
+            .and_then(|s| s.parse().ok())
+    }
+
+    /// Returns whether the message is flagged as a possible duplicate.
+    #[must_use]
+    pub fn is_poss_dup(&self) -> bool {
+        self.get_flag(fix_tag::POSS_DUP_FLAG)
+    }
+
+    /// Returns whether the message is a session level (admin) message.
+    #[must_use]
+    pub fn is_admin(&self) -> bool {
+        self.msg_type().is_some_and(is_admin_msg_type)
+    }
+
+    /// Encodes the message with the given standard `header`, computing `BodyLength` and
+    /// `CheckSum`.
+    ///
+    /// Any header or trailer fields already present on the message are replaced.
+    #[must_use]
+    pub fn encode(&self, header: &FixHeader) -> Vec<u8> {
+        let mut body = Vec::with_capacity(256);
+        let push = |buf: &mut Vec<u8>, tag: u32, value: &str| {
+            buf.extend_from_slice(tag.to_string().as_bytes());
+            buf.push(b'=');
+            buf.extend_from_slice(value.as_bytes());
+            buf.push(FIX_DELIMITER);
+        };
+
+        push(
+            &mut body,
+            fix_tag::MSG_TYPE,
+            self.msg_type().unwrap_or_default(),
+        );
+        push(&mut body, fix_tag::SENDER_COMP_ID, header.sender_comp_id);
+        push(&mut body, fix_tag::TARGET_COMP_ID, header.target_comp_id);
+        push(
+            &mut body,
+            fix_tag::MSG_SEQ_NUM,
+            &header.msg_seq_num.to_string(),
+        );
+        if header.orig_sending_time.is_some() {
+            push(&mut body, fix_tag::POSS_DUP_FLAG, "Y");
+        }
+        push(
+            &mut body,
+            fix_tag::SENDING_TIME,
+            &format_fix_timestamp(header.sending_time),
+        );
+        if let Some(orig_sending_time) = header.orig_sending_time {
+            push(&mut body, fix_tag::ORIG_SENDING_TIME, orig_sending_time);
+        }
+
+        for (tag, value) in &self.fields {
+            if !HEADER_TAGS.contains(tag) {
+                push(&mut body, *tag, value);
+            }
+        }
+
+        let mut buf = Vec::with_capacity(body.len() + 32);
+        push(&mut buf, fix_tag::BEGIN_STRING, header.begin_string);
+        push(&mut buf, fix_tag::BODY_LENGTH, &body.len().to_string());
+        buf.extend_from_slice(&body);
+
+        let checksum = fix_checksum(&buf);
+        push(&mut buf, fix_tag::CHECKSUM, &format!("{checksum:03}"));
+
+        buf
+    }
+
+    /// Parses and validates a complete FIX message.
+    ///
+    /// The message must start with `BeginString`, `BodyLength` and `MsgType`, and end with
+    /// a `CheckSum` field; both the body length and checksum are verified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is malformed or fails validation.
+    pub fn parse(data: &[u8]) -> anyhow::Result<Self> {
+        let text = std::str::from_utf8(data).map_err(|e| anyhow::anyhow!("Invalid UTF-8: {e}"))?;
+        let text = text
+            .strip_suffix(FIX_DELIMITER as char)
+            .ok_or_else(|| anyhow::anyhow!("Message not terminated by SOH"))?;
+
+        let mut fields = Vec::new();
+        for field in text.split(FIX_DELIMITER as char) {
+            let (tag, value) = field
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Invalid field: {field}"))?;
+            let tag = tag
+                .parse::<u32>()
+                .map_err(|e| anyhow::anyhow!("Invalid tag '{tag}': {e}"))?;
+            fields.push((tag, value.to_string()));
+        }
+
+        let tags: Vec<u32> = fields.iter().take(3).map(|(t, _)| *t).collect();
+        anyhow::ensure!(
+            tags == [
+                fix_tag::BEGIN_STRING,
+                fix_tag::BODY_LENGTH,
+                fix_tag::MSG_TYPE
+            ],
+            "Message must start with BeginString, BodyLength and MsgType"
+        );
+        let Some((fix_tag::CHECKSUM, checksum)) = fields.last() else {
+            anyhow::bail!("Message must end with CheckSum");
+        };
+
+        // Checksum covers every byte up to (excluding) the "10=" trailer
+        let trailer_len = "10=".len() + checksum.len() + 1;
+        let checksum_start = data.len() - trailer_len;
+        let expected = fix_checksum(&data[..checksum_start]);
+        let actual: u8 = checksum
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid checksum '{checksum}': {e}"))?;
+        anyhow::ensure!(
+            actual == expected,
+            "Invalid checksum {actual:03}, expected {expected:03}"
+        );
+
+        // Body length covers the bytes after the BodyLength field up to the trailer
+        let body_start = fields[0].1.len() + fields[1].1.len() + "8=\x019=\x01".len();
+        let body_length: usize = fields[1].1.parse()?;
+        anyhow::ensure!(
+            checksum_start.checked_sub(body_start) == Some(body_length),
+            "Invalid BodyLength {body_length}, expected {}",
+            checksum_start.saturating_sub(body_start)
+        );
+
+        // Drop BeginString and BodyLength, so the message starts with MsgType as when built
+        fields.drain(..2);
+        fields.pop();
+
+        Ok(Self { fields })
+    }
+}
+
+/// Represents a typed session level (admin) FIX message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdminMessage {
+    /// Logon (`35=A`).
+    Logon {
+        heart_bt_int: u64,
+        reset_seq_num: bool,
+    },
+    /// Heartbeat (`35=0`), optionally answering a test request.
+    Heartbeat { test_req_id: Option<String> },
+    /// TestRequest (`35=1`).
+    TestRequest { test_req_id: String },
+    /// ResendRequest (`35=2`), an `end_seq_no` of zero requests all messages after `begin_seq_no`.
+    ResendRequest { begin_seq_no: u64, end_seq_no: u64 },
+    /// Session level Reject (`35=3`).
+    Reject {
+        ref_seq_num: u64,
+        reason: Option<u32>,
+        text: Option<String>,
+    },
+    /// SequenceReset (`35=4`), either in gap fill or reset mode.
+    SequenceReset { new_seq_no: u64, gap_fill: bool },
+    /// Logout (`35=5`).
+    Logout { text: Option<String> },
+}
+
+impl AdminMessage {
+    /// Returns the FIX `MsgType` of the message.
+    #[must_use]
+    pub const fn msg_type(&self) -> &'static str {
+        match self {
+            Self::Logon { .. } => fix_msg_type::LOGON,
+            Self::Heartbeat { .. } => fix_msg_type::HEARTBEAT,
+            Self::TestRequest { .. } => fix_msg_type::TEST_REQUEST,
+            Self::ResendRequest { .. } => fix_msg_type::RESEND_REQUEST,
+            Self::Reject { .. } => fix_msg_type::REJECT,
+            Self::SequenceReset { .. } => fix_msg_type::SEQUENCE_RESET,
+            Self::Logout { .. } => fix_msg_type::LOGOUT,
+        }
+    }
+
+    /// Converts the typed message into a [`FixMessage`] (without header fields).
+    #[must_use]
+    pub fn to_message(&self) -> FixMessage {
+        let mut msg = FixMessage::new(self.msg_type());
+        match self {
+            Self::Logon {
+                heart_bt_int,
+                reset_seq_num,
+            } => {
+                msg.add_field(fix_tag::ENCRYPT_METHOD, 0)
+                    .add_field(fix_tag::HEART_BT_INT, heart_bt_int);
+                if *reset_seq_num {
+                    msg.add_field(fix_tag::RESET_SEQ_NUM_FLAG, "Y");
+                }
+            }
+            Self::Heartbeat { test_req_id } => {
+                if let Some(test_req_id) = test_req_id {
+                    msg.add_field(fix_tag::TEST_REQ_ID, test_req_id);
+                }
+            }
+            Self::TestRequest { test_req_id } => {
+                msg.add_field(fix_tag::TEST_REQ_ID, test_req_id);
+            }
+            Self::ResendRequest {
+                begin_seq_no,
+                end_seq_no,
+            } => {
+                msg.add_field(fix_tag::BEGIN_SEQ_NO, begin_seq_no)
+                    .add_field(fix_tag::END_SEQ_NO, end_seq_no);
+            }
+            Self::Reject {
+                ref_seq_num,
+                reason,
+                text,
+            } => {
+                msg.add_field(fix_tag::REF_SEQ_NUM, ref_seq_num);
+                if let Some(reason) = reason {
+                    msg.add_field(fix_tag::SESSION_REJECT_REASON, reason);
+                }
+                if let Some(text) = text {
+                    msg.add_field(fix_tag::TEXT, text);
+                }
+            }
+            Self::SequenceReset {
+                new_seq_no,
+                gap_fill,
+            } => {
+                if *gap_fill {
+                    msg.add_field(fix_tag::GAP_FILL_FLAG, "Y");
+                }
+                msg.add_field(fix_tag::NEW_SEQ_NO, new_seq_no);
+            }
+            Self::Logout { text } => {
+                if let Some(text) = text {
+                    msg.add_field(fix_tag::TEXT, text);
+                }
+            }
+        }
+        msg
+    }
+
+    /// Parses the typed admin message from `msg`, returning `None` for application messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required field of the admin message is missing or invalid.
+    pub fn from_message(msg: &FixMessage) -> anyhow::Result<Option<Self>> {
+        let admin = match msg.msg_type() {
+            Some(fix_msg_type::LOGON) => Self::Logon {
+                heart_bt_int: msg.get_parsed(fix_tag::HEART_BT_INT)?,
+                reset_seq_num: msg.get_flag(fix_tag::RESET_SEQ_NUM_FLAG),
+            },
+            Some(fix_msg_type::HEARTBEAT) => Self::Heartbeat {
+                test_req_id: msg.get_field(fix_tag::TEST_REQ_ID).map(ToString::to_string),
+            },
+            Some(fix_msg_type::TEST_REQUEST) => Self::TestRequest {
+                test_req_id: msg.get_field_checked(fix_tag::TEST_REQ_ID)?.to_string(),
+            },
+            Some(fix_msg_type::RESEND_REQUEST) => Self::ResendRequest {
+                begin_seq_no: msg.get_parsed(fix_tag::BEGIN_SEQ_NO)?,
+                end_seq_no: msg.get_parsed(fix_tag::END_SEQ_NO)?,
+            },
+            Some(fix_msg_type::REJECT) => Self::Reject {
+                ref_seq_num: msg.get_parsed(fix_tag::REF_SEQ_NUM)?,
+                reason: msg.get_parsed(fix_tag::SESSION_REJECT_REASON).ok(),
+                text: msg.get_field(fix_tag::TEXT).map(ToString::to_string),
+            },
+            Some(fix_msg_type::SEQUENCE_RESET) => Self::SequenceReset {
+                new_seq_no: msg.get_parsed(fix_tag::NEW_SEQ_NO)?,
+                gap_fill: msg.get_flag(fix_tag::GAP_FILL_FLAG),
+            },
+            Some(fix_msg_type::LOGOUT) => Self::Logout {
+                text: msg.get_field(fix_tag::TEXT).map(ToString::to_string),
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(admin))
+    }
+}
+
+impl From<AdminMessage> for FixMessage {
+    fn from(value: AdminMessage) -> Self {
+        value.to_message()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    // 2025-03-22T12:34:56.789Z
+    const TS: u64 = 1_742_646_896_789_000_000;
+
+    fn header(seq: u64) -> FixHeader<'static> {
+        FixHeader {
+            begin_string: FIX44_BEGIN_STRING,
+            sender_comp_id: "CLIENT",
+            target_comp_id: "VENUE",
+            msg_seq_num: seq,
+            sending_time: UnixNanos::from(TS),
+            orig_sending_time: None,
+        }
+    }
+
+    #[rstest]
+    fn test_format_fix_timestamp() {
+        assert_eq!(
+            format_fix_timestamp(UnixNanos::from(TS)),
+            "20250322-12:34:56.789"
+        );
+    }
+
+    #[rstest]
+    fn test_encode_heartbeat() {
+        let bytes = AdminMessage::Heartbeat { test_req_id: None }
+            .to_message()
+            .encode(&header(2));
+        let text = String::from_utf8(bytes).unwrap();
+
+        assert_eq!(
+            text,
+            "8=FIX.4.4\x019=54\x0135=0\x0149=CLIENT\x0156=VENUE\x0134=2\x0152=20250322-12:34:56.789\x0110=037\x01"
+        );
+    }
+
+    #[rstest]
+    fn test_encode_parse_roundtrip_with_repeating_group() {
+        let msg = FixMessage::new("D")
+            .with_field(11, "O-1")
+            .with_field(453, 2)
+            .with_field(448, "A")
+            .with_field(448, "B");
+
+        let parsed = FixMessage::parse(&msg.encode(&header(7))).unwrap();
+
+        assert_eq!(parsed.msg_type(), Some("D"));
+        assert_eq!(parsed.msg_seq_num(), Some(7));
+        assert_eq!(parsed.get_field(fix_tag::SENDER_COMP_ID), Some("CLIENT"));
+        assert_eq!(parsed.get_field(11), Some("O-1"));
+        assert_eq!(parsed.get_fields(448), vec!["A", "B"]);
+        assert!(!parsed.is_admin());
+        assert!(!parsed.is_poss_dup());
+    }
+
+    #[rstest]
+    fn test_encode_resend_sets_poss_dup() {
+        let mut header = header(3);
+        header.orig_sending_time = Some("20250322-12:00:00.000");
+
+        let parsed = FixMessage::parse(&FixMessage::new("D").encode(&header)).unwrap();
+
+        assert!(parsed.is_poss_dup());
+        assert_eq!(
+            parsed.get_field(fix_tag::ORIG_SENDING_TIME),
+            Some("20250322-12:00:00.000")
+        );
+    }
+
+    #[rstest]
+    #[case(b"8=FIX.4.4\x019=5\x0135=0\x0110=000\x01".as_slice(), "checksum")]
+    #[case(b"8=FIX.4.4\x019=9\x0135=0\x0110=167\x01".as_slice(), "BodyLength")]
+    #[case(b"8=FIX.4.4\x0135=0\x019=5\x0110=163\x01".as_slice(), "must start")]
+    #[case(b"8=FIX.4.4\x019=5\x0135=0\x01".as_slice(), "must end")]
+    #[case(b"8=FIX.4.4\x019=5\x0135=0\x0110=163".as_slice(), "SOH")]
+    fn test_parse_invalid(#[case] data: &[u8], #[case] expected: &str) {
+        let err = FixMessage::parse(data).unwrap_err().to_string();
+        assert!(err.contains(expected), "{err}");
+    }
+
+    #[rstest]
+    #[case(AdminMessage::Logon { heart_bt_int: 30, reset_seq_num: true })]
+    #[case(AdminMessage::Heartbeat { test_req_id: Some("T1".to_string()) })]
+    #[case(AdminMessage::TestRequest { test_req_id: "T2".to_string() })]
+    #[case(AdminMessage::ResendRequest { begin_seq_no: 5, end_seq_no: 0 })]
+    #[case(AdminMessage::Reject { ref_seq_num: 4, reason: Some(1), text: Some("bad".to_string()) })]
+    #[case(AdminMessage::SequenceReset { new_seq_no: 10, gap_fill: true })]
+    #[case(AdminMessage::Logout { text: None })]
+    fn test_admin_message_roundtrip(#[case] admin: AdminMessage) {
+        let parsed = FixMessage::parse(&admin.to_message().encode(&header(1))).unwrap();
+
+        assert!(parsed.is_admin());
+        assert_eq!(AdminMessage::from_message(&parsed).unwrap(), Some(admin));
+    }
+
+    #[rstest]
+    fn test_admin_from_application_message() {
+        assert_eq!(
+            AdminMessage::from_message(&FixMessage::new("8")).unwrap(),
+            None
+        );
+    }
+}
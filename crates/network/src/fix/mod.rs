@@ -13,7 +13,18 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-//! Simple FIX message buffer processor.
+//! FIX protocol support.
+//!
+//! - `process_fix_buffer` frames FIX messages out of a raw byte stream by checksum.
+//! - [`message`] provides a typed FIX message builder and parser, including the session
+//!   level (admin) messages.
+//! - [`store`] persists session sequence numbers and sent messages for resends.
+//! - [`session`] implements a FIX 4.4 session layer (logon/logout, heartbeats, test requests,
+//!   resend requests, gap fills and sequence resets) on top of [`SocketClient`](crate::socket::SocketClient).
+
+pub mod message;
+pub mod session;
+pub mod store;
 
 use std::sync::Arc;
 
@@ -0,0 +1,1416 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX 4.4 session layer.
+//!
+//! The session protocol is implemented by [`FixSessionCore`], a state machine which performs
+//! no I/O: it consumes inbound bytes and timer ticks and returns [`FixSessionAction`]s to
+//! send bytes, deliver application messages or disconnect. It handles:
+//!
+//! - Logon/logout for both initiator and acceptor roles (with optional sequence reset).
+//! - Heartbeats, and TestRequests when the counterparty goes quiet.
+//! - Sequence number checks, ResendRequests on gaps (queuing messages received out of order)
+//!   and answering ResendRequests with stored messages and gap fills.
+//! - SequenceReset in both gap fill and reset modes.
+//!
+//! [`FixSession`] runs the core on top of a [`SocketClient`] for initiator sessions, so any
+//! FIX venue adapter only needs to build and handle its application messages.
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use nautilus_core::{UnixNanos, time::get_atomic_clock_realtime};
+use tokio::{
+    sync::mpsc::{UnboundedReceiver, unbounded_channel},
+    task::JoinHandle,
+};
+
+use super::{
+    message::{
+        AdminMessage, FIX44_BEGIN_STRING, FixHeader, FixMessage, fix_tag, format_fix_timestamp,
+    },
+    store::FixSessionStore,
+};
+use crate::socket::{SocketClient, SocketConfig, TcpMessageHandler};
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+/// Session reject reason: required tag missing.
+const REJECT_REASON_REQUIRED_TAG_MISSING: u32 = 1;
+/// Session reject reason: value is incorrect (out of range) for this tag.
+const REJECT_REASON_VALUE_INCORRECT: u32 = 5;
+
+/// The role of a FIX session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixSessionRole {
+    /// Connects and sends the first Logon.
+    Initiator,
+    /// Accepts connections and answers the counterparty's Logon.
+    Acceptor,
+}
+
+/// The state of a FIX session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixSessionState {
+    /// Not logged on.
+    Disconnected,
+    /// A Logon was sent and the counterparty's Logon is awaited.
+    LogonSent,
+    /// Logged on, application messages may be exchanged.
+    Active,
+    /// A Logout was sent and the counterparty's Logout is awaited.
+    LogoutSent,
+}
+
+/// Configuration for a FIX session.
+#[derive(Clone, Debug)]
+pub struct FixSessionConfig {
+    /// The `BeginString` (e.g. `FIX.4.4`).
+    pub begin_string: String,
+    /// Our `SenderCompID`.
+    pub sender_comp_id: String,
+    /// The counterparty's `SenderCompID` (our `TargetCompID`).
+    pub target_comp_id: String,
+    /// The heartbeat interval (seconds).
+    pub heartbeat_secs: u64,
+    /// If sequence numbers are reset on logon (`ResetSeqNumFlag=Y`).
+    pub reset_on_logon: bool,
+    /// Additional fields for the Logon message (e.g. username, password, signature).
+    pub logon_fields: Vec<(u32, String)>,
+    /// The timeout (seconds) waiting for the counterparty's Logon or Logout response.
+    pub logon_timeout_secs: u64,
+}
+
+impl FixSessionConfig {
+    /// Creates a new FIX 4.4 [`FixSessionConfig`] with a 30 second heartbeat.
+    #[must_use]
+    pub fn new(sender_comp_id: &str, target_comp_id: &str) -> Self {
+        Self {
+            begin_string: FIX44_BEGIN_STRING.to_string(),
+            sender_comp_id: sender_comp_id.to_string(),
+            target_comp_id: target_comp_id.to_string(),
+            heartbeat_secs: 30,
+            reset_on_logon: false,
+            logon_fields: Vec::new(),
+            logon_timeout_secs: 10,
+        }
+    }
+
+    /// Returns the session ID (e.g. `FIX.4.4-CLIENT-VENUE`), used to name persisted state.
+    #[must_use]
+    pub fn session_id(&self) -> String {
+        format!(
+            "{}-{}-{}",
+            self.begin_string, self.sender_comp_id, self.target_comp_id
+        )
+    }
+}
+
+/// Represents an action requested by the [`FixSessionCore`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FixSessionAction {
+    /// Send the encoded message to the counterparty.
+    Send(Vec<u8>),
+    /// Deliver an inbound application (or session reject) message.
+    Deliver(FixMessage),
+    /// Close the connection.
+    Disconnect,
+}
+
+/// The FIX session protocol state machine, independent of any transport.
+///
+/// All methods take the current time, so the core can be driven deterministically.
+#[derive(Debug)]
+pub struct FixSessionCore {
+    config: FixSessionConfig,
+    role: FixSessionRole,
+    state: FixSessionState,
+    store: Box<dyn FixSessionStore>,
+    last_sent: UnixNanos,
+    last_received: UnixNanos,
+    test_request: Option<(String, UnixNanos)>,
+    test_request_count: u64,
+    resend_until: Option<u64>,
+    queued: BTreeMap<u64, FixMessage>,
+}
+
+impl FixSessionCore {
+    /// Creates a new [`FixSessionCore`] in the `Disconnected` state.
+    #[must_use]
+    pub fn new(
+        config: FixSessionConfig,
+        role: FixSessionRole,
+        store: Box<dyn FixSessionStore>,
+    ) -> Self {
+        Self {
+            config,
+            role,
+            state: FixSessionState::Disconnected,
+            store,
+            last_sent: UnixNanos::default(),
+            last_received: UnixNanos::default(),
+            test_request: None,
+            test_request_count: 0,
+            resend_until: None,
+            queued: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the session configuration.
+    #[must_use]
+    pub const fn config(&self) -> &FixSessionConfig {
+        &self.config
+    }
+
+    /// Returns the current session state.
+    #[must_use]
+    pub const fn state(&self) -> FixSessionState {
+        self.state
+    }
+
+    /// Returns the sequence number of the next outbound message.
+    #[must_use]
+    pub fn next_sender_seq_num(&self) -> u64 {
+        self.store.next_sender_seq_num()
+    }
+
+    /// Returns the sequence number expected for the next inbound message.
+    #[must_use]
+    pub fn next_target_seq_num(&self) -> u64 {
+        self.store.next_target_seq_num()
+    }
+
+    /// Starts the session by building the Logon message (initiator only).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session is not an initiator, is not disconnected, or the
+    /// store cannot be reset.
+    pub fn logon(&mut self, now: UnixNanos) -> anyhow::Result<Vec<u8>> {
+        anyhow::ensure!(
+            self.role == FixSessionRole::Initiator,
+            "Only an initiator can start a logon"
+        );
+        anyhow::ensure!(
+            self.state == FixSessionState::Disconnected,
+            "Cannot logon in state {:?}",
+            self.state
+        );
+
+        if self.config.reset_on_logon {
+            self.store.reset()?;
+        }
+
+        let logon = self.logon_message(self.config.reset_on_logon);
+        self.state = FixSessionState::LogonSent;
+        self.last_received = now;
+        Ok(self.send_admin(&logon, now))
+    }
+
+    /// Builds a Logout message and waits for the counterparty's Logout.
+    pub fn logout(&mut self, text: Option<&str>, now: UnixNanos) -> Vec<u8> {
+        self.state = FixSessionState::LogoutSent;
+        let logout = AdminMessage::Logout {
+            text: text.map(ToString::to_string),
+        };
+        self.send_admin(&logout.to_message(), now)
+    }
+
+    /// Encodes an application message with the next sender sequence number, storing it for
+    /// resends.
+    ///
+    /// Returns the sequence number and encoded message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session is not active or the message cannot be stored.
+    pub fn send(&mut self, msg: &FixMessage, now: UnixNanos) -> anyhow::Result<(u64, Vec<u8>)> {
+        anyhow::ensure!(
+            self.state == FixSessionState::Active,
+            "Cannot send application message in state {:?}",
+            self.state
+        );
+        anyhow::ensure!(
+            !msg.is_admin(),
+            "Session level messages are sent by the session"
+        );
+
+        let seq_num = self.store.next_sender_seq_num();
+        let bytes = self.encode(msg, seq_num, now, None);
+        self.store.store_message(seq_num, &bytes)?;
+        self.store.set_next_sender_seq_num(seq_num + 1)?;
+        self.last_sent = now;
+
+        Ok((seq_num, bytes))
+    }
+
+    /// Resets the session state after the connection was lost.
+    pub fn on_disconnected(&mut self) {
+        self.state = FixSessionState::Disconnected;
+        self.test_request = None;
+        self.resend_until = None;
+        self.queued.clear();
+    }
+
+    /// Processes the heartbeat and timeout logic for the current time.
+    pub fn on_timer(&mut self, now: UnixNanos) -> Vec<FixSessionAction> {
+        let mut actions = Vec::new();
+        let heartbeat_ns = self.config.heartbeat_secs * NANOS_PER_SEC;
+        let timeout_ns = self.config.logon_timeout_secs * NANOS_PER_SEC;
+        let since_received = now.as_u64().saturating_sub(self.last_received.as_u64());
+        let since_sent = now.as_u64().saturating_sub(self.last_sent.as_u64());
+
+        match self.state {
+            FixSessionState::Disconnected => {}
+            FixSessionState::LogonSent => {
+                if since_received >= timeout_ns {
+                    tracing::error!("Timed out waiting for Logon response");
+                    self.disconnect(&mut actions);
+                }
+            }
+            FixSessionState::LogoutSent => {
+                if since_sent >= timeout_ns {
+                    tracing::warn!("Timed out waiting for Logout response");
+                    self.disconnect(&mut actions);
+                }
+            }
+            FixSessionState::Active => {
+                if let Some((test_req_id, sent_at)) = &self.test_request {
+                    if now.as_u64().saturating_sub(sent_at.as_u64()) >= heartbeat_ns {
+                        tracing::error!("No response to TestRequest {test_req_id}, disconnecting");
+                        self.disconnect(&mut actions);
+                        return actions;
+                    }
+                } else if since_received >= heartbeat_ns + heartbeat_ns / 5 {
+                    self.test_request_count += 1;
+                    let test_req_id = format!("TEST-{}", self.test_request_count);
+                    let msg = AdminMessage::TestRequest {
+                        test_req_id: test_req_id.clone(),
+                    };
+                    actions.push(FixSessionAction::Send(
+                        self.send_admin(&msg.to_message(), now),
+                    ));
+                    self.test_request = Some((test_req_id, now));
+                }
+
+                if now.as_u64().saturating_sub(self.last_sent.as_u64()) >= heartbeat_ns {
+                    let msg = AdminMessage::Heartbeat { test_req_id: None };
+                    actions.push(FixSessionAction::Send(
+                        self.send_admin(&msg.to_message(), now),
+                    ));
+                }
+            }
+        }
+
+        actions
+    }
+
+    /// Processes an inbound framed FIX message.
+    pub fn on_message(&mut self, data: &[u8], now: UnixNanos) -> Vec<FixSessionAction> {
+        let mut actions = Vec::new();
+
+        let msg = match FixMessage::parse(data) {
+            Ok(msg) => msg,
+            Err(e) => {
+                // Garbled messages are ignored as if never received
+                tracing::warn!("Discarding invalid FIX message: {e}");
+                return actions;
+            }
+        };
+        self.last_received = now;
+        self.process(msg, now, false, &mut actions);
+
+        // Process messages queued behind a gap which has now been filled
+        while let Some(entry) = self.queued.first_entry() {
+            let expected = self.store.next_target_seq_num();
+            if *entry.key() > expected || self.state == FixSessionState::Disconnected {
+                break;
+            }
+            let (seq_num, msg) = entry.remove_entry();
+            if seq_num == expected {
+                self.process(msg, now, true, &mut actions);
+            }
+        }
+
+        actions
+    }
+
+    fn process(
+        &mut self,
+        msg: FixMessage,
+        now: UnixNanos,
+        from_queue: bool,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        let Some(seq_num) = msg.msg_seq_num() else {
+            tracing::error!("Received message without MsgSeqNum, disconnecting");
+            self.logout_and_disconnect("MsgSeqNum missing", now, actions);
+            return;
+        };
+
+        if msg.get_field(fix_tag::SENDER_COMP_ID) != Some(self.config.target_comp_id.as_str())
+            || msg.get_field(fix_tag::TARGET_COMP_ID) != Some(self.config.sender_comp_id.as_str())
+        {
+            tracing::error!("Received message with unexpected CompIDs: {msg:?}");
+            self.logout_and_disconnect("CompID problem", now, actions);
+            return;
+        }
+
+        let admin = match AdminMessage::from_message(&msg) {
+            Ok(admin) => admin,
+            Err(e) => {
+                tracing::warn!("Rejecting invalid session message {seq_num}: {e}");
+                let reject = AdminMessage::Reject {
+                    ref_seq_num: seq_num,
+                    reason: Some(REJECT_REASON_REQUIRED_TAG_MISSING),
+                    text: Some(e.to_string()),
+                };
+                actions.push(FixSessionAction::Send(
+                    self.send_admin(&reject.to_message(), now),
+                ));
+                if seq_num == self.store.next_target_seq_num() {
+                    self.set_next_target(seq_num + 1);
+                }
+                return;
+            }
+        };
+
+        // The first message of a session must be a Logon
+        if matches!(
+            self.state,
+            FixSessionState::Disconnected | FixSessionState::LogonSent
+        ) && !matches!(
+            admin,
+            Some(AdminMessage::Logon { .. } | AdminMessage::Logout { .. })
+        ) {
+            tracing::error!("Received {:?} before Logon, disconnecting", msg.msg_type());
+            self.disconnect(actions);
+            return;
+        }
+
+        // A Logon requesting a reset restarts both sides at sequence number 1
+        if matches!(
+            admin,
+            Some(AdminMessage::Logon {
+                reset_seq_num: true,
+                ..
+            })
+        ) && self.role == FixSessionRole::Acceptor
+            && self.state == FixSessionState::Disconnected
+        {
+            if let Err(e) = self.store.reset() {
+                tracing::error!("Failed to reset session store: {e}");
+            }
+        }
+
+        // SequenceReset in reset mode ignores the MsgSeqNum
+        if let Some(AdminMessage::SequenceReset {
+            new_seq_no,
+            gap_fill: false,
+        }) = admin
+        {
+            self.on_sequence_reset(seq_num, new_seq_no, now, actions);
+            return;
+        }
+
+        let expected = self.store.next_target_seq_num();
+
+        if seq_num < expected {
+            if msg.is_poss_dup() {
+                tracing::debug!("Ignoring possible duplicate {seq_num} (expected {expected})");
+            } else {
+                let text =
+                    format!("MsgSeqNum too low, expecting {expected} but received {seq_num}");
+                tracing::error!("{text}");
+                self.logout_and_disconnect(&text, now, actions);
+            }
+            return;
+        }
+
+        if seq_num > expected {
+            match admin {
+                Some(AdminMessage::Logon { heart_bt_int, .. }) => {
+                    self.on_logon(heart_bt_int, false, now, actions);
+                    self.request_resend(expected, seq_num, now, actions);
+                }
+                Some(AdminMessage::Logout { .. }) => {
+                    self.on_logout(now, actions);
+                }
+                _ => {
+                    if let Some(AdminMessage::ResendRequest {
+                        begin_seq_no,
+                        end_seq_no,
+                    }) = admin
+                    {
+                        // Answer resend requests immediately to avoid a deadlock
+                        self.resend(begin_seq_no, end_seq_no, now, actions);
+                    }
+                    tracing::warn!("Sequence gap, expected {expected} but received {seq_num}");
+                    self.queued.insert(seq_num, msg);
+                    self.request_resend(expected, seq_num, now, actions);
+                }
+            }
+            return;
+        }
+
+        // In sequence
+        match admin {
+            Some(AdminMessage::SequenceReset {
+                new_seq_no,
+                gap_fill: true,
+            }) => {
+                if new_seq_no > seq_num {
+                    self.set_next_target(new_seq_no);
+                } else {
+                    self.set_next_target(seq_num + 1);
+                    self.reject_value(seq_num, "NewSeqNo must increase", now, actions);
+                }
+            }
+            _ => self.set_next_target(seq_num + 1),
+        }
+
+        match admin {
+            Some(AdminMessage::Logon {
+                heart_bt_int,
+                reset_seq_num,
+            }) => self.on_logon(heart_bt_int, reset_seq_num, now, actions),
+            Some(AdminMessage::Heartbeat { test_req_id }) => {
+                if self
+                    .test_request
+                    .as_ref()
+                    .is_some_and(|(expected_id, _)| test_req_id.as_ref() == Some(expected_id))
+                {
+                    self.test_request = None;
+                }
+            }
+            Some(AdminMessage::TestRequest { test_req_id }) => {
+                let heartbeat = AdminMessage::Heartbeat {
+                    test_req_id: Some(test_req_id),
+                };
+                actions.push(FixSessionAction::Send(
+                    self.send_admin(&heartbeat.to_message(), now),
+                ));
+            }
+            Some(AdminMessage::ResendRequest {
+                begin_seq_no,
+                end_seq_no,
+            }) => {
+                if !from_queue {
+                    self.resend(begin_seq_no, end_seq_no, now, actions);
+                }
+            }
+            Some(AdminMessage::Reject {
+                ref_seq_num, text, ..
+            }) => {
+                tracing::warn!("Session reject for {ref_seq_num}: {text:?}");
+                actions.push(FixSessionAction::Deliver(msg));
+            }
+            Some(AdminMessage::SequenceReset { .. }) => {}
+            Some(AdminMessage::Logout { text }) => {
+                tracing::info!("Received Logout: {text:?}");
+                self.on_logout(now, actions);
+            }
+            None => actions.push(FixSessionAction::Deliver(msg)),
+        }
+
+        let next_target = self.store.next_target_seq_num();
+        if self
+            .resend_until
+            .is_some_and(|resend_until| next_target > resend_until)
+        {
+            tracing::info!("Sequence gap filled");
+            self.resend_until = None;
+        }
+    }
+
+    fn on_logon(
+        &mut self,
+        heart_bt_int: u64,
+        reset_seq_num: bool,
+        now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        match (self.role, self.state) {
+            (FixSessionRole::Initiator, FixSessionState::LogonSent) => {
+                tracing::info!("Logon successful");
+                self.state = FixSessionState::Active;
+            }
+            (FixSessionRole::Acceptor, FixSessionState::Disconnected) => {
+                tracing::info!("Accepted Logon with heartbeat {heart_bt_int}s");
+                self.config.heartbeat_secs = heart_bt_int.max(1);
+                let logon = self.logon_message(reset_seq_num);
+                actions.push(FixSessionAction::Send(self.send_admin(&logon, now)));
+                self.state = FixSessionState::Active;
+            }
+            (_, state) => tracing::warn!("Ignoring unexpected Logon in state {state:?}"),
+        }
+    }
+
+    fn on_logout(&mut self, now: UnixNanos, actions: &mut Vec<FixSessionAction>) {
+        if self.state != FixSessionState::LogoutSent {
+            let logout = AdminMessage::Logout { text: None };
+            actions.push(FixSessionAction::Send(
+                self.send_admin(&logout.to_message(), now),
+            ));
+        }
+        self.disconnect(actions);
+    }
+
+    fn on_sequence_reset(
+        &mut self,
+        seq_num: u64,
+        new_seq_no: u64,
+        now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        let expected = self.store.next_target_seq_num();
+        if new_seq_no < expected {
+            self.reject_value(seq_num, "NewSeqNo lower than expected", now, actions);
+            return;
+        }
+
+        tracing::info!("SequenceReset to {new_seq_no} (expected {expected})");
+        self.set_next_target(new_seq_no);
+        self.queued.retain(|seq, _| *seq >= new_seq_no);
+        if self
+            .resend_until
+            .is_some_and(|resend_until| new_seq_no > resend_until)
+        {
+            self.resend_until = None;
+        }
+    }
+
+    fn request_resend(
+        &mut self,
+        begin_seq_no: u64,
+        received_seq_num: u64,
+        now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        if self.resend_until.is_some() {
+            return; // Already requested, the resend covers all later messages
+        }
+
+        let request = AdminMessage::ResendRequest {
+            begin_seq_no,
+            end_seq_no: 0,
+        };
+        actions.push(FixSessionAction::Send(
+            self.send_admin(&request.to_message(), now),
+        ));
+        self.resend_until = Some(received_seq_num);
+    }
+
+    fn resend(
+        &mut self,
+        begin_seq_no: u64,
+        end_seq_no: u64,
+        now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        let last_sent = self.store.next_sender_seq_num().saturating_sub(1);
+        let end = if end_seq_no == 0 || end_seq_no > last_sent {
+            last_sent
+        } else {
+            end_seq_no
+        };
+        tracing::info!("Resending messages {begin_seq_no}..={end}");
+
+        let stored = match self.store.get_messages(begin_seq_no, end) {
+            Ok(stored) => stored,
+            Err(e) => {
+                tracing::error!("Failed to load messages for resend: {e}");
+                Vec::new()
+            }
+        };
+
+        let mut next = begin_seq_no;
+        for (seq_num, bytes) in stored {
+            let msg = match FixMessage::parse(&bytes) {
+                Ok(msg) => msg,
+                Err(e) => {
+                    tracing::error!("Stored message {seq_num} is invalid, gap filling: {e}");
+                    continue;
+                }
+            };
+
+            if seq_num > next {
+                actions.push(FixSessionAction::Send(self.gap_fill(next, seq_num, now)));
+            }
+
+            let orig_sending_time = msg
+                .get_field(fix_tag::SENDING_TIME)
+                .map(ToString::to_string)
+                .unwrap_or_else(|| format_fix_timestamp(now));
+            let bytes = self.encode(&msg, seq_num, now, Some(&orig_sending_time));
+            actions.push(FixSessionAction::Send(bytes));
+            next = seq_num + 1;
+        }
+
+        if next <= end {
+            actions.push(FixSessionAction::Send(self.gap_fill(next, end + 1, now)));
+        }
+        self.last_sent = now;
+    }
+
+    fn gap_fill(&self, seq_num: u64, new_seq_no: u64, now: UnixNanos) -> Vec<u8> {
+        let gap_fill = AdminMessage::SequenceReset {
+            new_seq_no,
+            gap_fill: true,
+        };
+        let orig_sending_time = format_fix_timestamp(now);
+        self.encode(
+            &gap_fill.to_message(),
+            seq_num,
+            now,
+            Some(&orig_sending_time),
+        )
+    }
+
+    fn reject_value(
+        &mut self,
+        ref_seq_num: u64,
+        text: &str,
+        now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        let reject = AdminMessage::Reject {
+            ref_seq_num,
+            reason: Some(REJECT_REASON_VALUE_INCORRECT),
+            text: Some(text.to_string()),
+        };
+        actions.push(FixSessionAction::Send(
+            self.send_admin(&reject.to_message(), now),
+        ));
+    }
+
+    fn logout_and_disconnect(
+        &mut self,
+        text: &str,
+        now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        let logout = AdminMessage::Logout {
+            text: Some(text.to_string()),
+        };
+        actions.push(FixSessionAction::Send(
+            self.send_admin(&logout.to_message(), now),
+        ));
+        self.disconnect(actions);
+    }
+
+    fn disconnect(&mut self, actions: &mut Vec<FixSessionAction>) {
+        self.on_disconnected();
+        actions.push(FixSessionAction::Disconnect);
+    }
+
+    fn logon_message(&self, reset_seq_num: bool) -> FixMessage {
+        let mut logon = AdminMessage::Logon {
+            heart_bt_int: self.config.heartbeat_secs,
+            reset_seq_num,
+        }
+        .to_message();
+        for (tag, value) in &self.config.logon_fields {
+            logon.add_field(*tag, value);
+        }
+        logon
+    }
+
+    /// Encodes and sends a session level message, which is not stored (resends gap fill it).
+    fn send_admin(&mut self, msg: &FixMessage, now: UnixNanos) -> Vec<u8> {
+        let seq_num = self.store.next_sender_seq_num();
+        let bytes = self.encode(msg, seq_num, now, None);
+        if let Err(e) = self.store.set_next_sender_seq_num(seq_num + 1) {
+            tracing::error!("Failed to persist sender sequence number: {e}");
+        }
+        self.last_sent = now;
+        bytes
+    }
+
+    fn set_next_target(&mut self, seq_num: u64) {
+        if let Err(e) = self.store.set_next_target_seq_num(seq_num) {
+            tracing::error!("Failed to persist target sequence number: {e}");
+        }
+    }
+
+    fn encode(
+        &self,
+        msg: &FixMessage,
+        seq_num: u64,
+        now: UnixNanos,
+        orig_sending_time: Option<&str>,
+    ) -> Vec<u8> {
+        msg.encode(&FixHeader {
+            begin_string: &self.config.begin_string,
+            sender_comp_id: &self.config.sender_comp_id,
+            target_comp_id: &self.config.target_comp_id,
+            msg_seq_num: seq_num,
+            sending_time: now,
+            orig_sending_time,
+        })
+    }
+}
+
+/// A FIX initiator session running over a [`SocketClient`].
+///
+/// Inbound application messages (and session rejects) are delivered on the receiver returned
+/// by [`FixSession::connect`]. Heartbeats, test requests, resends and sequence gaps are
+/// handled by the session. When the socket reconnects, the session logs on again with its
+/// persisted sequence numbers.
+pub struct FixSession {
+    core: Arc<Mutex<FixSessionCore>>,
+    socket: Arc<SocketClient>,
+    task: JoinHandle<()>,
+}
+
+impl std::fmt::Debug for FixSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(FixSession))
+            .field("state", &self.state())
+            .finish_non_exhaustive()
+    }
+}
+
+impl FixSession {
+    /// Connects to the counterparty and logs on.
+    ///
+    /// The socket `suffix` and `heartbeat` are managed by the session and overridden.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection fails or the Logon is not answered in time.
+    pub async fn connect(
+        mut socket_config: SocketConfig,
+        config: FixSessionConfig,
+        store: Box<dyn FixSessionStore>,
+    ) -> anyhow::Result<(Self, UnboundedReceiver<FixMessage>)> {
+        socket_config.suffix = Vec::new(); // Messages are framed by checksum
+        socket_config.heartbeat = None; // Using FIX heartbeats
+
+        let logon_timeout = Duration::from_secs(config.logon_timeout_secs);
+        let core = Arc::new(Mutex::new(FixSessionCore::new(
+            config,
+            FixSessionRole::Initiator,
+            store,
+        )));
+
+        let (inbound_tx, inbound_rx) = unbounded_channel::<Vec<u8>>();
+        let handler: Arc<TcpMessageHandler> = Arc::new(move |data: &[u8]| {
+            if let Err(e) = inbound_tx.send(data.to_vec()) {
+                tracing::error!("Failed to forward FIX message: {e}");
+            }
+        });
+
+        let socket = SocketClient::connect(
+            socket_config,
+            Some(handler),
+            #[cfg(feature = "python")]
+            None,
+            #[cfg(feature = "python")]
+            None,
+            #[cfg(feature = "python")]
+            None,
+        )
+        .await?;
+        let socket = Arc::new(socket);
+
+        let logon = lock(&core).logon(get_atomic_clock_realtime().get_time_ns())?;
+        socket
+            .send_bytes(logon)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to send Logon: {e}"))?;
+
+        let (app_tx, app_rx) = unbounded_channel();
+        let task = tokio::spawn(Self::run(core.clone(), socket.clone(), inbound_rx, app_tx));
+        let session = Self { core, socket, task };
+
+        let logged_on = tokio::time::timeout(logon_timeout, async {
+            while session.state() == FixSessionState::LogonSent {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await;
+
+        if logged_on.is_err() || !session.is_logged_on() {
+            session.socket.close().await;
+            anyhow::bail!("Logon failed (state {:?})", session.state());
+        }
+
+        Ok((session, app_rx))
+    }
+
+    /// Returns the current session state.
+    #[must_use]
+    pub fn state(&self) -> FixSessionState {
+        lock(&self.core).state()
+    }
+
+    /// Returns whether the session is logged on.
+    #[must_use]
+    pub fn is_logged_on(&self) -> bool {
+        self.state() == FixSessionState::Active
+    }
+
+    /// Returns the sequence number of the next outbound message.
+    #[must_use]
+    pub fn next_sender_seq_num(&self) -> u64 {
+        lock(&self.core).next_sender_seq_num()
+    }
+
+    /// Returns the sequence number expected for the next inbound message.
+    #[must_use]
+    pub fn next_target_seq_num(&self) -> u64 {
+        lock(&self.core).next_target_seq_num()
+    }
+
+    /// Sends an application message, returning its sequence number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session is not logged on or sending fails.
+    pub async fn send(&self, msg: &FixMessage) -> anyhow::Result<u64> {
+        let now = get_atomic_clock_realtime().get_time_ns();
+        let (seq_num, bytes) = lock(&self.core).send(msg, now)?;
+        self.socket
+            .send_bytes(bytes)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to send message {seq_num}: {e}"))?;
+        Ok(seq_num)
+    }
+
+    /// Logs out, waits for the counterparty's Logout (up to the logon timeout), and closes
+    /// the connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Logout cannot be sent.
+    pub async fn logout(&self, text: Option<&str>) -> anyhow::Result<()> {
+        let now = get_atomic_clock_realtime().get_time_ns();
+        let (logout, timeout_secs) = {
+            let mut core = lock(&self.core);
+            (core.logout(text, now), core.config().logon_timeout_secs)
+        };
+        self.socket
+            .send_bytes(logout)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to send Logout: {e}"))?;
+
+        let _ = tokio::time::timeout(Duration::from_secs(timeout_secs), async {
+            while self.state() != FixSessionState::Disconnected {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await;
+
+        lock(&self.core).on_disconnected();
+        self.socket.close().await;
+        self.task.abort();
+        Ok(())
+    }
+
+    async fn run(
+        core: Arc<Mutex<FixSessionCore>>,
+        socket: Arc<SocketClient>,
+        mut inbound_rx: UnboundedReceiver<Vec<u8>>,
+        app_tx: tokio::sync::mpsc::UnboundedSender<FixMessage>,
+    ) {
+        let mut interval = tokio::time::interval(Duration::from_millis(100));
+        let mut reconnecting = false;
+
+        loop {
+            let actions = tokio::select! {
+                Some(data) = inbound_rx.recv() => {
+                    let now = get_atomic_clock_realtime().get_time_ns();
+                    lock(&core).on_message(&data, now)
+                }
+                _ = interval.tick() => {
+                    if socket.is_closed() {
+                        break;
+                    }
+                    if socket.is_reconnecting() {
+                        reconnecting = true;
+                        continue;
+                    }
+
+                    let now = get_atomic_clock_realtime().get_time_ns();
+                    let mut core = lock(&core);
+                    if reconnecting && socket.is_active() {
+                        reconnecting = false;
+                        tracing::info!("Reconnected, logging on");
+                        core.on_disconnected();
+                        match core.logon(now) {
+                            Ok(logon) => vec![FixSessionAction::Send(logon)],
+                            Err(e) => {
+                                tracing::error!("Failed to logon after reconnect: {e}");
+                                Vec::new()
+                            }
+                        }
+                    } else {
+                        core.on_timer(now)
+                    }
+                }
+            };
+
+            for action in actions {
+                match action {
+                    FixSessionAction::Send(bytes) => {
+                        if let Err(e) = socket.send_bytes(bytes).await {
+                            tracing::error!("Failed to send FIX message: {e}");
+                        }
+                    }
+                    FixSessionAction::Deliver(msg) => {
+                        if let Err(e) = app_tx.send(msg) {
+                            tracing::error!("Failed to deliver FIX message: {e}");
+                        }
+                    }
+                    FixSessionAction::Disconnect => {
+                        tracing::info!("Session ended, closing connection");
+                        socket.close().await;
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Drop for FixSession {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn lock(core: &Mutex<FixSessionCore>) -> MutexGuard<'_, FixSessionCore> {
+    // The core holds no invariants which a panic could break mid-update
+    core.lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fix::{message::fix_msg_type, store::MemorySessionStore};
+
+    const SEC: u64 = NANOS_PER_SEC;
+
+    fn initiator() -> FixSessionCore {
+        let mut config = FixSessionConfig::new("CLIENT", "VENUE");
+        config.heartbeat_secs = 10;
+        FixSessionCore::new(
+            config,
+            FixSessionRole::Initiator,
+            Box::new(MemorySessionStore::new()),
+        )
+    }
+
+    fn acceptor() -> FixSessionCore {
+        FixSessionCore::new(
+            FixSessionConfig::new("VENUE", "CLIENT"),
+            FixSessionRole::Acceptor,
+            Box::new(MemorySessionStore::new()),
+        )
+    }
+
+    fn ts(secs: u64) -> UnixNanos {
+        UnixNanos::from(1_700_000_000 * SEC + secs * SEC)
+    }
+
+    /// Feeds every `Send` action into `to`, returning the actions `to` produced.
+    fn pump(
+        actions: Vec<FixSessionAction>,
+        to: &mut FixSessionCore,
+        now: UnixNanos,
+    ) -> Vec<FixSessionAction> {
+        actions
+            .into_iter()
+            .filter_map(|action| match action {
+                FixSessionAction::Send(bytes) => Some(to.on_message(&bytes, now)),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    fn sent(actions: &[FixSessionAction]) -> Vec<FixMessage> {
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                FixSessionAction::Send(bytes) => Some(FixMessage::parse(bytes).unwrap()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn delivered(actions: &[FixSessionAction]) -> Vec<&FixMessage> {
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                FixSessionAction::Deliver(msg) => Some(msg),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn logged_on_pair() -> (FixSessionCore, FixSessionCore) {
+        let mut client = initiator();
+        let mut venue = acceptor();
+        let logon = client.logon(ts(0)).unwrap();
+        let response = venue.on_message(&logon, ts(0));
+        pump(response, &mut client, ts(0));
+        (client, venue)
+    }
+
+    fn order(id: &str) -> FixMessage {
+        FixMessage::new("D").with_field(11, id)
+    }
+
+    #[rstest]
+    fn test_logon_handshake() {
+        let (client, venue) = logged_on_pair();
+
+        assert_eq!(client.state(), FixSessionState::Active);
+        assert_eq!(venue.state(), FixSessionState::Active);
+        assert_eq!(venue.config().heartbeat_secs, 10); // Adopted from the initiator
+        assert_eq!(client.next_sender_seq_num(), 2);
+        assert_eq!(client.next_target_seq_num(), 2);
+    }
+
+    #[rstest]
+    fn test_logon_reset_and_fields() {
+        let mut client = initiator();
+        client.config.reset_on_logon = true;
+        client.config.logon_fields = vec![(fix_tag::USERNAME, "user".to_string())];
+
+        let logon = FixMessage::parse(&client.logon(ts(0)).unwrap()).unwrap();
+
+        assert_eq!(logon.get_field(fix_tag::RESET_SEQ_NUM_FLAG), Some("Y"));
+        assert_eq!(logon.get_field(fix_tag::USERNAME), Some("user"));
+    }
+
+    #[rstest]
+    fn test_application_messages_delivered() {
+        let (mut client, mut venue) = logged_on_pair();
+
+        let (seq_num, bytes) = client.send(&order("O-1"), ts(1)).unwrap();
+        let actions = venue.on_message(&bytes, ts(1));
+
+        assert_eq!(seq_num, 2);
+        assert_eq!(delivered(&actions)[0].get_field(11), Some("O-1"));
+        assert!(acceptor().send(&order("O-2"), ts(1)).is_err()); // Not logged on
+    }
+
+    #[rstest]
+    fn test_message_before_logon_disconnects() {
+        let (mut client, _) = logged_on_pair();
+        let (_, bytes) = client.send(&order("O-1"), ts(1)).unwrap();
+
+        let actions = acceptor().on_message(&bytes, ts(1));
+
+        assert_eq!(actions, vec![FixSessionAction::Disconnect]);
+    }
+
+    #[rstest]
+    fn test_heartbeat_and_test_request() {
+        let (mut client, mut venue) = logged_on_pair();
+
+        // Quiet for a heartbeat interval: heartbeat sent
+        let actions = client.on_timer(ts(10));
+        assert_eq!(sent(&actions)[0].msg_type(), Some(fix_msg_type::HEARTBEAT));
+        assert!(pump(actions, &mut venue, ts(10)).is_empty());
+
+        // Nothing received beyond the grace period: test request sent
+        let actions = client.on_timer(ts(12));
+        let test_request = &sent(&actions)[0];
+        assert_eq!(test_request.msg_type(), Some(fix_msg_type::TEST_REQUEST));
+
+        // Venue answers with a heartbeat echoing the TestReqID
+        let replies = pump(actions, &mut venue, ts(12));
+        let heartbeat = &sent(&replies)[0];
+        assert_eq!(
+            heartbeat.get_field(fix_tag::TEST_REQ_ID),
+            test_request.get_field(fix_tag::TEST_REQ_ID)
+        );
+        pump(replies, &mut client, ts(13));
+        assert!(client.test_request.is_none());
+    }
+
+    #[rstest]
+    fn test_unanswered_test_request_disconnects() {
+        let (mut client, _) = logged_on_pair();
+        client.on_timer(ts(12)); // Sends test request
+
+        let actions = client.on_timer(ts(22));
+
+        assert!(actions.contains(&FixSessionAction::Disconnect));
+        assert_eq!(client.state(), FixSessionState::Disconnected);
+    }
+
+    #[rstest]
+    fn test_logon_timeout() {
+        let mut client = initiator();
+        client.logon(ts(0)).unwrap();
+
+        assert!(client.on_timer(ts(5)).is_empty());
+        assert_eq!(client.on_timer(ts(10)), vec![FixSessionAction::Disconnect]);
+    }
+
+    #[rstest]
+    fn test_gap_detection_resend_and_gap_fill() {
+        let (mut client, mut venue) = logged_on_pair();
+
+        // Client sends 2, 3 and 4 but the venue only receives 4
+        let (_, _) = client.send(&order("O-2"), ts(1)).unwrap();
+        client.on_timer(ts(11)); // Heartbeat 3 (admin, so gap filled on resend)
+        let (_, msg4) = client.send(&order("O-4"), ts(12)).unwrap();
+
+        let actions = venue.on_message(&msg4, ts(12));
+        assert!(delivered(&actions).is_empty());
+        let resend_request = &sent(&actions)[0];
+        assert_eq!(
+            AdminMessage::from_message(resend_request).unwrap(),
+            Some(AdminMessage::ResendRequest {
+                begin_seq_no: 2,
+                end_seq_no: 0
+            })
+        );
+
+        // Client resends 2 (PossDup), gap fills 3 and resends 4
+        let resent = pump(actions, &mut client, ts(13));
+        let resent_msgs = sent(&resent);
+        assert_eq!(resent_msgs.len(), 3);
+        assert!(resent_msgs[0].is_poss_dup());
+        assert_eq!(resent_msgs[0].get_field(11), Some("O-2"));
+        assert_eq!(
+            AdminMessage::from_message(&resent_msgs[1]).unwrap(),
+            Some(AdminMessage::SequenceReset {
+                new_seq_no: 4,
+                gap_fill: true
+            })
+        );
+        assert_eq!(resent_msgs[2].get_field(11), Some("O-4"));
+
+        // Venue delivers 2 and 4 exactly once, in order
+        let actions = pump(resent, &mut venue, ts(13));
+        let ids: Vec<_> = delivered(&actions)
+            .iter()
+            .map(|msg| msg.get_field(11).unwrap().to_string())
+            .collect();
+        assert_eq!(ids, vec!["O-2", "O-4"]);
+        assert_eq!(venue.next_target_seq_num(), 5);
+        assert!(venue.resend_until.is_none());
+        assert!(venue.queued.is_empty());
+    }
+
+    #[rstest]
+    fn test_seq_num_too_low() {
+        let (mut client, mut venue) = logged_on_pair();
+        let (_, bytes) = client.send(&order("O-1"), ts(1)).unwrap();
+        venue.on_message(&bytes, ts(1));
+
+        // Replayed with PossDupFlag: ignored
+        let poss_dup = client.encode(&order("O-1"), 2, ts(2), Some("20231114-22:13:21.000"));
+        assert!(venue.on_message(&poss_dup, ts(2)).is_empty());
+
+        // Replayed without PossDupFlag: fatal
+        let actions = venue.on_message(&bytes, ts(2));
+        let logout = &sent(&actions)[0];
+        assert_eq!(logout.msg_type(), Some(fix_msg_type::LOGOUT));
+        assert!(logout.get_field(fix_tag::TEXT).unwrap().contains("too low"));
+        assert_eq!(actions.last(), Some(&FixSessionAction::Disconnect));
+    }
+
+    #[rstest]
+    fn test_sequence_reset_mode() {
+        let (client, mut venue) = logged_on_pair();
+        let reset = AdminMessage::SequenceReset {
+            new_seq_no: 50,
+            gap_fill: false,
+        };
+
+        let bytes = client.encode(&reset.to_message(), 99, ts(1), None);
+        venue.on_message(&bytes, ts(1));
+        assert_eq!(venue.next_target_seq_num(), 50);
+
+        // Resetting backwards is rejected
+        let reset = AdminMessage::SequenceReset {
+            new_seq_no: 10,
+            gap_fill: false,
+        };
+        let bytes = client.encode(&reset.to_message(), 50, ts(2), None);
+        let actions = venue.on_message(&bytes, ts(2));
+        assert_eq!(sent(&actions)[0].msg_type(), Some(fix_msg_type::REJECT));
+        assert_eq!(venue.next_target_seq_num(), 50);
+    }
+
+    #[rstest]
+    fn test_logout_handshake() {
+        let (mut client, mut venue) = logged_on_pair();
+
+        let logout = client.logout(Some("bye"), ts(1));
+        let response = venue.on_message(&logout, ts(1));
+        assert_eq!(venue.state(), FixSessionState::Disconnected);
+        assert_eq!(sent(&response)[0].msg_type(), Some(fix_msg_type::LOGOUT));
+
+        let actions = pump(response, &mut client, ts(1));
+        assert_eq!(actions, vec![FixSessionAction::Disconnect]);
+        assert_eq!(client.state(), FixSessionState::Disconnected);
+    }
+
+    #[rstest]
+    fn test_comp_id_mismatch_disconnects() {
+        let (_, mut venue) = logged_on_pair();
+        let mut other = FixSessionCore::new(
+            FixSessionConfig::new("OTHER", "VENUE"),
+            FixSessionRole::Initiator,
+            Box::new(MemorySessionStore::new()),
+        );
+        let bytes = other.encode(&order("O-1"), 2, ts(1), None);
+        other.state = FixSessionState::Active;
+
+        let actions = venue.on_message(&bytes, ts(1));
+
+        assert_eq!(actions.last(), Some(&FixSessionAction::Disconnect));
+    }
+}
+
+#[cfg(test)]
+#[cfg(target_os = "linux")] // Only run network tests on Linux (CI stability)
+mod socket_tests {
+    use std::sync::Mutex as StdMutex;
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+    use tokio_tungstenite::tungstenite::stream::Mode;
+
+    use super::*;
+    use crate::fix::{process_fix_buffer, store::MemorySessionStore};
+
+    /// Runs an in-process acceptor for a single connection, echoing application messages
+    /// back with their `ClOrdID` as an execution report.
+    async fn spawn_acceptor() -> (u16, JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let task = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut core = FixSessionCore::new(
+                FixSessionConfig::new("VENUE", "CLIENT"),
+                FixSessionRole::Acceptor,
+                Box::new(MemorySessionStore::new()),
+            );
+            let frames = Arc::new(StdMutex::new(Vec::new()));
+            let frames_clone = frames.clone();
+            let handler: Arc<TcpMessageHandler> = Arc::new(move |data: &[u8]| {
+                frames_clone.lock().unwrap().push(data.to_vec());
+            });
+
+            let mut buf = Vec::new();
+            loop {
+                let mut chunk = [0_u8; 4096];
+                let n = stream.read(&mut chunk).await.unwrap_or(0);
+                if n == 0 {
+                    break;
+                }
+                buf.extend_from_slice(&chunk[..n]);
+                process_fix_buffer(&mut buf, &handler);
+
+                let pending: Vec<Vec<u8>> = frames.lock().unwrap().drain(..).collect();
+                for frame in pending {
+                    let now = get_atomic_clock_realtime().get_time_ns();
+                    let mut actions = core.on_message(&frame, now);
+                    let mut replies = Vec::new();
+                    for action in &actions {
+                        if let FixSessionAction::Deliver(msg) = action {
+                            let report = FixMessage::new("8")
+                                .with_field(11, msg.get_field(11).unwrap_or_default());
+                            replies
+                                .push(FixSessionAction::Send(core.send(&report, now).unwrap().1));
+                        }
+                    }
+                    actions.extend(replies);
+
+                    for action in actions {
+                        match action {
+                            FixSessionAction::Send(bytes) => {
+                                stream.write_all(&bytes).await.unwrap()
+                            }
+                            FixSessionAction::Disconnect => return,
+                            FixSessionAction::Deliver(_) => {}
+                        }
+                    }
+                }
+            }
+        });
+
+        (port, task)
+    }
+
+    #[tokio::test]
+    async fn test_session_against_in_process_acceptor() {
+        let (port, acceptor) = spawn_acceptor().await;
+        let socket_config = SocketConfig {
+            url: format!("127.0.0.1:{port}"),
+            mode: Mode::Plain,
+            suffix: vec![],
+            #[cfg(feature = "python")]
+            py_handler: None,
+            heartbeat: None,
+            reconnect_timeout_ms: None,
+            reconnect_delay_initial_ms: None,
+            reconnect_delay_max_ms: None,
+            reconnect_backoff_factor: None,
+            reconnect_jitter_ms: None,
+            certs_dir: None,
+        };
+        let mut config = FixSessionConfig::new("CLIENT", "VENUE");
+        config.reset_on_logon = true;
+
+        let (session, mut rx) =
+            FixSession::connect(socket_config, config, Box::new(MemorySessionStore::new()))
+                .await
+                .unwrap();
+        assert!(session.is_logged_on());
+
+        let seq_num = session
+            .send(&FixMessage::new("D").with_field(11, "O-1"))
+            .await
+            .unwrap();
+        assert_eq!(seq_num, 2);
+
+        let report = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.msg_type(), Some("8"));
+        assert_eq!(report.get_field(11), Some("O-1"));
+
+        session.logout(None).await.unwrap();
+        assert_eq!(session.state(), FixSessionState::Disconnected);
+        tokio::time::timeout(Duration::from_secs(2), acceptor)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+}
@@ -0,0 +1,317 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Persistence of FIX session state.
+//!
+//! A [`FixSessionStore`] keeps the next outbound (sender) and expected inbound (target)
+//! sequence numbers, and the encoded application messages sent, so that they can be
+//! resent when the counterparty issues a ResendRequest.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+/// Provides persistence of FIX session sequence numbers and sent messages.
+pub trait FixSessionStore: Debug + Send {
+    /// Returns the sequence number of the next outbound message.
+    fn next_sender_seq_num(&self) -> u64;
+
+    /// Returns the sequence number expected for the next inbound message.
+    fn next_target_seq_num(&self) -> u64;
+
+    /// Sets the sequence number of the next outbound message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequence number cannot be persisted.
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()>;
+
+    /// Sets the sequence number expected for the next inbound message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequence number cannot be persisted.
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()>;
+
+    /// Stores an encoded outbound message sent with `seq_num`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be persisted.
+    fn store_message(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()>;
+
+    /// Returns the stored outbound messages in the inclusive range `begin..=end`, ordered
+    /// by sequence number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the messages cannot be loaded.
+    fn get_messages(&self, begin: u64, end: u64) -> anyhow::Result<Vec<(u64, Vec<u8>)>>;
+
+    /// Resets both sequence numbers to 1 and discards all stored messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reset cannot be persisted.
+    fn reset(&mut self) -> anyhow::Result<()>;
+}
+
+/// An in-memory [`FixSessionStore`], session state is lost when the process exits.
+#[derive(Debug)]
+pub struct MemorySessionStore {
+    next_sender_seq_num: u64,
+    next_target_seq_num: u64,
+    messages: BTreeMap<u64, Vec<u8>>,
+}
+
+impl MemorySessionStore {
+    /// Creates a new [`MemorySessionStore`] with both sequence numbers at 1.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            next_sender_seq_num: 1,
+            next_target_seq_num: 1,
+            messages: BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for MemorySessionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FixSessionStore for MemorySessionStore {
+    fn next_sender_seq_num(&self) -> u64 {
+        self.next_sender_seq_num
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        self.next_target_seq_num
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.next_sender_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.next_target_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn store_message(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()> {
+        self.messages.insert(seq_num, message.to_vec());
+        Ok(())
+    }
+
+    fn get_messages(&self, begin: u64, end: u64) -> anyhow::Result<Vec<(u64, Vec<u8>)>> {
+        Ok(self
+            .messages
+            .range(begin..=end)
+            .map(|(seq, msg)| (*seq, msg.clone()))
+            .collect())
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        *self = Self::new();
+        Ok(())
+    }
+}
+
+/// A file backed [`FixSessionStore`] which survives process restarts.
+///
+/// For a session ID the store keeps two files in its directory:
+/// - `{session_id}.seqnums` with the next sender and target sequence numbers.
+/// - `{session_id}.body` with one stored message per line, as `{seq_num}\t{message}`
+///   (FIX messages never contain a newline).
+///
+/// Messages are also cached in memory, so resends do not read the file.
+#[derive(Debug)]
+pub struct FileSessionStore {
+    seqnums_path: PathBuf,
+    body_path: PathBuf,
+    inner: MemorySessionStore,
+}
+
+impl FileSessionStore {
+    /// Opens (or creates) the store for `session_id` in the directory `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created or existing files are corrupt.
+    pub fn new<P: AsRef<Path>>(dir: P, session_id: &str) -> anyhow::Result<Self> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let mut store = Self {
+            seqnums_path: dir.join(format!("{session_id}.seqnums")),
+            body_path: dir.join(format!("{session_id}.body")),
+            inner: MemorySessionStore::new(),
+        };
+        store.load()?;
+
+        Ok(store)
+    }
+
+    fn load(&mut self) -> anyhow::Result<()> {
+        if self.seqnums_path.exists() {
+            let text = fs::read_to_string(&self.seqnums_path)?;
+            let (sender, target) = text
+                .trim()
+                .split_once(':')
+                .ok_or_else(|| anyhow::anyhow!("Invalid seqnums file {:?}", self.seqnums_path))?;
+            self.inner.next_sender_seq_num = sender.trim().parse()?;
+            self.inner.next_target_seq_num = target.trim().parse()?;
+        }
+
+        if self.body_path.exists() {
+            let reader = BufReader::new(File::open(&self.body_path)?);
+            for line in reader.lines() {
+                let line = line?;
+                let Some((seq_num, message)) = line.split_once('\t') else {
+                    continue;
+                };
+                self.inner
+                    .messages
+                    .insert(seq_num.parse()?, message.as_bytes().to_vec());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_seqnums(&self) -> anyhow::Result<()> {
+        // Write then rename, so a crash never leaves a truncated file
+        let tmp_path = self.seqnums_path.with_extension("seqnums.tmp");
+        fs::write(
+            &tmp_path,
+            format!(
+                "{}:{}",
+                self.inner.next_sender_seq_num, self.inner.next_target_seq_num
+            ),
+        )?;
+        fs::rename(tmp_path, &self.seqnums_path)?;
+        Ok(())
+    }
+}
+
+impl FixSessionStore for FileSessionStore {
+    fn next_sender_seq_num(&self) -> u64 {
+        self.inner.next_sender_seq_num
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        self.inner.next_target_seq_num
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.inner.next_sender_seq_num = seq_num;
+        self.write_seqnums()
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.inner.next_target_seq_num = seq_num;
+        self.write_seqnums()
+    }
+
+    fn store_message(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.body_path)?;
+        file.write_all(seq_num.to_string().as_bytes())?;
+        file.write_all(b"\t")?;
+        file.write_all(message)?;
+        file.write_all(b"\n")?;
+
+        self.inner.store_message(seq_num, message)
+    }
+
+    fn get_messages(&self, begin: u64, end: u64) -> anyhow::Result<Vec<(u64, Vec<u8>)>> {
+        self.inner.get_messages(begin, end)
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        self.inner.reset()?;
+        if self.body_path.exists() {
+            fs::remove_file(&self.body_path)?;
+        }
+        self.write_seqnums()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn exercise(store: &mut dyn FixSessionStore) {
+        store.set_next_sender_seq_num(4).unwrap();
+        store.set_next_target_seq_num(9).unwrap();
+        store.store_message(2, b"msg-2").unwrap();
+        store.store_message(3, b"msg-3").unwrap();
+    }
+
+    #[rstest]
+    fn test_memory_store() {
+        let mut store = MemorySessionStore::new();
+        exercise(&mut store);
+
+        assert_eq!(store.next_sender_seq_num(), 4);
+        assert_eq!(store.next_target_seq_num(), 9);
+        assert_eq!(
+            store.get_messages(1, 2).unwrap(),
+            vec![(2, b"msg-2".to_vec())]
+        );
+
+        store.reset().unwrap();
+        assert_eq!(store.next_sender_seq_num(), 1);
+        assert!(store.get_messages(1, u64::MAX).unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_file_store_persists_across_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let mut store = FileSessionStore::new(dir.path(), "CLIENT-VENUE").unwrap();
+            exercise(&mut store);
+        }
+
+        let mut store = FileSessionStore::new(dir.path(), "CLIENT-VENUE").unwrap();
+
+        assert_eq!(store.next_sender_seq_num(), 4);
+        assert_eq!(store.next_target_seq_num(), 9);
+        assert_eq!(
+            store.get_messages(1, u64::MAX).unwrap(),
+            vec![(2, b"msg-2".to_vec()), (3, b"msg-3".to_vec())]
+        );
+
+        store.reset().unwrap();
+        let store = FileSessionStore::new(dir.path(), "CLIENT-VENUE").unwrap();
+        assert_eq!(store.next_sender_seq_num(), 1);
+        assert_eq!(store.next_target_seq_num(), 1);
+        assert!(store.get_messages(1, u64::MAX).unwrap().is_empty());
+    }
+}
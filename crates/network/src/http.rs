@@ -15,7 +15,16 @@
 
 //! A high-performance HTTP client implementation.
 
-use std::{collections::HashMap, hash::Hash, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
 
 use bytes::Bytes;
 use http::{HeaderValue, StatusCode, status::InvalidStatusCode};
@@ -24,7 +33,14 @@ use reqwest::{
     header::{HeaderMap, HeaderName},
 };
 
-use crate::ratelimiter::{RateLimiter, clock::MonotonicClock, quota::Quota};
+use nautilus_core::time::get_atomic_clock_realtime;
+
+use crate::{
+    backoff::ExponentialBackoff,
+    ratelimiter::{RateLimiter, clock::MonotonicClock, quota::Quota},
+    recording::Recorder,
+    signing::{RequestSigner, SignableRequest},
+};
 
 /// Represents a HTTP status code.
 ///
@@ -180,10 +196,91 @@ impl From<String> for HttpClientError {
     }
 }
 
+/// Configuration for automatic retries of rate limited (429) and server error (5xx) responses.
+///
+/// Retries are delayed with an [`ExponentialBackoff`], or for longer if the response
+/// carries a `Retry-After` header.
+#[derive(Clone, Debug)]
+pub struct HttpRetryConfig {
+    /// The maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// The initial backoff delay (milliseconds).
+    pub delay_initial_ms: u64,
+    /// The maximum backoff delay (milliseconds).
+    pub delay_max_ms: u64,
+    /// The factor to multiply the delay by on each retry.
+    pub backoff_factor: f64,
+    /// The maximum random jitter added to each delay (milliseconds).
+    pub jitter_ms: u64,
+    /// If server error (5xx) responses are retried.
+    pub retry_server_errors: bool,
+    /// If non-idempotent requests (POST, PATCH) are retried on server errors, which may
+    /// repeat side effects such as order submission. Rate limited requests are always retried
+    /// as the server did not process them.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for HttpRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            delay_initial_ms: 500,
+            delay_max_ms: 10_000,
+            backoff_factor: 2.0,
+            jitter_ms: 250,
+            retry_server_errors: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+/// A snapshot of the request, retry and throttling metrics of an [`HttpClient`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HttpClientMetrics {
+    /// The number of requests sent, including retries.
+    pub requests: u64,
+    /// The number of retries.
+    pub retries: u64,
+    /// The number of requests delayed by the local rate limiter.
+    pub throttled: u64,
+    /// The total time requests were delayed by the local rate limiter (milliseconds).
+    pub throttled_ms: u64,
+    /// The number of rate limited (429) or banned (418) responses.
+    pub rate_limited: u64,
+    /// The number of server error (5xx) responses.
+    pub server_errors: u64,
+}
+
+/// Atomic counters backing [`HttpClientMetrics`], shared between clones of a client.
+#[derive(Debug, Default)]
+pub(crate) struct HttpClientCounters {
+    requests: AtomicU64,
+    retries: AtomicU64,
+    throttled: AtomicU64,
+    throttled_ms: AtomicU64,
+    rate_limited: AtomicU64,
+    server_errors: AtomicU64,
+}
+
+impl HttpClientCounters {
+    fn snapshot(&self) -> HttpClientMetrics {
+        HttpClientMetrics {
+            requests: self.requests.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            throttled: self.throttled.load(Ordering::Relaxed),
+            throttled_ms: self.throttled_ms.load(Ordering::Relaxed),
+            rate_limited: self.rate_limited.load(Ordering::Relaxed),
+            server_errors: self.server_errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
 /// An HTTP client that supports rate limiting and timeouts.
 ///
 /// Built on `reqwest` for async I/O. Allows per-endpoint and default quotas
-/// through a rate limiter.
+/// through a rate limiter, with optional per-request weights, syncing of used weight
+/// from response headers, and retries of rate limited (429) and server error (5xx)
+/// responses (see [`HttpRetryConfig`]).
 ///
 /// This struct is designed to handle HTTP requests efficiently, providing
 /// support for rate limiting, timeouts, and custom headers. The client is
@@ -199,6 +296,16 @@ pub struct HttpClient {
     pub(crate) client: InnerHttpClient,
     /// The rate limiter to control the request rate.
     pub(crate) rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
+    /// The optional signer for authenticated requests.
+    pub(crate) signer: Option<Arc<dyn RequestSigner>>,
+    /// The optional recorder for HTTP exchanges.
+    pub(crate) recorder: Option<Recorder>,
+    /// The optional retry configuration, with the backoff cloned for each request.
+    pub(crate) retry: Option<(HttpRetryConfig, ExponentialBackoff)>,
+    /// The response headers reporting used weight, with the rate limit key each syncs.
+    pub(crate) usage_headers: Vec<(String, String)>,
+    /// The request, retry and throttling counters.
+    pub(crate) counters: Arc<HttpClientCounters>,
 }
 
 impl HttpClient {
@@ -242,6 +349,90 @@ impl HttpClient {
         Self {
             client,
             rate_limiter,
+            signer: None,
+            recorder: None,
+            retry: None,
+            usage_headers: Vec::new(),
+            counters: Arc::new(HttpClientCounters::default()),
+        }
+    }
+
+    /// Sets the signer used for authenticated requests sent with [`HttpClient::request_signed`].
+    #[must_use]
+    pub fn with_signer(mut self, signer: Arc<dyn RequestSigner>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Returns whether a request signer is configured.
+    #[must_use]
+    pub fn has_signer(&self) -> bool {
+        self.signer.is_some()
+    }
+
+    /// Sets the recorder which captures every successful HTTP exchange made by the client.
+    ///
+    /// Request headers are not recorded.
+    #[must_use]
+    pub fn with_recorder(mut self, recorder: Recorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Returns whether a recorder is configured.
+    #[must_use]
+    pub fn has_recorder(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Enables automatic retries of rate limited and server error responses.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backoff parameters are invalid.
+    pub fn with_retry_config(mut self, config: HttpRetryConfig) -> anyhow::Result<Self> {
+        let backoff = ExponentialBackoff::new(
+            Duration::from_millis(config.delay_initial_ms),
+            Duration::from_millis(config.delay_max_ms),
+            config.backoff_factor,
+            config.jitter_ms,
+            false,
+        )?;
+        self.retain_header("retry-after");
+        self.retry = Some((config, backoff));
+        Ok(self)
+    }
+
+    /// Syncs the rate limit `key` with the used weight reported by the response `header`
+    /// (e.g. `X-MBX-USED-WEIGHT-1M`), so that requests made elsewhere with the same API
+    /// credentials or IP address are accounted for.
+    ///
+    /// The quota for `key` should match the server limit the header reports against.
+    #[must_use]
+    pub fn with_usage_header(mut self, header: &str, key: &str) -> Self {
+        self.retain_header(header);
+        self.usage_headers
+            .push((header.to_lowercase(), key.to_string()));
+        self
+    }
+
+    /// Returns a snapshot of the request, retry and throttling metrics.
+    #[must_use]
+    pub fn metrics(&self) -> HttpClientMetrics {
+        self.counters.snapshot()
+    }
+
+    fn retain_header(&mut self, header: &str) {
+        let header = header.to_lowercase();
+        if !self
+            .client
+            .header_keys
+            .iter()
+            .any(|key| key.eq_ignore_ascii_case(&header))
+        {
+            let mut header_keys = (*self.client.header_keys).clone();
+            header_keys.push(header);
+            self.client.header_keys = Arc::new(header_keys);
         }
     }
 
@@ -271,13 +462,282 @@ impl HttpClient {
         timeout_secs: Option<u64>,
         keys: Option<Vec<String>>,
     ) -> Result<HttpResponse, HttpClientError> {
-        let rate_limiter = self.rate_limiter.clone();
-        rate_limiter.await_keys_ready(keys).await;
+        self.request_weighted(method, url, headers, body, timeout_secs, unit_weights(keys))
+            .await
+    }
+
+    /// Sends an HTTP request where each rate limit key is charged the given weight.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if unable to send request or times out.
+    ///
+    /// # Examples
+    ///
+    /// If requesting `/api/v3/depth?limit=1000` weighted 50 against an IP limit keyed
+    /// `"weight"`, pass `[("weight", 50)]`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn request_weighted(
+        &self,
+        method: Method,
+        url: String,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        timeout_secs: Option<u64>,
+        keys: Vec<(String, u32)>,
+    ) -> Result<HttpResponse, HttpClientError> {
+        self.execute(method, url, headers, body, timeout_secs, keys, false)
+            .await
+    }
+
+    /// Sends an HTTP request signed with the configured [`RequestSigner`].
+    ///
+    /// The request is signed after waiting on the rate limiter, so that any timestamp
+    /// included in the signature is as fresh as possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no signer is configured, signing fails, or unable to send request
+    /// or times out.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn request_signed(
+        &self,
+        method: Method,
+        url: String,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        timeout_secs: Option<u64>,
+        keys: Option<Vec<String>>,
+    ) -> Result<HttpResponse, HttpClientError> {
+        self.request_signed_weighted(method, url, headers, body, timeout_secs, unit_weights(keys))
+            .await
+    }
 
-        self.client
-            .send_request(method, url, headers, body, timeout_secs)
+    /// Sends an HTTP request signed with the configured [`RequestSigner`], where each rate
+    /// limit key is charged the given weight.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no signer is configured, signing fails, or unable to send request
+    /// or times out.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn request_signed_weighted(
+        &self,
+        method: Method,
+        url: String,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        timeout_secs: Option<u64>,
+        keys: Vec<(String, u32)>,
+    ) -> Result<HttpResponse, HttpClientError> {
+        self.execute(method, url, headers, body, timeout_secs, keys, true)
             .await
     }
+
+    /// Sends a request, waiting on the rate limiter and retrying per the retry configuration.
+    #[allow(clippy::too_many_arguments)]
+    async fn execute(
+        &self,
+        method: Method,
+        url: String,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        timeout_secs: Option<u64>,
+        keys: Vec<(String, u32)>,
+        signed: bool,
+    ) -> Result<HttpResponse, HttpClientError> {
+        let signer =
+            if signed {
+                Some(self.signer.as_ref().ok_or_else(|| {
+                    HttpClientError::from("No request signer configured".to_string())
+                })?)
+            } else {
+                None
+            };
+        let mut backoff = self.retry.as_ref().map(|(_, backoff)| backoff.clone());
+        let mut retries = 0;
+
+        loop {
+            self.await_keys_ready(&keys).await;
+            self.counters.requests.fetch_add(1, Ordering::Relaxed);
+
+            let result = match signer {
+                Some(signer) => {
+                    // Signed after waiting on the rate limiter (and per attempt), so that any
+                    // timestamp included in the signature is as fresh as possible
+                    let url = Url::parse(url.as_str())
+                        .map_err(|e| HttpClientError::from(format!("URL parse error: {e}")))?;
+                    let mut request = SignableRequest::new(
+                        method.clone(),
+                        url,
+                        headers.clone().unwrap_or_default(),
+                        body.clone(),
+                    );
+                    let ts_now = get_atomic_clock_realtime().get_time_ns();
+                    signer.sign(&mut request, ts_now).map_err(|e| {
+                        HttpClientError::from(format!("Failed to sign request: {e}"))
+                    })?;
+                    self.send_and_record(
+                        request.method,
+                        request.url.to_string(),
+                        Some(request.headers),
+                        request.body,
+                        timeout_secs,
+                    )
+                    .await
+                }
+                None => {
+                    self.send_and_record(
+                        method.clone(),
+                        url.clone(),
+                        headers.clone(),
+                        body.clone(),
+                        timeout_secs,
+                    )
+                    .await
+                }
+            };
+
+            let Ok(response) = &result else {
+                return result;
+            };
+            let Some(retry_after) = self.handle_response(&method, response, &keys) else {
+                return result;
+            };
+
+            let max_retries = self
+                .retry
+                .as_ref()
+                .map_or(0, |(config, _)| config.max_retries);
+            let Some(backoff) = backoff.as_mut().filter(|_| retries < max_retries) else {
+                return result;
+            };
+
+            retries += 1;
+            self.counters.retries.fetch_add(1, Ordering::Relaxed);
+            let delay = backoff.next_duration().max(retry_after);
+            tracing::warn!(
+                "{method} {url} returned {}, retry {retries}/{max_retries} in {delay:?}",
+                response.status.as_u16(),
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    async fn await_keys_ready(&self, keys: &[(String, u32)]) {
+        if keys.is_empty() {
+            return;
+        }
+
+        let start = Instant::now();
+        self.rate_limiter
+            .await_weighted_keys_ready(keys.to_vec())
+            .await;
+
+        let waited = start.elapsed();
+        if waited >= Duration::from_millis(1) {
+            self.counters.throttled.fetch_add(1, Ordering::Relaxed);
+            self.counters
+                .throttled_ms
+                .fetch_add(waited.as_millis() as u64, Ordering::Relaxed);
+        }
+    }
+
+    /// Updates the rate limiter and counters from the response.
+    ///
+    /// Returns the minimum delay before a retry (zero unless the server sent `Retry-After`),
+    /// or `None` if the request should not be retried.
+    fn handle_response(
+        &self,
+        method: &Method,
+        response: &HttpResponse,
+        keys: &[(String, u32)],
+    ) -> Option<Duration> {
+        for (header, key) in &self.usage_headers {
+            if let Some(used) = get_header(&response.headers, header).and_then(|v| v.parse().ok()) {
+                self.rate_limiter.sync_key_usage(key, used);
+            }
+        }
+
+        let status = response.status.as_u16();
+        if status == StatusCode::TOO_MANY_REQUESTS.as_u16() || status == 418 {
+            self.counters.rate_limited.fetch_add(1, Ordering::Relaxed);
+            let retry_after = get_header(&response.headers, "retry-after")
+                .and_then(|v| v.trim().parse().ok())
+                .map(Duration::from_secs);
+            if let Some(retry_after) = retry_after {
+                for (key, _) in keys {
+                    self.rate_limiter.block_key_for(key, retry_after);
+                }
+            }
+
+            // A 418 means the IP is banned, retrying only extends the ban
+            if status == 418 {
+                tracing::error!("Request banned (418), retry after {retry_after:?}");
+                return None;
+            }
+            return Some(retry_after.unwrap_or_default());
+        }
+
+        if response.status.is_server_error() {
+            self.counters.server_errors.fetch_add(1, Ordering::Relaxed);
+            let retryable = self.retry.as_ref().is_some_and(|(config, _)| {
+                config.retry_server_errors && (config.retry_non_idempotent || is_idempotent(method))
+            });
+            return retryable.then_some(Duration::ZERO);
+        }
+
+        None
+    }
+
+    async fn send_and_record(
+        &self,
+        method: Method,
+        url: String,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        timeout_secs: Option<u64>,
+    ) -> Result<HttpResponse, HttpClientError> {
+        let Some(recorder) = &self.recorder else {
+            return self
+                .client
+                .send_request(method, url, headers, body, timeout_secs)
+                .await;
+        };
+
+        let ts_sent = Recorder::ts_now();
+        let response = self
+            .client
+            .send_request(
+                method.clone(),
+                url.clone(),
+                headers,
+                body.clone(),
+                timeout_secs,
+            )
+            .await?;
+        recorder.record_http_exchange(ts_sent, &method, &url, body.as_deref(), &response);
+
+        Ok(response)
+    }
+}
+
+fn unit_weights(keys: Option<Vec<String>>) -> Vec<(String, u32)> {
+    keys.unwrap_or_default()
+        .into_iter()
+        .map(|key| (key, 1))
+        .collect()
+}
+
+fn get_header<'a>(headers: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    !matches!(*method, Method::POST | Method::PATCH)
 }
 
 /// Internal implementation backing [`HttpClient`].
@@ -453,6 +913,183 @@ mod tests {
         Ok(addr)
     }
 
+    async fn start_router(router: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            serve(listener, router).await.unwrap();
+        });
+        format!("http://{addr}")
+    }
+
+    /// Returns a route which responds with `status` for the first `failures` calls, then 200.
+    fn flaky_router(
+        status: StatusCode,
+        failures: u32,
+        headers: &'static [(&'static str, &'static str)],
+    ) -> (Router, Arc<std::sync::atomic::AtomicU32>) {
+        let calls = Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let calls_clone = calls.clone();
+        let handler = move || {
+            let calls = calls_clone.clone();
+            async move {
+                let call = calls.fetch_add(1, Ordering::SeqCst);
+                let mut response =
+                    axum::response::IntoResponse::into_response(if call < failures {
+                        status
+                    } else {
+                        StatusCode::OK
+                    });
+                for (key, value) in headers {
+                    response
+                        .headers_mut()
+                        .insert(*key, HeaderValue::from_static(value));
+                }
+                response
+            }
+        };
+        let router = Router::new().route("/flaky", get(handler.clone()).post(handler));
+        (router, calls)
+    }
+
+    fn fast_retry_config() -> HttpRetryConfig {
+        HttpRetryConfig {
+            delay_initial_ms: 10,
+            delay_max_ms: 50,
+            jitter_ms: 0,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_server_errors() {
+        let (router, calls) = flaky_router(StatusCode::SERVICE_UNAVAILABLE, 2, &[]);
+        let url = start_router(router).await;
+        let client = HttpClient::new(HashMap::new(), vec![], vec![], None, None)
+            .with_retry_config(fast_retry_config())
+            .unwrap();
+
+        let response = client
+            .request(Method::GET, format!("{url}/flaky"), None, None, None, None)
+            .await
+            .unwrap();
+
+        assert!(response.status.is_success());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        let metrics = client.metrics();
+        assert_eq!(metrics.requests, 3);
+        assert_eq!(metrics.retries, 2);
+        assert_eq!(metrics.server_errors, 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_of_non_idempotent_server_errors() {
+        let (router, calls) = flaky_router(StatusCode::INTERNAL_SERVER_ERROR, 1, &[]);
+        let url = start_router(router).await;
+        let client = HttpClient::new(HashMap::new(), vec![], vec![], None, None)
+            .with_retry_config(fast_retry_config())
+            .unwrap();
+
+        let response = client
+            .request(Method::POST, format!("{url}/flaky"), None, None, None, None)
+            .await
+            .unwrap();
+
+        assert!(response.status.is_server_error());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_rate_limited_after_retry_after() {
+        let (router, calls) =
+            flaky_router(StatusCode::TOO_MANY_REQUESTS, 1, &[("retry-after", "1")]);
+        let url = start_router(router).await;
+        let client = HttpClient::new(HashMap::new(), vec![], vec![], None, None)
+            .with_retry_config(fast_retry_config())
+            .unwrap();
+
+        let start = Instant::now();
+        let response = client
+            .request(Method::POST, format!("{url}/flaky"), None, None, None, None)
+            .await
+            .unwrap();
+
+        assert!(response.status.is_success());
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(client.metrics().rate_limited, 1);
+    }
+
+    #[tokio::test]
+    async fn test_banned_is_not_retried_and_blocks_keys() {
+        let (router, calls) =
+            flaky_router(StatusCode::IM_A_TEAPOT, u32::MAX, &[("retry-after", "120")]);
+        let url = start_router(router).await;
+        let quota = Quota::per_second(std::num::NonZeroU32::new(10).unwrap());
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec![],
+            vec![("ip".to_string(), quota)],
+            None,
+            None,
+        )
+        .with_retry_config(fast_retry_config())
+        .unwrap();
+
+        let response = client
+            .request(
+                Method::GET,
+                format!("{url}/flaky"),
+                None,
+                None,
+                None,
+                Some(vec!["ip".to_string()]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status.as_u16(), 418);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(client.rate_limiter.check_key(&"ip".to_string()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_weighted_request_and_usage_header_sync() {
+        let (router, _) = flaky_router(StatusCode::OK, 0, &[("x-mbx-used-weight-1m", "9")]);
+        let url = start_router(router).await;
+        let quota = Quota::per_minute(std::num::NonZeroU32::new(10).unwrap());
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec![],
+            vec![("weight".to_string(), quota)],
+            None,
+            None,
+        )
+        .with_usage_header("X-MBX-USED-WEIGHT-1M", "weight");
+
+        let response = client
+            .request_weighted(
+                Method::GET,
+                format!("{url}/flaky"),
+                None,
+                None,
+                None,
+                vec![("weight".to_string(), 1)],
+            )
+            .await
+            .unwrap();
+
+        // Server reports 9 of 10 used, so only one more unit of weight is available
+        assert_eq!(
+            response.headers.get("x-mbx-used-weight-1m"),
+            Some(&"9".to_string())
+        );
+        let key = "weight".to_string();
+        assert!(client.rate_limiter.check_key_n(&key, 2).is_err());
+        assert!(client.rate_limiter.check_key(&key).is_ok());
+        assert!(client.rate_limiter.check_key(&key).is_err());
+    }
+
     #[tokio::test]
     async fn test_get() {
         let addr = start_test_server().await.unwrap();
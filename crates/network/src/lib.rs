@@ -39,6 +39,8 @@ pub mod backoff;
 pub mod fix;
 pub mod http;
 pub mod mode;
+pub mod recording;
+pub mod signing;
 pub mod socket;
 pub mod websocket;
 
@@ -156,13 +156,11 @@ impl HttpClient {
         timeout_secs: Option<u64>,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
-        let rate_limiter = self.rate_limiter.clone();
+        let client = self.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            rate_limiter.await_keys_ready(keys).await;
             client
-                .send_request(method.into(), url, headers, body, timeout_secs)
+                .request(method.into(), url, headers, body, timeout_secs, keys)
                 .await
                 .map_err(HttpClientError::into_py_err)
         })
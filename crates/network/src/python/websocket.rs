@@ -65,6 +65,7 @@ impl WebSocketConfig {
             reconnect_delay_max_ms,
             reconnect_backoff_factor,
             reconnect_jitter_ms,
+            recorder: None,
         }
     }
 }
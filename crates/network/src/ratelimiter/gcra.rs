@@ -134,29 +134,76 @@ impl Gcra {
         t0 + self.t
     }
 
-    /// Tests a single cell against the rate limiter state and updates it at the given key.
-    pub(crate) fn test_and_update<K, S: StateStore<Key = K>, P: clock::Reference>(
+    /// Returns the maximum number of cells which can be let through at once.
+    pub(crate) fn burst_size(&self) -> u32 {
+        (self.tau.as_u64() / self.t.as_u64()) as u32
+    }
+
+    /// Tests `n` cells (a weighted request) against the rate limiter state and updates it at
+    /// the given key.
+    ///
+    /// A weight greater than the burst size is capped at the burst size, so that the request
+    /// conforms once the bucket is full rather than never.
+    pub(crate) fn test_n_and_update<K, S: StateStore<Key = K>, P: clock::Reference>(
         &self,
         start: P,
         key: &K,
         state: &S,
         t0: P,
+        n: u32,
     ) -> Result<(), NotUntil<P>> {
+        let n = n.clamp(1, self.burst_size().max(1));
         let t0 = t0.duration_since(start);
         let tau = self.tau;
         let t = self.t;
+        let additional_weight = t * u64::from(n - 1);
         state.measure_and_replace(key, |tat| {
             let tat = tat.unwrap_or_else(|| self.starting_state(t0));
-            let earliest_time = tat.saturating_sub(tau);
+            let earliest_time = (tat + additional_weight).saturating_sub(tau);
             if t0 < earliest_time {
                 Err(NotUntil::new(
                     StateSnapshot::new(self.t, self.tau, earliest_time, earliest_time),
                     start,
                 ))
             } else {
-                let next = cmp::max(tat, t0) + t;
+                let next = cmp::max(tat, t0) + t + additional_weight;
                 Ok(((), next))
             }
         })
     }
+
+    /// Synchronizes the state at the given key with the `used` cells reported by the
+    /// counterparty (e.g. from a used weight response header).
+    ///
+    /// The state is only ever made more restrictive, as local requests may still be in flight.
+    pub(crate) fn sync_used<K, S: StateStore<Key = K>, P: clock::Reference>(
+        &self,
+        start: P,
+        key: &K,
+        state: &S,
+        t0: P,
+        used: u32,
+    ) {
+        let t0 = t0.duration_since(start);
+        // Matches `starting_state`, where a TAT of `t0 + t` means nothing used yet
+        let reported = t0 + self.t * (u64::from(used) + 1);
+        let _ = state.measure_and_replace(key, |tat| {
+            let tat = tat.unwrap_or(t0);
+            Ok::<_, ()>(((), cmp::max(tat, reported)))
+        });
+    }
+
+    /// Blocks the given key until `until` (e.g. from a `Retry-After` response header).
+    pub(crate) fn block_until<K, S: StateStore<Key = K>, P: clock::Reference>(
+        &self,
+        start: P,
+        key: &K,
+        state: &S,
+        until: P,
+    ) {
+        let blocked_tat = until.duration_since(start) + self.tau;
+        let _ = state.measure_and_replace(key, |tat| {
+            Ok::<_, ()>(((), cmp::max(tat.unwrap_or_default(), blocked_tat)))
+        });
+    }
 }
@@ -193,23 +193,61 @@ where
         self.gcra.insert(key, Gcra::new(value));
     }
 
+    /// Returns the GCRA for the given key, falling back to the default quota.
+    fn gcra_for_key<'a>(&'a self, key: &K) -> Option<GcraRef<'a, K>> {
+        match self.gcra.get(key) {
+            Some(gcra) => Some(GcraRef::Keyed(gcra)),
+            None => self.default_gcra.as_ref().map(GcraRef::Default),
+        }
+    }
+
     /// Checks if the given key is allowed under the rate limit.
     ///
     /// # Errors
     ///
     /// Returns `Err(NotUntil)` if the key is rate-limited, indicating when it will be allowed.
     pub fn check_key(&self, key: &K) -> Result<(), NotUntil<C::Instant>> {
-        match self.gcra.get(key) {
-            Some(quota) => quota.test_and_update(self.start, key, &self.state, self.clock.now()),
-            None => self.default_gcra.as_ref().map_or(Ok(()), |gcra| {
-                gcra.test_and_update(self.start, key, &self.state, self.clock.now())
-            }),
+        self.check_key_n(key, 1)
+    }
+
+    /// Checks if a request of the given `weight` is allowed under the rate limit for the key.
+    ///
+    /// A weight greater than the quota burst size is treated as the burst size.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(NotUntil)` if the key is rate-limited, indicating when it will be allowed.
+    pub fn check_key_n(&self, key: &K, weight: u32) -> Result<(), NotUntil<C::Instant>> {
+        self.gcra_for_key(key).map_or(Ok(()), |gcra| {
+            gcra.test_n_and_update(self.start, key, &self.state, self.clock.now(), weight)
+        })
+    }
+
+    /// Synchronizes the key with the `used` weight reported by the server for the current
+    /// quota period (e.g. Binance `X-MBX-USED-WEIGHT-1M`).
+    ///
+    /// Only ever makes the limit more restrictive, so in-flight requests are not lost.
+    pub fn sync_key_usage(&self, key: &K, used: u32) {
+        if let Some(gcra) = self.gcra_for_key(key) {
+            gcra.sync_used(self.start, key, &self.state, self.clock.now(), used);
+        }
+    }
+
+    /// Blocks the key for the given duration (e.g. from a `Retry-After` response header).
+    pub fn block_key_for(&self, key: &K, duration: Duration) {
+        if let Some(gcra) = self.gcra_for_key(key) {
+            let until = self.clock.now() + Nanos::from(duration);
+            gcra.block_until(self.start, key, &self.state, until);
         }
     }
 
     pub async fn until_key_ready(&self, key: &K) {
+        self.until_key_ready_n(key, 1).await;
+    }
+
+    pub async fn until_key_ready_n(&self, key: &K, weight: u32) {
         loop {
-            match self.check_key(key) {
+            match self.check_key_n(key, weight) {
                 Ok(()) => {
                     break;
                 }
@@ -221,8 +259,19 @@ where
     }
 
     pub async fn await_keys_ready(&self, keys: Option<Vec<K>>) {
-        let keys = keys.unwrap_or_default();
-        let tasks = keys.iter().map(|key| self.until_key_ready(key));
+        let keys = keys
+            .unwrap_or_default()
+            .into_iter()
+            .map(|key| (key, 1))
+            .collect();
+        self.await_weighted_keys_ready(keys).await;
+    }
+
+    /// Waits until every key can accommodate its weight.
+    pub async fn await_weighted_keys_ready(&self, keys: Vec<(K, u32)>) {
+        let tasks = keys
+            .iter()
+            .map(|(key, weight)| self.until_key_ready_n(key, *weight));
 
         futures::stream::iter(tasks)
             .for_each_concurrent(None, |key_future| async move {
@@ -232,6 +281,23 @@ where
     }
 }
 
+/// A reference to either a keyed or the default GCRA.
+enum GcraRef<'a, K> {
+    Keyed(dashmap::mapref::one::Ref<'a, K, Gcra>),
+    Default(&'a Gcra),
+}
+
+impl<K: Hash + Eq> std::ops::Deref for GcraRef<'_, K> {
+    type Target = Gcra;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Keyed(gcra) => gcra.value(),
+            Self::Default(gcra) => gcra,
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -371,6 +437,65 @@ mod tests {
         assert!(mock_limiter.check_key(&"per_minute".to_string()).is_err());
     }
 
+    #[rstest]
+    fn test_weighted_requests() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "weighted".to_string();
+        mock_limiter
+            .add_quota_for_key(key.clone(), Quota::per_second(NonZeroU32::new(10).unwrap()));
+
+        assert!(mock_limiter.check_key_n(&key, 6).is_ok());
+        assert!(mock_limiter.check_key_n(&key, 5).is_err());
+        assert!(mock_limiter.check_key_n(&key, 4).is_ok());
+        assert!(mock_limiter.check_key(&key).is_err());
+
+        // Half the weight replenishes in half the period
+        mock_limiter.advance_clock(Duration::from_millis(500));
+        assert!(mock_limiter.check_key_n(&key, 5).is_ok());
+        assert!(mock_limiter.check_key(&key).is_err());
+    }
+
+    #[rstest]
+    fn test_weight_capped_at_burst_size() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "heavy".to_string();
+
+        // Default quota has a burst of 2, a heavier request still conforms when full
+        assert!(mock_limiter.check_key_n(&key, 50).is_ok());
+        assert!(mock_limiter.check_key(&key).is_err());
+    }
+
+    #[rstest]
+    fn test_sync_key_usage() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "synced".to_string();
+        mock_limiter
+            .add_quota_for_key(key.clone(), Quota::per_second(NonZeroU32::new(10).unwrap()));
+
+        mock_limiter.sync_key_usage(&key, 8);
+
+        assert!(mock_limiter.check_key_n(&key, 2).is_ok());
+        assert!(mock_limiter.check_key(&key).is_err());
+
+        // Reported usage lower than the local state is ignored
+        mock_limiter.sync_key_usage(&key, 0);
+        assert!(mock_limiter.check_key(&key).is_err());
+    }
+
+    #[rstest]
+    fn test_block_key_for() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "blocked".to_string();
+
+        mock_limiter.block_key_for(&key, Duration::from_secs(5));
+
+        assert!(mock_limiter.check_key(&key).is_err());
+        mock_limiter.advance_clock(Duration::from_millis(4_999));
+        assert!(mock_limiter.check_key(&key).is_err());
+        mock_limiter.advance_clock(Duration::from_millis(1));
+        assert!(mock_limiter.check_key(&key).is_ok());
+    }
+
     #[tokio::test]
     async fn test_await_keys_ready() {
         let mock_limiter = initialize_mock_rate_limiter();
@@ -0,0 +1,1022 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Record and replay of network traffic for offline testing.
+//!
+//! A [`Recorder`] appends every WebSocket frame and HTTP exchange seen by a client to a
+//! JSON lines file, each event stamped with the wall clock time it was observed. Recordings
+//! are served back by local stand-in servers:
+//!
+//! - [`HttpReplayServer`] answers requests with the recorded responses, matched on method
+//!   and path (and query where possible), after the recorded latency.
+//! - [`WsReplayServer`] sends the recorded inbound frames to each connecting client with
+//!   the original inter-frame timing, one recorded session per connection.
+//!
+//! Point an adapter's base URLs at the replay server addresses to regression test it
+//! offline or reproduce a production incident exactly.
+//!
+//! Request headers are never recorded, so credentials and signatures do not leak into
+//! recordings.
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        mpsc::{Receiver, Sender, channel},
+    },
+    thread::JoinHandle as ThreadHandle,
+    time::Duration,
+};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use futures_util::{SinkExt, StreamExt};
+use nautilus_core::{UnixNanos, time::get_atomic_clock_realtime};
+use reqwest::{Method, Url};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader as AsyncBufReader},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+use tokio_tungstenite::{accept_async, tungstenite::Message};
+
+use crate::http::HttpResponse;
+
+/// Recorded response headers which describe the original wire framing and encoding, and so
+/// must not be replayed with the decoded body.
+const REPLAY_SKIP_HEADERS: [&str; 3] = ["content-encoding", "content-length", "transfer-encoding"];
+
+/// Represents the payload of a recorded WebSocket frame.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum RecordedFrame {
+    /// A UTF-8 text frame.
+    Text(String),
+    /// A binary frame, base64 encoded.
+    Binary(String),
+}
+
+impl RecordedFrame {
+    /// Creates a recorded frame from the given `message`.
+    ///
+    /// Returns `None` for control frames (ping, pong, close), which are not recorded.
+    #[must_use]
+    pub fn from_message(message: &Message) -> Option<Self> {
+        match message {
+            Message::Text(text) => Some(Self::Text(text.to_string())),
+            Message::Binary(data) => Some(Self::Binary(STANDARD.encode(data))),
+            _ => None,
+        }
+    }
+
+    /// Converts the recorded frame back into a WebSocket message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a binary payload is not valid base64.
+    pub fn to_message(&self) -> anyhow::Result<Message> {
+        match self {
+            Self::Text(text) => Ok(Message::Text(text.clone().into())),
+            Self::Binary(data) => Ok(Message::Binary(STANDARD.decode(data)?.into())),
+        }
+    }
+}
+
+/// Represents a recorded HTTP request and its response.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedHttpExchange {
+    /// UNIX timestamp (nanoseconds) when the request was sent.
+    pub ts: UnixNanos,
+    /// The HTTP method.
+    pub method: String,
+    /// The full request URL.
+    pub url: String,
+    /// The request body, base64 encoded.
+    pub request_body: Option<String>,
+    /// The response status code.
+    pub status: u16,
+    /// The response headers captured by the client.
+    pub response_headers: HashMap<String, String>,
+    /// The response body, base64 encoded.
+    pub response_body: String,
+    /// The time (nanoseconds) between sending the request and receiving the response.
+    pub latency_ns: u64,
+}
+
+impl RecordedHttpExchange {
+    /// Returns the path and query of the recorded URL (e.g. `/api/v1/orders?limit=10`).
+    #[must_use]
+    pub fn path_and_query(&self) -> String {
+        match Url::parse(&self.url) {
+            Ok(url) => match url.query() {
+                Some(query) => format!("{}?{query}", url.path()),
+                None => url.path().to_string(),
+            },
+            Err(_) => self.url.clone(),
+        }
+    }
+}
+
+/// Represents a single event in a network recording.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecordedEvent {
+    /// A WebSocket connection (or reconnection) was established.
+    WsConnected { ts: UnixNanos, url: String },
+    /// A WebSocket frame was sent to the server.
+    WsOutbound {
+        ts: UnixNanos,
+        url: String,
+        frame: RecordedFrame,
+    },
+    /// A WebSocket frame was received from the server.
+    WsInbound {
+        ts: UnixNanos,
+        url: String,
+        frame: RecordedFrame,
+    },
+    /// An HTTP request was answered by the server.
+    HttpExchange(RecordedHttpExchange),
+}
+
+impl RecordedEvent {
+    /// Returns the UNIX timestamp (nanoseconds) of the event.
+    #[must_use]
+    pub const fn ts(&self) -> UnixNanos {
+        match self {
+            Self::WsConnected { ts, .. }
+            | Self::WsOutbound { ts, .. }
+            | Self::WsInbound { ts, .. } => *ts,
+            Self::HttpExchange(exchange) => exchange.ts,
+        }
+    }
+}
+
+/// Appends network events to a JSON lines recording file.
+///
+/// The recorder is cheap to clone and may be shared between several clients. Events are
+/// sent over a channel to a dedicated writer thread, so recording never blocks a client on
+/// file I/O. The writer flushes whenever its queue is drained so a recording survives a
+/// crash, and flushes and exits once the last clone is dropped.
+#[derive(Clone)]
+pub struct Recorder {
+    path: PathBuf,
+    writer: Arc<RecorderWriter>,
+}
+
+enum WriterCommand {
+    Line(String),
+    Flush(Sender<()>),
+}
+
+struct RecorderWriter {
+    tx: Option<Sender<WriterCommand>>,
+    handle: Option<ThreadHandle<()>>,
+}
+
+impl RecorderWriter {
+    fn send(&self, command: WriterCommand) -> anyhow::Result<()> {
+        self.tx
+            .as_ref()
+            .and_then(|tx| tx.send(command).ok())
+            .ok_or_else(|| anyhow::anyhow!("Recorder writer has stopped"))
+    }
+}
+
+impl Drop for RecorderWriter {
+    fn drop(&mut self) {
+        // Closing the channel lets the writer drain its queue and exit
+        self.tx.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn run_recorder_writer(path: &Path, mut writer: BufWriter<File>, rx: &Receiver<WriterCommand>) {
+    while let Ok(command) = rx.recv() {
+        let mut next = Some(command);
+
+        // Write everything queued before flushing once
+        while let Some(command) = next.take() {
+            match command {
+                WriterCommand::Line(line) => {
+                    if let Err(e) = writer
+                        .write_all(line.as_bytes())
+                        .and_then(|()| writer.write_all(b"\n"))
+                    {
+                        tracing::error!("Failed to write network event to {path:?}: {e}");
+                    }
+                }
+                WriterCommand::Flush(reply) => {
+                    if let Err(e) = writer.flush() {
+                        tracing::error!("Failed to flush recording {path:?}: {e}");
+                    }
+                    let _ = reply.send(());
+                }
+            }
+            next = rx.try_recv().ok();
+        }
+
+        if let Err(e) = writer.flush() {
+            tracing::error!("Failed to flush recording {path:?}: {e}");
+        }
+    }
+}
+
+impl Debug for Recorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(Recorder))
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] writing to `path`, truncating any existing file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created or the writer thread cannot be spawned.
+    pub fn new<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let writer = BufWriter::new(File::create(&path)?);
+        let (tx, rx) = channel();
+
+        let writer_path = path.clone();
+        let handle = std::thread::Builder::new()
+            .name("recorder".to_string())
+            .spawn(move || run_recorder_writer(&writer_path, writer, &rx))?;
+
+        Ok(Self {
+            path,
+            writer: Arc::new(RecorderWriter {
+                tx: Some(tx),
+                handle: Some(handle),
+            }),
+        })
+    }
+
+    /// Returns the path of the recording file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Queues the given `event` to be appended to the recording.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be serialized or the writer has stopped.
+    pub fn record(&self, event: &RecordedEvent) -> anyhow::Result<()> {
+        let line = serde_json::to_string(event)?;
+        self.writer.send(WriterCommand::Line(line))
+    }
+
+    /// Blocks until all events recorded so far have been written and flushed to the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer has stopped.
+    pub fn flush(&self) -> anyhow::Result<()> {
+        let (reply_tx, reply_rx) = channel();
+        self.writer.send(WriterCommand::Flush(reply_tx))?;
+        reply_rx
+            .recv()
+            .map_err(|_| anyhow::anyhow!("Recorder writer has stopped"))
+    }
+
+    /// Records that a WebSocket connection to `url` was established.
+    pub fn record_ws_connected(&self, url: &str) {
+        self.record_or_log(&RecordedEvent::WsConnected {
+            ts: Self::ts_now(),
+            url: url.to_string(),
+        });
+    }
+
+    /// Records a WebSocket `message` sent to `url` (control frames are skipped).
+    pub fn record_ws_outbound(&self, url: &str, message: &Message) {
+        if let Some(frame) = RecordedFrame::from_message(message) {
+            self.record_or_log(&RecordedEvent::WsOutbound {
+                ts: Self::ts_now(),
+                url: url.to_string(),
+                frame,
+            });
+        }
+    }
+
+    /// Records a WebSocket `message` received from `url` (control frames are skipped).
+    pub fn record_ws_inbound(&self, url: &str, message: &Message) {
+        if let Some(frame) = RecordedFrame::from_message(message) {
+            self.record_or_log(&RecordedEvent::WsInbound {
+                ts: Self::ts_now(),
+                url: url.to_string(),
+                frame,
+            });
+        }
+    }
+
+    /// Records an HTTP exchange which was sent at `ts_sent`.
+    pub fn record_http_exchange(
+        &self,
+        ts_sent: UnixNanos,
+        method: &Method,
+        url: &str,
+        request_body: Option<&[u8]>,
+        response: &HttpResponse,
+    ) {
+        let latency_ns = Self::ts_now().as_u64().saturating_sub(ts_sent.as_u64());
+        self.record_or_log(&RecordedEvent::HttpExchange(RecordedHttpExchange {
+            ts: ts_sent,
+            method: method.to_string(),
+            url: url.to_string(),
+            request_body: request_body.map(|body| STANDARD.encode(body)),
+            status: response.status.as_u16(),
+            response_headers: response.headers.clone(),
+            response_body: STANDARD.encode(&response.body),
+            latency_ns,
+        }));
+    }
+
+    /// Returns the current UNIX timestamp (nanoseconds) used to stamp events.
+    #[must_use]
+    pub fn ts_now() -> UnixNanos {
+        get_atomic_clock_realtime().get_time_ns()
+    }
+
+    fn record_or_log(&self, event: &RecordedEvent) {
+        if let Err(e) = self.record(event) {
+            tracing::error!("Failed to record network event to {:?}: {e}", self.path);
+        }
+    }
+}
+
+/// Loads all events from the JSON lines recording at `path`.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a line is not a valid event.
+pub fn load_recording<P: AsRef<Path>>(path: P) -> anyhow::Result<Vec<RecordedEvent>> {
+    let reader = BufReader::new(File::open(path.as_ref())?);
+    let mut events = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let event = serde_json::from_str(&line)
+            .map_err(|e| anyhow::anyhow!("Invalid event on line {}: {e}", i + 1))?;
+        events.push(event);
+    }
+
+    Ok(events)
+}
+
+fn scaled_delay(delta_ns: u64, speed: f64) -> Duration {
+    Duration::from_nanos(delta_ns).div_f64(speed)
+}
+
+/// A local HTTP server which answers requests from a recording.
+///
+/// Each recorded exchange is served once and in recorded order. A request is matched on
+/// method and path with query first, then on method and path alone (so requests carrying
+/// fresh timestamps or signatures in their query still match). Unmatched requests receive
+/// a `404 Not Found`.
+///
+/// Recorded bodies are already decoded, so the recorded `content-encoding`, `content-length`
+/// and `transfer-encoding` headers are dropped and the body is sent with its own length.
+#[derive(Debug)]
+pub struct HttpReplayServer {
+    addr: SocketAddr,
+    remaining: Arc<Mutex<Vec<Option<RecordedHttpExchange>>>>,
+    task: JoinHandle<()>,
+}
+
+impl HttpReplayServer {
+    /// Starts a replay server on an ephemeral local port serving the HTTP exchanges in `events`.
+    ///
+    /// The recorded latency of each exchange is divided by `speed` (e.g. `2.0` replays twice
+    /// as fast, a very large value replays without delay).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `speed` is not positive or the listener cannot be bound.
+    pub async fn start(events: &[RecordedEvent], speed: f64) -> anyhow::Result<Self> {
+        anyhow::ensure!(speed > 0.0, "Replay speed must be positive, was {speed}");
+
+        let exchanges: Vec<Option<RecordedHttpExchange>> = events
+            .iter()
+            .filter_map(|event| match event {
+                RecordedEvent::HttpExchange(exchange) => Some(Some(exchange.clone())),
+                _ => None,
+            })
+            .collect();
+        let remaining = Arc::new(Mutex::new(exchanges));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        let remaining_clone = remaining.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    break;
+                };
+                let remaining = remaining_clone.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = Self::serve_connection(stream, remaining, speed).await {
+                        tracing::debug!("Replay connection closed: {e}");
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            addr,
+            remaining,
+            task,
+        })
+    }
+
+    /// Returns the local socket address of the server.
+    #[must_use]
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the base URL of the server (e.g. `http://127.0.0.1:54321`).
+    #[must_use]
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Returns the number of recorded exchanges not yet served.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.remaining
+            .lock()
+            .expect("Replay lock poisoned")
+            .iter()
+            .filter(|exchange| exchange.is_some())
+            .count()
+    }
+
+    fn take_match(
+        remaining: &Mutex<Vec<Option<RecordedHttpExchange>>>,
+        method: &str,
+        target: &str,
+    ) -> Option<RecordedHttpExchange> {
+        let mut remaining = remaining.lock().ok()?;
+        let path = target.split('?').next().unwrap_or(target);
+
+        let position = remaining
+            .iter()
+            .position(|exchange| {
+                exchange
+                    .as_ref()
+                    .is_some_and(|e| e.method == method && e.path_and_query() == target)
+            })
+            .or_else(|| {
+                remaining.iter().position(|exchange| {
+                    exchange.as_ref().is_some_and(|e| {
+                        let recorded = e.path_and_query();
+                        e.method == method && recorded.split('?').next() == Some(path)
+                    })
+                })
+            })?;
+
+        remaining[position].take()
+    }
+
+    async fn serve_connection(
+        stream: TcpStream,
+        remaining: Arc<Mutex<Vec<Option<RecordedHttpExchange>>>>,
+        speed: f64,
+    ) -> anyhow::Result<()> {
+        let mut stream = AsyncBufReader::new(stream);
+
+        loop {
+            // Request line
+            let mut request_line = String::new();
+            if stream.read_line(&mut request_line).await? == 0 {
+                return Ok(()); // Client closed the connection
+            }
+            let mut parts = request_line.split_whitespace();
+            let method = parts.next().unwrap_or_default().to_string();
+            let target = parts.next().unwrap_or("/").to_string();
+
+            // Headers
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                if stream.read_line(&mut line).await? == 0 {
+                    return Ok(());
+                }
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(value) = line
+                    .split_once(':')
+                    .filter(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                    .map(|(_, value)| value)
+                {
+                    content_length = value.trim().parse()?;
+                }
+            }
+
+            // Body (ignored, exchanges are matched on method and target)
+            let mut body = vec![0; content_length];
+            stream.read_exact(&mut body).await?;
+
+            let response = if let Some(exchange) = Self::take_match(&remaining, &method, &target) {
+                tokio::time::sleep(scaled_delay(exchange.latency_ns, speed)).await;
+                let body = STANDARD.decode(&exchange.response_body)?;
+                let mut head = format!("HTTP/1.1 {} Replay\r\n", exchange.status);
+                let headers = exchange.response_headers.iter().filter(|(key, _)| {
+                    !REPLAY_SKIP_HEADERS
+                        .iter()
+                        .any(|skip| key.eq_ignore_ascii_case(skip))
+                });
+                for (key, value) in headers {
+                    head.push_str(key);
+                    head.push_str(": ");
+                    head.push_str(value);
+                    head.push_str("\r\n");
+                }
+                head.push_str("content-length: ");
+                head.push_str(&body.len().to_string());
+                head.push_str("\r\n\r\n");
+                [head.into_bytes(), body].concat()
+            } else {
+                tracing::warn!("No recorded exchange for {method} {target}");
+                b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n\r\n".to_vec()
+            };
+
+            stream.get_mut().write_all(&response).await?;
+            stream.get_mut().flush().await?;
+        }
+    }
+}
+
+impl Drop for HttpReplayServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Represents the recorded inbound frames of a single WebSocket connection.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WsReplaySession {
+    /// UNIX timestamp (nanoseconds) when the connection was established.
+    pub ts_connected: UnixNanos,
+    /// The inbound frames with the UNIX timestamp (nanoseconds) they were received.
+    pub frames: Vec<(UnixNanos, RecordedFrame)>,
+}
+
+impl WsReplaySession {
+    /// Splits the WebSocket events in `events` into one session per recorded connection.
+    ///
+    /// When `url` is given only events for that URL are considered. Inbound frames recorded
+    /// before any connection event start an implicit session.
+    #[must_use]
+    pub fn from_events(events: &[RecordedEvent], url: Option<&str>) -> Vec<Self> {
+        let mut sessions: Vec<Self> = Vec::new();
+
+        for event in events {
+            match event {
+                RecordedEvent::WsConnected { ts, url: event_url }
+                    if url.is_none_or(|url| url == event_url) =>
+                {
+                    sessions.push(Self {
+                        ts_connected: *ts,
+                        frames: Vec::new(),
+                    });
+                }
+                RecordedEvent::WsInbound {
+                    ts,
+                    url: event_url,
+                    frame,
+                } if url.is_none_or(|url| url == event_url) => {
+                    if sessions.is_empty() {
+                        sessions.push(Self {
+                            ts_connected: *ts,
+                            frames: Vec::new(),
+                        });
+                    }
+                    if let Some(session) = sessions.last_mut() {
+                        session.frames.push((*ts, frame.clone()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        sessions
+    }
+}
+
+/// A local WebSocket server which sends recorded inbound frames to connecting clients.
+///
+/// The n-th connection receives the n-th recorded session, so reconnects are reproduced
+/// as they happened. Frames are sent with their original delay from the connection time,
+/// divided by the replay speed. Frames sent by the client are read and discarded. Once
+/// all sessions have been served, further connections receive no frames.
+#[derive(Debug)]
+pub struct WsReplayServer {
+    addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl WsReplayServer {
+    /// Starts a replay server on an ephemeral local port for the WebSocket events in `events`.
+    ///
+    /// When `url` is given only events recorded for that URL are replayed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `speed` is not positive or the listener cannot be bound.
+    pub async fn start(
+        events: &[RecordedEvent],
+        url: Option<&str>,
+        speed: f64,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(speed > 0.0, "Replay speed must be positive, was {speed}");
+
+        let mut sessions = WsReplaySession::from_events(events, url).into_iter();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        let task = tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    break;
+                };
+                let session = sessions.next().unwrap_or_default();
+                tokio::spawn(async move {
+                    if let Err(e) = Self::serve_session(stream, session, speed).await {
+                        tracing::debug!("Replay session closed: {e}");
+                    }
+                });
+            }
+        });
+
+        Ok(Self { addr, task })
+    }
+
+    /// Returns the local socket address of the server.
+    #[must_use]
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the URL of the server (e.g. `ws://127.0.0.1:54321`).
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("ws://{}", self.addr)
+    }
+
+    async fn serve_session(
+        stream: TcpStream,
+        session: WsReplaySession,
+        speed: f64,
+    ) -> anyhow::Result<()> {
+        let (mut writer, mut reader) = accept_async(stream).await?.split();
+
+        // Drain (and discard) client frames so control frames are processed
+        let drain_task =
+            tokio::spawn(async move { while let Some(Ok(_)) = reader.next().await {} });
+
+        let start = tokio::time::Instant::now();
+        for (ts, frame) in session.frames {
+            let delta_ns = ts.as_u64().saturating_sub(session.ts_connected.as_u64());
+            tokio::time::sleep_until(start + scaled_delay(delta_ns, speed)).await;
+            writer.send(frame.to_message()?).await?;
+        }
+
+        drain_task.await?;
+        Ok(())
+    }
+}
+
+impl Drop for WsReplayServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+#[cfg(target_os = "linux")] // Only run network tests on Linux (CI stability)
+mod tests {
+    use std::collections::HashMap;
+
+    use futures_util::StreamExt;
+    use rstest::rstest;
+    use tokio_tungstenite::connect_async;
+
+    use super::*;
+    use crate::http::HttpClient;
+
+    fn exchange(method: &str, url: &str, body: &str, latency_ns: u64) -> RecordedEvent {
+        RecordedEvent::HttpExchange(RecordedHttpExchange {
+            ts: UnixNanos::from(1_000),
+            method: method.to_string(),
+            url: url.to_string(),
+            request_body: None,
+            status: 200,
+            response_headers: HashMap::new(),
+            response_body: STANDARD.encode(body),
+            latency_ns,
+        })
+    }
+
+    fn inbound(ts: u64, url: &str, text: &str) -> RecordedEvent {
+        RecordedEvent::WsInbound {
+            ts: UnixNanos::from(ts),
+            url: url.to_string(),
+            frame: RecordedFrame::Text(text.to_string()),
+        }
+    }
+
+    #[rstest]
+    fn test_frame_roundtrip() {
+        let text = Message::Text("hello".into());
+        let binary = Message::Binary(vec![0, 1, 2, 255].into());
+
+        let text_frame = RecordedFrame::from_message(&text).unwrap();
+        let binary_frame = RecordedFrame::from_message(&binary).unwrap();
+
+        assert_eq!(text_frame.to_message().unwrap(), text);
+        assert_eq!(binary_frame.to_message().unwrap(), binary);
+        assert!(RecordedFrame::from_message(&Message::Ping(vec![].into())).is_none());
+    }
+
+    #[rstest]
+    fn test_recorder_write_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        let recorder = Recorder::new(&path).unwrap();
+
+        recorder.record_ws_connected("wss://example.com/ws");
+        recorder.record_ws_outbound("wss://example.com/ws", &Message::Text("sub".into()));
+        recorder.record_ws_inbound("wss://example.com/ws", &Message::Binary(vec![7].into()));
+        recorder.record_ws_inbound("wss://example.com/ws", &Message::Pong(vec![].into()));
+        recorder
+            .record(&exchange("GET", "https://example.com/a?b=1", "{}", 5))
+            .unwrap();
+        recorder.flush().unwrap();
+
+        let events = load_recording(&path).unwrap();
+
+        assert_eq!(events.len(), 4);
+        assert!(matches!(events[0], RecordedEvent::WsConnected { .. }));
+        assert!(matches!(
+            &events[1],
+            RecordedEvent::WsOutbound { frame: RecordedFrame::Text(text), .. } if text == "sub"
+        ));
+        assert!(matches!(
+            &events[2],
+            RecordedEvent::WsInbound { frame: RecordedFrame::Binary(data), .. } if data == "Bw=="
+        ));
+        assert_eq!(
+            events[3],
+            exchange("GET", "https://example.com/a?b=1", "{}", 5)
+        );
+    }
+
+    #[rstest]
+    fn test_path_and_query() {
+        let RecordedEvent::HttpExchange(exchange) =
+            exchange("GET", "https://example.com/api/v1/orders?limit=10", "", 0)
+        else {
+            unreachable!()
+        };
+
+        assert_eq!(exchange.path_and_query(), "/api/v1/orders?limit=10");
+    }
+
+    #[rstest]
+    fn test_sessions_from_events() {
+        let events = vec![
+            inbound(5, "ws://a", "orphan"),
+            RecordedEvent::WsConnected {
+                ts: UnixNanos::from(10),
+                url: "ws://a".to_string(),
+            },
+            inbound(11, "ws://b", "other"),
+            inbound(12, "ws://a", "one"),
+            RecordedEvent::WsConnected {
+                ts: UnixNanos::from(20),
+                url: "ws://a".to_string(),
+            },
+            inbound(25, "ws://a", "two"),
+        ];
+
+        let sessions = WsReplaySession::from_events(&events, Some("ws://a"));
+
+        assert_eq!(sessions.len(), 3);
+        assert_eq!(sessions[0].ts_connected, UnixNanos::from(5));
+        assert_eq!(sessions[1].frames.len(), 1);
+        assert_eq!(
+            sessions[1].frames[0].1,
+            RecordedFrame::Text("one".to_string())
+        );
+        assert_eq!(sessions[2].ts_connected, UnixNanos::from(20));
+        assert_eq!(WsReplaySession::from_events(&events, None).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay_http() {
+        let events = vec![
+            exchange("GET", "https://example.com/time?ts=1", "first", 1_000_000),
+            exchange("GET", "https://example.com/time?ts=2", "second", 0),
+            exchange("POST", "https://example.com/orders", "created", 0),
+        ];
+        let server = HttpReplayServer::start(&events, 1.0).await.unwrap();
+        let base_url = server.base_url();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("http.jsonl");
+        let recorder = Recorder::new(&path).unwrap();
+        let client = HttpClient::new(HashMap::new(), vec![], vec![], None, None)
+            .with_recorder(recorder.clone());
+
+        // Exact match, then fallback on path when the query differs
+        let resp1 = client
+            .request(
+                Method::GET,
+                format!("{base_url}/time?ts=2"),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let resp2 = client
+            .request(
+                Method::GET,
+                format!("{base_url}/time?ts=9"),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let resp3 = client
+            .request(
+                Method::POST,
+                format!("{base_url}/orders"),
+                None,
+                Some(b"{}".to_vec()),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let resp4 = client
+            .request(
+                Method::GET,
+                format!("{base_url}/time"),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resp1.body.as_ref(), b"second");
+        assert_eq!(resp2.body.as_ref(), b"first");
+        assert_eq!(resp3.body.as_ref(), b"created");
+        assert_eq!(resp4.status.as_u16(), 404);
+        assert_eq!(server.remaining(), 0);
+
+        recorder.flush().unwrap();
+        let recorded = load_recording(&path).unwrap();
+        assert_eq!(recorded.len(), 4);
+        let RecordedEvent::HttpExchange(post) = &recorded[2] else {
+            panic!("Expected HTTP exchange");
+        };
+        assert_eq!(post.method, "POST");
+        assert_eq!(post.request_body.as_deref(), Some("e30="));
+        assert_eq!(post.response_body, STANDARD.encode("created"));
+    }
+
+    #[tokio::test]
+    async fn test_replay_http_strips_wire_encoding_headers() {
+        // A chunked, gzip encoded response as captured by the client with its body decoded
+        let body = r#"{"symbol":"BTC-PERP","price":"50000.5"}"#;
+        let events = vec![RecordedEvent::HttpExchange(RecordedHttpExchange {
+            ts: UnixNanos::from(1_000),
+            method: "GET".to_string(),
+            url: "https://api.venue.com/api/v1/ticker?symbol=BTC-PERP".to_string(),
+            request_body: None,
+            status: 200,
+            response_headers: HashMap::from([
+                ("content-type".to_string(), "application/json".to_string()),
+                ("Content-Encoding".to_string(), "gzip".to_string()),
+                ("transfer-encoding".to_string(), "chunked".to_string()),
+                ("content-length".to_string(), "27".to_string()),
+                ("x-ratelimit-remaining".to_string(), "99".to_string()),
+            ]),
+            response_body: STANDARD.encode(body),
+            latency_ns: 0,
+        })];
+        let server = HttpReplayServer::start(&events, 1.0).await.unwrap();
+        let header_keys = [
+            "content-type",
+            "content-encoding",
+            "content-length",
+            "transfer-encoding",
+            "x-ratelimit-remaining",
+        ]
+        .map(String::from)
+        .to_vec();
+        let client = HttpClient::new(HashMap::new(), header_keys, vec![], None, None);
+
+        let resp = client
+            .request(
+                Method::GET,
+                format!("{}/api/v1/ticker?symbol=BTC-PERP", server.base_url()),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status.as_u16(), 200);
+        assert_eq!(resp.body.as_ref(), body.as_bytes());
+        assert_eq!(resp.headers["content-type"], "application/json");
+        assert_eq!(resp.headers["x-ratelimit-remaining"], "99");
+        assert_eq!(resp.headers["content-length"], body.len().to_string());
+        assert!(!resp.headers.contains_key("content-encoding"));
+        assert!(!resp.headers.contains_key("transfer-encoding"));
+    }
+
+    #[tokio::test]
+    async fn test_replay_ws_sessions() {
+        let events = vec![
+            RecordedEvent::WsConnected {
+                ts: UnixNanos::from(0),
+                url: "ws://venue".to_string(),
+            },
+            inbound(1_000_000, "ws://venue", "a"),
+            inbound(50_000_000, "ws://venue", "b"),
+            RecordedEvent::WsConnected {
+                ts: UnixNanos::from(100_000_000),
+                url: "ws://venue".to_string(),
+            },
+            inbound(101_000_000, "ws://venue", "c"),
+        ];
+        let server = WsReplayServer::start(&events, None, 1.0).await.unwrap();
+
+        let (mut stream, _) = connect_async(server.url()).await.unwrap();
+        let start = tokio::time::Instant::now();
+        assert_eq!(
+            stream.next().await.unwrap().unwrap(),
+            Message::Text("a".into())
+        );
+        assert_eq!(
+            stream.next().await.unwrap().unwrap(),
+            Message::Text("b".into())
+        );
+        assert!(start.elapsed() >= Duration::from_millis(45));
+
+        // A reconnect receives the next recorded session
+        let (mut stream, _) = connect_async(server.url()).await.unwrap();
+        assert_eq!(
+            stream.next().await.unwrap().unwrap(),
+            Message::Text("c".into())
+        );
+    }
+}
@@ -60,6 +60,7 @@ use crate::{
     logging::{log_task_aborted, log_task_started, log_task_stopped},
     mode::ConnectionMode,
     ratelimiter::{RateLimiter, clock::MonotonicClock, quota::Quota},
+    recording::Recorder,
 };
 
 type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
@@ -109,6 +110,8 @@ pub struct WebSocketConfig {
     pub reconnect_backoff_factor: Option<f64>,
     /// The maximum jitter (milliseconds) added to reconnection delays.
     pub reconnect_jitter_ms: Option<u64>,
+    /// The optional recorder for connections and text/binary frames in both directions.
+    pub recorder: Option<Recorder>,
 }
 
 /// Represents a command for the writer task.
@@ -165,9 +168,14 @@ impl WebSocketClientInner {
             reconnect_delay_max_ms,
             reconnect_backoff_factor,
             reconnect_jitter_ms,
+            recorder,
         } = &config;
         let (writer, reader) = Self::connect_with_server(url, headers.clone()).await?;
 
+        if let Some(recorder) = recorder {
+            recorder.record_ws_connected(url);
+        }
+
         let connection_mode = Arc::new(AtomicU8::new(ConnectionMode::Active.as_u8()));
 
         let read_task = match &handler {
@@ -178,17 +186,27 @@ impl WebSocketClientInner {
                     reader,
                     handler.clone(),
                     ping_handler.clone(),
+                    recorder.clone(),
+                    url.clone(),
                 )
             }),
             Consumer::Rust(sender) => Some(Self::spawn_rust_streaming_task(
                 connection_mode.clone(),
                 reader,
                 sender.clone(),
+                recorder.clone(),
+                url.clone(),
             )),
         };
 
         let (writer_tx, writer_rx) = tokio::sync::mpsc::unbounded_channel::<WriterCommand>();
-        let write_task = Self::spawn_write_task(connection_mode.clone(), writer, writer_rx);
+        let write_task = Self::spawn_write_task(
+            connection_mode.clone(),
+            writer,
+            writer_rx,
+            recorder.clone(),
+            url.clone(),
+        );
 
         // Optionally spawn a heartbeat task to periodically ping server
         let heartbeat_task = heartbeat.as_ref().map(|heartbeat_secs| {
@@ -264,6 +282,10 @@ impl WebSocketClientInner {
                 return Ok(());
             }
 
+            if let Some(recorder) = &self.config.recorder {
+                recorder.record_ws_connected(&self.config.url);
+            }
+
             if let Err(e) = self.writer_tx.send(WriterCommand::Update(new_writer)) {
                 tracing::error!("{e}");
             }
@@ -301,12 +323,16 @@ impl WebSocketClientInner {
                         reader,
                         handler.clone(),
                         self.config.ping_handler.clone(),
+                        self.config.recorder.clone(),
+                        self.config.url.clone(),
                     )
                 }),
                 Consumer::Rust(sender) => Some(Self::spawn_rust_streaming_task(
                     self.connection_mode.clone(),
                     reader,
                     sender.clone(),
+                    self.config.recorder.clone(),
+                    self.config.url.clone(),
                 )),
             };
 
@@ -345,6 +371,8 @@ impl WebSocketClientInner {
         connection_state: Arc<AtomicU8>,
         mut reader: MessageReader,
         sender: Sender<Message>,
+        recorder: Option<Recorder>,
+        url: String,
     ) -> tokio::task::JoinHandle<()> {
         tracing::debug!("Started streaming task 'read'");
 
@@ -358,6 +386,9 @@ impl WebSocketClientInner {
 
                 match tokio::time::timeout(check_interval, reader.next()).await {
                     Ok(Some(Ok(message))) => {
+                        if let Some(recorder) = &recorder {
+                            recorder.record_ws_inbound(&url, &message);
+                        }
                         if let Err(e) = sender.send(message).await {
                             tracing::error!("Failed to send message: {e}");
                         }
@@ -385,6 +416,8 @@ impl WebSocketClientInner {
         mut reader: MessageReader,
         handler: Arc<PyObject>,
         ping_handler: Option<Arc<PyObject>>,
+        recorder: Option<Recorder>,
+        url: String,
     ) -> tokio::task::JoinHandle<()> {
         log_task_started("read");
 
@@ -397,7 +430,12 @@ impl WebSocketClientInner {
                     break;
                 }
 
-                match tokio::time::timeout(check_interval, reader.next()).await {
+                let next = tokio::time::timeout(check_interval, reader.next()).await;
+                if let (Some(recorder), Ok(Some(Ok(message)))) = (&recorder, &next) {
+                    recorder.record_ws_inbound(&url, message);
+                }
+
+                match next {
                     Ok(Some(Ok(Message::Binary(data)))) => {
                         tracing::trace!("Received message <binary> {} bytes", data.len());
                         if let Err(e) =
@@ -461,6 +499,8 @@ impl WebSocketClientInner {
         connection_state: Arc<AtomicU8>,
         writer: MessageWriter,
         mut writer_rx: tokio::sync::mpsc::UnboundedReceiver<WriterCommand>,
+        recorder: Option<Recorder>,
+        url: String,
     ) -> tokio::task::JoinHandle<()> {
         log_task_started("write");
 
@@ -509,6 +549,9 @@ impl WebSocketClientInner {
                                 continue;
                             }
                             WriterCommand::Send(msg) => {
+                                if let Some(recorder) = &recorder {
+                                    recorder.record_ws_outbound(&url, &msg);
+                                }
                                 if let Err(e) = active_writer.send(msg).await {
                                     tracing::error!("Failed to send message: {e}");
                                     // Mode is active so trigger reconnection
@@ -1118,6 +1161,7 @@ mod tests {
             reconnect_backoff_factor: None,
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
+            recorder: None,
         };
         WebSocketClient::connect(config, None, None, None, vec![], None)
             .await
@@ -1162,6 +1206,7 @@ mod tests {
             reconnect_backoff_factor: None,
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
+            recorder: None,
         };
         let res = WebSocketClient::connect(config, None, None, None, vec![], None).await;
         assert!(res.is_err(), "Should fail quickly with no server");
@@ -1206,6 +1251,7 @@ mod tests {
             reconnect_backoff_factor: None,
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
+            recorder: None,
         };
 
         let client = WebSocketClient::connect(
@@ -1297,6 +1343,7 @@ mod rust_tests {
             reconnect_delay_max_ms: Some(100),
             reconnect_backoff_factor: Some(1.0),
             reconnect_jitter_ms: Some(0),
+            recorder: None,
         };
 
         // Connect the client
@@ -1322,4 +1369,84 @@ mod rust_tests {
         assert!(client.is_disconnected());
         server.abort();
     }
+    #[tokio::test]
+    async fn test_record_replayed_session() {
+        use crate::recording::{
+            RecordedEvent, RecordedFrame, Recorder, WsReplayServer, load_recording,
+        };
+
+        let recorded = vec![
+            RecordedEvent::WsConnected {
+                ts: 0.into(),
+                url: "wss://venue/ws".to_string(),
+            },
+            RecordedEvent::WsInbound {
+                ts: 1_000_000.into(),
+                url: "wss://venue/ws".to_string(),
+                frame: RecordedFrame::Text("hello".to_string()),
+            },
+        ];
+        let server = WsReplayServer::start(&recorded, None, 1.0).await.unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ws.jsonl");
+        let (consumer, mut rx) = Consumer::rust_consumer();
+        let recorder = Recorder::new(&path).unwrap();
+        let config = WebSocketConfig {
+            url: server.url(),
+            headers: vec![],
+            handler: consumer,
+            heartbeat: None,
+            heartbeat_msg: None,
+            #[cfg(feature = "python")]
+            ping_handler: None,
+            reconnect_timeout_ms: None,
+            reconnect_delay_initial_ms: None,
+            reconnect_delay_max_ms: None,
+            reconnect_backoff_factor: None,
+            reconnect_jitter_ms: None,
+            recorder: Some(recorder.clone()),
+        };
+
+        let client = {
+            #[cfg(feature = "python")]
+            {
+                WebSocketClient::connect(config, None, None, None, vec![], None)
+                    .await
+                    .unwrap()
+            }
+            #[cfg(not(feature = "python"))]
+            {
+                WebSocketClient::connect(config, vec![], None)
+                    .await
+                    .unwrap()
+            }
+        };
+
+        let msg = tokio::time::timeout(Duration::from_secs(1), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(msg, Message::Text("hello".into()));
+
+        client
+            .send_text("subscribe".to_string(), None)
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(100)).await;
+        client.disconnect().await;
+        recorder.flush().unwrap();
+
+        let events = load_recording(&path).unwrap();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], RecordedEvent::WsConnected { .. }));
+        assert!(matches!(
+            &events[1],
+            RecordedEvent::WsInbound { frame: RecordedFrame::Text(text), .. } if text == "hello"
+        ));
+        assert!(matches!(
+            &events[2],
+            RecordedEvent::WsOutbound { frame: RecordedFrame::Text(text), .. } if text == "subscribe"
+        ));
+    }
 }
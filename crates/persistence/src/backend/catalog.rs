@@ -13,51 +13,196 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{fmt::Debug, path::PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    future::Future,
+    io::Cursor,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use datafusion::arrow::record_batch::RecordBatch;
+use bytes::Bytes;
+use datafusion::arrow::{ipc::reader::StreamReader, record_batch::RecordBatch};
+use futures::TryStreamExt;
 use heck::ToSnakeCase;
 use itertools::Itertools;
-use log::info;
+use log::{info, warn};
 use nautilus_core::UnixNanos;
-use nautilus_model::data::{
-    Bar, Data, GetTsInit, IndexPriceUpdate, MarkPriceUpdate, OrderBookDelta, OrderBookDepth10,
-    QuoteTick, TradeTick, close::InstrumentClose,
+use nautilus_model::{
+    data::{
+        Bar, CustomDataAny, Data, GetTsInit, IndexPriceUpdate, MarkPriceUpdate, OrderBookDelta,
+        OrderBookDepth10, QuoteTick, TradeTick, close::InstrumentClose,
+    },
+    events::{AccountState, OrderEventAny, PositionSnapshot},
+    instruments::InstrumentAny,
 };
 use nautilus_serialization::{
-    arrow::{DecodeDataFromRecordBatch, EncodeToRecordBatch},
+    arrow::{
+        ArrowSchemaProvider, DecodeDataFromRecordBatch, DecodeFromRecordBatch, EncodeToRecordBatch,
+    },
     enums::ParquetWriteMode,
-    parquet::{combine_data_files, min_max_from_parquet_metadata, write_batches_to_parquet},
+    parquet::{min_max_from_parquet_reader, write_batches_to_bytes},
 };
+use object_store::{ObjectStore, local::LocalFileSystem, path::Path as ObjectPath};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use serde::Serialize;
+use url::Url;
+
+use super::{
+    custom::get_custom_data_registration,
+    manifest::{CatalogManifest, FileBounds, MANIFEST_FILENAME},
+    partition::{
+        CatalogPartitioning, filter_by_ts_init, merge_batches, split_by_partition, ts_init_bounds,
+        ts_init_column,
+    },
+    session::{self, DataBackendSession, DecodeDataFn, QueryResult, build_query},
+    store::create_object_store,
+};
+use crate::config::DataCatalogConfig;
 
-use super::session::{self, DataBackendSession, QueryResult, build_query};
-
+/// A data catalog of Parquet files stored in an [`ObjectStore`], such as the local
+/// file system, S3, GCS or Azure Blob Storage.
 pub struct ParquetDataCatalog {
-    base_path: PathBuf,
+    store: Arc<dyn ObjectStore>,
+    store_url: Url,
+    base_path: ObjectPath,
     batch_size: usize,
+    partitioning: CatalogPartitioning,
     session: DataBackendSession,
+    table_count: usize,
 }
 
 impl Debug for ParquetDataCatalog {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct(stringify!(ParquetDataCatalog))
-            .field("base_path", &self.base_path)
+            .field("store_url", &self.store_url.as_str())
+            .field("base_path", &self.base_path.as_ref())
+            .field("partitioning", &self.partitioning)
             .finish()
     }
 }
 
+/// A summary of the data stored in a catalog data directory.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CatalogDataSummary {
+    /// The data type path prefix, e.g. `quotes`.
+    pub type_name: String,
+    /// The instrument ID or bar type the data is stored for, if any.
+    pub identifier: Option<String>,
+    /// The number of parquet files.
+    pub file_count: usize,
+    /// The total number of rows in the files.
+    pub row_count: u64,
+    /// The minimum `ts_init` (UNIX nanoseconds) of the data.
+    pub start: u64,
+    /// The maximum `ts_init` (UNIX nanoseconds) of the data.
+    pub end: u64,
+}
+
 impl ParquetDataCatalog {
+    /// Creates a new [`ParquetDataCatalog`] instance rooted at the local `base_path`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base_path` cannot be resolved to an absolute path.
     #[must_use]
     pub fn new(base_path: PathBuf, batch_size: Option<usize>) -> Self {
+        let base_path = std::path::absolute(&base_path)
+            .ok()
+            .and_then(|path| ObjectPath::from_absolute_path(path).ok())
+            .unwrap_or_else(|| panic!("Invalid catalog path {base_path:?}"));
+        let store_url = Url::parse("file:///").expect("Invalid URL");
+
+        Self::from_object_store(
+            Arc::new(LocalFileSystem::new()),
+            store_url,
+            base_path,
+            batch_size,
+        )
+    }
+
+    /// Creates a new [`ParquetDataCatalog`] instance from a catalog `uri`, e.g.
+    /// `s3://bucket/catalog`, `gs://bucket/catalog`, `az://container/catalog`,
+    /// `memory:///catalog` or `file:///path/to/catalog`.
+    ///
+    /// A `uri` without a scheme is treated as a local path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object store for `uri` cannot be created.
+    pub fn from_uri(
+        uri: &str,
+        storage_options: Option<HashMap<String, String>>,
+        batch_size: Option<usize>,
+    ) -> anyhow::Result<Self> {
+        if !uri.contains("://") {
+            return Ok(Self::new(PathBuf::from(uri), batch_size));
+        }
+
+        let (store, store_url, base_path) = create_object_store(uri, storage_options)?;
+        Ok(Self::from_object_store(
+            store, store_url, base_path, batch_size,
+        ))
+    }
+
+    /// Creates a new [`ParquetDataCatalog`] instance from the catalog `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object store for the configured URI cannot be created.
+    pub fn from_config(
+        config: &DataCatalogConfig,
+        batch_size: Option<usize>,
+    ) -> anyhow::Result<Self> {
+        let catalog = Self::from_uri(&config.uri(), config.storage_options().cloned(), batch_size)?;
+        Ok(catalog.with_partitioning(config.partitioning()))
+    }
+
+    /// Creates a new [`ParquetDataCatalog`] instance rooted at `base_path` within `store`,
+    /// where `store_url` is the scheme and authority the store is registered under
+    /// for queries (e.g. `s3://bucket`).
+    #[must_use]
+    pub fn from_object_store(
+        store: Arc<dyn ObjectStore>,
+        store_url: Url,
+        base_path: ObjectPath,
+        batch_size: Option<usize>,
+    ) -> Self {
         let batch_size = batch_size.unwrap_or(5000);
+        let mut session = session::DataBackendSession::new(batch_size);
+        session.register_object_store(&store_url, store.clone());
+
         Self {
+            store,
+            store_url,
             base_path,
             batch_size,
-            session: session::DataBackendSession::new(batch_size),
+            partitioning: CatalogPartitioning::default(),
+            session,
+            table_count: 0,
         }
     }
 
+    /// Sets the time based partitioning of the files written to each data directory.
+    #[must_use]
+    pub const fn with_partitioning(mut self, partitioning: CatalogPartitioning) -> Self {
+        self.partitioning = partitioning;
+        self
+    }
+
+    /// Returns the time based partitioning of the files written to each data directory.
+    #[must_use]
+    pub const fn partitioning(&self) -> CatalogPartitioning {
+        self.partitioning
+    }
+
+    /// Returns the URI of the catalog root.
+    #[must_use]
+    pub fn uri(&self) -> String {
+        self.object_uri(&self.base_path)
+    }
+
     pub fn write_data_enum(&self, data: Vec<Data>, write_mode: Option<ParquetWriteMode>) {
         let mut deltas: Vec<OrderBookDelta> = Vec::new();
         let mut depth10s: Vec<OrderBookDepth10> = Vec::new();
@@ -67,6 +212,7 @@ impl ParquetDataCatalog {
         let mut mark_prices: Vec<MarkPriceUpdate> = Vec::new();
         let mut index_prices: Vec<IndexPriceUpdate> = Vec::new();
         let mut closes: Vec<InstrumentClose> = Vec::new();
+        let mut customs: Vec<CustomDataAny> = Vec::new();
 
         for d in data.iter().cloned() {
             match d {
@@ -95,6 +241,9 @@ impl ParquetDataCatalog {
                 Data::InstrumentClose(c) => {
                     closes.push(c);
                 }
+                Data::Custom(c) => {
+                    customs.push(c);
+                }
             }
         }
 
@@ -106,8 +255,15 @@ impl ParquetDataCatalog {
         let _ = self.write_to_parquet(mark_prices, None, None, None, write_mode);
         let _ = self.write_to_parquet(index_prices, None, None, None, write_mode);
         let _ = self.write_to_parquet(closes, None, None, None, write_mode);
+        let _ = self.write_custom_data(customs, write_mode);
     }
 
+    /// Writes `data` to `path`, or a file within the data directory for the type and
+    /// instrument, returning the path written.
+    ///
+    /// When the catalog is partitioned and no `path` is given, the data is instead merged
+    /// into one file per partition, removing duplicate rows, and the data directory is
+    /// returned. The `write_mode` does not apply to partitioned writes.
     pub fn write_to_parquet<T>(
         &self,
         data: Vec<T>,
@@ -122,20 +278,151 @@ impl ParquetDataCatalog {
         let type_name = std::any::type_name::<T>().to_snake_case();
         Self::check_ascending_timestamps(&data, &type_name);
         let batches = self.data_to_record_batches(data)?;
-        let schema = batches.first().expect("Batches are empty.").schema();
-        let instrument_id = schema.metadata.get("instrument_id").cloned();
-        let new_path = self.make_path(T::path_prefix(), instrument_id, write_mode)?;
-        let path = path.unwrap_or(new_path);
+
+        self.write_record_batches(
+            &batches,
+            T::path_prefix(),
+            &type_name,
+            path,
+            compression,
+            max_row_group_size,
+            write_mode,
+        )
+    }
+
+    /// Writes custom data values of registered custom data types, grouped by type and
+    /// instrument, returning the paths of the files written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value's type is not registered, or encoding or writing fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the values of a type and instrument are not in ascending `ts_init` order.
+    pub fn write_custom_data(
+        &self,
+        data: Vec<CustomDataAny>,
+        write_mode: Option<ParquetWriteMode>,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let groups = data
+            .into_iter()
+            .into_group_map_by(|custom| (custom.type_name(), custom.instrument_id()));
+        let mut paths = Vec::new();
+
+        for ((type_name, _), values) in groups.into_iter().sorted_by_key(|(key, _)| *key) {
+            let registration = get_custom_data_registration(type_name)
+                .ok_or_else(|| anyhow::anyhow!("Custom data type `{type_name}` not registered"))?;
+            Self::check_ascending_timestamps(&values, type_name);
+            let batches = registration.encode(&values, self.batch_size)?;
+
+            paths.push(self.write_record_batches(
+                &batches,
+                &registration.path_prefix,
+                type_name,
+                None,
+                None,
+                None,
+                write_mode,
+            )?);
+        }
+
+        Ok(paths)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn write_record_batches(
+        &self,
+        batches: &[RecordBatch],
+        path_prefix: &str,
+        type_name: &str,
+        path: Option<PathBuf>,
+        compression: Option<parquet::basic::Compression>,
+        max_row_group_size: Option<usize>,
+        write_mode: Option<ParquetWriteMode>,
+    ) -> anyhow::Result<PathBuf> {
+        let Some(first) = batches.first() else {
+            anyhow::bail!("No {type_name} data to write");
+        };
+        let schema = first.schema();
+        let identifier = Self::identifier_from_metadata(&schema.metadata);
+        let path = match path {
+            Some(path) => self.to_object_path(&path)?,
+            None if self.partitioning != CatalogPartitioning::None => {
+                let directory = self.make_directory_path(path_prefix, identifier);
+                info!(
+                    "Writing {} batches of {type_name} data to partitions in {directory}",
+                    batches.len()
+                );
+                self.write_partitions(&directory, batches)?;
+                return Ok(self.to_external_path(&directory));
+            }
+            None => self.make_path(path_prefix, identifier, write_mode)?,
+        };
 
         // Write all batches to parquet file
         info!(
-            "Writing {} batches of {type_name} data to {path:?}",
+            "Writing {} batches of {type_name} data to {path}",
+            batches.len()
+        );
+
+        self.write_batches(batches, &path, compression, max_row_group_size, write_mode)?;
+
+        Ok(self.to_external_path(&path))
+    }
+
+    /// Writes `data` covering the `start` to `end` (inclusive, UNIX nanoseconds) interval
+    /// to a new file named after the interval, so the coverage can be recovered even
+    /// when no data exists at the interval bounds.
+    ///
+    /// When `data` is empty an empty file with the schema of `metadata` is written, so
+    /// intervals without data (e.g. weekends or halts) are also recorded as covered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encoding or writing the data fails.
+    pub fn write_interval<T>(
+        &self,
+        data: Vec<T>,
+        metadata: &HashMap<String, String>,
+        start: u64,
+        end: u64,
+    ) -> anyhow::Result<PathBuf>
+    where
+        T: GetTsInit + EncodeToRecordBatch + CatalogPathPrefix,
+    {
+        let type_name = std::any::type_name::<T>().to_snake_case();
+        let batches = if data.is_empty() {
+            let schema = T::get_schema(Some(metadata.clone()));
+            vec![RecordBatch::new_empty(Arc::new(schema))]
+        } else {
+            Self::check_ascending_timestamps(&data, &type_name);
+            self.data_to_record_batches(data)?
+        };
+        let schema = batches.first().expect("Batches are empty.").schema();
+        let identifier = Self::identifier_from_metadata(&schema.metadata);
+        let path = self
+            .make_directory_path(T::path_prefix(), identifier)
+            .child(format!("{start}-{end}.parquet"));
+
+        info!(
+            "Writing {} batches of {type_name} data to {path}",
             batches.len()
         );
 
-        write_batches_to_parquet(&batches, &path, compression, max_row_group_size, write_mode)?;
+        self.write_batches(&batches, &path, None, None, None)?;
+
+        Ok(self.to_external_path(&path))
+    }
 
-        Ok(path)
+    /// Returns the identifier of the data directory from the batch `metadata`, which is
+    /// the bar type for bars (as they also carry their instrument ID) and otherwise the
+    /// instrument ID.
+    fn identifier_from_metadata(metadata: &HashMap<String, String>) -> Option<String> {
+        metadata
+            .get("bar_type")
+            .or_else(|| metadata.get("instrument_id"))
+            .cloned()
     }
 
     fn check_ascending_timestamps<T: GetTsInit>(data: &[T], type_name: &str) {
@@ -164,40 +451,45 @@ impl ParquetDataCatalog {
     fn make_path(
         &self,
         type_name: &str,
-        instrument_id: Option<String>,
+        identifier: Option<String>,
         write_mode: Option<ParquetWriteMode>,
-    ) -> anyhow::Result<PathBuf> {
-        let path = self.make_directory_path(type_name, instrument_id);
-        std::fs::create_dir_all(&path)?;
+    ) -> anyhow::Result<ObjectPath> {
+        let directory = self.make_directory_path(type_name, identifier);
+        let existing: HashSet<String> = self
+            .list_parquet_files(&directory)?
+            .iter()
+            .filter_map(|path| path.filename().map(str::to_string))
+            .collect();
         let used_write_mode = write_mode.unwrap_or(ParquetWriteMode::Overwrite);
-        let mut file_path = path.join("part-0.parquet");
-        let mut empty_path = file_path.clone();
         let mut i = 0;
 
-        while empty_path.exists() {
+        while existing.contains(&format!("part-{i}.parquet")) {
             i += 1;
-            let name = format!("part-{i}.parquet");
-            empty_path = path.join(name);
         }
 
         if i > 1 && used_write_mode != ParquetWriteMode::NewFile {
             anyhow::bail!(
                 "Only ParquetWriteMode::NewFile is allowed for a directory containing several parquet files."
             );
-        } else if used_write_mode == ParquetWriteMode::NewFile {
-            file_path = empty_path;
         }
 
-        info!("Created directory path: {file_path:?}");
+        let file_index = if used_write_mode == ParquetWriteMode::NewFile {
+            i
+        } else {
+            0
+        };
+        let file_path = directory.child(format!("part-{file_index}.parquet"));
+
+        info!("Created directory path: {file_path}");
 
         Ok(file_path)
     }
 
-    fn make_directory_path(&self, type_name: &str, instrument_id: Option<String>) -> PathBuf {
-        let mut path = self.base_path.join("data").join(type_name);
+    fn make_directory_path(&self, type_name: &str, identifier: Option<String>) -> ObjectPath {
+        let mut path = self.base_path.child("data").child(type_name);
 
-        if let Some(id) = instrument_id {
-            path = path.join(id.replace('/', "")); // for FX symbols like EUR/USD
+        if let Some(id) = identifier {
+            path = path.child(id.replace('/', "")); // for FX symbols like EUR/USD
         }
 
         path
@@ -214,26 +506,28 @@ impl ParquetDataCatalog {
     {
         let type_name = std::any::type_name::<T>().to_snake_case();
         Self::check_ascending_timestamps(&data, &type_name);
-        let new_path = self.make_path(T::path_prefix(), None, None)?;
-        let json_path = path.unwrap_or(new_path.with_extension("json"));
+        let json_path = match path {
+            Some(path) => self.to_object_path(&path)?,
+            None => with_extension(&self.make_path(T::path_prefix(), None, None)?, "json"),
+        };
 
         info!(
-            "Writing {} records of {type_name} data to {json_path:?}",
+            "Writing {} records of {type_name} data to {json_path}",
             data.len(),
         );
 
         if write_metadata {
             let metadata = T::chunk_metadata(&data);
-            let metadata_path = json_path.with_extension("metadata.json");
-            info!("Writing metadata to {metadata_path:?}");
-            let metadata_file = std::fs::File::create(&metadata_path)?;
-            serde_json::to_writer_pretty(metadata_file, &metadata)?;
+            let metadata_path = with_extension(&json_path, "metadata.json");
+            info!("Writing metadata to {metadata_path}");
+            let bytes = serde_json::to_vec_pretty(&metadata)?;
+            self.block_on(self.store.put(&metadata_path, bytes.into()))?;
         }
 
-        let file = std::fs::File::create(&json_path)?;
-        serde_json::to_writer_pretty(file, &serde_json::to_value(data)?)?;
+        let bytes = serde_json::to_vec_pretty(&serde_json::to_value(data)?)?;
+        self.block_on(self.store.put(&json_path, bytes.into()))?;
 
-        Ok(json_path)
+        Ok(self.to_external_path(&json_path))
     }
 
     pub fn consolidate_data(
@@ -241,73 +535,480 @@ impl ParquetDataCatalog {
         type_name: &str,
         instrument_id: Option<String>,
     ) -> anyhow::Result<()> {
-        let parquet_files = self.query_parquet_files(type_name, instrument_id)?;
+        let directory = self.make_directory_path(type_name, instrument_id);
+        self.consolidate_directory(&directory)
+    }
 
-        if !parquet_files.is_empty() {
-            combine_data_files(parquet_files, "ts_init", None, None)?;
+    pub fn consolidate_catalog(&self) -> anyhow::Result<()> {
+        for directory in self.leaf_directories()? {
+            self.consolidate_directory(&directory)?;
         }
 
         Ok(())
     }
 
-    pub fn consolidate_catalog(&self) -> anyhow::Result<()> {
-        let leaf_directories = self.find_leaf_data_directories()?;
-
-        for directory in leaf_directories {
-            let parquet_files: Vec<PathBuf> = std::fs::read_dir(directory)?
-                .filter_map(|entry| {
-                    let path = entry.ok()?.path();
-
-                    if path.extension().and_then(|s| s.to_str()) == Some("parquet") {
-                        Some(path)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            if !parquet_files.is_empty() {
-                combine_data_files(parquet_files, "ts_init", None, None)?;
+    /// Deletes the data stored for `type_name` and `identifier` with `ts_init` between the
+    /// optional `start` and `end` (inclusive).
+    ///
+    /// Files partially within the range are rewritten with the data remaining before and
+    /// after it, named after the intervals they still cover.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the files cannot be read, written or deleted.
+    pub fn delete_data_range(
+        &self,
+        type_name: &str,
+        identifier: Option<String>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<()> {
+        let directory = self.make_directory_path(type_name, identifier);
+        self.delete_directory_range(&directory, start, end)
+    }
+
+    /// Deletes all data in the catalog with `ts_init` between the optional `start` and
+    /// `end` (inclusive), e.g. with no `start` to enforce a retention period.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the files cannot be read, written or deleted.
+    pub fn delete_catalog_range(
+        &self,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<()> {
+        for directory in self.leaf_directories()? {
+            self.delete_directory_range(&directory, start, end)?;
+        }
+
+        Ok(())
+    }
+
+    /// Rebuilds the manifest of every data directory from the parquet file metadata,
+    /// e.g. after files were written to the catalog by other tools.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the files cannot be read or a manifest cannot be written.
+    pub fn rebuild_manifests(&self) -> anyhow::Result<()> {
+        for directory in self.leaf_directories()? {
+            let mut manifest = CatalogManifest::default();
+
+            for file in self.list_parquet_files(&directory)? {
+                let filename = file.filename().unwrap_or_default();
+                manifest.insert(filename, self.footer_bounds(&file)?);
             }
+
+            self.write_manifest(&directory, &manifest)?;
         }
 
         Ok(())
     }
 
-    pub fn find_leaf_data_directories(&self) -> anyhow::Result<Vec<PathBuf>> {
-        let mut all_paths: Vec<PathBuf> = Vec::new();
-        let data_dir = self.base_path.join("data");
+    /// Converts the feather files streamed by the instance `instance_id` under the
+    /// `subdirectory` of the catalog (e.g. `live` or `backtest`) into catalog data.
+    ///
+    /// The streamed batches of each data type and identifier are merged in `ts_init`
+    /// order, without duplicate rows, and written to a new file in the data directory
+    /// (or merged into the partitions of a partitioned catalog). The feather files are
+    /// left in place, so converting the same stream twice duplicates the data of an
+    /// unpartitioned catalog.
+    ///
+    /// Returns the paths written to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the feather files cannot be read or the data cannot
+    /// be written.
+    pub fn convert_stream_to_data(
+        &self,
+        instance_id: &str,
+        subdirectory: &str,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let stream_dir = self.base_path.child(subdirectory).child(instance_id);
+        let mut groups: HashMap<(String, Option<String>), Vec<RecordBatch>> = HashMap::new();
+
+        for file in self.list_feather_files_recursive(&stream_dir)? {
+            let Some(path_prefix) = stream_path_prefix(&stream_dir, &file) else {
+                warn!("Skipping feather file {file} outside of a data type directory");
+                continue;
+            };
+
+            let batches = self.read_stream_batches(&file)?;
+            let Some(first) = batches.first() else {
+                continue;
+            };
+            let identifier = Self::identifier_from_metadata(&first.schema().metadata);
+
+            groups
+                .entry((path_prefix, identifier))
+                .or_default()
+                .extend(batches);
+        }
+
+        let mut paths = Vec::new();
 
-        for entry in walkdir::WalkDir::new(data_dir) {
-            all_paths.push(entry?.path().to_path_buf());
+        for ((path_prefix, _), batches) in groups.into_iter().sorted_by(|a, b| a.0.cmp(&b.0)) {
+            let Some(batch) = merge_batches(&batches)? else {
+                continue;
+            };
+
+            paths.push(self.write_record_batches(
+                &[batch],
+                &path_prefix,
+                &path_prefix,
+                None,
+                None,
+                None,
+                Some(ParquetWriteMode::NewFile),
+            )?);
         }
 
-        let all_dirs = all_paths
-            .iter()
-            .filter(|p| p.is_dir())
-            .cloned()
-            .collect::<Vec<PathBuf>>();
-        let mut leaf_dirs = Vec::new();
-
-        for directory in all_dirs {
-            let items = std::fs::read_dir(&directory)?;
-            let has_subdirs = items.into_iter().any(|entry| {
-                let entry = entry.unwrap();
-                entry.path().is_dir()
-            });
-            let has_files = std::fs::read_dir(&directory)?.any(|entry| {
-                let entry = entry.unwrap();
-                entry.path().is_file()
+        Ok(paths)
+    }
+
+    /// Returns a summary of the data in each data directory of the catalog, sorted by
+    /// data type and identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the directories or file metadata cannot be read.
+    pub fn list_data(&self) -> anyhow::Result<Vec<CatalogDataSummary>> {
+        let data_dir = self.base_path.child("data");
+        let mut summaries = Vec::new();
+
+        for directory in self.leaf_directories()? {
+            let files = self.list_parquet_files(&directory)?;
+            let bounds = self.file_bounds(&directory, &files)?;
+            let (Some(start), Some(end)) = (
+                bounds.iter().map(|(_, bounds)| bounds.start).min(),
+                bounds.iter().map(|(_, bounds)| bounds.end).max(),
+            ) else {
+                continue; // No data files
+            };
+
+            let mut parts = directory
+                .prefix_match(&data_dir)
+                .into_iter()
+                .flatten()
+                .map(|part| part.as_ref().to_string());
+            let Some(type_name) = parts.next() else {
+                continue;
+            };
+
+            summaries.push(CatalogDataSummary {
+                type_name,
+                identifier: parts.next(),
+                file_count: files.len(),
+                row_count: files
+                    .iter()
+                    .map(|file| self.footer_row_count(file))
+                    .sum::<anyhow::Result<u64>>()?,
+                start,
+                end,
             });
+        }
 
-            if has_files && !has_subdirs {
-                leaf_dirs.push(directory);
+        Ok(summaries)
+    }
+
+    /// Returns the gaps between consecutive `ts_init` values of the data stored for
+    /// `type_name` and `identifier` which are longer than `min_gap` nanoseconds, as
+    /// intervals (inclusive, UNIX nanoseconds) from the last value before each gap to the
+    /// first value after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the files cannot be read.
+    pub fn find_gaps(
+        &self,
+        type_name: &str,
+        identifier: Option<String>,
+        min_gap: u64,
+    ) -> anyhow::Result<Vec<(u64, u64)>> {
+        let directory = self.make_directory_path(type_name, identifier);
+        let mut timestamps = Vec::new();
+
+        for file in self.list_parquet_files(&directory)? {
+            for batch in self.read_batches(&file)? {
+                timestamps.extend_from_slice(ts_init_column(&batch)?.values());
             }
         }
+        timestamps.sort_unstable();
+
+        Ok(timestamps
+            .windows(2)
+            .filter(|pair| pair[1] - pair[0] > min_gap)
+            .map(|pair| (pair[0], pair[1]))
+            .collect())
+    }
+
+    pub fn find_leaf_data_directories(&self) -> anyhow::Result<Vec<PathBuf>> {
+        Ok(self
+            .leaf_directories()?
+            .iter()
+            .map(|directory| self.to_external_path(directory))
+            .collect())
+    }
+
+    /// Returns the directories under `data` which contain files but no subdirectories.
+    fn leaf_directories(&self) -> anyhow::Result<Vec<ObjectPath>> {
+        let data_dir = self.base_path.child("data");
+        let objects: Vec<ObjectPath> = self.block_on(
+            self.store
+                .list(Some(&data_dir))
+                .map_ok(|meta| meta.location)
+                .try_collect(),
+        )?;
+
+        let directories: HashSet<ObjectPath> = objects.iter().filter_map(parent_path).collect();
+        let mut leaf_dirs: Vec<ObjectPath> = directories
+            .iter()
+            .filter(|directory| {
+                !directories
+                    .iter()
+                    .any(|other| other != *directory && other.prefix_matches(directory))
+            })
+            .cloned()
+            .collect();
+        leaf_dirs.sort();
 
         Ok(leaf_dirs)
     }
 
+    /// Combines `files` into the file with the earliest `column_name` values, after checking
+    /// the files do not overlap, then deletes the other files.
+    fn combine_files(&self, files: Vec<ObjectPath>, column_name: &str) -> anyhow::Result<()> {
+        // Files recording intervals without data are kept, as they have no bounds to merge
+        let mut non_empty = Vec::with_capacity(files.len());
+        for file in files {
+            if self.footer_row_count(&file)? > 0 {
+                non_empty.push(file);
+            }
+        }
+        let files = non_empty;
+
+        if files.len() <= 1 {
+            return Ok(());
+        }
+
+        let mut min_max_per_file = Vec::with_capacity(files.len());
+        for file in files {
+            let (min, max) = min_max_from_parquet_reader(self.get_bytes(&file)?, column_name)?;
+            min_max_per_file.push((min, max, file));
+        }
+        min_max_per_file.sort_by_key(|(min, _, _)| *min);
+
+        for pair in min_max_per_file.windows(2) {
+            if pair[0].1 >= pair[1].0 {
+                anyhow::bail!(
+                    "Merging not safe due to intersection of timestamps between files. Aborting."
+                );
+            }
+        }
+
+        let mut all_batches = Vec::new();
+        for (_, _, file) in &min_max_per_file {
+            all_batches.extend(self.read_batches(file)?);
+        }
+
+        let target = &min_max_per_file[0].2;
+        self.write_batches(&all_batches, target, None, None, None)?;
+
+        for (_, _, file) in min_max_per_file.iter().skip(1) {
+            self.delete_file(file)?;
+        }
+
+        Ok(())
+    }
+
+    /// Combines the files of `directory` into a single file, or into one file per
+    /// partition when the catalog is partitioned.
+    fn consolidate_directory(&self, directory: &ObjectPath) -> anyhow::Result<()> {
+        if self.partitioning == CatalogPartitioning::None {
+            return self.combine_files(self.list_parquet_files(directory)?, "ts_init");
+        }
+
+        let files = self.file_bounds(directory, &self.list_parquet_files(directory)?)?;
+        let spans: Vec<(u64, u64)> = files
+            .iter()
+            .map(|(_, bounds)| self.partitioning.span(bounds.start, bounds.end))
+            .collect();
+
+        // Files spanning several partitions, or sharing a partition with another file
+        let seeds: Vec<(u64, u64)> = spans
+            .iter()
+            .enumerate()
+            .filter(|&(i, span)| {
+                self.partitioning.bounds(span.0) != *span
+                    || spans
+                        .iter()
+                        .enumerate()
+                        .any(|(j, other)| j != i && spans_intersect(*span, *other))
+            })
+            .map(|(_, span)| *span)
+            .collect();
+
+        if seeds.is_empty() {
+            return Ok(());
+        }
+
+        let files = self.partition_closure(directory, seeds)?;
+        self.rewrite_partitions(directory, &files, Vec::new())
+    }
+
+    /// Merges `batches` into the partitions of `directory`, rewriting the existing files
+    /// which share a partition with the new data.
+    fn write_partitions(
+        &self,
+        directory: &ObjectPath,
+        batches: &[RecordBatch],
+    ) -> anyhow::Result<()> {
+        let Some(merged) = merge_batches(batches)? else {
+            return Ok(());
+        };
+        let seeds = split_by_partition(&merged, self.partitioning)?
+            .into_iter()
+            .map(|(bounds, _)| bounds)
+            .collect();
+        let files = self.partition_closure(directory, seeds)?;
+
+        self.rewrite_partitions(directory, &files, vec![merged])
+    }
+
+    /// Returns the files of `directory` which share a partition with any of the `seeds`
+    /// partition spans, directly or through other such files.
+    fn partition_closure(
+        &self,
+        directory: &ObjectPath,
+        mut seeds: Vec<(u64, u64)>,
+    ) -> anyhow::Result<Vec<ObjectPath>> {
+        let files = self.file_bounds(directory, &self.list_parquet_files(directory)?)?;
+        let mut selected = vec![false; files.len()];
+
+        loop {
+            let mut changed = false;
+
+            for (i, (_, bounds)) in files.iter().enumerate() {
+                let span = self.partitioning.span(bounds.start, bounds.end);
+                if !selected[i] && seeds.iter().any(|seed| spans_intersect(*seed, span)) {
+                    selected[i] = true;
+                    seeds.push(span);
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        Ok(files
+            .into_iter()
+            .zip(selected)
+            .filter_map(|((file, _), is_selected)| is_selected.then_some(file))
+            .collect())
+    }
+
+    /// Replaces `files` with one file per partition of their data merged with `batches`,
+    /// each named after its `ts_init` bounds.
+    fn rewrite_partitions(
+        &self,
+        directory: &ObjectPath,
+        files: &[ObjectPath],
+        batches: Vec<RecordBatch>,
+    ) -> anyhow::Result<()> {
+        let mut all_batches = Vec::new();
+        for file in files {
+            all_batches.extend(self.read_batches(file)?);
+        }
+        all_batches.extend(batches);
+
+        let mut written = Vec::new();
+
+        if let Some(merged) = merge_batches(&all_batches)? {
+            for (_, batch) in split_by_partition(&merged, self.partitioning)? {
+                let (start, end) = ts_init_bounds(std::slice::from_ref(&batch))
+                    .expect("Partition batches are not empty");
+                let path = directory.child(format!("{start}-{end}.parquet"));
+                self.write_batches(&[batch], &path, None, None, None)?;
+                written.push(path);
+            }
+        }
+
+        for file in files.iter().filter(|file| !written.contains(file)) {
+            self.delete_file(file)?;
+        }
+
+        Ok(())
+    }
+
+    fn delete_directory_range(
+        &self,
+        directory: &ObjectPath,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<()> {
+        let start = start.map_or(0, |start| start.as_u64());
+        let end = end.map_or(u64::MAX, |end| end.as_u64());
+        let files = self.list_parquet_files(directory)?;
+
+        for (file, bounds) in self.file_bounds(directory, &files)? {
+            // Interval file names declare the covered interval beyond the data bounds
+            let (covered_start, covered_end) = file
+                .filename()
+                .and_then(parse_interval_filename)
+                .unwrap_or((bounds.start, bounds.end));
+
+            if covered_end < start || covered_start > end {
+                continue;
+            }
+
+            let mut remaining = Vec::new();
+            if covered_start < start {
+                remaining.push((covered_start, start - 1));
+            }
+            if covered_end > end {
+                remaining.push((end + 1, covered_end));
+            }
+
+            if !remaining.is_empty() {
+                let batches = self.read_batches(&file)?;
+                for (remaining_start, remaining_end) in remaining {
+                    self.write_remaining(directory, &batches, remaining_start, remaining_end)?;
+                }
+            }
+
+            self.delete_file(&file)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the rows of `batches` between `start` and `end` (inclusive) to a file
+    /// named after the interval, if there are any.
+    fn write_remaining(
+        &self,
+        directory: &ObjectPath,
+        batches: &[RecordBatch],
+        start: u64,
+        end: u64,
+    ) -> anyhow::Result<()> {
+        let remaining: Vec<RecordBatch> = batches
+            .iter()
+            .map(|batch| filter_by_ts_init(batch, start, end))
+            .filter_ok(|batch| batch.num_rows() > 0)
+            .collect::<anyhow::Result<_>>()?;
+
+        if !remaining.is_empty() {
+            let path = directory.child(format!("{start}-{end}.parquet"));
+            self.write_batches(&remaining, &path, None, None, None)?;
+        }
+
+        Ok(())
+    }
+
     /// Query data loaded in the catalog
     pub fn query_file<T>(
         &mut self,
@@ -344,25 +1045,110 @@ impl ParquetDataCatalog {
         T: DecodeDataFromRecordBatch + CatalogPathPrefix,
     {
         let mut paths = Vec::new();
+        let is_all_instruments = instrument_ids.is_empty();
 
         for instrument_id in instrument_ids {
-            paths.extend(self.query_parquet_files(T::path_prefix(), Some(instrument_id))?);
+            let directory = self.make_directory_path(T::path_prefix(), Some(instrument_id));
+            let files = self.list_parquet_files(&directory)?;
+            paths.extend(self.prune_files(&directory, files, start, end)?);
         }
 
         // If no specific instrument_id is selected query all files for the data type
-        if paths.is_empty() {
+        if is_all_instruments {
             paths.push(self.make_path(T::path_prefix(), None, None)?);
         }
 
         for path in &paths {
-            let path = path.to_str().expect("Failed to convert path to string");
-            let query = build_query(path, start, end, where_clause);
-            self.session.add_file::<T>(path, path, Some(&query))?;
+            self.add_query_file::<T>(path, start, end, where_clause)?;
         }
 
         Ok(self.session.get_query_result())
     }
 
+    /// Queries the registered custom data type named `type_name` for the given
+    /// `identifiers` (all data of the type if empty), returning a [`QueryResult`] merged
+    /// by `ts_init` with any other queries added to the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type is not registered, or any of the files cannot be queried.
+    pub fn query_custom_data(
+        &mut self,
+        type_name: &str,
+        identifiers: Vec<String>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        where_clause: Option<&str>,
+    ) -> anyhow::Result<QueryResult> {
+        let registration = get_custom_data_registration(type_name)
+            .ok_or_else(|| anyhow::anyhow!("Custom data type `{type_name}` not registered"))?;
+        let directory = self.make_directory_path(&registration.path_prefix, None);
+        let mut paths = Vec::new();
+
+        if identifiers.is_empty() {
+            paths.extend(self.list_parquet_files_recursive(&directory)?);
+        } else {
+            for identifier in identifiers {
+                let directory =
+                    self.make_directory_path(&registration.path_prefix, Some(identifier));
+                paths.extend(self.list_parquet_files(&directory)?);
+            }
+        }
+
+        for path in &paths {
+            self.add_query_file_with_decoder(
+                path,
+                &registration.path_prefix,
+                registration.decode,
+                start,
+                end,
+                where_clause,
+            )?;
+        }
+
+        Ok(self.session.get_query_result())
+    }
+
+    fn add_query_file<T>(
+        &mut self,
+        path: &ObjectPath,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        where_clause: Option<&str>,
+    ) -> anyhow::Result<()>
+    where
+        T: DecodeDataFromRecordBatch + CatalogPathPrefix,
+    {
+        self.add_query_file_with_decoder(
+            path,
+            T::path_prefix(),
+            T::decode_data_batch,
+            start,
+            end,
+            where_clause,
+        )
+    }
+
+    fn add_query_file_with_decoder(
+        &mut self,
+        path: &ObjectPath,
+        path_prefix: &str,
+        decode: DecodeDataFn,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        where_clause: Option<&str>,
+    ) -> anyhow::Result<()> {
+        // Table names must be unique within the session and valid SQL identifiers
+        self.table_count += 1;
+        let table_name = format!("{path_prefix}_{}", self.table_count);
+        let query = build_query(&table_name, start, end, where_clause);
+        let uri = self.object_uri(path);
+        self.session
+            .add_file_with_decoder(&table_name, &uri, Some(&query), decode)?;
+
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub fn query_timestamp_bound(
         &self,
@@ -371,25 +1157,20 @@ impl ParquetDataCatalog {
         is_last: Option<bool>,
     ) -> anyhow::Result<Option<i64>> {
         let is_last = is_last.unwrap_or(true);
-        let parquet_files = self.query_parquet_files(data_cls, instrument_id)?;
+        let directory = self.make_directory_path(data_cls, instrument_id);
+        let parquet_files = self.list_parquet_files(&directory)?;
 
         if parquet_files.is_empty() {
             return Ok(None);
         }
 
-        let min_max_per_file: Vec<(i64, i64)> = parquet_files
-            .iter()
-            .map(|file| min_max_from_parquet_metadata(file, "ts_init"))
-            .collect::<Result<Vec<_>, _>>()?;
         let mut timestamps: Vec<i64> = Vec::new();
 
-        for min_max in min_max_per_file {
-            let (min, max) = min_max;
-
+        for (_, bounds) in self.file_bounds(&directory, &parquet_files)? {
             if is_last {
-                timestamps.push(max);
+                timestamps.push(bounds.end as i64);
             } else {
-                timestamps.push(min);
+                timestamps.push(bounds.start as i64);
             }
         }
 
@@ -409,20 +1190,528 @@ impl ParquetDataCatalog {
         type_name: &str,
         instrument_id: Option<String>,
     ) -> anyhow::Result<Vec<PathBuf>> {
-        let path = self.make_directory_path(type_name, instrument_id);
-        let mut files = Vec::new();
-
-        if path.exists() {
-            for entry in std::fs::read_dir(path)? {
-                let path = entry?.path();
-                if path.is_file() && path.extension().unwrap() == "parquet" {
-                    files.push(path);
-                }
+        self.query_parquet_files_in_range(type_name, instrument_id, None, None)
+    }
+
+    /// Returns the parquet files stored for `type_name` and `identifier` which may contain
+    /// data with `ts_init` between the optional `start` and `end` (inclusive).
+    ///
+    /// Files are pruned using the `ts_init` bounds recorded in the directory manifest,
+    /// falling back to the file metadata for files missing from the manifest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory, manifest or file metadata cannot be read.
+    pub fn query_parquet_files_in_range(
+        &self,
+        type_name: &str,
+        identifier: Option<String>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let directory = self.make_directory_path(type_name, identifier);
+        let files = self.list_parquet_files(&directory)?;
+
+        Ok(self
+            .prune_files(&directory, files, start, end)?
+            .iter()
+            .map(|path| self.to_external_path(path))
+            .collect())
+    }
+
+    /// Returns the merged `ts_init` intervals (inclusive, UNIX nanoseconds) covered by the
+    /// data stored for `type_name` and `identifier`.
+    ///
+    /// Files named `{start}-{end}.parquet` contribute their declared interval, any other
+    /// file contributes its min and max `ts_init` from the directory manifest or metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or file metadata cannot be read.
+    pub fn get_intervals(
+        &self,
+        type_name: &str,
+        identifier: Option<String>,
+    ) -> anyhow::Result<Vec<(u64, u64)>> {
+        let directory = self.make_directory_path(type_name, identifier);
+        let mut intervals = Vec::new();
+        let mut unnamed_files = Vec::new();
+
+        for file in self.list_parquet_files(&directory)? {
+            match file.filename().and_then(parse_interval_filename) {
+                Some(interval) => intervals.push(interval),
+                None => unnamed_files.push(file),
             }
         }
 
+        for (_, bounds) in self.file_bounds(&directory, &unnamed_files)? {
+            intervals.push((bounds.start, bounds.end));
+        }
+
+        Ok(merge_intervals(intervals))
+    }
+
+    /// Returns the intervals within `start` to `end` (inclusive, UNIX nanoseconds) which are
+    /// not covered by the data stored for `type_name` and `identifier`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored intervals cannot be determined.
+    pub fn get_missing_intervals(
+        &self,
+        type_name: &str,
+        identifier: Option<String>,
+        start: u64,
+        end: u64,
+    ) -> anyhow::Result<Vec<(u64, u64)>> {
+        let intervals = self.get_intervals(type_name, identifier)?;
+        Ok(missing_intervals(start, end, &intervals))
+    }
+
+    /// Queries all data of type `T` stored for `identifier` with `ts_init` between the
+    /// optional `start` and `end` (inclusive), returned in ascending `ts_init` order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the files cannot be queried.
+    pub fn query_identifier<T>(
+        &mut self,
+        identifier: &str,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Vec<Data>>
+    where
+        T: DecodeDataFromRecordBatch + CatalogPathPrefix,
+    {
+        let directory = self.make_directory_path(T::path_prefix(), Some(identifier.to_string()));
+        let files = self.list_parquet_files(&directory)?;
+        let files = self.prune_files(&directory, files, start, end)?;
+
+        if files.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        for file in &files {
+            self.add_query_file::<T>(file, start, end, None)?;
+        }
+
+        Ok(self.session.get_query_result().collect())
+    }
+
+    /// Queries all values of type `T` with `ts_init` between the optional `start` and
+    /// `end` (inclusive), returned in ascending `ts_init` order.
+    ///
+    /// Unlike the market data queries, this decodes the files directly rather than
+    /// through the query session, so applies to types which are not [`Data`] such as
+    /// instruments, order events, position snapshots and account states.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the files cannot be read or decoded.
+    pub fn query_typed<T>(
+        &self,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Vec<T>>
+    where
+        T: DecodeFromRecordBatch + GetTsInit + CatalogPathPrefix,
+    {
+        let directory = self.make_directory_path(T::path_prefix(), None);
+        let files = self.list_parquet_files(&directory)?;
+        let mut items = Vec::new();
+
+        for file in self.prune_files(&directory, files, start, end)? {
+            for batch in self.read_batches(&file)? {
+                let metadata = batch.schema().metadata().clone();
+                items.extend(T::decode_batch(&metadata, batch)?);
+            }
+        }
+
+        items.retain(|item| {
+            let ts_init = item.ts_init();
+            start.is_none_or(|start| ts_init >= start) && end.is_none_or(|end| ts_init <= end)
+        });
+        items.sort_by_key(GetTsInit::ts_init);
+
+        Ok(items)
+    }
+
+    // -- OBJECT STORE ----------------------------------------------------------------------------
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.session.runtime.block_on(future)
+    }
+
+    /// Returns the parquet files directly within `directory`, sorted by path.
+    fn list_parquet_files(&self, directory: &ObjectPath) -> anyhow::Result<Vec<ObjectPath>> {
+        let listing = self.block_on(self.store.list_with_delimiter(Some(directory)))?;
+        let mut files: Vec<ObjectPath> = listing
+            .objects
+            .into_iter()
+            .map(|meta| meta.location)
+            .filter(|path| path.extension() == Some("parquet"))
+            .collect();
+        files.sort();
+
         Ok(files)
     }
+
+    /// Returns the parquet files within `directory` and all its subdirectories, sorted by path.
+    fn list_parquet_files_recursive(
+        &self,
+        directory: &ObjectPath,
+    ) -> anyhow::Result<Vec<ObjectPath>> {
+        let mut files: Vec<ObjectPath> = self.block_on(
+            self.store
+                .list(Some(directory))
+                .map_ok(|meta| meta.location)
+                .try_filter(|path| futures::future::ready(path.extension() == Some("parquet")))
+                .try_collect(),
+        )?;
+        files.sort();
+
+        Ok(files)
+    }
+
+    /// Returns the feather files within `directory` and all its subdirectories, sorted by path.
+    fn list_feather_files_recursive(
+        &self,
+        directory: &ObjectPath,
+    ) -> anyhow::Result<Vec<ObjectPath>> {
+        let mut files: Vec<ObjectPath> = self.block_on(
+            self.store
+                .list(Some(directory))
+                .map_ok(|meta| meta.location)
+                .try_filter(|path| futures::future::ready(path.extension() == Some("feather")))
+                .try_collect(),
+        )?;
+        files.sort();
+
+        Ok(files)
+    }
+
+    fn get_bytes(&self, path: &ObjectPath) -> anyhow::Result<Bytes> {
+        let bytes = self.block_on(async { self.store.get(path).await?.bytes().await })?;
+        Ok(bytes)
+    }
+
+    fn read_batches(&self, path: &ObjectPath) -> anyhow::Result<Vec<RecordBatch>> {
+        let reader = ParquetRecordBatchReaderBuilder::try_new(self.get_bytes(path)?)?.build()?;
+        Ok(reader.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Reads the batches of the Arrow IPC stream (feather) file at `path`.
+    fn read_stream_batches(&self, path: &ObjectPath) -> anyhow::Result<Vec<RecordBatch>> {
+        let reader = StreamReader::try_new(Cursor::new(self.get_bytes(path)?), None)?;
+        Ok(reader.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Returns the number of rows in the parquet file at `path` from its metadata.
+    fn footer_row_count(&self, path: &ObjectPath) -> anyhow::Result<u64> {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(self.get_bytes(path)?)?;
+        Ok(builder.metadata().file_metadata().num_rows() as u64)
+    }
+
+    fn exists(&self, path: &ObjectPath) -> anyhow::Result<bool> {
+        match self.block_on(self.store.head(path)) {
+            Ok(_) => Ok(true),
+            Err(object_store::Error::NotFound { .. }) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Writes `batches` to `path`, combining them with the existing file for the
+    /// `Append` and `Prepend` write modes.
+    fn write_batches(
+        &self,
+        batches: &[RecordBatch],
+        path: &ObjectPath,
+        compression: Option<parquet::basic::Compression>,
+        max_row_group_size: Option<usize>,
+        write_mode: Option<ParquetWriteMode>,
+    ) -> anyhow::Result<()> {
+        let used_write_mode = write_mode.unwrap_or(ParquetWriteMode::Overwrite);
+        let is_combining = matches!(
+            used_write_mode,
+            ParquetWriteMode::Append | ParquetWriteMode::Prepend
+        );
+
+        let combined;
+        let batches = if is_combining && self.exists(path)? {
+            let existing = self.read_batches(path)?;
+            combined = if used_write_mode == ParquetWriteMode::Append {
+                [existing.as_slice(), batches].concat()
+            } else {
+                [batches, existing.as_slice()].concat()
+            };
+            combined.as_slice()
+        } else {
+            batches
+        };
+
+        let bytes = write_batches_to_bytes(batches, compression, max_row_group_size)?;
+        self.block_on(self.store.put(path, bytes.into()))?;
+
+        let bounds = ts_init_bounds(batches).map(|(start, end)| FileBounds { start, end });
+        self.update_manifest(path, bounds)
+    }
+
+    /// Deletes the file at `path` and its manifest entry.
+    fn delete_file(&self, path: &ObjectPath) -> anyhow::Result<()> {
+        self.block_on(self.store.delete(path))?;
+        self.update_manifest(path, None)
+    }
+
+    /// Returns the URI of `path` within the store, as registered for queries.
+    fn object_uri(&self, path: &ObjectPath) -> String {
+        format!("{}/{path}", self.store_prefix())
+    }
+
+    /// Returns the scheme and authority of the store URL without a trailing slash.
+    fn store_prefix(&self) -> &str {
+        &self.store_url[..url::Position::BeforePath]
+    }
+
+    /// Returns `path` as reported to callers: a file system path for local catalogs,
+    /// otherwise the object URI.
+    fn to_external_path(&self, path: &ObjectPath) -> PathBuf {
+        if self.store_url.scheme() == "file" {
+            PathBuf::from(format!("/{path}"))
+        } else {
+            PathBuf::from(self.object_uri(path))
+        }
+    }
+
+    /// Resolves a caller provided `path` to a path within the store: object URIs are used
+    /// as is, local catalogs resolve file system paths, and relative paths are otherwise
+    /// resolved against the catalog root.
+    fn to_object_path(&self, path: &Path) -> anyhow::Result<ObjectPath> {
+        let path_str = path.to_string_lossy();
+        let store_prefix = format!("{}/", self.store_prefix());
+
+        if let Some(object_path) = path_str.strip_prefix(&store_prefix) {
+            Ok(ObjectPath::parse(object_path)?)
+        } else if self.store_url.scheme() == "file" {
+            Ok(ObjectPath::from_absolute_path(std::path::absolute(path)?)?)
+        } else {
+            Ok(ObjectPath::parse(format!("{}/{path_str}", self.base_path))?)
+        }
+    }
+
+    // -- MANIFEST --------------------------------------------------------------------------------
+
+    /// Returns `files` within `directory` with their `ts_init` bounds from the directory
+    /// manifest, reading the metadata of files missing from the manifest and adding them.
+    fn file_bounds(
+        &self,
+        directory: &ObjectPath,
+        files: &[ObjectPath],
+    ) -> anyhow::Result<Vec<(ObjectPath, FileBounds)>> {
+        let mut manifest = self.read_manifest(directory)?;
+        let mut is_updated = false;
+        let mut bounds_per_file = Vec::with_capacity(files.len());
+
+        for file in files {
+            let filename = file.filename().unwrap_or_default();
+            let bounds = match manifest.get(filename) {
+                Some(bounds) => bounds,
+                None => {
+                    let bounds = self.footer_bounds(file)?;
+                    manifest.insert(filename, bounds);
+                    is_updated = true;
+                    bounds
+                }
+            };
+            bounds_per_file.push((file.clone(), bounds));
+        }
+
+        // Best effort, as reading a catalog should not require write access
+        if is_updated {
+            if let Err(e) = self.write_manifest(directory, &manifest) {
+                warn!("Failed to update manifest for {directory}: {e}");
+            }
+        }
+
+        Ok(bounds_per_file)
+    }
+
+    /// Returns the `files` within `directory` which may contain data with `ts_init`
+    /// between the optional `start` and `end` (inclusive).
+    fn prune_files(
+        &self,
+        directory: &ObjectPath,
+        files: Vec<ObjectPath>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Vec<ObjectPath>> {
+        if start.is_none() && end.is_none() {
+            return Ok(files);
+        }
+
+        let start = start.map_or(0, |start| start.as_u64());
+        let end = end.map_or(u64::MAX, |end| end.as_u64());
+
+        Ok(self
+            .file_bounds(directory, &files)?
+            .into_iter()
+            .filter(|(_, bounds)| bounds.intersects(start, end))
+            .map(|(file, _)| file)
+            .collect())
+    }
+
+    /// Returns the `ts_init` bounds of `file` from its metadata, or from its interval name
+    /// when it records an interval without data.
+    fn footer_bounds(&self, file: &ObjectPath) -> anyhow::Result<FileBounds> {
+        if let Some((start, end)) = file.filename().and_then(parse_interval_filename) {
+            if self.footer_row_count(file)? == 0 {
+                return Ok(FileBounds { start, end });
+            }
+        }
+
+        let (min, max) = min_max_from_parquet_reader(self.get_bytes(file)?, "ts_init")?;
+        Ok(FileBounds {
+            start: min as u64,
+            end: max as u64,
+        })
+    }
+
+    fn read_manifest(&self, directory: &ObjectPath) -> anyhow::Result<CatalogManifest> {
+        let path = directory.child(MANIFEST_FILENAME);
+        match self.block_on(async { self.store.get(&path).await?.bytes().await }) {
+            Ok(bytes) => CatalogManifest::from_json(&bytes),
+            Err(object_store::Error::NotFound { .. }) => Ok(CatalogManifest::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn write_manifest(
+        &self,
+        directory: &ObjectPath,
+        manifest: &CatalogManifest,
+    ) -> anyhow::Result<()> {
+        let path = directory.child(MANIFEST_FILENAME);
+
+        if !manifest.is_empty() {
+            self.block_on(self.store.put(&path, manifest.to_json()?.into()))?;
+            return Ok(());
+        }
+
+        match self.block_on(self.store.delete(&path)) {
+            Ok(()) | Err(object_store::Error::NotFound { .. }) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Records the `bounds` of the file at `path` in its directory manifest, or removes
+    /// its entry when `None`. Files outside the catalog data directories are not indexed.
+    fn update_manifest(&self, path: &ObjectPath, bounds: Option<FileBounds>) -> anyhow::Result<()> {
+        let (Some(directory), Some(filename)) = (parent_path(path), path.filename()) else {
+            return Ok(());
+        };
+
+        if !directory.prefix_matches(&self.base_path.child("data")) {
+            return Ok(());
+        }
+
+        let mut manifest = self.read_manifest(&directory)?;
+
+        match bounds {
+            Some(bounds) => manifest.insert(filename, bounds),
+            None if manifest.remove(filename) => {}
+            None => return Ok(()),
+        }
+
+        self.write_manifest(&directory, &manifest)
+    }
+}
+
+fn parent_path(path: &ObjectPath) -> Option<ObjectPath> {
+    let parts: Vec<_> = path.parts().collect();
+    let (_, parent) = parts.split_last()?;
+    Some(ObjectPath::from_iter(parent.iter().cloned()))
+}
+
+fn with_extension(path: &ObjectPath, extension: &str) -> ObjectPath {
+    let path_str = path.as_ref();
+    let stem = match (path_str.rfind('.'), path_str.rfind('/')) {
+        (Some(dot), Some(slash)) if dot > slash => &path_str[..dot],
+        (Some(dot), None) => &path_str[..dot],
+        _ => path_str,
+    };
+    ObjectPath::from(format!("{stem}.{extension}"))
+}
+
+/// Returns the catalog path prefix of a feather `file` streamed to `stream_dir`, either
+/// the name of its data type subdirectory (`{prefix}/{identifier}_{ts}.feather`) or
+/// the start of its file name (`{prefix}_{ts}.feather`).
+fn stream_path_prefix(stream_dir: &ObjectPath, file: &ObjectPath) -> Option<String> {
+    let parent = parent_path(file)?;
+
+    if parent == *stream_dir {
+        let (prefix, _) = file.filename()?.rsplit_once('_')?;
+        Some(prefix.to_string())
+    } else {
+        parent.filename().map(str::to_string)
+    }
+}
+
+/// Returns whether the inclusive intervals `a` and `b` intersect.
+const fn spans_intersect(a: (u64, u64), b: (u64, u64)) -> bool {
+    a.0 <= b.1 && a.1 >= b.0
+}
+
+fn parse_interval_filename(filename: &str) -> Option<(u64, u64)> {
+    let stem = filename.strip_suffix(".parquet")?;
+    let (start, end) = stem.split_once('-')?;
+    let start = start.parse::<u64>().ok()?;
+    let end = end.parse::<u64>().ok()?;
+    (start <= end).then_some((start, end))
+}
+
+/// Sorts and merges overlapping or adjacent inclusive `intervals`.
+#[must_use]
+pub fn merge_intervals(mut intervals: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    intervals.sort_unstable();
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(intervals.len());
+
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+/// Returns the sub-intervals of `start` to `end` (inclusive) not covered by the sorted and
+/// merged `intervals`.
+#[must_use]
+pub fn missing_intervals(start: u64, end: u64, intervals: &[(u64, u64)]) -> Vec<(u64, u64)> {
+    let mut missing = Vec::new();
+    let mut cursor = start;
+
+    for &(covered_start, covered_end) in intervals {
+        if covered_end < cursor {
+            continue;
+        }
+        if covered_start > end {
+            break;
+        }
+        if covered_start > cursor {
+            missing.push((cursor, covered_start - 1));
+        }
+        if covered_end >= end {
+            return missing;
+        }
+        cursor = covered_end + 1;
+    }
+
+    if cursor <= end {
+        missing.push((cursor, end));
+    }
+
+    missing
 }
 
 pub trait CatalogPathPrefix {
@@ -447,3 +1736,7 @@ impl_catalog_path_prefix!(Bar, "bars");
 impl_catalog_path_prefix!(IndexPriceUpdate, "index_prices");
 impl_catalog_path_prefix!(MarkPriceUpdate, "mark_prices");
 impl_catalog_path_prefix!(InstrumentClose, "instrument_closes");
+impl_catalog_path_prefix!(InstrumentAny, "instruments");
+impl_catalog_path_prefix!(OrderEventAny, "order_events");
+impl_catalog_path_prefix!(PositionSnapshot, "position_snapshots");
+impl_catalog_path_prefix!(AccountState, "account_states");
@@ -0,0 +1,166 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A registry of user-defined custom data types which can be written to and queried
+//! from a [`ParquetDataCatalog`](super::catalog::ParquetDataCatalog).
+
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+};
+
+use datafusion::arrow::record_batch::RecordBatch;
+use heck::ToSnakeCase;
+use itertools::Itertools;
+use nautilus_model::data::{CustomDataAny, CustomDataType};
+use nautilus_serialization::arrow::{DecodeDataFromRecordBatch, EncodeToRecordBatch};
+
+use super::session::DecodeDataFn;
+
+/// Encodes custom data values of a single registered type into record batches of at
+/// most the given number of rows.
+type EncodeCustomDataFn = fn(&[CustomDataAny], usize) -> anyhow::Result<Vec<RecordBatch>>;
+
+/// The catalog path and Arrow codecs for a registered custom data type.
+#[derive(Clone, Debug)]
+pub struct CustomDataRegistration {
+    /// The unique name of the data type.
+    pub type_name: &'static str,
+    /// The directory name of the data type under the catalog `data` directory.
+    pub path_prefix: String,
+    /// Decodes record batches of the data type into `Data::Custom` values.
+    pub decode: DecodeDataFn,
+    encode: EncodeCustomDataFn,
+}
+
+impl CustomDataRegistration {
+    /// Encodes `data`, which must all be of the registered type, into record batches of
+    /// at most `batch_size` rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any value is not of the registered type, or encoding fails.
+    pub fn encode(
+        &self,
+        data: &[CustomDataAny],
+        batch_size: usize,
+    ) -> anyhow::Result<Vec<RecordBatch>> {
+        (self.encode)(data, batch_size)
+    }
+}
+
+static CUSTOM_DATA_REGISTRY: LazyLock<Mutex<HashMap<&'static str, CustomDataRegistration>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Registers the custom data type `T`, giving it the catalog path
+/// `data/custom_{type_name}` (in snake case) and allowing `Data::Custom` values of
+/// the type to be written to and queried from catalogs by type name.
+///
+/// Registering a type again replaces the previous registration.
+///
+/// # Panics
+///
+/// Panics if the registry mutex is poisoned.
+pub fn register_custom_data_type<T>()
+where
+    T: CustomDataType + EncodeToRecordBatch + DecodeDataFromRecordBatch,
+{
+    let type_name = T::type_name();
+    let registration = CustomDataRegistration {
+        type_name,
+        path_prefix: custom_data_path_prefix(type_name),
+        decode: T::decode_data_batch,
+        encode: encode_custom_data::<T>,
+    };
+
+    log::debug!("Registered custom data type {type_name}");
+    CUSTOM_DATA_REGISTRY
+        .lock()
+        .expect("Custom data registry mutex poisoned")
+        .insert(type_name, registration);
+}
+
+/// Returns the registration for the custom data type named `type_name`, if registered.
+///
+/// # Panics
+///
+/// Panics if the registry mutex is poisoned.
+#[must_use]
+pub fn get_custom_data_registration(type_name: &str) -> Option<CustomDataRegistration> {
+    CUSTOM_DATA_REGISTRY
+        .lock()
+        .expect("Custom data registry mutex poisoned")
+        .get(type_name)
+        .cloned()
+}
+
+/// Returns the catalog directory name for the custom data type named `type_name`.
+#[must_use]
+pub fn custom_data_path_prefix(type_name: &str) -> String {
+    format!("custom_{}", type_name.to_snake_case())
+}
+
+fn encode_custom_data<T>(
+    data: &[CustomDataAny],
+    batch_size: usize,
+) -> anyhow::Result<Vec<RecordBatch>>
+where
+    T: CustomDataType + EncodeToRecordBatch,
+{
+    let values: Vec<T> = data
+        .iter()
+        .map(|custom| {
+            custom.downcast_ref::<T>().cloned().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Cannot encode custom data `{}` as `{}`",
+                    custom.type_name(),
+                    T::type_name()
+                )
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    let mut batches = Vec::new();
+
+    for chunk in &values.into_iter().chunks(batch_size) {
+        let chunk = chunk.collect_vec();
+        let metadata = T::chunk_metadata(&chunk);
+        batches.push(T::encode_batch(&metadata, &chunk)?);
+    }
+
+    Ok(batches)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Sentiment", "custom_sentiment")]
+    #[case("OnChainMetric", "custom_on_chain_metric")]
+    fn test_custom_data_path_prefix(#[case] type_name: &str, #[case] expected: &str) {
+        assert_eq!(custom_data_path_prefix(type_name), expected);
+    }
+
+    #[rstest]
+    fn test_get_custom_data_registration_when_not_registered() {
+        assert!(get_custom_data_registration("NotRegistered").is_none());
+    }
+}
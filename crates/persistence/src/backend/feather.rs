@@ -25,7 +25,7 @@ use datafusion::arrow::{
 };
 use nautilus_common::clock::Clock;
 use nautilus_core::UnixNanos;
-use nautilus_serialization::arrow::{EncodeToRecordBatch, KEY_INSTRUMENT_ID};
+use nautilus_serialization::arrow::{EncodeToRecordBatch, KEY_BAR_TYPE, KEY_INSTRUMENT_ID};
 use object_store::{ObjectStore, path::Path};
 
 use super::catalog::CatalogPathPrefix;
@@ -134,6 +134,9 @@ pub enum RotationConfig {
 /// and the manager encodes it (using T's metadata via `EncodeToRecordBatch`), routes it by `CatalogPathPrefix`,
 /// and writes it to the appropriate `FileWriter`. When a writer's buffer is full or rotation criteria are met,
 /// its contents are flushed to the object store and it is replaced.
+///
+/// Synchronous clients can instead `buffer()` data and put the buffers taken with `take_pending()`
+/// or `take_all()` in the object store themselves.
 pub struct FeatherWriter {
     /// Base directory for writing files.
     base_path: String,
@@ -149,6 +152,8 @@ pub struct FeatherWriter {
     per_instrument_types: HashSet<String>,
     /// Map of active `FeatherBuffers` keyed by their path.
     writers: HashMap<FileWriterPath, FeatherBuffer>,
+    /// Contents of rotated `FeatherBuffers` not yet put in the object store.
+    pending: Vec<(Path, Vec<u8>)>,
 }
 
 impl FeatherWriter {
@@ -169,14 +174,45 @@ impl FeatherWriter {
             included_types,
             per_instrument_types: per_instrument_types.unwrap_or_default(),
             writers: HashMap::new(),
+            pending: Vec::new(),
         }
     }
 
+    /// Returns the base directory files are written to.
+    #[must_use]
+    pub fn base_path(&self) -> &str {
+        &self.base_path
+    }
+
+    /// Returns the object store files are written to.
+    #[must_use]
+    pub fn store(&self) -> Arc<dyn ObjectStore> {
+        self.store.clone()
+    }
+
     /// Writes a single data value.
     /// This is the user entry point. The data is encoded into a `RecordBatch` and written to the appropriate `FileWriter`.
     /// If the writer's buffer reaches capacity or meets rotation criteria (based on the rotation configuration),
     /// the `FileWriter` is flushed to the object store and replaced.
     pub async fn write<T>(&mut self, data: T) -> Result<(), Box<dyn std::error::Error>>
+    where
+        T: EncodeToRecordBatch + CatalogPathPrefix + 'static,
+    {
+        self.buffer(data)?;
+
+        for (path, bytes) in self.take_pending() {
+            self.store.put(&path, bytes.into()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes and buffers a single data value without performing any I/O.
+    ///
+    /// The contents of writers rotated as a result are held until taken with
+    /// [`FeatherWriter::take_pending`], so this can be called from synchronous
+    /// contexts such as message bus handlers.
+    pub fn buffer<T>(&mut self, data: T) -> Result<(), Box<dyn std::error::Error>>
     where
         T: EncodeToRecordBatch + CatalogPathPrefix + 'static,
     {
@@ -198,22 +234,40 @@ impl FeatherWriter {
         if let Some(writer) = self.writers.get_mut(&path) {
             let should_rotate = writer.write_record_batch(&batch)?;
             if should_rotate {
-                self.rotate_writer(&path).await?;
+                self.rotate_writer(&path)?;
             }
         }
 
         Ok(())
     }
 
-    /// Flushes and rotates `FileWriter` associated with `key`.
-    /// TODO: Fix error type to handle arrow error and object store error
-    async fn rotate_writer(
-        &mut self,
-        path: &FileWriterPath,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    /// Returns the contents of rotated writers which have not yet been put in the
+    /// object store, keyed by their path.
+    pub fn take_pending(&mut self) -> Vec<(Path, Vec<u8>)> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Returns the contents of rotated writers and drains all active writers, keyed by
+    /// their path, so they can be put in the object store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if finishing an Arrow stream fails.
+    pub fn take_all(&mut self) -> Result<Vec<(Path, Vec<u8>)>, ArrowError> {
+        let mut files = self.take_pending();
+
+        for (path, mut writer) in self.writers.drain() {
+            files.push((path.path, writer.take_buffer()?));
+        }
+
+        Ok(files)
+    }
+
+    /// Rotates the `FileWriter` associated with `path`, holding its contents as pending.
+    fn rotate_writer(&mut self, path: &FileWriterPath) -> Result<(), Box<dyn std::error::Error>> {
         let mut writer = self.writers.remove(path).unwrap();
         let bytes = writer.take_buffer()?;
-        self.store.put(&path.path, bytes.into()).await?;
+        self.pending.push((path.path.clone(), bytes));
         let new_path = self.regen_writer_path(path)?;
         self.writers.insert(new_path, writer);
         Ok(())
@@ -241,9 +295,8 @@ impl FeatherWriter {
     /// Note: This is not called automatically and must be called by the client.
     /// It is expected that no other writes are performed after this.
     pub async fn flush(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        for (path, mut writer) in self.writers.drain() {
-            let bytes = writer.take_buffer()?;
-            self.store.put(&path.path, bytes.into()).await?;
+        for (path, bytes) in self.take_all()? {
+            self.store.put(&path, bytes.into()).await?;
         }
         Ok(())
     }
@@ -289,6 +342,7 @@ impl FeatherWriter {
             let metadata = T::metadata(data);
             metadata
                 .get(KEY_INSTRUMENT_ID)
+                .or_else(|| metadata.get(KEY_BAR_TYPE))
                 .cloned()
                 .expect("Data {type_str} expected instrument_id metadata for per instrument writer")
         });
@@ -402,6 +456,39 @@ mod tests {
         assert!(writer.size > 0);
     }
 
+    #[rstest]
+    fn test_buffer_holds_rotated_writers_as_pending() {
+        let clock: Rc<RefCell<dyn Clock>> = Rc::new(RefCell::new(TestClock::new()));
+        let mut manager = FeatherWriter::new(
+            "catalog/live".to_string(),
+            Arc::new(object_store::memory::InMemory::new()),
+            clock,
+            RotationConfig::Size { max_size: 1 },
+            None,
+            None,
+        );
+        let quote = QuoteTick::new(
+            InstrumentId::from("AAPL.AAPL"),
+            Price::from("100.0"),
+            Price::from("100.0"),
+            Quantity::from("100.0"),
+            Quantity::from("100.0"),
+            UnixNanos::from(100),
+            UnixNanos::from(100),
+        );
+
+        manager.buffer(quote).unwrap();
+
+        let pending = manager.take_pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].0, Path::from("catalog/live/quotes_0.feather"));
+        assert!(manager.take_pending().is_empty());
+
+        // The rotated writer is kept active until all buffers are taken
+        assert_eq!(manager.take_all().unwrap().len(), 1);
+        assert!(manager.writers.is_empty());
+    }
+
     #[rstest]
     fn test_file_writer_round_trip() {
         let instrument_id = "AAPL.AAPL";
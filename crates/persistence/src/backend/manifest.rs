@@ -0,0 +1,137 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An index of the parquet files within a catalog data directory and their `ts_init`
+//! bounds, so files can be pruned by time without reading their footers.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The name of the manifest file within each catalog data directory.
+pub const MANIFEST_FILENAME: &str = "_manifest.json";
+
+/// The min and max `ts_init` (UNIX nanoseconds) of the rows in a parquet file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileBounds {
+    /// The minimum `ts_init` of the rows in the file.
+    pub start: u64,
+    /// The maximum `ts_init` of the rows in the file.
+    pub end: u64,
+}
+
+impl FileBounds {
+    /// Returns whether the file may contain rows between `start` and `end` (inclusive).
+    #[must_use]
+    pub const fn intersects(&self, start: u64, end: u64) -> bool {
+        self.start <= end && self.end >= start
+    }
+}
+
+/// The parquet files of a catalog data directory keyed by file name.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogManifest {
+    /// The bounds of each file keyed by file name.
+    pub files: BTreeMap<String, FileBounds>,
+}
+
+impl CatalogManifest {
+    /// Parses a manifest from its JSON representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not a valid manifest.
+    pub fn from_json(bytes: &[u8]) -> anyhow::Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    /// Returns the JSON representation of the manifest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(serde_json::to_vec_pretty(self)?)
+    }
+
+    /// Returns the bounds recorded for `filename`.
+    #[must_use]
+    pub fn get(&self, filename: &str) -> Option<FileBounds> {
+        self.files.get(filename).copied()
+    }
+
+    /// Records the `bounds` of `filename`, replacing any existing entry.
+    pub fn insert(&mut self, filename: &str, bounds: FileBounds) {
+        self.files.insert(filename.to_string(), bounds);
+    }
+
+    /// Removes the entry for `filename`, returning whether it existed.
+    pub fn remove(&mut self, filename: &str) -> bool {
+        self.files.remove(filename).is_some()
+    }
+
+    /// Returns whether the manifest has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_manifest_json_round_trip() {
+        let mut manifest = CatalogManifest::default();
+        manifest.insert("part-0.parquet", FileBounds { start: 1, end: 5 });
+        manifest.insert("6-9.parquet", FileBounds { start: 6, end: 9 });
+
+        let parsed = CatalogManifest::from_json(&manifest.to_json().unwrap()).unwrap();
+
+        assert_eq!(parsed, manifest);
+        assert_eq!(
+            parsed.get("6-9.parquet"),
+            Some(FileBounds { start: 6, end: 9 })
+        );
+        assert!(parsed.get("missing.parquet").is_none());
+    }
+
+    #[rstest]
+    fn test_manifest_remove() {
+        let mut manifest = CatalogManifest::default();
+        manifest.insert("part-0.parquet", FileBounds { start: 1, end: 5 });
+
+        assert!(manifest.remove("part-0.parquet"));
+        assert!(!manifest.remove("part-0.parquet"));
+        assert!(manifest.is_empty());
+    }
+
+    #[rstest]
+    #[case(0, 0, false)]
+    #[case(0, 1, true)]
+    #[case(2, 3, true)]
+    #[case(5, 10, true)]
+    #[case(6, 10, false)]
+    fn test_file_bounds_intersects(#[case] start: u64, #[case] end: u64, #[case] expected: bool) {
+        let bounds = FileBounds { start: 1, end: 5 };
+        assert_eq!(bounds.intersects(start, end), expected);
+    }
+}
@@ -16,6 +16,10 @@
 //! Provides an Apache Parquet backend powered by [DataFusion](https://arrow.apache.org/datafusion).
 
 pub mod catalog;
+pub mod custom;
 pub mod feather;
 pub mod kmerge_batch;
+pub mod manifest;
+pub mod partition;
 pub mod session;
+pub mod store;
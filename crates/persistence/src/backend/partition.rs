@@ -0,0 +1,329 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Time based partitioning of catalog files, and the record batch operations used to
+//! merge, deduplicate and split data by `ts_init` when writing or compacting partitions.
+
+use std::collections::HashSet;
+
+use chrono::{Datelike, NaiveDate};
+use datafusion::arrow::{
+    array::{AsArray, BooleanArray, UInt32Array, UInt64Array},
+    compute::{concat_batches, filter_record_batch, max, min, take_record_batch},
+    datatypes::UInt64Type,
+    record_batch::RecordBatch,
+    row::{RowConverter, SortField},
+};
+use nautilus_core::UnixNanos;
+
+const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
+
+/// The time based partitioning of the files within each catalog data directory.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CatalogPartitioning {
+    /// Files are not partitioned by time.
+    #[default]
+    None,
+    /// One file per UTC calendar day.
+    Daily,
+    /// One file per UTC calendar month.
+    Monthly,
+}
+
+impl CatalogPartitioning {
+    /// Returns the inclusive bounds (UNIX nanoseconds) of the partition containing `ts`,
+    /// the whole time range when not partitioned.
+    #[must_use]
+    pub fn bounds(self, ts: u64) -> (u64, u64) {
+        match self {
+            Self::None => (0, u64::MAX),
+            Self::Daily => {
+                let start = ts - ts % NANOSECONDS_IN_DAY;
+                (start, start.saturating_add(NANOSECONDS_IN_DAY - 1))
+            }
+            Self::Monthly => {
+                let datetime = UnixNanos::from(ts).to_datetime_utc();
+                let (year, month) = (datetime.year(), datetime.month());
+                let (next_year, next_month) = if month == 12 {
+                    (year + 1, 1)
+                } else {
+                    (year, month + 1)
+                };
+                (
+                    month_start(year, month),
+                    month_start(next_year, next_month).saturating_sub(1),
+                )
+            }
+        }
+    }
+
+    /// Returns the inclusive bounds of all partitions touched by the `start` to `end`
+    /// interval, so two intervals share a partition when their spans intersect.
+    #[must_use]
+    pub fn span(self, start: u64, end: u64) -> (u64, u64) {
+        (self.bounds(start).0, self.bounds(end).1)
+    }
+}
+
+fn month_start(year: i32, month: u32) -> u64 {
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .and_then(|datetime| datetime.and_utc().timestamp_nanos_opt())
+        .map_or(u64::MAX, |nanos| nanos as u64)
+}
+
+/// Returns the `ts_init` column of `batch`.
+///
+/// # Errors
+///
+/// Returns an error if `batch` has no `UInt64` column named `ts_init`.
+pub fn ts_init_column(batch: &RecordBatch) -> anyhow::Result<&UInt64Array> {
+    batch
+        .column_by_name("ts_init")
+        .and_then(|column| column.as_primitive_opt::<UInt64Type>())
+        .ok_or_else(|| anyhow::anyhow!("Record batch has no UInt64 `ts_init` column"))
+}
+
+/// Returns the min and max `ts_init` values of `batches`, or `None` if there are no rows
+/// or no `ts_init` column.
+#[must_use]
+pub fn ts_init_bounds(batches: &[RecordBatch]) -> Option<(u64, u64)> {
+    let mut bounds: Option<(u64, u64)> = None;
+
+    for batch in batches {
+        let column = ts_init_column(batch).ok()?;
+        if let (Some(batch_min), Some(batch_max)) = (min(column), max(column)) {
+            bounds = Some(match bounds {
+                Some((lo, hi)) => (lo.min(batch_min), hi.max(batch_max)),
+                None => (batch_min, batch_max),
+            });
+        }
+    }
+
+    bounds
+}
+
+/// Merges `batches` into a single batch in ascending `ts_init` order, dropping rows which
+/// duplicate an earlier row in every column. Rows with equal `ts_init` keep their order.
+///
+/// Returns `None` if `batches` contain no rows.
+///
+/// # Errors
+///
+/// Returns an error if the batches have incompatible schemas or no `ts_init` column.
+pub fn merge_batches(batches: &[RecordBatch]) -> anyhow::Result<Option<RecordBatch>> {
+    let Some(first) = batches.first() else {
+        return Ok(None);
+    };
+    let batch = concat_batches(&first.schema(), batches)?;
+
+    if batch.num_rows() == 0 {
+        return Ok(None);
+    }
+
+    let ts_init = ts_init_column(&batch)?;
+    let mut indices: Vec<u32> = (0..batch.num_rows() as u32).collect();
+    indices.sort_by_key(|&i| ts_init.value(i as usize)); // Stable
+
+    let fields = batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| SortField::new(field.data_type().clone()))
+        .collect();
+    let converter = RowConverter::new(fields)?;
+    let rows = converter.convert_columns(batch.columns())?;
+    let mut seen = HashSet::with_capacity(batch.num_rows());
+    indices.retain(|&i| seen.insert(rows.row(i as usize)));
+
+    Ok(Some(take_record_batch(
+        &batch,
+        &UInt32Array::from(indices),
+    )?))
+}
+
+/// Splits `batch`, which must be in ascending `ts_init` order, into one batch per
+/// partition with the partition bounds.
+///
+/// # Errors
+///
+/// Returns an error if `batch` has no `ts_init` column.
+pub fn split_by_partition(
+    batch: &RecordBatch,
+    partitioning: CatalogPartitioning,
+) -> anyhow::Result<Vec<((u64, u64), RecordBatch)>> {
+    let ts_init = ts_init_column(batch)?;
+    let mut parts = Vec::new();
+    let mut offset = 0;
+
+    while offset < batch.num_rows() {
+        let bounds = partitioning.bounds(ts_init.value(offset));
+        let mut end = offset;
+        while end < batch.num_rows() && ts_init.value(end) <= bounds.1 {
+            end += 1;
+        }
+        parts.push((bounds, batch.slice(offset, end - offset)));
+        offset = end;
+    }
+
+    Ok(parts)
+}
+
+/// Returns the rows of `batch` with `ts_init` between `start` and `end` (inclusive).
+///
+/// # Errors
+///
+/// Returns an error if `batch` has no `ts_init` column.
+pub fn filter_by_ts_init(batch: &RecordBatch, start: u64, end: u64) -> anyhow::Result<RecordBatch> {
+    let mask: BooleanArray = ts_init_column(batch)?
+        .values()
+        .iter()
+        .map(|ts| Some((start..=end).contains(ts)))
+        .collect();
+
+    Ok(filter_record_batch(batch, &mask)?)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use datafusion::arrow::{
+        array::StringArray,
+        datatypes::{DataType, Field, Schema},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    // 2024-01-15T12:00:00Z
+    const TS: u64 = 1_705_320_000_000_000_000;
+
+    fn batch(rows: &[(u64, &str)]) -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("value", DataType::Utf8, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(StringArray::from_iter_values(rows.iter().map(|row| row.1))),
+                Arc::new(UInt64Array::from_iter_values(rows.iter().map(|row| row.0))),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn rows(batch: &RecordBatch) -> Vec<(u64, String)> {
+        let values = batch.column(0).as_string::<i32>();
+        let ts_init = ts_init_column(batch).unwrap();
+        (0..batch.num_rows())
+            .map(|i| (ts_init.value(i), values.value(i).to_string()))
+            .collect()
+    }
+
+    #[rstest]
+    #[case(CatalogPartitioning::None, (0, u64::MAX))]
+    #[case(
+        CatalogPartitioning::Daily,
+        (1_705_276_800_000_000_000, 1_705_363_199_999_999_999)
+    )]
+    #[case(
+        CatalogPartitioning::Monthly,
+        (1_704_067_200_000_000_000, 1_706_745_599_999_999_999)
+    )]
+    fn test_partition_bounds(
+        #[case] partitioning: CatalogPartitioning,
+        #[case] expected: (u64, u64),
+    ) {
+        assert_eq!(partitioning.bounds(TS), expected);
+        assert_eq!(partitioning.bounds(expected.0), expected);
+        assert_eq!(partitioning.bounds(expected.1), expected);
+    }
+
+    #[rstest]
+    fn test_monthly_partition_bounds_december() {
+        // 2023-12-31T00:00:00Z falls in the December 2023 partition
+        let (start, end) = CatalogPartitioning::Monthly.bounds(1_703_980_800_000_000_000);
+
+        assert_eq!(start, 1_701_388_800_000_000_000);
+        assert_eq!(end, 1_704_067_199_999_999_999);
+    }
+
+    #[rstest]
+    fn test_partition_span() {
+        let (day_start, day_end) = CatalogPartitioning::Daily.bounds(TS);
+
+        assert_eq!(
+            CatalogPartitioning::Daily.span(TS, TS + NANOSECONDS_IN_DAY),
+            (day_start, day_end + NANOSECONDS_IN_DAY)
+        );
+    }
+
+    #[rstest]
+    fn test_merge_batches_sorts_and_deduplicates() {
+        let first = batch(&[(1, "a"), (3, "c")]);
+        let second = batch(&[(2, "b"), (3, "c"), (3, "d"), (1, "a")]);
+
+        let merged = merge_batches(&[first, second]).unwrap().unwrap();
+
+        assert_eq!(
+            rows(&merged),
+            vec![
+                (1, "a".to_string()),
+                (2, "b".to_string()),
+                (3, "c".to_string()),
+                (3, "d".to_string()),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_merge_batches_empty() {
+        assert!(merge_batches(&[]).unwrap().is_none());
+        assert!(merge_batches(&[batch(&[])]).unwrap().is_none());
+    }
+
+    #[rstest]
+    fn test_split_by_partition() {
+        let next_day = TS + NANOSECONDS_IN_DAY;
+        let batch = batch(&[(TS, "a"), (TS + 1, "b"), (next_day, "c")]);
+
+        let parts = split_by_partition(&batch, CatalogPartitioning::Daily).unwrap();
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].0, CatalogPartitioning::Daily.bounds(TS));
+        assert_eq!(parts[0].1.num_rows(), 2);
+        assert_eq!(parts[1].0, CatalogPartitioning::Daily.bounds(next_day));
+        assert_eq!(rows(&parts[1].1), vec![(next_day, "c".to_string())]);
+    }
+
+    #[rstest]
+    fn test_filter_by_ts_init_and_bounds() {
+        let batch = batch(&[(1, "a"), (2, "b"), (3, "c")]);
+
+        let filtered = filter_by_ts_init(&batch, 2, 5).unwrap();
+
+        assert_eq!(filtered.num_rows(), 2);
+        assert_eq!(
+            ts_init_bounds(std::slice::from_ref(&filtered)),
+            Some((2, 3))
+        );
+        assert_eq!(ts_init_bounds(&[]), None);
+    }
+}
@@ -17,14 +17,17 @@ use std::{collections::HashMap, sync::Arc, vec::IntoIter};
 
 use compare::Compare;
 use datafusion::{
-    error::Result, logical_expr::expr::Sort, physical_plan::SendableRecordBatchStream, prelude::*,
+    arrow::record_batch::RecordBatch, error::Result, logical_expr::expr::Sort,
+    physical_plan::SendableRecordBatchStream, prelude::*,
 };
 use futures::StreamExt;
 use nautilus_core::{UnixNanos, ffi::cvec::CVec};
 use nautilus_model::data::{Data, GetTsInit};
 use nautilus_serialization::arrow::{
-    DataStreamingError, DecodeDataFromRecordBatch, EncodeToRecordBatch, WriteStream,
+    DataStreamingError, DecodeDataFromRecordBatch, EncodeToRecordBatch, EncodingError, WriteStream,
 };
+use object_store::ObjectStore;
+use url::Url;
 
 use super::kmerge_batch::{EagerStream, ElementBatchIter, KMerge};
 
@@ -47,6 +50,11 @@ where
 
 pub type QueryResult = KMerge<EagerStream<std::vec::IntoIter<Data>>, Data, TsInitComparator>;
 
+/// Decodes a `RecordBatch` into `Data` values using the schema metadata, as provided
+/// by [`DecodeDataFromRecordBatch::decode_data_batch`] for each data type.
+pub type DecodeDataFn =
+    fn(&HashMap<String, String>, RecordBatch) -> Result<Vec<Data>, EncodingError>;
+
 /// Provides a `DataFusion` session and registers `DataFusion` queries.
 ///
 /// The session is used to register data sources and make queries on them. A
@@ -83,6 +91,12 @@ impl DataBackendSession {
         }
     }
 
+    /// Registers the object `store` for `url` (scheme and authority, e.g. `s3://bucket`),
+    /// so files with that URL prefix can be added to the session.
+    pub fn register_object_store(&mut self, url: &Url, store: Arc<dyn ObjectStore>) {
+        self.session_ctx.register_object_store(url, store);
+    }
+
     pub fn write_data<T: EncodeToRecordBatch>(
         data: &[T],
         metadata: &HashMap<String, String>,
@@ -115,6 +129,25 @@ impl DataBackendSession {
     where
         T: DecodeDataFromRecordBatch + Into<Data>,
     {
+        self.add_file_with_decoder(table_name, file_path, sql_query, T::decode_data_batch)
+    }
+
+    /// Query a file for its records, decoding them with `decode`.
+    ///
+    /// This allows files of data types only known at runtime, such as registered
+    /// custom data types, to be merged with the other queries of the session.
+    ///
+    /// # Safety
+    ///
+    /// The file data must be ordered by the `ts_init` in ascending order for this
+    /// to work correctly.
+    pub fn add_file_with_decoder(
+        &mut self,
+        table_name: &str,
+        file_path: &str,
+        sql_query: Option<&str>,
+        decode: DecodeDataFn,
+    ) -> Result<()> {
         let parquet_options = ParquetReadOptions::<'_> {
             skip_metadata: Some(false),
             file_sort_order: vec![vec![Sort {
@@ -136,16 +169,13 @@ impl DataBackendSession {
 
         let batch_stream = self.runtime.block_on(query.execute_stream())?;
 
-        self.add_batch_stream::<T>(batch_stream);
+        self.add_batch_stream(batch_stream, decode);
         Ok(())
     }
 
-    fn add_batch_stream<T>(&mut self, stream: SendableRecordBatchStream)
-    where
-        T: DecodeDataFromRecordBatch + Into<Data>,
-    {
-        let transform = stream.map(|result| match result {
-            Ok(batch) => T::decode_data_batch(batch.schema().metadata(), batch)
+    fn add_batch_stream(&mut self, stream: SendableRecordBatchStream, decode: DecodeDataFn) {
+        let transform = stream.map(move |result| match result {
+            Ok(batch) => decode(batch.schema().metadata(), batch)
                 .unwrap()
                 .into_iter(),
             Err(e) => panic!("Error getting next batch from RecordBatchStream: {e}"),
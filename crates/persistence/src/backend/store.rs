@@ -0,0 +1,127 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Creates [`ObjectStore`] instances for catalog URIs.
+
+use std::{collections::HashMap, sync::Arc};
+
+use object_store::{
+    ObjectStore, aws::AmazonS3Builder, azure::MicrosoftAzureBuilder,
+    gcp::GoogleCloudStorageBuilder, local::LocalFileSystem, memory::InMemory,
+    path::Path as ObjectPath,
+};
+use url::Url;
+
+/// Creates an object store for the given `uri`, returning the store, the URL of the store
+/// (scheme and authority) and the path addressed by `uri` within the store.
+///
+/// Supported schemes are `file`, `memory`, `s3`/`s3a`, `gs` and `az`/`azure`/`abfs`/`abfss`.
+/// Cloud stores are configured from the environment (e.g. `AWS_ACCESS_KEY_ID`), overridden
+/// by any `storage_options` (e.g. `aws_endpoint` for a MinIO compatible store).
+///
+/// # Errors
+///
+/// Returns an error if `uri` is invalid, the scheme is not supported, a storage option
+/// is not recognized, or the store cannot be built.
+pub fn create_object_store(
+    uri: &str,
+    storage_options: Option<HashMap<String, String>>,
+) -> anyhow::Result<(Arc<dyn ObjectStore>, Url, ObjectPath)> {
+    let url = Url::parse(uri)?;
+    let store_url = Url::parse(&url[..url::Position::BeforePath])?;
+    let path = ObjectPath::from_url_path(url.path())?;
+    let options = storage_options.unwrap_or_default();
+
+    let store: Arc<dyn ObjectStore> = match url.scheme() {
+        "file" => Arc::new(LocalFileSystem::new()),
+        "memory" => Arc::new(InMemory::new()),
+        "s3" | "s3a" => {
+            let mut builder = AmazonS3Builder::from_env().with_url(uri);
+            for (key, value) in options {
+                builder = builder.with_config(key.parse()?, value);
+            }
+            Arc::new(builder.build()?)
+        }
+        "gs" => {
+            let mut builder = GoogleCloudStorageBuilder::from_env().with_url(uri);
+            for (key, value) in options {
+                builder = builder.with_config(key.parse()?, value);
+            }
+            Arc::new(builder.build()?)
+        }
+        "az" | "azure" | "abfs" | "abfss" => {
+            let mut builder = MicrosoftAzureBuilder::from_env().with_url(uri);
+            for (key, value) in options {
+                builder = builder.with_config(key.parse()?, value);
+            }
+            Arc::new(builder.build()?)
+        }
+        scheme => anyhow::bail!("Unsupported catalog URI scheme '{scheme}' for {uri}"),
+    };
+
+    Ok((store, store_url, path))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("memory:///catalog", "memory://", "catalog")]
+    #[case("file:///tmp/catalog", "file:///", "tmp/catalog")]
+    #[case("file:///tmp/my%20catalog", "file:///", "tmp/my catalog")]
+    #[case("s3://bucket/research/catalog", "s3://bucket", "research/catalog")]
+    fn test_create_object_store(
+        #[case] uri: &str,
+        #[case] expected_url: &str,
+        #[case] expected_path: &str,
+    ) {
+        let (_, store_url, path) = create_object_store(uri, None).unwrap();
+
+        assert_eq!(store_url.as_str(), expected_url);
+        assert_eq!(path, ObjectPath::from(expected_path));
+    }
+
+    #[rstest]
+    fn test_create_object_store_with_storage_options() {
+        let options = HashMap::from([
+            ("aws_region".to_string(), "us-east-1".to_string()),
+            (
+                "aws_endpoint".to_string(),
+                "http://localhost:9000".to_string(),
+            ),
+            ("aws_allow_http".to_string(), "true".to_string()),
+        ]);
+
+        assert!(create_object_store("s3://bucket/catalog", Some(options)).is_ok());
+    }
+
+    #[rstest]
+    fn test_create_object_store_unknown_storage_option() {
+        let options = HashMap::from([("not_an_option".to_string(), "value".to_string())]);
+
+        assert!(create_object_store("s3://bucket/catalog", Some(options)).is_err());
+    }
+
+    #[rstest]
+    fn test_create_object_store_unsupported_scheme() {
+        assert!(create_object_store("ftp://host/catalog", None).is_err());
+    }
+}
@@ -17,7 +17,9 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use crate::backend::feather::RotationConfig;
+use std::collections::HashMap;
+
+use crate::backend::{feather::RotationConfig, partition::CatalogPartitioning};
 
 /// Configuration for streaming live or backtest runs to the catalog in feather format.
 #[derive(Debug, Clone)]
@@ -52,6 +54,36 @@ impl StreamingConfig {
             rotation_config,
         }
     }
+
+    /// Returns the path to the data catalog.
+    #[must_use]
+    pub fn catalog_path(&self) -> &str {
+        &self.catalog_path
+    }
+
+    /// Returns the `fsspec` filesystem protocol for the catalog.
+    #[must_use]
+    pub fn fst_protocol(&self) -> &str {
+        &self.fst_protocol
+    }
+
+    /// Returns the flush interval (milliseconds) for writing chunks.
+    #[must_use]
+    pub const fn flush_interval_ms(&self) -> u64 {
+        self.flush_interval_ms
+    }
+
+    /// Returns whether any existing feather files should be replaced.
+    #[must_use]
+    pub const fn replace_existing(&self) -> bool {
+        self.replace_existing
+    }
+
+    /// Returns the rotation configuration for the feather files.
+    #[must_use]
+    pub const fn rotation_config(&self) -> &RotationConfig {
+        &self.rotation_config
+    }
 }
 
 /// Configuration for a data catalog.
@@ -60,12 +92,64 @@ pub struct DataCatalogConfig {
     path: String,
     /// The fsspec file system protocol for the data catalog.
     fs_protocol: String,
+    /// The object store options for the data catalog (e.g. credentials, region or endpoint).
+    storage_options: Option<HashMap<String, String>>,
+    /// The time based partitioning of the files within each data directory.
+    partitioning: CatalogPartitioning,
 }
 
 impl DataCatalogConfig {
     /// Create a new data catalog configuration.
     #[must_use]
-    pub const fn new(path: String, fs_protocol: String) -> Self {
-        Self { path, fs_protocol }
+    pub const fn new(
+        path: String,
+        fs_protocol: String,
+        storage_options: Option<HashMap<String, String>>,
+    ) -> Self {
+        Self {
+            path,
+            fs_protocol,
+            storage_options,
+            partitioning: CatalogPartitioning::None,
+        }
+    }
+
+    /// Sets the time based partitioning of the files within each data directory.
+    #[must_use]
+    pub const fn with_partitioning(mut self, partitioning: CatalogPartitioning) -> Self {
+        self.partitioning = partitioning;
+        self
+    }
+
+    /// Returns the catalog URI for the path and file system protocol.
+    #[must_use]
+    pub fn uri(&self) -> String {
+        let scheme = match self.fs_protocol.as_str() {
+            "" | "file" | "local" => return self.path.clone(),
+            "gcs" => "gs",
+            protocol => protocol,
+        };
+
+        let path = self.path.trim_start_matches('/');
+
+        if self.path.contains("://") {
+            self.path.clone()
+        } else if scheme == "memory" {
+            format!("memory:///{path}") // In-memory stores have no authority
+        } else {
+            format!("{scheme}://{path}")
+        }
+    }
+
+    /// Returns the object store options for the data catalog.
+    #[must_use]
+    pub const fn storage_options(&self) -> Option<&HashMap<String, String>> {
+        self.storage_options.as_ref()
+    }
+
+    /// Returns the time based partitioning of the files within each data directory.
+    #[must_use]
+    pub const fn partitioning(&self) -> CatalogPartitioning {
+        self.partitioning
     }
 }
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::path::PathBuf;
+use std::collections::HashMap;
 
 use nautilus_model::data::{Bar, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick};
 use nautilus_serialization::enums::ParquetWriteMode;
@@ -32,14 +32,18 @@ pub struct ParquetDataCatalogV2 {
 
 #[pymethods]
 impl ParquetDataCatalogV2 {
-    /// Create a new `ParquetCatalog` with the given base path and optional batch size.
+    /// Create a new `ParquetCatalog` with the given base path or URI (e.g. `s3://bucket/catalog`),
+    /// optional batch size and optional object store options.
     #[new]
-    #[pyo3(signature = (base_path, batch_size=None))]
-    #[must_use]
-    pub fn new(base_path: String, batch_size: Option<usize>) -> Self {
-        Self {
-            inner: ParquetDataCatalog::new(PathBuf::from(base_path), batch_size),
-        }
+    #[pyo3(signature = (base_path, batch_size=None, storage_options=None))]
+    pub fn new(
+        base_path: String,
+        batch_size: Option<usize>,
+        storage_options: Option<HashMap<String, String>>,
+    ) -> PyResult<Self> {
+        let inner = ParquetDataCatalog::from_uri(&base_path, storage_options, batch_size)
+            .map_err(|e| PyIOError::new_err(format!("Failed to create catalog: {e}")))?;
+        Ok(Self { inner })
     }
 
     // TODO: Cannot pass mixed data across pyo3 as a single type
@@ -0,0 +1,427 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Records the data and events published on the message bus during a session to feather
+//! files, which can be converted into catalog data with
+//! [`ParquetDataCatalog::convert_stream_to_data`](crate::backend::catalog::ParquetDataCatalog::convert_stream_to_data).
+
+use std::{any::Any, cell::RefCell, collections::HashSet, fmt::Debug, rc::Rc, sync::Arc};
+
+use futures::TryStreamExt;
+use nautilus_common::{
+    clock::Clock,
+    msgbus::{
+        self,
+        handler::{ShareableMessageHandler, TypedMessageHandler},
+    },
+};
+use nautilus_model::{
+    data::{
+        Bar, IndexPriceUpdate, InstrumentClose, MarkPriceUpdate, OrderBookDelta, OrderBookDeltas,
+        OrderBookDepth10, QuoteTick, TradeTick,
+    },
+    events::{AccountState, OrderDenied, OrderEventAny, PositionSnapshot},
+    instruments::InstrumentAny,
+    orders::{Order, OrderAny},
+    position::Position,
+};
+use object_store::{ObjectStore, local::LocalFileSystem, path::Path as ObjectPath};
+
+use crate::{
+    backend::{catalog::CatalogPathPrefix, feather::FeatherWriter, store::create_object_store},
+    config::{DataCatalogConfig, StreamingConfig},
+};
+
+/// The message bus patterns of the topics recorded by a [`SessionRecorder`].
+pub const RECORDED_PATTERNS: [&str; 4] = [
+    "data.*",
+    "events.order.*",
+    "events.account.*",
+    "positions.snapshots.*",
+];
+
+/// The subdirectory of the catalog live sessions are recorded under.
+pub const LIVE_SUBDIRECTORY: &str = "live";
+
+/// Records every data and event message published on the message bus to feather files
+/// through a [`FeatherWriter`], so a session can later be converted into catalog data
+/// and replayed as a backtest.
+///
+/// Market data is written to one file per instrument (or bar type) within a directory per
+/// data type. Instruments, order events, position snapshots and account states are written
+/// to one file per type.
+pub struct SessionRecorder {
+    writer: Rc<RefCell<FeatherWriter>>,
+    flush_interval_ms: u64,
+    replace_existing: bool,
+    handler: Option<ShareableMessageHandler>,
+}
+
+impl Debug for SessionRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(SessionRecorder))
+            .field("flush_interval_ms", &self.flush_interval_ms)
+            .field("replace_existing", &self.replace_existing)
+            .field("is_recording", &self.is_recording())
+            .finish()
+    }
+}
+
+impl SessionRecorder {
+    /// Creates a new [`SessionRecorder`] instance writing through `writer`.
+    #[must_use]
+    pub fn new(writer: FeatherWriter, flush_interval_ms: u64, replace_existing: bool) -> Self {
+        Self {
+            writer: Rc::new(RefCell::new(writer)),
+            flush_interval_ms,
+            replace_existing,
+            handler: None,
+        }
+    }
+
+    /// Creates a new [`SessionRecorder`] instance from the streaming `config`, recording
+    /// to the `live/{instance_id}` directory of the configured catalog.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object store for the catalog cannot be created.
+    pub fn from_config(
+        config: &StreamingConfig,
+        instance_id: &str,
+        clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Self> {
+        let uri = DataCatalogConfig::new(
+            config.catalog_path().to_string(),
+            config.fst_protocol().to_string(),
+            None,
+        )
+        .uri();
+
+        let (store, catalog_path): (Arc<dyn ObjectStore>, ObjectPath) = if uri.contains("://") {
+            let (store, _, path) = create_object_store(&uri, None)?;
+            (store, path)
+        } else {
+            let path = ObjectPath::from_absolute_path(std::path::absolute(&uri)?)?;
+            (Arc::new(LocalFileSystem::new()), path)
+        };
+        let base_path = catalog_path.child(LIVE_SUBDIRECTORY).child(instance_id);
+
+        let writer = FeatherWriter::new(
+            base_path.to_string(),
+            store,
+            clock,
+            config.rotation_config().clone(),
+            None,
+            Some(per_instrument_types()),
+        );
+
+        Ok(Self::new(
+            writer,
+            config.flush_interval_ms(),
+            config.replace_existing(),
+        ))
+    }
+
+    /// Returns the interval (milliseconds) at which buffered data should be flushed.
+    #[must_use]
+    pub const fn flush_interval_ms(&self) -> u64 {
+        self.flush_interval_ms
+    }
+
+    /// Returns whether the recorder is subscribed to the message bus.
+    #[must_use]
+    pub const fn is_recording(&self) -> bool {
+        self.handler.is_some()
+    }
+
+    /// Starts recording by subscribing to the [`RECORDED_PATTERNS`] on the message bus.
+    pub fn start(&mut self) {
+        if self.is_recording() {
+            log::warn!("SessionRecorder already recording");
+            return;
+        }
+
+        let writer = self.writer.clone();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from_any(
+            Some("SessionRecorder"),
+            move |message: &dyn Any| {
+                if let Err(e) = record(&mut writer.borrow_mut(), message) {
+                    log::error!("Failed to record message: {e}");
+                }
+            },
+        )));
+
+        for pattern in RECORDED_PATTERNS {
+            msgbus::subscribe_str(pattern, handler.clone(), None);
+        }
+
+        self.handler = Some(handler);
+        log::info!("SessionRecorder started");
+    }
+
+    /// Stops recording by unsubscribing from the message bus.
+    ///
+    /// Data already buffered is kept until the next [`SessionRecorder::flush`].
+    pub fn stop(&mut self) {
+        let Some(handler) = self.handler.take() else {
+            return;
+        };
+
+        for pattern in RECORDED_PATTERNS {
+            msgbus::unsubscribe_str(pattern, handler.clone());
+        }
+
+        log::info!("SessionRecorder stopped");
+    }
+
+    /// Deletes the files of a previous session with the same instance ID when the
+    /// recorder is configured to replace existing files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if listing or deleting the files fails.
+    pub async fn clear_existing(&self) -> anyhow::Result<()> {
+        if !self.replace_existing {
+            return Ok(());
+        }
+
+        let (store, base_path) = {
+            let writer = self.writer.borrow();
+            (writer.store(), ObjectPath::from(writer.base_path()))
+        };
+        let files: Vec<ObjectPath> = store
+            .list(Some(&base_path))
+            .map_ok(|meta| meta.location)
+            .try_collect()
+            .await?;
+
+        for file in files {
+            store.delete(&file).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes all buffered data to the object store, so each flush starts new files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encoding or writing any of the files fails.
+    pub async fn flush(&self) -> anyhow::Result<()> {
+        // Take the buffers under a short borrow, as messages may be recorded while writing
+        let (store, files) = {
+            let mut writer = self.writer.borrow_mut();
+            (writer.store(), writer.take_all()?)
+        };
+
+        for (path, bytes) in files {
+            store.put(&path, bytes.into()).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for SessionRecorder {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn per_instrument_types() -> HashSet<String> {
+    [
+        QuoteTick::path_prefix(),
+        TradeTick::path_prefix(),
+        OrderBookDelta::path_prefix(),
+        OrderBookDepth10::path_prefix(),
+        Bar::path_prefix(),
+        MarkPriceUpdate::path_prefix(),
+        IndexPriceUpdate::path_prefix(),
+        InstrumentClose::path_prefix(),
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .collect()
+}
+
+/// Buffers `message` in `writer` if it is of a recorded type, other messages are ignored.
+fn record(writer: &mut FeatherWriter, message: &dyn Any) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+        writer.buffer(*quote)
+    } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+        writer.buffer(*trade)
+    } else if let Some(bar) = message.downcast_ref::<Bar>() {
+        writer.buffer(*bar)
+    } else if let Some(deltas) = message.downcast_ref::<OrderBookDeltas>() {
+        deltas
+            .deltas
+            .iter()
+            .try_for_each(|delta| writer.buffer(*delta))
+    } else if let Some(depth) = message.downcast_ref::<OrderBookDepth10>() {
+        writer.buffer(*depth)
+    } else if let Some(mark_price) = message.downcast_ref::<MarkPriceUpdate>() {
+        writer.buffer(*mark_price)
+    } else if let Some(index_price) = message.downcast_ref::<IndexPriceUpdate>() {
+        writer.buffer(*index_price)
+    } else if let Some(close) = message.downcast_ref::<InstrumentClose>() {
+        writer.buffer(*close)
+    } else if let Some(instrument) = message.downcast_ref::<InstrumentAny>() {
+        writer.buffer(instrument.clone())
+    } else if let Some(order) = message.downcast_ref::<OrderAny>() {
+        writer.buffer(order.last_event().clone())
+    } else if let Some(denied) = message.downcast_ref::<OrderDenied>() {
+        writer.buffer(OrderEventAny::Denied(*denied))
+    } else if let Some(position) = message.downcast_ref::<Position>() {
+        writer.buffer(PositionSnapshot::from(position, None))
+    } else if let Some(account_state) = message.downcast_ref::<AccountState>() {
+        writer.buffer(account_state.clone())
+    } else {
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::clock::TestClock;
+    use nautilus_model::{
+        data::stubs::{quote_ethusdt_binance, stub_delta, stub_trade_ethusdt_buyer},
+        enums::OrderType,
+        orders::OrderTestBuilder,
+        types::Quantity,
+    };
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::backend::feather::RotationConfig;
+
+    fn recorder(temp_dir: &TempDir) -> SessionRecorder {
+        let config = StreamingConfig::new(
+            temp_dir.path().to_str().unwrap().to_string(),
+            "file".to_string(),
+            1000,
+            false,
+            RotationConfig::NoRotation,
+        );
+        let clock: Rc<RefCell<dyn Clock>> = Rc::new(RefCell::new(TestClock::new()));
+        SessionRecorder::from_config(&config, "trader-001", clock).unwrap()
+    }
+
+    fn recorded_files(temp_dir: &TempDir) -> Vec<String> {
+        let base_path = temp_dir.path().join(LIVE_SUBDIRECTORY).join("trader-001");
+        let mut files: Vec<String> = walk(&base_path)
+            .into_iter()
+            .map(|path| {
+                path.strip_prefix(&base_path)
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    fn walk(path: &std::path::Path) -> Vec<std::path::PathBuf> {
+        let Ok(entries) = std::fs::read_dir(path) else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .flat_map(|entry| {
+                let path = entry.path();
+                if path.is_dir() {
+                    walk(&path)
+                } else {
+                    vec![path]
+                }
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_record_ignores_unknown_messages() {
+        let temp_dir = TempDir::new().unwrap();
+        let recorder = recorder(&temp_dir);
+
+        record(&mut recorder.writer.borrow_mut(), &"unknown").unwrap();
+
+        assert!(recorder.writer.borrow_mut().take_all().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_recorder_records_published_messages() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut recorder = recorder(&temp_dir);
+        let quote = quote_ethusdt_binance();
+        let trade = stub_trade_ethusdt_buyer();
+        let deltas = OrderBookDeltas::new(stub_delta().instrument_id, vec![stub_delta()]);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(quote.instrument_id)
+            .quantity(Quantity::from(1))
+            .build();
+
+        recorder.start();
+        assert!(recorder.is_recording());
+
+        msgbus::publish(
+            "data.quotes.BINANCE.ETHUSDT-PERP".into(),
+            &quote as &dyn Any,
+        );
+        msgbus::publish(
+            "data.trades.BINANCE.ETHUSDT-PERP".into(),
+            &trade as &dyn Any,
+        );
+        msgbus::publish("data.book.deltas.XNAS.AAPL".into(), &deltas as &dyn Any);
+        msgbus::publish("events.order.S-001".into(), &order as &dyn Any);
+        msgbus::publish("events.position.S-001".into(), &"not recorded" as &dyn Any);
+
+        recorder.stop();
+        msgbus::publish(
+            "data.quotes.BINANCE.ETHUSDT-PERP".into(),
+            &quote as &dyn Any,
+        );
+        recorder.flush().await.unwrap();
+
+        let files = recorded_files(&temp_dir);
+        assert_eq!(files.len(), 4);
+        assert!(files[0].starts_with("order_book_deltas/AAPL.XNAS_"));
+        assert!(files[1].starts_with("order_events_"));
+        assert!(files[2].starts_with("quotes/ETHUSDT-PERP.BINANCE_"));
+        assert!(files[3].starts_with("trades/ETHUSDT-PERP.BINANCE_"));
+    }
+
+    #[tokio::test]
+    async fn test_recorder_clear_existing() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut recorder = recorder(&temp_dir);
+        recorder.replace_existing = true;
+
+        recorder
+            .writer
+            .borrow_mut()
+            .buffer(quote_ethusdt_binance())
+            .unwrap();
+        recorder.flush().await.unwrap();
+        assert_eq!(recorded_files(&temp_dir).len(), 1);
+
+        recorder.clear_existing().await.unwrap();
+
+        assert!(recorded_files(&temp_dir).is_empty());
+    }
+}
@@ -13,22 +13,49 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::path::PathBuf;
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+};
 
+use nautilus_common::clock::TestClock;
 use nautilus_core::{ffi::cvec::CVec, python::IntoPyObjectNautilusExt};
-use nautilus_model::data::{
-    Bar, Data, OrderBookDelta, QuoteTick, TradeTick, is_monotonically_increasing_by_init,
-    to_variant,
+use nautilus_model::{
+    data::{
+        Bar, Data, OrderBookDelta, QuoteTick, TradeTick, is_monotonically_increasing_by_init,
+        to_variant,
+    },
+    events::{
+        AccountState, OrderAccepted, OrderEventAny, OrderFilled, OrderInitialized,
+        PositionSnapshot,
+        account::stubs::{cash_account_state, margin_account_state},
+        order::stubs::{order_accepted, order_filled, order_initialized_buy_limit},
+    },
+    identifiers::InstrumentId,
+    instruments::{
+        InstrumentAny,
+        stubs::{audusd_sim, currency_pair_btcusdt, equity_aapl},
+    },
+    stubs::{stub_position_long, stub_position_short},
+    types::{Price, Quantity},
 };
 use nautilus_persistence::{
     backend::{
-        catalog::ParquetDataCatalog,
+        catalog::{CatalogDataSummary, ParquetDataCatalog, merge_intervals, missing_intervals},
+        feather::{FeatherWriter, RotationConfig},
+        manifest::{CatalogManifest, FileBounds},
+        partition::CatalogPartitioning,
         session::{DataBackendSession, DataQueryResult, QueryResult},
     },
+    config::DataCatalogConfig,
     python::backend::session::NautilusDataType,
 };
-use nautilus_serialization::arrow::ArrowSchemaProvider;
+use nautilus_serialization::{arrow::ArrowSchemaProvider, enums::ParquetWriteMode};
 use nautilus_testkit::common::get_nautilus_test_data_file_path;
+use object_store::local::LocalFileSystem;
 #[cfg(target_os = "linux")]
 use procfs::{self, process::Process};
 use pyo3::{prelude::*, types::PyCapsule};
@@ -498,3 +525,697 @@ fn test_catalog_export_functionality() {
         assert_eq!(original, final_quote, "Quotes don't match");
     }
 }
+
+fn quote_at(instrument_id: InstrumentId, ts: u64) -> QuoteTick {
+    QuoteTick::new(
+        instrument_id,
+        Price::from("1.00000"),
+        Price::from("1.00001"),
+        Quantity::from(100_000),
+        Quantity::from(100_000),
+        ts.into(),
+        ts.into(),
+    )
+}
+
+fn quote_metadata(instrument_id: InstrumentId) -> HashMap<String, String> {
+    QuoteTick::get_metadata(&instrument_id, 5, 0)
+}
+
+#[rstest]
+#[case(vec![], vec![])]
+#[case(vec![(5, 10), (1, 3)], vec![(1, 3), (5, 10)])]
+#[case(vec![(1, 5), (6, 10)], vec![(1, 10)])]
+#[case(vec![(1, 8), (3, 5), (7, 12)], vec![(1, 12)])]
+fn test_merge_intervals(#[case] intervals: Vec<(u64, u64)>, #[case] expected: Vec<(u64, u64)>) {
+    assert_eq!(merge_intervals(intervals), expected);
+}
+
+#[rstest]
+#[case(vec![], vec![(10, 20)])]
+#[case(vec![(0, 30)], vec![])]
+#[case(vec![(10, 20)], vec![])]
+#[case(vec![(0, 12)], vec![(13, 20)])]
+#[case(vec![(15, 30)], vec![(10, 14)])]
+#[case(vec![(12, 13), (16, 17)], vec![(10, 11), (14, 15), (18, 20)])]
+#[case(vec![(0, 5), (25, 30)], vec![(10, 20)])]
+fn test_missing_intervals(#[case] intervals: Vec<(u64, u64)>, #[case] expected: Vec<(u64, u64)>) {
+    assert_eq!(missing_intervals(10, 20, &intervals), expected);
+}
+
+#[rstest]
+fn test_catalog_write_interval_and_query_identifier() {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let instrument_id = InstrumentId::from("AUD/USD.SIM");
+    let identifier = instrument_id.to_string();
+
+    let first = vec![
+        quote_at(instrument_id, 1_000),
+        quote_at(instrument_id, 2_000),
+    ];
+    let second = vec![quote_at(instrument_id, 3_000)];
+    let metadata = quote_metadata(instrument_id);
+    catalog
+        .write_interval(first.clone(), &metadata, 1_000, 2_500)
+        .unwrap();
+    catalog
+        .write_interval(second.clone(), &metadata, 2_501, 4_000)
+        .unwrap();
+    let empty = catalog
+        .write_interval(Vec::<QuoteTick>::new(), &metadata, 5_000, 6_000)
+        .unwrap();
+
+    assert!(empty.ends_with("5000-6000.parquet"));
+    assert_eq!(
+        catalog
+            .get_intervals("quotes", Some(identifier.clone()))
+            .unwrap(),
+        vec![(1_000, 4_000), (5_000, 6_000)]
+    );
+    assert_eq!(
+        catalog
+            .get_missing_intervals("quotes", Some(identifier.clone()), 0, 7_000)
+            .unwrap(),
+        vec![(0, 999), (4_001, 4_999), (6_001, 7_000)]
+    );
+
+    let all: Vec<QuoteTick> = to_variant(
+        catalog
+            .query_identifier::<QuoteTick>(&identifier, None, None)
+            .unwrap(),
+    );
+    let range: Vec<QuoteTick> = to_variant(
+        catalog
+            .query_identifier::<QuoteTick>(&identifier, Some(2_000.into()), Some(3_000.into()))
+            .unwrap(),
+    );
+
+    assert_eq!(all, [first.clone(), second.clone()].concat());
+    assert_eq!(range, vec![first[1], second[0]]);
+}
+
+#[rstest]
+fn test_catalog_get_intervals_falls_back_to_file_metadata() {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let instrument_id = InstrumentId::from("AUD/USD.SIM");
+    let quotes = vec![
+        quote_at(instrument_id, 1_000),
+        quote_at(instrument_id, 2_000),
+    ];
+
+    catalog
+        .write_to_parquet(quotes, None, None, None, None)
+        .unwrap();
+
+    assert_eq!(
+        catalog
+            .get_intervals("quotes", Some(instrument_id.to_string()))
+            .unwrap(),
+        vec![(1_000, 2_000)]
+    );
+}
+
+#[rstest]
+fn test_catalog_in_memory_write_and_query() {
+    let mut catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    let instrument_id = InstrumentId::from("AUD/USD.SIM");
+    let quotes = vec![
+        quote_at(instrument_id, 1_000),
+        quote_at(instrument_id, 2_000),
+    ];
+
+    let path = catalog
+        .write_to_parquet(quotes.clone(), None, None, None, None)
+        .unwrap();
+
+    assert_eq!(catalog.uri(), "memory:///catalog");
+    assert_eq!(
+        path,
+        PathBuf::from("memory:///catalog/data/quotes/AUDUSD.SIM/part-0.parquet")
+    );
+    assert_eq!(
+        catalog
+            .query_parquet_files("quotes", Some(instrument_id.to_string()))
+            .unwrap(),
+        vec![path]
+    );
+    assert_eq!(
+        catalog
+            .query_timestamp_bound("quotes", Some(instrument_id.to_string()), Some(true))
+            .unwrap(),
+        Some(2_000)
+    );
+
+    let queried: Vec<QuoteTick> = to_variant(
+        catalog
+            .query_identifier::<QuoteTick>(&instrument_id.to_string(), None, None)
+            .unwrap(),
+    );
+    assert_eq!(queried, quotes);
+}
+
+#[rstest]
+fn test_catalog_in_memory_append_write_mode() {
+    let mut catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    let instrument_id = InstrumentId::from("AUD/USD.SIM");
+    let first = vec![quote_at(instrument_id, 1_000)];
+    let second = vec![quote_at(instrument_id, 2_000)];
+
+    catalog
+        .write_to_parquet(first.clone(), None, None, None, None)
+        .unwrap();
+    catalog
+        .write_to_parquet(
+            second.clone(),
+            None,
+            None,
+            None,
+            Some(ParquetWriteMode::Append),
+        )
+        .unwrap();
+
+    let queried: Vec<QuoteTick> = to_variant(
+        catalog
+            .query_directory::<QuoteTick>(vec![instrument_id.to_string()], None, None, None)
+            .unwrap()
+            .collect(),
+    );
+    assert_eq!(queried, [first, second].concat());
+}
+
+#[rstest]
+fn test_catalog_in_memory_consolidate_data() {
+    let mut catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    let instrument_id = InstrumentId::from("AUD/USD.SIM");
+    let first = vec![
+        quote_at(instrument_id, 1_000),
+        quote_at(instrument_id, 2_000),
+    ];
+    let second = vec![
+        quote_at(instrument_id, 3_000),
+        quote_at(instrument_id, 4_000),
+    ];
+
+    for quotes in [second.clone(), first.clone()] {
+        catalog
+            .write_to_parquet(quotes, None, None, None, Some(ParquetWriteMode::NewFile))
+            .unwrap();
+    }
+    assert_eq!(
+        catalog
+            .query_parquet_files("quotes", Some(instrument_id.to_string()))
+            .unwrap()
+            .len(),
+        2
+    );
+
+    catalog.consolidate_catalog().unwrap();
+
+    assert_eq!(
+        catalog
+            .query_parquet_files("quotes", Some(instrument_id.to_string()))
+            .unwrap()
+            .len(),
+        1
+    );
+    let queried: Vec<QuoteTick> = to_variant(
+        catalog
+            .query_identifier::<QuoteTick>(&instrument_id.to_string(), None, None)
+            .unwrap(),
+    );
+    assert_eq!(queried, [first, second].concat());
+}
+
+const DAY: u64 = 86_400_000_000_000;
+
+fn quote_file_names(catalog: &ParquetDataCatalog, instrument_id: InstrumentId) -> Vec<String> {
+    catalog
+        .query_parquet_files("quotes", Some(instrument_id.to_string()))
+        .unwrap()
+        .iter()
+        .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+        .collect()
+}
+
+#[rstest]
+fn test_catalog_daily_partitioned_writes_deduplicate() {
+    let mut catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None)
+        .unwrap()
+        .with_partitioning(CatalogPartitioning::Daily);
+    let instrument_id = InstrumentId::from("AUD/USD.SIM");
+    let first = vec![
+        quote_at(instrument_id, 1_000),
+        quote_at(instrument_id, DAY + 1_000),
+    ];
+    let second = vec![
+        quote_at(instrument_id, 1_000),
+        quote_at(instrument_id, 2_000),
+    ];
+
+    let path = catalog
+        .write_to_parquet(first, None, None, None, None)
+        .unwrap();
+    catalog
+        .write_to_parquet(second, None, None, None, None)
+        .unwrap();
+
+    assert_eq!(
+        path,
+        PathBuf::from("memory:///catalog/data/quotes/AUDUSD.SIM")
+    );
+    assert_eq!(
+        quote_file_names(&catalog, instrument_id),
+        vec![
+            "1000-2000.parquet".to_string(),
+            format!("{0}-{0}.parquet", DAY + 1_000),
+        ]
+    );
+
+    let queried: Vec<QuoteTick> = to_variant(
+        catalog
+            .query_identifier::<QuoteTick>(&instrument_id.to_string(), None, None)
+            .unwrap(),
+    );
+    assert_eq!(
+        queried,
+        vec![
+            quote_at(instrument_id, 1_000),
+            quote_at(instrument_id, 2_000),
+            quote_at(instrument_id, DAY + 1_000),
+        ]
+    );
+}
+
+#[rstest]
+fn test_catalog_consolidate_respects_partitions() {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let instrument_id = InstrumentId::from("AUD/USD.SIM");
+
+    // Overlapping files, each spanning both days
+    for quotes in [
+        vec![
+            quote_at(instrument_id, 1_000),
+            quote_at(instrument_id, DAY + 1_000),
+        ],
+        vec![
+            quote_at(instrument_id, 2_000),
+            quote_at(instrument_id, DAY + 1_000),
+        ],
+    ] {
+        catalog
+            .write_to_parquet(quotes, None, None, None, Some(ParquetWriteMode::NewFile))
+            .unwrap();
+    }
+
+    let mut partitioned = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None)
+        .with_partitioning(CatalogPartitioning::Daily);
+    partitioned.consolidate_catalog().unwrap();
+    let file_names = quote_file_names(&partitioned, instrument_id);
+    partitioned.consolidate_catalog().unwrap();
+
+    assert_eq!(
+        file_names,
+        vec![
+            "1000-2000.parquet".to_string(),
+            format!("{0}-{0}.parquet", DAY + 1_000),
+        ]
+    );
+    assert_eq!(quote_file_names(&partitioned, instrument_id), file_names);
+
+    let queried: Vec<QuoteTick> = to_variant(
+        partitioned
+            .query_identifier::<QuoteTick>(&instrument_id.to_string(), None, None)
+            .unwrap(),
+    );
+    assert_eq!(
+        queried,
+        vec![
+            quote_at(instrument_id, 1_000),
+            quote_at(instrument_id, 2_000),
+            quote_at(instrument_id, DAY + 1_000),
+        ]
+    );
+}
+
+#[rstest]
+fn test_catalog_delete_data_range() {
+    let mut catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    let instrument_id = InstrumentId::from("AUD/USD.SIM");
+    let identifier = instrument_id.to_string();
+    let quotes: Vec<QuoteTick> = [1_000, 2_000, 3_000, 4_000]
+        .into_iter()
+        .map(|ts| quote_at(instrument_id, ts))
+        .collect();
+
+    catalog
+        .write_to_parquet(quotes.clone(), None, None, None, None)
+        .unwrap();
+    catalog
+        .delete_data_range(
+            "quotes",
+            Some(identifier.clone()),
+            Some(2_000.into()),
+            Some(3_000.into()),
+        )
+        .unwrap();
+
+    assert_eq!(
+        quote_file_names(&catalog, instrument_id),
+        vec!["1000-1999.parquet", "3001-4000.parquet"]
+    );
+    assert_eq!(
+        catalog
+            .get_intervals("quotes", Some(identifier.clone()))
+            .unwrap(),
+        vec![(1_000, 1_999), (3_001, 4_000)]
+    );
+    let queried: Vec<QuoteTick> = to_variant(
+        catalog
+            .query_identifier::<QuoteTick>(&identifier, None, None)
+            .unwrap(),
+    );
+    assert_eq!(queried, vec![quotes[0], quotes[3]]);
+
+    catalog
+        .delete_catalog_range(None, Some(1_999.into()))
+        .unwrap();
+
+    assert_eq!(
+        quote_file_names(&catalog, instrument_id),
+        vec!["3001-4000.parquet"]
+    );
+    let queried: Vec<QuoteTick> = to_variant(
+        catalog
+            .query_identifier::<QuoteTick>(&identifier, None, None)
+            .unwrap(),
+    );
+    assert_eq!(queried, vec![quotes[3]]);
+}
+
+#[rstest]
+fn test_catalog_manifest_prunes_files_by_time_range() {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let instrument_id = InstrumentId::from("AUD/USD.SIM");
+    let manifest_path = temp_dir
+        .path()
+        .join("data/quotes/AUDUSD.SIM/_manifest.json");
+    let read_manifest =
+        || CatalogManifest::from_json(&std::fs::read(&manifest_path).unwrap()).unwrap();
+
+    let metadata = quote_metadata(instrument_id);
+    catalog
+        .write_interval(
+            vec![quote_at(instrument_id, 1_000)],
+            &metadata,
+            1_000,
+            2_500,
+        )
+        .unwrap();
+    catalog
+        .write_interval(
+            vec![quote_at(instrument_id, 3_000)],
+            &metadata,
+            2_501,
+            4_000,
+        )
+        .unwrap();
+
+    let files = catalog
+        .query_parquet_files_in_range(
+            "quotes",
+            Some(instrument_id.to_string()),
+            Some(2_600.into()),
+            Some(3_500.into()),
+        )
+        .unwrap();
+
+    assert_eq!(files.len(), 1);
+    assert!(files[0].ends_with("2501-4000.parquet"));
+
+    let manifest = read_manifest();
+    assert_eq!(
+        manifest.get("1000-2500.parquet"),
+        Some(FileBounds {
+            start: 1_000,
+            end: 1_000
+        })
+    );
+    assert_eq!(
+        manifest.get("2501-4000.parquet"),
+        Some(FileBounds {
+            start: 3_000,
+            end: 3_000
+        })
+    );
+
+    std::fs::remove_file(&manifest_path).unwrap();
+    catalog.rebuild_manifests().unwrap();
+
+    assert_eq!(read_manifest(), manifest);
+}
+
+#[rstest]
+fn test_catalog_convert_stream_to_data() {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let clock = Rc::new(RefCell::new(TestClock::new()));
+    let audusd = InstrumentId::from("AUD/USD.SIM");
+    let gbpusd = InstrumentId::from("GBP/USD.SIM");
+    let mut writer = FeatherWriter::new(
+        format!("{}/live/trader-001", temp_dir.path().display()),
+        Arc::new(LocalFileSystem::new()),
+        clock.clone(),
+        RotationConfig::NoRotation,
+        None,
+        Some(HashSet::from(["quotes".to_string()])),
+    );
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    // Two flushes write separate files, with the second repeating a quote
+    for ts in [1_000, 2_000] {
+        writer.buffer(quote_at(audusd, ts)).unwrap();
+        writer.buffer(quote_at(gbpusd, ts)).unwrap();
+        runtime.block_on(writer.flush()).unwrap();
+        clock.borrow_mut().advance_time(ts.into(), true);
+    }
+    writer.buffer(quote_at(audusd, 2_000)).unwrap();
+    writer.buffer(quote_at(audusd, 3_000)).unwrap();
+    writer
+        .buffer(InstrumentAny::CurrencyPair(audusd_sim()))
+        .unwrap();
+    runtime.block_on(writer.flush()).unwrap();
+    drop(runtime);
+
+    let paths = catalog
+        .convert_stream_to_data("trader-001", "live")
+        .unwrap();
+
+    assert_eq!(paths.len(), 3);
+    let queried: Vec<QuoteTick> = to_variant(
+        catalog
+            .query_identifier::<QuoteTick>(&audusd.to_string(), None, None)
+            .unwrap(),
+    );
+    assert_eq!(
+        queried,
+        vec![
+            quote_at(audusd, 1_000),
+            quote_at(audusd, 2_000),
+            quote_at(audusd, 3_000),
+        ]
+    );
+    assert_eq!(quote_file_names(&catalog, gbpusd), vec!["part-0.parquet"]);
+    let instruments: Vec<InstrumentAny> = catalog.query_typed(None, None).unwrap();
+    assert_eq!(instruments, vec![InstrumentAny::CurrencyPair(audusd_sim())]);
+}
+
+#[rstest]
+fn test_catalog_list_data_and_find_gaps() {
+    let catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    let audusd = InstrumentId::from("AUD/USD.SIM");
+    let gbpusd = InstrumentId::from("GBP/USD.SIM");
+
+    for quotes in [
+        vec![quote_at(audusd, 1_000), quote_at(audusd, 2_000)],
+        vec![quote_at(audusd, 10_000)],
+    ] {
+        catalog
+            .write_to_parquet(quotes, None, None, None, Some(ParquetWriteMode::NewFile))
+            .unwrap();
+    }
+    catalog
+        .write_to_parquet(vec![quote_at(gbpusd, 5_000)], None, None, None, None)
+        .unwrap();
+    catalog
+        .write_to_parquet(
+            vec![InstrumentAny::CurrencyPair(audusd_sim())],
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+    let summaries = catalog.list_data().unwrap();
+
+    assert_eq!(
+        summaries,
+        vec![
+            CatalogDataSummary {
+                type_name: "instruments".to_string(),
+                identifier: None,
+                file_count: 1,
+                row_count: 1,
+                start: 0,
+                end: 0,
+            },
+            CatalogDataSummary {
+                type_name: "quotes".to_string(),
+                identifier: Some("AUDUSD.SIM".to_string()),
+                file_count: 2,
+                row_count: 3,
+                start: 1_000,
+                end: 10_000,
+            },
+            CatalogDataSummary {
+                type_name: "quotes".to_string(),
+                identifier: Some("GBPUSD.SIM".to_string()),
+                file_count: 1,
+                row_count: 1,
+                start: 5_000,
+                end: 5_000,
+            },
+        ]
+    );
+    assert_eq!(
+        catalog
+            .find_gaps("quotes", Some(audusd.to_string()), 1_000)
+            .unwrap(),
+        vec![(2_000, 10_000)]
+    );
+    assert!(
+        catalog
+            .find_gaps("quotes", Some(audusd.to_string()), 8_000)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[rstest]
+fn test_catalog_from_config_partitioning() {
+    let config = DataCatalogConfig::new("/catalog".to_string(), "memory".to_string(), None)
+        .with_partitioning(CatalogPartitioning::Monthly);
+
+    let catalog = ParquetDataCatalog::from_config(&config, None).unwrap();
+
+    assert_eq!(catalog.partitioning(), CatalogPartitioning::Monthly);
+}
+
+#[rstest]
+fn test_catalog_instruments_round_trip() {
+    let catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    let instruments = vec![
+        InstrumentAny::CurrencyPair(audusd_sim()),
+        InstrumentAny::CurrencyPair(currency_pair_btcusdt()),
+        InstrumentAny::Equity(equity_aapl()),
+    ];
+
+    let path = catalog
+        .write_to_parquet(instruments.clone(), None, None, None, None)
+        .unwrap();
+
+    assert_eq!(
+        path,
+        PathBuf::from("memory:///catalog/data/instruments/part-0.parquet")
+    );
+    let queried: Vec<InstrumentAny> = catalog.query_typed(None, None).unwrap();
+    assert_eq!(queried, instruments);
+}
+
+#[rstest]
+fn test_catalog_order_events_query_by_time_range(
+    mut order_initialized_buy_limit: OrderInitialized,
+    mut order_accepted: OrderAccepted,
+    mut order_filled: OrderFilled,
+) {
+    let catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    order_initialized_buy_limit.ts_init = 1_000.into();
+    order_accepted.ts_init = 2_000.into();
+    order_filled.ts_init = 3_000.into();
+    let initialized = OrderEventAny::Initialized(order_initialized_buy_limit);
+    let accepted = OrderEventAny::Accepted(order_accepted);
+    let filled = OrderEventAny::Filled(order_filled);
+
+    // Write in separate files out of order, as a live session flushing events would
+    for events in [
+        vec![accepted.clone(), filled.clone()],
+        vec![initialized.clone()],
+    ] {
+        catalog
+            .write_to_parquet(events, None, None, None, Some(ParquetWriteMode::NewFile))
+            .unwrap();
+    }
+
+    let all: Vec<OrderEventAny> = catalog.query_typed(None, None).unwrap();
+    let ranged: Vec<OrderEventAny> = catalog
+        .query_typed(Some(2_000.into()), Some(2_500.into()))
+        .unwrap();
+
+    assert_eq!(all, vec![initialized, accepted.clone(), filled]);
+    assert_eq!(ranged, vec![accepted]);
+}
+
+#[rstest]
+fn test_catalog_position_snapshots_and_account_states_round_trip(
+    cash_account_state: AccountState,
+    margin_account_state: AccountState,
+) {
+    let catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    let snapshots = vec![
+        PositionSnapshot::from(&stub_position_long(audusd_sim()), None),
+        PositionSnapshot::from(&stub_position_short(audusd_sim()), None),
+    ];
+    let states = vec![cash_account_state, margin_account_state];
+
+    catalog
+        .write_to_parquet(snapshots.clone(), None, None, None, None)
+        .unwrap();
+    catalog
+        .write_to_parquet(states.clone(), None, None, None, None)
+        .unwrap();
+
+    let queried_snapshots: Vec<PositionSnapshot> = catalog.query_typed(None, None).unwrap();
+    let queried_states: Vec<AccountState> = catalog.query_typed(None, None).unwrap();
+
+    assert_eq!(queried_snapshots, snapshots);
+    assert_eq!(queried_states.len(), 2);
+    assert_eq!(queried_states[0].balances, states[0].balances);
+    assert_eq!(queried_states[1].margins, states[1].margins);
+    assert!(
+        catalog
+            .query_typed::<InstrumentAny>(None, None)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[rstest]
+#[case("/data/catalog", "file", "/data/catalog")]
+#[case("/data/catalog", "", "/data/catalog")]
+#[case("bucket/catalog", "s3", "s3://bucket/catalog")]
+#[case("bucket/catalog", "gcs", "gs://bucket/catalog")]
+#[case("s3://bucket/catalog", "s3", "s3://bucket/catalog")]
+#[case("/catalog", "memory", "memory:///catalog")]
+fn test_data_catalog_config_uri(
+    #[case] path: &str,
+    #[case] fs_protocol: &str,
+    #[case] expected: &str,
+) {
+    let config = DataCatalogConfig::new(path.to_string(), fs_protocol.to_string(), None);
+
+    assert_eq!(config.uri(), expected);
+}
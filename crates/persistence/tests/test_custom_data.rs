@@ -0,0 +1,336 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Float64Array, UInt64Array},
+    datatypes::{DataType as ArrowDataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{CustomDataAny, CustomDataType, Data, GetTsInit, QuoteTick, to_variant},
+    identifiers::InstrumentId,
+    types::{Price, Quantity},
+};
+use nautilus_persistence::backend::{
+    catalog::ParquetDataCatalog,
+    custom::{get_custom_data_registration, register_custom_data_type},
+    session::DataBackendSession,
+};
+use nautilus_serialization::arrow::{
+    ArrowSchemaProvider, DecodeDataFromRecordBatch, DecodeFromRecordBatch, EncodeToRecordBatch,
+    EncodingError, KEY_INSTRUMENT_ID, extract_column,
+};
+use rstest::rstest;
+
+/// An alternative data type scoring the market sentiment for an instrument.
+#[derive(Clone, Debug, PartialEq)]
+struct Sentiment {
+    instrument_id: InstrumentId,
+    score: f64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+}
+
+impl GetTsInit for Sentiment {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl CustomDataType for Sentiment {
+    fn type_name() -> &'static str {
+        "Sentiment"
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn instrument_id(&self) -> Option<InstrumentId> {
+        Some(self.instrument_id)
+    }
+}
+
+impl From<Sentiment> for Data {
+    fn from(value: Sentiment) -> Self {
+        Self::Custom(CustomDataAny::new(value))
+    }
+}
+
+impl ArrowSchemaProvider for Sentiment {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("score", ArrowDataType::Float64, false),
+            Field::new("ts_event", ArrowDataType::UInt64, false),
+            Field::new("ts_init", ArrowDataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for Sentiment {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let score: Float64Array = data.iter().map(|item| item.score).collect();
+        let ts_event: UInt64Array = data.iter().map(|item| item.ts_event.as_u64()).collect();
+        let ts_init: UInt64Array = data.iter().map(|item| item.ts_init.as_u64()).collect();
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![Arc::new(score), Arc::new(ts_event), Arc::new(ts_init)],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        HashMap::from([(
+            KEY_INSTRUMENT_ID.to_string(),
+            self.instrument_id.to_string(),
+        )])
+    }
+}
+
+impl DecodeFromRecordBatch for Sentiment {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let instrument_id = metadata
+            .get(KEY_INSTRUMENT_ID)
+            .ok_or(EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))
+            .and_then(|value| {
+                InstrumentId::from_str(value)
+                    .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))
+            })?;
+        let cols = record_batch.columns();
+        let score = extract_column::<Float64Array>(cols, "score", 0, ArrowDataType::Float64)?;
+        let ts_event = extract_column::<UInt64Array>(cols, "ts_event", 1, ArrowDataType::UInt64)?;
+        let ts_init = extract_column::<UInt64Array>(cols, "ts_init", 2, ArrowDataType::UInt64)?;
+
+        Ok((0..record_batch.num_rows())
+            .map(|row| Self {
+                instrument_id,
+                score: score.value(row),
+                ts_event: ts_event.value(row).into(),
+                ts_init: ts_init.value(row).into(),
+            })
+            .collect())
+    }
+}
+
+impl DecodeDataFromRecordBatch for Sentiment {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let items = Self::decode_batch(metadata, record_batch)?;
+        Ok(items.into_iter().map(Data::from).collect())
+    }
+}
+
+/// A custom data type which is never registered.
+#[derive(Clone, Debug, PartialEq)]
+struct BlockHeight {
+    height: u64,
+    ts_init: UnixNanos,
+}
+
+impl GetTsInit for BlockHeight {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl CustomDataType for BlockHeight {
+    fn type_name() -> &'static str {
+        "BlockHeight"
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+fn sentiment_at(instrument_id: &str, score: f64, ts: u64) -> Sentiment {
+    Sentiment {
+        instrument_id: InstrumentId::from(instrument_id),
+        score,
+        ts_event: ts.into(),
+        ts_init: ts.into(),
+    }
+}
+
+fn quote_at(instrument_id: &str, ts: u64) -> QuoteTick {
+    QuoteTick::new(
+        InstrumentId::from(instrument_id),
+        Price::from("1.00000"),
+        Price::from("1.00001"),
+        Quantity::from(100_000),
+        Quantity::from(100_000),
+        ts.into(),
+        ts.into(),
+    )
+}
+
+fn sentiments(data: Vec<Data>) -> Vec<Sentiment> {
+    to_variant::<CustomDataAny>(data)
+        .iter()
+        .filter_map(|custom| custom.downcast_ref::<Sentiment>().cloned())
+        .collect()
+}
+
+#[rstest]
+fn test_register_custom_data_type() {
+    register_custom_data_type::<Sentiment>();
+
+    let registration = get_custom_data_registration("Sentiment").unwrap();
+
+    assert_eq!(registration.type_name, "Sentiment");
+    assert_eq!(registration.path_prefix, "custom_sentiment");
+}
+
+#[rstest]
+fn test_write_and_query_custom_data() {
+    register_custom_data_type::<Sentiment>();
+    let mut catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    let aapl = vec![
+        sentiment_at("AAPL.XNAS", 0.2, 1_000),
+        sentiment_at("AAPL.XNAS", 0.4, 3_000),
+    ];
+    let msft = vec![sentiment_at("MSFT.XNAS", -0.1, 2_000)];
+    let data: Vec<Data> = [aapl.clone(), msft.clone()]
+        .concat()
+        .into_iter()
+        .map(Data::from)
+        .collect();
+
+    catalog.write_data_enum(data, None);
+
+    let all = sentiments(
+        catalog
+            .query_custom_data("Sentiment", Vec::new(), None, None, None)
+            .unwrap()
+            .collect(),
+    );
+    let aapl_after = sentiments(
+        catalog
+            .query_custom_data(
+                "Sentiment",
+                vec!["AAPL.XNAS".to_string()],
+                Some(2_000.into()),
+                None,
+                None,
+            )
+            .unwrap()
+            .collect(),
+    );
+
+    assert_eq!(all, vec![aapl[0].clone(), msft[0].clone(), aapl[1].clone()]);
+    assert_eq!(aapl_after, vec![aapl[1].clone()]);
+}
+
+#[rstest]
+fn test_write_custom_data_returns_paths_per_instrument() {
+    register_custom_data_type::<Sentiment>();
+    let catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    let data = vec![
+        CustomDataAny::new(sentiment_at("MSFT.XNAS", 0.1, 1_000)),
+        CustomDataAny::new(sentiment_at("AAPL.XNAS", 0.2, 2_000)),
+    ];
+
+    let paths = catalog.write_custom_data(data, None).unwrap();
+
+    assert_eq!(
+        paths
+            .iter()
+            .map(|path| path.to_str().unwrap())
+            .collect::<Vec<_>>(),
+        vec![
+            "memory:///catalog/data/custom_sentiment/AAPL.XNAS/part-0.parquet",
+            "memory:///catalog/data/custom_sentiment/MSFT.XNAS/part-0.parquet",
+        ]
+    );
+}
+
+#[rstest]
+fn test_write_unregistered_custom_data_type_errors() {
+    let catalog = ParquetDataCatalog::from_uri("memory:///catalog", None, None).unwrap();
+    let data = vec![CustomDataAny::new(BlockHeight {
+        height: 1,
+        ts_init: 1.into(),
+    })];
+
+    let result = catalog.write_custom_data(data, None);
+
+    assert!(result.is_err());
+    assert!(
+        catalog
+            .query_custom_data("BlockHeight", Vec::new(), None, None, None)
+            .is_err()
+    );
+}
+
+#[rstest]
+fn test_custom_data_merged_with_market_data_by_ts_init() {
+    register_custom_data_type::<Sentiment>();
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let quotes = vec![quote_at("AAPL.XNAS", 1_000), quote_at("AAPL.XNAS", 3_000)];
+    let custom = vec![
+        CustomDataAny::new(sentiment_at("AAPL.XNAS", 0.2, 2_000)),
+        CustomDataAny::new(sentiment_at("AAPL.XNAS", 0.4, 4_000)),
+    ];
+    let quotes_path = catalog
+        .write_to_parquet(quotes, None, None, None, None)
+        .unwrap();
+    let custom_paths = catalog.write_custom_data(custom, None).unwrap();
+    let registration = get_custom_data_registration("Sentiment").unwrap();
+
+    let mut session = DataBackendSession::new(1_000);
+    session
+        .add_file::<QuoteTick>("quotes", quotes_path.to_str().unwrap(), None)
+        .unwrap();
+    session
+        .add_file_with_decoder(
+            "sentiment",
+            custom_paths[0].to_str().unwrap(),
+            None,
+            registration.decode,
+        )
+        .unwrap();
+    let data: Vec<Data> = session.get_query_result().collect();
+
+    assert_eq!(
+        data.iter()
+            .map(|d| d.ts_init().as_u64())
+            .collect::<Vec<_>>(),
+        vec![1_000, 2_000, 3_000, 4_000]
+    );
+    assert!(matches!(data[0], Data::Quote(_)));
+    assert!(matches!(data[1], Data::Custom(_)));
+    assert_eq!(
+        data[1].instrument_id(),
+        Some(InstrumentId::from("AAPL.XNAS"))
+    );
+}
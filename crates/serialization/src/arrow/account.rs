@@ -0,0 +1,403 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Arrow encoding for [`AccountState`] events.
+//!
+//! Balances and margins are stored as parallel list columns, one list item per balance
+//! or margin, with money values stored as strings carrying their currency and precision.
+
+use std::{collections::HashMap, fmt::Display, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{
+        BooleanArray, BooleanBuilder, ListArray, ListBuilder, StringArray, StringBuilder,
+        UInt64Array,
+    },
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_core::UUID4;
+use nautilus_model::{
+    enums::AccountType,
+    events::AccountState,
+    identifiers::{AccountId, InstrumentId},
+    types::{AccountBalance, Currency, MarginBalance, Money},
+};
+
+use super::{
+    ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch, EncodingError, boolean_value,
+    extract_named_column, parse_value, required, string_list_data_type, string_list_value,
+};
+
+impl ArrowSchemaProvider for AccountState {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("account_id", DataType::Utf8, false),
+            Field::new("account_type", DataType::Utf8, false),
+            Field::new("base_currency", DataType::Utf8, true),
+            Field::new("balance_currency", string_list_data_type(), false),
+            Field::new("balance_total", string_list_data_type(), false),
+            Field::new("balance_locked", string_list_data_type(), false),
+            Field::new("balance_free", string_list_data_type(), false),
+            Field::new("margin_instrument_id", string_list_data_type(), false),
+            Field::new("margin_currency", string_list_data_type(), false),
+            Field::new("margin_initial", string_list_data_type(), false),
+            Field::new("margin_maintenance", string_list_data_type(), false),
+            Field::new("is_reported", DataType::Boolean, false),
+            Field::new("event_id", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for AccountState {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut account_id_builder = StringBuilder::new();
+        let mut account_type_builder = StringBuilder::new();
+        let mut base_currency_builder = StringBuilder::new();
+        let mut balance_currency_builder = ListBuilder::new(StringBuilder::new());
+        let mut balance_total_builder = ListBuilder::new(StringBuilder::new());
+        let mut balance_locked_builder = ListBuilder::new(StringBuilder::new());
+        let mut balance_free_builder = ListBuilder::new(StringBuilder::new());
+        let mut margin_instrument_id_builder = ListBuilder::new(StringBuilder::new());
+        let mut margin_currency_builder = ListBuilder::new(StringBuilder::new());
+        let mut margin_initial_builder = ListBuilder::new(StringBuilder::new());
+        let mut margin_maintenance_builder = ListBuilder::new(StringBuilder::new());
+        let mut is_reported_builder = BooleanBuilder::with_capacity(data.len());
+        let mut event_id_builder = StringBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for item in data {
+            account_id_builder.append_value(item.account_id);
+            account_type_builder.append_value(item.account_type.to_string());
+            base_currency_builder.append_option(item.base_currency.map(|v| v.code));
+            balance_currency_builder
+                .append_value(item.balances.iter().map(|b| Some(b.currency.code)));
+            balance_total_builder
+                .append_value(item.balances.iter().map(|b| Some(b.total.to_string())));
+            balance_locked_builder
+                .append_value(item.balances.iter().map(|b| Some(b.locked.to_string())));
+            balance_free_builder
+                .append_value(item.balances.iter().map(|b| Some(b.free.to_string())));
+            margin_instrument_id_builder.append_value(
+                item.margins
+                    .iter()
+                    .map(|m| Some(m.instrument_id.to_string())),
+            );
+            margin_currency_builder
+                .append_value(item.margins.iter().map(|m| Some(m.currency.code)));
+            margin_initial_builder
+                .append_value(item.margins.iter().map(|m| Some(m.initial.to_string())));
+            margin_maintenance_builder
+                .append_value(item.margins.iter().map(|m| Some(m.maintenance.to_string())));
+            is_reported_builder.append_value(item.is_reported);
+            event_id_builder.append_value(item.event_id.to_string());
+            ts_event_builder.append_value(item.ts_event.as_u64());
+            ts_init_builder.append_value(item.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(account_id_builder.finish()),
+                Arc::new(account_type_builder.finish()),
+                Arc::new(base_currency_builder.finish()),
+                Arc::new(balance_currency_builder.finish()),
+                Arc::new(balance_total_builder.finish()),
+                Arc::new(balance_locked_builder.finish()),
+                Arc::new(balance_free_builder.finish()),
+                Arc::new(margin_instrument_id_builder.finish()),
+                Arc::new(margin_currency_builder.finish()),
+                Arc::new(margin_initial_builder.finish()),
+                Arc::new(margin_maintenance_builder.finish()),
+                Arc::new(is_reported_builder.finish()),
+                Arc::new(event_id_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        // States for all accounts share a single table, so no per-chunk metadata
+        HashMap::new()
+    }
+}
+
+/// Parses each item of a list column, requiring exactly `len` items so parallel
+/// list columns stay aligned.
+fn parse_list<T, E: Display>(
+    values: &ListArray,
+    column_key: &'static str,
+    row: usize,
+    len: Option<usize>,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Result<Vec<T>, EncodingError> {
+    let items = required(column_key, string_list_value(values, column_key, row)?)?;
+    if let Some(len) = len.filter(|len| items.len() != *len) {
+        return Err(EncodingError::ParseError(
+            column_key,
+            format!("expected {len} items, was {}", items.len()),
+        ));
+    }
+
+    items
+        .iter()
+        .map(|item| parse(item).map_err(|e| EncodingError::ParseError(column_key, e.to_string())))
+        .collect()
+}
+
+impl DecodeFromRecordBatch for AccountState {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        macro_rules! column {
+            ($array:ty, $name:literal, $type:expr) => {
+                extract_named_column::<$array>(&record_batch, $name, $type)?
+            };
+        }
+
+        let account_id_values = column!(StringArray, "account_id", DataType::Utf8);
+        let account_type_values = column!(StringArray, "account_type", DataType::Utf8);
+        let base_currency_values = column!(StringArray, "base_currency", DataType::Utf8);
+        let balance_currency_values =
+            column!(ListArray, "balance_currency", string_list_data_type());
+        let balance_total_values = column!(ListArray, "balance_total", string_list_data_type());
+        let balance_locked_values = column!(ListArray, "balance_locked", string_list_data_type());
+        let balance_free_values = column!(ListArray, "balance_free", string_list_data_type());
+        let margin_instrument_id_values =
+            column!(ListArray, "margin_instrument_id", string_list_data_type());
+        let margin_currency_values = column!(ListArray, "margin_currency", string_list_data_type());
+        let margin_initial_values = column!(ListArray, "margin_initial", string_list_data_type());
+        let margin_maintenance_values =
+            column!(ListArray, "margin_maintenance", string_list_data_type());
+        let is_reported_values = column!(BooleanArray, "is_reported", DataType::Boolean);
+        let event_id_values = column!(StringArray, "event_id", DataType::Utf8);
+        let ts_event_values = column!(UInt64Array, "ts_event", DataType::UInt64);
+        let ts_init_values = column!(UInt64Array, "ts_init", DataType::UInt64);
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let balance_currencies = parse_list(
+                    balance_currency_values,
+                    "balance_currency",
+                    i,
+                    None,
+                    Currency::from_str,
+                )?;
+                let n_balances = Some(balance_currencies.len());
+                let balance_totals = parse_list(
+                    balance_total_values,
+                    "balance_total",
+                    i,
+                    n_balances,
+                    Money::from_str,
+                )?;
+                let balance_locked = parse_list(
+                    balance_locked_values,
+                    "balance_locked",
+                    i,
+                    n_balances,
+                    Money::from_str,
+                )?;
+                let balance_free = parse_list(
+                    balance_free_values,
+                    "balance_free",
+                    i,
+                    n_balances,
+                    Money::from_str,
+                )?;
+                let balances = balance_currencies
+                    .into_iter()
+                    .zip(balance_totals)
+                    .zip(balance_locked)
+                    .zip(balance_free)
+                    .map(|(((currency, total), locked), free)| AccountBalance {
+                        currency,
+                        total,
+                        locked,
+                        free,
+                    })
+                    .collect();
+
+                let margin_instrument_ids = parse_list(
+                    margin_instrument_id_values,
+                    "margin_instrument_id",
+                    i,
+                    None,
+                    InstrumentId::from_str,
+                )?;
+                let n_margins = Some(margin_instrument_ids.len());
+                let margin_currencies = parse_list(
+                    margin_currency_values,
+                    "margin_currency",
+                    i,
+                    n_margins,
+                    Currency::from_str,
+                )?;
+                let margin_initial = parse_list(
+                    margin_initial_values,
+                    "margin_initial",
+                    i,
+                    n_margins,
+                    Money::from_str,
+                )?;
+                let margin_maintenance = parse_list(
+                    margin_maintenance_values,
+                    "margin_maintenance",
+                    i,
+                    n_margins,
+                    Money::from_str,
+                )?;
+                let margins = margin_instrument_ids
+                    .into_iter()
+                    .zip(margin_currencies)
+                    .zip(margin_initial)
+                    .zip(margin_maintenance)
+                    .map(
+                        |(((instrument_id, currency), initial), maintenance)| MarginBalance {
+                            initial,
+                            maintenance,
+                            currency,
+                            instrument_id,
+                        },
+                    )
+                    .collect();
+
+                Ok(Self {
+                    account_id: required(
+                        "account_id",
+                        parse_value(account_id_values, "account_id", i, |v| {
+                            AccountId::new_checked(v)
+                        })?,
+                    )?,
+                    account_type: required(
+                        "account_type",
+                        parse_value(
+                            account_type_values,
+                            "account_type",
+                            i,
+                            AccountType::from_str,
+                        )?,
+                    )?,
+                    base_currency: parse_value(
+                        base_currency_values,
+                        "base_currency",
+                        i,
+                        Currency::from_str,
+                    )?,
+                    balances,
+                    margins,
+                    is_reported: required("is_reported", boolean_value(is_reported_values, i))?,
+                    event_id: required(
+                        "event_id",
+                        parse_value(event_id_values, "event_id", i, UUID4::from_str)?,
+                    )?,
+                    ts_event: ts_event_values.value(i).into(),
+                    ts_init: ts_init_values.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+    use nautilus_model::events::account::stubs::{cash_account_state, margin_account_state};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = AccountState::get_schema_map();
+
+        assert_eq!(schema_map.len(), 15);
+        assert_eq!(schema_map["account_type"], "Utf8");
+        assert_eq!(schema_map["is_reported"], "Boolean");
+        assert_eq!(schema_map["ts_event"], "UInt64");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(
+        cash_account_state: AccountState,
+        margin_account_state: AccountState,
+    ) {
+        let data = vec![cash_account_state, margin_account_state];
+        let metadata = AccountState::chunk_metadata(&data);
+        let record_batch = AccountState::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let account_type_values = columns[1].as_any().downcast_ref::<StringArray>().unwrap();
+        let margin_initial_values = columns[9].as_any().downcast_ref::<ListArray>().unwrap();
+        let is_reported_values = columns[11].as_any().downcast_ref::<BooleanArray>().unwrap();
+
+        assert_eq!(columns.len(), 15);
+        assert_eq!(account_type_values.value(0), "CASH");
+        assert_eq!(account_type_values.value(1), "MARGIN");
+        assert_eq!(margin_initial_values.value(0).len(), 0);
+        assert_eq!(margin_initial_values.value(1).len(), 1);
+        assert_eq!(is_reported_values.len(), 2);
+
+        let decoded = AccountState::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded.len(), 2);
+        for (decoded, original) in decoded.iter().zip(&data) {
+            assert_eq!(decoded, original);
+            assert_eq!(decoded.base_currency, original.base_currency);
+            assert_eq!(decoded.balances, original.balances);
+            assert_eq!(decoded.margins, original.margins);
+            assert_eq!(decoded.is_reported, original.is_reported);
+            assert_eq!(decoded.ts_event, original.ts_event);
+            assert_eq!(decoded.ts_init, original.ts_init);
+        }
+    }
+
+    #[rstest]
+    fn test_decode_misaligned_balances(cash_account_state: AccountState) {
+        let data = vec![cash_account_state];
+        let metadata = AccountState::chunk_metadata(&data);
+        let record_batch = AccountState::encode_batch(&metadata, &data).unwrap();
+
+        let mut empty_builder = ListBuilder::new(StringBuilder::new());
+        empty_builder.append_value(Vec::<Option<String>>::new());
+        let mut columns = record_batch.columns().to_vec();
+        columns[4] = Arc::new(empty_builder.finish());
+        let record_batch = RecordBatch::try_new(record_batch.schema(), columns).unwrap();
+
+        let result = AccountState::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::ParseError("balance_total", _))
+        ));
+    }
+}
@@ -0,0 +1,1087 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Arrow encoding for [`InstrumentAny`], covering every instrument variant.
+//!
+//! All variants share a single table with a column per instrument field, where the
+//! columns which only apply to some variants are nullable. Prices, quantities, money
+//! and decimals are stored as their string representation, which carries the
+//! precision of each value.
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{
+        BooleanArray, BooleanBuilder, Float64Array, StringArray, StringBuilder, UInt8Array,
+        UInt64Array,
+    },
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    enums::{AssetClass, OptionKind},
+    identifiers::{InstrumentId, Symbol},
+    instruments::{
+        BettingInstrument, BinaryOption, CryptoFuture, CryptoOption, CryptoPerpetual, CurrencyPair,
+        Equity, FuturesContract, FuturesSpread, InstrumentAny, OptionContract, OptionSpread,
+    },
+    types::{Currency, Money, Price, Quantity},
+};
+use rust_decimal::Decimal;
+use ustr::Ustr;
+
+use super::{
+    ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch, EncodingError, boolean_value,
+    extract_named_column, parse_value, primitive_value, required, string_value,
+};
+
+impl ArrowSchemaProvider for InstrumentAny {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("instrument_type", DataType::Utf8, false),
+            Field::new("raw_symbol", DataType::Utf8, false),
+            Field::new("asset_class", DataType::Utf8, true),
+            Field::new("exchange", DataType::Utf8, true),
+            Field::new("underlying", DataType::Utf8, true),
+            Field::new("strategy_type", DataType::Utf8, true),
+            Field::new("base_currency", DataType::Utf8, true),
+            Field::new("quote_currency", DataType::Utf8, true),
+            Field::new("settlement_currency", DataType::Utf8, true),
+            Field::new("currency", DataType::Utf8, true),
+            Field::new("is_inverse", DataType::Boolean, true),
+            Field::new("option_kind", DataType::Utf8, true),
+            Field::new("strike_price", DataType::Utf8, true),
+            Field::new("activation_ns", DataType::UInt64, true),
+            Field::new("expiration_ns", DataType::UInt64, true),
+            Field::new("isin", DataType::Utf8, true),
+            Field::new("outcome", DataType::Utf8, true),
+            Field::new("description", DataType::Utf8, true),
+            Field::new("price_precision", DataType::UInt8, false),
+            Field::new("size_precision", DataType::UInt8, true),
+            Field::new("price_increment", DataType::Utf8, false),
+            Field::new("size_increment", DataType::Utf8, true),
+            Field::new("multiplier", DataType::Utf8, true),
+            Field::new("lot_size", DataType::Utf8, true),
+            Field::new("margin_init", DataType::Utf8, false),
+            Field::new("margin_maint", DataType::Utf8, false),
+            Field::new("maker_fee", DataType::Utf8, false),
+            Field::new("taker_fee", DataType::Utf8, false),
+            Field::new("max_quantity", DataType::Utf8, true),
+            Field::new("min_quantity", DataType::Utf8, true),
+            Field::new("max_notional", DataType::Utf8, true),
+            Field::new("min_notional", DataType::Utf8, true),
+            Field::new("max_price", DataType::Utf8, true),
+            Field::new("min_price", DataType::Utf8, true),
+            Field::new("event_type_id", DataType::UInt64, true),
+            Field::new("event_type_name", DataType::Utf8, true),
+            Field::new("competition_id", DataType::UInt64, true),
+            Field::new("competition_name", DataType::Utf8, true),
+            Field::new("event_id", DataType::UInt64, true),
+            Field::new("event_name", DataType::Utf8, true),
+            Field::new("event_country_code", DataType::Utf8, true),
+            Field::new("event_open_date", DataType::UInt64, true),
+            Field::new("betting_type", DataType::Utf8, true),
+            Field::new("market_id", DataType::Utf8, true),
+            Field::new("market_name", DataType::Utf8, true),
+            Field::new("market_type", DataType::Utf8, true),
+            Field::new("market_start_time", DataType::UInt64, true),
+            Field::new("selection_id", DataType::UInt64, true),
+            Field::new("selection_name", DataType::Utf8, true),
+            Field::new("selection_handicap", DataType::Float64, true),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+/// Returns the variant name of the `instrument`, e.g. `CurrencyPair`.
+#[must_use]
+pub fn instrument_type_name(instrument: &InstrumentAny) -> &'static str {
+    match instrument {
+        InstrumentAny::Betting(_) => "BettingInstrument",
+        InstrumentAny::BinaryOption(_) => "BinaryOption",
+        InstrumentAny::CryptoFuture(_) => "CryptoFuture",
+        InstrumentAny::CryptoOption(_) => "CryptoOption",
+        InstrumentAny::CryptoPerpetual(_) => "CryptoPerpetual",
+        InstrumentAny::CurrencyPair(_) => "CurrencyPair",
+        InstrumentAny::Equity(_) => "Equity",
+        InstrumentAny::FuturesContract(_) => "FuturesContract",
+        InstrumentAny::FuturesSpread(_) => "FuturesSpread",
+        InstrumentAny::OptionContract(_) => "OptionContract",
+        InstrumentAny::OptionSpread(_) => "OptionSpread",
+    }
+}
+
+/// The column values of a single instrument, with `None` for the columns which do not
+/// apply to its variant.
+#[derive(Default)]
+struct InstrumentRow {
+    instrument_id: Option<InstrumentId>,
+    raw_symbol: Option<Symbol>,
+    asset_class: Option<AssetClass>,
+    exchange: Option<Ustr>,
+    underlying: Option<Ustr>,
+    strategy_type: Option<Ustr>,
+    base_currency: Option<Currency>,
+    quote_currency: Option<Currency>,
+    settlement_currency: Option<Currency>,
+    currency: Option<Currency>,
+    is_inverse: Option<bool>,
+    option_kind: Option<OptionKind>,
+    strike_price: Option<Price>,
+    activation_ns: Option<UnixNanos>,
+    expiration_ns: Option<UnixNanos>,
+    isin: Option<Ustr>,
+    outcome: Option<Ustr>,
+    description: Option<Ustr>,
+    price_precision: Option<u8>,
+    size_precision: Option<u8>,
+    price_increment: Option<Price>,
+    size_increment: Option<Quantity>,
+    multiplier: Option<Quantity>,
+    lot_size: Option<Quantity>,
+    margin_init: Option<Decimal>,
+    margin_maint: Option<Decimal>,
+    maker_fee: Option<Decimal>,
+    taker_fee: Option<Decimal>,
+    max_quantity: Option<Quantity>,
+    min_quantity: Option<Quantity>,
+    max_notional: Option<Money>,
+    min_notional: Option<Money>,
+    max_price: Option<Price>,
+    min_price: Option<Price>,
+    event_type_id: Option<u64>,
+    event_type_name: Option<Ustr>,
+    competition_id: Option<u64>,
+    competition_name: Option<Ustr>,
+    event_id: Option<u64>,
+    event_name: Option<Ustr>,
+    event_country_code: Option<Ustr>,
+    event_open_date: Option<UnixNanos>,
+    betting_type: Option<Ustr>,
+    market_id: Option<Ustr>,
+    market_name: Option<Ustr>,
+    market_type: Option<Ustr>,
+    market_start_time: Option<UnixNanos>,
+    selection_id: Option<u64>,
+    selection_name: Option<Ustr>,
+    selection_handicap: Option<f64>,
+    ts_event: Option<UnixNanos>,
+    ts_init: Option<UnixNanos>,
+}
+
+/// Creates an [`InstrumentRow`] from the fields common to every instrument variant,
+/// plus the given variant specific columns.
+macro_rules! row_from_instrument {
+    ($instrument:ident, { $($column:ident: $value:expr),* $(,)? }) => {
+        InstrumentRow {
+            instrument_id: Some($instrument.id),
+            raw_symbol: Some($instrument.raw_symbol),
+            price_precision: Some($instrument.price_precision),
+            price_increment: Some($instrument.price_increment),
+            margin_init: Some($instrument.margin_init),
+            margin_maint: Some($instrument.margin_maint),
+            maker_fee: Some($instrument.maker_fee),
+            taker_fee: Some($instrument.taker_fee),
+            max_quantity: $instrument.max_quantity,
+            min_quantity: $instrument.min_quantity,
+            max_price: $instrument.max_price,
+            min_price: $instrument.min_price,
+            ts_event: Some($instrument.ts_event),
+            ts_init: Some($instrument.ts_init),
+            $($column: $value,)*
+            ..Default::default()
+        }
+    };
+}
+
+/// Creates an instrument of type `$instrument` from the row columns common to every
+/// instrument variant, plus the given variant specific fields.
+macro_rules! instrument_from_row {
+    ($row:ident, $instrument:ident { $($field:ident: $value:expr),* $(,)? }) => {
+        $instrument {
+            id: required("instrument_id", $row.instrument_id)?,
+            raw_symbol: required("raw_symbol", $row.raw_symbol)?,
+            price_precision: required("price_precision", $row.price_precision)?,
+            price_increment: required("price_increment", $row.price_increment)?,
+            margin_init: required("margin_init", $row.margin_init)?,
+            margin_maint: required("margin_maint", $row.margin_maint)?,
+            maker_fee: required("maker_fee", $row.maker_fee)?,
+            taker_fee: required("taker_fee", $row.taker_fee)?,
+            max_quantity: $row.max_quantity,
+            min_quantity: $row.min_quantity,
+            max_price: $row.max_price,
+            min_price: $row.min_price,
+            ts_event: required("ts_event", $row.ts_event)?,
+            ts_init: required("ts_init", $row.ts_init)?,
+            $($field: $value,)*
+        }
+    };
+}
+
+impl InstrumentRow {
+    fn from_instrument(instrument: &InstrumentAny) -> Self {
+        match instrument {
+            InstrumentAny::Betting(inst) => row_from_instrument!(inst, {
+                currency: Some(inst.currency),
+                size_precision: Some(inst.size_precision),
+                size_increment: Some(inst.size_increment),
+                max_notional: inst.max_notional,
+                min_notional: inst.min_notional,
+                event_type_id: Some(inst.event_type_id),
+                event_type_name: Some(inst.event_type_name),
+                competition_id: Some(inst.competition_id),
+                competition_name: Some(inst.competition_name),
+                event_id: Some(inst.event_id),
+                event_name: Some(inst.event_name),
+                event_country_code: Some(inst.event_country_code),
+                event_open_date: Some(inst.event_open_date),
+                betting_type: Some(inst.betting_type),
+                market_id: Some(inst.market_id),
+                market_name: Some(inst.market_name),
+                market_type: Some(inst.market_type),
+                market_start_time: Some(inst.market_start_time),
+                selection_id: Some(inst.selection_id),
+                selection_name: Some(inst.selection_name),
+                selection_handicap: Some(inst.selection_handicap),
+            }),
+            InstrumentAny::BinaryOption(inst) => row_from_instrument!(inst, {
+                asset_class: Some(inst.asset_class),
+                currency: Some(inst.currency),
+                activation_ns: Some(inst.activation_ns),
+                expiration_ns: Some(inst.expiration_ns),
+                outcome: inst.outcome,
+                description: inst.description,
+                size_precision: Some(inst.size_precision),
+                size_increment: Some(inst.size_increment),
+                max_notional: inst.max_notional,
+                min_notional: inst.min_notional,
+            }),
+            InstrumentAny::CryptoFuture(inst) => row_from_instrument!(inst, {
+                underlying: Some(inst.underlying.code),
+                quote_currency: Some(inst.quote_currency),
+                settlement_currency: Some(inst.settlement_currency),
+                is_inverse: Some(inst.is_inverse),
+                activation_ns: Some(inst.activation_ns),
+                expiration_ns: Some(inst.expiration_ns),
+                size_precision: Some(inst.size_precision),
+                size_increment: Some(inst.size_increment),
+                multiplier: Some(inst.multiplier),
+                lot_size: Some(inst.lot_size),
+                max_notional: inst.max_notional,
+                min_notional: inst.min_notional,
+            }),
+            InstrumentAny::CryptoOption(inst) => row_from_instrument!(inst, {
+                underlying: Some(inst.underlying.code),
+                quote_currency: Some(inst.quote_currency),
+                settlement_currency: Some(inst.settlement_currency),
+                is_inverse: Some(inst.is_inverse),
+                option_kind: Some(inst.option_kind),
+                strike_price: Some(inst.strike_price),
+                activation_ns: Some(inst.activation_ns),
+                expiration_ns: Some(inst.expiration_ns),
+                size_precision: Some(inst.size_precision),
+                size_increment: Some(inst.size_increment),
+                multiplier: Some(inst.multiplier),
+                lot_size: Some(inst.lot_size),
+                max_notional: inst.max_notional,
+                min_notional: inst.min_notional,
+            }),
+            InstrumentAny::CryptoPerpetual(inst) => row_from_instrument!(inst, {
+                base_currency: Some(inst.base_currency),
+                quote_currency: Some(inst.quote_currency),
+                settlement_currency: Some(inst.settlement_currency),
+                is_inverse: Some(inst.is_inverse),
+                size_precision: Some(inst.size_precision),
+                size_increment: Some(inst.size_increment),
+                multiplier: Some(inst.multiplier),
+                lot_size: Some(inst.lot_size),
+                max_notional: inst.max_notional,
+                min_notional: inst.min_notional,
+            }),
+            InstrumentAny::CurrencyPair(inst) => row_from_instrument!(inst, {
+                base_currency: Some(inst.base_currency),
+                quote_currency: Some(inst.quote_currency),
+                size_precision: Some(inst.size_precision),
+                size_increment: Some(inst.size_increment),
+                lot_size: inst.lot_size,
+                max_notional: inst.max_notional,
+                min_notional: inst.min_notional,
+            }),
+            InstrumentAny::Equity(inst) => row_from_instrument!(inst, {
+                isin: inst.isin,
+                currency: Some(inst.currency),
+                lot_size: inst.lot_size,
+            }),
+            InstrumentAny::FuturesContract(inst) => row_from_instrument!(inst, {
+                asset_class: Some(inst.asset_class),
+                exchange: inst.exchange,
+                underlying: Some(inst.underlying),
+                activation_ns: Some(inst.activation_ns),
+                expiration_ns: Some(inst.expiration_ns),
+                currency: Some(inst.currency),
+                size_precision: Some(inst.size_precision),
+                size_increment: Some(inst.size_increment),
+                multiplier: Some(inst.multiplier),
+                lot_size: Some(inst.lot_size),
+            }),
+            InstrumentAny::FuturesSpread(inst) => row_from_instrument!(inst, {
+                asset_class: Some(inst.asset_class),
+                exchange: inst.exchange,
+                underlying: Some(inst.underlying),
+                strategy_type: Some(inst.strategy_type),
+                activation_ns: Some(inst.activation_ns),
+                expiration_ns: Some(inst.expiration_ns),
+                currency: Some(inst.currency),
+                size_precision: Some(inst.size_precision),
+                size_increment: Some(inst.size_increment),
+                multiplier: Some(inst.multiplier),
+                lot_size: Some(inst.lot_size),
+            }),
+            InstrumentAny::OptionContract(inst) => row_from_instrument!(inst, {
+                asset_class: Some(inst.asset_class),
+                exchange: inst.exchange,
+                underlying: Some(inst.underlying),
+                option_kind: Some(inst.option_kind),
+                strike_price: Some(inst.strike_price),
+                activation_ns: Some(inst.activation_ns),
+                expiration_ns: Some(inst.expiration_ns),
+                currency: Some(inst.currency),
+                size_precision: Some(inst.size_precision),
+                size_increment: Some(inst.size_increment),
+                multiplier: Some(inst.multiplier),
+                lot_size: Some(inst.lot_size),
+            }),
+            InstrumentAny::OptionSpread(inst) => row_from_instrument!(inst, {
+                asset_class: Some(inst.asset_class),
+                exchange: inst.exchange,
+                underlying: Some(inst.underlying),
+                strategy_type: Some(inst.strategy_type),
+                activation_ns: Some(inst.activation_ns),
+                expiration_ns: Some(inst.expiration_ns),
+                currency: Some(inst.currency),
+                size_precision: Some(inst.size_precision),
+                size_increment: Some(inst.size_increment),
+                multiplier: Some(inst.multiplier),
+                lot_size: Some(inst.lot_size),
+            }),
+        }
+    }
+
+    fn into_instrument(self, instrument_type: &str) -> Result<InstrumentAny, EncodingError> {
+        let row = self;
+        let instrument = match instrument_type {
+            "BettingInstrument" => InstrumentAny::Betting(instrument_from_row!(
+                row,
+                BettingInstrument {
+                    currency: required("currency", row.currency)?,
+                    size_precision: required("size_precision", row.size_precision)?,
+                    size_increment: required("size_increment", row.size_increment)?,
+                    max_notional: row.max_notional,
+                    min_notional: row.min_notional,
+                    event_type_id: required("event_type_id", row.event_type_id)?,
+                    event_type_name: required("event_type_name", row.event_type_name)?,
+                    competition_id: required("competition_id", row.competition_id)?,
+                    competition_name: required("competition_name", row.competition_name)?,
+                    event_id: required("event_id", row.event_id)?,
+                    event_name: required("event_name", row.event_name)?,
+                    event_country_code: required("event_country_code", row.event_country_code)?,
+                    event_open_date: required("event_open_date", row.event_open_date)?,
+                    betting_type: required("betting_type", row.betting_type)?,
+                    market_id: required("market_id", row.market_id)?,
+                    market_name: required("market_name", row.market_name)?,
+                    market_type: required("market_type", row.market_type)?,
+                    market_start_time: required("market_start_time", row.market_start_time)?,
+                    selection_id: required("selection_id", row.selection_id)?,
+                    selection_name: required("selection_name", row.selection_name)?,
+                    selection_handicap: required("selection_handicap", row.selection_handicap)?,
+                }
+            )),
+            "BinaryOption" => InstrumentAny::BinaryOption(instrument_from_row!(
+                row,
+                BinaryOption {
+                    asset_class: required("asset_class", row.asset_class)?,
+                    currency: required("currency", row.currency)?,
+                    activation_ns: required("activation_ns", row.activation_ns)?,
+                    expiration_ns: required("expiration_ns", row.expiration_ns)?,
+                    outcome: row.outcome,
+                    description: row.description,
+                    size_precision: required("size_precision", row.size_precision)?,
+                    size_increment: required("size_increment", row.size_increment)?,
+                    max_notional: row.max_notional,
+                    min_notional: row.min_notional,
+                }
+            )),
+            "CryptoFuture" => InstrumentAny::CryptoFuture(instrument_from_row!(
+                row,
+                CryptoFuture {
+                    underlying: underlying_currency(row.underlying)?,
+                    quote_currency: required("quote_currency", row.quote_currency)?,
+                    settlement_currency: required("settlement_currency", row.settlement_currency)?,
+                    is_inverse: required("is_inverse", row.is_inverse)?,
+                    activation_ns: required("activation_ns", row.activation_ns)?,
+                    expiration_ns: required("expiration_ns", row.expiration_ns)?,
+                    size_precision: required("size_precision", row.size_precision)?,
+                    size_increment: required("size_increment", row.size_increment)?,
+                    multiplier: required("multiplier", row.multiplier)?,
+                    lot_size: required("lot_size", row.lot_size)?,
+                    max_notional: row.max_notional,
+                    min_notional: row.min_notional,
+                }
+            )),
+            "CryptoOption" => InstrumentAny::CryptoOption(instrument_from_row!(
+                row,
+                CryptoOption {
+                    underlying: underlying_currency(row.underlying)?,
+                    quote_currency: required("quote_currency", row.quote_currency)?,
+                    settlement_currency: required("settlement_currency", row.settlement_currency)?,
+                    is_inverse: required("is_inverse", row.is_inverse)?,
+                    option_kind: required("option_kind", row.option_kind)?,
+                    strike_price: required("strike_price", row.strike_price)?,
+                    activation_ns: required("activation_ns", row.activation_ns)?,
+                    expiration_ns: required("expiration_ns", row.expiration_ns)?,
+                    size_precision: required("size_precision", row.size_precision)?,
+                    size_increment: required("size_increment", row.size_increment)?,
+                    multiplier: required("multiplier", row.multiplier)?,
+                    lot_size: required("lot_size", row.lot_size)?,
+                    max_notional: row.max_notional,
+                    min_notional: row.min_notional,
+                }
+            )),
+            "CryptoPerpetual" => InstrumentAny::CryptoPerpetual(instrument_from_row!(
+                row,
+                CryptoPerpetual {
+                    base_currency: required("base_currency", row.base_currency)?,
+                    quote_currency: required("quote_currency", row.quote_currency)?,
+                    settlement_currency: required("settlement_currency", row.settlement_currency)?,
+                    is_inverse: required("is_inverse", row.is_inverse)?,
+                    size_precision: required("size_precision", row.size_precision)?,
+                    size_increment: required("size_increment", row.size_increment)?,
+                    multiplier: required("multiplier", row.multiplier)?,
+                    lot_size: required("lot_size", row.lot_size)?,
+                    max_notional: row.max_notional,
+                    min_notional: row.min_notional,
+                }
+            )),
+            "CurrencyPair" => InstrumentAny::CurrencyPair(instrument_from_row!(
+                row,
+                CurrencyPair {
+                    base_currency: required("base_currency", row.base_currency)?,
+                    quote_currency: required("quote_currency", row.quote_currency)?,
+                    size_precision: required("size_precision", row.size_precision)?,
+                    size_increment: required("size_increment", row.size_increment)?,
+                    lot_size: row.lot_size,
+                    max_notional: row.max_notional,
+                    min_notional: row.min_notional,
+                }
+            )),
+            "Equity" => InstrumentAny::Equity(instrument_from_row!(
+                row,
+                Equity {
+                    isin: row.isin,
+                    currency: required("currency", row.currency)?,
+                    lot_size: row.lot_size,
+                }
+            )),
+            "FuturesContract" => InstrumentAny::FuturesContract(instrument_from_row!(
+                row,
+                FuturesContract {
+                    asset_class: required("asset_class", row.asset_class)?,
+                    exchange: row.exchange,
+                    underlying: required("underlying", row.underlying)?,
+                    activation_ns: required("activation_ns", row.activation_ns)?,
+                    expiration_ns: required("expiration_ns", row.expiration_ns)?,
+                    currency: required("currency", row.currency)?,
+                    size_precision: required("size_precision", row.size_precision)?,
+                    size_increment: required("size_increment", row.size_increment)?,
+                    multiplier: required("multiplier", row.multiplier)?,
+                    lot_size: required("lot_size", row.lot_size)?,
+                }
+            )),
+            "FuturesSpread" => InstrumentAny::FuturesSpread(instrument_from_row!(
+                row,
+                FuturesSpread {
+                    asset_class: required("asset_class", row.asset_class)?,
+                    exchange: row.exchange,
+                    underlying: required("underlying", row.underlying)?,
+                    strategy_type: required("strategy_type", row.strategy_type)?,
+                    activation_ns: required("activation_ns", row.activation_ns)?,
+                    expiration_ns: required("expiration_ns", row.expiration_ns)?,
+                    currency: required("currency", row.currency)?,
+                    size_precision: required("size_precision", row.size_precision)?,
+                    size_increment: required("size_increment", row.size_increment)?,
+                    multiplier: required("multiplier", row.multiplier)?,
+                    lot_size: required("lot_size", row.lot_size)?,
+                }
+            )),
+            "OptionContract" => InstrumentAny::OptionContract(instrument_from_row!(
+                row,
+                OptionContract {
+                    asset_class: required("asset_class", row.asset_class)?,
+                    exchange: row.exchange,
+                    underlying: required("underlying", row.underlying)?,
+                    option_kind: required("option_kind", row.option_kind)?,
+                    strike_price: required("strike_price", row.strike_price)?,
+                    activation_ns: required("activation_ns", row.activation_ns)?,
+                    expiration_ns: required("expiration_ns", row.expiration_ns)?,
+                    currency: required("currency", row.currency)?,
+                    size_precision: required("size_precision", row.size_precision)?,
+                    size_increment: required("size_increment", row.size_increment)?,
+                    multiplier: required("multiplier", row.multiplier)?,
+                    lot_size: required("lot_size", row.lot_size)?,
+                }
+            )),
+            "OptionSpread" => InstrumentAny::OptionSpread(instrument_from_row!(
+                row,
+                OptionSpread {
+                    asset_class: required("asset_class", row.asset_class)?,
+                    exchange: row.exchange,
+                    underlying: required("underlying", row.underlying)?,
+                    strategy_type: required("strategy_type", row.strategy_type)?,
+                    activation_ns: required("activation_ns", row.activation_ns)?,
+                    expiration_ns: required("expiration_ns", row.expiration_ns)?,
+                    currency: required("currency", row.currency)?,
+                    size_precision: required("size_precision", row.size_precision)?,
+                    size_increment: required("size_increment", row.size_increment)?,
+                    multiplier: required("multiplier", row.multiplier)?,
+                    lot_size: required("lot_size", row.lot_size)?,
+                }
+            )),
+            _ => {
+                return Err(EncodingError::ParseError(
+                    "instrument_type",
+                    format!("unknown instrument type '{instrument_type}'"),
+                ));
+            }
+        };
+        Ok(instrument)
+    }
+}
+
+/// Parses the `underlying` column of a crypto derivative, which holds a currency code.
+fn underlying_currency(underlying: Option<Ustr>) -> Result<Currency, EncodingError> {
+    let code = required("underlying", underlying)?;
+    Currency::from_str(code.as_str())
+        .map_err(|e| EncodingError::ParseError("underlying", e.to_string()))
+}
+
+impl EncodeToRecordBatch for InstrumentAny {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut instrument_id_builder = StringBuilder::new();
+        let mut instrument_type_builder = StringBuilder::new();
+        let mut raw_symbol_builder = StringBuilder::new();
+        let mut asset_class_builder = StringBuilder::new();
+        let mut exchange_builder = StringBuilder::new();
+        let mut underlying_builder = StringBuilder::new();
+        let mut strategy_type_builder = StringBuilder::new();
+        let mut base_currency_builder = StringBuilder::new();
+        let mut quote_currency_builder = StringBuilder::new();
+        let mut settlement_currency_builder = StringBuilder::new();
+        let mut currency_builder = StringBuilder::new();
+        let mut is_inverse_builder = BooleanBuilder::with_capacity(data.len());
+        let mut option_kind_builder = StringBuilder::new();
+        let mut strike_price_builder = StringBuilder::new();
+        let mut activation_ns_builder = UInt64Array::builder(data.len());
+        let mut expiration_ns_builder = UInt64Array::builder(data.len());
+        let mut isin_builder = StringBuilder::new();
+        let mut outcome_builder = StringBuilder::new();
+        let mut description_builder = StringBuilder::new();
+        let mut price_precision_builder = UInt8Array::builder(data.len());
+        let mut size_precision_builder = UInt8Array::builder(data.len());
+        let mut price_increment_builder = StringBuilder::new();
+        let mut size_increment_builder = StringBuilder::new();
+        let mut multiplier_builder = StringBuilder::new();
+        let mut lot_size_builder = StringBuilder::new();
+        let mut margin_init_builder = StringBuilder::new();
+        let mut margin_maint_builder = StringBuilder::new();
+        let mut maker_fee_builder = StringBuilder::new();
+        let mut taker_fee_builder = StringBuilder::new();
+        let mut max_quantity_builder = StringBuilder::new();
+        let mut min_quantity_builder = StringBuilder::new();
+        let mut max_notional_builder = StringBuilder::new();
+        let mut min_notional_builder = StringBuilder::new();
+        let mut max_price_builder = StringBuilder::new();
+        let mut min_price_builder = StringBuilder::new();
+        let mut event_type_id_builder = UInt64Array::builder(data.len());
+        let mut event_type_name_builder = StringBuilder::new();
+        let mut competition_id_builder = UInt64Array::builder(data.len());
+        let mut competition_name_builder = StringBuilder::new();
+        let mut event_id_builder = UInt64Array::builder(data.len());
+        let mut event_name_builder = StringBuilder::new();
+        let mut event_country_code_builder = StringBuilder::new();
+        let mut event_open_date_builder = UInt64Array::builder(data.len());
+        let mut betting_type_builder = StringBuilder::new();
+        let mut market_id_builder = StringBuilder::new();
+        let mut market_name_builder = StringBuilder::new();
+        let mut market_type_builder = StringBuilder::new();
+        let mut market_start_time_builder = UInt64Array::builder(data.len());
+        let mut selection_id_builder = UInt64Array::builder(data.len());
+        let mut selection_name_builder = StringBuilder::new();
+        let mut selection_handicap_builder = Float64Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for item in data {
+            let row = InstrumentRow::from_instrument(item);
+
+            instrument_id_builder.append_option(row.instrument_id.map(|v| v.to_string()));
+            instrument_type_builder.append_value(instrument_type_name(item));
+            raw_symbol_builder.append_option(row.raw_symbol);
+            asset_class_builder.append_option(row.asset_class.map(|v| v.to_string()));
+            exchange_builder.append_option(row.exchange);
+            underlying_builder.append_option(row.underlying);
+            strategy_type_builder.append_option(row.strategy_type);
+            base_currency_builder.append_option(row.base_currency.map(|v| v.code));
+            quote_currency_builder.append_option(row.quote_currency.map(|v| v.code));
+            settlement_currency_builder.append_option(row.settlement_currency.map(|v| v.code));
+            currency_builder.append_option(row.currency.map(|v| v.code));
+            is_inverse_builder.append_option(row.is_inverse);
+            option_kind_builder.append_option(row.option_kind.map(|v| v.to_string()));
+            strike_price_builder.append_option(row.strike_price.map(|v| v.to_string()));
+            activation_ns_builder.append_option(row.activation_ns.map(|v| v.as_u64()));
+            expiration_ns_builder.append_option(row.expiration_ns.map(|v| v.as_u64()));
+            isin_builder.append_option(row.isin);
+            outcome_builder.append_option(row.outcome);
+            description_builder.append_option(row.description);
+            price_precision_builder.append_option(row.price_precision);
+            size_precision_builder.append_option(row.size_precision);
+            price_increment_builder.append_option(row.price_increment.map(|v| v.to_string()));
+            size_increment_builder.append_option(row.size_increment.map(|v| v.to_string()));
+            multiplier_builder.append_option(row.multiplier.map(|v| v.to_string()));
+            lot_size_builder.append_option(row.lot_size.map(|v| v.to_string()));
+            margin_init_builder.append_option(row.margin_init.map(|v| v.to_string()));
+            margin_maint_builder.append_option(row.margin_maint.map(|v| v.to_string()));
+            maker_fee_builder.append_option(row.maker_fee.map(|v| v.to_string()));
+            taker_fee_builder.append_option(row.taker_fee.map(|v| v.to_string()));
+            max_quantity_builder.append_option(row.max_quantity.map(|v| v.to_string()));
+            min_quantity_builder.append_option(row.min_quantity.map(|v| v.to_string()));
+            max_notional_builder.append_option(row.max_notional.map(|v| v.to_string()));
+            min_notional_builder.append_option(row.min_notional.map(|v| v.to_string()));
+            max_price_builder.append_option(row.max_price.map(|v| v.to_string()));
+            min_price_builder.append_option(row.min_price.map(|v| v.to_string()));
+            event_type_id_builder.append_option(row.event_type_id);
+            event_type_name_builder.append_option(row.event_type_name);
+            competition_id_builder.append_option(row.competition_id);
+            competition_name_builder.append_option(row.competition_name);
+            event_id_builder.append_option(row.event_id);
+            event_name_builder.append_option(row.event_name);
+            event_country_code_builder.append_option(row.event_country_code);
+            event_open_date_builder.append_option(row.event_open_date.map(|v| v.as_u64()));
+            betting_type_builder.append_option(row.betting_type);
+            market_id_builder.append_option(row.market_id);
+            market_name_builder.append_option(row.market_name);
+            market_type_builder.append_option(row.market_type);
+            market_start_time_builder.append_option(row.market_start_time.map(|v| v.as_u64()));
+            selection_id_builder.append_option(row.selection_id);
+            selection_name_builder.append_option(row.selection_name);
+            selection_handicap_builder.append_option(row.selection_handicap);
+            ts_event_builder.append_option(row.ts_event.map(|v| v.as_u64()));
+            ts_init_builder.append_option(row.ts_init.map(|v| v.as_u64()));
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(instrument_id_builder.finish()),
+                Arc::new(instrument_type_builder.finish()),
+                Arc::new(raw_symbol_builder.finish()),
+                Arc::new(asset_class_builder.finish()),
+                Arc::new(exchange_builder.finish()),
+                Arc::new(underlying_builder.finish()),
+                Arc::new(strategy_type_builder.finish()),
+                Arc::new(base_currency_builder.finish()),
+                Arc::new(quote_currency_builder.finish()),
+                Arc::new(settlement_currency_builder.finish()),
+                Arc::new(currency_builder.finish()),
+                Arc::new(is_inverse_builder.finish()),
+                Arc::new(option_kind_builder.finish()),
+                Arc::new(strike_price_builder.finish()),
+                Arc::new(activation_ns_builder.finish()),
+                Arc::new(expiration_ns_builder.finish()),
+                Arc::new(isin_builder.finish()),
+                Arc::new(outcome_builder.finish()),
+                Arc::new(description_builder.finish()),
+                Arc::new(price_precision_builder.finish()),
+                Arc::new(size_precision_builder.finish()),
+                Arc::new(price_increment_builder.finish()),
+                Arc::new(size_increment_builder.finish()),
+                Arc::new(multiplier_builder.finish()),
+                Arc::new(lot_size_builder.finish()),
+                Arc::new(margin_init_builder.finish()),
+                Arc::new(margin_maint_builder.finish()),
+                Arc::new(maker_fee_builder.finish()),
+                Arc::new(taker_fee_builder.finish()),
+                Arc::new(max_quantity_builder.finish()),
+                Arc::new(min_quantity_builder.finish()),
+                Arc::new(max_notional_builder.finish()),
+                Arc::new(min_notional_builder.finish()),
+                Arc::new(max_price_builder.finish()),
+                Arc::new(min_price_builder.finish()),
+                Arc::new(event_type_id_builder.finish()),
+                Arc::new(event_type_name_builder.finish()),
+                Arc::new(competition_id_builder.finish()),
+                Arc::new(competition_name_builder.finish()),
+                Arc::new(event_id_builder.finish()),
+                Arc::new(event_name_builder.finish()),
+                Arc::new(event_country_code_builder.finish()),
+                Arc::new(event_open_date_builder.finish()),
+                Arc::new(betting_type_builder.finish()),
+                Arc::new(market_id_builder.finish()),
+                Arc::new(market_name_builder.finish()),
+                Arc::new(market_type_builder.finish()),
+                Arc::new(market_start_time_builder.finish()),
+                Arc::new(selection_id_builder.finish()),
+                Arc::new(selection_name_builder.finish()),
+                Arc::new(selection_handicap_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        // Instruments of all types and IDs share a single table, so no per-chunk metadata
+        HashMap::new()
+    }
+}
+
+impl DecodeFromRecordBatch for InstrumentAny {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        macro_rules! column {
+            ($array:ty, $name:literal, $type:expr) => {
+                extract_named_column::<$array>(&record_batch, $name, $type)?
+            };
+        }
+
+        let instrument_id_values = column!(StringArray, "instrument_id", DataType::Utf8);
+        let instrument_type_values = column!(StringArray, "instrument_type", DataType::Utf8);
+        let raw_symbol_values = column!(StringArray, "raw_symbol", DataType::Utf8);
+        let asset_class_values = column!(StringArray, "asset_class", DataType::Utf8);
+        let exchange_values = column!(StringArray, "exchange", DataType::Utf8);
+        let underlying_values = column!(StringArray, "underlying", DataType::Utf8);
+        let strategy_type_values = column!(StringArray, "strategy_type", DataType::Utf8);
+        let base_currency_values = column!(StringArray, "base_currency", DataType::Utf8);
+        let quote_currency_values = column!(StringArray, "quote_currency", DataType::Utf8);
+        let settlement_currency_values =
+            column!(StringArray, "settlement_currency", DataType::Utf8);
+        let currency_values = column!(StringArray, "currency", DataType::Utf8);
+        let is_inverse_values = column!(BooleanArray, "is_inverse", DataType::Boolean);
+        let option_kind_values = column!(StringArray, "option_kind", DataType::Utf8);
+        let strike_price_values = column!(StringArray, "strike_price", DataType::Utf8);
+        let activation_ns_values = column!(UInt64Array, "activation_ns", DataType::UInt64);
+        let expiration_ns_values = column!(UInt64Array, "expiration_ns", DataType::UInt64);
+        let isin_values = column!(StringArray, "isin", DataType::Utf8);
+        let outcome_values = column!(StringArray, "outcome", DataType::Utf8);
+        let description_values = column!(StringArray, "description", DataType::Utf8);
+        let price_precision_values = column!(UInt8Array, "price_precision", DataType::UInt8);
+        let size_precision_values = column!(UInt8Array, "size_precision", DataType::UInt8);
+        let price_increment_values = column!(StringArray, "price_increment", DataType::Utf8);
+        let size_increment_values = column!(StringArray, "size_increment", DataType::Utf8);
+        let multiplier_values = column!(StringArray, "multiplier", DataType::Utf8);
+        let lot_size_values = column!(StringArray, "lot_size", DataType::Utf8);
+        let margin_init_values = column!(StringArray, "margin_init", DataType::Utf8);
+        let margin_maint_values = column!(StringArray, "margin_maint", DataType::Utf8);
+        let maker_fee_values = column!(StringArray, "maker_fee", DataType::Utf8);
+        let taker_fee_values = column!(StringArray, "taker_fee", DataType::Utf8);
+        let max_quantity_values = column!(StringArray, "max_quantity", DataType::Utf8);
+        let min_quantity_values = column!(StringArray, "min_quantity", DataType::Utf8);
+        let max_notional_values = column!(StringArray, "max_notional", DataType::Utf8);
+        let min_notional_values = column!(StringArray, "min_notional", DataType::Utf8);
+        let max_price_values = column!(StringArray, "max_price", DataType::Utf8);
+        let min_price_values = column!(StringArray, "min_price", DataType::Utf8);
+        let event_type_id_values = column!(UInt64Array, "event_type_id", DataType::UInt64);
+        let event_type_name_values = column!(StringArray, "event_type_name", DataType::Utf8);
+        let competition_id_values = column!(UInt64Array, "competition_id", DataType::UInt64);
+        let competition_name_values = column!(StringArray, "competition_name", DataType::Utf8);
+        let event_id_values = column!(UInt64Array, "event_id", DataType::UInt64);
+        let event_name_values = column!(StringArray, "event_name", DataType::Utf8);
+        let event_country_code_values = column!(StringArray, "event_country_code", DataType::Utf8);
+        let event_open_date_values = column!(UInt64Array, "event_open_date", DataType::UInt64);
+        let betting_type_values = column!(StringArray, "betting_type", DataType::Utf8);
+        let market_id_values = column!(StringArray, "market_id", DataType::Utf8);
+        let market_name_values = column!(StringArray, "market_name", DataType::Utf8);
+        let market_type_values = column!(StringArray, "market_type", DataType::Utf8);
+        let market_start_time_values = column!(UInt64Array, "market_start_time", DataType::UInt64);
+        let selection_id_values = column!(UInt64Array, "selection_id", DataType::UInt64);
+        let selection_name_values = column!(StringArray, "selection_name", DataType::Utf8);
+        let selection_handicap_values =
+            column!(Float64Array, "selection_handicap", DataType::Float64);
+        let ts_event_values = column!(UInt64Array, "ts_event", DataType::UInt64);
+        let ts_init_values = column!(UInt64Array, "ts_init", DataType::UInt64);
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let row = InstrumentRow {
+                    instrument_id: parse_value(
+                        instrument_id_values,
+                        "instrument_id",
+                        i,
+                        InstrumentId::from_str,
+                    )?,
+                    raw_symbol: parse_value(raw_symbol_values, "raw_symbol", i, |v| {
+                        Symbol::new_checked(v)
+                    })?,
+                    asset_class: parse_value(
+                        asset_class_values,
+                        "asset_class",
+                        i,
+                        AssetClass::from_str,
+                    )?,
+                    exchange: string_value(exchange_values, i).map(Ustr::from),
+                    underlying: string_value(underlying_values, i).map(Ustr::from),
+                    strategy_type: string_value(strategy_type_values, i).map(Ustr::from),
+                    base_currency: parse_value(
+                        base_currency_values,
+                        "base_currency",
+                        i,
+                        Currency::from_str,
+                    )?,
+                    quote_currency: parse_value(
+                        quote_currency_values,
+                        "quote_currency",
+                        i,
+                        Currency::from_str,
+                    )?,
+                    settlement_currency: parse_value(
+                        settlement_currency_values,
+                        "settlement_currency",
+                        i,
+                        Currency::from_str,
+                    )?,
+                    currency: parse_value(currency_values, "currency", i, Currency::from_str)?,
+                    is_inverse: boolean_value(is_inverse_values, i),
+                    option_kind: parse_value(
+                        option_kind_values,
+                        "option_kind",
+                        i,
+                        OptionKind::from_str,
+                    )?,
+                    strike_price: parse_value(
+                        strike_price_values,
+                        "strike_price",
+                        i,
+                        Price::from_str,
+                    )?,
+                    activation_ns: primitive_value(activation_ns_values, i).map(UnixNanos::from),
+                    expiration_ns: primitive_value(expiration_ns_values, i).map(UnixNanos::from),
+                    isin: string_value(isin_values, i).map(Ustr::from),
+                    outcome: string_value(outcome_values, i).map(Ustr::from),
+                    description: string_value(description_values, i).map(Ustr::from),
+                    price_precision: primitive_value(price_precision_values, i),
+                    size_precision: primitive_value(size_precision_values, i),
+                    price_increment: parse_value(
+                        price_increment_values,
+                        "price_increment",
+                        i,
+                        Price::from_str,
+                    )?,
+                    size_increment: parse_value(
+                        size_increment_values,
+                        "size_increment",
+                        i,
+                        Quantity::from_str,
+                    )?,
+                    multiplier: parse_value(
+                        multiplier_values,
+                        "multiplier",
+                        i,
+                        Quantity::from_str,
+                    )?,
+                    lot_size: parse_value(lot_size_values, "lot_size", i, Quantity::from_str)?,
+                    margin_init: parse_value(
+                        margin_init_values,
+                        "margin_init",
+                        i,
+                        Decimal::from_str,
+                    )?,
+                    margin_maint: parse_value(
+                        margin_maint_values,
+                        "margin_maint",
+                        i,
+                        Decimal::from_str,
+                    )?,
+                    maker_fee: parse_value(maker_fee_values, "maker_fee", i, Decimal::from_str)?,
+                    taker_fee: parse_value(taker_fee_values, "taker_fee", i, Decimal::from_str)?,
+                    max_quantity: parse_value(
+                        max_quantity_values,
+                        "max_quantity",
+                        i,
+                        Quantity::from_str,
+                    )?,
+                    min_quantity: parse_value(
+                        min_quantity_values,
+                        "min_quantity",
+                        i,
+                        Quantity::from_str,
+                    )?,
+                    max_notional: parse_value(
+                        max_notional_values,
+                        "max_notional",
+                        i,
+                        Money::from_str,
+                    )?,
+                    min_notional: parse_value(
+                        min_notional_values,
+                        "min_notional",
+                        i,
+                        Money::from_str,
+                    )?,
+                    max_price: parse_value(max_price_values, "max_price", i, Price::from_str)?,
+                    min_price: parse_value(min_price_values, "min_price", i, Price::from_str)?,
+                    event_type_id: primitive_value(event_type_id_values, i),
+                    event_type_name: string_value(event_type_name_values, i).map(Ustr::from),
+                    competition_id: primitive_value(competition_id_values, i),
+                    competition_name: string_value(competition_name_values, i).map(Ustr::from),
+                    event_id: primitive_value(event_id_values, i),
+                    event_name: string_value(event_name_values, i).map(Ustr::from),
+                    event_country_code: string_value(event_country_code_values, i).map(Ustr::from),
+                    event_open_date: primitive_value(event_open_date_values, i)
+                        .map(UnixNanos::from),
+                    betting_type: string_value(betting_type_values, i).map(Ustr::from),
+                    market_id: string_value(market_id_values, i).map(Ustr::from),
+                    market_name: string_value(market_name_values, i).map(Ustr::from),
+                    market_type: string_value(market_type_values, i).map(Ustr::from),
+                    market_start_time: primitive_value(market_start_time_values, i)
+                        .map(UnixNanos::from),
+                    selection_id: primitive_value(selection_id_values, i),
+                    selection_name: string_value(selection_name_values, i).map(Ustr::from),
+                    selection_handicap: primitive_value(selection_handicap_values, i),
+                    ts_event: primitive_value(ts_event_values, i).map(UnixNanos::from),
+                    ts_init: primitive_value(ts_init_values, i).map(UnixNanos::from),
+                };
+                row.into_instrument(instrument_type_values.value(i))
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+    use nautilus_model::instruments::{
+        Instrument,
+        stubs::{
+            betting, binary_option, crypto_future_btcusdt, currency_pair_btcusdt, equity_aapl,
+            futures_spread_es, option_contract_appl,
+        },
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = InstrumentAny::get_schema_map();
+
+        assert_eq!(schema_map.len(), 53);
+        assert_eq!(schema_map["instrument_id"], "Utf8");
+        assert_eq!(schema_map["instrument_type"], "Utf8");
+        assert_eq!(schema_map["is_inverse"], "Boolean");
+        assert_eq!(schema_map["price_precision"], "UInt8");
+        assert_eq!(schema_map["price_increment"], "Utf8");
+        assert_eq!(schema_map["expiration_ns"], "UInt64");
+        assert_eq!(schema_map["selection_handicap"], "Float64");
+        assert_eq!(schema_map["ts_init"], "UInt64");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(
+        betting: BettingInstrument,
+        binary_option: BinaryOption,
+        crypto_future_btcusdt: CryptoFuture,
+        currency_pair_btcusdt: CurrencyPair,
+        equity_aapl: Equity,
+        futures_spread_es: FuturesSpread,
+        option_contract_appl: OptionContract,
+    ) {
+        let data = vec![
+            InstrumentAny::Betting(betting),
+            InstrumentAny::BinaryOption(binary_option),
+            InstrumentAny::CryptoFuture(crypto_future_btcusdt),
+            InstrumentAny::CurrencyPair(currency_pair_btcusdt),
+            InstrumentAny::Equity(equity_aapl),
+            InstrumentAny::FuturesSpread(futures_spread_es),
+            InstrumentAny::OptionContract(option_contract_appl),
+        ];
+        let metadata = InstrumentAny::chunk_metadata(&data);
+        let record_batch = InstrumentAny::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let instrument_id_values = columns[0].as_any().downcast_ref::<StringArray>().unwrap();
+        let instrument_type_values = columns[1].as_any().downcast_ref::<StringArray>().unwrap();
+        let size_precision_values = columns[20].as_any().downcast_ref::<UInt8Array>().unwrap();
+        let price_increment_values = columns[21].as_any().downcast_ref::<StringArray>().unwrap();
+
+        assert!(metadata.is_empty());
+        assert_eq!(columns.len(), 53);
+        assert_eq!(instrument_id_values.len(), 7);
+        assert_eq!(instrument_id_values.value(3), "BTCUSDT.BINANCE");
+        assert_eq!(instrument_type_values.value(0), "BettingInstrument");
+        assert_eq!(instrument_type_values.value(4), "Equity");
+        assert!(size_precision_values.is_null(4));
+        assert_eq!(
+            price_increment_values.value(3),
+            data[3].price_increment().to_string()
+        );
+
+        let decoded = InstrumentAny::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded.len(), data.len());
+        for (decoded, original) in decoded.iter().zip(&data) {
+            assert_eq!(decoded, original);
+            assert_eq!(
+                instrument_type_name(decoded),
+                instrument_type_name(original)
+            );
+            // Instruments compare by ID only, so compare every field through `Debug`
+            assert_eq!(format!("{decoded:?}"), format!("{original:?}"));
+        }
+    }
+
+    #[rstest]
+    fn test_decode_missing_required_value(equity_aapl: Equity) {
+        let data = vec![InstrumentAny::Equity(equity_aapl)];
+        let metadata = InstrumentAny::chunk_metadata(&data);
+        let record_batch = InstrumentAny::encode_batch(&metadata, &data).unwrap();
+
+        // Decoding the equity row as a currency pair requires the base currency column
+        let mut columns = record_batch.columns().to_vec();
+        columns[1] = Arc::new(StringArray::from(vec!["CurrencyPair"]));
+        let record_batch = RecordBatch::try_new(record_batch.schema(), columns).unwrap();
+        let result = InstrumentAny::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::MissingValue("base_currency"))
+        ));
+    }
+}
@@ -15,23 +15,29 @@
 
 //! Defines the Apache Arrow schema for Nautilus types.
 
+pub mod account;
 pub mod bar;
 pub mod close;
 pub mod delta;
 pub mod depth;
 pub mod index_price;
+pub mod instrument;
 pub mod mark_price;
+pub mod order_event;
+pub mod position;
 pub mod quote;
 pub mod trade;
 
 use std::{
     collections::HashMap,
+    fmt::Display,
     io::{self, Write},
+    sync::Arc,
 };
 
 use arrow::{
-    array::{Array, ArrayRef},
-    datatypes::{DataType, Schema},
+    array::{Array, ArrayRef, BooleanArray, ListArray, PrimitiveArray, StringArray},
+    datatypes::{ArrowPrimitiveType, DataType, Field, Schema},
     error::ArrowError,
     ipc::writer::StreamWriter,
     record_batch::RecordBatch,
@@ -47,7 +53,7 @@ use nautilus_model::{
 use pyo3::prelude::*;
 
 // Define metadata key constants constants
-const KEY_BAR_TYPE: &str = "bar_type";
+pub const KEY_BAR_TYPE: &str = "bar_type";
 pub const KEY_INSTRUMENT_ID: &str = "instrument_id";
 const KEY_PRICE_PRECISION: &str = "price_precision";
 const KEY_SIZE_PRECISION: &str = "size_precision";
@@ -75,6 +81,8 @@ pub enum EncodingError {
     ParseError(&'static str, String),
     #[error("Invalid column type `{0}` at index {1}: expected {2}, found {3}")]
     InvalidColumnType(&'static str, usize, DataType, DataType),
+    #[error("Missing value in column `{0}`")]
+    MissingValue(&'static str),
     #[error("Arrow error: {0}")]
     ArrowError(#[from] arrow::error::ArrowError),
 }
@@ -136,7 +144,7 @@ where
 
 pub trait DecodeFromRecordBatch
 where
-    Self: Sized + Into<Data> + ArrowSchemaProvider,
+    Self: Sized + ArrowSchemaProvider,
 {
     /// Decodes a `RecordBatch` into a vector of values of the implementing type, using the provided metadata.
     ///
@@ -211,6 +219,104 @@ pub fn extract_column<'a, T: Array + 'static>(
     Ok(downcasted_values)
 }
 
+/// Extracts and downcasts the `column_key` column from a `RecordBatch` by name, for
+/// wide schemas where most columns only apply to some rows.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The column is not in the schema: `EncodingError::MissingColumn`.
+/// - The column type does not match `expected_type`: `EncodingError::InvalidColumnType`.
+fn extract_named_column<'a, T: Array + 'static>(
+    record_batch: &'a RecordBatch,
+    column_key: &'static str,
+    expected_type: DataType,
+) -> Result<&'a T, EncodingError> {
+    let cols = record_batch.columns();
+    let column_index = record_batch
+        .schema()
+        .index_of(column_key)
+        .unwrap_or(cols.len());
+    extract_column(cols, column_key, column_index, expected_type)
+}
+
+/// Returns the value at `row` of a nullable primitive column.
+fn primitive_value<T: ArrowPrimitiveType>(
+    values: &PrimitiveArray<T>,
+    row: usize,
+) -> Option<T::Native> {
+    values.is_valid(row).then(|| values.value(row))
+}
+
+/// Returns the value at `row` of a nullable boolean column.
+fn boolean_value(values: &BooleanArray, row: usize) -> Option<bool> {
+    values.is_valid(row).then(|| values.value(row))
+}
+
+/// Returns the value at `row` of a nullable string column.
+fn string_value(values: &StringArray, row: usize) -> Option<&str> {
+    values.is_valid(row).then(|| values.value(row))
+}
+
+/// Parses the value at `row` of the nullable string column `column_key` with `parse`.
+///
+/// # Errors
+///
+/// Returns an `EncodingError::ParseError` if the value fails to parse.
+fn parse_value<T, E: Display>(
+    values: &StringArray,
+    column_key: &'static str,
+    row: usize,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<Option<T>, EncodingError> {
+    string_value(values, row)
+        .map(|value| parse(value).map_err(|e| EncodingError::ParseError(column_key, e.to_string())))
+        .transpose()
+}
+
+/// Returns the data type of a nullable list of strings column.
+fn string_list_data_type() -> DataType {
+    DataType::List(Arc::new(Field::new("item", DataType::Utf8, true)))
+}
+
+/// Returns the strings of the list at `row` of the nullable list column `column_key`.
+///
+/// # Errors
+///
+/// Returns an `EncodingError::ParseError` if the list items are not strings.
+fn string_list_value(
+    values: &ListArray,
+    column_key: &'static str,
+    row: usize,
+) -> Result<Option<Vec<String>>, EncodingError> {
+    if values.is_null(row) {
+        return Ok(None);
+    }
+
+    let items = values.value(row);
+    let items = items
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .ok_or_else(|| {
+            EncodingError::ParseError(column_key, "expected a list of strings".to_string())
+        })?;
+    Ok(Some(
+        items
+            .iter()
+            .map(|item| item.unwrap_or_default().to_string())
+            .collect(),
+    ))
+}
+
+/// Returns the value of the `column_key` column, which is required for the row's type.
+///
+/// # Errors
+///
+/// Returns an `EncodingError::MissingValue` if `value` is `None`.
+fn required<T>(column_key: &'static str, value: Option<T>) -> Result<T, EncodingError> {
+    value.ok_or(EncodingError::MissingValue(column_key))
+}
+
 /// Converts a vector of `OrderBookDelta` into an Arrow `RecordBatch`.
 ///
 /// # Errors
@@ -0,0 +1,1033 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Arrow encoding for [`OrderEventAny`], covering every order event variant.
+//!
+//! All variants share a single table with a column per event field, where the columns
+//! which only apply to some variants are nullable. Prices, quantities, money and
+//! decimals are stored as their string representation, which carries the precision
+//! of each value.
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{
+        Array, BooleanArray, BooleanBuilder, ListArray, ListBuilder, MapArray, MapBuilder,
+        StringArray, StringBuilder, UInt64Array,
+    },
+    datatypes::{DataType, Field, Fields, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use indexmap::IndexMap;
+use nautilus_core::{UUID4, UnixNanos};
+use nautilus_model::{
+    enums::{
+        ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
+        TriggerType,
+    },
+    events::{
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEmulated,
+        OrderEventAny, OrderExpired, OrderFilled, OrderInitialized, OrderModifyRejected,
+        OrderPendingCancel, OrderPendingUpdate, OrderRejected, OrderReleased, OrderSubmitted,
+        OrderTriggered, OrderUpdated,
+    },
+    identifiers::{
+        AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
+        StrategyId, TradeId, TraderId, VenueOrderId,
+    },
+    types::{Currency, Money, Price, Quantity},
+};
+use rust_decimal::Decimal;
+use ustr::Ustr;
+
+use super::{
+    ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch, EncodingError, boolean_value,
+    extract_named_column, parse_value, primitive_value, required, string_list_data_type,
+    string_list_value, string_value,
+};
+
+fn string_map_data_type() -> DataType {
+    let entries = Fields::from(vec![
+        Field::new("keys", DataType::Utf8, false),
+        Field::new("values", DataType::Utf8, true),
+    ]);
+    DataType::Map(
+        Arc::new(Field::new("entries", DataType::Struct(entries), false)),
+        false,
+    )
+}
+
+impl ArrowSchemaProvider for OrderEventAny {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("trader_id", DataType::Utf8, false),
+            Field::new("strategy_id", DataType::Utf8, false),
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("client_order_id", DataType::Utf8, false),
+            Field::new("event_type", DataType::Utf8, false),
+            Field::new("event_id", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+            Field::new("venue_order_id", DataType::Utf8, true),
+            Field::new("account_id", DataType::Utf8, true),
+            Field::new("reconciliation", DataType::Boolean, true),
+            Field::new("reason", DataType::Utf8, true),
+            Field::new("order_side", DataType::Utf8, true),
+            Field::new("order_type", DataType::Utf8, true),
+            Field::new("quantity", DataType::Utf8, true),
+            Field::new("time_in_force", DataType::Utf8, true),
+            Field::new("post_only", DataType::Boolean, true),
+            Field::new("reduce_only", DataType::Boolean, true),
+            Field::new("quote_quantity", DataType::Boolean, true),
+            Field::new("price", DataType::Utf8, true),
+            Field::new("trigger_price", DataType::Utf8, true),
+            Field::new("trigger_type", DataType::Utf8, true),
+            Field::new("limit_offset", DataType::Utf8, true),
+            Field::new("trailing_offset", DataType::Utf8, true),
+            Field::new("trailing_offset_type", DataType::Utf8, true),
+            Field::new("expire_time", DataType::UInt64, true),
+            Field::new("display_qty", DataType::Utf8, true),
+            Field::new("emulation_trigger", DataType::Utf8, true),
+            Field::new("trigger_instrument_id", DataType::Utf8, true),
+            Field::new("contingency_type", DataType::Utf8, true),
+            Field::new("order_list_id", DataType::Utf8, true),
+            Field::new("linked_order_ids", string_list_data_type(), true),
+            Field::new("parent_order_id", DataType::Utf8, true),
+            Field::new("exec_algorithm_id", DataType::Utf8, true),
+            Field::new("exec_algorithm_params", string_map_data_type(), true),
+            Field::new("exec_spawn_id", DataType::Utf8, true),
+            Field::new("tags", string_list_data_type(), true),
+            Field::new("released_price", DataType::Utf8, true),
+            Field::new("trade_id", DataType::Utf8, true),
+            Field::new("position_id", DataType::Utf8, true),
+            Field::new("last_qty", DataType::Utf8, true),
+            Field::new("last_px", DataType::Utf8, true),
+            Field::new("currency", DataType::Utf8, true),
+            Field::new("liquidity_side", DataType::Utf8, true),
+            Field::new("commission", DataType::Utf8, true),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+/// The column values of a single order event, with `None` for the columns which do not
+/// apply to its variant.
+#[derive(Default)]
+struct OrderEventRow {
+    trader_id: Option<TraderId>,
+    strategy_id: Option<StrategyId>,
+    instrument_id: Option<InstrumentId>,
+    client_order_id: Option<ClientOrderId>,
+    event_id: Option<UUID4>,
+    ts_event: Option<UnixNanos>,
+    ts_init: Option<UnixNanos>,
+    venue_order_id: Option<VenueOrderId>,
+    account_id: Option<AccountId>,
+    reconciliation: Option<bool>,
+    reason: Option<Ustr>,
+    order_side: Option<OrderSide>,
+    order_type: Option<OrderType>,
+    quantity: Option<Quantity>,
+    time_in_force: Option<TimeInForce>,
+    post_only: Option<bool>,
+    reduce_only: Option<bool>,
+    quote_quantity: Option<bool>,
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    trigger_type: Option<TriggerType>,
+    limit_offset: Option<Decimal>,
+    trailing_offset: Option<Decimal>,
+    trailing_offset_type: Option<TrailingOffsetType>,
+    expire_time: Option<UnixNanos>,
+    display_qty: Option<Quantity>,
+    emulation_trigger: Option<TriggerType>,
+    trigger_instrument_id: Option<InstrumentId>,
+    contingency_type: Option<ContingencyType>,
+    order_list_id: Option<OrderListId>,
+    linked_order_ids: Option<Vec<ClientOrderId>>,
+    parent_order_id: Option<ClientOrderId>,
+    exec_algorithm_id: Option<ExecAlgorithmId>,
+    exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+    exec_spawn_id: Option<ClientOrderId>,
+    tags: Option<Vec<Ustr>>,
+    released_price: Option<Price>,
+    trade_id: Option<TradeId>,
+    position_id: Option<PositionId>,
+    last_qty: Option<Quantity>,
+    last_px: Option<Price>,
+    currency: Option<Currency>,
+    liquidity_side: Option<LiquiditySide>,
+    commission: Option<Money>,
+}
+
+/// Creates an [`OrderEventRow`] from the fields common to every order event variant,
+/// plus the given variant specific columns.
+macro_rules! row_from_event {
+    ($event:ident, { $($column:ident: $value:expr),* $(,)? }) => {
+        OrderEventRow {
+            trader_id: Some($event.trader_id),
+            strategy_id: Some($event.strategy_id),
+            instrument_id: Some($event.instrument_id),
+            client_order_id: Some($event.client_order_id),
+            event_id: Some($event.event_id),
+            ts_event: Some($event.ts_event),
+            ts_init: Some($event.ts_init),
+            $($column: $value,)*
+            ..Default::default()
+        }
+    };
+}
+
+/// Creates an order event of type `$event` from the row columns common to every order
+/// event variant, plus the given variant specific fields.
+macro_rules! event_from_row {
+    ($row:ident, $event:ident { $($field:ident: $value:expr),* $(,)? }) => {
+        $event {
+            trader_id: required("trader_id", $row.trader_id)?,
+            strategy_id: required("strategy_id", $row.strategy_id)?,
+            instrument_id: required("instrument_id", $row.instrument_id)?,
+            client_order_id: required("client_order_id", $row.client_order_id)?,
+            event_id: required("event_id", $row.event_id)?,
+            ts_event: required("ts_event", $row.ts_event)?,
+            ts_init: required("ts_init", $row.ts_init)?,
+            $($field: $value,)*
+        }
+    };
+}
+
+impl OrderEventRow {
+    fn from_event(event: &OrderEventAny) -> Self {
+        match event {
+            OrderEventAny::Initialized(event) => row_from_event!(event, {
+                order_side: Some(event.order_side),
+                order_type: Some(event.order_type),
+                quantity: Some(event.quantity),
+                time_in_force: Some(event.time_in_force),
+                post_only: Some(event.post_only),
+                reduce_only: Some(event.reduce_only),
+                quote_quantity: Some(event.quote_quantity),
+                reconciliation: Some(event.reconciliation),
+                price: event.price,
+                trigger_price: event.trigger_price,
+                trigger_type: event.trigger_type,
+                limit_offset: event.limit_offset,
+                trailing_offset: event.trailing_offset,
+                trailing_offset_type: event.trailing_offset_type,
+                expire_time: event.expire_time,
+                display_qty: event.display_qty,
+                emulation_trigger: event.emulation_trigger,
+                trigger_instrument_id: event.trigger_instrument_id,
+                contingency_type: event.contingency_type,
+                order_list_id: event.order_list_id,
+                linked_order_ids: event.linked_order_ids.clone(),
+                parent_order_id: event.parent_order_id,
+                exec_algorithm_id: event.exec_algorithm_id,
+                exec_algorithm_params: event.exec_algorithm_params.clone(),
+                exec_spawn_id: event.exec_spawn_id,
+                tags: event.tags.clone(),
+            }),
+            OrderEventAny::Denied(event) => row_from_event!(event, {
+                reason: Some(event.reason),
+            }),
+            OrderEventAny::Emulated(event) => row_from_event!(event, {}),
+            OrderEventAny::Released(event) => row_from_event!(event, {
+                released_price: Some(event.released_price),
+            }),
+            OrderEventAny::Submitted(event) => row_from_event!(event, {
+                account_id: Some(event.account_id),
+            }),
+            OrderEventAny::Accepted(event) => row_from_event!(event, {
+                venue_order_id: Some(event.venue_order_id),
+                account_id: Some(event.account_id),
+                reconciliation: Some(event.reconciliation != 0),
+            }),
+            OrderEventAny::Rejected(event) => row_from_event!(event, {
+                account_id: Some(event.account_id),
+                reason: Some(event.reason),
+                reconciliation: Some(event.reconciliation != 0),
+            }),
+            OrderEventAny::Canceled(event) => row_from_event!(event, {
+                venue_order_id: event.venue_order_id,
+                account_id: event.account_id,
+                reconciliation: Some(event.reconciliation != 0),
+            }),
+            OrderEventAny::Expired(event) => row_from_event!(event, {
+                venue_order_id: event.venue_order_id,
+                account_id: event.account_id,
+                reconciliation: Some(event.reconciliation != 0),
+            }),
+            OrderEventAny::Triggered(event) => row_from_event!(event, {
+                venue_order_id: event.venue_order_id,
+                account_id: event.account_id,
+                reconciliation: Some(event.reconciliation != 0),
+            }),
+            OrderEventAny::PendingUpdate(event) => row_from_event!(event, {
+                venue_order_id: event.venue_order_id,
+                account_id: Some(event.account_id),
+                reconciliation: Some(event.reconciliation != 0),
+            }),
+            OrderEventAny::PendingCancel(event) => row_from_event!(event, {
+                venue_order_id: event.venue_order_id,
+                account_id: Some(event.account_id),
+                reconciliation: Some(event.reconciliation != 0),
+            }),
+            OrderEventAny::ModifyRejected(event) => row_from_event!(event, {
+                venue_order_id: event.venue_order_id,
+                account_id: event.account_id,
+                reason: Some(event.reason),
+                reconciliation: Some(event.reconciliation != 0),
+            }),
+            OrderEventAny::CancelRejected(event) => row_from_event!(event, {
+                venue_order_id: event.venue_order_id,
+                account_id: event.account_id,
+                reason: Some(event.reason),
+                reconciliation: Some(event.reconciliation != 0),
+            }),
+            OrderEventAny::Updated(event) => row_from_event!(event, {
+                venue_order_id: event.venue_order_id,
+                account_id: event.account_id,
+                quantity: Some(event.quantity),
+                price: event.price,
+                trigger_price: event.trigger_price,
+                reconciliation: Some(event.reconciliation != 0),
+            }),
+            OrderEventAny::Filled(event) => row_from_event!(event, {
+                venue_order_id: Some(event.venue_order_id),
+                account_id: Some(event.account_id),
+                trade_id: Some(event.trade_id),
+                order_side: Some(event.order_side),
+                order_type: Some(event.order_type),
+                last_qty: Some(event.last_qty),
+                last_px: Some(event.last_px),
+                currency: Some(event.currency),
+                liquidity_side: Some(event.liquidity_side),
+                reconciliation: Some(event.reconciliation),
+                position_id: event.position_id,
+                commission: event.commission,
+            }),
+        }
+    }
+
+    fn into_event(self, event_type: &str) -> Result<OrderEventAny, EncodingError> {
+        let row = self;
+        // Most events still store the reconciliation flag as a `u8`
+        let reconciliation = row.reconciliation.map(u8::from);
+
+        let event = match event_type {
+            "Initialized" => OrderEventAny::Initialized(event_from_row!(
+                row,
+                OrderInitialized {
+                    order_side: required("order_side", row.order_side)?,
+                    order_type: required("order_type", row.order_type)?,
+                    quantity: required("quantity", row.quantity)?,
+                    time_in_force: required("time_in_force", row.time_in_force)?,
+                    post_only: required("post_only", row.post_only)?,
+                    reduce_only: required("reduce_only", row.reduce_only)?,
+                    quote_quantity: required("quote_quantity", row.quote_quantity)?,
+                    reconciliation: required("reconciliation", row.reconciliation)?,
+                    price: row.price,
+                    trigger_price: row.trigger_price,
+                    trigger_type: row.trigger_type,
+                    limit_offset: row.limit_offset,
+                    trailing_offset: row.trailing_offset,
+                    trailing_offset_type: row.trailing_offset_type,
+                    expire_time: row.expire_time,
+                    display_qty: row.display_qty,
+                    emulation_trigger: row.emulation_trigger,
+                    trigger_instrument_id: row.trigger_instrument_id,
+                    contingency_type: row.contingency_type,
+                    order_list_id: row.order_list_id,
+                    linked_order_ids: row.linked_order_ids,
+                    parent_order_id: row.parent_order_id,
+                    exec_algorithm_id: row.exec_algorithm_id,
+                    exec_algorithm_params: row.exec_algorithm_params,
+                    exec_spawn_id: row.exec_spawn_id,
+                    tags: row.tags,
+                }
+            )),
+            "Denied" => OrderEventAny::Denied(event_from_row!(
+                row,
+                OrderDenied {
+                    reason: required("reason", row.reason)?,
+                }
+            )),
+            "Emulated" => OrderEventAny::Emulated(event_from_row!(row, OrderEmulated {})),
+            "Released" => OrderEventAny::Released(event_from_row!(
+                row,
+                OrderReleased {
+                    released_price: required("released_price", row.released_price)?,
+                }
+            )),
+            "Submitted" => OrderEventAny::Submitted(event_from_row!(
+                row,
+                OrderSubmitted {
+                    account_id: required("account_id", row.account_id)?,
+                }
+            )),
+            "Accepted" => OrderEventAny::Accepted(event_from_row!(
+                row,
+                OrderAccepted {
+                    venue_order_id: required("venue_order_id", row.venue_order_id)?,
+                    account_id: required("account_id", row.account_id)?,
+                    reconciliation: required("reconciliation", reconciliation)?,
+                }
+            )),
+            "Rejected" => OrderEventAny::Rejected(event_from_row!(
+                row,
+                OrderRejected {
+                    account_id: required("account_id", row.account_id)?,
+                    reason: required("reason", row.reason)?,
+                    reconciliation: required("reconciliation", reconciliation)?,
+                }
+            )),
+            "Canceled" => OrderEventAny::Canceled(event_from_row!(
+                row,
+                OrderCanceled {
+                    venue_order_id: row.venue_order_id,
+                    account_id: row.account_id,
+                    reconciliation: required("reconciliation", reconciliation)?,
+                }
+            )),
+            "Expired" => OrderEventAny::Expired(event_from_row!(
+                row,
+                OrderExpired {
+                    venue_order_id: row.venue_order_id,
+                    account_id: row.account_id,
+                    reconciliation: required("reconciliation", reconciliation)?,
+                }
+            )),
+            "Triggered" => OrderEventAny::Triggered(event_from_row!(
+                row,
+                OrderTriggered {
+                    venue_order_id: row.venue_order_id,
+                    account_id: row.account_id,
+                    reconciliation: required("reconciliation", reconciliation)?,
+                }
+            )),
+            "PendingUpdate" => OrderEventAny::PendingUpdate(event_from_row!(
+                row,
+                OrderPendingUpdate {
+                    venue_order_id: row.venue_order_id,
+                    account_id: required("account_id", row.account_id)?,
+                    reconciliation: required("reconciliation", reconciliation)?,
+                }
+            )),
+            "PendingCancel" => OrderEventAny::PendingCancel(event_from_row!(
+                row,
+                OrderPendingCancel {
+                    venue_order_id: row.venue_order_id,
+                    account_id: required("account_id", row.account_id)?,
+                    reconciliation: required("reconciliation", reconciliation)?,
+                }
+            )),
+            "ModifyRejected" => OrderEventAny::ModifyRejected(event_from_row!(
+                row,
+                OrderModifyRejected {
+                    venue_order_id: row.venue_order_id,
+                    account_id: row.account_id,
+                    reason: required("reason", row.reason)?,
+                    reconciliation: required("reconciliation", reconciliation)?,
+                }
+            )),
+            "CancelRejected" => OrderEventAny::CancelRejected(event_from_row!(
+                row,
+                OrderCancelRejected {
+                    venue_order_id: row.venue_order_id,
+                    account_id: row.account_id,
+                    reason: required("reason", row.reason)?,
+                    reconciliation: required("reconciliation", reconciliation)?,
+                }
+            )),
+            "Updated" => OrderEventAny::Updated(event_from_row!(
+                row,
+                OrderUpdated {
+                    venue_order_id: row.venue_order_id,
+                    account_id: row.account_id,
+                    quantity: required("quantity", row.quantity)?,
+                    price: row.price,
+                    trigger_price: row.trigger_price,
+                    reconciliation: required("reconciliation", reconciliation)?,
+                }
+            )),
+            "Filled" => OrderEventAny::Filled(event_from_row!(
+                row,
+                OrderFilled {
+                    venue_order_id: required("venue_order_id", row.venue_order_id)?,
+                    account_id: required("account_id", row.account_id)?,
+                    trade_id: required("trade_id", row.trade_id)?,
+                    order_side: required("order_side", row.order_side)?,
+                    order_type: required("order_type", row.order_type)?,
+                    last_qty: required("last_qty", row.last_qty)?,
+                    last_px: required("last_px", row.last_px)?,
+                    currency: required("currency", row.currency)?,
+                    liquidity_side: required("liquidity_side", row.liquidity_side)?,
+                    reconciliation: required("reconciliation", row.reconciliation)?,
+                    position_id: row.position_id,
+                    commission: row.commission,
+                }
+            )),
+            _ => {
+                return Err(EncodingError::ParseError(
+                    "event_type",
+                    format!("unknown order event type '{event_type}'"),
+                ));
+            }
+        };
+        Ok(event)
+    }
+}
+
+/// Returns the string entries of the map at `row` of a nullable map column.
+fn string_map_value(
+    values: &MapArray,
+    column_key: &'static str,
+    row: usize,
+) -> Result<Option<IndexMap<Ustr, Ustr>>, EncodingError> {
+    if values.is_null(row) {
+        return Ok(None);
+    }
+
+    let entries = values.value(row);
+    let downcast = |index: usize| {
+        entries
+            .column(index)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .ok_or_else(|| {
+                EncodingError::ParseError(column_key, "expected a map of strings".to_string())
+            })
+    };
+    let keys = downcast(0)?;
+    let map_values = downcast(1)?;
+    Ok(Some(
+        (0..entries.len())
+            .map(|i| (Ustr::from(keys.value(i)), Ustr::from(map_values.value(i))))
+            .collect(),
+    ))
+}
+
+impl EncodeToRecordBatch for OrderEventAny {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut trader_id_builder = StringBuilder::new();
+        let mut strategy_id_builder = StringBuilder::new();
+        let mut instrument_id_builder = StringBuilder::new();
+        let mut client_order_id_builder = StringBuilder::new();
+        let mut event_type_builder = StringBuilder::new();
+        let mut event_id_builder = StringBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+        let mut venue_order_id_builder = StringBuilder::new();
+        let mut account_id_builder = StringBuilder::new();
+        let mut reconciliation_builder = BooleanBuilder::with_capacity(data.len());
+        let mut reason_builder = StringBuilder::new();
+        let mut order_side_builder = StringBuilder::new();
+        let mut order_type_builder = StringBuilder::new();
+        let mut quantity_builder = StringBuilder::new();
+        let mut time_in_force_builder = StringBuilder::new();
+        let mut post_only_builder = BooleanBuilder::with_capacity(data.len());
+        let mut reduce_only_builder = BooleanBuilder::with_capacity(data.len());
+        let mut quote_quantity_builder = BooleanBuilder::with_capacity(data.len());
+        let mut price_builder = StringBuilder::new();
+        let mut trigger_price_builder = StringBuilder::new();
+        let mut trigger_type_builder = StringBuilder::new();
+        let mut limit_offset_builder = StringBuilder::new();
+        let mut trailing_offset_builder = StringBuilder::new();
+        let mut trailing_offset_type_builder = StringBuilder::new();
+        let mut expire_time_builder = UInt64Array::builder(data.len());
+        let mut display_qty_builder = StringBuilder::new();
+        let mut emulation_trigger_builder = StringBuilder::new();
+        let mut trigger_instrument_id_builder = StringBuilder::new();
+        let mut contingency_type_builder = StringBuilder::new();
+        let mut order_list_id_builder = StringBuilder::new();
+        let mut linked_order_ids_builder = ListBuilder::new(StringBuilder::new());
+        let mut parent_order_id_builder = StringBuilder::new();
+        let mut exec_algorithm_id_builder = StringBuilder::new();
+        let mut exec_algorithm_params_builder =
+            MapBuilder::new(None, StringBuilder::new(), StringBuilder::new());
+        let mut exec_spawn_id_builder = StringBuilder::new();
+        let mut tags_builder = ListBuilder::new(StringBuilder::new());
+        let mut released_price_builder = StringBuilder::new();
+        let mut trade_id_builder = StringBuilder::new();
+        let mut position_id_builder = StringBuilder::new();
+        let mut last_qty_builder = StringBuilder::new();
+        let mut last_px_builder = StringBuilder::new();
+        let mut currency_builder = StringBuilder::new();
+        let mut liquidity_side_builder = StringBuilder::new();
+        let mut commission_builder = StringBuilder::new();
+
+        for item in data {
+            let row = OrderEventRow::from_event(item);
+
+            trader_id_builder.append_option(row.trader_id);
+            strategy_id_builder.append_option(row.strategy_id);
+            instrument_id_builder.append_option(row.instrument_id.map(|v| v.to_string()));
+            client_order_id_builder.append_option(row.client_order_id);
+            event_type_builder.append_value(item.to_string());
+            event_id_builder.append_option(row.event_id.map(|v| v.to_string()));
+            ts_event_builder.append_option(row.ts_event.map(|v| v.as_u64()));
+            ts_init_builder.append_option(row.ts_init.map(|v| v.as_u64()));
+            venue_order_id_builder.append_option(row.venue_order_id);
+            account_id_builder.append_option(row.account_id);
+            reconciliation_builder.append_option(row.reconciliation);
+            reason_builder.append_option(row.reason);
+            order_side_builder.append_option(row.order_side.map(|v| v.to_string()));
+            order_type_builder.append_option(row.order_type.map(|v| v.to_string()));
+            quantity_builder.append_option(row.quantity.map(|v| v.to_string()));
+            time_in_force_builder.append_option(row.time_in_force.map(|v| v.to_string()));
+            post_only_builder.append_option(row.post_only);
+            reduce_only_builder.append_option(row.reduce_only);
+            quote_quantity_builder.append_option(row.quote_quantity);
+            price_builder.append_option(row.price.map(|v| v.to_string()));
+            trigger_price_builder.append_option(row.trigger_price.map(|v| v.to_string()));
+            trigger_type_builder.append_option(row.trigger_type.map(|v| v.to_string()));
+            limit_offset_builder.append_option(row.limit_offset.map(|v| v.to_string()));
+            trailing_offset_builder.append_option(row.trailing_offset.map(|v| v.to_string()));
+            trailing_offset_type_builder
+                .append_option(row.trailing_offset_type.map(|v| v.to_string()));
+            expire_time_builder.append_option(row.expire_time.map(|v| v.as_u64()));
+            display_qty_builder.append_option(row.display_qty.map(|v| v.to_string()));
+            emulation_trigger_builder.append_option(row.emulation_trigger.map(|v| v.to_string()));
+            trigger_instrument_id_builder
+                .append_option(row.trigger_instrument_id.map(|v| v.to_string()));
+            contingency_type_builder.append_option(row.contingency_type.map(|v| v.to_string()));
+            order_list_id_builder.append_option(row.order_list_id);
+            linked_order_ids_builder.append_option(row.linked_order_ids.map(|ids| {
+                ids.iter()
+                    .map(|id| Some(id.to_string()))
+                    .collect::<Vec<_>>()
+            }));
+            parent_order_id_builder.append_option(row.parent_order_id);
+            exec_algorithm_id_builder.append_option(row.exec_algorithm_id);
+            if let Some(params) = row.exec_algorithm_params {
+                for (key, value) in &params {
+                    exec_algorithm_params_builder.keys().append_value(key);
+                    exec_algorithm_params_builder.values().append_value(value);
+                }
+                exec_algorithm_params_builder.append(true)?;
+            } else {
+                exec_algorithm_params_builder.append(false)?;
+            }
+            exec_spawn_id_builder.append_option(row.exec_spawn_id);
+            tags_builder.append_option(row.tags.map(|tags| {
+                tags.iter()
+                    .map(|tag| Some(tag.to_string()))
+                    .collect::<Vec<_>>()
+            }));
+            released_price_builder.append_option(row.released_price.map(|v| v.to_string()));
+            trade_id_builder.append_option(row.trade_id.map(|v| v.to_string()));
+            position_id_builder.append_option(row.position_id);
+            last_qty_builder.append_option(row.last_qty.map(|v| v.to_string()));
+            last_px_builder.append_option(row.last_px.map(|v| v.to_string()));
+            currency_builder.append_option(row.currency.map(|v| v.code));
+            liquidity_side_builder.append_option(row.liquidity_side.map(|v| v.to_string()));
+            commission_builder.append_option(row.commission.map(|v| v.to_string()));
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(trader_id_builder.finish()),
+                Arc::new(strategy_id_builder.finish()),
+                Arc::new(instrument_id_builder.finish()),
+                Arc::new(client_order_id_builder.finish()),
+                Arc::new(event_type_builder.finish()),
+                Arc::new(event_id_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+                Arc::new(venue_order_id_builder.finish()),
+                Arc::new(account_id_builder.finish()),
+                Arc::new(reconciliation_builder.finish()),
+                Arc::new(reason_builder.finish()),
+                Arc::new(order_side_builder.finish()),
+                Arc::new(order_type_builder.finish()),
+                Arc::new(quantity_builder.finish()),
+                Arc::new(time_in_force_builder.finish()),
+                Arc::new(post_only_builder.finish()),
+                Arc::new(reduce_only_builder.finish()),
+                Arc::new(quote_quantity_builder.finish()),
+                Arc::new(price_builder.finish()),
+                Arc::new(trigger_price_builder.finish()),
+                Arc::new(trigger_type_builder.finish()),
+                Arc::new(limit_offset_builder.finish()),
+                Arc::new(trailing_offset_builder.finish()),
+                Arc::new(trailing_offset_type_builder.finish()),
+                Arc::new(expire_time_builder.finish()),
+                Arc::new(display_qty_builder.finish()),
+                Arc::new(emulation_trigger_builder.finish()),
+                Arc::new(trigger_instrument_id_builder.finish()),
+                Arc::new(contingency_type_builder.finish()),
+                Arc::new(order_list_id_builder.finish()),
+                Arc::new(linked_order_ids_builder.finish()),
+                Arc::new(parent_order_id_builder.finish()),
+                Arc::new(exec_algorithm_id_builder.finish()),
+                Arc::new(exec_algorithm_params_builder.finish()),
+                Arc::new(exec_spawn_id_builder.finish()),
+                Arc::new(tags_builder.finish()),
+                Arc::new(released_price_builder.finish()),
+                Arc::new(trade_id_builder.finish()),
+                Arc::new(position_id_builder.finish()),
+                Arc::new(last_qty_builder.finish()),
+                Arc::new(last_px_builder.finish()),
+                Arc::new(currency_builder.finish()),
+                Arc::new(liquidity_side_builder.finish()),
+                Arc::new(commission_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        // Events for all orders share a single table, so no per-chunk metadata
+        HashMap::new()
+    }
+}
+
+impl DecodeFromRecordBatch for OrderEventAny {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        macro_rules! column {
+            ($array:ty, $name:literal, $type:expr) => {
+                extract_named_column::<$array>(&record_batch, $name, $type)?
+            };
+        }
+
+        let trader_id_values = column!(StringArray, "trader_id", DataType::Utf8);
+        let strategy_id_values = column!(StringArray, "strategy_id", DataType::Utf8);
+        let instrument_id_values = column!(StringArray, "instrument_id", DataType::Utf8);
+        let client_order_id_values = column!(StringArray, "client_order_id", DataType::Utf8);
+        let event_type_values = column!(StringArray, "event_type", DataType::Utf8);
+        let event_id_values = column!(StringArray, "event_id", DataType::Utf8);
+        let ts_event_values = column!(UInt64Array, "ts_event", DataType::UInt64);
+        let ts_init_values = column!(UInt64Array, "ts_init", DataType::UInt64);
+        let venue_order_id_values = column!(StringArray, "venue_order_id", DataType::Utf8);
+        let account_id_values = column!(StringArray, "account_id", DataType::Utf8);
+        let reconciliation_values = column!(BooleanArray, "reconciliation", DataType::Boolean);
+        let reason_values = column!(StringArray, "reason", DataType::Utf8);
+        let order_side_values = column!(StringArray, "order_side", DataType::Utf8);
+        let order_type_values = column!(StringArray, "order_type", DataType::Utf8);
+        let quantity_values = column!(StringArray, "quantity", DataType::Utf8);
+        let time_in_force_values = column!(StringArray, "time_in_force", DataType::Utf8);
+        let post_only_values = column!(BooleanArray, "post_only", DataType::Boolean);
+        let reduce_only_values = column!(BooleanArray, "reduce_only", DataType::Boolean);
+        let quote_quantity_values = column!(BooleanArray, "quote_quantity", DataType::Boolean);
+        let price_values = column!(StringArray, "price", DataType::Utf8);
+        let trigger_price_values = column!(StringArray, "trigger_price", DataType::Utf8);
+        let trigger_type_values = column!(StringArray, "trigger_type", DataType::Utf8);
+        let limit_offset_values = column!(StringArray, "limit_offset", DataType::Utf8);
+        let trailing_offset_values = column!(StringArray, "trailing_offset", DataType::Utf8);
+        let trailing_offset_type_values =
+            column!(StringArray, "trailing_offset_type", DataType::Utf8);
+        let expire_time_values = column!(UInt64Array, "expire_time", DataType::UInt64);
+        let display_qty_values = column!(StringArray, "display_qty", DataType::Utf8);
+        let emulation_trigger_values = column!(StringArray, "emulation_trigger", DataType::Utf8);
+        let trigger_instrument_id_values =
+            column!(StringArray, "trigger_instrument_id", DataType::Utf8);
+        let contingency_type_values = column!(StringArray, "contingency_type", DataType::Utf8);
+        let order_list_id_values = column!(StringArray, "order_list_id", DataType::Utf8);
+        let linked_order_ids_values =
+            column!(ListArray, "linked_order_ids", string_list_data_type());
+        let parent_order_id_values = column!(StringArray, "parent_order_id", DataType::Utf8);
+        let exec_algorithm_id_values = column!(StringArray, "exec_algorithm_id", DataType::Utf8);
+        let exec_algorithm_params_values =
+            column!(MapArray, "exec_algorithm_params", string_map_data_type());
+        let exec_spawn_id_values = column!(StringArray, "exec_spawn_id", DataType::Utf8);
+        let tags_values = column!(ListArray, "tags", string_list_data_type());
+        let released_price_values = column!(StringArray, "released_price", DataType::Utf8);
+        let trade_id_values = column!(StringArray, "trade_id", DataType::Utf8);
+        let position_id_values = column!(StringArray, "position_id", DataType::Utf8);
+        let last_qty_values = column!(StringArray, "last_qty", DataType::Utf8);
+        let last_px_values = column!(StringArray, "last_px", DataType::Utf8);
+        let currency_values = column!(StringArray, "currency", DataType::Utf8);
+        let liquidity_side_values = column!(StringArray, "liquidity_side", DataType::Utf8);
+        let commission_values = column!(StringArray, "commission", DataType::Utf8);
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let row = OrderEventRow {
+                    trader_id: parse_value(trader_id_values, "trader_id", i, |v| {
+                        TraderId::new_checked(v)
+                    })?,
+                    strategy_id: parse_value(strategy_id_values, "strategy_id", i, |v| {
+                        StrategyId::new_checked(v)
+                    })?,
+                    instrument_id: parse_value(
+                        instrument_id_values,
+                        "instrument_id",
+                        i,
+                        InstrumentId::from_str,
+                    )?,
+                    client_order_id: parse_value(
+                        client_order_id_values,
+                        "client_order_id",
+                        i,
+                        |v| ClientOrderId::new_checked(v),
+                    )?,
+                    event_id: parse_value(event_id_values, "event_id", i, UUID4::from_str)?,
+                    ts_event: primitive_value(ts_event_values, i).map(UnixNanos::from),
+                    ts_init: primitive_value(ts_init_values, i).map(UnixNanos::from),
+                    venue_order_id: parse_value(venue_order_id_values, "venue_order_id", i, |v| {
+                        VenueOrderId::new_checked(v)
+                    })?,
+                    account_id: parse_value(account_id_values, "account_id", i, |v| {
+                        AccountId::new_checked(v)
+                    })?,
+                    reconciliation: boolean_value(reconciliation_values, i),
+                    reason: string_value(reason_values, i).map(Ustr::from),
+                    order_side: parse_value(
+                        order_side_values,
+                        "order_side",
+                        i,
+                        OrderSide::from_str,
+                    )?,
+                    order_type: parse_value(
+                        order_type_values,
+                        "order_type",
+                        i,
+                        OrderType::from_str,
+                    )?,
+                    quantity: parse_value(quantity_values, "quantity", i, Quantity::from_str)?,
+                    time_in_force: parse_value(
+                        time_in_force_values,
+                        "time_in_force",
+                        i,
+                        TimeInForce::from_str,
+                    )?,
+                    post_only: boolean_value(post_only_values, i),
+                    reduce_only: boolean_value(reduce_only_values, i),
+                    quote_quantity: boolean_value(quote_quantity_values, i),
+                    price: parse_value(price_values, "price", i, Price::from_str)?,
+                    trigger_price: parse_value(
+                        trigger_price_values,
+                        "trigger_price",
+                        i,
+                        Price::from_str,
+                    )?,
+                    trigger_type: parse_value(
+                        trigger_type_values,
+                        "trigger_type",
+                        i,
+                        TriggerType::from_str,
+                    )?,
+                    limit_offset: parse_value(
+                        limit_offset_values,
+                        "limit_offset",
+                        i,
+                        Decimal::from_str,
+                    )?,
+                    trailing_offset: parse_value(
+                        trailing_offset_values,
+                        "trailing_offset",
+                        i,
+                        Decimal::from_str,
+                    )?,
+                    trailing_offset_type: parse_value(
+                        trailing_offset_type_values,
+                        "trailing_offset_type",
+                        i,
+                        TrailingOffsetType::from_str,
+                    )?,
+                    expire_time: primitive_value(expire_time_values, i).map(UnixNanos::from),
+                    display_qty: parse_value(
+                        display_qty_values,
+                        "display_qty",
+                        i,
+                        Quantity::from_str,
+                    )?,
+                    emulation_trigger: parse_value(
+                        emulation_trigger_values,
+                        "emulation_trigger",
+                        i,
+                        TriggerType::from_str,
+                    )?,
+                    trigger_instrument_id: parse_value(
+                        trigger_instrument_id_values,
+                        "trigger_instrument_id",
+                        i,
+                        InstrumentId::from_str,
+                    )?,
+                    contingency_type: parse_value(
+                        contingency_type_values,
+                        "contingency_type",
+                        i,
+                        ContingencyType::from_str,
+                    )?,
+                    order_list_id: parse_value(order_list_id_values, "order_list_id", i, |v| {
+                        OrderListId::new_checked(v)
+                    })?,
+                    linked_order_ids: string_list_value(
+                        linked_order_ids_values,
+                        "linked_order_ids",
+                        i,
+                    )?
+                    .map(|ids| {
+                        ids.iter()
+                            .map(|id| ClientOrderId::new_checked(id))
+                            .collect::<anyhow::Result<Vec<_>>>()
+                    })
+                    .transpose()
+                    .map_err(|e| EncodingError::ParseError("linked_order_ids", e.to_string()))?,
+                    parent_order_id: parse_value(
+                        parent_order_id_values,
+                        "parent_order_id",
+                        i,
+                        |v| ClientOrderId::new_checked(v),
+                    )?,
+                    exec_algorithm_id: parse_value(
+                        exec_algorithm_id_values,
+                        "exec_algorithm_id",
+                        i,
+                        |v| ExecAlgorithmId::new_checked(v),
+                    )?,
+                    exec_algorithm_params: string_map_value(
+                        exec_algorithm_params_values,
+                        "exec_algorithm_params",
+                        i,
+                    )?,
+                    exec_spawn_id: parse_value(exec_spawn_id_values, "exec_spawn_id", i, |v| {
+                        ClientOrderId::new_checked(v)
+                    })?,
+                    tags: string_list_value(tags_values, "tags", i)?
+                        .map(|tags| tags.iter().map(|tag| Ustr::from(tag.as_str())).collect()),
+                    released_price: parse_value(
+                        released_price_values,
+                        "released_price",
+                        i,
+                        Price::from_str,
+                    )?,
+                    trade_id: parse_value(trade_id_values, "trade_id", i, |v| {
+                        TradeId::new_checked(v)
+                    })?,
+                    position_id: parse_value(position_id_values, "position_id", i, |v| {
+                        PositionId::new_checked(v)
+                    })?,
+                    last_qty: parse_value(last_qty_values, "last_qty", i, Quantity::from_str)?,
+                    last_px: parse_value(last_px_values, "last_px", i, Price::from_str)?,
+                    currency: parse_value(currency_values, "currency", i, Currency::from_str)?,
+                    liquidity_side: parse_value(
+                        liquidity_side_values,
+                        "liquidity_side",
+                        i,
+                        LiquiditySide::from_str,
+                    )?,
+                    commission: parse_value(commission_values, "commission", i, Money::from_str)?,
+                };
+                row.into_event(event_type_values.value(i))
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::order::stubs::{
+        order_accepted, order_denied_max_submitted_rate, order_filled, order_initialized_buy_limit,
+        order_released, order_updated,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = OrderEventAny::get_schema_map();
+
+        assert_eq!(schema_map.len(), 45);
+        assert_eq!(schema_map["client_order_id"], "Utf8");
+        assert_eq!(schema_map["event_type"], "Utf8");
+        assert_eq!(schema_map["ts_init"], "UInt64");
+        assert_eq!(schema_map["reconciliation"], "Boolean");
+        assert_eq!(schema_map["expire_time"], "UInt64");
+        assert_eq!(schema_map["last_px"], "Utf8");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(
+        mut order_initialized_buy_limit: OrderInitialized,
+        order_denied_max_submitted_rate: OrderDenied,
+        order_released: OrderReleased,
+        order_accepted: OrderAccepted,
+        order_updated: OrderUpdated,
+        mut order_filled: OrderFilled,
+    ) {
+        order_initialized_buy_limit.exec_algorithm_params = Some(IndexMap::from([(
+            Ustr::from("horizon_secs"),
+            Ustr::from("20"),
+        )]));
+        order_initialized_buy_limit.tags = Some(vec![Ustr::from("ENTRY")]);
+        order_filled.ts_event = 2.into();
+        order_filled.ts_init = 3.into();
+        order_filled.commission = Some(Money::from("0.12 USDT"));
+        let last_px = order_filled.last_px;
+        let data = vec![
+            OrderEventAny::Initialized(order_initialized_buy_limit),
+            OrderEventAny::Denied(order_denied_max_submitted_rate),
+            OrderEventAny::Released(order_released),
+            OrderEventAny::Accepted(order_accepted),
+            OrderEventAny::Updated(order_updated),
+            OrderEventAny::Filled(order_filled),
+        ];
+        let metadata = OrderEventAny::chunk_metadata(&data);
+        let record_batch = OrderEventAny::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let event_type_values = columns[4].as_any().downcast_ref::<StringArray>().unwrap();
+        let ts_init_values = columns[7].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let reconciliation_values = columns[10].as_any().downcast_ref::<BooleanArray>().unwrap();
+        let last_px_values = columns[41].as_any().downcast_ref::<StringArray>().unwrap();
+
+        assert_eq!(columns.len(), 45);
+        assert_eq!(event_type_values.len(), 6);
+        assert_eq!(event_type_values.value(0), "Initialized");
+        assert_eq!(event_type_values.value(3), "Accepted");
+        assert_eq!(event_type_values.value(5), "Filled");
+        assert_eq!(ts_init_values.value(5), 3);
+        assert!(reconciliation_values.is_null(1));
+        assert!(last_px_values.is_null(0));
+        assert_eq!(last_px_values.value(5), last_px.to_string());
+
+        let decoded = OrderEventAny::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_unknown_event_type(order_released: OrderReleased) {
+        let data = vec![OrderEventAny::Released(order_released)];
+        let metadata = OrderEventAny::chunk_metadata(&data);
+        let record_batch = OrderEventAny::encode_batch(&metadata, &data).unwrap();
+
+        let mut columns = record_batch.columns().to_vec();
+        columns[4] = Arc::new(StringArray::from(vec!["Unknown"]));
+        let record_batch = RecordBatch::try_new(record_batch.schema(), columns).unwrap();
+        let result = OrderEventAny::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::ParseError("event_type", _))
+        ));
+    }
+}
@@ -0,0 +1,401 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Arrow encoding for [`PositionSnapshot`], the state captured by each position event.
+//!
+//! Each snapshot field is stored in its own column. Quantities and money are stored as
+//! their string representation, which carries the precision of each value.
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{Float64Array, ListArray, ListBuilder, StringArray, StringBuilder, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    enums::{OrderSide, PositionSide},
+    events::PositionSnapshot,
+    identifiers::{AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, TraderId},
+    types::{Currency, Money, Quantity},
+};
+
+use super::{
+    ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch, EncodingError,
+    extract_named_column, parse_value, primitive_value, required, string_list_data_type,
+    string_list_value,
+};
+
+impl ArrowSchemaProvider for PositionSnapshot {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("trader_id", DataType::Utf8, false),
+            Field::new("strategy_id", DataType::Utf8, false),
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("position_id", DataType::Utf8, false),
+            Field::new("account_id", DataType::Utf8, false),
+            Field::new("opening_order_id", DataType::Utf8, false),
+            Field::new("closing_order_id", DataType::Utf8, true),
+            Field::new("entry", DataType::Utf8, false),
+            Field::new("side", DataType::Utf8, false),
+            Field::new("signed_qty", DataType::Float64, false),
+            Field::new("quantity", DataType::Utf8, false),
+            Field::new("peak_qty", DataType::Utf8, false),
+            Field::new("quote_currency", DataType::Utf8, false),
+            Field::new("base_currency", DataType::Utf8, true),
+            Field::new("settlement_currency", DataType::Utf8, false),
+            Field::new("avg_px_open", DataType::Float64, false),
+            Field::new("avg_px_close", DataType::Float64, true),
+            Field::new("realized_return", DataType::Float64, true),
+            Field::new("realized_pnl", DataType::Utf8, true),
+            Field::new("unrealized_pnl", DataType::Utf8, true),
+            Field::new("commissions", string_list_data_type(), false),
+            Field::new("duration_ns", DataType::UInt64, true),
+            Field::new("ts_opened", DataType::UInt64, false),
+            Field::new("ts_closed", DataType::UInt64, true),
+            Field::new("ts_last", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for PositionSnapshot {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut trader_id_builder = StringBuilder::new();
+        let mut strategy_id_builder = StringBuilder::new();
+        let mut instrument_id_builder = StringBuilder::new();
+        let mut position_id_builder = StringBuilder::new();
+        let mut account_id_builder = StringBuilder::new();
+        let mut opening_order_id_builder = StringBuilder::new();
+        let mut closing_order_id_builder = StringBuilder::new();
+        let mut entry_builder = StringBuilder::new();
+        let mut side_builder = StringBuilder::new();
+        let mut signed_qty_builder = Float64Array::builder(data.len());
+        let mut quantity_builder = StringBuilder::new();
+        let mut peak_qty_builder = StringBuilder::new();
+        let mut quote_currency_builder = StringBuilder::new();
+        let mut base_currency_builder = StringBuilder::new();
+        let mut settlement_currency_builder = StringBuilder::new();
+        let mut avg_px_open_builder = Float64Array::builder(data.len());
+        let mut avg_px_close_builder = Float64Array::builder(data.len());
+        let mut realized_return_builder = Float64Array::builder(data.len());
+        let mut realized_pnl_builder = StringBuilder::new();
+        let mut unrealized_pnl_builder = StringBuilder::new();
+        let mut commissions_builder = ListBuilder::new(StringBuilder::new());
+        let mut duration_ns_builder = UInt64Array::builder(data.len());
+        let mut ts_opened_builder = UInt64Array::builder(data.len());
+        let mut ts_closed_builder = UInt64Array::builder(data.len());
+        let mut ts_last_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for item in data {
+            trader_id_builder.append_value(item.trader_id);
+            strategy_id_builder.append_value(item.strategy_id);
+            instrument_id_builder.append_value(item.instrument_id.to_string());
+            position_id_builder.append_value(item.position_id);
+            account_id_builder.append_value(item.account_id);
+            opening_order_id_builder.append_value(item.opening_order_id);
+            closing_order_id_builder.append_option(item.closing_order_id);
+            entry_builder.append_value(item.entry.to_string());
+            side_builder.append_value(item.side.to_string());
+            signed_qty_builder.append_value(item.signed_qty);
+            quantity_builder.append_value(item.quantity.to_string());
+            peak_qty_builder.append_value(item.peak_qty.to_string());
+            quote_currency_builder.append_value(item.quote_currency.code);
+            base_currency_builder.append_option(item.base_currency.map(|v| v.code));
+            settlement_currency_builder.append_value(item.settlement_currency.code);
+            avg_px_open_builder.append_value(item.avg_px_open);
+            avg_px_close_builder.append_option(item.avg_px_close);
+            realized_return_builder.append_option(item.realized_return);
+            realized_pnl_builder.append_option(item.realized_pnl.map(|v| v.to_string()));
+            unrealized_pnl_builder.append_option(item.unrealized_pnl.map(|v| v.to_string()));
+            commissions_builder.append_value(
+                item.commissions
+                    .iter()
+                    .map(|commission| Some(commission.to_string())),
+            );
+            duration_ns_builder.append_option(item.duration_ns);
+            ts_opened_builder.append_value(item.ts_opened.as_u64());
+            ts_closed_builder.append_option(item.ts_closed.map(|ts| ts.as_u64()));
+            ts_last_builder.append_value(item.ts_last.as_u64());
+            ts_init_builder.append_value(item.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(trader_id_builder.finish()),
+                Arc::new(strategy_id_builder.finish()),
+                Arc::new(instrument_id_builder.finish()),
+                Arc::new(position_id_builder.finish()),
+                Arc::new(account_id_builder.finish()),
+                Arc::new(opening_order_id_builder.finish()),
+                Arc::new(closing_order_id_builder.finish()),
+                Arc::new(entry_builder.finish()),
+                Arc::new(side_builder.finish()),
+                Arc::new(signed_qty_builder.finish()),
+                Arc::new(quantity_builder.finish()),
+                Arc::new(peak_qty_builder.finish()),
+                Arc::new(quote_currency_builder.finish()),
+                Arc::new(base_currency_builder.finish()),
+                Arc::new(settlement_currency_builder.finish()),
+                Arc::new(avg_px_open_builder.finish()),
+                Arc::new(avg_px_close_builder.finish()),
+                Arc::new(realized_return_builder.finish()),
+                Arc::new(realized_pnl_builder.finish()),
+                Arc::new(unrealized_pnl_builder.finish()),
+                Arc::new(commissions_builder.finish()),
+                Arc::new(duration_ns_builder.finish()),
+                Arc::new(ts_opened_builder.finish()),
+                Arc::new(ts_closed_builder.finish()),
+                Arc::new(ts_last_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        // Snapshots for all positions share a single table, so no per-chunk metadata
+        HashMap::new()
+    }
+}
+
+impl DecodeFromRecordBatch for PositionSnapshot {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        macro_rules! column {
+            ($array:ty, $name:literal, $type:expr) => {
+                extract_named_column::<$array>(&record_batch, $name, $type)?
+            };
+        }
+
+        let trader_id_values = column!(StringArray, "trader_id", DataType::Utf8);
+        let strategy_id_values = column!(StringArray, "strategy_id", DataType::Utf8);
+        let instrument_id_values = column!(StringArray, "instrument_id", DataType::Utf8);
+        let position_id_values = column!(StringArray, "position_id", DataType::Utf8);
+        let account_id_values = column!(StringArray, "account_id", DataType::Utf8);
+        let opening_order_id_values = column!(StringArray, "opening_order_id", DataType::Utf8);
+        let closing_order_id_values = column!(StringArray, "closing_order_id", DataType::Utf8);
+        let entry_values = column!(StringArray, "entry", DataType::Utf8);
+        let side_values = column!(StringArray, "side", DataType::Utf8);
+        let signed_qty_values = column!(Float64Array, "signed_qty", DataType::Float64);
+        let quantity_values = column!(StringArray, "quantity", DataType::Utf8);
+        let peak_qty_values = column!(StringArray, "peak_qty", DataType::Utf8);
+        let quote_currency_values = column!(StringArray, "quote_currency", DataType::Utf8);
+        let base_currency_values = column!(StringArray, "base_currency", DataType::Utf8);
+        let settlement_currency_values =
+            column!(StringArray, "settlement_currency", DataType::Utf8);
+        let avg_px_open_values = column!(Float64Array, "avg_px_open", DataType::Float64);
+        let avg_px_close_values = column!(Float64Array, "avg_px_close", DataType::Float64);
+        let realized_return_values = column!(Float64Array, "realized_return", DataType::Float64);
+        let realized_pnl_values = column!(StringArray, "realized_pnl", DataType::Utf8);
+        let unrealized_pnl_values = column!(StringArray, "unrealized_pnl", DataType::Utf8);
+        let commissions_values = column!(ListArray, "commissions", string_list_data_type());
+        let duration_ns_values = column!(UInt64Array, "duration_ns", DataType::UInt64);
+        let ts_opened_values = column!(UInt64Array, "ts_opened", DataType::UInt64);
+        let ts_closed_values = column!(UInt64Array, "ts_closed", DataType::UInt64);
+        let ts_last_values = column!(UInt64Array, "ts_last", DataType::UInt64);
+        let ts_init_values = column!(UInt64Array, "ts_init", DataType::UInt64);
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let commissions = required(
+                    "commissions",
+                    string_list_value(commissions_values, "commissions", i)?,
+                )?
+                .iter()
+                .map(|commission| Money::from_str(commission))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| EncodingError::ParseError("commissions", e.to_string()))?;
+
+                Ok(Self {
+                    trader_id: required(
+                        "trader_id",
+                        parse_value(trader_id_values, "trader_id", i, |v| {
+                            TraderId::new_checked(v)
+                        })?,
+                    )?,
+                    strategy_id: required(
+                        "strategy_id",
+                        parse_value(strategy_id_values, "strategy_id", i, |v| {
+                            StrategyId::new_checked(v)
+                        })?,
+                    )?,
+                    instrument_id: required(
+                        "instrument_id",
+                        parse_value(
+                            instrument_id_values,
+                            "instrument_id",
+                            i,
+                            InstrumentId::from_str,
+                        )?,
+                    )?,
+                    position_id: required(
+                        "position_id",
+                        parse_value(position_id_values, "position_id", i, |v| {
+                            PositionId::new_checked(v)
+                        })?,
+                    )?,
+                    account_id: required(
+                        "account_id",
+                        parse_value(account_id_values, "account_id", i, |v| {
+                            AccountId::new_checked(v)
+                        })?,
+                    )?,
+                    opening_order_id: required(
+                        "opening_order_id",
+                        parse_value(opening_order_id_values, "opening_order_id", i, |v| {
+                            ClientOrderId::new_checked(v)
+                        })?,
+                    )?,
+                    closing_order_id: parse_value(
+                        closing_order_id_values,
+                        "closing_order_id",
+                        i,
+                        |v| ClientOrderId::new_checked(v),
+                    )?,
+                    entry: required(
+                        "entry",
+                        parse_value(entry_values, "entry", i, OrderSide::from_str)?,
+                    )?,
+                    side: required(
+                        "side",
+                        parse_value(side_values, "side", i, PositionSide::from_str)?,
+                    )?,
+                    signed_qty: required("signed_qty", primitive_value(signed_qty_values, i))?,
+                    quantity: required(
+                        "quantity",
+                        parse_value(quantity_values, "quantity", i, Quantity::from_str)?,
+                    )?,
+                    peak_qty: required(
+                        "peak_qty",
+                        parse_value(peak_qty_values, "peak_qty", i, Quantity::from_str)?,
+                    )?,
+                    quote_currency: required(
+                        "quote_currency",
+                        parse_value(
+                            quote_currency_values,
+                            "quote_currency",
+                            i,
+                            Currency::from_str,
+                        )?,
+                    )?,
+                    base_currency: parse_value(
+                        base_currency_values,
+                        "base_currency",
+                        i,
+                        Currency::from_str,
+                    )?,
+                    settlement_currency: required(
+                        "settlement_currency",
+                        parse_value(
+                            settlement_currency_values,
+                            "settlement_currency",
+                            i,
+                            Currency::from_str,
+                        )?,
+                    )?,
+                    avg_px_open: required("avg_px_open", primitive_value(avg_px_open_values, i))?,
+                    avg_px_close: primitive_value(avg_px_close_values, i),
+                    realized_return: primitive_value(realized_return_values, i),
+                    realized_pnl: parse_value(
+                        realized_pnl_values,
+                        "realized_pnl",
+                        i,
+                        Money::from_str,
+                    )?,
+                    unrealized_pnl: parse_value(
+                        unrealized_pnl_values,
+                        "unrealized_pnl",
+                        i,
+                        Money::from_str,
+                    )?,
+                    commissions,
+                    duration_ns: primitive_value(duration_ns_values, i),
+                    ts_opened: ts_opened_values.value(i).into(),
+                    ts_closed: primitive_value(ts_closed_values, i).map(UnixNanos::from),
+                    ts_last: ts_last_values.value(i).into(),
+                    ts_init: ts_init_values.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+    use nautilus_model::{
+        position::Position,
+        stubs::{stub_position_long, stub_position_short},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = PositionSnapshot::get_schema_map();
+
+        assert_eq!(schema_map.len(), 26);
+        assert_eq!(schema_map["position_id"], "Utf8");
+        assert_eq!(schema_map["signed_qty"], "Float64");
+        assert_eq!(schema_map["quantity"], "Utf8");
+        assert_eq!(schema_map["ts_closed"], "UInt64");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(stub_position_long: Position, stub_position_short: Position) {
+        let data = vec![
+            PositionSnapshot::from(&stub_position_long, Some(Money::from("1.50 USD"))),
+            PositionSnapshot::from(&stub_position_short, None),
+        ];
+        let metadata = PositionSnapshot::chunk_metadata(&data);
+        let record_batch = PositionSnapshot::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let side_values = columns[8].as_any().downcast_ref::<StringArray>().unwrap();
+        let quantity_values = columns[10].as_any().downcast_ref::<StringArray>().unwrap();
+        let unrealized_pnl_values = columns[19].as_any().downcast_ref::<StringArray>().unwrap();
+        let ts_closed_values = columns[23].as_any().downcast_ref::<UInt64Array>().unwrap();
+
+        assert_eq!(columns.len(), 26);
+        assert_eq!(side_values.value(0), "LONG");
+        assert_eq!(side_values.value(1), "SHORT");
+        assert_eq!(quantity_values.value(0), data[0].quantity.to_string());
+        assert_eq!(unrealized_pnl_values.value(0), "1.50 USD");
+        assert!(unrealized_pnl_values.is_null(1));
+        assert!(ts_closed_values.is_null(0));
+
+        let decoded = PositionSnapshot::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+}
@@ -20,7 +20,7 @@ use parquet::{
     arrow::{ArrowWriter, arrow_reader::ParquetRecordBatchReaderBuilder},
     file::{
         properties::WriterProperties,
-        reader::{FileReader, SerializedFileReader},
+        reader::{ChunkReader, FileReader, SerializedFileReader},
         statistics::Statistics,
     },
 };
@@ -198,12 +198,41 @@ fn write_batches_to_file(
     max_row_group_size: Option<usize>,
 ) -> anyhow::Result<()> {
     let file = File::create(filepath)?;
+    write_batches(batches, file, compression, max_row_group_size)
+}
+
+/// Encodes multiple `RecordBatch` items into an in-memory Parquet file, with optional compression and row group sizing.
+///
+/// Used to write Parquet files to object stores, which accept whole payloads rather than streamed writes.
+///
+/// # Errors
+///
+/// Returns an error if `batches` is empty or writing to Parquet fails.
+pub fn write_batches_to_bytes(
+    batches: &[RecordBatch],
+    compression: Option<parquet::basic::Compression>,
+    max_row_group_size: Option<usize>,
+) -> anyhow::Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    write_batches(batches, &mut buffer, compression, max_row_group_size)?;
+    Ok(buffer)
+}
+
+fn write_batches<W: std::io::Write + Send>(
+    batches: &[RecordBatch],
+    writer: W,
+    compression: Option<parquet::basic::Compression>,
+    max_row_group_size: Option<usize>,
+) -> anyhow::Result<()> {
+    let Some(first) = batches.first() else {
+        anyhow::bail!("No record batches to write");
+    };
     let writer_props = WriterProperties::builder()
         .set_compression(compression.unwrap_or(parquet::basic::Compression::SNAPPY))
         .set_max_row_group_size(max_row_group_size.unwrap_or(5000))
         .build();
 
-    let mut writer = ArrowWriter::try_new(file, batches[0].schema(), Some(writer_props))?;
+    let mut writer = ArrowWriter::try_new(writer, first.schema(), Some(writer_props))?;
     for batch in batches {
         writer.write(batch)?;
     }
@@ -227,7 +256,24 @@ pub fn min_max_from_parquet_metadata(
 ) -> anyhow::Result<(i64, i64)> {
     // Open the parquet file
     let file = File::open(file_path)?;
-    let reader = SerializedFileReader::new(file)?;
+    min_max_from_parquet_reader(file, column_name)
+}
+
+/// Extracts the minimum and maximum i64 values for the specified `column_name` from the metadata
+/// of Parquet data provided by `chunk_reader`, such as an in-memory buffer fetched from an object store.
+///
+/// # Errors
+///
+/// Returns an error if metadata parsing fails, or the column is missing or has no statistics.
+///
+/// # Panics
+///
+/// Panics if the Parquet metadata's min/max unwrap operations fail unexpectedly.
+pub fn min_max_from_parquet_reader<R: ChunkReader + 'static>(
+    chunk_reader: R,
+    column_name: &str,
+) -> anyhow::Result<(i64, i64)> {
+    let reader = SerializedFileReader::new(chunk_reader)?;
 
     let metadata = reader.metadata();
     let mut overall_min_value: Option<i64> = None;
@@ -15,6 +15,7 @@
 
 use nautilus_common::{cache::CacheConfig, enums::Environment, logging::logger::LoggerConfig};
 use nautilus_core::UUID4;
+use nautilus_cryptography::secrets::SecretsConfig;
 use nautilus_data::engine::config::DataEngineConfig;
 use nautilus_execution::engine::config::ExecutionEngineConfig;
 use nautilus_model::identifiers::TraderId;
@@ -47,6 +48,7 @@ pub struct NautilusKernelBuilder {
     risk_engine: Option<RiskEngineConfig>,
     exec_engine: Option<ExecutionEngineConfig>,
     portfolio: Option<PortfolioConfig>,
+    secrets: Option<SecretsConfig>,
 }
 
 impl NautilusKernelBuilder {
@@ -72,6 +74,7 @@ impl NautilusKernelBuilder {
             risk_engine: None,
             exec_engine: None,
             portfolio: None,
+            secrets: None,
         }
     }
 
@@ -180,6 +183,13 @@ impl NautilusKernelBuilder {
         self
     }
 
+    /// Set the secrets configuration.
+    #[must_use]
+    pub fn with_secrets_config(mut self, config: SecretsConfig) -> Self {
+        self.secrets = Some(config);
+        self
+    }
+
     /// Build the [`NautilusKernel`] with the configured settings.
     ///
     /// # Errors
@@ -206,6 +216,7 @@ impl NautilusKernelBuilder {
             exec_engine: self.exec_engine,
             portfolio: self.portfolio,
             streaming: None, // streaming config - not exposed in builder yet
+            secrets: self.secrets,
         };
 
         NautilusKernel::new(self.name, config)
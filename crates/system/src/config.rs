@@ -20,6 +20,7 @@ use nautilus_common::{
     msgbus::database::MessageBusConfig,
 };
 use nautilus_core::UUID4;
+use nautilus_cryptography::secrets::SecretsConfig;
 use nautilus_data::engine::config::DataEngineConfig;
 use nautilus_execution::engine::config::ExecutionEngineConfig;
 use nautilus_model::identifiers::TraderId;
@@ -67,6 +68,8 @@ pub trait NautilusKernelConfig: Debug {
     fn portfolio(&self) -> Option<PortfolioConfig>;
     /// Returns the configuration for streaming to feather files.
     fn streaming(&self) -> Option<StreamingConfig>;
+    /// Returns the configuration for resolving secret references in client configs.
+    fn secrets(&self) -> Option<SecretsConfig>;
 }
 
 /// Basic implementation of `NautilusKernelConfig` for builder and testing.
@@ -110,6 +113,8 @@ pub struct KernelConfig {
     pub portfolio: Option<PortfolioConfig>,
     /// The configuration for streaming to feather files.
     pub streaming: Option<StreamingConfig>,
+    /// The configuration for resolving secret references in client configs.
+    pub secrets: Option<SecretsConfig>,
 }
 
 impl NautilusKernelConfig for KernelConfig {
@@ -188,6 +193,10 @@ impl NautilusKernelConfig for KernelConfig {
     fn streaming(&self) -> Option<StreamingConfig> {
         self.streaming.clone()
     }
+
+    fn secrets(&self) -> Option<SecretsConfig> {
+        self.secrets.clone()
+    }
 }
 
 impl Default for KernelConfig {
@@ -212,6 +221,7 @@ impl Default for KernelConfig {
             exec_engine: None,
             portfolio: None,
             streaming: None,
+            secrets: None,
         }
     }
 }
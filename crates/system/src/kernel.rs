@@ -33,7 +33,6 @@ use nautilus_common::{
         logger::{LogGuard, LoggerConfig},
         writer::FileWriterConfig,
     },
-    messages::{DataResponse, data::DataCommand},
     msgbus::{
         self, MessageBus, get_message_bus,
         handler::{ShareableMessageHandler, TypedMessageHandler},
@@ -42,10 +41,11 @@ use nautilus_common::{
     },
 };
 use nautilus_core::{UUID4, UnixNanos};
+use nautilus_cryptography::secrets::set_secret_resolver;
 use nautilus_data::engine::DataEngine;
 use nautilus_execution::engine::ExecutionEngine;
-use nautilus_model::{data::Data, identifiers::TraderId};
-use nautilus_portfolio::portfolio::Portfolio;
+use nautilus_model::{events::AccountState, identifiers::TraderId};
+use nautilus_portfolio::portfolio::{Portfolio, update_account};
 use nautilus_risk::engine::RiskEngine;
 use nautilus_trading::trader::Trader;
 use ustr::Ustr;
@@ -78,7 +78,7 @@ pub struct NautilusKernel {
     /// The risk engine instance.
     pub risk_engine: RiskEngine,
     /// The execution engine instance.
-    pub exec_engine: ExecutionEngine,
+    pub exec_engine: Rc<RefCell<ExecutionEngine>>,
     /// The trader component.
     pub trader: Trader,
     /// The UNIX timestamp (nanoseconds) when the kernel was created.
@@ -120,6 +120,11 @@ impl NautilusKernel {
 
         log::info!("Building system kernel");
 
+        // Install the secret resolver before any client configs are resolved
+        if let Some(secrets) = config.secrets() {
+            set_secret_resolver(secrets.build_resolver()?)?;
+        }
+
         let clock = Self::initialize_clock(&config.environment());
         let cache = Self::initialize_cache(config.cache());
 
@@ -139,32 +144,19 @@ impl NautilusKernel {
             cache.clone(),
         );
         let exec_engine = ExecutionEngine::new(clock.clone(), cache.clone(), config.exec_engine());
+        let exec_engine = Rc::new(RefCell::new(exec_engine));
 
         let data_engine = DataEngine::new(clock.clone(), cache.clone(), config.data_engine());
         let data_engine = Rc::new(RefCell::new(data_engine));
 
-        // Register DataEngine execute handler
-        let data_engine_ref = data_engine.clone();
-        let endpoint = MessagingSwitchboard::data_engine_execute();
-        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
-            move |cmd: &DataCommand| data_engine_ref.borrow_mut().execute(cmd),
-        )));
-        msgbus::register(endpoint, handler);
-
-        // Register DataEngine process handler
-        let data_engine_ref = data_engine.clone();
-        let endpoint = MessagingSwitchboard::data_engine_process();
-        let handler =
-            ShareableMessageHandler(Rc::new(TypedMessageHandler::from(move |data: &Data| {
-                data_engine_ref.borrow_mut().process_data(data.clone()) // TODO: Optimize
-            })));
-        msgbus::register(endpoint, handler);
+        DataEngine::register_message_handlers(&data_engine);
+        ExecutionEngine::register_message_handlers(&exec_engine);
 
-        // Register DataEngine response handler
-        let data_engine_ref = data_engine.clone();
-        let endpoint = MessagingSwitchboard::data_engine_response();
+        // Register Portfolio update account handler
+        let cache_ref = cache.clone();
+        let endpoint = MessagingSwitchboard::portfolio_update_account();
         let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
-            move |resp: &DataResponse| data_engine_ref.borrow_mut().response(resp.clone()),
+            move |event: &AccountState| update_account(cache_ref.clone(), event),
         )));
         msgbus::register(endpoint, handler);
 
@@ -348,8 +340,8 @@ impl NautilusKernel {
 
     /// Returns the kernel's execution engine.
     #[must_use]
-    pub const fn exec_engine(&self) -> &ExecutionEngine {
-        &self.exec_engine
+    pub fn exec_engine(&self) -> Ref<'_, ExecutionEngine> {
+        self.exec_engine.borrow()
     }
 
     /// Returns the kernel's trader.
@@ -3639,6 +3639,9 @@ class BookLevel:
     def exposure_raw(self) -> int: ...
     def first(self) -> BookOrder | None: ...
     def get_orders(self) -> list[BookOrder]: ...
+    def queue_position(self, order_id: int) -> int | None: ...
+    def orders_ahead(self, order_id: int) -> list[BookOrder] | None: ...
+    def size_ahead(self, order_id: int) -> float | None: ...
 
 class OrderBook:
     def __init__(
@@ -3718,6 +3721,12 @@ class OrderBook:
     def midpoint(self) -> float | None: ...
     def get_avg_px_for_quantity(self, qty: Quantity, order_side: OrderSide) -> float: ...
     def get_quantity_for_price(self, price: Price, order_side: OrderSide) -> float: ...
+    def queue_position(self, order_id: int) -> int | None: ...
+    def orders_ahead(self, order_id: int) -> list[BookOrder] | None: ...
+    def size_ahead(self, order_id: int) -> float | None: ...
+    def order_age(self, order_id: int) -> int | None: ...
+    def avg_order_age(self, side: OrderSide, price: Price) -> float | None: ...
+    def cancel_fill_ratio(self, side: OrderSide, price: Price) -> float | None: ...
     def simulate_fills(self, order: BookOrder) -> list[tuple[Price, Quantity]]: ...
     def pprint(self, num_levels: int = 3) -> str: ...
 
@@ -5065,6 +5074,28 @@ class SpreadAnalyzer:
     def handle_quote_tick(self, quote: QuoteTick) -> None: ...
     def reset(self) -> None: ...
 
+class ZScore:
+    def __init__(self, period: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def mean(self) -> float: ...
+    @property
+    def std(self) -> float: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, value: float) -> None: ...
+    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
+    def handle_trade_tick(self, trade: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
 class KeltnerPosition:
     def __init__(
         self,
@@ -5095,6 +5126,227 @@ class KeltnerPosition:
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
+class AverageDirectionalIndex:
+    def __init__(self, period: int, ma_type: MovingAverageType = ...) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def pos_di(self) -> float: ...
+    @property
+    def neg_di(self) -> float: ...
+    @property
+    def dx(self) -> float: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class IchimokuCloud:
+    def __init__(
+        self,
+        tenkan_period: int | None = None,
+        kijun_period: int | None = None,
+        senkou_period: int | None = None,
+        displacement: int | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def tenkan_period(self) -> int: ...
+    @property
+    def kijun_period(self) -> int: ...
+    @property
+    def senkou_period(self) -> int: ...
+    @property
+    def displacement(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def tenkan_sen(self) -> float: ...
+    @property
+    def kijun_sen(self) -> float: ...
+    @property
+    def leading_span_a(self) -> float: ...
+    @property
+    def leading_span_b(self) -> float: ...
+    @property
+    def senkou_span_a(self) -> float: ...
+    @property
+    def senkou_span_b(self) -> float: ...
+    @property
+    def chikou_span(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class SuperTrend:
+    def __init__(self, period: int, multiplier: float) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def multiplier(self) -> float: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def upper(self) -> float: ...
+    @property
+    def lower(self) -> float: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def direction(self) -> int: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class ParabolicSar:
+    def __init__(
+        self,
+        af_start: float | None = None,
+        af_step: float | None = None,
+        af_max: float | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def af_start(self) -> float: ...
+    @property
+    def af_step(self) -> float: ...
+    @property
+    def af_max(self) -> float: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def direction(self) -> int: ...
+    @property
+    def extreme_point(self) -> float: ...
+    @property
+    def acceleration(self) -> float: ...
+    def update_raw(self, high: float, low: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class FisherTransform:
+    def __init__(self, period: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    @property
+    def signal(self) -> float: ...
+    def update_raw(self, high: float, low: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class HurstExponent:
+    def __init__(self, period: int, max_lag: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def max_lag(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class ChandelierExit:
+    def __init__(self, period: int, multiplier: float) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def multiplier(self) -> float: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def long_stop(self) -> float: ...
+    @property
+    def short_stop(self) -> float: ...
+    def update_raw(self, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class ParkinsonVolatility:
+    def __init__(self, period: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, high: float, low: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class GarmanKlassVolatility:
+    def __init__(self, period: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, open: float, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
+class YangZhangVolatility:
+    def __init__(self, period: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, open: float, high: float, low: float, close: float) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+    def reset(self) -> None: ...
+
 class WeightedMovingAverage:
     def __init__(
         self,
@@ -5139,6 +5391,189 @@ class BookImbalanceRatio:
     def update(self, best_bid: Quantity | None, best_ask: Quantity) -> None: ...
     def reset(self) -> None: ...
 
+class BookQueueAge:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def bid_value(self) -> float: ...
+    @property
+    def ask_value(self) -> float: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def reset(self) -> None: ...
+
+class BookCancelFillRatio:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def bid_value(self) -> float: ...
+    @property
+    def ask_value(self) -> float: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def reset(self) -> None: ...
+
+class BookDepthImbalance:
+    def __init__(self, depth: int) -> None: ...
+    @property
+    def depth(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def update(self, bids: list[tuple[float, float]], asks: list[tuple[float, float]]) -> None: ...
+    def reset(self) -> None: ...
+
+class MicroPrice:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def handle_quote(self, quote: QuoteTick) -> None: ...
+    def update(self, best_bid: tuple[float, float] | None = None, best_ask: tuple[float, float] | None = None) -> None: ...
+    def reset(self) -> None: ...
+
+class WeightedMidPrice:
+    def __init__(self, depth: int) -> None: ...
+    @property
+    def depth(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def update(self, bids: list[tuple[float, float]], asks: list[tuple[float, float]]) -> None: ...
+    def reset(self) -> None: ...
+
+class OrderFlowImbalance:
+    def __init__(self, window: int, book_type: BookType | None = None) -> None: ...
+    @property
+    def window(self) -> int: ...
+    @property
+    def book_type(self) -> BookType: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_deltas(self, deltas: OrderBookDeltas) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_quote(self, quote: QuoteTick) -> None: ...
+    def update(self, best_bid: tuple[float, float] | None = None, best_ask: tuple[float, float] | None = None) -> None: ...
+    def reset(self) -> None: ...
+
+class BookPressure:
+    def __init__(self, depth: int) -> None: ...
+    @property
+    def depth(self) -> int: ...
+    @property
+    def bid_value(self) -> float: ...
+    @property
+    def ask_value(self) -> float: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def update(self, bids: list[tuple[float, float]], asks: list[tuple[float, float]]) -> None: ...
+    def reset(self) -> None: ...
+
+class BookSlope:
+    def __init__(self, depth: int) -> None: ...
+    @property
+    def depth(self) -> int: ...
+    @property
+    def bid_value(self) -> float: ...
+    @property
+    def ask_value(self) -> float: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def update(self, bids: list[tuple[float, float]], asks: list[tuple[float, float]]) -> None: ...
+    def reset(self) -> None: ...
+
+class Vpin:
+    def __init__(self, bucket_size: float, window: int) -> None: ...
+    @property
+    def bucket_size(self) -> float: ...
+    @property
+    def window(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_trade(self, trade: TradeTick) -> None: ...
+    def update(self, price: float, size: float, aggressor_side: AggressorSide) -> None: ...
+    def reset(self) -> None: ...
+
 ###################################################################################################
 # Adapters
 ###################################################################################################
@@ -355,4 +355,33 @@ CREATE TABLE IF NOT EXISTS "swap" (
     price TEXT,
     FOREIGN KEY (chain_id, pool_address) REFERENCES pool(chain_id, address),
     FOREIGN KEY (chain_id, block) REFERENCES block(chain_id, number)
-);
\ No newline at end of file
+);
+CREATE TABLE IF NOT EXISTS "pool_liquidity" (
+    id BIGSERIAL PRIMARY KEY,
+    chain_id INTEGER NOT NULL REFERENCES chain(chain_id) ON DELETE CASCADE,
+    pool_address TEXT NOT NULL,
+    block BIGINT NOT NULL,
+    event_type TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    position_liquidity TEXT NOT NULL,
+    amount0 TEXT NOT NULL,
+    amount1 TEXT NOT NULL,
+    tick_lower INTEGER NOT NULL,
+    tick_upper INTEGER NOT NULL,
+    FOREIGN KEY (chain_id, pool_address) REFERENCES pool(chain_id, address),
+    FOREIGN KEY (chain_id, block) REFERENCES block(chain_id, number)
+);
+
+CREATE TABLE IF NOT EXISTS "pool_collect" (
+    id BIGSERIAL PRIMARY KEY,
+    chain_id INTEGER NOT NULL REFERENCES chain(chain_id) ON DELETE CASCADE,
+    pool_address TEXT NOT NULL,
+    block BIGINT NOT NULL,
+    owner TEXT NOT NULL,
+    amount0 TEXT NOT NULL,
+    amount1 TEXT NOT NULL,
+    tick_lower INTEGER NOT NULL,
+    tick_upper INTEGER NOT NULL,
+    FOREIGN KEY (chain_id, pool_address) REFERENCES pool(chain_id, address),
+    FOREIGN KEY (chain_id, block) REFERENCES block(chain_id, number)
+);